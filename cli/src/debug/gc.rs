@@ -1,19 +1,158 @@
+use std::{cell::Cell, time::Duration};
+
 use boa_engine::{
-    js_string, object::ObjectInitializer, Context, JsObject, JsResult, JsValue, NativeFunction,
+    js_string,
+    object::{builtins::JsArray, FunctionObjectBuilder, ObjectInitializer},
+    property::Attribute,
+    Context, JsArgs, JsNativeError, JsObject, JsResult, JsValue, NativeFunction,
 };
 
+thread_local!(static LOG: Cell<bool> = const { Cell::new(false) });
+
 /// Trigger garbage collection.
-fn collect(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
-    boa_gc::force_collect();
+fn collect(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    context.heap().collect();
+    Ok(JsValue::undefined())
+}
+
+/// Get the statistics of the garbage collector.
+fn stats(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let stats = context.heap().stats();
+
+    let types = stats
+        .types
+        .iter()
+        .map(|ty| {
+            ObjectInitializer::new(context)
+                .property(
+                    js_string!("name"),
+                    js_string!(ty.type_name),
+                    Attribute::all(),
+                )
+                .property(js_string!("count"), ty.count, Attribute::all())
+                .property(js_string!("bytes"), ty.bytes, Attribute::all())
+                .build()
+                .into()
+        })
+        .collect::<Vec<JsValue>>();
+    let types = JsArray::from_iter(types, context);
+
+    let millis = |duration: Duration| duration.as_secs_f64() * 1000.0;
+
+    Ok(ObjectInitializer::new(context)
+        .property(
+            js_string!("bytesAllocated"),
+            stats.bytes_allocated,
+            Attribute::all(),
+        )
+        .property(js_string!("liveBytes"), stats.live_bytes, Attribute::all())
+        .property(
+            js_string!("youngBytes"),
+            stats.young_bytes,
+            Attribute::all(),
+        )
+        .property(js_string!("threshold"), stats.threshold, Attribute::all())
+        .property(
+            js_string!("collections"),
+            stats.collections,
+            Attribute::all(),
+        )
+        .property(
+            js_string!("minorCollections"),
+            stats.minor_collections,
+            Attribute::all(),
+        )
+        .property(
+            js_string!("lastPause"),
+            millis(stats.last_pause),
+            Attribute::all(),
+        )
+        .property(
+            js_string!("maxPause"),
+            millis(stats.max_pause),
+            Attribute::all(),
+        )
+        .property(
+            js_string!("totalPause"),
+            millis(stats.total_pause),
+            Attribute::all(),
+        )
+        .property(
+            js_string!("maxMinorPause"),
+            millis(stats.max_minor_pause),
+            Attribute::all(),
+        )
+        .property(
+            js_string!("maxMajorPause"),
+            millis(stats.max_major_pause),
+            Attribute::all(),
+        )
+        .property(js_string!("objects"), stats.objects, Attribute::all())
+        .property(js_string!("ephemerons"), stats.ephemerons, Attribute::all())
+        .property(js_string!("weakMaps"), stats.weak_maps, Attribute::all())
+        .property(js_string!("types"), types, Attribute::all())
+        .build()
+        .into())
+}
+
+/// Write a heap snapshot to the given path, in the format of the Chrome `DevTools`.
+fn snapshot(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let path = args.get_or_undefined(0).to_string(context)?;
+    let path = path.to_std_string_escaped();
+
+    context.write_heap_snapshot(&path).map_err(|err| {
+        JsNativeError::error()
+            .with_message(format!("could not write heap snapshot to {path}: {err}"))
+    })?;
+
+    Ok(JsValue::undefined())
+}
+
+fn get_log(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    Ok(LOG.with(Cell::get).into())
+}
+
+fn set_log(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0).to_boolean();
+    LOG.with(|log| log.set(value));
+
+    let hook: Option<boa_gc::GcHook> = if value {
+        Some(Box::new(|event| eprintln!("[gc] {event}")))
+    } else {
+        None
+    };
+    drop(boa_gc::set_collection_hook(hook));
+
     Ok(JsValue::undefined())
 }
 
 pub(super) fn create_object(context: &mut Context) -> JsObject {
+    let get_log = FunctionObjectBuilder::new(context.realm(), NativeFunction::from_fn_ptr(get_log))
+        .name("get log")
+        .length(0)
+        .build();
+    let set_log = FunctionObjectBuilder::new(context.realm(), NativeFunction::from_fn_ptr(set_log))
+        .name("set log")
+        .length(1)
+        .build();
+
     ObjectInitializer::new(context)
         .function(
             NativeFunction::from_fn_ptr(collect),
             js_string!("collect"),
             0,
         )
+        .function(NativeFunction::from_fn_ptr(stats), js_string!("stats"), 0)
+        .function(
+            NativeFunction::from_fn_ptr(snapshot),
+            js_string!("snapshot"),
+            1,
+        )
+        .accessor(
+            js_string!("log"),
+            Some(get_log),
+            Some(set_log),
+            Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
         .build()
 }
@@ -12,7 +12,7 @@ mod helper;
 use boa_engine::{
     builtins::promise::PromiseState,
     context::ContextBuilder,
-    job::{FutureJob, JobQueue, NativeJob},
+    job::{FutureJob, JobQueue, NativeJob, PendingFutures},
     js_string,
     module::{Module, SimpleModuleLoader},
     optimizer::OptimizerOptions,
@@ -273,7 +273,8 @@ fn evaluate_files(
             }
         } else if args.module {
             let result: JsResult<PromiseState> = (|| {
-                let module = Module::parse(Source::from_bytes(&buffer), None, context)?;
+                let module =
+                    Module::parse(Source::from_reader(&*buffer, Some(file)), None, context)?;
 
                 loader.insert(
                     file.canonicalize()
@@ -312,9 +313,14 @@ fn evaluate_files(
                 }
             }
         } else {
-            match context.eval(Source::from_bytes(&buffer)) {
+            match context.eval(Source::from_reader(&*buffer, Some(file))) {
                 Ok(v) => println!("{}", v.display()),
-                Err(v) => eprintln!("Uncaught {v}"),
+                Err(v) => {
+                    eprintln!("Uncaught {v}");
+                    for frame in v.stack_frames() {
+                        eprintln!("    at {frame}");
+                    }
+                }
             }
             context.run_jobs();
         }
@@ -447,29 +453,45 @@ fn add_runtime(context: &mut Context) {
 }
 
 #[derive(Default)]
-struct Jobs(RefCell<VecDeque<NativeJob>>);
+struct Jobs {
+    jobs: RefCell<VecDeque<NativeJob>>,
+    futures: PendingFutures,
+}
 
 impl JobQueue for Jobs {
     fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
-        self.0.borrow_mut().push_back(job);
+        self.jobs.borrow_mut().push_back(job);
     }
 
     fn run_jobs(&self, context: &mut Context) {
         loop {
-            let jobs = std::mem::take(&mut *self.0.borrow_mut());
-            if jobs.is_empty() {
-                return;
-            }
+            let jobs = std::mem::take(&mut *self.jobs.borrow_mut());
             for job in jobs {
                 if let Err(e) = job.call(context) {
                     eprintln!("Uncaught {e}");
                 }
             }
+
+            if !self.jobs.borrow().is_empty() {
+                continue;
+            }
+
+            if self
+                .futures
+                .poll(|job| self.jobs.borrow_mut().push_back(job))
+            {
+                continue;
+            }
+
+            if self.futures.is_empty() {
+                return;
+            }
+
+            self.futures.wait();
         }
     }
 
     fn enqueue_future_job(&self, future: FutureJob, _: &mut Context) {
-        let job = pollster::block_on(future);
-        self.0.borrow_mut().push_back(job);
+        self.futures.push(future);
     }
 }
@@ -11,7 +11,7 @@
 
 use std::ops::ControlFlow;
 
-use super::{ModuleSpecifier, VarDeclaration};
+use super::{ImportAttribute, ModuleRequest, ModuleSpecifier, VarDeclaration};
 use crate::{
     expression::Identifier,
     function::{AsyncFunction, AsyncGenerator, Class, Function, Generator},
@@ -87,6 +87,8 @@ pub enum ExportDeclaration {
         kind: ReExportKind,
         /// Reexported module specifier.
         specifier: ModuleSpecifier,
+        /// Import attributes of the `with` clause.
+        attributes: Box<[ImportAttribute]>,
     },
     /// List of exports.
     List(Box<[ExportSpecifier]>),
@@ -114,8 +116,15 @@ impl VisitWith for ExportDeclaration {
         V: Visitor<'a>,
     {
         match self {
-            Self::ReExport { specifier, kind } => {
+            Self::ReExport {
+                specifier,
+                kind,
+                attributes,
+            } => {
                 try_break!(visitor.visit_module_specifier(specifier));
+                for attribute in &**attributes {
+                    try_break!(attribute.visit_with(visitor));
+                }
                 visitor.visit_re_export_kind(kind)
             }
             Self::List(list) => {
@@ -140,8 +149,15 @@ impl VisitWith for ExportDeclaration {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::ReExport { specifier, kind } => {
+            Self::ReExport {
+                specifier,
+                kind,
+                attributes,
+            } => {
                 try_break!(visitor.visit_module_specifier_mut(specifier));
+                for attribute in &mut **attributes {
+                    try_break!(attribute.visit_with_mut(visitor));
+                }
                 visitor.visit_re_export_kind_mut(kind)
             }
             Self::List(list) => {
@@ -245,14 +261,14 @@ pub enum ReExportImportName {
 /// [`ExportEntry`][spec] record.
 ///
 /// [spec]: https://tc39.es/ecma262/#table-exportentry-records
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum ExportEntry {
     /// An ordinary export entry
     Ordinary(LocalExportEntry),
     /// A star reexport entry.
     StarReExport {
         /// The module from where this reexport will import.
-        module_request: Sym,
+        module_request: ModuleRequest,
     },
     /// A reexport entry with an export name.
     ReExport(IndirectExportEntry),
@@ -301,9 +317,9 @@ impl LocalExportEntry {
 }
 
 /// A reexported export entry.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct IndirectExportEntry {
-    module_request: Sym,
+    module_request: ModuleRequest,
     import_name: ReExportImportName,
     export_name: Sym,
 }
@@ -312,7 +328,7 @@ impl IndirectExportEntry {
     /// Creates a new `IndirectExportEntry`.
     #[must_use]
     pub const fn new(
-        module_request: Sym,
+        module_request: ModuleRequest,
         import_name: ReExportImportName,
         export_name: Sym,
     ) -> Self {
@@ -325,8 +341,8 @@ impl IndirectExportEntry {
 
     /// Gets the module from where this entry reexports.
     #[must_use]
-    pub const fn module_request(&self) -> Sym {
-        self.module_request
+    pub const fn module_request(&self) -> &ModuleRequest {
+        &self.module_request
     }
 
     /// Gets the import name of the reexport.
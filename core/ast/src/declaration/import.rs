@@ -18,7 +18,7 @@ use crate::{
 };
 use boa_interner::Sym;
 
-use super::ModuleSpecifier;
+use super::{ImportAttribute, ModuleRequest, ModuleSpecifier};
 
 /// The kind of import in an [`ImportDeclaration`].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -88,6 +88,8 @@ pub struct ImportDeclaration {
     kind: ImportKind,
     /// Module specifier.
     specifier: ModuleSpecifier,
+    /// Import attributes of the `with` clause.
+    attributes: Box<[ImportAttribute]>,
 }
 
 impl ImportDeclaration {
@@ -98,11 +100,13 @@ impl ImportDeclaration {
         default: Option<Identifier>,
         kind: ImportKind,
         specifier: ModuleSpecifier,
+        attributes: Box<[ImportAttribute]>,
     ) -> Self {
         Self {
             default,
             kind,
             specifier,
+            attributes,
         }
     }
 
@@ -126,6 +130,20 @@ impl ImportDeclaration {
     pub const fn kind(&self) -> &ImportKind {
         &self.kind
     }
+
+    /// Gets the import attributes of the import declaration.
+    #[inline]
+    #[must_use]
+    pub const fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+
+    /// Gets the [`ModuleRequest`] of the import declaration.
+    #[inline]
+    #[must_use]
+    pub fn module_request(&self) -> ModuleRequest {
+        ModuleRequest::new(self.specifier.sym(), &self.attributes)
+    }
 }
 
 impl VisitWith for ImportDeclaration {
@@ -137,7 +155,11 @@ impl VisitWith for ImportDeclaration {
             try_break!(visitor.visit_identifier(default));
         }
         try_break!(visitor.visit_import_kind(&self.kind));
-        visitor.visit_module_specifier(&self.specifier)
+        try_break!(visitor.visit_module_specifier(&self.specifier));
+        for attribute in &*self.attributes {
+            try_break!(attribute.visit_with(visitor));
+        }
+        ControlFlow::Continue(())
     }
 
     fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
@@ -148,7 +170,11 @@ impl VisitWith for ImportDeclaration {
             try_break!(visitor.visit_identifier_mut(default));
         }
         try_break!(visitor.visit_import_kind_mut(&mut self.kind));
-        visitor.visit_module_specifier_mut(&mut self.specifier)
+        try_break!(visitor.visit_module_specifier_mut(&mut self.specifier));
+        for attribute in &mut *self.attributes {
+            try_break!(attribute.visit_with_mut(visitor));
+        }
+        ControlFlow::Continue(())
     }
 }
 
@@ -222,9 +248,9 @@ pub enum ImportName {
 /// [`ImportEntry`][spec] record.
 ///
 /// [spec]: https://tc39.es/ecma262/#table-importentry-record-fields
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct ImportEntry {
-    module_request: Sym,
+    module_request: ModuleRequest,
     import_name: ImportName,
     local_name: Identifier,
 }
@@ -232,7 +258,11 @@ pub struct ImportEntry {
 impl ImportEntry {
     /// Creates a new `ImportEntry`.
     #[must_use]
-    pub const fn new(module_request: Sym, import_name: ImportName, local_name: Identifier) -> Self {
+    pub const fn new(
+        module_request: ModuleRequest,
+        import_name: ImportName,
+        local_name: Identifier,
+    ) -> Self {
         Self {
             module_request,
             import_name,
@@ -242,8 +272,8 @@ impl ImportEntry {
 
     /// Gets the module from where the binding must be imported.
     #[must_use]
-    pub const fn module_request(&self) -> Sym {
-        self.module_request
+    pub const fn module_request(&self) -> &ModuleRequest {
+        &self.module_request
     }
 
     /// Gets the import name of the imported binding.
@@ -22,7 +22,10 @@ mod export;
 mod import;
 mod variable;
 
-use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{
+    try_break,
+    visitor::{VisitWith, Visitor, VisitorMut},
+};
 pub use export::*;
 pub use import::*;
 pub use variable::*;
@@ -149,3 +152,104 @@ impl VisitWith for ModuleSpecifier {
         visitor.visit_sym_mut(&mut self.module)
     }
 }
+
+/// An import attribute.
+///
+/// Import attributes are the key-value pairs of the `with` clause of import declarations, re-export
+/// declarations and dynamic imports (`import json from "data.json" with { type: "json" }`).
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-WithClause
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+pub struct ImportAttribute {
+    key: Sym,
+    value: Sym,
+}
+
+impl ImportAttribute {
+    /// Creates a new `ImportAttribute` from its key and value.
+    #[inline]
+    #[must_use]
+    pub const fn new(key: Sym, value: Sym) -> Self {
+        Self { key, value }
+    }
+
+    /// Gets the key of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn key(self) -> Sym {
+        self.key
+    }
+
+    /// Gets the value of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn value(self) -> Sym {
+        self.value
+    }
+}
+
+impl VisitWith for ImportAttribute {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        try_break!(visitor.visit_sym(&self.key));
+        visitor.visit_sym(&self.value)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        try_break!(visitor.visit_sym_mut(&mut self.key));
+        visitor.visit_sym_mut(&mut self.value)
+    }
+}
+
+/// [`ModuleRequest`][spec] record.
+///
+/// A module request is the combination of a module specifier and the import attributes used to
+/// import it. Two requests with the same specifier but different attributes can resolve to
+/// different modules.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-modulerequest-record
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ModuleRequest {
+    specifier: Sym,
+    attributes: Box<[ImportAttribute]>,
+}
+
+impl ModuleRequest {
+    /// Creates a new `ModuleRequest` from a specifier and its import attributes.
+    ///
+    /// The attributes are sorted by key, making requests with the same attributes in different
+    /// order equal.
+    #[must_use]
+    pub fn new(specifier: Sym, attributes: &[ImportAttribute]) -> Self {
+        let mut attributes = attributes.to_vec();
+        attributes.sort_unstable_by_key(|attribute| attribute.key());
+        Self {
+            specifier,
+            attributes: attributes.into_boxed_slice(),
+        }
+    }
+
+    /// Gets the module specifier of the request.
+    #[inline]
+    #[must_use]
+    pub const fn specifier(&self) -> Sym {
+        self.specifier
+    }
+
+    /// Gets the import attributes of the request.
+    #[inline]
+    #[must_use]
+    pub const fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+}
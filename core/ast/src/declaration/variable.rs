@@ -105,6 +105,20 @@ pub enum LexicalDeclaration {
     ///
     /// [let]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/let
     Let(VariableList),
+
+    /// A <code>[using]</code> declaration creates a constant that holds a resource, which is
+    /// disposed by calling its `[Symbol.dispose]()` method when the scope of the declaration is
+    /// exited.
+    ///
+    /// [using]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/using
+    Using(VariableList),
+
+    /// An <code>[await using]</code> declaration creates a constant that holds a resource, which is
+    /// disposed by calling and awaiting its `[Symbol.asyncDispose]()` method when the scope of the
+    /// declaration is exited.
+    ///
+    /// [await using]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/await_using
+    AwaitUsing(VariableList),
 }
 
 impl LexicalDeclaration {
@@ -112,14 +126,30 @@ impl LexicalDeclaration {
     #[must_use]
     pub const fn variable_list(&self) -> &VariableList {
         match self {
-            Self::Const(list) | Self::Let(list) => list,
+            Self::Const(list) | Self::Let(list) | Self::Using(list) | Self::AwaitUsing(list) => {
+                list
+            }
         }
     }
 
-    /// Returns `true` if the declaration is a `const` declaration.
+    /// Returns `true` if the declaration creates immutable bindings.
+    ///
+    /// This is the case of `const`, `using` and `await using` declarations.
     #[must_use]
     pub const fn is_const(&self) -> bool {
-        matches!(self, Self::Const(_))
+        !matches!(self, Self::Let(_))
+    }
+
+    /// Returns `true` if the declaration is a `using` or an `await using` declaration.
+    #[must_use]
+    pub const fn is_using(&self) -> bool {
+        matches!(self, Self::Using(_) | Self::AwaitUsing(_))
+    }
+
+    /// Returns `true` if the declaration is an `await using` declaration.
+    #[must_use]
+    pub const fn is_await_using(&self) -> bool {
+        matches!(self, Self::AwaitUsing(_))
     }
 }
 
@@ -136,6 +166,8 @@ impl ToInternedString for LexicalDeclaration {
             match &self {
                 Self::Let(_) => "let",
                 Self::Const(_) => "const",
+                Self::Using(_) => "using",
+                Self::AwaitUsing(_) => "await using",
             },
             self.variable_list().to_interned_string(interner)
         )
@@ -148,7 +180,9 @@ impl VisitWith for LexicalDeclaration {
         V: Visitor<'a>,
     {
         match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list(vars),
+            Self::Const(vars) | Self::Let(vars) | Self::Using(vars) | Self::AwaitUsing(vars) => {
+                visitor.visit_variable_list(vars)
+            }
         }
     }
 
@@ -157,7 +191,9 @@ impl VisitWith for LexicalDeclaration {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::Const(vars) | Self::Let(vars) => visitor.visit_variable_list_mut(vars),
+            Self::Const(vars) | Self::Let(vars) | Self::Using(vars) | Self::AwaitUsing(vars) => {
+                visitor.visit_variable_list_mut(vars)
+            }
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::join_nodes;
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::Position;
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -22,10 +23,11 @@ use super::Expression;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Functions#Calling_functions
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Call {
     function: Box<Expression>,
     args: Box<[Expression]>,
+    position: Option<Position>,
 }
 
 impl Call {
@@ -36,9 +38,18 @@ impl Call {
         Self {
             function: function.into(),
             args,
+            position: None,
         }
     }
 
+    /// Sets the source position of this call expression.
+    #[inline]
+    #[must_use]
+    pub const fn with_position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
     /// Gets the target function of this call expression.
     #[inline]
     #[must_use]
@@ -52,6 +63,22 @@ impl Call {
     pub const fn args(&self) -> &[Expression] {
         &self.args
     }
+
+    /// Gets the source position of this call expression, if known.
+    ///
+    /// This is used to report the location of the call in stack traces.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+// The position is only metadata and doesn't take part in the equality of two calls.
+impl PartialEq for Call {
+    fn eq(&self, other: &Self) -> bool {
+        self.function == other.function && self.args == other.args
+    }
 }
 
 impl ToInternedString for Call {
@@ -178,6 +205,7 @@ impl VisitWith for SuperCall {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ImportCall {
     arg: Box<Expression>,
+    options: Option<Box<Expression>>,
 }
 
 impl ImportCall {
@@ -188,20 +216,48 @@ impl ImportCall {
     {
         Self {
             arg: Box::new(arg.into()),
+            options: None,
+        }
+    }
+
+    /// Creates a new `ImportCall` AST node with an options argument
+    /// (`import("data.json", { with: { type: "json" } })`).
+    pub fn with_options<A, O>(arg: A, options: O) -> Self
+    where
+        A: Into<Expression>,
+        O: Into<Expression>,
+    {
+        Self {
+            arg: Box::new(arg.into()),
+            options: Some(Box::new(options.into())),
         }
     }
 
-    /// Retrieves the single argument of the import call.
+    /// Retrieves the specifier argument of the import call.
     #[must_use]
     pub const fn argument(&self) -> &Expression {
         &self.arg
     }
+
+    /// Retrieves the options argument of the import call, if present.
+    #[must_use]
+    pub fn options(&self) -> Option<&Expression> {
+        self.options.as_deref()
+    }
 }
 
 impl ToInternedString for ImportCall {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!("import({})", self.arg.to_interned_string(interner))
+        if let Some(options) = &self.options {
+            format!(
+                "import({}, {})",
+                self.arg.to_interned_string(interner),
+                options.to_interned_string(interner)
+            )
+        } else {
+            format!("import({})", self.arg.to_interned_string(interner))
+        }
     }
 }
 
@@ -217,13 +273,21 @@ impl VisitWith for ImportCall {
     where
         V: Visitor<'a>,
     {
-        visitor.visit_expression(&self.arg)
+        try_break!(visitor.visit_expression(&self.arg));
+        if let Some(options) = &self.options {
+            try_break!(visitor.visit_expression(options));
+        }
+        ControlFlow::Continue(())
     }
 
     fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: VisitorMut<'a>,
     {
-        visitor.visit_expression_mut(&mut self.arg)
+        try_break!(visitor.visit_expression_mut(&mut self.arg));
+        if let Some(options) = &mut self.options {
+            try_break!(visitor.visit_expression_mut(options));
+        }
+        ControlFlow::Continue(())
     }
 }
@@ -29,6 +29,7 @@ pub struct Class {
     pub(crate) constructor: Option<Function>,
     pub(crate) elements: Box<[ClassElement]>,
     has_binding_identifier: bool,
+    pub(crate) decorators: Box<[Decorator]>,
 }
 
 impl Class {
@@ -41,6 +42,7 @@ impl Class {
         constructor: Option<Function>,
         elements: Box<[ClassElement]>,
         has_binding_identifier: bool,
+        decorators: Box<[Decorator]>,
     ) -> Self {
         Self {
             name,
@@ -48,6 +50,7 @@ impl Class {
             constructor,
             elements,
             has_binding_identifier,
+            decorators,
         }
     }
 
@@ -85,6 +88,13 @@ impl Class {
     pub const fn has_binding_identifier(&self) -> bool {
         self.has_binding_identifier
     }
+
+    /// Gets the list of decorators applied to the class.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
 }
 
 impl ToIndentedString for Class {
@@ -96,9 +106,10 @@ impl ToIndentedString for Class {
                 true,
             )
         });
+        let decorators = decorators_to_string(&self.decorators, interner);
         if self.elements.is_empty() && self.constructor().is_none() {
             return format!(
-                "class {class_name}{} {{}}",
+                "{decorators}class {class_name}{} {{}}",
                 self.super_ref
                     .as_ref()
                     .map_or_else(String::new, |sup| format!(
@@ -109,7 +120,7 @@ impl ToIndentedString for Class {
         }
         let indentation = "    ".repeat(indent_n + 1);
         let mut buf = format!(
-            "class {class_name}{} {{\n",
+            "{decorators}class {class_name}{} {{\n",
             self.super_ref
                 .as_ref()
                 .map_or_else(String::new, |sup| format!(
@@ -125,8 +136,12 @@ impl ToIndentedString for Class {
             ));
         }
         for element in &*self.elements {
+            let indentation = format!(
+                "{indentation}{}",
+                decorators_to_string(element.decorators(), interner)
+            );
             buf.push_str(&match element {
-                ClassElement::MethodDefinition(name, method) => {
+                ClassElement::MethodDefinition(name, method, _) => {
                     format!(
                         "{indentation}{}{}({}) {}\n",
                         match &method {
@@ -169,7 +184,7 @@ impl ToIndentedString for Class {
                         },
                     )
                 }
-                ClassElement::StaticMethodDefinition(name, method) => {
+                ClassElement::StaticMethodDefinition(name, method, _) => {
                     format!(
                         "{indentation}static {}{}({}) {}\n",
                         match &method {
@@ -212,7 +227,7 @@ impl ToIndentedString for Class {
                         },
                     )
                 }
-                ClassElement::FieldDefinition(name, field) => match field {
+                ClassElement::FieldDefinition(name, field, _) => match field {
                     Some(expr) => {
                         format!(
                             "{indentation}{} = {};\n",
@@ -224,7 +239,7 @@ impl ToIndentedString for Class {
                         format!("{indentation}{};\n", name.to_interned_string(interner),)
                     }
                 },
-                ClassElement::StaticFieldDefinition(name, field) => match field {
+                ClassElement::StaticFieldDefinition(name, field, _) => match field {
                     Some(expr) => {
                         format!(
                             "{indentation}static {} = {};\n",
@@ -239,7 +254,7 @@ impl ToIndentedString for Class {
                         )
                     }
                 },
-                ClassElement::PrivateMethodDefinition(name, method) => {
+                ClassElement::PrivateMethodDefinition(name, method, _) => {
                     format!(
                         "{indentation}{}#{}({}) {}\n",
                         match &method {
@@ -282,7 +297,7 @@ impl ToIndentedString for Class {
                         },
                     )
                 }
-                ClassElement::PrivateStaticMethodDefinition(name, method) => {
+                ClassElement::PrivateStaticMethodDefinition(name, method, _) => {
                     format!(
                         "{indentation}static {}#{}({}) {}\n",
                         match &method {
@@ -325,7 +340,7 @@ impl ToIndentedString for Class {
                         },
                     )
                 }
-                ClassElement::PrivateFieldDefinition(name, field) => match field {
+                ClassElement::PrivateFieldDefinition(name, field, _) => match field {
                     Some(expr) => {
                         format!(
                             "{indentation}#{} = {};\n",
@@ -340,7 +355,7 @@ impl ToIndentedString for Class {
                         )
                     }
                 },
-                ClassElement::PrivateStaticFieldDefinition(name, field) => match field {
+                ClassElement::PrivateStaticFieldDefinition(name, field, _) => match field {
                     Some(expr) => {
                         format!(
                             "{indentation}static #{} = {};\n",
@@ -355,6 +370,66 @@ impl ToIndentedString for Class {
                         )
                     }
                 },
+                ClassElement::AccessorDefinition(name, field, _) => match field {
+                    Some(expr) => {
+                        format!(
+                            "{indentation}accessor {} = {};\n",
+                            name.to_interned_string(interner),
+                            expr.to_no_indent_string(interner, indent_n + 1)
+                        )
+                    }
+                    None => {
+                        format!(
+                            "{indentation}accessor {};\n",
+                            name.to_interned_string(interner),
+                        )
+                    }
+                },
+                ClassElement::StaticAccessorDefinition(name, field, _) => match field {
+                    Some(expr) => {
+                        format!(
+                            "{indentation}static accessor {} = {};\n",
+                            name.to_interned_string(interner),
+                            expr.to_no_indent_string(interner, indent_n + 1)
+                        )
+                    }
+                    None => {
+                        format!(
+                            "{indentation}static accessor {};\n",
+                            name.to_interned_string(interner),
+                        )
+                    }
+                },
+                ClassElement::PrivateAccessorDefinition(name, field, _) => match field {
+                    Some(expr) => {
+                        format!(
+                            "{indentation}accessor #{} = {};\n",
+                            interner.resolve_expect(name.description()),
+                            expr.to_no_indent_string(interner, indent_n + 1)
+                        )
+                    }
+                    None => {
+                        format!(
+                            "{indentation}accessor #{};\n",
+                            interner.resolve_expect(name.description()),
+                        )
+                    }
+                },
+                ClassElement::PrivateStaticAccessorDefinition(name, field, _) => match field {
+                    Some(expr) => {
+                        format!(
+                            "{indentation}static accessor #{} = {};\n",
+                            interner.resolve_expect(name.description()),
+                            expr.to_no_indent_string(interner, indent_n + 1)
+                        )
+                    }
+                    None => {
+                        format!(
+                            "{indentation}static accessor #{};\n",
+                            interner.resolve_expect(name.description()),
+                        )
+                    }
+                },
                 ClassElement::StaticBlock(body) => {
                     format!(
                         "{indentation}static {}\n",
@@ -385,6 +460,9 @@ impl VisitWith for Class {
     where
         V: Visitor<'a>,
     {
+        for decorator in &*self.decorators {
+            try_break!(visitor.visit_decorator(decorator));
+        }
         if let Some(ident) = &self.name {
             try_break!(visitor.visit_identifier(ident));
         }
@@ -404,6 +482,9 @@ impl VisitWith for Class {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in &mut *self.decorators {
+            try_break!(visitor.visit_decorator_mut(decorator));
+        }
         if let Some(ident) = &mut self.name {
             try_break!(visitor.visit_identifier_mut(ident));
         }
@@ -435,46 +516,92 @@ type StaticBlockBody = crate::Script;
 #[derive(Clone, Debug, PartialEq)]
 pub enum ClassElement {
     /// A method definition, including `get` and `set` accessors.
-    MethodDefinition(PropertyName, MethodDefinition),
+    MethodDefinition(PropertyName, MethodDefinition, Box<[Decorator]>),
 
     /// A static method definition, accessible from the class constructor object.
-    StaticMethodDefinition(PropertyName, MethodDefinition),
+    StaticMethodDefinition(PropertyName, MethodDefinition, Box<[Decorator]>),
 
     /// A field definition.
-    FieldDefinition(PropertyName, Option<Expression>),
+    FieldDefinition(PropertyName, Option<Expression>, Box<[Decorator]>),
 
     /// A static field definition, accessible from the class constructor object
-    StaticFieldDefinition(PropertyName, Option<Expression>),
+    StaticFieldDefinition(PropertyName, Option<Expression>, Box<[Decorator]>),
 
     /// A private method definition, only accessible inside the class declaration.
-    PrivateMethodDefinition(PrivateName, MethodDefinition),
+    PrivateMethodDefinition(PrivateName, MethodDefinition, Box<[Decorator]>),
 
     /// A private static method definition, only accessible from static methods and fields inside
     /// the class declaration.
-    PrivateStaticMethodDefinition(PrivateName, MethodDefinition),
+    PrivateStaticMethodDefinition(PrivateName, MethodDefinition, Box<[Decorator]>),
 
     /// A private field definition, only accessible inside the class declaration.
-    PrivateFieldDefinition(PrivateName, Option<Expression>),
+    PrivateFieldDefinition(PrivateName, Option<Expression>, Box<[Decorator]>),
 
     /// A private static field definition, only accessible from static methods and fields inside the
     /// class declaration.
-    PrivateStaticFieldDefinition(PrivateName, Option<Expression>),
+    PrivateStaticFieldDefinition(PrivateName, Option<Expression>, Box<[Decorator]>),
+
+    /// An auto-accessor definition, which defines a getter and a setter backed by a private
+    /// storage field.
+    AccessorDefinition(PropertyName, Option<Expression>, Box<[Decorator]>),
+
+    /// A static auto-accessor definition, accessible from the class constructor object.
+    StaticAccessorDefinition(PropertyName, Option<Expression>, Box<[Decorator]>),
+
+    /// A private auto-accessor definition, only accessible inside the class declaration.
+    PrivateAccessorDefinition(PrivateName, Option<Expression>, Box<[Decorator]>),
+
+    /// A private static auto-accessor definition, only accessible from static methods and fields
+    /// inside the class declaration.
+    PrivateStaticAccessorDefinition(PrivateName, Option<Expression>, Box<[Decorator]>),
 
     /// A static block, where a class can have initialization logic for its static fields.
     StaticBlock(StaticBlockBody),
 }
 
+impl ClassElement {
+    /// Gets the list of decorators applied to the element.
+    #[must_use]
+    pub fn decorators(&self) -> &[Decorator] {
+        match self {
+            Self::MethodDefinition(_, _, decorators)
+            | Self::StaticMethodDefinition(_, _, decorators)
+            | Self::PrivateMethodDefinition(_, _, decorators)
+            | Self::PrivateStaticMethodDefinition(_, _, decorators)
+            | Self::FieldDefinition(_, _, decorators)
+            | Self::StaticFieldDefinition(_, _, decorators)
+            | Self::PrivateFieldDefinition(_, _, decorators)
+            | Self::PrivateStaticFieldDefinition(_, _, decorators)
+            | Self::AccessorDefinition(_, _, decorators)
+            | Self::StaticAccessorDefinition(_, _, decorators)
+            | Self::PrivateAccessorDefinition(_, _, decorators)
+            | Self::PrivateStaticAccessorDefinition(_, _, decorators) => decorators,
+            Self::StaticBlock(_) => &[],
+        }
+    }
+}
+
 impl VisitWith for ClassElement {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: Visitor<'a>,
     {
         match self {
-            Self::MethodDefinition(pn, md) | Self::StaticMethodDefinition(pn, md) => {
+            Self::MethodDefinition(pn, md, decorators)
+            | Self::StaticMethodDefinition(pn, md, decorators) => {
+                for decorator in &**decorators {
+                    try_break!(visitor.visit_decorator(decorator));
+                }
                 try_break!(visitor.visit_property_name(pn));
                 visitor.visit_method_definition(md)
             }
-            Self::FieldDefinition(pn, maybe_expr) | Self::StaticFieldDefinition(pn, maybe_expr) => {
+            Self::FieldDefinition(pn, maybe_expr, decorators)
+            | Self::StaticFieldDefinition(pn, maybe_expr, decorators)
+            | Self::AccessorDefinition(pn, maybe_expr, decorators)
+            | Self::StaticAccessorDefinition(pn, maybe_expr, decorators) => {
+                for decorator in &**decorators {
+                    try_break!(visitor.visit_decorator(decorator));
+                }
                 try_break!(visitor.visit_property_name(pn));
                 if let Some(expr) = maybe_expr {
                     visitor.visit_expression(expr)
@@ -482,13 +609,21 @@ impl VisitWith for ClassElement {
                     ControlFlow::Continue(())
                 }
             }
-            Self::PrivateMethodDefinition(name, md)
-            | Self::PrivateStaticMethodDefinition(name, md) => {
+            Self::PrivateMethodDefinition(name, md, decorators)
+            | Self::PrivateStaticMethodDefinition(name, md, decorators) => {
+                for decorator in &**decorators {
+                    try_break!(visitor.visit_decorator(decorator));
+                }
                 try_break!(visitor.visit_private_name(name));
                 visitor.visit_method_definition(md)
             }
-            Self::PrivateFieldDefinition(name, maybe_expr)
-            | Self::PrivateStaticFieldDefinition(name, maybe_expr) => {
+            Self::PrivateFieldDefinition(name, maybe_expr, decorators)
+            | Self::PrivateStaticFieldDefinition(name, maybe_expr, decorators)
+            | Self::PrivateAccessorDefinition(name, maybe_expr, decorators)
+            | Self::PrivateStaticAccessorDefinition(name, maybe_expr, decorators) => {
+                for decorator in &**decorators {
+                    try_break!(visitor.visit_decorator(decorator));
+                }
                 try_break!(visitor.visit_private_name(name));
                 if let Some(expr) = maybe_expr {
                     visitor.visit_expression(expr)
@@ -505,11 +640,21 @@ impl VisitWith for ClassElement {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::MethodDefinition(pn, md) | Self::StaticMethodDefinition(pn, md) => {
+            Self::MethodDefinition(pn, md, decorators)
+            | Self::StaticMethodDefinition(pn, md, decorators) => {
+                for decorator in &mut **decorators {
+                    try_break!(visitor.visit_decorator_mut(decorator));
+                }
                 try_break!(visitor.visit_property_name_mut(pn));
                 visitor.visit_method_definition_mut(md)
             }
-            Self::FieldDefinition(pn, maybe_expr) | Self::StaticFieldDefinition(pn, maybe_expr) => {
+            Self::FieldDefinition(pn, maybe_expr, decorators)
+            | Self::StaticFieldDefinition(pn, maybe_expr, decorators)
+            | Self::AccessorDefinition(pn, maybe_expr, decorators)
+            | Self::StaticAccessorDefinition(pn, maybe_expr, decorators) => {
+                for decorator in &mut **decorators {
+                    try_break!(visitor.visit_decorator_mut(decorator));
+                }
                 try_break!(visitor.visit_property_name_mut(pn));
                 if let Some(expr) = maybe_expr {
                     visitor.visit_expression_mut(expr)
@@ -517,13 +662,21 @@ impl VisitWith for ClassElement {
                     ControlFlow::Continue(())
                 }
             }
-            Self::PrivateMethodDefinition(name, md)
-            | Self::PrivateStaticMethodDefinition(name, md) => {
+            Self::PrivateMethodDefinition(name, md, decorators)
+            | Self::PrivateStaticMethodDefinition(name, md, decorators) => {
+                for decorator in &mut **decorators {
+                    try_break!(visitor.visit_decorator_mut(decorator));
+                }
                 try_break!(visitor.visit_private_name_mut(name));
                 visitor.visit_method_definition_mut(md)
             }
-            Self::PrivateFieldDefinition(name, maybe_expr)
-            | Self::PrivateStaticFieldDefinition(name, maybe_expr) => {
+            Self::PrivateFieldDefinition(name, maybe_expr, decorators)
+            | Self::PrivateStaticFieldDefinition(name, maybe_expr, decorators)
+            | Self::PrivateAccessorDefinition(name, maybe_expr, decorators)
+            | Self::PrivateStaticAccessorDefinition(name, maybe_expr, decorators) => {
+                for decorator in &mut **decorators {
+                    try_break!(visitor.visit_decorator_mut(decorator));
+                }
                 try_break!(visitor.visit_private_name_mut(name));
                 if let Some(expr) = maybe_expr {
                     visitor.visit_expression_mut(expr)
@@ -578,3 +731,72 @@ impl VisitWith for PrivateName {
         visitor.visit_sym_mut(&mut self.description)
     }
 }
+
+/// A decorator applied to a [`Class`] or to one of its elements, as defined by the [spec].
+///
+/// A decorator is a function called during class definition, which can replace or extend the
+/// decorated value.
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-Decorator
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decorator {
+    expression: Expression,
+}
+
+impl Decorator {
+    /// Creates a new decorator.
+    #[inline]
+    #[must_use]
+    pub const fn new(expression: Expression) -> Self {
+        Self { expression }
+    }
+
+    /// Gets the expression that evaluates to the decorator function.
+    #[inline]
+    #[must_use]
+    pub const fn expression(&self) -> &Expression {
+        &self.expression
+    }
+}
+
+impl ToInternedString for Decorator {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        match &self.expression {
+            Expression::Identifier(_)
+            | Expression::PropertyAccess(_)
+            | Expression::Call(_)
+            | Expression::Parenthesized(_) => {
+                format!("@{}", self.expression.to_interned_string(interner))
+            }
+            expr => format!("@({})", expr.to_interned_string(interner)),
+        }
+    }
+}
+
+impl VisitWith for Decorator {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_expression(&self.expression)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        visitor.visit_expression_mut(&mut self.expression)
+    }
+}
+
+/// Converts a list of decorators to a string, followed by a space if not empty.
+fn decorators_to_string(decorators: &[Decorator], interner: &Interner) -> String {
+    let mut buf = String::new();
+    for decorator in decorators {
+        buf.push_str(&decorator.to_interned_string(interner));
+        buf.push(' ');
+    }
+    buf
+}
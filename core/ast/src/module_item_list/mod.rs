@@ -14,14 +14,14 @@ use rustc_hash::{FxHashSet, FxHasher};
 use crate::{
     declaration::{
         ExportDeclaration, ExportEntry, ExportSpecifier, ImportDeclaration, ImportEntry,
-        ImportKind, ImportName, IndirectExportEntry, LocalExportEntry, ModuleSpecifier,
+        ImportKind, ImportName, IndirectExportEntry, LocalExportEntry, ModuleRequest,
         ReExportImportName, ReExportKind,
     },
     expression::Identifier,
     operations::{bound_names, BoundNamesVisitor},
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
-    StatementListItem,
+    Declaration, StatementListItem,
 };
 
 /// Module item list AST node.
@@ -46,6 +46,26 @@ impl ModuleItemList {
         &self.items
     }
 
+    /// Returns `true` if the list directly contains a `using` or an `await using` declaration.
+    ///
+    /// Declarations nested in other statements of the list are not taken into account.
+    #[must_use]
+    pub fn contains_using_declaration(&self) -> bool {
+        self.items.iter().any(|item| {
+            matches!(item, ModuleItem::StatementListItem(StatementListItem::Declaration(Declaration::Lexical(decl))) if decl.is_using())
+        })
+    }
+
+    /// Returns `true` if the list directly contains an `await using` declaration.
+    ///
+    /// Declarations nested in other statements of the list are not taken into account.
+    #[must_use]
+    pub fn contains_await_using_declaration(&self) -> bool {
+        self.items.iter().any(|item| {
+            matches!(item, ModuleItem::StatementListItem(StatementListItem::Declaration(Declaration::Lexical(decl))) if decl.is_await_using())
+        })
+    }
+
     /// Abstract operation [`ExportedNames`][spec].
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportednames
@@ -206,9 +226,11 @@ impl ModuleItemList {
     /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-modulerequests
     #[inline]
     #[must_use]
-    pub fn requests(&self) -> IndexSet<Sym, BuildHasherDefault<FxHasher>> {
+    pub fn requests(&self) -> IndexSet<ModuleRequest, BuildHasherDefault<FxHasher>> {
         #[derive(Debug)]
-        struct RequestsVisitor<'vec>(&'vec mut IndexSet<Sym, BuildHasherDefault<FxHasher>>);
+        struct RequestsVisitor<'vec>(
+            &'vec mut IndexSet<ModuleRequest, BuildHasherDefault<FxHasher>>,
+        );
 
         impl<'ast> Visitor<'ast> for RequestsVisitor<'_> {
             type BreakTy = Infallible;
@@ -219,11 +241,26 @@ impl ModuleItemList {
             ) -> ControlFlow<Self::BreakTy> {
                 ControlFlow::Continue(())
             }
-            fn visit_module_specifier(
+            fn visit_import_declaration(
                 &mut self,
-                node: &'ast ModuleSpecifier,
+                node: &'ast ImportDeclaration,
+            ) -> ControlFlow<Self::BreakTy> {
+                self.0.insert(node.module_request());
+                ControlFlow::Continue(())
+            }
+            fn visit_export_declaration(
+                &mut self,
+                node: &'ast ExportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
-                self.0.insert(node.sym());
+                if let ExportDeclaration::ReExport {
+                    specifier,
+                    attributes,
+                    ..
+                } = node
+                {
+                    self.0
+                        .insert(ModuleRequest::new(specifier.sym(), attributes));
+                }
                 ControlFlow::Continue(())
             }
         }
@@ -262,11 +299,11 @@ impl ModuleItemList {
                 &mut self,
                 node: &'ast ImportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
-                let module = node.specifier().sym();
+                let module = node.module_request();
 
                 if let Some(default) = node.default() {
                     self.0.push(ImportEntry::new(
-                        module,
+                        module.clone(),
                         ImportName::Name(Sym::DEFAULT),
                         default,
                     ));
@@ -275,13 +312,16 @@ impl ModuleItemList {
                 match node.kind() {
                     ImportKind::DefaultOrUnnamed => {}
                     ImportKind::Namespaced { binding } => {
-                        self.0
-                            .push(ImportEntry::new(module, ImportName::Namespace, *binding));
+                        self.0.push(ImportEntry::new(
+                            module.clone(),
+                            ImportName::Namespace,
+                            *binding,
+                        ));
                     }
                     ImportKind::Named { names } => {
                         for name in &**names {
                             self.0.push(ImportEntry::new(
-                                module,
+                                module.clone(),
                                 ImportName::Name(name.export_name()),
                                 name.binding(),
                             ));
@@ -328,15 +368,19 @@ impl ModuleItemList {
                 node: &'ast ExportDeclaration,
             ) -> ControlFlow<Self::BreakTy> {
                 let name = match node {
-                    ExportDeclaration::ReExport { kind, specifier } => {
-                        let module = specifier.sym();
+                    ExportDeclaration::ReExport {
+                        kind,
+                        specifier,
+                        attributes,
+                    } => {
+                        let module = ModuleRequest::new(specifier.sym(), attributes);
 
                         match kind {
                             ReExportKind::Namespaced { name } => {
                                 if let Some(name) = *name {
                                     self.0.push(
                                         IndirectExportEntry::new(
-                                            module,
+                                            module.clone(),
                                             ReExportImportName::Star,
                                             name,
                                         )
@@ -344,7 +388,7 @@ impl ModuleItemList {
                                     );
                                 } else {
                                     self.0.push(ExportEntry::StarReExport {
-                                        module_request: module,
+                                        module_request: module.clone(),
                                     });
                                 }
                             }
@@ -352,7 +396,7 @@ impl ModuleItemList {
                                 for name in &**names {
                                     self.0.push(
                                         IndirectExportEntry::new(
-                                            module,
+                                            module.clone(),
                                             ReExportImportName::Name(name.private_name()),
                                             name.alias(),
                                         )
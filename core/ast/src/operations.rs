@@ -117,11 +117,16 @@ where
 
         // `ComputedPropertyContains`: https://tc39.es/ecma262/#sec-static-semantics-computedpropertycontains
         fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
+            for decorator in node.decorators() {
+                try_break!(self.visit_decorator(decorator));
+            }
             match node {
-                ClassElement::MethodDefinition(name, _)
-                | ClassElement::StaticMethodDefinition(name, _)
-                | ClassElement::FieldDefinition(name, _)
-                | ClassElement::StaticFieldDefinition(name, _) => name.visit_with(self),
+                ClassElement::MethodDefinition(name, _, _)
+                | ClassElement::StaticMethodDefinition(name, _, _)
+                | ClassElement::FieldDefinition(name, _, _)
+                | ClassElement::StaticFieldDefinition(name, _, _)
+                | ClassElement::AccessorDefinition(name, _, _)
+                | ClassElement::StaticAccessorDefinition(name, _, _) => name.visit_with(self),
                 _ => ControlFlow::Continue(()),
             }
         }
@@ -211,6 +216,31 @@ where
             node.visit_with(self)
         }
 
+        fn visit_lexical_declaration(
+            &mut self,
+            node: &'ast LexicalDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            // An `await using` declaration performs an implicit `Await` when its scope exits.
+            if node.is_await_using() && self.0 == ContainsSymbol::AwaitExpression {
+                return ControlFlow::Break(());
+            }
+
+            node.visit_with(self)
+        }
+
+        fn visit_iterable_loop_initializer(
+            &mut self,
+            node: &'ast IterableLoopInitializer,
+        ) -> ControlFlow<Self::BreakTy> {
+            if matches!(node, IterableLoopInitializer::AwaitUsing(_))
+                && self.0 == ContainsSymbol::AwaitExpression
+            {
+                return ControlFlow::Break(());
+            }
+
+            node.visit_with(self)
+        }
+
         fn visit_expression(&mut self, node: &'ast Expression) -> ControlFlow<Self::BreakTy> {
             if node == &Expression::This && self.0 == ContainsSymbol::This {
                 return ControlFlow::Break(());
@@ -268,8 +298,13 @@ where
 
         fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
             match node {
-                ClassElement::MethodDefinition(name, _)
-                | ClassElement::StaticMethodDefinition(name, _) => return name.visit_with(self),
+                ClassElement::MethodDefinition(name, _, decorators)
+                | ClassElement::StaticMethodDefinition(name, _, decorators) => {
+                    for decorator in &**decorators {
+                        try_break!(self.visit_decorator(decorator));
+                    }
+                    return name.visit_with(self);
+                }
                 _ => {}
             }
             node.visit_with(self)
@@ -899,6 +934,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
     type BreakTy = ();
 
     fn visit_class(&mut self, node: &'ast Class) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators() {
+            try_break!(self.visit(decorator));
+        }
         if let Some(node) = node.super_ref() {
             try_break!(self.visit(node));
         }
@@ -906,10 +944,12 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         let mut names = self.0.clone();
         for element in node.elements() {
             match element {
-                ClassElement::PrivateMethodDefinition(name, _)
-                | ClassElement::PrivateStaticMethodDefinition(name, _)
-                | ClassElement::PrivateFieldDefinition(name, _)
-                | ClassElement::PrivateStaticFieldDefinition(name, _) => {
+                ClassElement::PrivateMethodDefinition(name, _, _)
+                | ClassElement::PrivateStaticMethodDefinition(name, _, _)
+                | ClassElement::PrivateFieldDefinition(name, _, _)
+                | ClassElement::PrivateStaticFieldDefinition(name, _, _)
+                | ClassElement::PrivateAccessorDefinition(name, _, _)
+                | ClassElement::PrivateStaticAccessorDefinition(name, _, _) => {
                     names.push(name.description());
                 }
                 _ => {}
@@ -923,25 +963,32 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         }
 
         for element in node.elements() {
+            for decorator in element.decorators() {
+                try_break!(visitor.visit(decorator));
+            }
             match element {
-                ClassElement::MethodDefinition(name, method)
-                | ClassElement::StaticMethodDefinition(name, method) => {
+                ClassElement::MethodDefinition(name, method, _)
+                | ClassElement::StaticMethodDefinition(name, method, _) => {
                     try_break!(visitor.visit(name));
                     try_break!(visitor.visit(method));
                 }
-                ClassElement::FieldDefinition(name, expression)
-                | ClassElement::StaticFieldDefinition(name, expression) => {
+                ClassElement::FieldDefinition(name, expression, _)
+                | ClassElement::StaticFieldDefinition(name, expression, _)
+                | ClassElement::AccessorDefinition(name, expression, _)
+                | ClassElement::StaticAccessorDefinition(name, expression, _) => {
                     try_break!(visitor.visit(name));
                     if let Some(expression) = expression {
                         try_break!(visitor.visit(expression));
                     }
                 }
-                ClassElement::PrivateMethodDefinition(_, method)
-                | ClassElement::PrivateStaticMethodDefinition(_, method) => {
+                ClassElement::PrivateMethodDefinition(_, method, _)
+                | ClassElement::PrivateStaticMethodDefinition(_, method, _) => {
                     try_break!(visitor.visit(method));
                 }
-                ClassElement::PrivateFieldDefinition(_, expression)
-                | ClassElement::PrivateStaticFieldDefinition(_, expression) => {
+                ClassElement::PrivateFieldDefinition(_, expression, _)
+                | ClassElement::PrivateStaticFieldDefinition(_, expression, _)
+                | ClassElement::PrivateAccessorDefinition(_, expression, _)
+                | ClassElement::PrivateStaticAccessorDefinition(_, expression, _) => {
                     if let Some(expression) = expression {
                         try_break!(visitor.visit(expression));
                     }
@@ -2058,7 +2105,10 @@ impl<'ast> Visitor<'ast> for AnnexBFunctionDeclarationNamesVisitor<'_> {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
-        if let IterableLoopInitializer::Const(node) = node.initializer() {
+        if let IterableLoopInitializer::Const(node)
+        | IterableLoopInitializer::Using(node)
+        | IterableLoopInitializer::AwaitUsing(node) = node.initializer()
+        {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
@@ -2076,7 +2126,10 @@ impl<'ast> Visitor<'ast> for AnnexBFunctionDeclarationNamesVisitor<'_> {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
-        if let IterableLoopInitializer::Const(node) = node.initializer() {
+        if let IterableLoopInitializer::Const(node)
+        | IterableLoopInitializer::Using(node)
+        | IterableLoopInitializer::AwaitUsing(node) = node.initializer()
+        {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
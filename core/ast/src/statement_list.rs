@@ -5,6 +5,7 @@ use crate::{
     statement::Statement,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    Position,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
@@ -96,10 +97,11 @@ impl VisitWith for StatementListItem {
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default)]
 pub struct StatementList {
     statements: Box<[StatementListItem]>,
     strict: bool,
+    positions: Box<[Position]>,
 }
 
 impl StatementList {
@@ -112,9 +114,31 @@ impl StatementList {
         Self {
             statements: statements.into(),
             strict,
+            positions: Box::default(),
         }
     }
 
+    /// Sets the source positions of the statements of this list.
+    ///
+    /// `positions` must contain the start position of each statement in the list, in order.
+    #[must_use]
+    pub fn with_positions<P>(mut self, positions: P) -> Self
+    where
+        P: Into<Box<[Position]>>,
+    {
+        let positions = positions.into();
+        debug_assert_eq!(positions.len(), self.statements.len());
+        self.positions = positions;
+        self
+    }
+
+    /// Gets the source position of the statement at `index`, if known.
+    #[inline]
+    #[must_use]
+    pub fn position(&self, index: usize) -> Option<Position> {
+        self.positions.get(index).copied()
+    }
+
     /// Gets the list of statements.
     #[inline]
     #[must_use]
@@ -128,6 +152,26 @@ impl StatementList {
     pub const fn strict(&self) -> bool {
         self.strict
     }
+
+    /// Returns `true` if the list directly contains a `using` or an `await using` declaration.
+    ///
+    /// Declarations nested in other statements of the list are not taken into account.
+    #[must_use]
+    pub fn contains_using_declaration(&self) -> bool {
+        self.statements.iter().any(|item| {
+            matches!(item, StatementListItem::Declaration(Declaration::Lexical(decl)) if decl.is_using())
+        })
+    }
+
+    /// Returns `true` if the list directly contains an `await using` declaration.
+    ///
+    /// Declarations nested in other statements of the list are not taken into account.
+    #[must_use]
+    pub fn contains_await_using_declaration(&self) -> bool {
+        self.statements.iter().any(|item| {
+            matches!(item, StatementListItem::Declaration(Declaration::Lexical(decl)) if decl.is_await_using())
+        })
+    }
 }
 
 impl From<Box<[StatementListItem]>> for StatementList {
@@ -136,6 +180,7 @@ impl From<Box<[StatementListItem]>> for StatementList {
         Self {
             statements: stm,
             strict: false,
+            positions: Box::default(),
         }
     }
 }
@@ -146,10 +191,18 @@ impl From<Vec<StatementListItem>> for StatementList {
         Self {
             statements: stm.into(),
             strict: false,
+            positions: Box::default(),
         }
     }
 }
 
+// The positions are only metadata and don't take part in the equality of two lists.
+impl PartialEq for StatementList {
+    fn eq(&self, other: &Self) -> bool {
+        self.statements == other.statements && self.strict == other.strict
+    }
+}
+
 impl Deref for StatementList {
     type Target = [StatementListItem];
 
@@ -200,6 +253,7 @@ impl<'a> arbitrary::Arbitrary<'a> for StatementList {
         Ok(Self {
             statements: u.arbitrary()?,
             strict: false, // disable strictness; this is *not* in source data
+            positions: Box::default(),
         })
     }
 }
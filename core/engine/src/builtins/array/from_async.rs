@@ -0,0 +1,554 @@
+//! This module implements `Array.fromAsync`.
+//!
+//! The specification defines `Array.fromAsync` as an async abstract closure, which this module
+//! implements as a state machine that resumes itself from promise reactions on every `Await`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::utf16;
+
+use crate::{
+    builtins::{
+        iterable::{AsyncFromSyncIterator, IteratorHint, IteratorRecord, IteratorResult},
+        promise::PromiseCapability,
+        Number, Promise,
+    },
+    error::JsNativeError,
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsObject},
+    symbol::JsSymbol,
+    Context, JsArgs, JsError, JsResult, JsValue,
+};
+
+use super::Array;
+
+/// The source of the elements collected by `Array.fromAsync`.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum Source {
+    /// An async iterator, or a sync iterator wrapped by `CreateAsyncFromSyncIterator`.
+    Iterator(IteratorRecord),
+
+    /// An array-like object.
+    ArrayLike { array_like: JsObject, len: u64 },
+}
+
+/// The state captured by the `fromAsyncClosure` abstract closure.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct FromAsyncState {
+    /// The capability of the promise returned by `Array.fromAsync`.
+    capability: PromiseCapability,
+
+    /// The array being populated.
+    a: JsObject,
+
+    /// The index of the next element.
+    k: u64,
+
+    /// The `mapfn` argument, if `mapping` is true.
+    mapfn: Option<JsObject>,
+
+    /// The `thisArg` argument.
+    this_arg: JsValue,
+
+    /// The source of the elements.
+    source: Source,
+}
+
+/// A step of `fromAsyncClosure` that runs after an `Await`.
+///
+/// The second argument is the settled value of the awaited promise.
+type Continuation = fn(FromAsyncState, JsValue, &mut Context) -> JsResult<()>;
+
+impl Array {
+    /// `Array.fromAsync ( asyncItems [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// The `Array.fromAsync()` static method creates a new, shallow-copied Array instance from an
+    /// async iterable, iterable, or array-like object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+    #[allow(clippy::unnecessary_wraps)]
+    pub(crate) fn from_async(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        // 3. Let fromAsyncClosure be a new Abstract Closure with no parameters that captures C,
+        //    mapfn, and thisArg and performs the following steps when called:
+        // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+        if let Err(err) = from_async_start(this, args, &capability, context) {
+            reject(&capability, &err, context);
+        }
+
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(capability.promise().clone().into())
+    }
+}
+
+/// Runs `fromAsyncClosure` until its first `Await`.
+#[allow(clippy::similar_names)]
+fn from_async_start(
+    this: &JsValue,
+    args: &[JsValue],
+    capability: &PromiseCapability,
+    context: &mut Context,
+) -> JsResult<()> {
+    let async_items = args.get_or_undefined(0);
+    let mapfn = args.get_or_undefined(1);
+    let this_arg = args.get_or_undefined(2).clone();
+
+    // a. If mapfn is undefined, let mapping be false.
+    // b. Else,
+    //     i. If IsCallable(mapfn) is false, throw a TypeError exception.
+    //     ii. Let mapping be true.
+    let mapfn = match mapfn {
+        JsValue::Undefined => None,
+        JsValue::Object(o) if o.is_callable() => Some(o.clone()),
+        _ => {
+            return Err(JsNativeError::typ()
+                .with_message(format!("`{}` is not callable", mapfn.type_of()))
+                .into())
+        }
+    };
+
+    // c. Let usingAsyncIterator be ? GetMethod(asyncItems, @@asyncIterator).
+    let using_async_iterator = async_items.get_method(JsSymbol::async_iterator(), context)?;
+
+    // d. If usingAsyncIterator is undefined, then
+    //     i. Let usingSyncIterator be ? GetMethod(asyncItems, @@iterator).
+    let using_sync_iterator = if using_async_iterator.is_none() {
+        async_items.get_method(JsSymbol::iterator(), context)?
+    } else {
+        None
+    };
+
+    // e. Let iteratorRecord be undefined.
+    // f. If usingAsyncIterator is not undefined, then
+    //     i. Set iteratorRecord to ? GetIterator(asyncItems, async, usingAsyncIterator).
+    // g. Else if usingSyncIterator is not undefined, then
+    //     i. Set iteratorRecord to ? CreateAsyncFromSyncIterator(GetIterator(asyncItems, sync, usingSyncIterator)).
+    let iterator_record = if let Some(method) = using_async_iterator {
+        Some(async_items.get_iterator(context, Some(IteratorHint::Async), Some(method))?)
+    } else if let Some(method) = using_sync_iterator {
+        let sync_iterator_record =
+            async_items.get_iterator(context, Some(IteratorHint::Sync), Some(method))?;
+        Some(AsyncFromSyncIterator::create(sync_iterator_record, context))
+    } else {
+        None
+    };
+
+    // h. If iteratorRecord is not undefined, then
+    if let Some(iterator_record) = iterator_record {
+        // i. If IsConstructor(C) is true, then
+        //     1. Let A be ? Construct(C).
+        // ii. Else,
+        //     1. Let A be ! ArrayCreate(0).
+        let a = match this.as_constructor() {
+            Some(constructor) => constructor.construct(&[], None, context)?,
+            _ => {
+                Array::array_create(0, None, context).expect("creating an empty array cannot fail")
+            }
+        };
+
+        // iii. Let k be 0.
+        let state = FromAsyncState {
+            capability: capability.clone(),
+            a,
+            k: 0,
+            mapfn,
+            this_arg,
+            source: Source::Iterator(iterator_record),
+        };
+
+        // iv. Repeat,
+        return iterator_step(&state, context);
+    }
+
+    // i. Else,
+    //     i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable so assume it is an
+    //        array-like object.
+    //     ii. Let arrayLike be ! ToObject(asyncItems).
+    let array_like = async_items
+        .to_object(context)
+        .expect("`GetMethod` already checked that `asyncItems` is coercible to an object");
+
+    //     iii. Let len be ? LengthOfArrayLike(arrayLike).
+    let len = array_like.length_of_array_like(context)?;
+
+    //     iv. If IsConstructor(C) is true, then
+    //         1. Let A be ? Construct(C, « 𝔽(len) »).
+    //     v. Else,
+    //         1. Let A be ? ArrayCreate(len).
+    let a = match this.as_constructor() {
+        Some(constructor) => constructor.construct(&[len.into()], None, context)?,
+        _ => Array::array_create(len, None, context)?,
+    };
+
+    //     vi. Let k be 0.
+    let state = FromAsyncState {
+        capability: capability.clone(),
+        a,
+        k: 0,
+        mapfn,
+        this_arg,
+        source: Source::ArrayLike { array_like, len },
+    };
+
+    //     vii. Repeat, while k < len,
+    array_like_step(&state, context)
+}
+
+/// Runs an iteration of step `h.iv` of `fromAsyncClosure`, up to the `Await` of the next
+/// iterator result.
+fn iterator_step(state: &FromAsyncState, context: &mut Context) -> JsResult<()> {
+    let Source::Iterator(iterator_record) = &state.source else {
+        unreachable!("must be called with an iterator source");
+    };
+
+    // 1. If k ≥ 2^53 - 1, then
+    if state.k >= Number::MAX_SAFE_INTEGER as u64 {
+        // a. Let error be ThrowCompletion(a newly created TypeError object).
+        let error = JsNativeError::typ()
+            .with_message("Array.fromAsync: reached the maximum array length")
+            .into();
+
+        // b. Return ? AsyncIteratorClose(iteratorRecord, error).
+        return async_iterator_close(state, error, context);
+    }
+
+    // 2. Let Pk be ! ToString(𝔽(k)).
+    // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+    let next_result = iterator_record.next_method().call(
+        &iterator_record.iterator().clone().into(),
+        &[],
+        context,
+    )?;
+
+    // 4. Set nextResult to ? Await(nextResult).
+    await_value(
+        next_result,
+        state,
+        iterator_next_result,
+        |state, reason, context| {
+            reject(&state.capability, &JsError::from_opaque(reason), context);
+            Ok(())
+        },
+        context,
+    )
+}
+
+/// Runs the steps of `h.iv` of `fromAsyncClosure` after awaiting the next iterator result.
+fn iterator_next_result(
+    state: FromAsyncState,
+    next_result: JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 5. If nextResult is not an Object, throw a TypeError exception.
+    let next_result = IteratorResult::from_value(next_result)?;
+
+    // 6. Let done be ? IteratorComplete(nextResult).
+    // 7. If done is true,
+    if next_result.complete(context)? {
+        // a. Perform ? Set(A, "length", 𝔽(k), true).
+        state.a.set(utf16!("length"), state.k, true, context)?;
+
+        // b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+        resolve(&state.capability, state.a.clone().into(), context);
+        return Ok(());
+    }
+
+    // 8. Let nextValue be ? IteratorValue(nextResult).
+    let next_value = next_result.value(context)?;
+
+    // 9. If mapping is true, then
+    if let Some(mapfn) = &state.mapfn {
+        // a. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+        // b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+        let mapped_value = match mapfn.call(&state.this_arg, &[next_value, state.k.into()], context)
+        {
+            Ok(value) => value,
+            Err(err) => return async_iterator_close(&state, err, context),
+        };
+
+        // c. Set mappedValue to Await(mappedValue).
+        // d. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+        if let Err(err) = await_value(
+            mapped_value,
+            &state,
+            iterator_define,
+            |state, reason, context| {
+                async_iterator_close(&state, JsError::from_opaque(reason), context)
+            },
+            context,
+        ) {
+            return async_iterator_close(&state, err, context);
+        }
+
+        return Ok(());
+    }
+
+    // 10. Else, let mappedValue be nextValue.
+    iterator_define(state, next_value, context)
+}
+
+/// Runs the steps `h.iv.11` to `h.iv.13` of `fromAsyncClosure`, defining the mapped value in the
+/// array and continuing with the next iteration.
+fn iterator_define(
+    mut state: FromAsyncState,
+    mapped_value: JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 11. Let defineStatus be CreateDataPropertyOrThrow(A, Pk, mappedValue).
+    // 12. If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+    if let Err(err) = state
+        .a
+        .create_data_property_or_throw(state.k, mapped_value, context)
+    {
+        return async_iterator_close(&state, err, context);
+    }
+
+    // 13. Set k to k + 1.
+    state.k += 1;
+
+    iterator_step(&state, context)
+}
+
+/// Runs an iteration of step `i.vii` of `fromAsyncClosure`, up to the `Await` of the next element.
+fn array_like_step(state: &FromAsyncState, context: &mut Context) -> JsResult<()> {
+    let Source::ArrayLike { array_like, len } = &state.source else {
+        unreachable!("must be called with an array-like source");
+    };
+
+    // vii. Repeat, while k < len,
+    if state.k >= *len {
+        // viii. Perform ? Set(A, "length", 𝔽(len), true).
+        state.a.set(utf16!("length"), *len, true, context)?;
+
+        // ix. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+        resolve(&state.capability, state.a.clone().into(), context);
+        return Ok(());
+    }
+
+    // 1. Let Pk be ! ToString(𝔽(k)).
+    // 2. Let kValue be ? Get(arrayLike, Pk).
+    let k_value = array_like.get(state.k, context)?;
+
+    // 3. Set kValue to ? Await(kValue).
+    await_value(
+        k_value,
+        state,
+        |state, k_value, context| {
+            // 4. If mapping is true, then
+            if let Some(mapfn) = &state.mapfn {
+                // a. Let mappedValue be ? Call(mapfn, thisArg, « kValue, 𝔽(k) »).
+                let mapped_value =
+                    mapfn.call(&state.this_arg, &[k_value, state.k.into()], context)?;
+
+                // b. Set mappedValue to ? Await(mappedValue).
+                return await_value(
+                    mapped_value,
+                    &state,
+                    array_like_define,
+                    |state, reason, context| {
+                        reject(&state.capability, &JsError::from_opaque(reason), context);
+                        Ok(())
+                    },
+                    context,
+                );
+            }
+
+            // 5. Else, let mappedValue be kValue.
+            array_like_define(state, k_value, context)
+        },
+        |state, reason, context| {
+            reject(&state.capability, &JsError::from_opaque(reason), context);
+            Ok(())
+        },
+        context,
+    )
+}
+
+/// Runs the steps `i.vii.6` and `i.vii.7` of `fromAsyncClosure`, defining the mapped value in the
+/// array and continuing with the next iteration.
+fn array_like_define(
+    mut state: FromAsyncState,
+    mapped_value: JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 6. Perform ? CreateDataPropertyOrThrow(A, Pk, mappedValue).
+    state
+        .a
+        .create_data_property_or_throw(state.k, mapped_value, context)?;
+
+    // 7. Set k to k + 1.
+    state.k += 1;
+
+    array_like_step(&state, context)
+}
+
+/// `AsyncIteratorClose ( iteratorRecord, completion )`, specialized for throw completions.
+///
+/// Since `completion` is a throw completion, the promise of `Array.fromAsync` is always
+/// rejected with `error`, but only after awaiting the result of the `return` method of the
+/// iterator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-asynciteratorclose
+fn async_iterator_close(
+    state: &FromAsyncState,
+    error: JsError,
+    context: &mut Context,
+) -> JsResult<()> {
+    let Source::Iterator(iterator_record) = &state.source else {
+        unreachable!("must be called with an iterator source");
+    };
+
+    // 1. Assert: iteratorRecord.[[Iterator]] is an Object.
+    // 2. Let iterator be iteratorRecord.[[Iterator]].
+    let iterator = iterator_record.iterator().clone();
+
+    // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+    // 4. If innerResult.[[Type]] is normal, then
+    //     a. Let return be innerResult.[[Value]].
+    //     b. If return is undefined, return ? completion.
+    //     c. Set innerResult to Completion(Call(return, iterator)).
+    //     d. If innerResult.[[Type]] is normal, set innerResult to Completion(Await(innerResult.[[Value]])).
+    // 5. If completion.[[Type]] is throw, return ? completion.
+    let Ok(Some(r#return)) = iterator.get_method(js_string!("return"), context) else {
+        return Err(error);
+    };
+    let Ok(inner_result) = r#return.call(&iterator.into(), &[], context) else {
+        return Err(error);
+    };
+
+    let error = error.to_opaque(context);
+    let Ok(promise) = Promise::promise_resolve(
+        &context.intrinsics().constructors().promise().constructor(),
+        inner_result,
+        context,
+    ) else {
+        return Err(JsError::from_opaque(error));
+    };
+
+    let on_settled = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, _, (capability, error), context| {
+                reject(capability, &JsError::from_opaque(error.clone()), context);
+                Ok(JsValue::undefined())
+            },
+            (state.capability.clone(), error),
+        ),
+    )
+    .name("")
+    .length(1)
+    .build();
+
+    Promise::perform_promise_then(
+        &promise,
+        Some(on_settled.clone()),
+        Some(on_settled),
+        None,
+        context,
+    );
+
+    Ok(())
+}
+
+/// Performs `Await(value)`, resuming `fromAsyncClosure` with `on_fulfilled` or `on_rejected`
+/// when the awaited promise settles.
+///
+/// Any error returned by the continuations rejects the promise of `Array.fromAsync`.
+fn await_value(
+    value: JsValue,
+    state: &FromAsyncState,
+    on_fulfilled: Continuation,
+    on_rejected: Continuation,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 2. Let promise be ? PromiseResolve(%Promise%, value).
+    let promise = Promise::promise_resolve(
+        &context.intrinsics().constructors().promise().constructor(),
+        value,
+        context,
+    )?;
+
+    let handler = |continuation: Continuation, context: &mut Context| {
+        FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                move |_, args, state: &FromAsyncState, context| {
+                    if let Err(err) =
+                        continuation(state.clone(), args.get_or_undefined(0).clone(), context)
+                    {
+                        reject(&state.capability, &err, context);
+                    }
+                    Ok(JsValue::undefined())
+                },
+                state.clone(),
+            ),
+        )
+        .name("")
+        .length(1)
+        .build()
+    };
+
+    // 3. Let fulfilledClosure be a new Abstract Closure with parameters (v) that captures
+    //    asyncContext and performs the following steps when called: ...
+    // 4. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 1, "", « »).
+    let on_fulfilled = handler(on_fulfilled, context);
+
+    // 5. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures
+    //    asyncContext and performs the following steps when called: ...
+    // 6. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+    let on_rejected = handler(on_rejected, context);
+
+    // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+    Promise::perform_promise_then(
+        &promise,
+        Some(on_fulfilled),
+        Some(on_rejected),
+        None,
+        context,
+    );
+
+    Ok(())
+}
+
+/// Resolves the promise of `Array.fromAsync` with `value`.
+fn resolve(capability: &PromiseCapability, value: JsValue, context: &mut Context) {
+    capability
+        .resolve()
+        .call(&JsValue::undefined(), &[value], context)
+        .expect("default resolving functions cannot throw");
+}
+
+/// Rejects the promise of `Array.fromAsync` with `error`.
+fn reject(capability: &PromiseCapability, error: &JsError, context: &mut Context) {
+    let error = error.to_opaque(context);
+    capability
+        .reject()
+        .call(&JsValue::undefined(), &[error], context)
+        .expect("default resolving functions cannot throw");
+}
@@ -41,6 +41,7 @@ use std::cmp::{min, Ordering};
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
 mod array_iterator;
+mod from_async;
 pub(crate) use array_iterator::ArrayIterator;
 #[cfg(test)]
 mod tests;
@@ -110,6 +111,7 @@ impl IntrinsicObject for Array {
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             // Static Methods
             .static_method(Self::from, js_string!("from"), 1)
+            .static_method(Self::from_async, js_string!("fromAsync"), 1)
             .static_method(Self::is_array, js_string!("isArray"), 1)
             .static_method(Self::of, js_string!("of"), 0)
             .static_accessor(
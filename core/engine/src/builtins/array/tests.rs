@@ -950,3 +950,53 @@ fn array_sort() {
             "#}),
     ]);
 }
+
+#[test]
+fn from_async() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const results = {};
+            async function* gen() {
+                yield 1;
+                yield Promise.resolve(2);
+                yield 3;
+            }
+            Array.fromAsync(gen()).then(a => results.asyncIterable = a);
+            Array.fromAsync([Promise.resolve(1), 2]).then(a => results.syncIterable = a);
+            Array.fromAsync({ length: 2, 0: Promise.resolve('a'), 1: 'b' })
+                .then(a => results.arrayLike = a);
+            Array.fromAsync([1, 2], async x => x * 2).then(a => results.mapped = a);
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("results.asyncIterable.join()", js_string!("1,2,3")),
+        TestAction::assert_eq("results.syncIterable.join()", js_string!("1,2")),
+        TestAction::assert_eq("results.arrayLike.join()", js_string!("a,b")),
+        TestAction::assert_eq("results.mapped.join()", js_string!("2,4")),
+    ]);
+}
+
+#[test]
+fn from_async_errors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const errors = {};
+            let closed = false;
+            const iterable = {
+                [Symbol.asyncIterator]() {
+                    return {
+                        next() { return Promise.resolve({ value: 1, done: false }); },
+                        return() { closed = true; return Promise.resolve({}); },
+                    };
+                },
+            };
+            Array.fromAsync([], 1).catch(e => errors.notCallable = e);
+            Array.fromAsync([Promise.reject(1)]).catch(e => errors.rejected = e);
+            Array.fromAsync(iterable, () => { throw 2; }).catch(e => errors.mapper = e);
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert("errors.notCallable instanceof TypeError"),
+        TestAction::assert_eq("errors.rejected", 1),
+        TestAction::assert_eq("errors.mapper", 2),
+        TestAction::assert("closed"),
+    ]);
+}
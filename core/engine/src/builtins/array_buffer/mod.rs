@@ -31,6 +31,7 @@ use crate::{
     realm::Realm,
     string::common::StaticJsStrings,
     symbol::JsSymbol,
+    vm::check_external_allocation,
     Context, JsArgs, JsData, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, GcRef, GcRefMut, Trace};
@@ -806,6 +807,13 @@ impl ArrayBuffer {
             // Should only truncate without reallocating.
             bytes.resize(new_len as usize, 0);
         } else {
+            if new_len as usize > bytes.len() {
+                if let Err(err) = check_external_allocation(new_len as usize) {
+                    buf.borrow_mut().data.data = Some(bytes);
+                    return Err(err);
+                }
+            }
+
             bytes.resize(new_len as usize, 0);
 
             // Realloc the vec to fit onto the new exact length.
@@ -936,6 +944,8 @@ pub(crate) fn create_byte_data_block(
         JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
     })?;
 
+    check_external_allocation(alloc_size)?;
+
     let mut data_block = Vec::new();
     data_block.try_reserve_exact(alloc_size).map_err(|e| {
         JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
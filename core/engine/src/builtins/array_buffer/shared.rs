@@ -19,6 +19,7 @@ use crate::{
     property::Attribute,
     realm::Realm,
     string::common::StaticJsStrings,
+    vm::check_external_allocation,
     Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
 };
 
@@ -571,6 +572,8 @@ pub(crate) fn create_shared_byte_data_block(
         return Ok(Box::default());
     }
 
+    check_external_allocation(size)?;
+
     // 2. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
     // 3. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose
     //    [[AgentSignifier]] is AgentSignifier().
@@ -133,6 +133,14 @@
 // In a future point in time, "Thread 1" will be notified, which will proceed with the
 // exact same steps as "Thread 2", emptying the wait queue and finishing the execution of our
 // program.
+//
+// `Atomics.waitAsync` uses the same wait queues, but instead of suspending the thread with the
+// `cond_var` of its `FutexWaiter`, it registers a heap allocated waiter that is owned by an
+// `AsyncWaiter` future. Notifying the waiter wakes the `Waker` of the future, which then resolves
+// on its next poll. The timeouts of the pending waiters are tracked by `FutexWaiters`, and a single
+// timer thread removes the expired waiters from their lists and wakes them, like `notify_many`
+// does. On wasm, where threads are unavailable, the timeouts are only checked when the future
+// is polled.
 
 #![deny(unsafe_op_in_unsafe_fn)]
 #![deny(clippy::undocumented_unsafe_blocks)]
@@ -141,7 +149,11 @@
 
 use std::{
     cell::UnsafeCell,
-    sync::{atomic::Ordering, Condvar, Mutex},
+    collections::BTreeSet,
+    future::Future,
+    pin::Pin,
+    sync::{atomic::Ordering, Condvar, Mutex, PoisonError},
+    task::{self, Poll, Waker},
 };
 
 use intrusive_collections::{intrusive_adapter, LinkedList, LinkedListLink, UnsafeRef};
@@ -152,22 +164,67 @@ use crate::{
         array_buffer::{utils::SliceRef, SharedArrayBuffer},
         typed_array::Element,
     },
+    js_string,
     small_map::{Entry, SmallMap},
     sys::time::{Duration, Instant},
-    JsNativeError, JsResult,
+    JsNativeError, JsResult, JsString,
 };
 
 /// Map of shared data addresses and its corresponding list of agents waiting on that location.
 pub(crate) static CRITICAL_SECTION: Mutex<FutexWaiters> = Mutex::new(FutexWaiters {
     waiters: SmallMap::new(),
+    timeouts: BTreeSet::new(),
 });
 
+/// Notified when the earliest timeout of the asynchronous waiters changes.
+#[cfg(not(target_family = "wasm"))]
+static TIMEOUTS_CHANGED: Condvar = Condvar::new();
+
+/// Starts the timer thread on the first asynchronous waiter with a timeout.
+#[cfg(not(target_family = "wasm"))]
+static START_TIMER: std::sync::Once = std::sync::Once::new();
+
+/// Wakes the asynchronous waiters whose timeout expired, until the process exits.
+#[cfg(not(target_family = "wasm"))]
+fn run_timer() {
+    let mut waiters = CRITICAL_SECTION
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    loop {
+        let now = Instant::now();
+        waiters.time_out_expired(now);
+
+        waiters = match waiters.timeouts.first() {
+            Some(&(deadline, _)) => {
+                TIMEOUTS_CHANGED
+                    .wait_timeout(waiters, deadline.saturating_duration_since(now))
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .0
+            }
+            None => TIMEOUTS_CHANGED
+                .wait(waiters)
+                .unwrap_or_else(PoisonError::into_inner),
+        };
+    }
+}
+
+/// A pointer to the `FutexWaiter` of an asynchronous waiter, ordered by its address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct WaiterPtr(*mut FutexWaiter);
+
+// SAFETY: `WaiterPtr`s are only stored in `FutexWaiters`, and they are only dereferenced while
+// holding the critical section.
+unsafe impl Send for WaiterPtr {}
+
 /// A waiter of a memory address.
 #[derive(Debug, Default)]
 pub(crate) struct FutexWaiter {
     pub(super) link: LinkedListLink,
     pub(super) cond_var: Condvar,
+    pub(super) waker: Option<Waker>,
     pub(super) waiting: bool,
+    pub(super) timed_out: bool,
     addr: usize,
 }
 
@@ -177,6 +234,7 @@ intrusive_adapter!(FutexWaiterAdapter = UnsafeRef<FutexWaiter>: FutexWaiter { li
 #[derive(Debug)]
 pub(crate) struct FutexWaiters {
     waiters: SmallMap<usize, LinkedList<FutexWaiterAdapter>, 16>,
+    timeouts: BTreeSet<(Instant, WaiterPtr)>,
 }
 
 impl FutexWaiters {
@@ -202,7 +260,11 @@ impl FutexWaiters {
 
             // SAFETY: all elements of the waiters list are guaranteed to be valid.
             unsafe {
-                (*UnsafeRef::into_raw(elem)).waiting = false;
+                let elem = UnsafeRef::into_raw(elem);
+                (*elem).waiting = false;
+                if let Some(waker) = (*elem).waker.take() {
+                    waker.wake();
+                }
             }
         }
 
@@ -255,6 +317,62 @@ impl FutexWaiters {
             wl.remove();
         }
     }
+
+    /// Registers the timeout of an asynchronous waiter, which is woken up by the timer thread
+    /// once `deadline` passes.
+    ///
+    /// # Safety
+    ///
+    /// - `node` must be inside a waiter list.
+    /// - The timeout must be removed by `remove_timeout` before `node` is deallocated.
+    unsafe fn add_timeout(&mut self, node: *mut FutexWaiter, deadline: Instant) {
+        self.timeouts.insert((deadline, WaiterPtr(node)));
+
+        #[cfg(not(target_family = "wasm"))]
+        {
+            START_TIMER.call_once(|| {
+                // Detaches the timer thread. Without it, the timeouts are still checked when the
+                // waiters are polled.
+                drop(
+                    std::thread::Builder::new()
+                        .name("boa-atomics-timer".to_owned())
+                        .spawn(run_timer),
+                );
+            });
+
+            // The timer rechecks the earliest timeout once the critical section is released.
+            TIMEOUTS_CHANGED.notify_one();
+        }
+    }
+
+    /// Unregisters the timeout of an asynchronous waiter, if it didn't expire yet.
+    fn remove_timeout(&mut self, node: *mut FutexWaiter, deadline: Instant) {
+        self.timeouts.remove(&(deadline, WaiterPtr(node)));
+    }
+
+    /// Removes the asynchronous waiters whose timeout expired at `now` from their lists, and
+    /// wakes them up.
+    fn time_out_expired(&mut self, now: Instant) {
+        while let Some(&(deadline, WaiterPtr(node))) = self.timeouts.first() {
+            if deadline > now {
+                return;
+            }
+            self.timeouts.pop_first();
+
+            // SAFETY: `node` is valid until its timeout is removed, and it's inside its waiter
+            // list while `waiting == true`.
+            unsafe {
+                if (*node).waiting {
+                    self.remove_waiter(node);
+                    (*node).waiting = false;
+                    (*node).timed_out = true;
+                    if let Some(waker) = (*node).waker.take() {
+                        waker.wake();
+                    }
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -264,6 +382,113 @@ pub(super) enum AtomicsWaitResult {
     Ok,
 }
 
+impl AtomicsWaitResult {
+    /// Gets the string returned to ECMAScript code for this result.
+    pub(super) fn to_js_string(self) -> JsString {
+        match self {
+            Self::NotEqual => js_string!("not-equal"),
+            Self::TimedOut => js_string!("timed-out"),
+            Self::Ok => js_string!("ok"),
+        }
+    }
+}
+
+/// The result of registering an asynchronous waiter.
+#[derive(Debug)]
+pub(super) enum AsyncWaitResult {
+    /// The waiter wasn't registered, returning the result synchronously.
+    Sync(AtomicsWaitResult),
+    /// The waiter was registered, and the returned future resolves after it gets notified or
+    /// times out.
+    Async(AsyncWaiter),
+}
+
+/// A waiter registered by [`wait_async`], which resolves after being notified or after its timeout
+/// expires.
+#[derive(Debug)]
+pub(super) struct AsyncWaiter {
+    /// Heap allocation of the `FutexWaiter` that is linked to the waiter list. This is owned by
+    /// the `AsyncWaiter`, and must be unlinked before deallocating it.
+    waiter: *mut FutexWaiter,
+    /// The timeout of the waiter, which is registered in `FutexWaiters` until the waiter resolves.
+    deadline: Option<Instant>,
+}
+
+impl Future for AsyncWaiter {
+    type Output = JsResult<AtomicsWaitResult>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let mut waiters = CRITICAL_SECTION.lock().map_err(|_| {
+            // avoids exposing internals of our implementation.
+            JsNativeError::typ().with_message("failed to synchronize with the agent cluster")
+        })?;
+
+        let waiter = self.waiter;
+
+        // The timer thread could be unavailable, so the timeout is also checked here.
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            // SAFETY: `waiter` is valid and contained in its waiter list if `waiting == true`.
+            unsafe {
+                if (*waiter).waiting {
+                    waiters.remove_waiter(waiter);
+                    (*waiter).waiting = false;
+                    (*waiter).timed_out = true;
+                }
+            }
+        }
+
+        // SAFETY: `waiter` is valid until `self` is dropped, and it can only be accessed while
+        // holding the critical section.
+        unsafe {
+            if (*waiter).waiting {
+                (*waiter).waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+        }
+
+        if let Some(deadline) = self.deadline.take() {
+            waiters.remove_timeout(waiter, deadline);
+        }
+
+        // SAFETY: `waiter` is valid until `self` is dropped.
+        let timed_out = unsafe { (*waiter).timed_out };
+
+        Poll::Ready(Ok(if timed_out {
+            AtomicsWaitResult::TimedOut
+        } else {
+            AtomicsWaitResult::Ok
+        }))
+    }
+}
+
+impl Drop for AsyncWaiter {
+    fn drop(&mut self) {
+        let mut waiters = CRITICAL_SECTION
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(deadline) = self.deadline {
+            waiters.remove_timeout(self.waiter, deadline);
+        }
+
+        // SAFETY: `waiter` is valid and contained in its waiter list if `waiting == true`.
+        unsafe {
+            if (*self.waiter).waiting {
+                waiters.remove_waiter(self.waiter);
+            }
+        }
+
+        drop(waiters);
+
+        // SAFETY: `waiter` was allocated by `Box::into_raw` in `wait_async`, and it was unlinked
+        // from its waiter list and its timeout above, so there are no other pointers to it.
+        drop(unsafe { Box::from_raw(self.waiter) });
+    }
+}
+
 /// Adds this agent to the wait queue for the address pointed to by `buffer[offset..]`.
 ///
 /// # Safety
@@ -379,6 +604,81 @@ pub(super) unsafe fn wait<E: Element + PartialEq>(
     Ok(result)
 }
 
+/// Adds an asynchronous waiter to the wait queue for the address pointed to by `buffer[offset..]`.
+///
+/// # Safety
+///
+/// - `addr` must be a multiple of `std::mem::size_of::<E>()`.
+/// - `buffer` must contain at least `std::mem::size_of::<E>()` bytes to read starting from `usize`.
+pub(super) unsafe fn wait_async<E: Element + PartialEq>(
+    buffer: &SharedArrayBuffer,
+    buf_len: usize,
+    offset: usize,
+    check: E,
+    timeout: Option<Duration>,
+) -> JsResult<AsyncWaitResult> {
+    // 11. Let block be buffer.[[ArrayBufferData]].
+    // 14. Let WL be GetWaiterList(block, byteIndexInBuffer).
+    // 17. Perform EnterCriticalSection(WL).
+    let mut waiters = CRITICAL_SECTION.lock().map_err(|_| {
+        // avoids exposing internals of our implementation.
+        JsNativeError::typ().with_message("failed to synchronize with the agent cluster")
+    })?;
+
+    let buffer = &buffer.bytes_with_len(buf_len)[offset..];
+
+    // 18. Let elementType be TypedArrayElementType(typedArray).
+    // 19. Let w be GetValueFromBuffer(buffer, byteIndexInBuffer, elementType, true, SeqCst).
+
+    // SAFETY: The safety of this operation is guaranteed by the caller.
+    let value = unsafe { E::read(SliceRef::AtomicSlice(buffer)).load(Ordering::SeqCst) };
+
+    // 20. If v ≠ w, then
+    //     a. Perform LeaveCriticalSection(WL).
+    //     b-e. Return "not-equal" synchronously.
+    if check != value {
+        return Ok(AsyncWaitResult::Sync(AtomicsWaitResult::NotEqual));
+    }
+
+    // 21. If t = 0 and mode is async, then
+    //     a. Perform LeaveCriticalSection(WL).
+    //     b-e. Return "timed-out" synchronously.
+    if timeout == Some(Duration::ZERO) {
+        return Ok(AsyncWaitResult::Sync(AtomicsWaitResult::TimedOut));
+    }
+
+    // 22. Let thisAgent be AgentSignifier().
+    // 23. Let now be the time value (UTC) identifying the current time.
+    // 24. Let additionalTimeout be an implementation-defined non-negative mathematical value.
+    // 25. Let timeoutTime be ℝ(now) + t + additionalTimeout.
+    // 26. NOTE: When t is +∞, timeoutTime will also be +∞.
+    let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+
+    // 27. Let waiterRecord be a new Waiter Record { [[AgentSignifier]]: thisAgent, [[PromiseCapability]]: promiseCapability, [[TimeoutTime]]: timeoutTime, [[Result]]: "ok" }.
+    // 28. Perform AddWaiter(WL, waiterRecord).
+    let waiter = Box::into_raw(Box::default());
+
+    // SAFETY: `waiter` is valid and it is unlinked by the `Drop` implementation of `AsyncWaiter`.
+    unsafe {
+        waiters.add_waiter(waiter, buffer.as_ptr().addr());
+    }
+
+    // 30. Else if timeoutTime is finite, then
+    //     a. Perform EnqueueAtomicsWaitAsyncTimeoutJob(WL, waiterRecord).
+    if let Some(deadline) = deadline {
+        // SAFETY: `waiter` was just added to its waiter list, and its timeout is removed by
+        // `AsyncWaiter` before deallocating it.
+        unsafe {
+            waiters.add_timeout(waiter, deadline);
+        }
+    }
+
+    // 31. Perform LeaveCriticalSection(WL).
+    drop(waiters);
+
+    Ok(AsyncWaitResult::Async(AsyncWaiter { waiter, deadline }))
+}
+
 /// Notifies at most `count` agents waiting on the memory address pointed to by `buffer[offset..]`.
 pub(super) fn notify(buffer: &SharedArrayBuffer, offset: usize, count: u64) -> JsResult<u64> {
     let addr = buffer.as_ptr().addr() + offset;
@@ -15,10 +15,17 @@ mod futex;
 use std::sync::atomic::Ordering;
 
 use crate::{
-    builtins::BuiltInObject, context::intrinsics::Intrinsics, js_string, object::JsObject,
-    property::Attribute, realm::Realm, string::common::StaticJsStrings, symbol::JsSymbol,
-    sys::time::Duration, value::IntegerOrInfinity, Context, JsArgs, JsNativeError, JsResult,
-    JsString, JsValue,
+    builtins::BuiltInObject,
+    context::intrinsics::Intrinsics,
+    js_string,
+    object::{JsObject, JsPromise},
+    property::Attribute,
+    realm::Realm,
+    string::common::StaticJsStrings,
+    symbol::JsSymbol,
+    sys::time::Duration,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
 };
 
 use boa_profiler::Profiler;
@@ -29,6 +36,15 @@ use super::{
     BuiltInBuilder, IntrinsicObject,
 };
 
+/// The mode of the [`DoWait`][spec] abstract operation.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dowait
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WaitMode {
+    Sync,
+    Async,
+}
+
 /// Javascript `Atomics` object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(crate) struct Atomics;
@@ -53,6 +69,7 @@ impl IntrinsicObject for Atomics {
             .static_method(Atomics::store, js_string!("store"), 3)
             .static_method(Atomics::sub, js_string!("sub"), 3)
             .static_method(Atomics::wait, js_string!("wait"), 4)
+            .static_method(Atomics::wait_async, js_string!("waitAsync"), 4)
             .static_method(Atomics::notify, js_string!("notify"), 3)
             .static_method(Atomics::bit_xor, js_string!("xor"), 3)
             .build();
@@ -386,8 +403,23 @@ impl Atomics {
     /// [`Atomics.wait ( typedArray, index, value, timeout )`][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-atomics.wait
-    // TODO: rewrite this to support Atomics.waitAsync
     fn wait(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? DoWait(sync, typedArray, index, value, timeout).
+        Self::do_wait(WaitMode::Sync, args, context)
+    }
+
+    /// [`Atomics.waitAsync ( typedArray, index, value, timeout )`][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.waitasync
+    fn wait_async(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? DoWait(async, typedArray, index, value, timeout).
+        Self::do_wait(WaitMode::Async, args, context)
+    }
+
+    /// Abstract operation [`DoWait ( mode, typedArray, index, value, timeout )`][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dowait
+    fn do_wait(mode: WaitMode, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         let array = args.get_or_undefined(0);
         let index = args.get_or_undefined(1);
         let value = args.get_or_undefined(2);
@@ -397,7 +429,7 @@ impl Atomics {
         let (ta, buf_len) = validate_integer_typed_array(array, true)?;
 
         // 2. Let buffer be taRecord.[[Object]].[[ViewedArrayBuffer]].
-        // 2. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
+        // 3. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
         let buffer = match ta.borrow().data.viewed_array_buffer() {
             BufferObject::SharedBuffer(buf) => buf.clone(),
             BufferObject::Buffer(_) => {
@@ -407,22 +439,23 @@ impl Atomics {
             }
         };
 
-        // 3. Let indexedPosition be ? ValidateAtomicAccess(typedArray, index).
+        // 4. Let i be ? ValidateAtomicAccess(taRecord, index).
         let access = validate_atomic_access(&ta, buf_len, index, context)?;
 
         // spec expects the evaluation of this first, then the timeout.
         let value = if access.kind == TypedArrayKind::BigInt64 {
-            // 4. If typedArray.[[TypedArrayName]] is "BigInt64Array", let v be ? ToBigInt64(value).
+            // 5. Let arrayTypeName be typedArray.[[TypedArrayName]].
+            // 6. If arrayTypeName is "BigInt64Array", let v be ? ToBigInt64(value).
             value.to_big_int64(context)?
         } else {
-            // 5. Otherwise, let v be ? ToInt32(value).
+            // 7. Else, let v be ? ToInt32(value).
             i64::from(value.to_i32(context)?)
         };
 
         // moving above since we need to make a generic call next.
 
-        // 6. Let q be ? ToNumber(timeout).
-        // 7. If q is either NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be 0; else let t be max(ℝ(q), 0).
+        // 8. Let q be ? ToNumber(timeout).
+        // 9. If q is either NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be 0; else let t be max(ℝ(q), 0).
         let mut timeout = timeout.to_number(context)?;
         // convert to nanoseconds to discard any excessively big timeouts.
         timeout = timeout.clamp(0.0, f64::INFINITY) * 1000.0 * 1000.0;
@@ -432,18 +465,44 @@ impl Atomics {
             Some(Duration::from_nanos(timeout as u64))
         };
 
-        // 8. Let B be AgentCanSuspend().
-        // 9. If B is false, throw a TypeError exception.
-        if !context.can_block() {
-            return Err(JsNativeError::typ()
-                .with_message("agent cannot be suspended")
-                .into());
+        if mode == WaitMode::Sync {
+            // 10. If mode is sync and AgentCanSuspend() is false, throw a TypeError exception.
+            if !context.can_block() {
+                return Err(JsNativeError::typ()
+                    .with_message("agent cannot be suspended")
+                    .into());
+            }
+
+            // SAFETY: the validity of `addr` is verified by our call to `validate_atomic_access`.
+            let result = unsafe {
+                if access.kind == TypedArrayKind::BigInt64 {
+                    futex::wait(
+                        &buffer.borrow().data,
+                        buf_len,
+                        access.byte_offset,
+                        value,
+                        timeout,
+                    )?
+                } else {
+                    // value must fit into `i32` since it came from an `i32` above.
+                    futex::wait(
+                        &buffer.borrow().data,
+                        buf_len,
+                        access.byte_offset,
+                        value as i32,
+                        timeout,
+                    )?
+                }
+            };
+
+            // 32. If mode is sync, return waiterRecord.[[Result]].
+            return Ok(result.to_js_string().into());
         }
 
         // SAFETY: the validity of `addr` is verified by our call to `validate_atomic_access`.
         let result = unsafe {
             if access.kind == TypedArrayKind::BigInt64 {
-                futex::wait(
+                futex::wait_async(
                     &buffer.borrow().data,
                     buf_len,
                     access.byte_offset,
@@ -452,7 +511,7 @@ impl Atomics {
                 )?
             } else {
                 // value must fit into `i32` since it came from an `i32` above.
-                futex::wait(
+                futex::wait_async(
                     &buffer.borrow().data,
                     buf_len,
                     access.byte_offset,
@@ -462,12 +521,48 @@ impl Atomics {
             }
         };
 
-        Ok(match result {
-            futex::AtomicsWaitResult::NotEqual => js_string!("not-equal"),
-            futex::AtomicsWaitResult::TimedOut => js_string!("timed-out"),
-            futex::AtomicsWaitResult::Ok => js_string!("ok"),
-        }
-        .into())
+        // 16. Else,
+        //     b. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+        let result_object = JsObject::with_object_proto(context.intrinsics());
+
+        let (is_async, value): (bool, JsValue) = match result {
+            // 20. If v ≠ w, then
+            //     c. Perform ! CreateDataPropertyOrThrow(resultObject, "async", false).
+            //     d. Perform ! CreateDataPropertyOrThrow(resultObject, "value", "not-equal").
+            // 21. If t = 0 and mode is async, then
+            //     b. Perform ! CreateDataPropertyOrThrow(resultObject, "async", false).
+            //     c. Perform ! CreateDataPropertyOrThrow(resultObject, "value", "timed-out").
+            futex::AsyncWaitResult::Sync(result) => (false, result.to_js_string().into()),
+            futex::AsyncWaitResult::Async(waiter) => {
+                // 16. Else,
+                //     a. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+                //
+                // The waiter holds a pointer to the data block of the buffer, so we keep the
+                // buffer alive until the waiter resolves.
+                let promise = JsPromise::from_future(
+                    async move {
+                        let result = waiter.await;
+                        drop(buffer);
+                        result.map(|result| result.to_js_string().into())
+                    },
+                    context,
+                );
+
+                // 33. Perform ! CreateDataPropertyOrThrow(resultObject, "async", true).
+                // 34. Perform ! CreateDataPropertyOrThrow(resultObject, "value", promiseCapability.[[Promise]]).
+                (true, promise.into())
+            }
+        };
+
+        result_object
+            .create_data_property_or_throw(js_string!("async"), is_async, context)
+            .expect("this CreateDataPropertyOrThrow call must not fail");
+        result_object
+            .create_data_property_or_throw(js_string!("value"), value, context)
+            .expect("this CreateDataPropertyOrThrow call must not fail");
+
+        // 35. Return resultObject.
+        Ok(result_object.into())
     }
 
     /// [`Atomics.notify ( typedArray, index, count )`][spec]
@@ -602,3 +697,126 @@ fn validate_atomic_access(
         kind,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use boa_parser::Source;
+    use indoc::indoc;
+
+    use super::futex;
+    use crate::{
+        builtins::array_buffer::SharedArrayBuffer, js_string, object::JsObject, run_test_actions,
+        Context, JsValue, TestAction,
+    };
+
+    /// Runs the jobs of `context` until the waiter of the test pushes its result.
+    fn run_jobs_until_resolved(context: &mut Context) {
+        for _ in 0..1000 {
+            context.run_jobs();
+            let len = context.eval(Source::from_bytes("results.length")).unwrap();
+            if len != JsValue::new(0) {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        panic!("the waiter was never resolved");
+    }
+
+    #[test]
+    fn wait_async_sync_results() {
+        run_test_actions([
+            TestAction::run("var i32a = new Int32Array(new SharedArrayBuffer(16));"),
+            TestAction::assert(indoc! {r#"
+                var result = Atomics.waitAsync(i32a, 0, 1);
+                !result.async && result.value === "not-equal"
+            "#}),
+            TestAction::assert(indoc! {r#"
+                var result = Atomics.waitAsync(i32a, 0, 0, 0);
+                !result.async && result.value === "timed-out"
+            "#}),
+        ]);
+    }
+
+    #[test]
+    fn wait_async_notify() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var i32a = new Int32Array(new SharedArrayBuffer(16));
+                var results = [];
+                var result = Atomics.waitAsync(i32a, 0, 0);
+                result.value.then(v => results.push(v));
+            "#}),
+            TestAction::assert("result.async && result.value instanceof Promise"),
+            TestAction::assert_eq("Atomics.notify(i32a, 0)", 1),
+            TestAction::assert_eq("Atomics.notify(i32a, 0)", 0),
+            TestAction::inspect_context(Context::run_jobs),
+            TestAction::assert_eq("results.length", 1),
+            TestAction::assert_eq("results[0]", js_string!("ok")),
+        ]);
+    }
+
+    #[test]
+    fn wait_async_notified_by_another_thread() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var sab = new SharedArrayBuffer(16);
+                var i32a = new Int32Array(sab);
+                var results = [];
+                Atomics.waitAsync(i32a, 0, 0).value.then(v => results.push(v));
+            "#}),
+            TestAction::inspect_context(|context| {
+                let sab = context
+                    .global_object()
+                    .get(js_string!("sab"), context)
+                    .unwrap();
+                let buffer = sab
+                    .as_object()
+                    .and_then(JsObject::downcast_ref::<SharedArrayBuffer>)
+                    .unwrap()
+                    .clone();
+
+                let notifier = std::thread::spawn(move || {
+                    std::thread::sleep(std::time::Duration::from_millis(10));
+                    futex::notify(&buffer, 0, 1).unwrap()
+                });
+
+                run_jobs_until_resolved(context);
+                assert_eq!(notifier.join().unwrap(), 1);
+            }),
+            TestAction::assert_eq("results.length", 1),
+            TestAction::assert_eq("results[0]", js_string!("ok")),
+        ]);
+    }
+
+    #[test]
+    fn wait_async_without_notify_does_not_block() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var i32a = new Int32Array(new SharedArrayBuffer(16));
+                var results = [];
+                Atomics.waitAsync(i32a, 0, 0).value.then(v => results.push(v));
+            "#}),
+            // Nothing can notify the waiter while the jobs run, so this must return.
+            TestAction::inspect_context(Context::run_jobs),
+            TestAction::assert_eq("results.length", 0),
+            TestAction::assert_eq("Atomics.notify(i32a, 0)", 1),
+            TestAction::inspect_context(Context::run_jobs),
+            TestAction::assert_eq("results[0]", js_string!("ok")),
+        ]);
+    }
+
+    #[test]
+    fn wait_async_timeout() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var i32a = new Int32Array(new SharedArrayBuffer(16));
+                var results = [];
+                Atomics.waitAsync(i32a, 0, 0, 10).value.then(v => results.push(v));
+            "#}),
+            TestAction::inspect_context(run_jobs_until_resolved),
+            TestAction::assert_eq("results.length", 1),
+            TestAction::assert_eq("results[0]", js_string!("timed-out")),
+            TestAction::assert_eq("Atomics.notify(i32a, 0)", 0),
+        ]);
+    }
+}
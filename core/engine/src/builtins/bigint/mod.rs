@@ -42,6 +42,7 @@ impl IntrinsicObject for BigInt {
         let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::to_locale_string, js_string!("toLocaleString"), 0)
             .method(Self::to_string, js_string!("toString"), 0)
             .method(Self::value_of, js_string!("valueOf"), 0)
             .static_method(Self::as_int_n, js_string!("asIntN"), 2)
@@ -156,6 +157,48 @@ impl BigInt {
             })
     }
 
+    /// `BigInt.prototype.toLocaleString( [ locales [ , options ] ] )`
+    ///
+    /// The `toLocaleString()` method returns a string with a language-sensitive representation of
+    /// this `BigInt`.
+    ///
+    /// Without the `intl` feature, this returns the same string as `BigInt.prototype.toString()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-bigint.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let x be ? thisBigIntValue(this value).
+        let x = Self::this_bigint_value(this)?;
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::number_format::{to_intl_mathematical_value, NumberFormat};
+
+            // 2. Let numberFormat be ? Construct(%Intl.NumberFormat%, « locales, options »).
+            let number_format =
+                NumberFormat::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+            // 3. Return FormatNumeric(numberFormat, ℝ(x)).
+            let x = to_intl_mathematical_value(&x.into(), context)?;
+            Ok(number_format.format(x).into())
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (args, context);
+            Ok(js_string!(x.to_string()).into())
+        }
+    }
+
     /// `BigInt.prototype.toString( [radix] )`
     ///
     /// The `toString()` method returns a string representing the specified `BigInt` object.
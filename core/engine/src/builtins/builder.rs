@@ -366,9 +366,17 @@ impl BuiltInConstructorWithPrototype<'_> {
         self = self.static_property(js_string!("name"), name, Attribute::CONFIGURABLE);
         self = self.static_property(PROTOTYPE, prototype, Attribute::empty());
 
-        let attributes = self.attributes;
-        let object = self.object.clone();
-        self = self.property(CONSTRUCTOR, object, attributes);
+        // Builtins can override the default `constructor` property of their prototype, e.g. with
+        // an accessor property.
+        if !self
+            .prototype_property_table
+            .map
+            .contains_key(&CONSTRUCTOR.into())
+        {
+            let attributes = self.attributes;
+            let object = self.object.clone();
+            self = self.property(CONSTRUCTOR, object, attributes);
+        }
 
         {
             let mut prototype = self.prototype.borrow_mut();
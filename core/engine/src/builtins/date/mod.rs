@@ -30,7 +30,7 @@ use crate::{
     string::{common::StaticJsStrings, utf16},
     symbol::JsSymbol,
     value::{JsValue, PreferredType},
-    Context, JsArgs, JsData, JsError, JsResult, JsString,
+    Context, JsArgs, JsData, JsResult, JsString,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
@@ -1477,7 +1477,7 @@ impl Date {
         func.call(this, &[], context)
     }
 
-    /// [`Date.prototype.toLocaleDateString()`][spec].
+    /// [`Date.prototype.toLocaleDateString ( [ locales [ , options ] ] )`][spec].
     ///
     /// The `toLocaleDateString()` method returns the date portion of the given Date instance according
     /// to language-specific conventions.
@@ -1485,55 +1485,117 @@ impl Date {
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaledatestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaledatestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString
     pub(crate) fn to_locale_date_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{FormatDefaults, FormatType};
+
+            Self::to_locale_string_with(this, args, FormatType::Date, FormatDefaults::Date, context)
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            Self::to_date_string(this, args, context)
+        }
     }
 
-    /// [`Date.prototype.toLocaleString()`][spec].
+    /// [`Date.prototype.toLocaleString ( [ locales [ , options ] ] )`][spec].
     ///
     /// The `toLocaleString()` method returns a string representing the specified Date object.
     ///
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocalestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString
     pub(crate) fn to_locale_string(
-        _this: &JsValue,
-        _: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented]"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{FormatDefaults, FormatType};
+
+            Self::to_locale_string_with(this, args, FormatType::Any, FormatDefaults::All, context)
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            Self::to_string(this, args, context)
+        }
     }
 
-    /// [`Date.prototype.toLocaleTimeString()`][spec].
+    /// [`Date.prototype.toLocaleTimeString ( [ locales [ , options ] ] )`][spec].
     ///
     /// The `toLocaleTimeString()` method returns the time portion of a Date object in human readable
-    /// form in American English.
+    /// form according to language-specific conventions.
     ///
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaletimestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString
     pub(crate) fn to_locale_time_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented]"
-        ))))
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{FormatDefaults, FormatType};
+
+            Self::to_locale_string_with(this, args, FormatType::Time, FormatDefaults::Time, context)
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            Self::to_time_string(this, args, context)
+        }
+    }
+
+    /// Shared steps of the `toLocaleString`, `toLocaleDateString` and `toLocaleTimeString` methods.
+    #[cfg(feature = "intl")]
+    fn to_locale_string_with(
+        this: &JsValue,
+        args: &[JsValue],
+        required: crate::builtins::intl::date_time_format::FormatType,
+        defaults: crate::builtins::intl::date_time_format::FormatDefaults,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        use crate::builtins::intl::DateTimeFormat;
+
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        // 3. Let x be dateObject.[[DateValue]].
+        let x = this
+            .as_object()
+            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
+            .0;
+
+        // 4. If x is NaN, return "Invalid Date".
+        if x.is_nan() {
+            return Ok(js_string!("Invalid Date").into());
+        }
+
+        // 5. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, required, defaults).
+        let date_format = DateTimeFormat::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            required,
+            defaults,
+            context,
+        )?;
+
+        // 6. Return ! FormatDateTime(dateFormat, x).
+        date_format.format_epoch_milliseconds(x).map(JsValue::from)
     }
 
     /// [`Date.prototype.toString()`][spec].
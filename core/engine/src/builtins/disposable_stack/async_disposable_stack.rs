@@ -0,0 +1,482 @@
+//! Boa's implementation of the `AsyncDisposableStack` builtin object.
+//!
+//! More information:
+//!  - [Explicit Resource Management proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::JsData;
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        promise::PromiseCapability, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject, Promise,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::common::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsError, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{DisposableResource, DisposableState, DisposeCapability, DisposeHint};
+
+/// Boa's implementation of the `AsyncDisposableStack` builtin object.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct AsyncDisposableStack {
+    // SAFETY: Nothing in `DisposableState` requires tracing, so this is safe.
+    #[unsafe_ignore_trace]
+    state: DisposableState,
+    capability: DisposeCapability,
+}
+
+impl IntrinsicObject for AsyncDisposableStack {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose_async = BuiltInBuilder::callable(realm, Self::dispose_async)
+            .name(js_string!("disposeAsync"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .property(
+                js_string!("disposeAsync"),
+                dispose_async.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#use, js_string!("use"), 1)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .method(Self::r#move, js_string!("move"), 0)
+            .property(
+                JsSymbol::async_dispose(),
+                dispose_async,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for AsyncDisposableStack {
+    const NAME: JsString = StaticJsStrings::ASYNC_DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for AsyncDisposableStack {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_disposable_stack;
+
+    /// `AsyncDisposableStack ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("AsyncDisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let asyncDisposableStack be ? OrdinaryCreateFromConstructor(NewTarget,
+        //    "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_disposable_stack,
+            context,
+        )?;
+
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return asyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                state: DisposableState::Pending,
+                capability: DisposeCapability::default(),
+            },
+        )
+        .into())
+    }
+}
+
+impl AsyncDisposableStack {
+    /// Abstract operation `RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]])`,
+    /// which also throws a `ReferenceError` if the stack is disposed and `pending` is true.
+    fn this_stack<'a>(this: &'a JsValue, method: &str, pending: bool) -> JsResult<&'a JsObject> {
+        let object = this
+            .as_object()
+            .filter(|object| object.is::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "AsyncDisposableStack.prototype.{method}: `this` is not an AsyncDisposableStack"
+                ))
+            })?;
+
+        if pending
+            && object
+                .downcast_ref::<Self>()
+                .expect("must be an AsyncDisposableStack")
+                .state
+                == DisposableState::Disposed
+        {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "AsyncDisposableStack.prototype.{method}: the stack is already disposed"
+                ))
+                .into());
+        }
+
+        Ok(object)
+    }
+
+    /// `get AsyncDisposableStack.prototype.disposed`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "disposed", false)?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        Ok((stack
+            .downcast_ref::<Self>()
+            .expect("must be an AsyncDisposableStack")
+            .state
+            == DisposableState::Disposed)
+            .into())
+    }
+
+    /// `AsyncDisposableStack.prototype.disposeAsync ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync
+    #[allow(clippy::unnecessary_wraps)]
+    fn dispose_async(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        // 3. If asyncDisposableStack does not have an [[AsyncDisposableState]] internal slot, then
+        //     a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+        //     b. Return promiseCapability.[[Promise]].
+        let stack = match Self::this_stack(this, "disposeAsync", false) {
+            Ok(stack) => stack,
+            Err(err) => {
+                reject(&capability, &err, context);
+                return Ok(capability.promise().clone().into());
+            }
+        };
+
+        let dispose = {
+            let mut stack = stack
+                .downcast_mut::<Self>()
+                .expect("must be an AsyncDisposableStack");
+
+            // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, then
+            if stack.state == DisposableState::Disposed {
+                drop(stack);
+
+                // a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                // b. Return promiseCapability.[[Promise]].
+                resolve(&capability, context);
+                return Ok(capability.promise().clone().into());
+            }
+
+            // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+            stack.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 6. Let result be DisposeResources(asyncDisposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        dispose_resources(
+            DisposeAsyncState {
+                promise: capability.clone(),
+                dispose,
+            },
+            context,
+        );
+
+        // 9. Return promiseCapability.[[Promise]].
+        Ok(capability.promise().clone().into())
+    }
+
+    /// `AsyncDisposableStack.prototype.use ( value )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let value = args.get_or_undefined(0);
+
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::this_stack(this, "use", true)?;
+
+        // 4. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], value, async-dispose).
+        let resource =
+            DisposableResource::create(value.clone(), DisposeHint::AsyncDispose, context)?;
+        stack
+            .downcast_mut::<Self>()
+            .expect("must be an AsyncDisposableStack")
+            .capability
+            .add_resource(resource);
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// `AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let value = args.get_or_undefined(0);
+
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::this_stack(this, "adopt", true)?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let on_dispose = args.get_or_undefined(1).as_callable().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "AsyncDisposableStack.prototype.adopt: onDisposeAsync is not callable",
+            )
+        })?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and
+        //    onDisposeAsync and performs the following steps when called:
+        //     a. Return ? Call(onDisposeAsync, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        // 7. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, F).
+        stack
+            .downcast_mut::<Self>()
+            .expect("must be an AsyncDisposableStack")
+            .capability
+            .add_adopted(value.clone(), on_dispose.clone(), DisposeHint::AsyncDispose);
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// `AsyncDisposableStack.prototype.defer ( onDisposeAsync )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::this_stack(this, "defer", true)?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let on_dispose = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "AsyncDisposableStack.prototype.defer: onDisposeAsync is not callable",
+            )
+        })?;
+
+        // 5. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, onDisposeAsync).
+        stack
+            .downcast_mut::<Self>()
+            .expect("must be an AsyncDisposableStack")
+            .capability
+            .add_callback(on_dispose.clone(), DisposeHint::AsyncDispose);
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `AsyncDisposableStack.prototype.move ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::this_stack(this, "move", true)?;
+
+        // 4. Let newAsyncDisposableStack be ? OrdinaryCreateFromConstructor(%AsyncDisposableStack%,
+        //    "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        // 5. Set newAsyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 6. Set newAsyncDisposableStack.[[DisposeCapability]] to asyncDisposableStack.[[DisposeCapability]].
+        // 7. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        let capability = {
+            let mut stack = stack
+                .downcast_mut::<Self>()
+                .expect("must be an AsyncDisposableStack");
+            stack.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 9. Return newAsyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .async_disposable_stack()
+                .prototype(),
+            Self {
+                state: DisposableState::Pending,
+                capability,
+            },
+        )
+        .into())
+    }
+}
+
+/// The state of a pending `AsyncDisposableStack.prototype.disposeAsync` call.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct DisposeAsyncState {
+    /// The capability of the promise returned by `disposeAsync`.
+    promise: PromiseCapability,
+
+    /// The resources being disposed of.
+    dispose: DisposeCapability,
+}
+
+/// Runs `DisposeResources` until its next `Await`, settling the promise of `disposeAsync` once
+/// all resources have been disposed of.
+fn dispose_resources(mut state: DisposeAsyncState, context: &mut Context) {
+    loop {
+        let Some(value) = state.dispose.step(context) else {
+            match state.dispose.take_completion() {
+                Ok(()) => resolve(&state.promise, context),
+                Err(err) => reject(&state.promise, &err, context),
+            }
+            return;
+        };
+
+        // Await ( value )
+        // 2. Let promise be ? PromiseResolve(%Promise%, value).
+        let promise = match Promise::promise_resolve(
+            &context.intrinsics().constructors().promise().constructor(),
+            value,
+            context,
+        ) {
+            Ok(promise) => promise,
+            Err(err) => {
+                state.dispose.resume(Err(err));
+                continue;
+            }
+        };
+
+        let on_fulfilled = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, _, state: &DisposeAsyncState, context| {
+                    dispose_resources(state.clone(), context);
+                    Ok(JsValue::undefined())
+                },
+                state.clone(),
+            ),
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        let on_rejected = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, state: &DisposeAsyncState, context| {
+                    let mut state = state.clone();
+                    state
+                        .dispose
+                        .resume(Err(JsError::from_opaque(args.get_or_undefined(0).clone())));
+                    dispose_resources(state, context);
+                    Ok(JsValue::undefined())
+                },
+                state,
+            ),
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+        Promise::perform_promise_then(
+            &promise,
+            Some(on_fulfilled),
+            Some(on_rejected),
+            None,
+            context,
+        );
+        return;
+    }
+}
+
+/// Resolves the promise of `disposeAsync` with `undefined`.
+fn resolve(capability: &PromiseCapability, context: &mut Context) {
+    capability
+        .resolve()
+        .call(&JsValue::undefined(), &[], context)
+        .expect("default resolving functions cannot throw");
+}
+
+/// Rejects the promise of `disposeAsync` with `error`.
+fn reject(capability: &PromiseCapability, error: &JsError, context: &mut Context) {
+    let error = error.to_opaque(context);
+    capability
+        .reject()
+        .call(&JsValue::undefined(), &[error], context)
+        .expect("default resolving functions cannot throw");
+}
@@ -0,0 +1,360 @@
+//! This module implements the `DisposeCapability` Record and its abstract operations.
+//!
+//! A dispose capability is shared by `using` declarations, which store it in the call frame of
+//! the scope that declares them, and by the `DisposableStack` and `AsyncDisposableStack`
+//! objects.
+//!
+//! More information:
+//!  - [Explicit Resource Management proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    object::JsObject, symbol::JsSymbol, Context, JsError, JsNativeError, JsResult, JsValue,
+};
+
+/// The hint of a disposable resource, which selects the method used to dispose of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposeHint {
+    /// The resource is disposed of with `@@dispose`.
+    SyncDispose,
+
+    /// The resource is disposed of with `@@asyncDispose`, or `@@dispose` as a fallback.
+    AsyncDispose,
+}
+
+/// The method used to dispose of a resource.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum DisposeMethod {
+    /// A method called with the resource value as the `this` value.
+    Method(JsObject),
+
+    /// A `@@dispose` method used as the fallback of a missing `@@asyncDispose` method.
+    ///
+    /// This is the closure created by step 1.b.ii of [`GetDisposeMethod`][spec], which calls
+    /// the method and awaits `undefined` instead of its result.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod
+    SyncFallback(JsObject),
+
+    /// A callback adopted by `DisposableStack.prototype.adopt` or
+    /// `AsyncDisposableStack.prototype.adopt`, called with the resource value as its only
+    /// argument.
+    Adopt(JsObject),
+}
+
+/// The `DisposableResource` Record.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct DisposableResource {
+    value: JsValue,
+    // SAFETY: Nothing in `DisposeHint` requires tracing, so this is safe.
+    #[unsafe_ignore_trace]
+    hint: DisposeHint,
+    method: Option<DisposeMethod>,
+}
+
+impl DisposableResource {
+    /// Creates the resource added by [`AddDisposableResource ( disposeCapability, V, hint )`][spec]
+    /// when no method is present.
+    ///
+    /// Returns `None` if no resource needs to be added.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    pub(crate) fn create(
+        value: JsValue,
+        hint: DisposeHint,
+        context: &mut Context,
+    ) -> JsResult<Option<Self>> {
+        // 1. If method is not present, then
+        //     a. If V is either null or undefined and hint is sync-dispose, return unused.
+        if value.is_null_or_undefined() && hint == DisposeHint::SyncDispose {
+            return Ok(None);
+        }
+
+        //     b. NOTE: When V is either null or undefined and hint is async-dispose, we record
+        //        that the resource was evaluated to ensure we will still perform an Await when
+        //        resources are later disposed.
+        //     c. Let resource be ? CreateDisposableResource(V, hint).
+
+        // CreateDisposableResource ( V, hint [ , method ] )
+        // 1. If method is not present, then
+        //     a. If V is either null or undefined, then
+        if value.is_null_or_undefined() {
+            // i. Set V to undefined.
+            // ii. Set method to undefined.
+            return Ok(Some(Self {
+                value: JsValue::undefined(),
+                hint,
+                method: None,
+            }));
+        }
+
+        //     b. Else,
+        //         i. If V is not an Object, throw a TypeError exception.
+        let object = value.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("disposable resource must be an object")
+        })?;
+
+        //         ii. Set method to ? GetDisposeMethod(V, hint).
+        //         iii. If method is undefined, throw a TypeError exception.
+        let method = get_dispose_method(object, hint, context)?.ok_or_else(|| {
+            JsNativeError::typ().with_message(match hint {
+                DisposeHint::SyncDispose => "disposable resource must have a dispose method",
+                DisposeHint::AsyncDispose => {
+                    "disposable resource must have an asyncDispose or dispose method"
+                }
+            })
+        })?;
+
+        // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint,
+        //    [[DisposeMethod]]: method }.
+        Ok(Some(Self {
+            value,
+            hint,
+            method: Some(method),
+        }))
+    }
+}
+
+/// The `DisposeCapability` Record.
+///
+/// Since disposing of resources with the `async-dispose` hint may await, [`DisposeResources`][spec]
+/// is implemented as a state machine: [`DisposeCapability::step`] runs it until it needs to
+/// await a value, and [`DisposeCapability::resume`] records the completion of that await.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+pub(crate) struct DisposeCapability {
+    /// The `[[DisposableResourceStack]]` field.
+    resources: Vec<DisposableResource>,
+
+    /// The completion of the disposal, if it is a throw completion.
+    completion: Option<JsError>,
+
+    /// The `needsAwait` variable of `DisposeResources`.
+    needs_await: bool,
+
+    /// The `hasAwaited` variable of `DisposeResources`.
+    has_awaited: bool,
+}
+
+impl DisposeCapability {
+    /// Abstract operation [`AddDisposableResource ( disposeCapability, V, hint )`][spec].
+    ///
+    /// The resource is created beforehand by [`DisposableResource::create`], which may call
+    /// into user code.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    pub(crate) fn add_resource(&mut self, resource: Option<DisposableResource>) {
+        // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+        if let Some(resource) = resource {
+            self.resources.push(resource);
+        }
+    }
+
+    /// Adds a callback that is called with `this` set to `undefined` on disposal, used by
+    /// the `defer` methods of `DisposableStack` and `AsyncDisposableStack`.
+    pub(crate) fn add_callback(&mut self, on_dispose: JsObject, hint: DisposeHint) {
+        self.resources.push(DisposableResource {
+            value: JsValue::undefined(),
+            hint,
+            method: Some(DisposeMethod::Method(on_dispose)),
+        });
+    }
+
+    /// Adds a callback that is called with `value` as its only argument on disposal, used by
+    /// the `adopt` methods of `DisposableStack` and `AsyncDisposableStack`.
+    pub(crate) fn add_adopted(&mut self, value: JsValue, on_dispose: JsObject, hint: DisposeHint) {
+        self.resources.push(DisposableResource {
+            value,
+            hint,
+            method: Some(DisposeMethod::Adopt(on_dispose)),
+        });
+    }
+
+    /// Runs [`DisposeResources ( disposeCapability, completion )`][spec] until the next
+    /// `Await`.
+    ///
+    /// Returns the value to await, or `None` if all resources have been disposed of.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn step(&mut self, context: &mut Context) -> Option<JsValue> {
+        // 3. For each element resource of disposeCapability.[[DisposableResourceStack]], in
+        //    reverse list order, do
+        while let Some(resource) = self.resources.pop() {
+            // a. Let value be resource.[[ResourceValue]].
+            // b. Let hint be resource.[[Hint]].
+            // c. Let method be resource.[[DisposeMethod]].
+            // d. If hint is sync-dispose and needsAwait is true and hasAwaited is false, then
+            if resource.hint == DisposeHint::SyncDispose && self.needs_await && !self.has_awaited {
+                // i. Perform ! Await(undefined).
+                // ii. Set needsAwait to false.
+                self.needs_await = false;
+                self.resources.push(resource);
+                return Some(JsValue::undefined());
+            }
+
+            // e. If method is not undefined, then
+            let Some(method) = &resource.method else {
+                // f. Else,
+                //     i. Assert: hint is async-dispose.
+                //     ii. Set needsAwait to true.
+                //     iii. NOTE: This can only indicate a case where either null or undefined
+                //         was the initialized value of an await using declaration.
+                self.needs_await = true;
+                continue;
+            };
+
+            // i. Let result be Completion(Call(method, value)).
+            let result = match method {
+                DisposeMethod::Method(method) | DisposeMethod::SyncFallback(method) => {
+                    method.call(&resource.value, &[], context)
+                }
+                DisposeMethod::Adopt(callback) => callback.call(
+                    &JsValue::undefined(),
+                    std::slice::from_ref(&resource.value),
+                    context,
+                ),
+            };
+
+            match result {
+                // ii. If result is a normal completion and hint is async-dispose, then
+                Ok(result) if resource.hint == DisposeHint::AsyncDispose => {
+                    // 1. Set result to Completion(Await(result.[[Value]])).
+                    // 2. Set hasAwaited to true.
+                    self.has_awaited = true;
+
+                    // The fallback closure of `GetDisposeMethod` returns a promise resolved
+                    // with `undefined` instead of the result of `@@dispose`.
+                    return Some(match method {
+                        DisposeMethod::SyncFallback(_) => JsValue::undefined(),
+                        _ => result,
+                    });
+                }
+                Ok(_) => {}
+                // iii. If result is a throw completion, then
+                Err(err) => {
+                    // The fallback closure of `GetDisposeMethod` returns a rejected promise
+                    // instead of throwing, which is awaited like any other result.
+                    let is_fallback = matches!(method, DisposeMethod::SyncFallback(_));
+                    self.record(err);
+                    if is_fallback {
+                        self.has_awaited = true;
+                        return Some(JsValue::undefined());
+                    }
+                }
+            }
+        }
+
+        // 4. If needsAwait is true and hasAwaited is false, then
+        if self.needs_await && !self.has_awaited {
+            // a. Perform ! Await(undefined).
+            self.needs_await = false;
+            return Some(JsValue::undefined());
+        }
+
+        // 5. NOTE: After disposeCapability has been disposed, it will never be used again.
+        //    The contents of disposeCapability.[[DisposableResourceStack]] can be discarded in
+        //    implementations, such as by garbage collection, at this point.
+        // 6. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+        None
+    }
+
+    /// Records the completion of an `Await` performed by [`DisposeCapability::step`].
+    pub(crate) fn resume(&mut self, result: JsResult<()>) {
+        if let Err(err) = result {
+            self.record(err);
+        }
+    }
+
+    /// Records `error` as the completion of the disposal, suppressing the previous error if
+    /// there is one.
+    pub(crate) fn record(&mut self, error: JsError) {
+        // iii. If result is a throw completion, then
+        self.completion = Some(match self.completion.take() {
+            // 1. If completion is a throw completion, then
+            //     a. Set result to result.[[Value]].
+            //     b. Let suppressed be completion.[[Value]].
+            //     c. Let error be a newly created SuppressedError object.
+            //     d. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+            //     e. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed",
+            //        suppressed).
+            //     f. Set completion to ThrowCompletion(error).
+            Some(suppressed) => JsNativeError::suppressed(error, suppressed).into(),
+            // 2. Else,
+            //     a. Set completion to result.
+            None => error,
+        });
+    }
+
+    /// Runs [`DisposeResources ( disposeCapability, completion )`][spec] to completion for a
+    /// capability with only `sync-dispose` resources.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn dispose_sync(&mut self, context: &mut Context) -> JsResult<()> {
+        while self.step(context).is_some() {}
+
+        // 7. Return ? completion.
+        self.take_completion()
+    }
+
+    /// Takes the completion of the disposal.
+    pub(crate) fn take_completion(&mut self) -> JsResult<()> {
+        self.needs_await = false;
+        self.has_awaited = false;
+        self.completion.take().map_or(Ok(()), Err)
+    }
+}
+
+/// Abstract operation [`GetDisposeMethod ( V, hint )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod
+fn get_dispose_method(
+    value: &JsObject,
+    hint: DisposeHint,
+    context: &mut Context,
+) -> JsResult<Option<DisposeMethod>> {
+    // 1. If hint is async-dispose, then
+    if hint == DisposeHint::AsyncDispose {
+        // a. Let method be ? GetMethod(V, @@asyncDispose).
+        if let Some(method) = value.get_method(JsSymbol::async_dispose(), context)? {
+            return Ok(Some(DisposeMethod::Method(method)));
+        }
+
+        // b. If method is undefined, then
+        //     i. Set method to ? GetMethod(V, @@dispose).
+        //     ii. If method is not undefined, then
+        //         1. Let closure be a new Abstract Closure with no parameters that captures
+        //            method and performs the following steps when called:
+        //             a. Let O be the this value.
+        //             b. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        //             c. Let result be Completion(Call(method, O)).
+        //             d. IfAbruptRejectPromise(result, promiseCapability).
+        //             e. Perform ? Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+        //             f. Return promiseCapability.[[Promise]].
+        //         2. NOTE: This function is not observable to user code. It is used to ensure
+        //            that a Promise returned from a synchronous @@dispose method will not be
+        //            awaited and that any exception thrown will not be thrown synchronously.
+        //         3. Return CreateBuiltinFunction(closure, 0, "", « »).
+        return Ok(value
+            .get_method(JsSymbol::dispose(), context)?
+            .map(DisposeMethod::SyncFallback));
+    }
+
+    // 2. Else,
+    //     a. Let method be ? GetMethod(V, @@dispose).
+    // 3. Return method.
+    Ok(value
+        .get_method(JsSymbol::dispose(), context)?
+        .map(DisposeMethod::Method))
+}
@@ -0,0 +1,363 @@
+//! Boa's implementation of the `DisposableStack` and `AsyncDisposableStack` builtin objects.
+//!
+//! More information:
+//!  - [Explicit Resource Management proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::JsData;
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::common::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
+};
+
+mod async_disposable_stack;
+mod capability;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use async_disposable_stack::AsyncDisposableStack;
+pub(crate) use capability::{DisposableResource, DisposeCapability, DisposeHint};
+
+/// The `[[DisposableState]]` internal slot of `DisposableStack` and `AsyncDisposableStack`
+/// objects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposableState {
+    Pending,
+    Disposed,
+}
+
+/// Boa's implementation of the `DisposableStack` builtin object.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct DisposableStack {
+    // SAFETY: Nothing in `DisposableState` requires tracing, so this is safe.
+    #[unsafe_ignore_trace]
+    state: DisposableState,
+    capability: DisposeCapability,
+}
+
+impl IntrinsicObject for DisposableStack {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose = BuiltInBuilder::callable(realm, Self::dispose)
+            .name(js_string!("dispose"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .property(
+                js_string!("dispose"),
+                dispose.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#use, js_string!("use"), 1)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .method(Self::r#move, js_string!("move"), 0)
+            .property(
+                JsSymbol::dispose(),
+                dispose,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisposableStack {
+    const NAME: JsString = StaticJsStrings::DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for DisposableStack {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::disposable_stack;
+
+    /// `DisposableStack ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("DisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget,
+        //    "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::disposable_stack,
+            context,
+        )?;
+
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return disposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                state: DisposableState::Pending,
+                capability: DisposeCapability::default(),
+            },
+        )
+        .into())
+    }
+}
+
+impl DisposableStack {
+    /// Abstract operation `RequireInternalSlot(disposableStack, [[DisposableState]])`, which
+    /// also throws a `ReferenceError` if the stack is disposed and `pending` is true.
+    fn this_stack<'a>(this: &'a JsValue, method: &str, pending: bool) -> JsResult<&'a JsObject> {
+        let object = this
+            .as_object()
+            .filter(|object| object.is::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "DisposableStack.prototype.{method}: `this` is not a DisposableStack"
+                ))
+            })?;
+
+        if pending
+            && object
+                .downcast_ref::<Self>()
+                .expect("must be a DisposableStack")
+                .state
+                == DisposableState::Disposed
+        {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "DisposableStack.prototype.{method}: the stack is already disposed"
+                ))
+                .into());
+        }
+
+        Ok(object)
+    }
+
+    /// `get DisposableStack.prototype.disposed`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "disposed", false)?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        Ok((stack
+            .downcast_ref::<Self>()
+            .expect("must be a DisposableStack")
+            .state
+            == DisposableState::Disposed)
+            .into())
+    }
+
+    /// `DisposableStack.prototype.dispose ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose
+    fn dispose(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "dispose", false)?;
+
+        let mut capability = {
+            let mut stack = stack
+                .downcast_mut::<Self>()
+                .expect("must be a DisposableStack");
+
+            // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+            if stack.state == DisposableState::Disposed {
+                return Ok(JsValue::undefined());
+            }
+
+            // 4. Set disposableStack.[[DisposableState]] to disposed.
+            stack.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 5. Return ? DisposeResources(disposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        capability.dispose_sync(context)?;
+        Ok(JsValue::undefined())
+    }
+
+    /// `DisposableStack.prototype.use ( value )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let value = args.get_or_undefined(0);
+
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::this_stack(this, "use", true)?;
+
+        // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], value, sync-dispose).
+        let resource =
+            DisposableResource::create(value.clone(), DisposeHint::SyncDispose, context)?;
+        stack
+            .downcast_mut::<Self>()
+            .expect("must be a DisposableStack")
+            .capability
+            .add_resource(resource);
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// `DisposableStack.prototype.adopt ( value, onDispose )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let value = args.get_or_undefined(0);
+
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::this_stack(this, "adopt", true)?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = args.get_or_undefined(1).as_callable().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("DisposableStack.prototype.adopt: onDispose is not callable")
+        })?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and
+        //    onDispose and performs the following steps when called:
+        //     a. Return ? Call(onDispose, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, F).
+        stack
+            .downcast_mut::<Self>()
+            .expect("must be a DisposableStack")
+            .capability
+            .add_adopted(value.clone(), on_dispose.clone(), DisposeHint::SyncDispose);
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// `DisposableStack.prototype.defer ( onDispose )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::this_stack(this, "defer", true)?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("DisposableStack.prototype.defer: onDispose is not callable")
+        })?;
+
+        // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, onDispose).
+        stack
+            .downcast_mut::<Self>()
+            .expect("must be a DisposableStack")
+            .capability
+            .add_callback(on_dispose.clone(), DisposeHint::SyncDispose);
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `DisposableStack.prototype.move ( )`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        let stack = Self::this_stack(this, "move", true)?;
+
+        // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(%DisposableStack%,
+        //    "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        // 5. Set newDisposableStack.[[DisposableState]] to pending.
+        // 6. Set newDisposableStack.[[DisposeCapability]] to disposableStack.[[DisposeCapability]].
+        // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set disposableStack.[[DisposableState]] to disposed.
+        let capability = {
+            let mut stack = stack
+                .downcast_mut::<Self>()
+                .expect("must be a DisposableStack");
+            stack.state = DisposableState::Disposed;
+            std::mem::take(&mut stack.capability)
+        };
+
+        // 9. Return newDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .disposable_stack()
+                .prototype(),
+            Self {
+                state: DisposableState::Pending,
+                capability,
+            },
+        )
+        .into())
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{js_string, run_test_actions, Context, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn disposable_stack_dispose() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const log = [];
+            const stack = new DisposableStack();
+            const resource = { [Symbol.dispose]() { log.push("use"); } };
+            stack.use(resource);
+            stack.use(null);
+            stack.adopt(1, (value) => log.push(`adopt ${value}`));
+            stack.defer(() => log.push("defer"));
+        "#}),
+        TestAction::assert("!stack.disposed"),
+        TestAction::assert("stack.dispose() === undefined"),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert_eq("log.join()", js_string!("defer,adopt 1,use")),
+        TestAction::assert_eq("stack.dispose(); log.length", 3),
+        TestAction::assert(
+            "DisposableStack.prototype[Symbol.dispose] === DisposableStack.prototype.dispose",
+        ),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(stack)",
+            js_string!("[object DisposableStack]"),
+        ),
+        TestAction::assert_native_error(
+            "stack.use({ [Symbol.dispose]() {} })",
+            JsNativeErrorKind::Reference,
+            "DisposableStack.prototype.use: the stack is already disposed",
+        ),
+        TestAction::assert_native_error(
+            "new DisposableStack().use({})",
+            JsNativeErrorKind::Type,
+            "disposable resource must have a dispose method",
+        ),
+        TestAction::assert_native_error(
+            "DisposableStack()",
+            JsNativeErrorKind::Type,
+            "DisposableStack: cannot call constructor without `new`",
+        ),
+    ]);
+}
+
+#[test]
+fn disposable_stack_suppressed_errors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const stack = new DisposableStack();
+            stack.defer(() => { throw new Error("first"); });
+            stack.defer(() => { throw new Error("second"); });
+            let error;
+            try {
+                stack.dispose();
+            } catch (e) {
+                error = e;
+            }
+        "#}),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error.message", js_string!("first")),
+        TestAction::assert_eq("error.suppressed.message", js_string!("second")),
+    ]);
+}
+
+#[test]
+fn disposable_stack_move() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const log = [];
+            const stack = new DisposableStack();
+            stack.defer(() => log.push("moved"));
+            const moved = stack.move();
+        "#}),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert("!moved.disposed"),
+        TestAction::assert("Object.getPrototypeOf(moved) === DisposableStack.prototype"),
+        TestAction::assert_eq("stack.dispose(); log.length", 0),
+        TestAction::assert_eq("moved.dispose(); log.join()", js_string!("moved")),
+    ]);
+}
+
+#[test]
+fn async_disposable_stack_dispose_async() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const log = [];
+            const stack = new AsyncDisposableStack();
+            stack.use({
+                async [Symbol.asyncDispose]() {
+                    await null;
+                    log.push("async");
+                },
+            });
+            stack.use({ [Symbol.dispose]() { log.push("sync"); } });
+            stack.defer(() => { throw new Error("deferred"); });
+
+            let error;
+            stack.disposeAsync().catch((e) => { error = e; });
+            log.push("started");
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("log.join()", js_string!("sync,started,async")),
+        TestAction::assert_eq("error.message", js_string!("deferred")),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert(
+            "AsyncDisposableStack.prototype[Symbol.asyncDispose] === AsyncDisposableStack.prototype.disposeAsync",
+        ),
+        TestAction::assert_native_error(
+            "stack.defer(() => {})",
+            JsNativeErrorKind::Reference,
+            "AsyncDisposableStack.prototype.defer: the stack is already disposed",
+        ),
+    ]);
+}
@@ -123,6 +123,9 @@ impl BuiltInConstructor for AggregateError {
         )
         .expect("should not fail according to spec");
 
+        // Non-standard: install the `stack` property of the error.
+        Error::install_error_stack(&o, &Self::NAME, context);
+
         // 5. Return O.
         Ok(o.into())
     }
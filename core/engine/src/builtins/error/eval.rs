@@ -100,6 +100,9 @@ impl BuiltInConstructor for EvalError {
         // 4. Perform ? InstallErrorCause(O, options).
         Error::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        // Non-standard: install the `stack` property of the error.
+        Error::install_error_stack(&o, &Self::NAME, context);
+
         // 5. Return O.
         Ok(o.into())
     }
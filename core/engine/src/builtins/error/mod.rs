@@ -13,7 +13,7 @@
 use crate::{
     builtins::BuiltInObject,
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
-    error::JsNativeError,
+    error::{format_stack, JsNativeError},
     js_string,
     object::{internal_methods::get_prototype_from_constructor, JsObject},
     property::Attribute,
@@ -28,6 +28,7 @@ pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
+pub(crate) mod suppressed;
 pub(crate) mod syntax;
 pub(crate) mod r#type;
 pub(crate) mod uri;
@@ -40,6 +41,7 @@ pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
 pub(crate) use self::reference::ReferenceError;
+pub(crate) use self::suppressed::SuppressedError;
 pub(crate) use self::syntax::SyntaxError;
 pub(crate) use self::uri::UriError;
 
@@ -117,6 +119,14 @@ pub enum ErrorObject {
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-syntaxerror
     Syntax,
 
+    /// The `SuppressedError` type.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed,
+
     /// The `URIError` type.
     ///
     /// More information:
@@ -197,12 +207,44 @@ impl BuiltInConstructor for Error {
         // 4. Perform ? InstallErrorCause(O, options).
         Self::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        // Non-standard: install the `stack` property of the error.
+        Self::install_error_stack(&o, &Self::NAME, context);
+
         // 5. Return O.
         Ok(o.into())
     }
 }
 
 impl Error {
+    /// Defines the non-standard `stack` property of the newly created error object `o`.
+    ///
+    /// The stack has the format used by V8: a `name: message` header, using the own `message`
+    /// property of `o`, followed by a line for each frame of the current call stack.
+    pub(crate) fn install_error_stack(o: &JsObject, name: &JsString, context: &mut Context) {
+        let message = o
+            .borrow()
+            .properties()
+            .get(&utf16!("message").into())
+            .and_then(|desc| desc.value().and_then(JsValue::as_string).cloned())
+            .filter(|message| !message.is_empty());
+        let header = match message {
+            Some(message) => format!(
+                "{}: {}",
+                name.to_std_string_escaped(),
+                message.to_std_string_escaped()
+            ),
+            None => name.to_std_string_escaped(),
+        };
+
+        let stack = context.capture_stack();
+        o.create_non_enumerable_data_property_or_throw(
+            utf16!("stack"),
+            format_stack(&header, &stack),
+            context,
+        );
+        context.set_error_stack(o, stack);
+    }
+
     pub(crate) fn install_error_cause(
         o: &JsObject,
         options: &JsValue,
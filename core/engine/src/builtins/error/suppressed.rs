@@ -0,0 +1,119 @@
+//! This module implements the global `SuppressedError` object.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [Explicit Resource Management proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::{common::StaticJsStrings, utf16},
+    Context, JsArgs, JsResult, JsString, JsValue,
+};
+use boa_profiler::Profiler;
+
+use super::{Error, ErrorObject};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SuppressedError;
+
+impl IntrinsicObject for SuppressedError {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .prototype(realm.intrinsics().constructors().error().constructor())
+            .inherits(Some(realm.intrinsics().constructors().error().prototype()))
+            .property(utf16!("name"), Self::NAME, attribute)
+            .property(utf16!("message"), js_string!(), attribute)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for SuppressedError {
+    const NAME: JsString = StaticJsStrings::SUPPRESSED_ERROR;
+}
+
+impl BuiltInConstructor for SuppressedError {
+    const LENGTH: usize = 3;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::suppressed_error;
+
+    /// Create a new suppressed error object.
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object; else let newTarget be NewTarget.
+        let new_target = &if new_target.is_undefined() {
+            context
+                .active_function_object()
+                .unwrap_or_else(|| {
+                    context
+                        .intrinsics()
+                        .constructors()
+                        .suppressed_error()
+                        .constructor()
+                })
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%SuppressedError.prototype%", « [[ErrorData]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::suppressed_error,
+            context,
+        )?;
+        let o = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ErrorObject::Suppressed,
+        );
+
+        // 3. If message is not undefined, then
+        let message = args.get_or_undefined(2);
+        if !message.is_undefined() {
+            // a. Let msg be ? ToString(message).
+            let msg = message.to_string(context)?;
+
+            // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
+            o.create_non_enumerable_data_property_or_throw(utf16!("message"), msg, context);
+        }
+
+        // 4. Perform CreateNonEnumerableDataPropertyOrThrow(O, "error", error).
+        o.create_non_enumerable_data_property_or_throw(
+            utf16!("error"),
+            args.get_or_undefined(0).clone(),
+            context,
+        );
+
+        // 5. Perform CreateNonEnumerableDataPropertyOrThrow(O, "suppressed", suppressed).
+        o.create_non_enumerable_data_property_or_throw(
+            utf16!("suppressed"),
+            args.get_or_undefined(1).clone(),
+            context,
+        );
+
+        // Non-standard: install the `stack` property of the error.
+        Error::install_error_stack(&o, &Self::NAME, context);
+
+        // 6. Return O.
+        Ok(o.into())
+    }
+}
@@ -1,5 +1,7 @@
-use crate::{js_string, run_test_actions, TestAction};
+use crate::{js_string, run_test_actions, Source, TestAction};
+use boa_ast::Position;
 use indoc::indoc;
+use std::path::Path;
 
 #[test]
 fn error_to_string() {
@@ -76,3 +78,89 @@ fn error_lengths() {
         TestAction::assert_eq("AggregateError.length", 2),
     ]);
 }
+
+#[test]
+fn error_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function make(message) {
+                return new TypeError(message);
+            }
+            function outer() {
+                return make("oops");
+            }
+            var error = outer();
+        "#}),
+        TestAction::assert_eq(
+            "error.stack",
+            js_string!(
+                "TypeError: oops\n    at make (<anonymous>:2:12)\n    at outer (<anonymous>:5:12)\n    at <anonymous>:7:13"
+            ),
+        ),
+        TestAction::assert(
+            "!Object.getOwnPropertyDescriptor(error, 'stack').enumerable",
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                try {
+                    null.x;
+                } catch (e) {
+                    e.stack
+                }
+            "#},
+            js_string!("TypeError: cannot convert 'null' or 'undefined' to object\n    at <anonymous>:2:5"),
+        ),
+        TestAction::assert_eq("new Error().stack", js_string!("Error\n    at <anonymous>:1:1")),
+    ]);
+}
+
+#[test]
+fn error_stack_frames() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let error = context
+            .eval(Source::from_bytes(indoc! {r#"
+                function thrower() {
+                    throw new Error();
+                }
+                (function () {
+                    thrower();
+                })();
+            "#}))
+            .unwrap_err();
+
+        let frames = error.stack_frames();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].function_name(), "thrower");
+        assert_eq!(frames[0].path(), None);
+        assert_eq!(frames[0].position(), Some(Position::new(2, 11)));
+        assert_eq!(frames[1].function_name(), "");
+        assert_eq!(frames[1].position(), Some(Position::new(5, 5)));
+        assert_eq!(frames[2].function_name(), "<main>");
+        assert_eq!(frames[2].to_string(), "<anonymous>:4:1");
+
+        // Values that are not `Error` objects don't capture a stack.
+        let error = context
+            .eval(Source::from_bytes("function f() { throw 1; }\nf();"))
+            .unwrap_err();
+        assert!(error.stack_frames().is_empty());
+    })]);
+}
+
+#[test]
+fn error_stack_frames_path() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let source = "function thrower() {\n  throw new Error();\n}\nthrower();";
+        let error = context
+            .eval(Source::from_reader(
+                source.as_bytes(),
+                Some(Path::new("script.js")),
+            ))
+            .unwrap_err();
+
+        let frames = error.stack_frames();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].path(), Some(Path::new("script.js")));
+        assert_eq!(frames[0].to_string(), "thrower (script.js:2:9)");
+        assert_eq!(frames[1].to_string(), "script.js:4:1");
+    })]);
+}
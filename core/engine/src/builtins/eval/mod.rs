@@ -22,7 +22,10 @@ use crate::{
     vm::{CallFrame, CallFrameFlags, Opcode},
     Context, JsArgs, JsResult, JsString, JsValue,
 };
-use boa_ast::operations::{contains, contains_arguments, ContainsSymbol};
+use boa_ast::{
+    operations::{contains, contains_arguments, ContainsSymbol},
+    Script,
+};
 use boa_gc::Gc;
 use boa_parser::{Parser, Source};
 use boa_profiler::Profiler;
@@ -86,14 +89,6 @@ impl Eval {
             }
         }
 
-        /// Possible actions that can be executed after exiting this function to restore the environment to its
-        /// original state.
-        #[derive(Debug)]
-        enum EnvStackAction {
-            Truncate(usize),
-            Restore(Vec<Environment>),
-        }
-
         // 1. Assert: If direct is false, then strictCaller is also false.
         debug_assert!(direct || !strict);
 
@@ -192,6 +187,30 @@ impl Eval {
 
         strict |= body.strict();
 
+        Self::evaluate_body(&body, direct, strict, context)
+    }
+
+    /// Runs steps 12 to 33 of [`PerformEval`][spec], evaluating an already parsed and validated
+    /// eval `body` within the current realm.
+    ///
+    /// This is shared with `ShadowRealm.prototype.evaluate`, which has its own parsing and early error
+    /// rules but evaluates the resulting script like an indirect `eval` call.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn evaluate_body(
+        body: &Script,
+        direct: bool,
+        strict: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        /// Possible actions that can be executed after exiting this function to restore the environment to its
+        /// original state.
+        #[derive(Debug)]
+        enum EnvStackAction {
+            Truncate(usize),
+            Restore(Vec<Environment>),
+        }
+
         // Because our environment model does not map directly to the spec, this section looks very different.
         // 12 - 13 are implicit in the call of `Context::compile_with_new_declarative`.
         // 14 - 33 are in the following section, together with EvalDeclarationInstantiation.
@@ -246,7 +265,7 @@ impl Eval {
             compiler.variable_environment = lex_env.clone();
         }
 
-        compiler.eval_declaration_instantiation(&body, strict, &var_env, &lex_env)?;
+        compiler.eval_declaration_instantiation(body, strict, &var_env, &lex_env)?;
         compiler.compile_statement_list(body.statements(), true, false);
 
         let code_block = Gc::new(compiler.finish());
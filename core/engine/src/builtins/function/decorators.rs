@@ -0,0 +1,1056 @@
+//! Boa's implementation of the runtime semantics of ECMAScript decorators.
+//!
+//! Classes with decorators, or with auto-accessors, don't define their elements while they are
+//! being evaluated. Instead, each element is recorded in the [`ClassDecorations`] of the class,
+//! and the elements are decorated and defined once all of them have been evaluated.
+//!
+//! More information:
+//!  - [Proposal reference][spec]
+//!
+//! [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417
+
+use super::{set_function_name, ClassFieldDefinition, OrdinaryFunction};
+use crate::{
+    builtins::OrdinaryObject,
+    error::JsNativeError,
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsFunction, JsObject, PrivateElement, PrivateName},
+    property::{PropertyDescriptor, PropertyKey},
+    string::utf16,
+    symbol::JsSymbol,
+    Context, JsArgs, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use thin_vec::ThinVec;
+
+/// A list of initializers that `addInitializer` functions can append to.
+type InitializerList = Gc<GcRefCell<Vec<JsObject>>>;
+
+/// The kind of a class element definition.
+///
+/// This is encoded in the `kind` operand of the `PushClassElement` opcode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize, TryFromPrimitive, IntoPrimitive)]
+#[boa_gc(empty_trace)]
+#[repr(u8)]
+pub(crate) enum ClassElementKind {
+    /// A method.
+    Method,
+
+    /// A getter.
+    Getter,
+
+    /// A setter.
+    Setter,
+
+    /// A field.
+    Field,
+
+    /// An auto-accessor, declared with the `accessor` keyword.
+    Accessor,
+
+    /// A static initialization block.
+    StaticBlock,
+}
+
+impl ClassElementKind {
+    /// Returns the value of the `kind` property of the decorator context objects of this kind.
+    fn name(self) -> JsString {
+        match self {
+            Self::Method => js_string!("method"),
+            Self::Getter => js_string!("getter"),
+            Self::Setter => js_string!("setter"),
+            Self::Field => js_string!("field"),
+            Self::Accessor => js_string!("accessor"),
+            Self::StaticBlock => unreachable!("static blocks cannot be decorated"),
+        }
+    }
+}
+
+/// The name of a class element.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) enum ClassElementName {
+    /// A `string` or `symbol` property key.
+    PropertyKey(PropertyKey),
+
+    /// A private name.
+    PrivateName(PrivateName),
+}
+
+impl ClassElementName {
+    /// Returns the name used to set the `name` property of the functions of the element.
+    fn function_name(&self) -> PropertyKey {
+        match self {
+            Self::PropertyKey(key) => key.clone(),
+            Self::PrivateName(name) => js_string!(utf16!("#"), name.description()).into(),
+        }
+    }
+}
+
+/// The getter, setter and backing storage of an auto-accessor.
+#[derive(Debug, Trace, Finalize)]
+struct AutoAccessor {
+    getter: JsObject,
+    setter: JsObject,
+    storage: PrivateName,
+}
+
+/// A `ClassElementDefinition` Record, holding a class element until its decorators are applied.
+///
+/// More information:
+///  - [Proposal reference][spec]
+///
+/// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417#sec-classelementdefinition-record-specification-type
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct ClassElementDefinition {
+    kind: ClassElementKind,
+    is_static: bool,
+    name: ClassElementName,
+
+    /// The function of a method, getter or setter, the initializer of a field or auto-accessor,
+    /// or the body of a static block.
+    value: JsObject,
+
+    /// The functions of an auto-accessor.
+    accessor: Option<AutoAccessor>,
+
+    /// The `[[Decorators]]` of the element.
+    decorators: Vec<JsValue>,
+
+    /// The `[[Initializers]]` returned by the decorators of a field or auto-accessor.
+    initializers: ThinVec<JsObject>,
+
+    /// The `[[ExtraInitializers]]` added by the decorators of a field or auto-accessor.
+    extra_initializers: InitializerList,
+}
+
+impl ClassElementDefinition {
+    /// Creates a new class element definition.
+    ///
+    /// The function of the element gets its name and home object, and auto-accessors get their
+    /// getter and setter, which store the value of the accessor in the private field `storage`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        kind: ClassElementKind,
+        is_static: bool,
+        name: ClassElementName,
+        value: JsObject,
+        decorators: Vec<JsValue>,
+        home_object: &JsObject,
+        storage: PrivateName,
+        context: &mut Context,
+    ) -> Self {
+        let prefix = match kind {
+            ClassElementKind::Method => Some(None),
+            ClassElementKind::Getter => Some(Some(js_string!("get"))),
+            ClassElementKind::Setter => Some(Some(js_string!("set"))),
+            _ => None,
+        };
+        if let Some(prefix) = prefix {
+            set_function_name(&value, &name.function_name(), prefix, context);
+        }
+        if let Some(mut function) = value.downcast_mut::<OrdinaryFunction>() {
+            function.set_home_object(home_object.clone());
+        }
+
+        let accessor = (kind == ClassElementKind::Accessor)
+            .then(|| AutoAccessor::new(&name, storage, context));
+
+        Self {
+            kind,
+            is_static,
+            name,
+            value,
+            accessor,
+            decorators,
+            initializers: ThinVec::new(),
+            extra_initializers: Gc::new(GcRefCell::new(Vec::new())),
+        }
+    }
+
+    /// Returns `true` if the element is defined when the class is defined, instead of when the
+    /// class or its instances are initialized.
+    fn is_method_like(&self) -> bool {
+        matches!(
+            self.kind,
+            ClassElementKind::Method
+                | ClassElementKind::Getter
+                | ClassElementKind::Setter
+                | ClassElementKind::Accessor
+        )
+    }
+
+    /// Abstract operation `ApplyDecoratorsToElementDefinition ( homeObject, elementRecord, extraInitializers, isStatic )`
+    ///
+    /// More information:
+    ///  - [Proposal reference][spec]
+    ///
+    /// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417#sec-applydecoratorstoelementdefinition
+    fn apply_decorators(
+        &mut self,
+        extra_initializers: &InitializerList,
+        metadata: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. If elementRecord.[[Kind]] is field or accessor, the extra initializers are
+        //    elementRecord.[[ExtraInitializers]] instead of the extra initializers of the class.
+        let extra_initializers = if matches!(
+            self.kind,
+            ClassElementKind::Field | ClassElementKind::Accessor
+        ) {
+            self.extra_initializers.clone()
+        } else {
+            extra_initializers.clone()
+        };
+
+        // 2. For each element decorator of elementRecord.[[Decorators]], in reverse list order, do
+        let decorators = self.decorators.clone();
+        for decorator in decorators.iter().rev() {
+            // a. Let decorationState be the Record { [[Finished]]: false }.
+            let finished = Gc::new(GcRefCell::new(false));
+
+            // b. Let context be CreateDecoratorContextObject(elementRecord.[[Kind]], elementRecord.[[Key]], extraInitializers, decorationState, isStatic).
+            let decorator_context =
+                self.decorator_context(&extra_initializers, &finished, metadata, context);
+
+            // c. Let value be undefined, the element function, or an object with the functions
+            //    of the auto-accessor.
+            let value: JsValue = match (self.kind, &self.accessor) {
+                (ClassElementKind::Field, _) => JsValue::undefined(),
+                (ClassElementKind::Accessor, Some(accessor)) => {
+                    let value = JsObject::with_object_proto(context.intrinsics());
+                    value
+                        .create_data_property_or_throw(
+                            js_string!("get"),
+                            accessor.getter.clone(),
+                            context,
+                        )
+                        .expect("defining properties on a new object cannot fail");
+                    value
+                        .create_data_property_or_throw(
+                            js_string!("set"),
+                            accessor.setter.clone(),
+                            context,
+                        )
+                        .expect("defining properties on a new object cannot fail");
+                    value.into()
+                }
+                _ => self.value.clone().into(),
+            };
+
+            // d. Let newValue be ? Call(decorator, undefined, « value, context »).
+            let new_value = decorator.call(
+                &JsValue::undefined(),
+                &[value, decorator_context.into()],
+                context,
+            )?;
+
+            // e. Set decorationState.[[Finished]] to true.
+            *finished.borrow_mut() = true;
+
+            match self.kind {
+                // f. If elementRecord.[[Kind]] is field, then
+                ClassElementKind::Field => {
+                    // i. If IsCallable(newValue) is true, append newValue to elementRecord.[[Initializers]].
+                    if let Some(initializer) = new_value.as_callable() {
+                        self.initializers.push(initializer.clone());
+                    }
+                    // ii. Else if newValue is not undefined, throw a TypeError exception.
+                    else if !new_value.is_undefined() {
+                        return Err(JsNativeError::typ()
+                            .with_message("field decorators must return a function or undefined")
+                            .into());
+                    }
+                }
+                // g. Else if elementRecord.[[Kind]] is accessor, then
+                ClassElementKind::Accessor => {
+                    // i. If newValue is an Object, then
+                    if let Some(new_value) = new_value.as_object() {
+                        let accessor = self
+                            .accessor
+                            .as_mut()
+                            .expect("auto-accessors must have an accessor");
+
+                        // 1. Let newGetter be ? Get(newValue, "get").
+                        // 2. If IsCallable(newGetter) is true, set elementRecord.[[Get]] to newGetter.
+                        // 3. Else if newGetter is not undefined, throw a TypeError exception.
+                        if let Some(getter) = callable_property(new_value, "get", context)? {
+                            accessor.getter = getter;
+                        }
+
+                        // 4. Let newSetter be ? Get(newValue, "set").
+                        // 5. If IsCallable(newSetter) is true, set elementRecord.[[Set]] to newSetter.
+                        // 6. Else if newSetter is not undefined, throw a TypeError exception.
+                        if let Some(setter) = callable_property(new_value, "set", context)? {
+                            accessor.setter = setter;
+                        }
+
+                        // 7. Let initializer be ? Get(newValue, "init").
+                        // 8. If IsCallable(initializer) is true, append initializer to elementRecord.[[Initializers]].
+                        // 9. Else if initializer is not undefined, throw a TypeError exception.
+                        if let Some(initializer) = callable_property(new_value, "init", context)? {
+                            self.initializers.push(initializer);
+                        }
+                    }
+                    // ii. Else if newValue is not undefined, throw a TypeError exception.
+                    else if !new_value.is_undefined() {
+                        return Err(JsNativeError::typ()
+                            .with_message("accessor decorators must return an object or undefined")
+                            .into());
+                    }
+                }
+                // h. Else,
+                _ => {
+                    // i. If IsCallable(newValue) is true, replace the element function with newValue.
+                    if let Some(new_value) = new_value.as_callable() {
+                        self.value = new_value.clone();
+                    }
+                    // ii. Else if newValue is not undefined, throw a TypeError exception.
+                    else if !new_value.is_undefined() {
+                        return Err(JsNativeError::typ()
+                            .with_message("method decorators must return a function or undefined")
+                            .into());
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Abstract operation `CreateDecoratorContextObject ( kind, name, initializers, decorationState [ , isStatic ] )`
+    ///
+    /// Creates the context object of a class element decorator.
+    ///
+    /// More information:
+    ///  - [Proposal reference][spec]
+    ///
+    /// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417#sec-createdecoratorcontextobject
+    fn decorator_context(
+        &self,
+        initializers: &InitializerList,
+        finished: &Gc<GcRefCell<bool>>,
+        metadata: &JsObject,
+        context: &mut Context,
+    ) -> JsObject {
+        // 1. Let contextObj be OrdinaryObjectCreate(%Object.prototype%).
+        let object = JsObject::with_object_proto(context.intrinsics());
+
+        // 2-8. Perform ! CreateDataPropertyOrThrow(contextObj, "kind", kindStr).
+        let mut properties: Vec<(JsString, JsValue)> =
+            vec![(js_string!("kind"), self.kind.name().into())];
+
+        // 9. If kind is not class, then
+        // a. Perform ! CreateDataPropertyOrThrow(contextObj, "access", CreateDecoratorAccessObject(kind, name)).
+        properties.push((
+            js_string!("access"),
+            create_access_object(self.kind, &self.name, context).into(),
+        ));
+
+        // b. If isStatic is present, perform ! CreateDataPropertyOrThrow(contextObj, "static", isStatic).
+        properties.push((js_string!("static"), self.is_static.into()));
+
+        match &self.name {
+            // c. If name is a Private Name, then
+            ClassElementName::PrivateName(name) => {
+                // i. Perform ! CreateDataPropertyOrThrow(contextObj, "private", true).
+                // ii. Perform ! CreateDataPropertyOrThrow(contextObj, "name", name.[[Description]]).
+                properties.push((js_string!("private"), true.into()));
+                properties.push((
+                    js_string!("name"),
+                    js_string!(utf16!("#"), name.description()).into(),
+                ));
+            }
+            // d. Else,
+            ClassElementName::PropertyKey(key) => {
+                // i. Perform ! CreateDataPropertyOrThrow(contextObj, "private", false).
+                // ii. Perform ! CreateDataPropertyOrThrow(contextObj, "name", name).
+                properties.push((js_string!("private"), false.into()));
+                properties.push((js_string!("name"), key.clone().into()));
+            }
+        }
+
+        // 11. Let addInitializer be CreateAddInitializerFunction(initializers, decorationState).
+        // 12. Perform ! CreateDataPropertyOrThrow(contextObj, "addInitializer", addInitializer).
+        properties.push((
+            js_string!("addInitializer"),
+            create_add_initializer_function(initializers, finished, context).into(),
+        ));
+
+        // 13. Perform ! CreateDataPropertyOrThrow(contextObj, "metadata", metadata).
+        properties.push((js_string!("metadata"), metadata.clone().into()));
+
+        for (key, value) in properties {
+            object
+                .create_data_property_or_throw(key, value, context)
+                .expect("defining properties on a new object cannot fail");
+        }
+
+        // 14. Return contextObj.
+        object
+    }
+
+    /// Returns the field definition of a field or the backing storage of an auto-accessor.
+    fn field_definition(&self) -> ClassFieldDefinition {
+        let initializer = JsFunction::from_object_unchecked(self.value.clone());
+        let field = match (&self.accessor, &self.name) {
+            (Some(accessor), _) => {
+                ClassFieldDefinition::Private(accessor.storage.clone(), initializer)
+            }
+            (None, ClassElementName::PrivateName(name)) => {
+                ClassFieldDefinition::Private(name.clone(), initializer)
+            }
+            (None, ClassElementName::PropertyKey(key)) => {
+                ClassFieldDefinition::Public(key.clone(), initializer)
+            }
+        };
+
+        let extra_initializers = self.extra_initializers.borrow();
+        if self.initializers.is_empty() && extra_initializers.is_empty() {
+            return field;
+        }
+
+        ClassFieldDefinition::Decorated {
+            field: Box::new(field),
+            initializers: self.initializers.clone(),
+            extra_initializers: extra_initializers.iter().cloned().collect(),
+        }
+    }
+
+    /// Defines a method, getter, setter or auto-accessor on its home object.
+    ///
+    /// Instance private methods are added to the `[[PrivateMethods]]` of `class` instead.
+    fn define_method(
+        &self,
+        class: &JsObject,
+        prototype: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let home_object = if self.is_static { class } else { prototype };
+
+        match &self.name {
+            ClassElementName::PropertyKey(key) => {
+                let desc = match (self.kind, &self.accessor) {
+                    (ClassElementKind::Method, _) => PropertyDescriptor::builder()
+                        .value(self.value.clone())
+                        .writable(true),
+                    (ClassElementKind::Getter, _) => {
+                        PropertyDescriptor::builder().get(self.value.clone())
+                    }
+                    (ClassElementKind::Setter, _) => {
+                        PropertyDescriptor::builder().set(self.value.clone())
+                    }
+                    (_, Some(accessor)) => PropertyDescriptor::builder()
+                        .get(accessor.getter.clone())
+                        .set(accessor.setter.clone()),
+                    _ => unreachable!("only methods and accessors are defined as methods"),
+                };
+                home_object.define_property_or_throw(
+                    key.clone(),
+                    desc.enumerable(false).configurable(true),
+                    context,
+                )?;
+            }
+            ClassElementName::PrivateName(name) => {
+                let element = match (self.kind, &self.accessor) {
+                    (ClassElementKind::Method, _) => PrivateElement::Method(self.value.clone()),
+                    (ClassElementKind::Getter, _) => PrivateElement::Accessor {
+                        getter: Some(self.value.clone()),
+                        setter: None,
+                    },
+                    (ClassElementKind::Setter, _) => PrivateElement::Accessor {
+                        getter: None,
+                        setter: Some(self.value.clone()),
+                    },
+                    (_, Some(accessor)) => PrivateElement::Accessor {
+                        getter: Some(accessor.getter.clone()),
+                        setter: Some(accessor.setter.clone()),
+                    },
+                    _ => unreachable!("only methods and accessors are defined as methods"),
+                };
+
+                if self.is_static {
+                    class
+                        .borrow_mut()
+                        .append_private_element(name.clone(), element);
+                } else {
+                    class
+                        .downcast_mut::<OrdinaryFunction>()
+                        .expect("class must be function object")
+                        .push_private_method(name.clone(), element);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl AutoAccessor {
+    /// Creates the getter and setter of an auto-accessor named `name`, storing its value in the
+    /// private field `storage`.
+    ///
+    /// More information:
+    ///  - [Proposal reference][spec]
+    ///
+    /// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417#sec-makeautoaccessorgetter
+    fn new(name: &ClassElementName, storage: PrivateName, context: &mut Context) -> Self {
+        let getter = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, _, storage, context| {
+                    let object = this.as_object().ok_or_else(|| {
+                        JsNativeError::typ().with_message("accessor receiver must be an object")
+                    })?;
+                    object.private_get(storage, context)
+                },
+                storage.clone(),
+            ),
+        )
+        .build();
+        set_function_name(
+            &getter,
+            &name.function_name(),
+            Some(js_string!("get")),
+            context,
+        );
+
+        let setter = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, args, storage, context| {
+                    let object = this.as_object().ok_or_else(|| {
+                        JsNativeError::typ().with_message("accessor receiver must be an object")
+                    })?;
+                    object.private_set(storage, args.get_or_undefined(0).clone(), context)?;
+                    Ok(JsValue::undefined())
+                },
+                storage.clone(),
+            ),
+        )
+        .length(1)
+        .build();
+        set_function_name(
+            &setter,
+            &name.function_name(),
+            Some(js_string!("set")),
+            context,
+        );
+
+        Self {
+            getter: getter.into(),
+            setter: setter.into(),
+            storage,
+        }
+    }
+}
+
+/// A static element of a decorated class, initialized after the class is defined.
+#[derive(Debug, Trace, Finalize)]
+enum StaticElement {
+    /// A static field or the backing storage of a static auto-accessor.
+    Field(ClassFieldDefinition),
+
+    /// A static initialization block.
+    Block(JsObject),
+}
+
+/// The decoration state of a class whose elements have not been defined yet.
+///
+/// This lives in the class constructor while the class is being defined.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct ClassDecorations {
+    /// The decorators of the class.
+    decorators: Vec<JsValue>,
+
+    /// The metadata object of the class.
+    metadata: JsObject,
+
+    /// The evaluated elements of the class, in document order.
+    elements: Vec<ClassElementDefinition>,
+
+    /// The static fields and blocks of the class, once the elements are defined.
+    static_elements: Vec<StaticElement>,
+
+    /// The extra initializers added by the decorators of static methods.
+    static_extra_initializers: Vec<JsObject>,
+
+    /// The extra initializers added by the decorators of the class.
+    class_extra_initializers: InitializerList,
+}
+
+impl ClassDecorations {
+    /// Creates the decoration state of `class`, along with its metadata object.
+    pub(crate) fn new(
+        decorators: Vec<JsValue>,
+        class: &JsObject,
+        prototype: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let is_derived = class
+            .downcast_ref::<OrdinaryFunction>()
+            .expect("class must be function object")
+            .is_derived_constructor();
+
+        // 8. If ClassHeritage is not present, then
+        //     c. Let metadataParent be null.
+        // 9. Else,
+        //     f. If superclass is null, then
+        //         iii. Let metadataParent be null.
+        //     g. Else,
+        //         iv. Let metadataParent be ? Get(superclass, @@metadata).
+        //         v. If metadataParent is not an Object, set metadataParent to null.
+        let mut parent = None;
+        if is_derived && prototype.prototype().is_some() {
+            if let Some(superclass) = class.prototype() {
+                parent = superclass
+                    .get(JsSymbol::metadata(), context)?
+                    .as_object()
+                    .cloned();
+            }
+        }
+
+        // 11. Let metadata be OrdinaryObjectCreate(metadataParent).
+        let metadata = JsObject::from_proto_and_data(parent, OrdinaryObject);
+
+        Ok(Self {
+            decorators,
+            metadata,
+            elements: Vec::new(),
+            static_elements: Vec::new(),
+            static_extra_initializers: Vec::new(),
+            class_extra_initializers: Gc::new(GcRefCell::new(Vec::new())),
+        })
+    }
+
+    /// Returns the number of elements of the class.
+    pub(crate) fn element_count(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Records an evaluated element of the class.
+    pub(crate) fn push_element(&mut self, element: ClassElementDefinition) {
+        self.elements.push(element);
+    }
+
+    /// Returns `true` if the class or any of its elements are decorated.
+    fn is_decorated(&self) -> bool {
+        !self.decorators.is_empty()
+            || self
+                .elements
+                .iter()
+                .any(|element| !element.decorators.is_empty())
+    }
+}
+
+/// Applies the decorators of `class` and its elements, and defines its methods and fields.
+///
+/// Returns the class returned by the class decorators, which must be initialized with
+/// [`initialize_decorated_class`] once the class binding is initialized.
+///
+/// More information:
+///  - [Proposal reference][spec]
+///
+/// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417#sec-runtime-semantics-classdefinitionevaluation
+pub(crate) fn apply_class_decorators(
+    class: &JsObject,
+    prototype: &JsObject,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    let mut decorations = class
+        .downcast_mut::<OrdinaryFunction>()
+        .expect("class must be function object")
+        .class_decorations
+        .take()
+        .expect("decorated class must have a decoration state");
+    let metadata = decorations.metadata.clone();
+
+    let static_extra_initializers: InitializerList = Gc::new(GcRefCell::new(Vec::new()));
+    let instance_extra_initializers: InitializerList = Gc::new(GcRefCell::new(Vec::new()));
+
+    // 27. For each element e of staticElements, if e is a method, getter, setter or accessor,
+    //     perform ? ApplyDecoratorsAndDefineMethod(F, e, staticExtraInitializers, true).
+    // 28. For each element e of instanceElements, if e is a method, getter, setter or accessor,
+    //     perform ? ApplyDecoratorsAndDefineMethod(proto, e, instanceExtraInitializers, false).
+    for is_static in [true, false] {
+        let extra_initializers = if is_static {
+            &static_extra_initializers
+        } else {
+            &instance_extra_initializers
+        };
+        for element in &mut decorations.elements {
+            if element.is_static == is_static && element.is_method_like() {
+                element.apply_decorators(extra_initializers, &metadata, context)?;
+                element.define_method(class, prototype, context)?;
+            }
+        }
+    }
+
+    // 29. For each element e of staticElements, if e is a field,
+    //     perform ? ApplyDecoratorsToElementDefinition(F, e, e.[[ExtraInitializers]], true).
+    // 30. For each element e of instanceElements, if e is a field,
+    //     perform ? ApplyDecoratorsToElementDefinition(proto, e, e.[[ExtraInitializers]], false).
+    for is_static in [true, false] {
+        for element in &mut decorations.elements {
+            if element.is_static == is_static && element.kind == ClassElementKind::Field {
+                element.apply_decorators(&static_extra_initializers, &metadata, context)?;
+            }
+        }
+    }
+
+    let mut static_elements = Vec::new();
+    {
+        let mut function = class
+            .downcast_mut::<OrdinaryFunction>()
+            .expect("class must be function object");
+        for element in &decorations.elements {
+            match (element.kind, element.is_static) {
+                (ClassElementKind::StaticBlock, _) => {
+                    static_elements.push(StaticElement::Block(element.value.clone()));
+                }
+                (ClassElementKind::Field | ClassElementKind::Accessor, true) => {
+                    static_elements.push(StaticElement::Field(element.field_definition()));
+                }
+                // 31. Set F.[[Fields]] to instanceFields.
+                (ClassElementKind::Field | ClassElementKind::Accessor, false) => {
+                    function.push_field_definition(element.field_definition());
+                }
+                _ => {}
+            }
+        }
+
+        // 32. Set F.[[Initializers]] to instanceExtraInitializers.
+        for initializer in instance_extra_initializers.borrow().iter() {
+            function.push_initializer(initializer.clone());
+        }
+    }
+
+    // 33. Perform ! DefinePropertyOrThrow(F, @@metadata, PropertyDescriptor { [[Value]]: metadata,
+    //     [[Writable]]: true, [[Enumerable]]: true, [[Configurable]]: true }).
+    if decorations.is_decorated() {
+        class.define_property_or_throw(
+            JsSymbol::metadata(),
+            PropertyDescriptor::builder()
+                .value(metadata.clone())
+                .writable(true)
+                .enumerable(true)
+                .configurable(true),
+            context,
+        )?;
+    }
+
+    // 34. Let newF be ? ApplyDecoratorsToClassDefinition(F, className, classDecorators, classExtraInitializers, metadata).
+    let mut new_class = class.clone();
+    let name = class
+        .downcast_ref::<OrdinaryFunction>()
+        .expect("class must be function object")
+        .code
+        .name()
+        .clone();
+    for decorator in decorations.decorators.iter().rev() {
+        let finished = Gc::new(GcRefCell::new(false));
+        let decorator_context = class_decorator_context(
+            name.clone(),
+            &decorations.class_extra_initializers,
+            &finished,
+            &metadata,
+            context,
+        );
+        let new_value = decorator.call(
+            &JsValue::undefined(),
+            &[new_class.clone().into(), decorator_context.into()],
+            context,
+        )?;
+        *finished.borrow_mut() = true;
+
+        if let Some(new_value) = new_value.as_callable() {
+            new_class = new_value.clone();
+        } else if !new_value.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("class decorators must return a function or undefined")
+                .into());
+        }
+    }
+
+    decorations.elements.clear();
+    decorations.static_elements = static_elements;
+    decorations
+        .static_extra_initializers
+        .clone_from(&static_extra_initializers.borrow());
+    class
+        .downcast_mut::<OrdinaryFunction>()
+        .expect("class must be function object")
+        .class_decorations = Some(decorations);
+
+    Ok(new_class)
+}
+
+/// Runs the static initializers of a decorated `class`, after its class binding has been
+/// initialized with the `new_class` returned by its class decorators.
+///
+/// More information:
+///  - [Proposal reference][spec]
+///
+/// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417#sec-runtime-semantics-classdefinitionevaluation
+pub(crate) fn initialize_decorated_class(
+    class: &JsObject,
+    new_class: &JsObject,
+    context: &mut Context,
+) -> JsResult<()> {
+    let decorations = class
+        .downcast_mut::<OrdinaryFunction>()
+        .expect("class must be function object")
+        .class_decorations
+        .take()
+        .expect("decorated class must have a decoration state");
+    let this = class.clone().into();
+
+    // 38. For each element initializer of staticExtraInitializers, perform ? Call(initializer, F).
+    for initializer in &decorations.static_extra_initializers {
+        initializer.call(&this, &[], context)?;
+    }
+
+    // 39. For each element elementRecord of staticElements, do
+    for element in &decorations.static_elements {
+        match element {
+            // a. If elementRecord is a ClassFieldDefinition Record, perform ? DefineField(F, elementRecord).
+            StaticElement::Field(field) => class.define_field(field, context)?,
+            // b. Else, perform ? Call(elementRecord.[[BodyFunction]], F).
+            StaticElement::Block(body) => {
+                body.call(&this, &[], context)?;
+            }
+        }
+    }
+
+    // 40. For each element initializer of classExtraInitializers, perform ? Call(initializer, newF).
+    let class_extra_initializers = decorations.class_extra_initializers.borrow().clone();
+    for initializer in class_extra_initializers {
+        initializer.call(&new_class.clone().into(), &[], context)?;
+    }
+
+    Ok(())
+}
+
+/// Creates the context object of a class decorator.
+///
+/// More information:
+///  - [Proposal reference][spec]
+///
+/// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417#sec-createdecoratorcontextobject
+fn class_decorator_context(
+    name: JsString,
+    initializers: &InitializerList,
+    finished: &Gc<GcRefCell<bool>>,
+    metadata: &JsObject,
+    context: &mut Context,
+) -> JsObject {
+    let object = JsObject::with_object_proto(context.intrinsics());
+    let add_initializer = create_add_initializer_function(initializers, finished, context);
+
+    let properties: [(JsString, JsValue); 4] = [
+        (js_string!("kind"), js_string!("class").into()),
+        (js_string!("name"), name.into()),
+        (js_string!("addInitializer"), add_initializer.into()),
+        (js_string!("metadata"), metadata.clone().into()),
+    ];
+    for (key, value) in properties {
+        object
+            .create_data_property_or_throw(key, value, context)
+            .expect("defining properties on a new object cannot fail");
+    }
+
+    object
+}
+
+/// Abstract operation `CreateDecoratorAccessObject ( kind, name )`
+///
+/// More information:
+///  - [Proposal reference][spec]
+///
+/// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417#sec-createdecoratoraccessobject
+fn create_access_object(
+    kind: ClassElementKind,
+    name: &ClassElementName,
+    context: &mut Context,
+) -> JsObject {
+    fn target(value: &JsValue) -> JsResult<&JsObject> {
+        value.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("decorator access target must be an object")
+                .into()
+        })
+    }
+
+    // 1. Let accessObj be OrdinaryObjectCreate(%Object.prototype%).
+    let access = JsObject::with_object_proto(context.intrinsics());
+
+    // 2. If kind is field, method, accessor, or getter, then
+    if matches!(
+        kind,
+        ClassElementKind::Field
+            | ClassElementKind::Method
+            | ClassElementKind::Accessor
+            | ClassElementKind::Getter
+    ) {
+        // a. Let getterClosure be a new Abstract Closure with parameters (obj) that captures name and performs the following steps when called:
+        //     i. If name is a Private Name, return ? PrivateGet(obj, name).
+        //     ii. Else, return ? Get(obj, name).
+        // b. Let getter be CreateBuiltinFunction(getterClosure, 1, "get", « »).
+        let get = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, name, context| {
+                    let object = target(args.get_or_undefined(0))?;
+                    match name {
+                        ClassElementName::PropertyKey(key) => object.get(key.clone(), context),
+                        ClassElementName::PrivateName(name) => object.private_get(name, context),
+                    }
+                },
+                name.clone(),
+            ),
+        )
+        .name(js_string!("get"))
+        .length(1)
+        .build();
+
+        // c. Perform ! CreateDataPropertyOrThrow(accessObj, "get", getter).
+        access
+            .create_data_property_or_throw(js_string!("get"), get, context)
+            .expect("defining properties on a new object cannot fail");
+    }
+
+    // 3. If kind is field, accessor, or setter, then
+    if matches!(
+        kind,
+        ClassElementKind::Field | ClassElementKind::Accessor | ClassElementKind::Setter
+    ) {
+        // a. Let setterClosure be a new Abstract Closure with parameters (obj, value) that captures name and performs the following steps when called:
+        //     i. If name is a Private Name, perform ? PrivateSet(obj, name, value).
+        //     ii. Else, perform ? Set(obj, name, value, true).
+        //     iii. Return undefined.
+        // b. Let setter be CreateBuiltinFunction(setterClosure, 2, "set", « »).
+        let set = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, name, context| {
+                    let object = target(args.get_or_undefined(0))?;
+                    let value = args.get_or_undefined(1).clone();
+                    match name {
+                        ClassElementName::PropertyKey(key) => {
+                            object.set(key.clone(), value, true, context)?;
+                        }
+                        ClassElementName::PrivateName(name) => {
+                            object.private_set(name, value, context)?;
+                        }
+                    }
+                    Ok(JsValue::undefined())
+                },
+                name.clone(),
+            ),
+        )
+        .name(js_string!("set"))
+        .length(2)
+        .build();
+
+        // c. Perform ! CreateDataPropertyOrThrow(accessObj, "set", setter).
+        access
+            .create_data_property_or_throw(js_string!("set"), set, context)
+            .expect("defining properties on a new object cannot fail");
+    }
+
+    // 4. Let hasClosure be a new Abstract Closure with parameters (obj) that captures name and performs the following steps when called:
+    //     a. If name is a Private Name, return PrivateElementFind(obj, name) is not empty.
+    //     b. Else, return ? HasProperty(obj, name).
+    // 5. Let has be CreateBuiltinFunction(hasClosure, 1, "has", « »).
+    let has = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, name, context| {
+                let object = target(args.get_or_undefined(0))?;
+                match name {
+                    ClassElementName::PropertyKey(key) => {
+                        Ok(object.has_property(key.clone(), context)?.into())
+                    }
+                    ClassElementName::PrivateName(name) => Ok(object
+                        .private_element_find(name, true, true)
+                        .is_some()
+                        .into()),
+                }
+            },
+            name.clone(),
+        ),
+    )
+    .name(js_string!("has"))
+    .length(1)
+    .build();
+
+    // 6. Perform ! CreateDataPropertyOrThrow(accessObj, "has", has).
+    access
+        .create_data_property_or_throw(js_string!("has"), has, context)
+        .expect("defining properties on a new object cannot fail");
+
+    // 7. Return accessObj.
+    access
+}
+
+/// Abstract operation `CreateAddInitializerFunction ( initializers, decorationState )`
+///
+/// More information:
+///  - [Proposal reference][spec]
+///
+/// [spec]: https://arai-a.github.io/ecma262-compare/?pr=2417#sec-createaddinitializerfunction
+fn create_add_initializer_function(
+    initializers: &InitializerList,
+    finished: &Gc<GcRefCell<bool>>,
+    context: &mut Context,
+) -> JsFunction {
+    FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, (initializers, finished), _| {
+                // a. If decorationState.[[Finished]] is true, throw a TypeError exception.
+                if *finished.borrow() {
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot add initializers after decoration has finished")
+                        .into());
+                }
+
+                // b. If IsCallable(initializer) is false, throw a TypeError exception.
+                let initializer = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+                    JsNativeError::typ().with_message("initializer must be a function")
+                })?;
+
+                // c. Append initializer to initializers.
+                initializers.borrow_mut().push(initializer.clone());
+
+                // d. Return unused.
+                Ok(JsValue::undefined())
+            },
+            (initializers.clone(), finished.clone()),
+        ),
+    )
+    .name(js_string!("addInitializer"))
+    .length(1)
+    .build()
+}
+
+/// Gets the property `key` of `object`, returning it if it is callable.
+///
+/// Throws a `TypeError` if the property is neither callable nor `undefined`.
+fn callable_property(
+    object: &JsObject,
+    key: &'static str,
+    context: &mut Context,
+) -> JsResult<Option<JsObject>> {
+    let value = object.get(js_string!(key), context)?;
+    if let Some(function) = value.as_callable() {
+        return Ok(Some(function.clone()));
+    }
+    if !value.is_undefined() {
+        return Err(JsNativeError::typ()
+            .with_message(format!("accessor decorator `{key}` must be a function"))
+            .into());
+    }
+    Ok(None)
+}
@@ -51,9 +51,12 @@ use super::Proxy;
 
 pub(crate) mod arguments;
 mod bound;
+pub(crate) mod decorators;
 
 pub use bound::BoundFunction;
 
+use decorators::ClassDecorations;
+
 #[cfg(test)]
 mod tests;
 
@@ -138,6 +141,19 @@ pub enum ClassFieldDefinition {
 
     /// A class field definition with a private name.
     Private(PrivateName, JsFunction),
+
+    /// A decorated class field definition, along with the `[[Initializers]]` and
+    /// `[[ExtraInitializers]]` added by its decorators.
+    Decorated {
+        /// The decorated field definition.
+        field: Box<ClassFieldDefinition>,
+
+        /// The initializers that transform the initial value of the field.
+        initializers: ThinVec<JsObject>,
+
+        /// The initializers that run after the field is defined.
+        extra_initializers: ThinVec<JsObject>,
+    },
 }
 
 unsafe impl Trace for ClassFieldDefinition {
@@ -149,6 +165,15 @@ unsafe impl Trace for ClassFieldDefinition {
             Self::Private(_, func) => {
                 mark(func);
             }
+            Self::Decorated {
+                field,
+                initializers,
+                extra_initializers,
+            } => {
+                mark(field);
+                mark(initializers);
+                mark(extra_initializers);
+            }
         }
     }}
 }
@@ -181,6 +206,12 @@ pub struct OrdinaryFunction {
 
     /// The `[[PrivateMethods]]` internal slot.
     private_methods: ThinVec<(PrivateName, PrivateElement)>,
+
+    /// The `[[Initializers]]` internal slot.
+    initializers: ThinVec<JsObject>,
+
+    /// The decoration state of a class, while its elements are being defined.
+    pub(crate) class_decorations: Option<Box<ClassDecorations>>,
 }
 
 impl JsData for OrdinaryFunction {
@@ -219,6 +250,8 @@ impl OrdinaryFunction {
             realm,
             fields: ThinVec::default(),
             private_methods: ThinVec::default(),
+            initializers: ThinVec::default(),
+            class_decorations: None,
         }
     }
 
@@ -268,6 +301,11 @@ impl OrdinaryFunction {
         self.fields.push(ClassFieldDefinition::Private(name, value));
     }
 
+    /// Pushes a field definition to the `[[Fields]]` internal slot.
+    pub(crate) fn push_field_definition(&mut self, field: ClassFieldDefinition) {
+        self.fields.push(field);
+    }
+
     /// Returns the values of the `[[PrivateMethods]]` internal slot.
     pub(crate) fn get_private_methods(&self) -> &[(PrivateName, PrivateElement)] {
         &self.private_methods
@@ -278,6 +316,16 @@ impl OrdinaryFunction {
         self.private_methods.push((name, method));
     }
 
+    /// Returns the values of the `[[Initializers]]` internal slot.
+    pub(crate) fn get_initializers(&self) -> &[JsObject] {
+        &self.initializers
+    }
+
+    /// Pushes an initializer to the `[[Initializers]]` internal slot.
+    pub(crate) fn push_initializer(&mut self, initializer: JsObject) {
+        self.initializers.push(initializer);
+    }
+
     /// Gets the `Realm` from where this function originates.
     #[must_use]
     pub const fn realm(&self) -> &Realm {
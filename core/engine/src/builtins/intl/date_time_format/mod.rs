@@ -0,0 +1,1389 @@
+//! This module implements the global `Intl.DateTimeFormat` object.
+//!
+//! `Intl.DateTimeFormat` is a built-in object that has properties and methods for date and time i18n.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_calendar::{any_calendar::AnyCalendarKind, types::Time, Date, DateTime, Iso};
+use icu_datetime::{
+    fields::FieldSymbol,
+    options::{length, preferences::HourCycle},
+    pattern::{runtime::Pattern, PatternItem},
+    provider::calendar::TimeLengthsV1Marker,
+    time_zone::TimeZoneFormatterOptions,
+    DateTimeFormatterOptions, ZonedDateTimeFormatter,
+};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{
+    extensions::unicode::{key, value, Key, Value},
+    Locale,
+};
+use icu_provider::DataLocale;
+use icu_timezone::CustomTimeZone;
+
+use crate::{
+    builtins::{
+        date::utils::{
+            date_from_time, hour_from_time, min_from_time, month_from_time, ms_from_time,
+            sec_from_time, time_clip, year_from_time,
+        },
+        options::get_option,
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    native_function::NativeFunction,
+    object::{
+        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsFunction,
+        JsObject, ObjectInitializer,
+    },
+    property::{Attribute, PropertyDescriptor},
+    realm::Realm,
+    string::{common::StaticJsStrings, utf16},
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales, validate_extension},
+    options::{coerce_options_to_object, IntlOptions},
+    Service,
+};
+
+mod options;
+mod pattern;
+mod time_zone;
+pub(crate) use options::*;
+pub(in crate::builtins::intl) use pattern::locale_hour_cycles;
+use pattern::Part;
+pub(crate) use time_zone::TimeZone;
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `Intl.DateTimeFormat` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct DateTimeFormat {
+    #[unsafe_ignore_trace]
+    locale: Locale,
+    #[unsafe_ignore_trace]
+    calendar: AnyCalendarKind,
+    #[unsafe_ignore_trace]
+    numbering_system: Option<Value>,
+    #[unsafe_ignore_trace]
+    time_zone: TimeZone,
+    #[unsafe_ignore_trace]
+    hour_cycle: Option<HourCycle>,
+    #[unsafe_ignore_trace]
+    format_options: FormatOptions,
+    #[unsafe_ignore_trace]
+    date_style: Option<Style>,
+    #[unsafe_ignore_trace]
+    time_style: Option<Style>,
+    #[unsafe_ignore_trace]
+    pattern: Pattern<'static>,
+    #[unsafe_ignore_trace]
+    formatter: ZonedDateTimeFormatter,
+    bound_format: Option<JsFunction>,
+}
+
+/// The `required` argument of the abstract operation [`CreateDateTimeFormat`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FormatType {
+    Date,
+    Time,
+    Any,
+}
+
+/// The `defaults` argument of the abstract operation [`CreateDateTimeFormat`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FormatDefaults {
+    Date,
+    Time,
+    All,
+}
+
+/// The wall-clock date and time fields of a value to format, in the ISO 8601 calendar.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct IsoDateTimeFields {
+    pub(crate) year: i32,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    pub(crate) nanosecond: u32,
+}
+
+impl IsoDateTimeFields {
+    /// Splits the time value `t` into its date and time fields.
+    fn from_time_value(t: f64) -> Self {
+        Self {
+            year: year_from_time(t),
+            month: month_from_time(t) + 1,
+            day: date_from_time(t),
+            hour: hour_from_time(t),
+            minute: min_from_time(t),
+            second: sec_from_time(t),
+            nanosecond: u32::from(ms_from_time(t)) * 1_000_000,
+        }
+    }
+
+    /// Converts the fields to an ICU4X date, keeping only `precision` digits of the fractional
+    /// seconds.
+    fn to_date_time(self, precision: Option<u8>) -> JsResult<DateTime<Iso>> {
+        let invalid = |err: icu_calendar::CalendarError| {
+            JsNativeError::range().with_message(format!("cannot format date: {err}"))
+        };
+
+        // ICU4X rounds the fractional seconds instead of truncating them.
+        let precision = precision.map_or(0, u32::from);
+        let nanosecond = self.nanosecond - self.nanosecond % 10u32.pow(9 - precision);
+
+        let date = Date::try_new_iso_date(self.year, self.month, self.day).map_err(invalid)?;
+        let time =
+            Time::try_new(self.hour, self.minute, self.second, nanosecond).map_err(invalid)?;
+
+        Ok(DateTime::new(date, time))
+    }
+}
+
+#[derive(Debug, Default)]
+pub(super) struct DateTimeFormatLocaleOptions {
+    calendar: Option<Value>,
+    numbering_system: Option<Value>,
+    hour_cycle: Option<HourCycle>,
+    /// `true` if the `hour12` option was provided, which overrides the `hc` extension.
+    hour12: bool,
+}
+
+/// Resolves the relevant extension key `key` from the unicode extensions of `locale` and the
+/// `option` value, following the steps of [`ResolveLocale`][spec].
+///
+/// Returns the resolved value, and whether the extension must be kept in the resolved locale.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-resolvelocale
+fn resolve_keyword(
+    locale: &Locale,
+    key: Key,
+    option: Option<Value>,
+    is_supported: impl Fn(&Value) -> bool,
+) -> (Option<Value>, bool) {
+    let extension = locale
+        .extensions
+        .unicode
+        .keywords
+        .get(&key)
+        .filter(|value| is_supported(value))
+        .cloned();
+
+    match option.filter(|value| is_supported(value)) {
+        Some(option) if extension.as_ref() != Some(&option) => (Some(option), false),
+        _ => {
+            let keep = extension.is_some();
+            (extension, keep)
+        }
+    }
+}
+
+/// Gets the value of the `hc` extension key for the hour cycle `hc`.
+pub(in crate::builtins::intl) const fn hour_cycle_value(hc: HourCycle) -> Value {
+    match hc {
+        HourCycle::H11 => value!("h11"),
+        HourCycle::H12 => value!("h12"),
+        HourCycle::H23 => value!("h23"),
+        HourCycle::H24 => value!("h24"),
+    }
+}
+
+/// Gets the hour cycle represented by the value of the `hc` extension key.
+fn hour_cycle_from_value(value: &Value) -> Option<HourCycle> {
+    [
+        HourCycle::H11,
+        HourCycle::H12,
+        HourCycle::H23,
+        HourCycle::H24,
+    ]
+    .into_iter()
+    .find(|hc| &hour_cycle_value(*hc) == value)
+}
+
+/// Gets the calendar kind represented by the value of the `ca` extension key, if it is
+/// supported.
+fn calendar_from_value(value: &Value) -> Option<AnyCalendarKind> {
+    AnyCalendarKind::get_for_bcp47_value(value).filter(|kind| pattern::is_supported_calendar(*kind))
+}
+
+impl Service for DateTimeFormat {
+    type LangMarker = TimeLengthsV1Marker;
+
+    type LocaleOptions = DateTimeFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        let (calendar, keep_calendar) =
+            resolve_keyword(locale, key!("ca"), options.calendar.take(), |ca| {
+                calendar_from_value(ca).is_some()
+            });
+
+        let (numbering_system, keep_numbering_system) =
+            resolve_keyword(locale, key!("nu"), options.numbering_system.take(), |nu| {
+                validate_extension::<DecimalSymbolsV1Marker>(
+                    locale.id.clone(),
+                    key!("nu"),
+                    nu,
+                    provider,
+                )
+            });
+
+        // If `hour12` is provided, both the `hourCycle` option and the `hc` extension are ignored.
+        let (hour_cycle, keep_hour_cycle) = if options.hour12 {
+            (None, false)
+        } else {
+            resolve_keyword(
+                locale,
+                key!("hc"),
+                options.hour_cycle.map(hour_cycle_value),
+                |hc| hour_cycle_from_value(hc).is_some(),
+            )
+        };
+
+        locale.extensions.unicode.clear();
+
+        if let Some(ca) = calendar.clone().filter(|_| keep_calendar) {
+            locale.extensions.unicode.keywords.set(key!("ca"), ca);
+        }
+        if let Some(hc) = hour_cycle.clone().filter(|_| keep_hour_cycle) {
+            locale.extensions.unicode.keywords.set(key!("hc"), hc);
+        }
+        if let Some(nu) = numbering_system.clone().filter(|_| keep_numbering_system) {
+            locale.extensions.unicode.keywords.set(key!("nu"), nu);
+        }
+
+        options.calendar = calendar;
+        options.numbering_system = numbering_system;
+        options.hour_cycle = hour_cycle.as_ref().and_then(hour_cycle_from_value);
+    }
+}
+
+impl IntrinsicObject for DateTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_format = BuiltInBuilder::callable(realm, Self::get_format)
+            .name(js_string!("get format"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DateTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("format"),
+                Some(get_format),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::format_range, js_string!("formatRange"), 2)
+            .method(
+                Self::format_range_to_parts,
+                js_string!("formatRangeToParts"),
+                2,
+            )
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DateTimeFormat {
+    const NAME: JsString = StaticJsStrings::DATE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for DateTimeFormat {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::date_time_format;
+
+    /// The `Intl.DateTimeFormat` constructor is the `%DateTimeFormat%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target_inner = &if new_target.is_undefined() {
+            context
+                .active_function_object()
+                .unwrap_or_else(|| {
+                    context
+                        .intrinsics()
+                        .constructors()
+                        .date_time_format()
+                        .constructor()
+                })
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let dateTimeFormat be ? CreateDateTimeFormat(newTarget, locales, options, any, date).
+        let prototype = get_prototype_from_constructor(
+            new_target_inner,
+            StandardConstructors::date_time_format,
+            context,
+        )?;
+        let date_time_format = Self::new(
+            locales,
+            options,
+            FormatType::Any,
+            FormatDefaults::Date,
+            context,
+        )?;
+        let date_time_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            date_time_format,
+        );
+
+        // 3. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Let this be the this value.
+        //     b. Return ? ChainDateTimeFormat(dateTimeFormat, NewTarget, this).
+        // ChainDateTimeFormat ( dateTimeFormat, newTarget, this )
+        // <https://tc39.es/ecma402/#sec-chaindatetimeformat>
+
+        let this = context.vm.frame().this(&context.vm);
+        let Some(this_obj) = this.as_object() else {
+            return Ok(date_time_format.into());
+        };
+
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .date_time_format()
+            .constructor();
+
+        // 1. If newTarget is undefined and ? OrdinaryHasInstance(%Intl.DateTimeFormat%, this) is true, then
+        if new_target.is_undefined()
+            && JsValue::ordinary_has_instance(&constructor.into(), &this, context)?
+        {
+            let fallback_symbol = context
+                .intrinsics()
+                .objects()
+                .intl()
+                .borrow()
+                .data
+                .fallback_symbol();
+
+            // a. Perform ? DefinePropertyOrThrow(this, %Intl%.[[FallbackSymbol]], PropertyDescriptor{ [[Value]]: dateTimeFormat, [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }).
+            this_obj.define_property_or_throw(
+                fallback_symbol,
+                PropertyDescriptor::builder()
+                    .value(date_time_format)
+                    .writable(false)
+                    .enumerable(false)
+                    .configurable(false),
+                context,
+            )?;
+            // b. Return this.
+            Ok(this)
+        } else {
+            // 2. Return dateTimeFormat.
+            Ok(date_time_format.into())
+        }
+    }
+}
+
+impl DateTimeFormat {
+    /// Abstract operation [`CreateDateTimeFormat ( newTarget, locales, options, required, defaults )`][spec].
+    ///
+    /// Creates the internal data of a `DateTimeFormat`. The object itself is created by the caller,
+    /// since `Date.prototype.toLocaleString` and friends don't need one.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        required: FormatType,
+        defaults: FormatDefaults,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let dateTimeFormat be ? OrdinaryCreateFromConstructor(newTarget, "%Intl.DateTimeFormat.prototype%", « ... »).
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 4. Let opt be a new Record.
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 6. Set opt.[[localeMatcher]] to matcher.
+        let matcher = get_option(&options, utf16!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 7. Let calendar be ? GetOption(options, "calendar", string, empty, undefined).
+        // 8. If calendar is not undefined, then
+        //     a. If calendar cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 9. Set opt.[[ca]] to calendar.
+        let calendar = get_option::<Value>(&options, utf16!("calendar"), context)?;
+
+        // 10. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 11. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 12. Set opt.[[nu]] to numberingSystem.
+        let numbering_system = get_option::<Value>(&options, utf16!("numberingSystem"), context)?;
+
+        // 13. Let hour12 be ? GetOption(options, "hour12", boolean, empty, undefined).
+        let hour12 = get_option::<bool>(&options, utf16!("hour12"), context)?;
+
+        // 14. Let hourCycle be ? GetOption(options, "hourCycle", string, « "h11", "h12", "h23", "h24" », undefined).
+        // 15. If hour12 is not undefined, then
+        //     a. Set hourCycle to null.
+        // 16. Set opt.[[hc]] to hourCycle.
+        let hour_cycle = get_option::<HourCycle>(&options, utf16!("hourCycle"), context)?
+            .filter(|_| hour12.is_none());
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: DateTimeFormatLocaleOptions {
+                calendar,
+                numbering_system,
+                hour_cycle,
+                hour12: hour12.is_some(),
+            },
+        };
+
+        // 17. Let r be ResolveLocale(%Intl.DateTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.DateTimeFormat%.[[RelevantExtensionKeys]], %Intl.DateTimeFormat%.[[LocaleData]]).
+        // 18. Set dateTimeFormat.[[Locale]] to r.[[Locale]].
+        let locale = resolve_locale::<Self>(
+            &requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        );
+        let DateTimeFormatLocaleOptions {
+            calendar,
+            numbering_system,
+            hour_cycle,
+            ..
+        } = intl_options.service_options;
+
+        // 19. Let resolvedCalendar be r.[[ca]].
+        // 20. Set dateTimeFormat.[[Calendar]] to resolvedCalendar.
+        let calendar = calendar.as_ref().and_then(calendar_from_value).unwrap_or(
+            if locale.id.language == icu_locid::subtags::language!("th") {
+                AnyCalendarKind::Buddhist
+            } else {
+                AnyCalendarKind::Gregorian
+            },
+        );
+
+        // 21. Set dateTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        // 22. Let dataLocale be r.[[DataLocale]].
+        let mut data_locale = DataLocale::from(&locale.id);
+        data_locale.set_unicode_ext(key!("ca"), calendar.as_bcp47_value());
+        if let Some(nu) = numbering_system.clone() {
+            data_locale.set_unicode_ext(key!("nu"), nu);
+        }
+
+        // 23. Let dataLocaleData be localeData.[[<dataLocale>]].
+        let hour_cycles = locale_hour_cycles(&data_locale, context.intl_provider())?;
+
+        let hc = match hour12 {
+            // 24. If hour12 is true, then
+            //     a. Let hc be dataLocaleData.[[hourCycle12]].
+            Some(true) => hour_cycles.h12,
+            // 25. Else if hour12 is false, then
+            //     a. Let hc be dataLocaleData.[[hourCycle24]].
+            Some(false) => hour_cycles.h24,
+            // 26. Else,
+            //     a. Assert: hour12 is undefined.
+            //     b. Let hc be r.[[hc]].
+            //     c. If hc is null, set hc to dataLocaleData.[[hourCycle]].
+            None => hour_cycle.unwrap_or(hour_cycles.default),
+        };
+        data_locale.set_unicode_ext(key!("hc"), hour_cycle_value(hc));
+
+        // 27. Let timeZone be ? Get(options, "timeZone").
+        let time_zone = options.get(utf16!("timeZone"), context)?;
+
+        // 28. If timeZone is undefined, then
+        //     a. Set timeZone to SystemTimeZoneIdentifier().
+        // 29. Else,
+        //     a. Set timeZone to ? ToString(timeZone).
+        // 30. If IsTimeZoneOffsetString(timeZone) is true, then
+        //     a. Let parseResult be ParseText(StringToCodePoints(timeZone), UTCOffset).
+        //     b. Assert: parseResult is a Parse Node.
+        //     c. If parseResult contains more than one MinuteSecond Parse Node, throw a RangeError exception.
+        //     d. Let offsetNanoseconds be ParseTimeZoneOffsetString(timeZone).
+        //     e. Let offsetMinutes be offsetNanoseconds / (6 × 10^10).
+        //     f. Assert: offsetMinutes is an integer.
+        //     g. Set timeZone to FormatOffsetTimeZoneIdentifier(offsetMinutes).
+        // 31. Else,
+        //     a. If IsValidTimeZoneName(timeZone) is false, throw a RangeError exception.
+        //     b. Set timeZone to CanonicalizeTimeZoneName(timeZone).
+        // 32. Set dateTimeFormat.[[TimeZone]] to timeZone.
+        let time_zone = if time_zone.is_undefined() {
+            TimeZone::default(context.intl_provider())?
+        } else {
+            let time_zone = time_zone.to_string(context)?.to_std_string_escaped();
+            TimeZone::from_identifier(&time_zone, context.intl_provider())?
+        };
+
+        // 33. Let formatOptions be a new Record.
+        // 34. Set formatOptions.[[hourCycle]] to hc.
+        // 35. Let hasExplicitFormatComponents be false.
+        // 36. For each row of Table 16, except the header row, in table order, do
+        //     a. Let prop be the name given in the Property column of the current row.
+        //     b. If prop is "fractionalSecondDigits", then
+        //         i. Let value be ? GetNumberOption(options, "fractionalSecondDigits", 1, 3, undefined).
+        //     c. Else,
+        //         i. Let values be a List whose elements are the strings given in the Values column of the current row.
+        //         ii. Let value be ? GetOption(options, prop, string, values, undefined).
+        //     d. Set formatOptions.[[<prop>]] to value.
+        //     e. If value is not undefined, then
+        //         i. Set hasExplicitFormatComponents to true.
+        let mut format_options = FormatOptions::from_options(&options, context)?;
+        let has_explicit_format_components = format_options.has_explicit_components();
+
+        // 37. Let formatMatcher be ? GetOption(options, "formatMatcher", string, « "basic", "best fit" », "best fit").
+        // ICU4X only implements the "best fit" matcher, which is also used for "basic".
+        let _format_matcher: FormatMatcher =
+            get_option(&options, utf16!("formatMatcher"), context)?.unwrap_or_default();
+
+        // 38. Let dateStyle be ? GetOption(options, "dateStyle", string, « "full", "long", "medium", "short" », undefined).
+        // 39. Set dateTimeFormat.[[DateStyle]] to dateStyle.
+        let date_style = get_option::<Style>(&options, utf16!("dateStyle"), context)?;
+
+        // 40. Let timeStyle be ? GetOption(options, "timeStyle", string, « "full", "long", "medium", "short" », undefined).
+        // 41. Set dateTimeFormat.[[TimeStyle]] to timeStyle.
+        let time_style = get_option::<Style>(&options, utf16!("timeStyle"), context)?;
+
+        let provider = context.intl_provider();
+
+        // 42. If dateStyle is not undefined or timeStyle is not undefined, then
+        let (pattern, formatter_options) = if date_style.is_some() || time_style.is_some() {
+            // a. If hasExplicitFormatComponents is true, then
+            if has_explicit_format_components {
+                // i. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "`dateStyle` and `timeStyle` cannot be used with date or time components",
+                    )
+                    .into());
+            }
+
+            // b. If required is date and timeStyle is not undefined, then
+            if required == FormatType::Date && time_style.is_some() {
+                // i. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("`timeStyle` cannot be used when formatting only a date")
+                    .into());
+            }
+
+            // c. If required is time and dateStyle is not undefined, then
+            if required == FormatType::Time && date_style.is_some() {
+                // i. Throw a TypeError exception.
+                return Err(JsNativeError::typ()
+                    .with_message("`dateStyle` cannot be used when formatting only a time")
+                    .into());
+            }
+
+            // d. Let styles be dataLocaleData.[[styles]].[[<resolvedCalendar>]].
+            // e. Let bestFormat be DateTimeStyleFormat(dateStyle, timeStyle, styles).
+            let pattern = pattern::pattern_for_styles(
+                date_style,
+                time_style,
+                hc,
+                calendar,
+                &data_locale,
+                provider,
+            )?;
+            let bag = match (date_style, time_style) {
+                (Some(date), Some(time)) => {
+                    length::Bag::from_date_time_style(date.to_date_length(), time.to_time_length())
+                }
+                (Some(date), None) => length::Bag::from_date_style(date.to_date_length()),
+                (None, Some(time)) => length::Bag::from_time_style(time.to_time_length()),
+                (None, None) => unreachable!("at least one of the styles must be defined"),
+            };
+
+            (pattern, DateTimeFormatterOptions::Length(bag))
+        } else {
+            // 43. Else,
+            //     a. Let needDefaults be true.
+            //     b. If required is date or any, then
+            //         i. For each property name prop of « "weekday", "year", "month", "day" », do
+            //             1. Let value be formatOptions.[[<prop>]].
+            //             2. If value is not undefined, let needDefaults be false.
+            //     c. If required is time or any, then
+            //         i. For each property name prop of « "dayPeriod", "hour", "minute", "second", "fractionalSecondDigits" », do
+            //             1. Let value be formatOptions.[[<prop>]].
+            //             2. If value is not undefined, let needDefaults be false.
+            let need_defaults = !(matches!(required, FormatType::Date | FormatType::Any)
+                && format_options.has_date_components()
+                || matches!(required, FormatType::Time | FormatType::Any)
+                    && format_options.has_time_components());
+
+            if need_defaults {
+                // d. If needDefaults is true and defaults is either date or all, then
+                //     i. For each property name prop of « "year", "month", "day" », do
+                //         1. Set formatOptions.[[<prop>]] to "numeric".
+                if matches!(defaults, FormatDefaults::Date | FormatDefaults::All) {
+                    format_options.year = Some(NumericWidth::Numeric);
+                    format_options.month = Some(MonthWidth::Numeric);
+                    format_options.day = Some(NumericWidth::Numeric);
+                }
+
+                // e. If needDefaults is true and defaults is either time or all, then
+                //     i. For each property name prop of « "hour", "minute", "second" », do
+                //         1. Set formatOptions.[[<prop>]] to "numeric".
+                if matches!(defaults, FormatDefaults::Time | FormatDefaults::All) {
+                    format_options.hour = Some(NumericWidth::Numeric);
+                    format_options.minute = Some(NumericWidth::Numeric);
+                    format_options.second = Some(NumericWidth::Numeric);
+                }
+            }
+
+            //     f. Let formats be dataLocaleData.[[formats]].[[<resolvedCalendar>]].
+            //     g. If formatMatcher is "basic", then
+            //         i. Let bestFormat be BasicFormatMatcher(formatOptions, formats).
+            //     h. Else,
+            //         i. Let bestFormat be BestFitFormatMatcher(formatOptions, formats).
+            let bag = format_options.to_components_bag(hc);
+            let pattern = pattern::pattern_for_components(&bag, calendar, &data_locale, provider)?;
+
+            (pattern, DateTimeFormatterOptions::Components(bag))
+        };
+
+        let formatter = ZonedDateTimeFormatter::try_new_experimental_unstable(
+            provider,
+            &data_locale,
+            formatter_options,
+            TimeZoneFormatterOptions::default(),
+        )
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+
+        // 44. Set dateTimeFormat.[[DateTimeFormat]] to bestFormat.
+        // 45. If bestFormat has a field [[hour]], then
+        //     a. Set dateTimeFormat.[[HourCycle]] to hc.
+        let has_hour = !format_options.is_day_period_only()
+            && pattern.items.iter().any(|item| {
+                matches!(item, PatternItem::Field(field) if matches!(field.symbol, FieldSymbol::Hour(_)))
+            });
+
+        // 46. Return dateTimeFormat.
+        Ok(Self {
+            locale,
+            calendar,
+            numbering_system,
+            time_zone,
+            hour_cycle: has_hour.then_some(hc),
+            format_options,
+            date_style,
+            time_style,
+            pattern,
+            formatter,
+            bound_format: None,
+        })
+    }
+
+    /// [`Intl.DateTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in date and
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`get Intl.DateTimeFormat.prototype.format`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format
+    fn get_format(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, context)?;
+        let dtf_clone = dtf.clone();
+        let mut dtf = dtf.borrow_mut();
+
+        let bound_format = if let Some(f) = dtf.data.bound_format.clone() {
+            f
+        } else {
+            // 4. If dtf.[[BoundFormat]] is undefined, then
+            //     a. Let F be a new built-in function object as defined in DateTime Format Functions (11.5.4.1).
+            //     b. Set F.[[DateTimeFormat]] to dtf.
+            //     c. Set dtf.[[BoundFormat]] to F.
+            let bound_format = FunctionObjectBuilder::new(
+                context.realm(),
+                // DateTime Format Functions
+                // <https://tc39.es/ecma402/#sec-datetime-format-functions>
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, dtf, context| {
+                        // 1. Let dtf be F.[[DateTimeFormat]].
+                        // 2. Assert: Type(dtf) is Object and dtf has an [[InitializedDateTimeFormat]] internal slot.
+                        let date = args.get_or_undefined(0);
+
+                        // 3. If date is not provided or is undefined, then
+                        let x = if date.is_undefined() {
+                            // a. Let x be ! Call(%Date.now%, undefined).
+                            context.host_hooks().utc_now() as f64
+                        } else {
+                            // 4. Else,
+                            //     a. Let x be ? ToNumber(date).
+                            date.to_number(context)?
+                        };
+
+                        // 5. Return ? FormatDateTime(dtf, x).
+                        dtf.borrow()
+                            .data
+                            .format_epoch_milliseconds(x)
+                            .map(JsValue::from)
+                    },
+                    dtf_clone,
+                ),
+            )
+            .length(1)
+            .build();
+
+            dtf.data.bound_format = Some(bound_format.clone());
+            bound_format
+        };
+
+        // 5. Return dtf.[[BoundFormat]].
+        Ok(bound_format.into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatToParts ( date )`][spec].
+    ///
+    /// Returns an array of objects representing the date in parts that can be used for custom
+    /// locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`formatToParts` can only be called on a `DateTimeFormat` object")
+            })?;
+
+        let date = args.get_or_undefined(0);
+
+        // 3. If date is undefined, then
+        let x = if date.is_undefined() {
+            // a. Let x be ! Call(%Date.now%, undefined).
+            context.host_hooks().utc_now() as f64
+        } else {
+            // 4. Else,
+            //     a. Let x be ? ToNumber(date).
+            date.to_number(context)?
+        };
+
+        // 5. Return ? FormatDateTimeToParts(dtf, x).
+
+        // `FormatDateTimeToParts ( dateTimeFormat, x )`
+        // https://tc39.es/ecma402/#sec-formatdatetimetoparts
+
+        // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+        let parts = dtf.borrow().data.partition_date_time_pattern(x)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        //     b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        //     c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        //     d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+        //     e. Increment n by 1.
+        // 5. Return result.
+        Ok(parts_to_array(parts.into_iter().map(|part| (part, None)), context).into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRange ( startDate, endDate )`][spec].
+    ///
+    /// Formats a date range in the most concise way based on the locale and options of this
+    /// `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatRange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToNumber(startDate).
+        // 5. Let y be ? ToNumber(endDate).
+        let (dtf, x, y) = Self::range_arguments(this, args, "formatRange", context)?;
+
+        // 6. Return ? FormatDateTimeRange(dtf, x, y).
+
+        // `FormatDateTimeRange ( dateTimeFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatdatetimerange
+
+        // 1. Let parts be ? PartitionDateTimeRangePattern(dateTimeFormat, x, y).
+        let parts = dtf.borrow().data.partition_date_time_range_pattern(x, y)?;
+
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]], [[Source]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        let result = parts
+            .into_iter()
+            .map(|(part, _)| part.value)
+            .collect::<String>();
+
+        // 4. Return result.
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRangeToParts ( startDate, endDate )`][spec].
+    ///
+    /// Returns an array of objects representing the parts of a formatted date range, including
+    /// the range of the dates each part comes from.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatRangeToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToNumber(startDate).
+        // 5. Let y be ? ToNumber(endDate).
+        let (dtf, x, y) = Self::range_arguments(this, args, "formatRangeToParts", context)?;
+
+        // 6. Return ? FormatDateTimeRangeToParts(dtf, x, y).
+
+        // `FormatDateTimeRangeToParts ( dateTimeFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatdatetimerangetoparts
+
+        // 1. Let parts be ? PartitionDateTimeRangePattern(dateTimeFormat, x, y).
+        let parts = dtf.borrow().data.partition_date_time_range_pattern(x, y)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Source]] } part in parts, do
+        //     a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        //     b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        //     c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        //     d. Perform ! CreateDataPropertyOrThrow(O, "source", part.[[Source]]).
+        //     e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+        //     f. Increment n by 1.
+        // 5. Return result.
+        Ok(parts_to_array(
+            parts.into_iter().map(|(part, source)| (part, Some(source))),
+            context,
+        )
+        .into())
+    }
+
+    /// Validates the arguments of `formatRange` and `formatRangeToParts`.
+    fn range_arguments(
+        this: &JsValue,
+        args: &[JsValue],
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<(JsObject<Self>, f64, f64)> {
+        let dtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "`{method}` can only be called on a `DateTimeFormat` object"
+                ))
+            })?;
+
+        let start_date = args.get_or_undefined(0);
+        let end_date = args.get_or_undefined(1);
+
+        if start_date.is_undefined() || end_date.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(format!("`{method}` requires both a start and an end date"))
+                .into());
+        }
+
+        let x = start_date.to_number(context)?;
+        let y = end_date.to_number(context)?;
+
+        Ok((dtf, x, y))
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and date and time formatting
+    /// options computed during the construction of the current `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, context)?;
+        let dtf = dtf.borrow();
+        let dtf = &dtf.data;
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 5. For each row of Table 17, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. If there is an Internal Slot value in the current row, then
+        //         i. Let v be the value of dtf's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Else,
+        //         i. Let format be dtf.[[DateTimeFormat]].
+        //         ii. If format has a field [[<p>]] and dtf.[[DateStyle]] is undefined and dtf.[[TimeStyle]] is undefined, then
+        //             1. Let v be format.[[<p>]].
+        //         iii. Else,
+        //             1. Let v be undefined.
+        //     d. If v is not undefined, then
+        //         i. If there is a Conversion value in the current row, then
+        //             1. Let conversion be the Conversion value of the current row.
+        //             2. If conversion is hour12, then
+        //                 a. If v is "h11" or "h12", set v to true. Otherwise, set v to false.
+        //             3. Else,
+        //                 a. Assert: conversion is number.
+        //                 b. Set v to 𝔽(v).
+        //         ii. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(dtf.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("calendar"),
+                js_string!(dtf.calendar.as_bcp47_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("numberingSystem"),
+                dtf.numbering_system
+                    .as_ref()
+                    .map_or_else(|| js_string!("latn"), |nu| js_string!(nu.to_string())),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("timeZone"),
+                js_string!(dtf.time_zone.identifier()),
+                Attribute::all(),
+            );
+
+        if let Some(hc) = dtf.hour_cycle {
+            options
+                .property(
+                    js_string!("hourCycle"),
+                    js_string!(hour_cycle_as_str(hc)),
+                    Attribute::all(),
+                )
+                .property(
+                    js_string!("hour12"),
+                    matches!(hc, HourCycle::H11 | HourCycle::H12),
+                    Attribute::all(),
+                );
+        }
+
+        if dtf.date_style.is_none() && dtf.time_style.is_none() {
+            let components = dtf.resolved_components();
+
+            let mut property = |name: &'static str, value: Option<&'static str>| {
+                if let Some(value) = value {
+                    options.property(js_string!(name), js_string!(value), Attribute::all());
+                }
+            };
+            property("weekday", components.weekday.map(TextWidth::as_str));
+            property("era", components.era.map(TextWidth::as_str));
+            property("year", components.year.map(NumericWidth::as_str));
+            property("month", components.month.map(MonthWidth::as_str));
+            property("day", components.day.map(NumericWidth::as_str));
+            property("dayPeriod", components.day_period.map(TextWidth::as_str));
+            property("hour", components.hour.map(NumericWidth::as_str));
+            property("minute", components.minute.map(NumericWidth::as_str));
+            property("second", components.second.map(NumericWidth::as_str));
+
+            if let Some(digits) = components.fractional_second_digits {
+                options.property(
+                    js_string!("fractionalSecondDigits"),
+                    digits,
+                    Attribute::all(),
+                );
+            }
+
+            if let Some(tz_name) = components.time_zone_name {
+                options.property(
+                    js_string!("timeZoneName"),
+                    js_string!(tz_name.as_str()),
+                    Attribute::all(),
+                );
+            }
+        }
+
+        if let Some(style) = dtf.date_style {
+            options.property(
+                js_string!("dateStyle"),
+                js_string!(style.as_str()),
+                Attribute::all(),
+            );
+        }
+        if let Some(style) = dtf.time_style {
+            options.property(
+                js_string!("timeStyle"),
+                js_string!(style.as_str()),
+                Attribute::all(),
+            );
+        }
+
+        // 6. Return options.
+        Ok(options.build().into())
+    }
+
+    /// Gets the components of the pattern selected for this `DateTimeFormat`, which can differ
+    /// from the requested components.
+    fn resolved_components(&self) -> FormatOptions {
+        if self.format_options.is_day_period_only() {
+            return FormatOptions {
+                day_period: self.format_options.day_period,
+                ..FormatOptions::default()
+            };
+        }
+
+        let numeric = |length| match length {
+            icu_datetime::fields::FieldLength::TwoDigit => NumericWidth::TwoDigit,
+            _ => NumericWidth::Numeric,
+        };
+        let text = |length| match length {
+            icu_datetime::fields::FieldLength::Wide => TextWidth::Long,
+            icu_datetime::fields::FieldLength::Narrow => TextWidth::Narrow,
+            _ => TextWidth::Short,
+        };
+
+        let mut components = FormatOptions {
+            day_period: self.format_options.day_period,
+            fractional_second_digits: self.format_options.fractional_second_digits,
+            ..FormatOptions::default()
+        };
+
+        for item in self.pattern.items.iter() {
+            let PatternItem::Field(field) = item else {
+                continue;
+            };
+
+            match field.symbol {
+                FieldSymbol::Weekday(_) => components.weekday = Some(text(field.length)),
+                FieldSymbol::Era => components.era = Some(text(field.length)),
+                FieldSymbol::Year(_) => components.year = Some(numeric(field.length)),
+                FieldSymbol::Month(_) => {
+                    components.month = Some(match field.length {
+                        icu_datetime::fields::FieldLength::One => MonthWidth::Numeric,
+                        icu_datetime::fields::FieldLength::TwoDigit => MonthWidth::TwoDigit,
+                        icu_datetime::fields::FieldLength::Wide => MonthWidth::Long,
+                        icu_datetime::fields::FieldLength::Narrow => MonthWidth::Narrow,
+                        _ => MonthWidth::Short,
+                    });
+                }
+                FieldSymbol::Day(_) => components.day = Some(numeric(field.length)),
+                FieldSymbol::Hour(_) => components.hour = Some(numeric(field.length)),
+                FieldSymbol::Minute => components.minute = Some(numeric(field.length)),
+                FieldSymbol::Second(icu_datetime::fields::Second::Second) => {
+                    components.second = Some(numeric(field.length));
+                }
+                FieldSymbol::TimeZone(_) => {
+                    components.time_zone_name = self.format_options.time_zone_name;
+                }
+                _ => {}
+            }
+        }
+
+        components
+    }
+
+    /// Abstract operation [`FormatDateTime ( dateTimeFormat, x )`][spec].
+    ///
+    /// Formats the time value `x`, in milliseconds since the epoch.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatdatetime
+    pub(crate) fn format_epoch_milliseconds(&self, x: f64) -> JsResult<JsString> {
+        // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+        let parts = self.partition_date_time_pattern(x)?;
+
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        Ok(js_string!(parts
+            .into_iter()
+            .map(|part| part.value)
+            .collect::<String>()))
+    }
+
+    /// Formats the wall-clock date and time `fields` of a Temporal object, whose calendar is
+    /// `calendar`.
+    ///
+    /// Throws a `RangeError` if `calendar` is neither `iso8601` nor the calendar of this
+    /// `DateTimeFormat`.
+    #[cfg(feature = "temporal")]
+    pub(crate) fn format_plain(
+        &self,
+        calendar: &str,
+        fields: IsoDateTimeFields,
+    ) -> JsResult<JsString> {
+        if calendar != "iso8601" && calendar != self.calendar.as_bcp47_string() {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "cannot format a date in the `{calendar}` calendar with a `{}` formatter",
+                    self.calendar.as_bcp47_string()
+                ))
+                .into());
+        }
+
+        let parts = self.format_fields(fields, |_| CustomTimeZone::utc())?;
+        Ok(js_string!(parts
+            .into_iter()
+            .map(|part| part.value)
+            .collect::<String>()))
+    }
+
+    /// Abstract operation [`PartitionDateTimePattern ( dateTimeFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimepattern
+    fn partition_date_time_pattern(&self, x: f64) -> JsResult<Vec<Part>> {
+        // 1. Let x be TimeClip(x).
+        let x = time_clip(x);
+
+        // 2. If x is NaN, throw a RangeError exception.
+        if x.is_nan() {
+            return Err(JsNativeError::range()
+                .with_message("cannot format an invalid date")
+                .into());
+        }
+
+        // 3. Let epochNanoseconds be ℤ(ℝ(x) × 10^6).
+        // 4. Let timeZone be dateTimeFormat.[[TimeZone]].
+        // 5. Let offsetNs be GetOffsetNanosecondsFor(timeZone, epochNanoseconds).
+        // 6. Let tm be ToLocalTime(epochNanoseconds, dateTimeFormat.[[Calendar]], timeZone).
+        let (offset, is_dst) = self.time_zone.offset_at(x);
+        let local = x + f64::from(offset) * 1000.0;
+
+        // 7. Let result be FormatDateTimePattern(dateTimeFormat, format, pattern, tm).
+        self.format_fields(IsoDateTimeFields::from_time_value(local), |date_time| {
+            self.time_zone
+                .to_custom_time_zone(offset, is_dst, date_time)
+        })
+    }
+
+    /// Formats the local date and time `fields` in the time zone returned by `time_zone`, and
+    /// splits the result into its parts.
+    fn format_fields(
+        &self,
+        fields: IsoDateTimeFields,
+        time_zone: impl FnOnce(&DateTime<Iso>) -> CustomTimeZone,
+    ) -> JsResult<Vec<Part>> {
+        let date_time = fields.to_date_time(self.format_options.fractional_second_digits)?;
+        let time_zone = time_zone(&date_time);
+
+        let formatted = self
+            .formatter
+            .format_to_string(&date_time.to_any(), &time_zone)
+            .map_err(|err| JsNativeError::range().with_message(err.to_string()))?;
+
+        let mut parts = pattern::partition(&self.pattern, &formatted);
+
+        // The hour is only formatted to display the day period.
+        if self.format_options.is_day_period_only() {
+            parts.retain(|part| part.typ == "dayPeriod");
+        }
+
+        Ok(parts)
+    }
+
+    /// Abstract operation [`PartitionDateTimeRangePattern ( dateTimeFormat, x, y )`][spec].
+    ///
+    /// Returns the parts of the formatted range, with the source of every part.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimerangepattern
+    fn partition_date_time_range_pattern(
+        &self,
+        x: f64,
+        y: f64,
+    ) -> JsResult<Vec<(Part, &'static str)>> {
+        // 1. Let x be TimeClip(x).
+        // 2. If x is NaN, throw a RangeError exception.
+        // 3. Let y be TimeClip(y).
+        // 4. If y is NaN, throw a RangeError exception.
+        let x_parts = self.partition_date_time_pattern(x)?;
+        let y_parts = self.partition_date_time_pattern(y)?;
+
+        // 5-13. If the formatted dates are practically equal, return the parts of the start date
+        //       with "shared" as their source.
+        // ICU4X doesn't have range patterns, so the formatted dates are instead compared directly.
+        if x_parts == y_parts {
+            return Ok(x_parts.into_iter().map(|part| (part, "shared")).collect());
+        }
+
+        // 14-16. Otherwise, join both dates with the range separator.
+        let separator = Part {
+            typ: "literal",
+            value: String::from(" – "),
+        };
+
+        Ok(x_parts
+            .into_iter()
+            .map(|part| (part, "startRange"))
+            .chain(std::iter::once((separator, "shared")))
+            .chain(y_parts.into_iter().map(|part| (part, "endRange")))
+            .collect())
+    }
+}
+
+/// Creates an array of part objects from `parts`, adding the `source` property if it is
+/// provided.
+fn parts_to_array(
+    parts: impl Iterator<Item = (Part, Option<&'static str>)>,
+    context: &mut Context,
+) -> JsObject {
+    let parts = parts
+        .map(|(part, source)| {
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            o.create_data_property_or_throw(utf16!("type"), js_string!(part.typ), context)
+                .expect("operation must not fail per the spec");
+            o.create_data_property_or_throw(utf16!("value"), js_string!(part.value), context)
+                .expect("operation must not fail per the spec");
+            if let Some(source) = source {
+                o.create_data_property_or_throw(utf16!("source"), js_string!(source), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            o.into()
+        })
+        .collect::<Vec<_>>();
+
+    Array::create_array_from_list(parts, context)
+}
+
+/// Abstract operation [`UnwrapDateTimeFormat ( dtf )`][spec].
+///
+/// This also checks that the returned object is a `DateTimeFormat`, which skips the
+/// call to `RequireInternalSlot`.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-unwrapdatetimeformat
+fn unwrap_date_time_format(
+    dtf: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject<DateTimeFormat>> {
+    // 1. If Type(dtf) is not Object, throw a TypeError exception.
+    let dtf_o = dtf.as_object().ok_or_else(|| {
+        JsNativeError::typ().with_message("value was not an `Intl.DateTimeFormat` object")
+    })?;
+
+    if let Ok(dtf) = dtf_o.clone().downcast::<DateTimeFormat>() {
+        // 3. Return dtf.
+        return Ok(dtf);
+    }
+
+    // 2. If dtf does not have an [[InitializedDateTimeFormat]] internal slot and ? OrdinaryHasInstance(%Intl.DateTimeFormat%, dtf)
+    //    is true, then
+    let constructor = context
+        .intrinsics()
+        .constructors()
+        .date_time_format()
+        .constructor();
+    if JsValue::ordinary_has_instance(&constructor.into(), dtf, context)? {
+        let fallback_symbol = context
+            .intrinsics()
+            .objects()
+            .intl()
+            .borrow()
+            .data
+            .fallback_symbol();
+
+        //    a. Return ? Get(dtf, %Intl%.[[FallbackSymbol]]).
+        let dtf = dtf_o.get(fallback_symbol, context)?;
+        if let JsValue::Object(dtf) = dtf {
+            if let Ok(dtf) = dtf.downcast::<DateTimeFormat>() {
+                return Ok(dtf);
+            }
+        }
+    }
+
+    Err(JsNativeError::typ()
+        .with_message("object was not an `Intl.DateTimeFormat` object")
+        .into())
+}
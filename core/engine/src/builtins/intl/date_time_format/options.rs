@@ -0,0 +1,467 @@
+use std::{fmt, str::FromStr};
+
+use icu_datetime::options::{components, length, preferences, preferences::HourCycle};
+
+use crate::{
+    builtins::{
+        intl::options::get_number_option,
+        options::{get_option, OptionType, ParsableOptionType},
+    },
+    string::utf16,
+    Context, JsNativeError, JsObject, JsResult, JsValue,
+};
+
+impl OptionType for HourCycle {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "h11" => Ok(Self::H11),
+            "h12" => Ok(Self::H12),
+            "h23" => Ok(Self::H23),
+            "h24" => Ok(Self::H24),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `h11`, `h12`, `h23` or `h24`")
+                .into()),
+        }
+    }
+}
+
+/// Gets the string representation of an hour cycle, as used by the `hc` Unicode extension key.
+pub(crate) const fn hour_cycle_as_str(hc: HourCycle) -> &'static str {
+    match hc {
+        HourCycle::H11 => "h11",
+        HourCycle::H12 => "h12",
+        HourCycle::H23 => "h23",
+        HourCycle::H24 => "h24",
+    }
+}
+
+/// The value of the `dateStyle` and `timeStyle` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Style {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl Style {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Long => "long",
+            Self::Medium => "medium",
+            Self::Short => "short",
+        }
+    }
+
+    pub(crate) const fn to_date_length(self) -> length::Date {
+        match self {
+            Self::Full => length::Date::Full,
+            Self::Long => length::Date::Long,
+            Self::Medium => length::Date::Medium,
+            Self::Short => length::Date::Short,
+        }
+    }
+
+    pub(crate) const fn to_time_length(self) -> length::Time {
+        match self {
+            Self::Full => length::Time::Full,
+            Self::Long => length::Time::Long,
+            Self::Medium => length::Time::Medium,
+            Self::Short => length::Time::Short,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseStyleError;
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `full`, `long`, `medium` or `short`")
+    }
+}
+
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(Self::Full),
+            "long" => Ok(Self::Long),
+            "medium" => Ok(Self::Medium),
+            "short" => Ok(Self::Short),
+            _ => Err(ParseStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for Style {}
+
+/// The value of the `formatMatcher` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum FormatMatcher {
+    Basic,
+    #[default]
+    BestFit,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseFormatMatcherError;
+
+impl fmt::Display for ParseFormatMatcherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `basic` or `best fit`")
+    }
+}
+
+impl FromStr for FormatMatcher {
+    type Err = ParseFormatMatcherError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "basic" => Ok(Self::Basic),
+            "best fit" => Ok(Self::BestFit),
+            _ => Err(ParseFormatMatcherError),
+        }
+    }
+}
+
+impl ParsableOptionType for FormatMatcher {}
+
+/// The value of the `weekday`, `era` and `dayPeriod` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TextWidth {
+    Narrow,
+    Short,
+    Long,
+}
+
+impl TextWidth {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+    }
+
+    const fn to_text(self) -> components::Text {
+        match self {
+            Self::Narrow => components::Text::Narrow,
+            Self::Short => components::Text::Short,
+            Self::Long => components::Text::Long,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseTextWidthError;
+
+impl fmt::Display for ParseTextWidthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `narrow`, `short` or `long`")
+    }
+}
+
+impl FromStr for TextWidth {
+    type Err = ParseTextWidthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseTextWidthError),
+        }
+    }
+}
+
+impl ParsableOptionType for TextWidth {}
+
+/// The value of the `year`, `day`, `hour`, `minute` and `second` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NumericWidth {
+    Numeric,
+    TwoDigit,
+}
+
+impl NumericWidth {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Numeric => "numeric",
+            Self::TwoDigit => "2-digit",
+        }
+    }
+
+    const fn to_numeric(self) -> components::Numeric {
+        match self {
+            Self::Numeric => components::Numeric::Numeric,
+            Self::TwoDigit => components::Numeric::TwoDigit,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseNumericWidthError;
+
+impl fmt::Display for ParseNumericWidthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `numeric` or `2-digit`")
+    }
+}
+
+impl FromStr for NumericWidth {
+    type Err = ParseNumericWidthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(ParseNumericWidthError),
+        }
+    }
+}
+
+impl ParsableOptionType for NumericWidth {}
+
+/// The value of the `month` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MonthWidth {
+    Numeric,
+    TwoDigit,
+    Narrow,
+    Short,
+    Long,
+}
+
+impl MonthWidth {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Numeric => "numeric",
+            Self::TwoDigit => "2-digit",
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseMonthWidthError;
+
+impl fmt::Display for ParseMonthWidthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not `numeric`, `2-digit`, `narrow`, `short` or `long`")
+    }
+}
+
+impl FromStr for MonthWidth {
+    type Err = ParseMonthWidthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(ParseMonthWidthError),
+        }
+    }
+}
+
+impl ParsableOptionType for MonthWidth {}
+
+/// The value of the `timeZoneName` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimeZoneNameWidth {
+    Short,
+    Long,
+    ShortOffset,
+    LongOffset,
+    ShortGeneric,
+    LongGeneric,
+}
+
+impl TimeZoneNameWidth {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Short => "short",
+            Self::Long => "long",
+            Self::ShortOffset => "shortOffset",
+            Self::LongOffset => "longOffset",
+            Self::ShortGeneric => "shortGeneric",
+            Self::LongGeneric => "longGeneric",
+        }
+    }
+
+    const fn to_time_zone_name(self) -> components::TimeZoneName {
+        match self {
+            Self::Short => components::TimeZoneName::ShortSpecific,
+            Self::Long => components::TimeZoneName::LongSpecific,
+            // ICU4X only supports the long localized GMT format.
+            Self::ShortOffset | Self::LongOffset => components::TimeZoneName::GmtOffset,
+            Self::ShortGeneric => components::TimeZoneName::ShortGeneric,
+            Self::LongGeneric => components::TimeZoneName::LongGeneric,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseTimeZoneNameWidthError;
+
+impl fmt::Display for ParseTimeZoneNameWidthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("provided string was not a valid `timeZoneName` option")
+    }
+}
+
+impl FromStr for TimeZoneNameWidth {
+    type Err = ParseTimeZoneNameWidthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            "shortOffset" => Ok(Self::ShortOffset),
+            "longOffset" => Ok(Self::LongOffset),
+            "shortGeneric" => Ok(Self::ShortGeneric),
+            "longGeneric" => Ok(Self::LongGeneric),
+            _ => Err(ParseTimeZoneNameWidthError),
+        }
+    }
+}
+
+impl ParsableOptionType for TimeZoneNameWidth {}
+
+/// The date and time components requested by the options of an `Intl.DateTimeFormat`.
+///
+/// This is the `formatOptions` record of [`CreateDateTimeFormat`][spec], with one field for
+/// every row of the "Components of date and time formats" table.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct FormatOptions {
+    pub(crate) weekday: Option<TextWidth>,
+    pub(crate) era: Option<TextWidth>,
+    pub(crate) year: Option<NumericWidth>,
+    pub(crate) month: Option<MonthWidth>,
+    pub(crate) day: Option<NumericWidth>,
+    pub(crate) day_period: Option<TextWidth>,
+    pub(crate) hour: Option<NumericWidth>,
+    pub(crate) minute: Option<NumericWidth>,
+    pub(crate) second: Option<NumericWidth>,
+    pub(crate) fractional_second_digits: Option<u8>,
+    pub(crate) time_zone_name: Option<TimeZoneNameWidth>,
+}
+
+impl FormatOptions {
+    /// Reads every date and time component from `options`, in table order.
+    pub(crate) fn from_options(options: &JsObject, context: &mut Context) -> JsResult<Self> {
+        Ok(Self {
+            weekday: get_option(options, utf16!("weekday"), context)?,
+            era: get_option(options, utf16!("era"), context)?,
+            year: get_option(options, utf16!("year"), context)?,
+            month: get_option(options, utf16!("month"), context)?,
+            day: get_option(options, utf16!("day"), context)?,
+            day_period: get_option(options, utf16!("dayPeriod"), context)?,
+            hour: get_option(options, utf16!("hour"), context)?,
+            minute: get_option(options, utf16!("minute"), context)?,
+            second: get_option(options, utf16!("second"), context)?,
+            fractional_second_digits: get_number_option(
+                options,
+                utf16!("fractionalSecondDigits"),
+                1,
+                3,
+                context,
+            )?,
+            time_zone_name: get_option(options, utf16!("timeZoneName"), context)?,
+        })
+    }
+
+    /// Returns `true` if any of the components was explicitly requested.
+    pub(crate) fn has_explicit_components(&self) -> bool {
+        *self != Self::default()
+    }
+
+    /// Returns `true` if any of the date components checked by `needDefaults` was requested.
+    pub(crate) const fn has_date_components(&self) -> bool {
+        self.weekday.is_some() || self.year.is_some() || self.month.is_some() || self.day.is_some()
+    }
+
+    /// Returns `true` if any of the time components checked by `needDefaults` was requested.
+    pub(crate) const fn has_time_components(&self) -> bool {
+        self.day_period.is_some()
+            || self.hour.is_some()
+            || self.minute.is_some()
+            || self.second.is_some()
+            || self.fractional_second_digits.is_some()
+    }
+
+    /// Returns `true` if the only requested component that can be displayed is `dayPeriod`.
+    ///
+    /// ICU4X cannot format a day period without an hour, so in that case the hour is formatted and
+    /// then discarded from the output.
+    pub(crate) const fn is_day_period_only(&self) -> bool {
+        self.day_period.is_some()
+            && !self.has_date_components()
+            && self.era.is_none()
+            && self.hour.is_none()
+            && self.minute.is_none()
+            && self.second.is_none()
+            && self.fractional_second_digits.is_none()
+    }
+
+    /// Converts the requested components to an ICU4X components bag.
+    pub(crate) fn to_components_bag(self, hc: HourCycle) -> components::Bag {
+        let mut bag = components::Bag::empty();
+
+        bag.era = self.era.map(TextWidth::to_text);
+        bag.year = self.year.map(|year| match year {
+            NumericWidth::Numeric => components::Year::Numeric,
+            NumericWidth::TwoDigit => components::Year::TwoDigit,
+        });
+        bag.month = self.month.map(|month| match month {
+            MonthWidth::Numeric => components::Month::Numeric,
+            MonthWidth::TwoDigit => components::Month::TwoDigit,
+            MonthWidth::Narrow => components::Month::Narrow,
+            MonthWidth::Short => components::Month::Short,
+            MonthWidth::Long => components::Month::Long,
+        });
+        bag.day = self.day.map(|day| match day {
+            NumericWidth::Numeric => components::Day::NumericDayOfMonth,
+            NumericWidth::TwoDigit => components::Day::TwoDigitDayOfMonth,
+        });
+        bag.weekday = self.weekday.map(TextWidth::to_text);
+        bag.hour = self.hour.map(NumericWidth::to_numeric);
+        bag.minute = self.minute.map(NumericWidth::to_numeric);
+        bag.second = self.second.map(NumericWidth::to_numeric);
+        bag.fractional_second = self.fractional_second_digits;
+        bag.time_zone_name = self
+            .time_zone_name
+            .map(TimeZoneNameWidth::to_time_zone_name);
+
+        if self.is_day_period_only() {
+            bag.hour = Some(components::Numeric::Numeric);
+        }
+
+        // ICU4X doesn't pad the minutes and seconds when they are followed by fractional
+        // seconds, so those must be explicitly requested with two digits.
+        if bag.fractional_second.is_some() {
+            if bag.hour.is_some() && bag.minute.is_some() {
+                bag.minute = Some(components::Numeric::TwoDigit);
+            }
+            if bag.minute.is_some() && bag.second.is_some() {
+                bag.second = Some(components::Numeric::TwoDigit);
+            }
+        }
+
+        // Without explicit preferences, ICU4X always uses a 24-hour clock for component bags.
+        if bag.hour.is_some() {
+            bag.preferences = Some(preferences::Bag::from_hour_cycle(hc));
+        }
+
+        bag
+    }
+}
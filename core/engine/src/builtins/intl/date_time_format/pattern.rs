@@ -0,0 +1,556 @@
+//! Reconstruction of the patterns used by ICU4X to format dates and times.
+//!
+//! ICU4X doesn't expose the pattern selected by its formatters, but `formatToParts` and
+//! `resolvedOptions` need it to split the formatted string into its components and to report the
+//! resolved components. This module replicates the pattern selection of ICU4X using the same
+//! locale data.
+
+use icu_calendar::{
+    any_calendar::AnyCalendarKind,
+    buddhist::Buddhist,
+    chinese::Chinese,
+    coptic::Coptic,
+    dangi::Dangi,
+    ethiopian::Ethiopian,
+    hebrew::Hebrew,
+    indian::Indian,
+    islamic::{IslamicCivil, IslamicObservational, IslamicTabular, IslamicUmmAlQura},
+    japanese::{Japanese, JapaneseExtended},
+    persian::Persian,
+    roc::Roc,
+    Gregorian,
+};
+use icu_datetime::{
+    fields::{self, Field, FieldLength, FieldSymbol},
+    options::{components, preferences::HourCycle},
+    pattern::{runtime::Pattern, CoarseHourCycle, PatternItem},
+    provider::calendar::{DateLengthsV1, DateSkeletonPatternsV1Marker, TimeLengthsV1Marker},
+    skeleton::{create_best_pattern_for_fields, BestSkeleton},
+    CldrCalendar,
+};
+use icu_locid::extensions::unicode::{key, value};
+use icu_provider::{
+    DataLocale, DataMarker, DataPayload, DataProvider, DataRequest, DataRequestMetadata,
+    DataResponse, KeyedDataMarker,
+};
+
+use crate::{context::icu::IntlProvider, JsNativeError, JsResult};
+
+use super::options::Style;
+
+/// Marker for the date length patterns of any calendar.
+///
+/// ICU4X uses a different marker for every calendar, but all of them share the same data struct.
+#[derive(Debug)]
+struct DateLengthsV1Marker;
+
+impl DataMarker for DateLengthsV1Marker {
+    type Yokeable = DateLengthsV1<'static>;
+}
+
+/// Loads the data of the marker `M` for `locale`.
+fn load<M>(locale: &DataLocale, provider: &IntlProvider) -> JsResult<DataPayload<M>>
+where
+    M: KeyedDataMarker,
+    IntlProvider: DataProvider<M>,
+{
+    provider
+        .load(DataRequest {
+            locale,
+            metadata: DataRequestMetadata::default(),
+        })
+        .and_then(DataResponse::take_payload)
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()).into())
+}
+
+/// Loads the date length patterns of the calendar `C`.
+fn load_lengths<C>(
+    locale: &DataLocale,
+    provider: &IntlProvider,
+) -> JsResult<DataPayload<DateLengthsV1Marker>>
+where
+    C: CldrCalendar,
+    IntlProvider: DataProvider<C::DateLengthsV1Marker>,
+{
+    load::<C::DateLengthsV1Marker>(locale, provider).map(DataPayload::cast)
+}
+
+/// Loads the date length patterns of the calendar `kind`.
+fn load_date_lengths(
+    kind: AnyCalendarKind,
+    locale: &DataLocale,
+    provider: &IntlProvider,
+) -> JsResult<DataPayload<DateLengthsV1Marker>> {
+    match kind {
+        AnyCalendarKind::Buddhist => load_lengths::<Buddhist>(locale, provider),
+        AnyCalendarKind::Chinese => load_lengths::<Chinese>(locale, provider),
+        AnyCalendarKind::Coptic => load_lengths::<Coptic>(locale, provider),
+        AnyCalendarKind::Dangi => load_lengths::<Dangi>(locale, provider),
+        AnyCalendarKind::Ethiopian | AnyCalendarKind::EthiopianAmeteAlem => {
+            load_lengths::<Ethiopian>(locale, provider)
+        }
+        AnyCalendarKind::Hebrew => load_lengths::<Hebrew>(locale, provider),
+        AnyCalendarKind::Indian => load_lengths::<Indian>(locale, provider),
+        AnyCalendarKind::IslamicCivil => load_lengths::<IslamicCivil>(locale, provider),
+        AnyCalendarKind::IslamicObservational => {
+            load_lengths::<IslamicObservational>(locale, provider)
+        }
+        AnyCalendarKind::IslamicTabular => load_lengths::<IslamicTabular>(locale, provider),
+        AnyCalendarKind::IslamicUmmAlQura => load_lengths::<IslamicUmmAlQura>(locale, provider),
+        AnyCalendarKind::Japanese => load_lengths::<Japanese>(locale, provider),
+        AnyCalendarKind::JapaneseExtended => load_lengths::<JapaneseExtended>(locale, provider),
+        AnyCalendarKind::Persian => load_lengths::<Persian>(locale, provider),
+        AnyCalendarKind::Roc => load_lengths::<Roc>(locale, provider),
+        _ => load_lengths::<Gregorian>(locale, provider),
+    }
+}
+
+/// Returns `true` if ICU4X supports formatting dates in the calendar `kind`.
+pub(super) const fn is_supported_calendar(kind: AnyCalendarKind) -> bool {
+    matches!(
+        kind,
+        AnyCalendarKind::Buddhist
+            | AnyCalendarKind::Chinese
+            | AnyCalendarKind::Coptic
+            | AnyCalendarKind::Dangi
+            | AnyCalendarKind::Ethiopian
+            | AnyCalendarKind::EthiopianAmeteAlem
+            | AnyCalendarKind::Gregorian
+            | AnyCalendarKind::Hebrew
+            | AnyCalendarKind::Indian
+            | AnyCalendarKind::IslamicCivil
+            | AnyCalendarKind::IslamicObservational
+            | AnyCalendarKind::IslamicTabular
+            | AnyCalendarKind::IslamicUmmAlQura
+            | AnyCalendarKind::Japanese
+            | AnyCalendarKind::JapaneseExtended
+            | AnyCalendarKind::Persian
+            | AnyCalendarKind::Roc
+    )
+}
+
+/// The hour cycles preferred by a locale.
+#[derive(Debug, Clone, Copy)]
+pub(in crate::builtins::intl) struct HourCycles {
+    /// The `[[HourCycle]]` field of the locale data.
+    pub(in crate::builtins::intl) default: HourCycle,
+    /// The `[[HourCycle12]]` field of the locale data.
+    pub(super) h12: HourCycle,
+    /// The `[[HourCycle24]]` field of the locale data.
+    pub(super) h24: HourCycle,
+}
+
+/// Gets the hour cycles preferred by `locale`.
+pub(in crate::builtins::intl) fn locale_hour_cycles(
+    locale: &DataLocale,
+    provider: &IntlProvider,
+) -> JsResult<HourCycles> {
+    let lengths = load::<TimeLengthsV1Marker>(locale, provider)?;
+    let lengths = lengths.get();
+
+    let hour_cycle_of = |pattern: &Pattern<'_>, fallback| {
+        pattern
+            .items
+            .iter()
+            .find_map(|item| match item {
+                PatternItem::Field(Field {
+                    symbol: FieldSymbol::Hour(hour),
+                    ..
+                }) => Some(match hour {
+                    fields::Hour::H11 => HourCycle::H11,
+                    fields::Hour::H12 => HourCycle::H12,
+                    fields::Hour::H23 => HourCycle::H23,
+                    fields::Hour::H24 => HourCycle::H24,
+                }),
+                _ => None,
+            })
+            .unwrap_or(fallback)
+    };
+
+    let h12 = hour_cycle_of(&lengths.time_h11_h12.short, HourCycle::H12);
+    let h24 = hour_cycle_of(&lengths.time_h23_h24.short, HourCycle::H23);
+    let default = match lengths.preferred_hour_cycle {
+        CoarseHourCycle::H11H12 => h12,
+        CoarseHourCycle::H23H24 => h24,
+    };
+
+    Ok(HourCycles { default, h12, h24 })
+}
+
+/// Replaces the first hour field of `pattern` with the symbol of the hour cycle `hc`.
+fn apply_hour_cycle(pattern: &mut Pattern<'_>, hc: HourCycle) {
+    let mut items = pattern.items.to_vec();
+    if let Some(PatternItem::Field(field)) = items.iter_mut().find(
+        |item| matches!(item, PatternItem::Field(f) if matches!(f.symbol, FieldSymbol::Hour(_))),
+    ) {
+        field.symbol = FieldSymbol::Hour(hc.field());
+    }
+    *pattern = Pattern::from(items);
+}
+
+/// Gets the pattern used by ICU4X to format the `dateStyle` and `timeStyle` options.
+pub(super) fn pattern_for_styles(
+    date_style: Option<Style>,
+    time_style: Option<Style>,
+    hc: HourCycle,
+    kind: AnyCalendarKind,
+    locale: &DataLocale,
+    provider: &IntlProvider,
+) -> JsResult<Pattern<'static>> {
+    let date = date_style
+        .map(|style| -> JsResult<_> {
+            let lengths = load_date_lengths(kind, locale, provider)?;
+            let date = &lengths.get().date;
+            let pattern = match style {
+                Style::Full => &date.full,
+                Style::Long => &date.long,
+                Style::Medium => &date.medium,
+                Style::Short => &date.short,
+            };
+            Ok((pattern.clone().into_owned(), lengths))
+        })
+        .transpose()?;
+
+    let time = time_style
+        .map(|style| -> JsResult<_> {
+            let lengths = load::<TimeLengthsV1Marker>(locale, provider)?;
+            let lengths = lengths.get();
+            let time = match hc {
+                HourCycle::H11 | HourCycle::H12 => &lengths.time_h11_h12,
+                HourCycle::H23 | HourCycle::H24 => &lengths.time_h23_h24,
+            };
+            let mut pattern = match style {
+                Style::Full => &time.full,
+                Style::Long => &time.long,
+                Style::Medium => &time.medium,
+                Style::Short => &time.short,
+            }
+            .clone()
+            .into_owned();
+            apply_hour_cycle(&mut pattern, hc);
+            Ok(pattern)
+        })
+        .transpose()?;
+
+    match (date, time) {
+        (Some((date, lengths)), Some(time)) => {
+            let combinations = &lengths.get().length_combinations;
+            let glue = match date_style {
+                Some(Style::Full) => &combinations.full,
+                Some(Style::Long) => &combinations.long,
+                Some(Style::Medium) => &combinations.medium,
+                _ => &combinations.short,
+            };
+            glue.clone()
+                .combined(date, time)
+                .map_err(|err| JsNativeError::typ().with_message(err.to_string()).into())
+        }
+        (Some((date, _)), None) => Ok(date),
+        (None, Some(time)) => Ok(time),
+        (None, None) => Ok(Pattern::default()),
+    }
+}
+
+/// Gets the list of fields requested by a components bag, sorted by their symbol.
+fn fields_for_components(bag: &components::Bag) -> Vec<Field> {
+    let mut fields = Vec::new();
+
+    let text_length = |text| match text {
+        components::Text::Short => FieldLength::Abbreviated,
+        components::Text::Long => FieldLength::Wide,
+        _ => FieldLength::Narrow,
+    };
+    let numeric_length = |numeric| match numeric {
+        components::Numeric::TwoDigit => FieldLength::TwoDigit,
+        _ => FieldLength::One,
+    };
+
+    if let Some(era) = bag.era {
+        fields.push(Field {
+            symbol: FieldSymbol::Era,
+            length: text_length(era),
+        });
+    }
+    if let Some(year) = bag.year {
+        fields.push(Field {
+            symbol: FieldSymbol::Year(fields::Year::Calendar),
+            length: match year {
+                components::Year::TwoDigit => FieldLength::TwoDigit,
+                _ => FieldLength::One,
+            },
+        });
+    }
+    if let Some(month) = bag.month {
+        fields.push(Field {
+            symbol: FieldSymbol::Month(fields::Month::Format),
+            length: match month {
+                components::Month::Numeric => FieldLength::One,
+                components::Month::TwoDigit => FieldLength::TwoDigit,
+                components::Month::Long => FieldLength::Wide,
+                components::Month::Short => FieldLength::Abbreviated,
+                _ => FieldLength::Narrow,
+            },
+        });
+    }
+    if let Some(day) = bag.day {
+        fields.push(Field {
+            symbol: FieldSymbol::Day(fields::Day::DayOfMonth),
+            length: match day {
+                components::Day::TwoDigitDayOfMonth => FieldLength::TwoDigit,
+                _ => FieldLength::One,
+            },
+        });
+    }
+    if let Some(weekday) = bag.weekday {
+        fields.push(Field {
+            symbol: FieldSymbol::Weekday(fields::Weekday::Format),
+            length: match weekday {
+                components::Text::Long => FieldLength::Wide,
+                components::Text::Short => FieldLength::One,
+                _ => FieldLength::Narrow,
+            },
+        });
+    }
+    if let Some(hour) = bag.hour {
+        let hc = bag
+            .preferences
+            .and_then(|preferences| preferences.hour_cycle);
+        fields.push(Field {
+            symbol: FieldSymbol::Hour(match hc {
+                Some(HourCycle::H11 | HourCycle::H12) => fields::Hour::H12,
+                _ => fields::Hour::H23,
+            }),
+            length: numeric_length(hour),
+        });
+    }
+    if let Some(minute) = bag.minute {
+        fields.push(Field {
+            symbol: FieldSymbol::Minute,
+            length: numeric_length(minute),
+        });
+    }
+    if let Some(second) = bag.second {
+        fields.push(Field {
+            symbol: FieldSymbol::Second(fields::Second::Second),
+            length: numeric_length(second),
+        });
+    }
+    if let Some(precision) = bag.fractional_second {
+        fields.push(Field {
+            symbol: FieldSymbol::Second(fields::Second::FractionalSecond),
+            length: FieldLength::Fixed(precision),
+        });
+    }
+    if bag.time_zone_name.is_some() {
+        fields.push(Field {
+            symbol: FieldSymbol::TimeZone(fields::TimeZone::LowerV),
+            length: FieldLength::One,
+        });
+    }
+
+    fields
+}
+
+/// Gets the pattern used by ICU4X to format a components bag.
+pub(super) fn pattern_for_components(
+    bag: &components::Bag,
+    kind: AnyCalendarKind,
+    locale: &DataLocale,
+    provider: &IntlProvider,
+) -> JsResult<Pattern<'static>> {
+    // Skeletons are stored under the main variant of every calendar.
+    let calendar = match kind {
+        AnyCalendarKind::EthiopianAmeteAlem => value!("ethiopic"),
+        AnyCalendarKind::IslamicCivil
+        | AnyCalendarKind::IslamicObservational
+        | AnyCalendarKind::IslamicTabular
+        | AnyCalendarKind::IslamicUmmAlQura => value!("islamic"),
+        kind => kind.as_bcp47_value(),
+    };
+    let mut skeletons_locale = locale.clone();
+    skeletons_locale.set_unicode_ext(key!("ca"), calendar);
+
+    let skeletons = load::<DateSkeletonPatternsV1Marker>(&skeletons_locale, provider)?;
+    let lengths = load_date_lengths(kind, locale, provider)?;
+
+    match create_best_pattern_for_fields(
+        skeletons.get(),
+        &lengths.get().length_combinations,
+        &fields_for_components(bag),
+        bag,
+        false,
+    ) {
+        BestSkeleton::AllFieldsMatch(patterns) | BestSkeleton::MissingOrExtraFields(patterns) => {
+            patterns
+                .patterns_iter()
+                .next()
+                .map(|pattern| pattern.clone().into_owned())
+                .ok_or_else(|| {
+                    JsNativeError::typ()
+                        .with_message("could not find a pattern for the date and time options")
+                        .into()
+                })
+        }
+        BestSkeleton::NoMatch => Err(JsNativeError::typ()
+            .with_message("could not find a pattern for the date and time options")
+            .into()),
+    }
+}
+
+/// A part of a formatted date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Part {
+    pub(crate) typ: &'static str,
+    pub(crate) value: String,
+}
+
+/// A token of a pattern, where consecutive literal characters are merged.
+#[derive(Debug)]
+enum Token {
+    Literal(String),
+    Field(Field),
+}
+
+/// Returns `true` if `field` is formatted as a number.
+const fn is_numeric(field: Field) -> bool {
+    match field.symbol {
+        FieldSymbol::Year(year) => !matches!(year, fields::Year::Cyclic),
+        FieldSymbol::Month(_) => matches!(field.length, FieldLength::One | FieldLength::TwoDigit),
+        FieldSymbol::Day(_)
+        | FieldSymbol::Week(_)
+        | FieldSymbol::Hour(_)
+        | FieldSymbol::Minute
+        | FieldSymbol::Second(_) => true,
+        _ => false,
+    }
+}
+
+/// Gets the type of the part produced by formatting `field`.
+const fn part_type(field: Field) -> &'static str {
+    match field.symbol {
+        FieldSymbol::Era => "era",
+        FieldSymbol::Year(fields::Year::Cyclic) => "yearName",
+        FieldSymbol::Year(fields::Year::RelatedIso) => "relatedYear",
+        FieldSymbol::Year(_) => "year",
+        FieldSymbol::Month(_) => "month",
+        FieldSymbol::Day(_) => "day",
+        FieldSymbol::Weekday(_) => "weekday",
+        FieldSymbol::DayPeriod(_) => "dayPeriod",
+        FieldSymbol::Hour(_) => "hour",
+        FieldSymbol::Minute => "minute",
+        FieldSymbol::Second(fields::Second::FractionalSecond) => "fractionalSecond",
+        FieldSymbol::Second(_) => "second",
+        FieldSymbol::TimeZone(_) => "timeZoneName",
+        FieldSymbol::Week(_) => "literal",
+    }
+}
+
+/// Splits a string formatted with `pattern` into its parts.
+///
+/// If the formatted string cannot be matched with the pattern, the whole string is returned
+/// as a single literal part.
+pub(crate) fn partition(pattern: &Pattern<'_>, formatted: &str) -> Vec<Part> {
+    let mut tokens = Vec::new();
+    for item in pattern.items.iter() {
+        match (item, tokens.last_mut()) {
+            (PatternItem::Literal(c), Some(Token::Literal(literal))) => literal.push(c),
+            (PatternItem::Literal(c), _) => tokens.push(Token::Literal(String::from(c))),
+            (PatternItem::Field(field), _) => tokens.push(Token::Field(field)),
+        }
+    }
+
+    let mut parts = Vec::new();
+    if !formatted.is_empty() && !split(&tokens, formatted, &mut parts) {
+        parts = vec![Part {
+            typ: "literal",
+            value: formatted.to_owned(),
+        }];
+    }
+
+    // Join consecutive literals, which can be produced by fractional seconds.
+    parts.dedup_by(|next, prev| {
+        if prev.typ == "literal" && next.typ == "literal" {
+            prev.value.push_str(&next.value);
+            true
+        } else {
+            false
+        }
+    });
+
+    parts
+}
+
+/// Matches the tokens with the formatted string `rest`, backtracking on text fields.
+fn split(tokens: &[Token], rest: &str, parts: &mut Vec<Part>) -> bool {
+    let Some((token, next_tokens)) = tokens.split_first() else {
+        return rest.is_empty();
+    };
+
+    let len = parts.len();
+    let push = |parts: &mut Vec<Part>, typ, value: &str| {
+        parts.push(Part {
+            typ,
+            value: value.to_owned(),
+        });
+    };
+
+    match token {
+        Token::Literal(literal) => {
+            let Some(rest) = rest.strip_prefix(literal.as_str()) else {
+                return false;
+            };
+            push(parts, "literal", literal);
+            if split(next_tokens, rest, parts) {
+                return true;
+            }
+        }
+        Token::Field(field) if is_numeric(*field) => {
+            let mut rest = rest;
+
+            // The decimal separator of fractional seconds is written by the seconds field.
+            if field.symbol == FieldSymbol::Second(fields::Second::FractionalSecond) {
+                let end = rest.find(char::is_numeric).unwrap_or(rest.len());
+                if end > 0 {
+                    push(parts, "literal", &rest[..end]);
+                    rest = &rest[end..];
+                }
+            }
+
+            let end = rest.find(|c: char| !c.is_numeric()).unwrap_or(rest.len());
+            if end == 0 {
+                parts.truncate(len);
+                return false;
+            }
+            push(parts, part_type(*field), &rest[..end]);
+            if split(next_tokens, &rest[end..], parts) {
+                return true;
+            }
+        }
+        Token::Field(field) => {
+            let ends: Vec<usize> = match next_tokens.first() {
+                None if rest.is_empty() => Vec::new(),
+                None => vec![rest.len()],
+                Some(Token::Literal(literal)) => rest
+                    .match_indices(literal.as_str())
+                    .map(|(i, _)| i)
+                    .filter(|i| *i > 0)
+                    .collect(),
+                Some(Token::Field(next)) if is_numeric(*next) => {
+                    rest.find(char::is_numeric).into_iter().collect()
+                }
+                Some(Token::Field(_)) => Vec::new(),
+            };
+
+            for end in ends {
+                push(parts, part_type(*field), &rest[..end]);
+                if split(next_tokens, &rest[end..], parts) {
+                    return true;
+                }
+                parts.truncate(len);
+            }
+        }
+    }
+
+    parts.truncate(len);
+    false
+}
@@ -0,0 +1,134 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+#[test]
+fn date_time_format_format() {
+    run_test_actions([
+        TestAction::run("const date = Date.UTC(2024, 2, 5, 14, 7, 9, 120);"),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en', { timeZone: 'UTC' }).format(date)",
+            js_string!("3/5/2024"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('de', { timeZone: 'Europe/Berlin', dateStyle: 'medium', timeStyle: 'short' }).format(date)",
+            js_string!("05.03.2024, 15:07"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en', { timeZone: 'UTC', hour: 'numeric', hourCycle: 'h23' }).format(date)",
+            js_string!("14"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en', { timeZone: '+05:30', hour: 'numeric', minute: '2-digit' }).format(date)",
+            js_string!("7:37\u{202f}PM"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en', { timeZone: 'UTC', hour: 'numeric', minute: '2-digit', second: '2-digit', fractionalSecondDigits: 2 }).format(date)",
+            js_string!("2:07:09.12\u{202f}PM"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en-u-ca-japanese', { timeZone: 'UTC', dateStyle: 'long' }).format(date)",
+            js_string!("March 5, 6 Reiwa"),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DateTimeFormat('en', { timeZone: 'Mars/Olympus_Mons' })",
+            JsNativeErrorKind::Range,
+            "unsupported time zone `Mars/Olympus_Mons`",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DateTimeFormat('en', { dateStyle: 'short', year: 'numeric' })",
+            JsNativeErrorKind::Type,
+            "`dateStyle` and `timeStyle` cannot be used with date or time components",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DateTimeFormat('en').format(NaN)",
+            JsNativeErrorKind::Range,
+            "cannot format an invalid date",
+        ),
+    ]);
+}
+
+#[test]
+fn date_time_format_format_to_parts() {
+    run_test_actions([
+        TestAction::run(
+            "const parts = new Intl.DateTimeFormat('en', { timeZone: 'UTC' }).formatToParts(Date.UTC(2024, 2, 5));",
+        ),
+        TestAction::assert_eq(
+            "JSON.stringify(parts)",
+            js_string!(
+                r#"[{"type":"month","value":"3"},{"type":"literal","value":"/"},{"type":"day","value":"5"},{"type":"literal","value":"/"},{"type":"year","value":"2024"}]"#
+            ),
+        ),
+    ]);
+}
+
+#[test]
+fn date_time_format_format_range() {
+    run_test_actions([
+        TestAction::run("const dtf = new Intl.DateTimeFormat('en', { timeZone: 'UTC' });"),
+        TestAction::assert_eq(
+            "dtf.formatRange(Date.UTC(2024, 2, 5), Date.UTC(2024, 2, 6))",
+            js_string!("3/5/2024 – 3/6/2024"),
+        ),
+        TestAction::assert_eq(
+            "dtf.formatRange(Date.UTC(2024, 2, 5, 1), Date.UTC(2024, 2, 5, 2))",
+            js_string!("3/5/2024"),
+        ),
+        TestAction::assert_eq(
+            "dtf.formatRangeToParts(Date.UTC(2024, 2, 5), Date.UTC(2024, 2, 6))[0].source",
+            js_string!("startRange"),
+        ),
+        TestAction::assert_native_error(
+            "dtf.formatRange(Date.UTC(2024, 2, 5))",
+            JsNativeErrorKind::Type,
+            "`formatRange` requires both a start and an end date",
+        ),
+    ]);
+}
+
+#[test]
+fn date_time_format_resolved_options() {
+    run_test_actions([
+        TestAction::run(
+            "const options = new Intl.DateTimeFormat('en', { timeZone: 'america/new_york', hour: 'numeric', hour12: false }).resolvedOptions();",
+        ),
+        TestAction::assert_eq("options.locale", js_string!("en")),
+        TestAction::assert_eq("options.calendar", js_string!("gregory")),
+        TestAction::assert_eq("options.timeZone", js_string!("America/New_York")),
+        TestAction::assert_eq("options.hourCycle", js_string!("h23")),
+        TestAction::assert_eq("options.hour12", false),
+        TestAction::assert_eq("options.hour", js_string!("2-digit")),
+        TestAction::assert_eq("options.year", JsValue::undefined()),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en', { timeZone: 'Etc/GMT' }).resolvedOptions().timeZone",
+            js_string!("UTC"),
+        ),
+    ]);
+}
+
+#[test]
+fn date_to_locale_string() {
+    run_test_actions([
+        TestAction::run("const date = new Date(Date.UTC(2024, 2, 5, 13, 5, 9));"),
+        TestAction::assert_eq(
+            "date.toLocaleDateString('en', { timeZone: 'UTC' })",
+            js_string!("3/5/2024"),
+        ),
+        TestAction::assert_eq(
+            "date.toLocaleTimeString('en', { timeZone: 'UTC' })",
+            js_string!("1:05:09\u{202f}PM"),
+        ),
+        TestAction::assert_eq(
+            "date.toLocaleString('en', { timeZone: 'UTC' })",
+            js_string!("3/5/2024, 1:05:09\u{202f}PM"),
+        ),
+        TestAction::assert_eq(
+            "new Date(NaN).toLocaleString('en')",
+            js_string!("Invalid Date"),
+        ),
+        TestAction::assert_native_error(
+            "date.toLocaleDateString('en', { timeStyle: 'short' })",
+            JsNativeErrorKind::Type,
+            "`timeStyle` cannot be used when formatting only a date",
+        ),
+    ]);
+}
@@ -0,0 +1,180 @@
+use icu_calendar::{DateTime, Iso};
+use icu_timezone::{
+    CustomTimeZone, GmtOffset, IanaBcp47RoundTripMapper, MetazoneCalculator, TimeZoneBcp47Id,
+    ZoneVariant,
+};
+use tinystr::tinystr;
+
+use crate::{context::icu::IntlProvider, JsNativeError, JsResult};
+
+/// The time zone of an `Intl.DateTimeFormat`.
+#[derive(Debug)]
+pub(crate) enum TimeZone {
+    /// A fixed UTC offset in minutes, such as `+05:30`.
+    Offset(i32),
+    /// An IANA time zone.
+    Named(Box<NamedTimeZone>),
+}
+
+/// An IANA time zone, with the rules used to calculate its offsets.
+#[derive(Debug)]
+pub(crate) struct NamedTimeZone {
+    /// The canonical IANA identifier of the time zone.
+    identifier: String,
+    bcp47: TimeZoneBcp47Id,
+    rules: tz::TimeZone,
+    metazones: MetazoneCalculator,
+}
+
+impl TimeZone {
+    /// Abstract operation [`SystemTimeZoneIdentifier ( )`][spec].
+    ///
+    /// Returns the time zone of the host, falling back to UTC if it is unknown or unsupported.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-systemtimezoneidentifier
+    pub(crate) fn default(provider: &IntlProvider) -> JsResult<Self> {
+        iana_time_zone::get_timezone()
+            .ok()
+            .and_then(|name| Self::from_named(&name, provider).ok())
+            .map_or_else(|| Self::from_named("UTC", provider), Ok)
+    }
+
+    /// Parses a time zone identifier, which can be either an IANA time zone name or a UTC
+    /// offset.
+    ///
+    /// Throws a `RangeError` if the time zone is invalid or not supported.
+    pub(crate) fn from_identifier(identifier: &str, provider: &IntlProvider) -> JsResult<Self> {
+        if identifier.starts_with(['+', '-']) {
+            return parse_offset(identifier).map(Self::Offset).ok_or_else(|| {
+                JsNativeError::range()
+                    .with_message(format!("invalid UTC offset time zone `{identifier}`"))
+                    .into()
+            });
+        }
+
+        Self::from_named(identifier, provider)
+    }
+
+    fn from_named(name: &str, provider: &IntlProvider) -> JsResult<Self> {
+        let invalid =
+            || JsNativeError::range().with_message(format!("unsupported time zone `{name}`"));
+
+        let metazones = MetazoneCalculator::try_new_unstable(provider)
+            .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+
+        // Per the spec, all the aliases of UTC must be canonicalized to "UTC".
+        if ["etc/utc", "etc/gmt", "utc", "gmt"].contains(&name.to_ascii_lowercase().as_str()) {
+            return Ok(Self::Named(Box::new(NamedTimeZone {
+                identifier: String::from("UTC"),
+                bcp47: TimeZoneBcp47Id(tinystr!(8, "utc")),
+                rules: tz::TimeZone::utc(),
+                metazones,
+            })));
+        }
+
+        let mapper = IanaBcp47RoundTripMapper::try_new_unstable(provider)
+            .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+        let mapper = mapper.as_borrowed();
+        let bcp47 = mapper.iana_to_bcp47(name).ok_or_else(invalid)?;
+        let identifier = mapper.bcp47_to_iana(bcp47).ok_or_else(invalid)?;
+
+        // The leading colon forces reading the rules from the system time zone database.
+        let rules =
+            tz::TimeZone::from_posix_tz(&format!(":{identifier}")).map_err(|_| invalid())?;
+
+        Ok(Self::Named(Box::new(NamedTimeZone {
+            identifier: identifier.to_owned(),
+            bcp47,
+            rules,
+            metazones,
+        })))
+    }
+
+    /// Gets the identifier of this time zone, as returned by `resolvedOptions`.
+    pub(crate) fn identifier(&self) -> String {
+        match self {
+            Self::Offset(minutes) => format_offset(*minutes),
+            Self::Named(tz) => tz.identifier.clone(),
+        }
+    }
+
+    /// Gets the offset from UTC in seconds of this time zone at the epoch milliseconds `epoch_ms`,
+    /// and whether the time zone is observing daylight saving time.
+    pub(crate) fn offset_at(&self, epoch_ms: f64) -> (i32, bool) {
+        match self {
+            Self::Offset(minutes) => (*minutes * 60, false),
+            Self::Named(tz) => {
+                #[allow(clippy::cast_possible_truncation)]
+                let seconds = (epoch_ms / 1000.0).floor() as i64;
+                tz.rules
+                    .find_local_time_type(seconds)
+                    .map_or((0, false), |ty| (ty.ut_offset(), ty.is_dst()))
+            }
+        }
+    }
+
+    /// Creates the ICU4X representation of this time zone at the local date and time
+    /// `local`.
+    pub(crate) fn to_custom_time_zone(
+        &self,
+        offset: i32,
+        is_dst: bool,
+        local: &DateTime<Iso>,
+    ) -> CustomTimeZone {
+        let gmt_offset = GmtOffset::try_from_offset_seconds(offset).ok();
+        match self {
+            Self::Offset(_) => CustomTimeZone {
+                gmt_offset,
+                time_zone_id: None,
+                metazone_id: None,
+                zone_variant: None,
+            },
+            Self::Named(tz) => CustomTimeZone {
+                gmt_offset,
+                time_zone_id: Some(tz.bcp47),
+                metazone_id: tz
+                    .metazones
+                    .compute_metazone_from_time_zone(tz.bcp47, local),
+                zone_variant: Some(if is_dst {
+                    ZoneVariant::daylight()
+                } else {
+                    ZoneVariant::standard()
+                }),
+            },
+        }
+    }
+}
+
+/// Parses a UTC offset of the form `±HH`, `±HHMM` or `±HH:MM`, returning the offset in minutes.
+fn parse_offset(offset: &str) -> Option<i32> {
+    let (sign, rest) = match offset.as_bytes() {
+        [b'+', rest @ ..] => (1, rest),
+        [b'-', rest @ ..] => (-1, rest),
+        _ => return None,
+    };
+
+    let (hours, minutes) = match rest {
+        [h1, h2] => ([*h1, *h2], [b'0', b'0']),
+        [h1, h2, m1, m2] | [h1, h2, b':', m1, m2] => ([*h1, *h2], [*m1, *m2]),
+        _ => return None,
+    };
+
+    let parse_two_digits = |digits: [u8; 2]| {
+        digits
+            .iter()
+            .all(u8::is_ascii_digit)
+            .then(|| i32::from(digits[0] - b'0') * 10 + i32::from(digits[1] - b'0'))
+    };
+
+    let hours = parse_two_digits(hours).filter(|h| *h <= 23)?;
+    let minutes = parse_two_digits(minutes).filter(|m| *m <= 59)?;
+
+    Some(sign * (hours * 60 + minutes))
+}
+
+/// Formats a UTC offset in minutes as `±HH:MM`.
+fn format_offset(minutes: i32) -> String {
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let minutes = minutes.unsigned_abs();
+    format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+}
@@ -0,0 +1,623 @@
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_displaynames::{
+    provider::LanguageDisplayNamesV1Marker, DisplayNamesOptions, Fallback, LanguageDisplay,
+    LanguageDisplayNames, LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames,
+    Style, VariantDisplayNames,
+};
+use icu_locid::{
+    subtags::{Region, Script},
+    LanguageIdentifier, Locale,
+};
+use icu_provider::DataLocale;
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::{common::StaticJsStrings, utf16},
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales},
+    number_format::data::currency_data,
+    options::IntlOptions,
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DisplayNames` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DisplayNames {
+    locale: Locale,
+    style: Style,
+    typ: DisplayNamesType,
+    fallback: Fallback,
+    language_display: Option<LanguageDisplay>,
+    native: NativeDisplayNames,
+}
+
+/// The ICU4X formatters used to look up the display names of a `DisplayNames` object.
+enum NativeDisplayNames {
+    Language(Box<LanguageNames>),
+    Region(RegionDisplayNames),
+    Script(ScriptDisplayNames),
+    /// ICU4X doesn't have data for the display names of currencies, so they are looked up in the
+    /// currency data of `Intl.NumberFormat`.
+    Currency,
+    // TODO: ICU4X doesn't have data for the display names of calendars and date-time fields
+    // yet, so codes of those types always use the fallback.
+    Unsupported,
+}
+
+/// The formatters needed to build the display name of a language identifier.
+struct LanguageNames {
+    language: LanguageDisplayNames,
+    script: ScriptDisplayNames,
+    region: RegionDisplayNames,
+    variant: VariantDisplayNames,
+    dialect: Option<LocaleDisplayNamesFormatter>,
+}
+
+impl LanguageNames {
+    /// Returns the display name of `id`, or `None` if there is no display name for its
+    /// language subtag.
+    fn of(&self, id: &LanguageIdentifier) -> Option<String> {
+        let language = self.language.of(id.language)?;
+
+        if let Some(dialect) = &self.dialect {
+            return Some(dialect.of(&Locale::from(id.clone())).into_owned());
+        }
+
+        let qualifiers = id
+            .script
+            .as_ref()
+            .map(|script| self.script.of(*script).unwrap_or(script.as_str()))
+            .into_iter()
+            .chain(
+                id.region
+                    .as_ref()
+                    .map(|region| self.region.of(*region).unwrap_or(region.as_str())),
+            )
+            .chain(
+                id.variants
+                    .iter()
+                    .map(|variant| self.variant.of(*variant).unwrap_or(variant.as_str())),
+            )
+            .collect::<Vec<_>>();
+
+        if qualifiers.is_empty() {
+            return Some(language.to_owned());
+        }
+
+        Some(format!("{language} ({})", qualifiers.join(", ")))
+    }
+}
+
+impl std::fmt::Debug for NativeDisplayNames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Language(_) => "Language",
+            Self::Region(_) => "Region",
+            Self::Script(_) => "Script",
+            Self::Currency => "Currency",
+            Self::Unsupported => "Unsupported",
+        };
+        f.debug_struct(name).finish_non_exhaustive()
+    }
+}
+
+impl NativeDisplayNames {
+    fn try_new(
+        typ: DisplayNamesType,
+        language_display: LanguageDisplay,
+        locale: &DataLocale,
+        options: DisplayNamesOptions,
+        provider: &IntlProvider,
+    ) -> JsResult<Self> {
+        let map_err = |e: icu_provider::DataError| JsNativeError::typ().with_message(e.to_string());
+        Ok(match typ {
+            DisplayNamesType::Language => Self::Language(Box::new(LanguageNames {
+                language: LanguageDisplayNames::try_new_unstable(provider, locale, options.clone())
+                    .map_err(map_err)?,
+                script: ScriptDisplayNames::try_new_unstable(provider, locale, options.clone())
+                    .map_err(map_err)?,
+                region: RegionDisplayNames::try_new_unstable(provider, locale, options.clone())
+                    .map_err(map_err)?,
+                variant: VariantDisplayNames::try_new_unstable(provider, locale, options.clone())
+                    .map_err(map_err)?,
+                dialect: if language_display == LanguageDisplay::Dialect {
+                    Some(
+                        LocaleDisplayNamesFormatter::try_new_unstable(provider, locale, options)
+                            .map_err(map_err)?,
+                    )
+                } else {
+                    None
+                },
+            })),
+            DisplayNamesType::Region => Self::Region(
+                RegionDisplayNames::try_new_unstable(provider, locale, options).map_err(map_err)?,
+            ),
+            DisplayNamesType::Script => Self::Script(
+                ScriptDisplayNames::try_new_unstable(provider, locale, options).map_err(map_err)?,
+            ),
+            DisplayNamesType::Currency => Self::Currency,
+            DisplayNamesType::Calendar | DisplayNamesType::DateTimeField => Self::Unsupported,
+        })
+    }
+}
+
+impl Service for DisplayNames {
+    type LangMarker = LanguageDisplayNamesV1Marker;
+
+    type LocaleOptions = ();
+}
+
+impl IntrinsicObject for DisplayNames {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DisplayNames"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::of, js_string!("of"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisplayNames {
+    const NAME: JsString = StaticJsStrings::DISPLAY_NAMES;
+}
+
+impl BuiltInConstructor for DisplayNames {
+    const LENGTH: usize = 2;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::display_names;
+
+    /// Constructor [`Intl.DisplayNames ( locales, options )`][spec].
+    ///
+    /// Constructor for `DisplayNames` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DisplayNames` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. If options is undefined, throw a TypeError exception.
+        if options.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("`Intl.DisplayNames` requires an options object")
+                .into());
+        }
+
+        // 5. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 6. Let opt be a new Record.
+        // 7. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher = get_option(&options, utf16!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 8. Set opt.[[localeMatcher]] to matcher.
+        // 9. Let localeData be %DisplayNames%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%DisplayNames%.[[AvailableLocales]], requestedLocales, opt, %DisplayNames%.[[RelevantExtensionKeys]], localeData).
+        let locale = resolve_locale::<Self>(
+            &requested_locales,
+            &mut IntlOptions {
+                matcher,
+                ..Default::default()
+            },
+            context.intl_provider(),
+        );
+
+        // 11. Let style be ? GetOption(options, "style", string, « "narrow", "short", "long" », "long").
+        // 12. Set displayNames.[[Style]] to style.
+        let style = get_option(&options, utf16!("style"), context)?.unwrap_or(Style::Long);
+
+        // 13. Let type be ? GetOption(options, "type", string, « "language", "region", "script", "currency", "calendar", "dateTimeField" », undefined).
+        // 14. If type is undefined, throw a TypeError exception.
+        // 15. Set displayNames.[[Type]] to type.
+        let typ = get_option::<DisplayNamesType>(&options, utf16!("type"), context)?.ok_or_else(
+            || JsNativeError::typ().with_message("`Intl.DisplayNames` requires a `type` option"),
+        )?;
+
+        // 16. Let fallback be ? GetOption(options, "fallback", string, « "code", "none" », "code").
+        // 17. Set displayNames.[[Fallback]] to fallback.
+        let fallback = get_option(&options, utf16!("fallback"), context)?.unwrap_or(Fallback::Code);
+
+        // 18. Set displayNames.[[Locale]] to r.[[locale]].
+        // 19. Let resolvedLocaleData be r.[[LocaleData]].
+        // 20. Let types be resolvedLocaleData.[[types]].
+        // 21. Assert: types is a Record (see 12.2.3).
+        // 22. Let languageDisplay be ? GetOption(options, "languageDisplay", string, « "dialect", "standard" », "dialect").
+        let language_display = get_option(&options, utf16!("languageDisplay"), context)?
+            .unwrap_or(LanguageDisplay::Dialect);
+
+        // 23. Let typeFields be types.[[<type>]].
+        // 24. Assert: typeFields is a Record (see 12.2.3).
+        // 25. If type is "language", then
+        //     a. Set displayNames.[[LanguageDisplay]] to languageDisplay.
+        //     b. Let typeFields be typeFields.[[<languageDisplay>]].
+        //     c. Assert: typeFields is a Record (see 12.2.3).
+        // 26. Let styleFields be typeFields.[[<style>]].
+        // 27. Assert: styleFields is a Record (see 12.2.3).
+        // 28. Set displayNames.[[Fields]] to styleFields.
+        let mut icu_options = DisplayNamesOptions::default();
+        // The "long" style of ECMA-402 corresponds to the default names of ICU4X, which are
+        // used when no style is set.
+        icu_options.style = (style != Style::Long).then_some(style);
+        icu_options.fallback = fallback;
+        icu_options.language_display = language_display;
+
+        let native = NativeDisplayNames::try_new(
+            typ,
+            language_display,
+            &DataLocale::from(&locale),
+            icu_options,
+            context.intl_provider(),
+        )?;
+
+        // 2. Let displayNames be ? OrdinaryCreateFromConstructor(NewTarget, "%DisplayNames.prototype%", « [[InitializedDisplayNames]], [[Locale]], [[Style]], [[Type]], [[Fallback]], [[LanguageDisplay]], [[Fields]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::display_names,
+            context,
+        )?;
+        let display_names = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                style,
+                typ,
+                fallback,
+                language_display: (typ == DisplayNamesType::Language).then_some(language_display),
+                native,
+            },
+        );
+
+        // 29. Return displayNames.
+        Ok(display_names.into())
+    }
+}
+
+impl DisplayNames {
+    /// [`Intl.DisplayNames.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in display
+    /// names without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %DisplayNames%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.DisplayNames.prototype.of ( code )`][spec].
+    ///
+    /// Returns the localized display name of the provided code.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of
+    fn of(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let object = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("`of` can only be called on a `DisplayNames` object")
+        })?;
+        let display_names = object.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message("`of` can only be called on a `DisplayNames` object")
+        })?;
+
+        // 3. Let code be ? ToString(code).
+        let code = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        // 4. Set code to ? CanonicalCodeForDisplayNames(displayNames.[[Type]], code).
+        // 5. Let fields be displayNames.[[Fields]].
+        // 6. If fields has a field [[<code>]], return fields.[[<code>]].
+        let (code, name) = match &display_names.native {
+            NativeDisplayNames::Language(names) => {
+                let id = canonical_language_code(&code, context)?;
+                (id.to_string(), names.of(&id))
+            }
+            NativeDisplayNames::Region(names) => {
+                let region = Region::try_from_bytes(code.as_bytes())
+                    .map_err(|_| JsNativeError::range().with_message("invalid region code"))?;
+                (
+                    region.as_str().to_owned(),
+                    names.of(region).map(str::to_owned),
+                )
+            }
+            NativeDisplayNames::Script(names) => {
+                let script = Script::try_from_bytes(code.as_bytes())
+                    .map_err(|_| JsNativeError::range().with_message("invalid script code"))?;
+                (
+                    script.as_str().to_owned(),
+                    names.of(script).map(str::to_owned),
+                )
+            }
+            NativeDisplayNames::Currency => {
+                let code = canonical_code_for_display_names(display_names.typ, &code)?;
+                let name = currency_data(&code).map(|data| {
+                    match display_names.style {
+                        Style::Narrow => data.narrow_symbol,
+                        Style::Short => data.symbol,
+                        _ => data.display_name,
+                    }
+                    .to_owned()
+                });
+                (code, name)
+            }
+            NativeDisplayNames::Unsupported => (
+                canonical_code_for_display_names(display_names.typ, &code)?,
+                None,
+            ),
+        };
+
+        if let Some(name) = name {
+            return Ok(js_string!(name).into());
+        }
+
+        // 7. If displayNames.[[Fallback]] is "code", return code.
+        if display_names.fallback == Fallback::Code {
+            return Ok(js_string!(code).into());
+        }
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`Intl.DisplayNames.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.DisplayNames` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let object = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `DisplayNames` object")
+        })?;
+        let display_names = object.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `DisplayNames` object")
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 16, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of displayNames's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                utf16!("locale"),
+                js_string!(display_names.locale.to_string()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                utf16!("style"),
+                match display_names.style {
+                    Style::Narrow => js_string!("narrow"),
+                    Style::Short => js_string!("short"),
+                    Style::Long => js_string!("long"),
+                    _ => unreachable!(),
+                },
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                utf16!("type"),
+                js_string!(display_names.typ.as_str()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                utf16!("fallback"),
+                match display_names.fallback {
+                    Fallback::Code => js_string!("code"),
+                    Fallback::None => js_string!("none"),
+                    _ => unreachable!(),
+                },
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        if let Some(language_display) = display_names.language_display {
+            options
+                .create_data_property_or_throw(
+                    utf16!("languageDisplay"),
+                    match language_display {
+                        LanguageDisplay::Dialect => js_string!("dialect"),
+                        LanguageDisplay::Standard => js_string!("standard"),
+                        _ => unreachable!(),
+                    },
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Canonicalizes a `language` code as described in steps 1.a-c of
+/// [`CanonicalCodeForDisplayNames ( type, code )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_language_code(code: &str, context: &Context) -> JsResult<LanguageIdentifier> {
+    // a. If code cannot be matched by the unicode_language_id Unicode locale nonterminal, throw a RangeError exception.
+    // b. If IsStructurallyValidLanguageTag(code) is false, throw a RangeError exception.
+    let id = if code.contains('_') {
+        None
+    } else {
+        LanguageIdentifier::try_from_bytes(code.as_bytes()).ok()
+    }
+    .ok_or_else(|| JsNativeError::range().with_message("invalid language code"))?;
+
+    // c. Return CanonicalizeUnicodeLocaleId(code).
+    let mut locale = Locale::from(id);
+    context
+        .intl_provider()
+        .locale_canonicalizer()
+        .canonicalize(&mut locale);
+    Ok(locale.id)
+}
+
+/// Abstract operation [`CanonicalCodeForDisplayNames ( type, code )`][spec], for the types
+/// that don't have ICU4X display names data.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_code_for_display_names(typ: DisplayNamesType, code: &str) -> JsResult<String> {
+    match typ {
+        // 4. If type is "calendar", then
+        DisplayNamesType::Calendar => {
+            // a. If code cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+            let is_type = code.split('-').all(|part| {
+                (3..=8).contains(&part.len()) && part.bytes().all(|b| b.is_ascii_alphanumeric())
+            });
+            // b. If code uses any of the backwards compatibility syntax described in Unicode Technical Standard #35 LDML § 3.3 BCP 47 Conformance, throw a RangeError exception.
+            if !is_type || code.contains('_') {
+                return Err(JsNativeError::range()
+                    .with_message("invalid calendar code")
+                    .into());
+            }
+
+            // c. Set code to CanonicalizeUValue("ca", code).
+            // d. Return code.
+            let code = code.to_ascii_lowercase();
+            Ok(match code.as_str() {
+                "ethiopic-amete-alem" => "ethioaa".to_owned(),
+                "islamicc" => "islamic-civil".to_owned(),
+                _ => code,
+            })
+        }
+        // 5. If type is "dateTimeField", then
+        DisplayNamesType::DateTimeField => {
+            // a. If the result of IsValidDateTimeFieldCode(code) is false, throw a RangeError exception.
+            // b. Return code.
+            if matches!(
+                code,
+                "era"
+                    | "year"
+                    | "quarter"
+                    | "month"
+                    | "weekOfYear"
+                    | "weekday"
+                    | "day"
+                    | "dayPeriod"
+                    | "hour"
+                    | "minute"
+                    | "second"
+                    | "timeZoneName"
+            ) {
+                Ok(code.to_owned())
+            } else {
+                Err(JsNativeError::range()
+                    .with_message("invalid date-time field code")
+                    .into())
+            }
+        }
+        // 6. Assert: type is "currency".
+        // 7. If IsWellFormedCurrencyCode(code) is false, throw a RangeError exception.
+        // 8. Return the ASCII-uppercase of code.
+        DisplayNamesType::Currency => {
+            if code.len() == 3 && code.bytes().all(|b| b.is_ascii_alphabetic()) {
+                Ok(code.to_ascii_uppercase())
+            } else {
+                Err(JsNativeError::range()
+                    .with_message("invalid currency code")
+                    .into())
+            }
+        }
+        DisplayNamesType::Language | DisplayNamesType::Region | DisplayNamesType::Script => {
+            unreachable!("codes of this type are canonicalized with their display names data")
+        }
+    }
+}
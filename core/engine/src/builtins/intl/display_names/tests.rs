@@ -0,0 +1,98 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+#[test]
+fn display_names_of() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'region' }).of('us')",
+            js_string!("United States"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'script' }).of('latn')",
+            js_string!("Latin"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'language' }).of('fr')",
+            js_string!("French"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'language' }).of('en-GB')",
+            js_string!("British English"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'language', languageDisplay: 'standard' }).of('en-GB')",
+            js_string!("English (United Kingdom)"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('es', { type: 'language' }).of('de')",
+            js_string!("alemán"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'currency' }).of('usd')",
+            js_string!("US Dollar"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'currency', style: 'short' }).of('EUR')",
+            js_string!("€"),
+        ),
+    ]);
+}
+
+#[test]
+fn display_names_fallback() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'region' }).of('XY')",
+            js_string!("XY"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'region', fallback: 'none' }).of('XY')",
+            JsValue::undefined(),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'currency' }).of('xyz')",
+            js_string!("XYZ"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'calendar' }).of('islamicc')",
+            js_string!("islamic-civil"),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', { type: 'language' }).of('root')",
+            JsNativeErrorKind::Range,
+            "invalid language code",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', { type: 'dateTimeField' }).of('days')",
+            JsNativeErrorKind::Range,
+            "invalid date-time field code",
+        ),
+    ]);
+}
+
+#[test]
+fn display_names_options() {
+    run_test_actions([
+        TestAction::run(
+            "const options = new Intl.DisplayNames('en', { type: 'language' }).resolvedOptions();",
+        ),
+        TestAction::assert_eq("options.locale", js_string!("en")),
+        TestAction::assert_eq("options.style", js_string!("long")),
+        TestAction::assert_eq("options.type", js_string!("language")),
+        TestAction::assert_eq("options.fallback", js_string!("code")),
+        TestAction::assert_eq("options.languageDisplay", js_string!("dialect")),
+        TestAction::assert(
+            "!('languageDisplay' in new Intl.DisplayNames('en', { type: 'region' }).resolvedOptions())",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en')",
+            JsNativeErrorKind::Type,
+            "`Intl.DisplayNames` requires an options object",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', {})",
+            JsNativeErrorKind::Type,
+            "`Intl.DisplayNames` requires a `type` option",
+        ),
+    ]);
+}
@@ -0,0 +1,1297 @@
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use fixed_decimal::{FixedDecimal, FloatPrecision, Sign};
+use icu_list::provider::UnitListV1Marker;
+use icu_locid::{extensions::unicode::Value, Locale};
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::{common::StaticJsStrings, utf16},
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    list_format::{ListFormat, ListPart},
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales},
+    number_format::{NumberFormat, NumberFormatLocaleOptions, NumberPart},
+    options::{get_number_option, IntlOptions},
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct DurationFormat {
+    #[unsafe_ignore_trace]
+    locale: Locale,
+    #[unsafe_ignore_trace]
+    numbering_system: Option<Value>,
+    #[unsafe_ignore_trace]
+    style: BaseStyle,
+    #[unsafe_ignore_trace]
+    units: [UnitOptions; 10],
+    #[unsafe_ignore_trace]
+    fractional_digits: Option<u8>,
+    list_format: JsObject<ListFormat>,
+}
+
+/// A [Duration Record][spec], with the value of every unit indexed by [`DurationUnit`].
+///
+/// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-duration-records
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DurationRecord([f64; 10]);
+
+impl DurationRecord {
+    /// Creates a new `DurationRecord` from the values of its units, from years to nanoseconds.
+    pub(crate) const fn new(values: [f64; 10]) -> Self {
+        Self(values)
+    }
+
+    /// Gets the value of `unit`.
+    const fn get(&self, unit: DurationUnit) -> f64 {
+        self.0[unit as usize]
+    }
+
+    /// Abstract operation [`DurationSign ( duration )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-durationsign
+    fn sign(&self) -> i8 {
+        // 1. For each value v of « duration.[[Years]], ..., duration.[[Nanoseconds]] », do
+        //     a. If v < 0, return -1.
+        //     b. If v > 0, return 1.
+        // 2. Return 0.
+        self.0
+            .iter()
+            .find(|v| **v != 0.0)
+            .map_or(0, |v| if *v < 0.0 { -1 } else { 1 })
+    }
+
+    /// Abstract operation [`IsValidDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-isvalidduration
+    fn is_valid(&self) -> bool {
+        // 1. Let sign be DurationSign(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+        let sign = self.sign();
+
+        // 2. For each value v of « years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds », do
+        for v in self.0 {
+            // a. If 𝔽(v) is not finite, return false.
+            if !v.is_finite() {
+                return false;
+            }
+            // b. If v < 0 and sign > 0, return false.
+            // c. If v > 0 and sign < 0, return false.
+            if (v < 0.0 && sign > 0) || (v > 0.0 && sign < 0) {
+                return false;
+            }
+        }
+
+        // 3. If abs(years) ≥ 2^32, return false.
+        // 4. If abs(months) ≥ 2^32, return false.
+        // 5. If abs(weeks) ≥ 2^32, return false.
+        let max_calendar = 2f64.powi(32);
+        if [
+            DurationUnit::Years,
+            DurationUnit::Months,
+            DurationUnit::Weeks,
+        ]
+        .iter()
+        .any(|unit| self.get(*unit).abs() >= max_calendar)
+        {
+            return false;
+        }
+
+        // 6. Let normalizedSeconds be days × 86,400 + hours × 3600 + minutes × 60 + seconds + ℝ(𝔽(milliseconds)) × 10^-3 + ℝ(𝔽(microseconds)) × 10^-6 + ℝ(𝔽(nanoseconds)) × 10^-9.
+        // 7. NOTE: The above step cannot be implemented directly using floating-point arithmetic. [...]
+        let normalized_seconds = self.get(DurationUnit::Days) * 86_400.0
+            + self.get(DurationUnit::Hours) * 3600.0
+            + self.get(DurationUnit::Minutes) * 60.0
+            + self.get(DurationUnit::Seconds)
+            + self.get(DurationUnit::Milliseconds) * 1e-3
+            + self.get(DurationUnit::Microseconds) * 1e-6
+            + self.get(DurationUnit::Nanoseconds) * 1e-9;
+
+        // 8. If abs(normalizedSeconds) ≥ 2^53, return false.
+        // 9. Return true.
+        normalized_seconds.abs() < 2f64.powi(53)
+    }
+}
+
+/// A part of a formatted duration.
+#[derive(Debug)]
+struct Part {
+    typ: &'static str,
+    value: String,
+    unit: Option<&'static str>,
+}
+
+impl Part {
+    fn literal(value: String) -> Self {
+        Self {
+            typ: "literal",
+            value,
+            unit: None,
+        }
+    }
+}
+
+impl Service for DurationFormat {
+    type LangMarker = UnitListV1Marker;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        // The only relevant extension key is "nu", which is resolved in the same way as for
+        // `Intl.NumberFormat`.
+        NumberFormat::resolve(locale, options, provider);
+    }
+}
+
+impl IntrinsicObject for DurationFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DurationFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 1)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DurationFormat {
+    const NAME: JsString = StaticJsStrings::DURATION_FORMAT;
+}
+
+impl BuiltInConstructor for DurationFormat {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::duration_format;
+
+    /// Constructor [`Intl.DurationFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `DurationFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-intl-durationformat-constructor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/DurationFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DurationFormat` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 2. Let durationFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%DurationFormatPrototype%", « [[InitializedDurationFormat]], [[Locale]], [[DataLocale]], [[NumberingSystem]], [[Style]], [[YearsStyle]], [[YearsDisplay]], [[MonthsStyle]], [[MonthsDisplay]], [[WeeksStyle]], [[WeeksDisplay]], [[DaysStyle]], [[DaysDisplay]], [[HoursStyle]], [[HoursDisplay]], [[MinutesStyle]], [[MinutesDisplay]], [[SecondsStyle]], [[SecondsDisplay]], [[MillisecondsStyle]], [[MillisecondsDisplay]], [[MicrosecondsStyle]], [[MicrosecondsDisplay]], [[NanosecondsStyle]], [[NanosecondsDisplay]], [[FractionalDigits]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::duration_format,
+            context,
+        )?;
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Let options be ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher = get_option(&options, utf16!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, undefined, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        // 8. Let opt be the Record { [[localeMatcher]]: matcher, [[nu]]: numberingSystem }.
+        let numbering_system = get_option(&options, utf16!("numberingSystem"), context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions { numbering_system },
+        };
+
+        // 9. Let r be ResolveLocale(%DurationFormat%.[[AvailableLocales]], requestedLocales, opt, %DurationFormat%.[[RelevantExtensionKeys]], %DurationFormat%.[[LocaleData]]).
+        // 10. Let locale be r.[[locale]].
+        // 11. Set durationFormat.[[Locale]] to locale.
+        // 12. Set durationFormat.[[DataLocale]] to r.[[dataLocale]].
+        // 13. Set durationFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            &requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        );
+
+        // 14. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow", "digital" », "short").
+        // 15. Set durationFormat.[[Style]] to style.
+        let style = get_option(&options, utf16!("style"), context)?.unwrap_or_default();
+
+        // 16. Let prevStyle be the empty String.
+        let mut prev_style = None;
+
+        // 17. For each row of Table 2, except the header row, in table order, do
+        let mut units = [UnitOptions {
+            style: UnitStyle::Short,
+            display: Display::Auto,
+        }; 10];
+        for unit in DurationUnit::ALL {
+            // a. Let styleSlot be the Style Slot value of the current row.
+            // b. Let displaySlot be the Display Slot value of the current row.
+            // c. Let unit be the Unit value of the current row.
+            // d. Let valueList be the Values value of the current row.
+            // e. Let digitalBase be the Digital Default value of the current row.
+            // f. Let unitOptions be ? GetDurationUnitOptions(unit, options, style, valueList, digitalBase, prevStyle).
+            let unit_options =
+                get_duration_unit_options(unit, &options, style, prev_style, context)?;
+
+            // g. Set the value of the styleSlot slot of durationFormat to unitOptions.[[Style]].
+            // h. Set the value of the displaySlot slot of durationFormat to unitOptions.[[Display]].
+            units[unit as usize] = unit_options;
+
+            // i. If unit is one of "hours", "minutes", "seconds", "milliseconds", or "microseconds", then
+            if matches!(
+                unit,
+                DurationUnit::Hours
+                    | DurationUnit::Minutes
+                    | DurationUnit::Seconds
+                    | DurationUnit::Milliseconds
+                    | DurationUnit::Microseconds
+            ) {
+                // i. Set prevStyle to unitOptions.[[Style]].
+                prev_style = Some(unit_options.style);
+            }
+        }
+
+        // 18. Set durationFormat.[[FractionalDigits]] to ? GetNumberOption(options, "fractionalDigits", 0, 9, undefined).
+        let fractional_digits =
+            get_number_option(&options, utf16!("fractionalDigits"), 0, 9, context)?;
+
+        // The list format used by `PartitionDurationFormatPattern`, which only depends on the
+        // locale and style of this duration format.
+        let list_format = create_list_format(&locale, style, context)?;
+
+        let duration_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                numbering_system: intl_options.service_options.numbering_system,
+                style,
+                units,
+                fractional_digits,
+                list_format,
+            },
+        );
+
+        // 19. Return durationFormat.
+        Ok(duration_format.into())
+    }
+}
+
+impl DurationFormat {
+    /// [`Intl.DurationFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in duration
+    /// formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %DurationFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.DurationFormat.prototype.format ( duration )`][spec].
+    ///
+    /// Formats a duration according to the locale and formatting options of this
+    /// `Intl.DurationFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`format` can only be called on a `DurationFormat` object")
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = to_duration_record(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        // 5. Let result be the empty String.
+        // 6. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 7. Return result.
+        Ok(Self::format_duration(&df, &record, context)?.into())
+    }
+
+    /// [`Intl.DurationFormat.prototype.formatToParts ( duration )`][spec].
+    ///
+    /// Returns an array of objects representing the formatted duration in parts that can be used
+    /// for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`formatToParts` can only be called on a `DurationFormat` object")
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = to_duration_record(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        let parts = df
+            .borrow()
+            .data
+            .partition_duration_format_pattern(&record, context)?;
+
+        // 5. Let result be ! ArrayCreate(0).
+        // 6. Let n be 0.
+        let mut result = Vec::with_capacity(parts.len());
+
+        // 7. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for part in parts {
+            // a. Let obj be OrdinaryObjectCreate(%ObjectPrototype%).
+            let obj = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, "type", part.[[Type]]).
+            obj.create_data_property_or_throw(utf16!("type"), js_string!(part.typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(obj, "value", part.[[Value]]).
+            obj.create_data_property_or_throw(utf16!("value"), js_string!(part.value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, perform ! CreateDataPropertyOrThrow(obj, "unit", part.[[Unit]]).
+            if let Some(unit) = part.unit {
+                obj.create_data_property_or_throw(utf16!("unit"), js_string!(unit), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), obj).
+            // f. Increment n by 1.
+            result.push(obj.into());
+        }
+
+        // 8. Return result.
+        Ok(Array::create_array_from_list(result, context).into())
+    }
+
+    /// [`Intl.DurationFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.DurationFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be the this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let object = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `DurationFormat` object")
+        })?;
+        let df = object.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`resolvedOptions` can only be called on a `DurationFormat` object")
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 3, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of df's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If p is "fractionalDigits", then
+        //         i. If v is not undefined, set v to 𝔽(v).
+        //     d. Else,
+        //         i. Assert: v is not undefined.
+        //     e. If v is "fractional", then
+        //         i. Assert: The Internal Slot value of the current row is [[MillisecondsStyle]], [[MicrosecondsStyle]], or [[NanosecondsStyle]] .
+        //         ii. Set v to "numeric".
+        //     f. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                utf16!("locale"),
+                js_string!(df.locale.to_string()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        if let Some(nu) = &df.numbering_system {
+            options
+                .create_data_property_or_throw(
+                    utf16!("numberingSystem"),
+                    js_string!(nu.to_string()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+        options
+            .create_data_property_or_throw(utf16!("style"), js_string!(df.style.as_str()), context)
+            .expect("operation must not fail per the spec");
+        for unit in DurationUnit::ALL {
+            let UnitOptions { style, display } = df.units[unit as usize];
+            let style = match style {
+                UnitStyle::Fractional => UnitStyle::Numeric,
+                style => style,
+            };
+            options
+                .create_data_property_or_throw(
+                    js_string!(unit.as_str()),
+                    js_string!(style.as_str()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+            options
+                .create_data_property_or_throw(
+                    js_string!(unit.display_option()),
+                    js_string!(display.as_str()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+        if let Some(fractional_digits) = df.fractional_digits {
+            options
+                .create_data_property_or_throw(
+                    utf16!("fractionalDigits"),
+                    fractional_digits,
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+
+    /// Formats `duration` into a string, concatenating the parts returned by
+    /// `PartitionDurationFormatPattern`.
+    pub(crate) fn format_duration(
+        df: &JsObject<Self>,
+        duration: &DurationRecord,
+        context: &mut Context,
+    ) -> JsResult<JsString> {
+        let parts = df
+            .borrow()
+            .data
+            .partition_duration_format_pattern(duration, context)?;
+        let result = parts.into_iter().map(|part| part.value).collect::<String>();
+        Ok(js_string!(result))
+    }
+
+    /// Abstract operation [`PartitionDurationFormatPattern ( durationFormat, duration )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-partitiondurationformatpattern
+    fn partition_duration_format_pattern(
+        &self,
+        duration: &DurationRecord,
+        context: &mut Context,
+    ) -> JsResult<Vec<Part>> {
+        // 1. Let result be a new empty List.
+        let mut result = Vec::new();
+
+        // 2. Let signDisplayed be true.
+        let mut sign_displayed = true;
+
+        // 3. Let numericUnitFound be false.
+        // 4. While numericUnitFound is false, repeat for each row in Table 2 in table order, except the header row:
+        for unit in DurationUnit::ALL {
+            // a. Let value be the value of duration's field whose name is the Value Field value of the current row.
+            let value = duration.get(unit);
+
+            // b. Let style be the value of durationFormat's internal slot whose name is the Style Slot value of the current row.
+            // c. Let display be the value of durationFormat's internal slot whose name is the Display Slot value of the current row.
+            let UnitOptions { style, display } = self.units[unit as usize];
+
+            // d. Let unit be the Unit value of the current row.
+            // e. If style is "numeric" or "2-digit", then
+            if style.is_numeric() {
+                // i. Append FormatNumericUnits(durationFormat, duration, unit, signDisplayed) to result.
+                let numeric = self.format_numeric_units(duration, unit, sign_displayed, context)?;
+                if !numeric.is_empty() {
+                    result.push(numeric);
+                }
+
+                // ii. Set numericUnitFound to true.
+                break;
+            }
+
+            // f. Else,
+            //     i. Let nfOpts be OrdinaryObjectCreate(null).
+            let mut nf_options = Vec::new();
+
+            //     ii. If unit is "seconds", "milliseconds", or "microseconds", then
+            //         1. If NextUnitFractional(durationFormat, unit) is true, then
+            let (mut value, done) = if self.next_unit_fractional(unit) {
+                // a. Set value to ComputeFractionalDigits(durationFormat, duration).
+                // b. If durationFormat.[[FractionalDigits]] is undefined, then
+                //     i. Let maximumFractionDigits be 9𝔽.
+                //     ii. Let minimumFractionDigits be +0𝔽.
+                // c. Else,
+                //     i. Let maximumFractionDigits be durationFormat.[[FractionalDigits]].
+                //     ii. Let minimumFractionDigits be durationFormat.[[FractionalDigits]].
+                // d. Perform ! CreateDataPropertyOrThrow(nfOpts, "maximumFractionDigits", maximumFractionDigits).
+                // e. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumFractionDigits", minimumFractionDigits).
+                // f. Perform ! CreateDataPropertyOrThrow(nfOpts, "roundingMode", "trunc").
+                self.push_fractional_options(&mut nf_options);
+
+                // g. Set numericUnitFound to true.
+                (compute_fractional_digits(duration, unit), true)
+            } else {
+                (to_fixed_decimal(value), false)
+            };
+
+            //     iii. If display is "always" or value is not zero, then
+            if display == Display::Always || !value.is_zero() {
+                // 1. If signDisplayed is true, then
+                if sign_displayed {
+                    // a. Set signDisplayed to false.
+                    sign_displayed = false;
+
+                    // b. If value is 0 and DurationSign(duration) is -1, then
+                    if value.is_zero() && duration.sign() == -1 {
+                        // i. Set value to negative-zero.
+                        value.set_sign(Sign::Negative);
+                    }
+                } else {
+                    // 2. Else,
+                    //     a. Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+                    nf_options.push(("signDisplay", js_string!("never").into()));
+                }
+
+                // 3. Let numberFormatUnit be the NumberFormat Unit value of the current row.
+                // 4. Perform ! CreateDataPropertyOrThrow(nfOpts, "style", "unit").
+                // 5. Perform ! CreateDataPropertyOrThrow(nfOpts, "unit", numberFormatUnit).
+                // 6. Perform ! CreateDataPropertyOrThrow(nfOpts, "unitDisplay", style).
+                nf_options.push(("style", js_string!("unit").into()));
+                nf_options.push(("unit", js_string!(unit.singular()).into()));
+                nf_options.push(("unitDisplay", js_string!(style.as_str()).into()));
+
+                // 7. Let nf be ! Construct(%NumberFormat%, « durationFormat.[[Locale]], nfOpts »).
+                // 8. Let parts be PartitionNumberPattern(nf, value).
+                let parts = self.partition_number(value, nf_options, context)?;
+
+                // 9. Let list be a new empty List.
+                // 10. For each Record { [[Type]], [[Value]] } part of parts, do
+                //     a. Append the Record { [[Type]]: part.[[Type]], [[Value]]: part.[[Value]], [[Unit]]: numberFormatUnit } to list.
+                // 11. Append list to result.
+                result.push(with_unit(parts, unit));
+            }
+
+            if done {
+                break;
+            }
+        }
+
+        // 5. Return ListFormatParts(durationFormat, result).
+        Ok(self.list_format_parts(result))
+    }
+
+    /// Abstract operation [`FormatNumericUnits ( durationFormat, duration, firstNumericUnit, signDisplayed )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-formatnumericunits
+    fn format_numeric_units(
+        &self,
+        duration: &DurationRecord,
+        first_numeric_unit: DurationUnit,
+        mut sign_displayed: bool,
+        context: &mut Context,
+    ) -> JsResult<Vec<Part>> {
+        // 1. Assert: firstNumericUnit is "hours", "minutes", or "seconds".
+        debug_assert!(matches!(
+            first_numeric_unit,
+            DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds
+        ));
+
+        // 2. Let numericPartsList be a new empty List.
+        let mut numeric_parts = Vec::new();
+
+        // 3. Let hoursValue be duration.[[Hours]].
+        // 4. Let hoursDisplay be durationFormat.[[HoursDisplay]].
+        let mut hours_value = to_fixed_decimal(duration.get(DurationUnit::Hours));
+        let hours_display = self.units[DurationUnit::Hours as usize].display;
+
+        // 5. Let minutesValue be duration.[[Minutes]].
+        // 6. Let minutesDisplay be durationFormat.[[MinutesDisplay]].
+        let mut minutes_value = to_fixed_decimal(duration.get(DurationUnit::Minutes));
+        let minutes_display = self.units[DurationUnit::Minutes as usize].display;
+
+        // 7. Let secondsValue be duration.[[Seconds]].
+        // 8. If duration.[[Milliseconds]] is not 0 or duration.[[Microseconds]] is not 0 or duration.[[Nanoseconds]] is not 0, then
+        //     a. Set secondsValue to ComputeFractionalDigits(durationFormat, duration).
+        let mut seconds_value = compute_fractional_digits(duration, DurationUnit::Seconds);
+
+        // 9. Let secondsDisplay be durationFormat.[[SecondsDisplay]].
+        let seconds_display = self.units[DurationUnit::Seconds as usize].display;
+
+        // 10. Let hoursFormatted be false.
+        // 11. If firstNumericUnit is "hours", then
+        //     a. If hoursValue is not 0 or hoursDisplay is "always", then
+        //         i. Set hoursFormatted to true.
+        let hours_formatted = first_numeric_unit == DurationUnit::Hours
+            && (!hours_value.is_zero() || hours_display == Display::Always);
+
+        // 12. If secondsValue is not 0 or secondsDisplay is "always", then
+        //     a. Let secondsFormatted be true.
+        // 13. Else,
+        //     a. Let secondsFormatted be false.
+        let seconds_formatted = !seconds_value.is_zero() || seconds_display == Display::Always;
+
+        // 14. Let minutesFormatted be false.
+        // 15. If firstNumericUnit is "hours" or firstNumericUnit is "minutes", then
+        //     a. If hoursFormatted is true and secondsFormatted is true, then
+        //         i. Set minutesFormatted to true.
+        //     b. Else if minutesValue is not 0 or minutesDisplay is "always", then
+        //         i. Set minutesFormatted to true.
+        let minutes_formatted = first_numeric_unit != DurationUnit::Seconds
+            && ((hours_formatted && seconds_formatted)
+                || !minutes_value.is_zero()
+                || minutes_display == Display::Always);
+
+        let sign = duration.sign();
+
+        // 16. If hoursFormatted is true, then
+        if hours_formatted {
+            // a. If signDisplayed is true, then
+            //     i. If hoursValue is 0 and DurationSign(duration) is -1, then
+            //         1. Set hoursValue to negative-zero.
+            if sign_displayed && hours_value.is_zero() && sign == -1 {
+                hours_value.set_sign(Sign::Negative);
+            }
+
+            // b. Append FormatNumericHours(durationFormat, hoursValue, signDisplayed) to numericPartsList.
+            let parts = self.format_numeric_unit(
+                DurationUnit::Hours,
+                hours_value,
+                false,
+                sign_displayed,
+                context,
+            )?;
+            numeric_parts.extend(parts);
+
+            // c. Set signDisplayed to false.
+            sign_displayed = false;
+        }
+
+        // 17. If minutesFormatted is true, then
+        if minutes_formatted {
+            // a. If signDisplayed is true, then
+            //     i. If minutesValue is 0 and DurationSign(duration) is -1, then
+            //         1. Set minutesValue to negative-zero.
+            if sign_displayed && minutes_value.is_zero() && sign == -1 {
+                minutes_value.set_sign(Sign::Negative);
+            }
+
+            // b. Append FormatNumericMinutes(durationFormat, minutesValue, hoursFormatted, signDisplayed) to numericPartsList.
+            let parts = self.format_numeric_unit(
+                DurationUnit::Minutes,
+                minutes_value,
+                hours_formatted,
+                sign_displayed,
+                context,
+            )?;
+            numeric_parts.extend(parts);
+
+            // c. Set signDisplayed to false.
+            sign_displayed = false;
+        }
+
+        // 18. If secondsFormatted is true, then
+        if seconds_formatted {
+            // a. If signDisplayed is true, then
+            //     i. If secondsValue is 0 and DurationSign(duration) is -1, then
+            //         1. Set secondsValue to negative-zero.
+            if sign_displayed && seconds_value.is_zero() && sign == -1 {
+                seconds_value.set_sign(Sign::Negative);
+            }
+
+            // b. Append FormatNumericSeconds(durationFormat, secondsValue, minutesFormatted, signDisplayed) to numericPartsList.
+            let parts = self.format_numeric_unit(
+                DurationUnit::Seconds,
+                seconds_value,
+                minutes_formatted,
+                sign_displayed,
+                context,
+            )?;
+            numeric_parts.extend(parts);
+        }
+
+        // 19. Return numericPartsList.
+        Ok(numeric_parts)
+    }
+
+    /// Abstract operations [`FormatNumericHours`][hours], [`FormatNumericMinutes`][minutes] and
+    /// [`FormatNumericSeconds`][seconds].
+    ///
+    /// [hours]: https://tc39.es/proposal-intl-duration-format/#sec-formatnumerichours
+    /// [minutes]: https://tc39.es/proposal-intl-duration-format/#sec-formatnumericminutes
+    /// [seconds]: https://tc39.es/proposal-intl-duration-format/#sec-formatnumericseconds
+    fn format_numeric_unit(
+        &self,
+        unit: DurationUnit,
+        value: FixedDecimal,
+        separated: bool,
+        sign_displayed: bool,
+        context: &mut Context,
+    ) -> JsResult<Vec<Part>> {
+        // 1. Let result be a new empty List.
+        let mut result = Vec::new();
+
+        // 2. If hoursDisplayed (resp. minutesDisplayed) is true, then
+        if separated {
+            // a. Let separator be dataLocaleData.[[digitalFormat]].[[separator]].
+            // b. Append the Record { [[Type]]: "literal", [[Value]]: separator, [[Unit]]: empty } to result.
+            // TODO: ICU4X doesn't provide the digital format separators yet, so this uses the
+            // separator of the root locale.
+            result.push(Part::literal(String::from(":")));
+        }
+
+        // 3. Let nfOpts be OrdinaryObjectCreate(null).
+        let mut nf_options = Vec::new();
+
+        // 4. If the style of unit is "2-digit", then
+        if self.units[unit as usize].style == UnitStyle::TwoDigit {
+            // a. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumIntegerDigits", 2𝔽).
+            nf_options.push(("minimumIntegerDigits", 2.into()));
+        }
+
+        // 5. If signDisplayed is false, then
+        if !sign_displayed {
+            // a. Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+            nf_options.push(("signDisplay", js_string!("never").into()));
+        }
+
+        // 6. Perform ! CreateDataPropertyOrThrow(nfOpts, "useGrouping", false).
+        nf_options.push(("useGrouping", false.into()));
+
+        // 7. If unit is "seconds", then
+        if unit == DurationUnit::Seconds {
+            // a. Set the fraction digit options and the rounding mode to "trunc".
+            self.push_fractional_options(&mut nf_options);
+        }
+
+        // 8. Let nf be ! Construct(%NumberFormat%, « durationFormat.[[Locale]], nfOpts »).
+        // 9. Let parts be PartitionNumberPattern(nf, value).
+        let parts = self.partition_number(value, nf_options, context)?;
+
+        // 10. For each Record { [[Type]], [[Value]] } part of parts, do
+        //     a. Append the Record { [[Type]]: part.[[Type]], [[Value]]: part.[[Value]], [[Unit]]: unit } to result.
+        result.extend(with_unit(parts, unit));
+
+        // 11. Return result.
+        Ok(result)
+    }
+
+    /// Abstract operation [`NextUnitFractional ( durationFormat, unit )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-nextunitfractional
+    fn next_unit_fractional(&self, unit: DurationUnit) -> bool {
+        // 1. Assert: unit is "seconds", "milliseconds", "microseconds", or "nanoseconds".
+        // 2. If unit is "seconds" and durationFormat.[[MillisecondsStyle]] is "fractional", return true.
+        // 3. Else if unit is "milliseconds" and durationFormat.[[MicrosecondsStyle]] is "fractional", return true.
+        // 4. Else if unit is "microseconds" and durationFormat.[[NanosecondsStyle]] is "fractional", return true.
+        // 5. Return false.
+        let next = match unit {
+            DurationUnit::Seconds => DurationUnit::Milliseconds,
+            DurationUnit::Milliseconds => DurationUnit::Microseconds,
+            DurationUnit::Microseconds => DurationUnit::Nanoseconds,
+            _ => return false,
+        };
+        self.units[next as usize].style == UnitStyle::Fractional
+    }
+
+    /// Pushes the options used to format a unit with a fractional part.
+    fn push_fractional_options(&self, nf_options: &mut Vec<(&'static str, JsValue)>) {
+        let (maximum, minimum) = self.fractional_digits.map_or((9, 0), |fd| (fd, fd));
+        nf_options.push(("maximumFractionDigits", maximum.into()));
+        nf_options.push(("minimumFractionDigits", minimum.into()));
+        nf_options.push(("roundingMode", js_string!("trunc").into()));
+    }
+
+    /// Creates an `Intl.NumberFormat` for the locale of this duration format with the options
+    /// `nf_options`, and partitions `value` with it.
+    fn partition_number(
+        &self,
+        value: FixedDecimal,
+        nf_options: Vec<(&'static str, JsValue)>,
+        context: &mut Context,
+    ) -> JsResult<Vec<NumberPart>> {
+        let options = JsObject::with_null_proto();
+        if let Some(nu) = &self.numbering_system {
+            options
+                .create_data_property_or_throw(
+                    utf16!("numberingSystem"),
+                    js_string!(nu.to_string()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+        for (key, value) in nf_options {
+            options
+                .create_data_property_or_throw(js_string!(key), value, context)
+                .expect("operation must not fail per the spec");
+        }
+
+        let locale = js_string!(self.locale.to_string()).into();
+        let nf = NumberFormat::new(&locale, &options.into(), context)?;
+        Ok(nf.partition_number_pattern(value.into()))
+    }
+
+    /// Abstract operation [`ListFormatParts ( durationFormat, partitionedPartsList )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-listformatparts
+    fn list_format_parts(&self, partitioned_parts: Vec<Vec<Part>>) -> Vec<Part> {
+        // 1. Let lfOpts be OrdinaryObjectCreate(null).
+        // 2. Perform ! CreateDataPropertyOrThrow(lfOpts, "type", "unit").
+        // 3. Let listStyle be durationFormat.[[Style]].
+        // 4. If listStyle is "digital", then
+        //     a. Set listStyle to "short".
+        // 5. Perform ! CreateDataPropertyOrThrow(lfOpts, "style", listStyle).
+        // 6. Let lf be ! Construct(%ListFormat%, « durationFormat.[[Locale]], lfOpts »).
+        let lf = self.list_format.borrow();
+
+        // 7. Let strings be a new empty List.
+        // 8. For each element parts of partitionedPartsList, do
+        //     a. Let string be the empty String.
+        //     b. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //         i. Set string to the string-concatenation of string and part.[[Value]].
+        //     c. Append string to strings.
+        let strings = partitioned_parts
+            .iter()
+            .map(|parts| parts.iter().map(|part| part.value.as_str()).collect())
+            .collect::<Vec<String>>();
+
+        // 9. Let formattedPartsList be CreatePartsFromList(lf, strings).
+        let formatted_parts = lf.data.create_parts_from_list(strings.into_iter());
+
+        // 10. Let partitionedPartsIndex be 0.
+        // 11. Let partitionedLength be the number of elements in partitionedPartsList.
+        // 12. Let flattenedPartsList be a new empty List.
+        let mut partitioned_parts = partitioned_parts.into_iter();
+        let mut flattened_parts = Vec::new();
+
+        // 13. For each Record { [[Type]], [[Value]] } listPart in formattedPartsList, do
+        for list_part in formatted_parts {
+            match list_part {
+                // a. If listPart.[[Type]] is "element", then
+                ListPart::Element(_) => {
+                    // i. Assert: partitionedPartsIndex < partitionedLength.
+                    // ii. Let parts be partitionedPartsList[partitionedPartsIndex].
+                    // iii. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+                    //     1. Append part to flattenedPartsList.
+                    // iv. Set partitionedPartsIndex to partitionedPartsIndex + 1.
+                    flattened_parts.extend(
+                        partitioned_parts
+                            .next()
+                            .expect("the list must have one element per partitioned list"),
+                    );
+                }
+                // b. Else,
+                //     i. Assert: listPart.[[Type]] is "literal".
+                //     ii. Append the Record { [[Type]]: "literal", [[Value]]: listPart.[[Value]], [[Unit]]: empty } to flattenedPartsList.
+                ListPart::Literal(value) => flattened_parts.push(Part::literal(value)),
+            }
+        }
+
+        // 14. Return flattenedPartsList.
+        flattened_parts
+    }
+}
+
+/// Abstract operation [`GetDurationUnitOptions ( unit, options, baseStyle, stylesList, digitalBase, prevStyle )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-getdurationunitoptions
+fn get_duration_unit_options(
+    unit: DurationUnit,
+    options: &JsObject,
+    base_style: BaseStyle,
+    prev_style: Option<UnitStyle>,
+    context: &mut Context,
+) -> JsResult<UnitOptions> {
+    // 1. Let style be ? GetOption(options, unit, string, stylesList, undefined).
+    let style = get_option::<UnitStyle>(options, &js_string!(unit.as_str()), context)?;
+    if let Some(style) = style {
+        if !unit.accepts(style) {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "`{}` is not a valid style for `{}`",
+                    style.as_str(),
+                    unit.as_str()
+                ))
+                .into());
+        }
+    }
+
+    // 2. Let displayDefault be "always".
+    let mut display_default = Display::Always;
+
+    let prev_numeric = matches!(
+        prev_style,
+        Some(UnitStyle::Fractional | UnitStyle::Numeric | UnitStyle::TwoDigit)
+    );
+
+    // 3. If style is undefined, then
+    let mut style = style.unwrap_or_else(|| {
+        // a. If baseStyle is "digital", then
+        if base_style == BaseStyle::Digital {
+            // i. If unit is not one of "hours", "minutes", or "seconds", then
+            if !matches!(
+                unit,
+                DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds
+            ) {
+                // 1. Set displayDefault to "auto".
+                display_default = Display::Auto;
+            }
+
+            // ii. Set style to digitalBase.
+            unit.digital_base()
+        }
+        // b. Else,
+        //     i. If prevStyle is "fractional", "numeric" or "2-digit", then
+        else if prev_numeric {
+            // 1. If unit is not one of "minutes" or "seconds", then
+            if !matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds) {
+                // a. Set displayDefault to "auto".
+                display_default = Display::Auto;
+            }
+
+            // 2. Set style to "numeric".
+            UnitStyle::Numeric
+        } else {
+            // ii. Else,
+            //     1. Set displayDefault to "auto".
+            display_default = Display::Auto;
+
+            //     2. Set style to baseStyle.
+            match base_style {
+                BaseStyle::Long => UnitStyle::Long,
+                BaseStyle::Short | BaseStyle::Digital => UnitStyle::Short,
+                BaseStyle::Narrow => UnitStyle::Narrow,
+            }
+        }
+    });
+
+    // 4. If style is "numeric" and unit is one of "milliseconds", "microseconds", or "nanoseconds", then
+    if style == UnitStyle::Numeric
+        && matches!(
+            unit,
+            DurationUnit::Milliseconds | DurationUnit::Microseconds | DurationUnit::Nanoseconds
+        )
+    {
+        // a. Set style to "fractional".
+        style = UnitStyle::Fractional;
+
+        // b. Set displayDefault to "auto".
+        display_default = Display::Auto;
+    }
+
+    // 5. Let displayField be the string-concatenation of unit and "Display".
+    // 6. Let display be ? GetOption(options, displayField, string, « "auto", "always" », displayDefault).
+    let display = get_option(options, &js_string!(unit.display_option()), context)?
+        .unwrap_or(display_default);
+
+    // 7. If display is "always" and style is "fractional", then
+    if display == Display::Always && style == UnitStyle::Fractional {
+        // a. Throw a RangeError exception.
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "`{}` cannot be always displayed with a fractional style",
+                unit.as_str()
+            ))
+            .into());
+    }
+
+    // 8. If prevStyle is "fractional", then
+    if prev_style == Some(UnitStyle::Fractional) {
+        // a. If style is not "fractional", then
+        if style != UnitStyle::Fractional {
+            // i. Throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "`{}` must have a fractional style after a fractional unit",
+                    unit.as_str()
+                ))
+                .into());
+        }
+    }
+
+    // 9. If prevStyle is "numeric" or "2-digit", then
+    if matches!(prev_style, Some(UnitStyle::Numeric | UnitStyle::TwoDigit)) {
+        // a. If style is not "fractional", "numeric" or "2-digit", then
+        if !matches!(
+            style,
+            UnitStyle::Fractional | UnitStyle::Numeric | UnitStyle::TwoDigit
+        ) {
+            // i. Throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "`{}` must have a numeric style after a numeric unit",
+                    unit.as_str()
+                ))
+                .into());
+        }
+
+        // b. If unit is "minutes" or "seconds", then
+        if matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds) {
+            // i. Set style to "2-digit".
+            style = UnitStyle::TwoDigit;
+        }
+    }
+
+    // 10. Return the Record { [[Style]]: style, [[Display]]: display  }.
+    Ok(UnitOptions { style, display })
+}
+
+/// Creates the `Intl.ListFormat` used to join the units of a duration format.
+fn create_list_format(
+    locale: &Locale,
+    style: BaseStyle,
+    context: &mut Context,
+) -> JsResult<JsObject<ListFormat>> {
+    let list_style = match style {
+        BaseStyle::Digital => BaseStyle::Short,
+        style => style,
+    };
+
+    let options = JsObject::with_null_proto();
+    options
+        .create_data_property_or_throw(utf16!("type"), js_string!("unit"), context)
+        .expect("operation must not fail per the spec");
+    options
+        .create_data_property_or_throw(utf16!("style"), js_string!(list_style.as_str()), context)
+        .expect("operation must not fail per the spec");
+
+    let list_format = context
+        .intrinsics()
+        .constructors()
+        .list_format()
+        .constructor()
+        .construct(
+            &[js_string!(locale.to_string()).into(), options.into()],
+            None,
+            context,
+        )?
+        .downcast::<ListFormat>()
+        .expect("`Intl.ListFormat` must construct a `ListFormat` object");
+
+    Ok(list_format)
+}
+
+/// Abstract operation [`ToDurationRecord ( input )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-todurationrecord
+pub(crate) fn to_duration_record(
+    input: &JsValue,
+    context: &mut Context,
+) -> JsResult<DurationRecord> {
+    // 1. If input is not an Object, then
+    let Some(input) = input.as_object() else {
+        // a. If input is a String, throw a RangeError exception.
+        if input.is_string() {
+            return Err(JsNativeError::range()
+                .with_message("formatting duration strings is not supported")
+                .into());
+        }
+
+        // b. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("the duration to format must be an object")
+            .into());
+    };
+
+    // 2. Let result be a new Duration Record with each field set to 0.
+    let mut result = [0.0; 10];
+    let mut any_defined = false;
+
+    // 3-22. For each unit of « days, hours, microseconds, milliseconds, minutes, months, nanoseconds, seconds, weeks, years », in that order, do
+    //     a. Let value be ? Get(input, unit).
+    //     b. If value is not undefined, set result.[[<unit>]] to ? ToIntegerIfIntegral(value).
+    for unit in [
+        DurationUnit::Days,
+        DurationUnit::Hours,
+        DurationUnit::Microseconds,
+        DurationUnit::Milliseconds,
+        DurationUnit::Minutes,
+        DurationUnit::Months,
+        DurationUnit::Nanoseconds,
+        DurationUnit::Seconds,
+        DurationUnit::Weeks,
+        DurationUnit::Years,
+    ] {
+        let value = input.get(js_string!(unit.as_str()), context)?;
+        if value.is_undefined() {
+            continue;
+        }
+        any_defined = true;
+
+        // `ToIntegerIfIntegral ( argument )`
+        // 1. Let number be ? ToNumber(argument).
+        // 2. If number is not an integral Number, throw a RangeError exception.
+        // 3. Return ℝ(number).
+        let number = value.to_number(context)?;
+        if !number.is_finite() || number.fract() != 0.0 {
+            return Err(JsNativeError::range()
+                .with_message(format!("`{}` must be an integral number", unit.as_str()))
+                .into());
+        }
+        result[unit as usize] = number + 0.0;
+    }
+
+    // 23. If years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, and nanoseconds are all undefined, throw a TypeError exception.
+    if !any_defined {
+        return Err(JsNativeError::typ()
+            .with_message("the duration to format must have at least one unit")
+            .into());
+    }
+
+    // 24. If IsValidDuration( result.[[Years]], [...], result.[[Nanoseconds]]) is false, then
+    //     a. Throw a RangeError exception.
+    let result = DurationRecord::new(result);
+    if !result.is_valid() {
+        return Err(JsNativeError::range()
+            .with_message("the duration to format is not valid")
+            .into());
+    }
+
+    // 25. Return result.
+    Ok(result)
+}
+
+/// Abstract operation [`ComputeFractionalDigits ( durationFormat, duration )`][spec].
+///
+/// Adds the values of all the units smaller than `unit` to the value of `unit`, as a decimal
+/// fraction.
+///
+/// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-computefractionaldigits
+fn compute_fractional_digits(duration: &DurationRecord, unit: DurationUnit) -> FixedDecimal {
+    let units: [(DurationUnit, u8); 4] = [
+        (DurationUnit::Seconds, 9),
+        (DurationUnit::Milliseconds, 6),
+        (DurationUnit::Microseconds, 3),
+        (DurationUnit::Nanoseconds, 0),
+    ];
+
+    // All the units are integers of at most 2^53 with the same sign, so their sum in nanoseconds
+    // can be computed exactly.
+    let position = units
+        .iter()
+        .position(|(u, _)| *u == unit)
+        .expect("`unit` must be a time unit of at most seconds");
+    let exponent = units[position].1;
+
+    #[allow(clippy::cast_possible_truncation)]
+    let nanoseconds = units[position..]
+        .iter()
+        .map(|(unit, exponent)| duration.get(*unit) as i128 * 10i128.pow(u32::from(*exponent)))
+        .sum::<i128>();
+
+    let mut value = FixedDecimal::from(nanoseconds);
+    value.multiply_pow10(-i16::from(exponent));
+    value
+}
+
+/// Converts the integral value of a unit into a `FixedDecimal`.
+fn to_fixed_decimal(value: f64) -> FixedDecimal {
+    FixedDecimal::try_from_f64(value, FloatPrecision::Floating)
+        .expect("the values of a duration must be finite")
+}
+
+/// Tags the parts of a formatted number with the unit of the duration they represent.
+fn with_unit(parts: Vec<NumberPart>, unit: DurationUnit) -> Vec<Part> {
+    parts
+        .into_iter()
+        .map(|part| Part {
+            typ: part.typ,
+            value: part.value,
+            unit: Some(unit.singular()),
+        })
+        .collect()
+}
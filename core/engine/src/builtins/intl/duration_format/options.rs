@@ -0,0 +1,249 @@
+use std::str::FromStr;
+
+use crate::{
+    builtins::options::{OptionType, ParsableOptionType},
+    Context, JsNativeError, JsResult, JsValue,
+};
+
+/// The base style of an `Intl.DurationFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum BaseStyle {
+    Long,
+    #[default]
+    Short,
+    Narrow,
+    Digital,
+}
+
+impl BaseStyle {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Long => "long",
+            Self::Short => "short",
+            Self::Narrow => "narrow",
+            Self::Digital => "digital",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseBaseStyleError;
+
+impl std::fmt::Display for ParseBaseStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow` or `digital`")
+    }
+}
+
+impl FromStr for BaseStyle {
+    type Err = ParseBaseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "digital" => Ok(Self::Digital),
+            _ => Err(ParseBaseStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for BaseStyle {}
+
+/// The style used to format a single unit of a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitStyle {
+    Long,
+    Short,
+    Narrow,
+    Numeric,
+    TwoDigit,
+    /// Only used internally for the sub-second units following a numeric unit.
+    Fractional,
+}
+
+impl UnitStyle {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Long => "long",
+            Self::Short => "short",
+            Self::Narrow => "narrow",
+            Self::Numeric => "numeric",
+            Self::TwoDigit => "2-digit",
+            Self::Fractional => "fractional",
+        }
+    }
+
+    /// Returns `true` if this style formats the unit as part of a digital clock, e.g. `1:02:03`.
+    pub(crate) const fn is_numeric(self) -> bool {
+        matches!(self, Self::Numeric | Self::TwoDigit)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitStyleError;
+
+impl std::fmt::Display for ParseUnitStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow`, `numeric` or `2-digit`")
+    }
+}
+
+impl FromStr for UnitStyle {
+    type Err = ParseUnitStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(ParseUnitStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for UnitStyle {}
+
+/// Whether a unit of a duration is displayed if its value is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Display {
+    Auto,
+    Always,
+}
+
+impl Display {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Always => "always",
+        }
+    }
+}
+
+impl OptionType for Display {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `auto` or `always`")
+                .into()),
+        }
+    }
+}
+
+/// The resolved style and display of a single unit of a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct UnitOptions {
+    pub(crate) style: UnitStyle,
+    pub(crate) display: Display,
+}
+
+/// A unit of a duration, in the order of [Table 2][spec] of the `Intl.DurationFormat`
+/// specification.
+///
+/// [spec]: https://tc39.es/proposal-intl-duration-format/#table-durationformat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DurationUnit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl DurationUnit {
+    /// All the units of a duration, from the largest to the smallest.
+    pub(crate) const ALL: [Self; 10] = [
+        Self::Years,
+        Self::Months,
+        Self::Weeks,
+        Self::Days,
+        Self::Hours,
+        Self::Minutes,
+        Self::Seconds,
+        Self::Milliseconds,
+        Self::Microseconds,
+        Self::Nanoseconds,
+    ];
+
+    /// Gets the name of the option and of the duration field of this unit.
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Years => "years",
+            Self::Months => "months",
+            Self::Weeks => "weeks",
+            Self::Days => "days",
+            Self::Hours => "hours",
+            Self::Minutes => "minutes",
+            Self::Seconds => "seconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Microseconds => "microseconds",
+            Self::Nanoseconds => "nanoseconds",
+        }
+    }
+
+    /// Gets the name of the display option of this unit.
+    pub(crate) const fn display_option(self) -> &'static str {
+        match self {
+            Self::Years => "yearsDisplay",
+            Self::Months => "monthsDisplay",
+            Self::Weeks => "weeksDisplay",
+            Self::Days => "daysDisplay",
+            Self::Hours => "hoursDisplay",
+            Self::Minutes => "minutesDisplay",
+            Self::Seconds => "secondsDisplay",
+            Self::Milliseconds => "millisecondsDisplay",
+            Self::Microseconds => "microsecondsDisplay",
+            Self::Nanoseconds => "nanosecondsDisplay",
+        }
+    }
+
+    /// Gets the sanctioned unit identifier used to format this unit with `Intl.NumberFormat`.
+    pub(crate) const fn singular(self) -> &'static str {
+        match self {
+            Self::Years => "year",
+            Self::Months => "month",
+            Self::Weeks => "week",
+            Self::Days => "day",
+            Self::Hours => "hour",
+            Self::Minutes => "minute",
+            Self::Seconds => "second",
+            Self::Milliseconds => "millisecond",
+            Self::Microseconds => "microsecond",
+            Self::Nanoseconds => "nanosecond",
+        }
+    }
+
+    /// Returns `true` if `style` is in the list of styles accepted for this unit.
+    pub(crate) const fn accepts(self, style: UnitStyle) -> bool {
+        match self {
+            Self::Years | Self::Months | Self::Weeks | Self::Days => {
+                matches!(
+                    style,
+                    UnitStyle::Long | UnitStyle::Short | UnitStyle::Narrow
+                )
+            }
+            Self::Hours | Self::Minutes | Self::Seconds => !matches!(style, UnitStyle::Fractional),
+            Self::Milliseconds | Self::Microseconds | Self::Nanoseconds => {
+                !matches!(style, UnitStyle::TwoDigit | UnitStyle::Fractional)
+            }
+        }
+    }
+
+    /// Gets the style of this unit when the base style is `"digital"`.
+    pub(crate) const fn digital_base(self) -> UnitStyle {
+        match self {
+            Self::Years | Self::Months | Self::Weeks | Self::Days => UnitStyle::Short,
+            _ => UnitStyle::Numeric,
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn duration_format_styles() {
+    run_test_actions([
+        TestAction::run("const duration = { hours: 1, minutes: 2, seconds: 3 };"),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'long' }).format(duration)",
+            js_string!("1 hour, 2 minutes, 3 seconds"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en').format(duration)",
+            js_string!("1 hr, 2 min, 3 sec"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'narrow' }).format(duration)",
+            js_string!("1h 2m 3s"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital' }).format(duration)",
+            js_string!("1:02:03"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital' }).format({ days: 1, hours: 1, minutes: 2, seconds: 3, milliseconds: 450 })",
+            js_string!("1 day, 1:02:03.45"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('de', { style: 'long' }).format({ hours: 2, minutes: 1 })",
+            js_string!("2 hours, 1 minute"),
+        ),
+    ]);
+}
+
+#[test]
+fn duration_format_unit_options() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { milliseconds: 'numeric' }).format({ seconds: 1, milliseconds: 500 })",
+            js_string!("1.5 sec"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital', fractionalDigits: 2 }).format({ minutes: 5, seconds: 3, milliseconds: 456 })",
+            js_string!("0:05:03.45"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { hours: 'numeric' }).format({ days: 2, hours: 3, minutes: 4 })",
+            js_string!("2 days, 3:04:00"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { daysDisplay: 'always' }).format({ hours: 2 })",
+            js_string!("0 days, 2 hr"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en').format({ hours: -1, minutes: -30 })",
+            js_string!("-1 hr, 30 min"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital' }).format({ minutes: -30 })",
+            js_string!("-0:30:00"),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en', { hours: 'numeric', minutes: 'long' })",
+            JsNativeErrorKind::Range,
+            "`minutes` must have a numeric style after a numeric unit",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en', { years: 'numeric' })",
+            JsNativeErrorKind::Range,
+            "`numeric` is not a valid style for `years`",
+        ),
+    ]);
+}
+
+#[test]
+fn duration_format_invalid_durations() {
+    run_test_actions([
+        TestAction::run("const df = new Intl.DurationFormat('en');"),
+        TestAction::assert_native_error(
+            "df.format({ hours: 1.5 })",
+            JsNativeErrorKind::Range,
+            "`hours` must be an integral number",
+        ),
+        TestAction::assert_native_error(
+            "df.format({})",
+            JsNativeErrorKind::Type,
+            "the duration to format must have at least one unit",
+        ),
+        TestAction::assert_native_error(
+            "df.format({ hours: 1, minutes: -1 })",
+            JsNativeErrorKind::Range,
+            "the duration to format is not valid",
+        ),
+        TestAction::assert_native_error(
+            "df.format(5)",
+            JsNativeErrorKind::Type,
+            "the duration to format must be an object",
+        ),
+    ]);
+}
+
+#[test]
+fn duration_format_format_to_parts() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "JSON.stringify(new Intl.DurationFormat('en', { style: 'digital' }).formatToParts({ hours: 1, minutes: 2 }))",
+            js_string!(
+                r#"[{"type":"integer","value":"1","unit":"hour"},{"type":"literal","value":":"},{"type":"integer","value":"02","unit":"minute"},{"type":"literal","value":":"},{"type":"integer","value":"00","unit":"second"}]"#
+            ),
+        ),
+        TestAction::assert_eq(
+            "JSON.stringify(new Intl.DurationFormat('en').formatToParts({ days: 1, hours: 2 }))",
+            js_string!(
+                r#"[{"type":"integer","value":"1","unit":"day"},{"type":"literal","value":" ","unit":"day"},{"type":"unit","value":"day","unit":"day"},{"type":"literal","value":", "},{"type":"integer","value":"2","unit":"hour"},{"type":"literal","value":" ","unit":"hour"},{"type":"unit","value":"hr","unit":"hour"}]"#
+            ),
+        ),
+    ]);
+}
+
+#[test]
+fn duration_format_resolved_options() {
+    run_test_actions([
+        TestAction::run(
+            "const options = new Intl.DurationFormat('en', { style: 'digital', fractionalDigits: 3 }).resolvedOptions();",
+        ),
+        TestAction::assert_eq("options.locale", js_string!("en")),
+        TestAction::assert_eq("options.style", js_string!("digital")),
+        TestAction::assert_eq("options.days", js_string!("short")),
+        TestAction::assert_eq("options.hours", js_string!("numeric")),
+        TestAction::assert_eq("options.minutes", js_string!("2-digit")),
+        TestAction::assert_eq("options.milliseconds", js_string!("numeric")),
+        TestAction::assert_eq("options.millisecondsDisplay", js_string!("auto")),
+        TestAction::assert_eq("options.fractionalDigits", 3),
+    ]);
+}
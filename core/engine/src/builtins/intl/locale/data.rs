@@ -0,0 +1,480 @@
+//! Locale data used by the locale info methods of `Intl.Locale` and by
+//! `Intl.supportedValuesOf`.
+//!
+//! Most of the data is read from the ICU4X provider, but ICU4X doesn't expose the calendar
+//! preferences and the weekend of a region, and the week data of the provider only contains the
+//! values of the root locale, so the subset of the CLDR supplemental data needed for those is
+//! included here.
+
+use icu_collator::provider::CollationDataV1Marker;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{
+    extensions::unicode::Value, extensions_unicode_key as key, langid, subtags::Region,
+    LanguageIdentifier,
+};
+use icu_provider::{
+    DataLocale, DataPayload, DataProvider, DataRequest, DataRequestMetadata, DataResponse,
+    KeyedDataMarker,
+};
+use icu_timezone::provider::names::{Bcp47ToIanaMapV1Marker, IanaToBcp47MapV1Marker};
+
+use crate::{context::icu::IntlProvider, JsNativeError, JsResult};
+
+use super::validate_extension;
+
+/// The calendars supported by ICU4X, sorted by identifier.
+pub(in crate::builtins::intl) static CALENDARS: [&str; 17] = [
+    "buddhist",
+    "chinese",
+    "coptic",
+    "dangi",
+    "ethioaa",
+    "ethiopic",
+    "gregory",
+    "hebrew",
+    "indian",
+    "islamic",
+    "islamic-civil",
+    "islamic-tbla",
+    "islamic-umalqura",
+    "iso8601",
+    "japanese",
+    "persian",
+    "roc",
+];
+
+const ARABIC: &[&str] = &["gregory", "islamic", "islamic-civil", "islamic-tbla"];
+const CIVIL: &[&str] = &["gregory", "islamic-civil", "islamic-tbla"];
+const GULF: &[&str] = &[
+    "gregory",
+    "islamic-umalqura",
+    "islamic",
+    "islamic-civil",
+    "islamic-tbla",
+];
+const PERSIAN: &[&str] = &[
+    "persian",
+    "gregory",
+    "islamic",
+    "islamic-civil",
+    "islamic-tbla",
+];
+const CHINESE: &[&str] = &["gregory", "chinese"];
+
+/// The calendars preferred by the regions that don't exclusively use the Gregorian calendar,
+/// sorted by region and ordered from the most preferred to the least preferred calendar.
+///
+/// Calendars not supported by ICU4X are omitted.
+static CALENDAR_PREFERENCES: [(&str, &[&str]); 48] = [
+    ("AE", GULF),
+    ("AF", PERSIAN),
+    ("AL", CIVIL),
+    ("AZ", CIVIL),
+    ("BD", ARABIC),
+    ("BH", GULF),
+    ("CN", CHINESE),
+    ("CX", CHINESE),
+    ("DJ", ARABIC),
+    ("DZ", ARABIC),
+    (
+        "EG",
+        &[
+            "gregory",
+            "coptic",
+            "islamic",
+            "islamic-civil",
+            "islamic-tbla",
+        ],
+    ),
+    ("EH", ARABIC),
+    ("ER", ARABIC),
+    ("ET", &["gregory", "ethiopic"]),
+    ("HK", CHINESE),
+    (
+        "IL",
+        &[
+            "gregory",
+            "hebrew",
+            "islamic",
+            "islamic-civil",
+            "islamic-tbla",
+        ],
+    ),
+    ("IN", &["gregory", "indian"]),
+    ("IQ", ARABIC),
+    ("IR", PERSIAN),
+    ("JO", ARABIC),
+    ("JP", &["gregory", "japanese"]),
+    ("KM", ARABIC),
+    ("KR", &["gregory", "dangi"]),
+    ("KW", GULF),
+    ("LB", ARABIC),
+    ("LY", ARABIC),
+    ("MA", ARABIC),
+    ("MO", CHINESE),
+    ("MR", ARABIC),
+    ("MV", CIVIL),
+    ("OM", ARABIC),
+    ("PK", ARABIC),
+    ("PS", ARABIC),
+    ("QA", GULF),
+    ("SA", &["islamic-umalqura", "gregory", "islamic"]),
+    ("SD", ARABIC),
+    ("SG", CHINESE),
+    ("SY", ARABIC),
+    ("TD", ARABIC),
+    ("TH", &["buddhist", "gregory"]),
+    ("TJ", CIVIL),
+    ("TM", CIVIL),
+    ("TN", ARABIC),
+    ("TR", CIVIL),
+    ("TW", &["gregory", "roc", "chinese"]),
+    ("UZ", CIVIL),
+    ("XK", CIVIL),
+    ("YE", ARABIC),
+];
+
+/// Gets the calendars preferred by `region`, ordered from the most preferred to the least
+/// preferred calendar.
+pub(in crate::builtins::intl) fn calendar_preferences(
+    region: Option<Region>,
+) -> &'static [&'static str] {
+    region
+        .and_then(|region| {
+            CALENDAR_PREFERENCES
+                .binary_search_by(|(r, _)| (*r).cmp(region.as_str()))
+                .ok()
+        })
+        .map_or(&["gregory"], |i| CALENDAR_PREFERENCES[i].1)
+}
+
+/// The first day of the week of the regions whose week doesn't start on Monday, sorted by
+/// region.
+///
+/// Days are numbered from 1 (Monday) to 7 (Sunday).
+static FIRST_DAYS: [(&str, u8); 72] = [
+    ("AE", 6),
+    ("AF", 6),
+    ("AG", 7),
+    ("AS", 7),
+    ("BD", 7),
+    ("BH", 6),
+    ("BR", 7),
+    ("BS", 7),
+    ("BT", 7),
+    ("BW", 7),
+    ("BZ", 7),
+    ("CA", 7),
+    ("CN", 7),
+    ("CO", 7),
+    ("DJ", 6),
+    ("DM", 7),
+    ("DO", 7),
+    ("DZ", 6),
+    ("EG", 6),
+    ("ET", 7),
+    ("GT", 7),
+    ("GU", 7),
+    ("HK", 7),
+    ("HN", 7),
+    ("ID", 7),
+    ("IL", 7),
+    ("IN", 7),
+    ("IQ", 6),
+    ("IR", 6),
+    ("JM", 7),
+    ("JO", 6),
+    ("JP", 7),
+    ("KE", 7),
+    ("KH", 7),
+    ("KR", 7),
+    ("KW", 6),
+    ("LA", 7),
+    ("LY", 6),
+    ("MH", 7),
+    ("MM", 7),
+    ("MO", 7),
+    ("MT", 7),
+    ("MV", 5),
+    ("MX", 7),
+    ("MZ", 7),
+    ("NI", 7),
+    ("NP", 7),
+    ("OM", 6),
+    ("PA", 7),
+    ("PE", 7),
+    ("PH", 7),
+    ("PK", 7),
+    ("PR", 7),
+    ("PT", 7),
+    ("PY", 7),
+    ("QA", 6),
+    ("SA", 7),
+    ("SD", 6),
+    ("SG", 7),
+    ("SV", 7),
+    ("SY", 6),
+    ("TH", 7),
+    ("TT", 7),
+    ("TW", 7),
+    ("UM", 7),
+    ("US", 7),
+    ("VE", 7),
+    ("VI", 7),
+    ("WS", 7),
+    ("YE", 7),
+    ("ZA", 7),
+    ("ZW", 7),
+];
+
+/// Gets the first day of the week of `region`, numbered from 1 (Monday) to 7 (Sunday).
+pub(in crate::builtins::intl) fn first_day(region: Option<Region>) -> u8 {
+    region
+        .and_then(|region| {
+            FIRST_DAYS
+                .binary_search_by(|(r, _)| (*r).cmp(region.as_str()))
+                .ok()
+        })
+        .map_or(1, |i| FIRST_DAYS[i].1)
+}
+
+/// The regions that require at least four days of a week in a month or year to consider it the
+/// first week of the month or year, sorted by region.
+static FOUR_MINIMAL_DAYS: [&str; 44] = [
+    "AD", "AN", "AT", "AX", "BE", "BG", "CH", "CZ", "DE", "DK", "EE", "ES", "FI", "FJ", "FO", "FR",
+    "GB", "GF", "GG", "GI", "GP", "GR", "HU", "IE", "IM", "IS", "IT", "JE", "LI", "LT", "LU", "MC",
+    "MQ", "NL", "NO", "PL", "RE", "RU", "SE", "SJ", "SK", "SM", "VA", "XK",
+];
+
+/// Gets the minimal number of days of `region` required in the first week of a month or year.
+pub(in crate::builtins::intl) fn minimal_days(region: Option<Region>) -> u8 {
+    if region.is_some_and(|region| FOUR_MINIMAL_DAYS.binary_search(&region.as_str()).is_ok()) {
+        4
+    } else {
+        1
+    }
+}
+
+/// The first and last days of the weekend of the regions whose weekend isn't Saturday and
+/// Sunday, sorted by region.
+///
+/// Days are numbered from 1 (Monday) to 7 (Sunday).
+static WEEKENDS: [(&str, u8, u8); 19] = [
+    ("AE", 5, 6),
+    ("AF", 4, 5),
+    ("BH", 5, 6),
+    ("DZ", 5, 6),
+    ("EG", 5, 6),
+    ("IL", 5, 6),
+    ("IN", 7, 7),
+    ("IQ", 5, 6),
+    ("IR", 5, 5),
+    ("JO", 5, 6),
+    ("KW", 5, 6),
+    ("LY", 5, 6),
+    ("OM", 5, 6),
+    ("QA", 5, 6),
+    ("SA", 5, 6),
+    ("SD", 5, 6),
+    ("SY", 5, 6),
+    ("UG", 7, 7),
+    ("YE", 5, 6),
+];
+
+/// Gets the days of the weekend of `region`, numbered from 1 (Monday) to 7 (Sunday).
+pub(in crate::builtins::intl) fn weekend(region: Option<Region>) -> Vec<u8> {
+    let (start, end) = region
+        .and_then(|region| {
+            WEEKENDS
+                .binary_search_by(|(r, ..)| (*r).cmp(region.as_str()))
+                .ok()
+        })
+        .map_or((6, 7), |i| (WEEKENDS[i].1, WEEKENDS[i].2));
+
+    (start..=end).collect()
+}
+
+/// The numbering systems with simple digit mappings that are used by the locales of the
+/// provider, sorted by identifier, along with the digit zero of each system.
+static NUMBERING_SYSTEMS: [(&str, char); 27] = [
+    ("adlm", '\u{1E950}'),
+    ("arab", '\u{0660}'),
+    ("arabext", '\u{06F0}'),
+    ("bali", '\u{1B50}'),
+    ("beng", '\u{09E6}'),
+    ("cakm", '\u{11136}'),
+    ("deva", '\u{0966}'),
+    ("fullwide", '\u{FF10}'),
+    ("gujr", '\u{0AE6}'),
+    ("guru", '\u{0A66}'),
+    ("hanidec", '\u{3007}'),
+    ("java", '\u{A9D0}'),
+    ("khmr", '\u{17E0}'),
+    ("knda", '\u{0CE6}'),
+    ("laoo", '\u{0ED0}'),
+    ("latn", '0'),
+    ("mlym", '\u{0D66}'),
+    ("mong", '\u{1810}'),
+    ("mtei", '\u{ABF0}'),
+    ("mymr", '\u{1040}'),
+    ("olck", '\u{1C50}'),
+    ("orya", '\u{0B66}'),
+    ("tamldec", '\u{0BE6}'),
+    ("telu", '\u{0C66}'),
+    ("thai", '\u{0E50}'),
+    ("tibt", '\u{0F20}'),
+    ("vaii", '\u{A620}'),
+];
+
+/// Gets the identifiers of the numbering systems with simple digit mappings, sorted by
+/// identifier.
+pub(in crate::builtins::intl) fn numbering_systems() -> impl Iterator<Item = &'static str> {
+    NUMBERING_SYSTEMS.iter().map(|(nu, _)| *nu)
+}
+
+/// Gets the default numbering system of `language`.
+///
+/// ICU4X doesn't store the identifier of the default numbering system of a locale, so it is
+/// inferred from the digits used by its decimal symbols.
+pub(in crate::builtins::intl) fn default_numbering_system(
+    language: &LanguageIdentifier,
+    provider: &IntlProvider,
+) -> &'static str {
+    load::<DecimalSymbolsV1Marker>(&DataLocale::from(language), provider)
+        .ok()
+        .and_then(|symbols| {
+            let zero = symbols.get().digits[0];
+            NUMBERING_SYSTEMS
+                .iter()
+                .find(|(_, z)| *z == zero)
+                .map(|(nu, _)| *nu)
+        })
+        .unwrap_or("latn")
+}
+
+/// The collation types defined by CLDR that can be tailored for a language, sorted by
+/// identifier, along with a language that tailors each collation.
+///
+/// `standard` and `search` are excluded, since those collations cannot be requested by
+/// `Intl.Collator`.
+static COLLATIONS: [(&str, LanguageIdentifier); 15] = [
+    ("big5han", langid!("zh")),
+    ("compat", langid!("ar")),
+    ("dict", langid!("si")),
+    ("emoji", langid!("und")),
+    ("eor", langid!("und")),
+    ("gb2312", langid!("zh")),
+    ("phonebk", langid!("de")),
+    ("phonetic", langid!("ln")),
+    ("pinyin", langid!("zh-Hant")),
+    ("reformed", langid!("sv")),
+    ("searchjl", langid!("ko")),
+    ("stroke", langid!("zh")),
+    ("trad", langid!("es")),
+    ("unihan", langid!("ko")),
+    ("zhuyin", langid!("zh")),
+];
+
+/// Returns `true` if the provider has the data of the collation `co` for `language`.
+fn has_collation(language: &LanguageIdentifier, co: &str, provider: &IntlProvider) -> bool {
+    co.parse::<Value>().is_ok_and(|co| {
+        validate_extension::<CollationDataV1Marker>(language.clone(), key!("co"), &co, provider)
+    })
+}
+
+/// Gets the collations tailored for `language`, sorted by identifier.
+///
+/// The default collation of the language is not included.
+pub(in crate::builtins::intl) fn collations(
+    language: &LanguageIdentifier,
+    provider: &IntlProvider,
+) -> Vec<&'static str> {
+    // Collations are tailored by language or by language and script, so the region and the
+    // variants of the identifier are ignored.
+    let mut languages = vec![LanguageIdentifier::from(language.language)];
+    if let Some(script) = language.script {
+        languages.push(LanguageIdentifier::from((
+            language.language,
+            Some(script),
+            None,
+        )));
+    }
+
+    let mut collations: Vec<_> = COLLATIONS
+        .iter()
+        .map(|(co, _)| *co)
+        .filter(|co| {
+            languages
+                .iter()
+                .any(|language| has_collation(language, co, provider))
+        })
+        .collect();
+    collations.dedup();
+    collations
+}
+
+/// Gets all the collations that are tailored for at least one language, sorted by identifier.
+pub(in crate::builtins::intl) fn available_collations(
+    provider: &IntlProvider,
+) -> Vec<&'static str> {
+    let mut collations: Vec<_> = COLLATIONS
+        .iter()
+        .filter(|(co, language)| has_collation(language, co, provider))
+        .map(|(co, _)| *co)
+        .collect();
+    collations.dedup();
+    collations
+}
+
+/// Gets the canonical IANA identifiers of the time zones of `region`, or of all the time zones
+/// if `region` is `None`, sorted by identifier.
+pub(in crate::builtins::intl) fn time_zones(
+    region: Option<Region>,
+    provider: &IntlProvider,
+) -> JsResult<Vec<String>> {
+    let bcp47_ids = load::<IanaToBcp47MapV1Marker>(&DataLocale::default(), provider)?;
+    let iana_ids = load::<Bcp47ToIanaMapV1Marker>(&DataLocale::default(), provider)?;
+
+    // The BCP-47 identifier of a time zone starts with the region code of the time zone, except
+    // for the special `unk` and `utc` time zones.
+    let region = region.map(|region| region.as_str().to_ascii_lowercase());
+
+    let mut time_zones: Vec<_> = bcp47_ids
+        .get()
+        .bcp47_ids
+        .iter()
+        .zip(iana_ids.get().canonical_iana_ids.iter())
+        .filter(|(bcp47, _)| bcp47.0 != "unk")
+        .filter(|(bcp47, _)| {
+            region.as_deref().map_or(true, |region| {
+                bcp47.0.starts_with(region) && bcp47.0 != "utc"
+            })
+        })
+        .map(|(_, iana)| {
+            // Per the spec, all the aliases of UTC must be canonicalized to "UTC".
+            if ["Etc/UTC", "Etc/GMT"].contains(&iana) {
+                String::from("UTC")
+            } else {
+                String::from(iana)
+            }
+        })
+        .collect();
+    time_zones.sort_unstable();
+    time_zones.dedup();
+    Ok(time_zones)
+}
+
+/// Loads the data of the marker `M` for `locale`.
+fn load<M>(locale: &DataLocale, provider: &IntlProvider) -> JsResult<DataPayload<M>>
+where
+    M: KeyedDataMarker,
+    IntlProvider: DataProvider<M>,
+{
+    provider
+        .load(DataRequest {
+            locale,
+            metadata: DataRequestMetadata::default(),
+        })
+        .and_then(DataResponse::take_payload)
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()).into())
+}
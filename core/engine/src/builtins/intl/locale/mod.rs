@@ -9,6 +9,8 @@ use icu_datetime::options::preferences::HourCycle;
 use icu_locid::{
     extensions::unicode::Value, extensions_unicode_key as key, extensions_unicode_value as value,
 };
+use icu_locid_transform::LocaleDirectionality;
+use icu_provider::DataLocale;
 
 #[cfg(test)]
 mod tests;
@@ -16,10 +18,12 @@ mod tests;
 mod utils;
 pub(crate) use utils::*;
 
+pub(super) mod data;
+
 mod options;
 
 use crate::{
-    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    builtins::{Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
     object::{internal_methods::get_prototype_from_constructor, JsObject},
@@ -28,7 +32,10 @@ use crate::{
     Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
 };
 
-use super::options::coerce_options_to_object;
+use super::{
+    date_time_format::{hour_cycle_value, locale_hour_cycles},
+    options::coerce_options_to_object,
+};
 
 #[derive(Debug, Clone)]
 pub(crate) struct Locale;
@@ -86,6 +93,17 @@ impl IntrinsicObject for Locale {
             .method(Self::maximize, js_string!("maximize"), 0)
             .method(Self::minimize, js_string!("minimize"), 0)
             .method(Self::to_string, js_string!("toString"), 0)
+            .method(Self::get_calendars, js_string!("getCalendars"), 0)
+            .method(Self::get_collations, js_string!("getCollations"), 0)
+            .method(Self::get_hour_cycles, js_string!("getHourCycles"), 0)
+            .method(
+                Self::get_numbering_systems,
+                js_string!("getNumberingSystems"),
+                0,
+            )
+            .method(Self::get_time_zones, js_string!("getTimeZones"), 0)
+            .method(Self::get_text_info, js_string!("getTextInfo"), 0)
+            .method(Self::get_week_info, js_string!("getWeekInfo"), 0)
             .accessor(
                 js_string!("baseName"),
                 Some(base_name),
@@ -479,6 +497,384 @@ impl Locale {
         Ok(js_string!(loc.to_string()).into())
     }
 
+    /// [`Intl.Locale.prototype.getCalendars ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getCalendars
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCalendars
+    pub(crate) fn get_calendars(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getCalendars` can only be called on a `Locale` object")
+        })?;
+        let mut loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getCalendars` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Return ! CalendarsOfLocale(loc).
+        // CalendarsOfLocale ( loc )
+        // 1. If loc.[[Calendar]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[Calendar]] »).
+        if let Some(ca) = loc.extensions.unicode.keywords.get(&key!("ca")) {
+            let ca = js_string!(ca.to_string()).into();
+            return Ok(Array::create_array_from_list([ca], context).into());
+        }
+
+        // 2. Let r be ! AddLikelySubtags(loc.[[Locale]]).
+        context.intl_provider().locale_expander().maximize(&mut loc);
+
+        // 3. Let list be a List of one or more unique canonical calendar identifiers, which must
+        //    be lower case String values conforming to the type sequence from UTS 35 Unicode
+        //    Locale Identifier, section 3.2, sorted in descending preference of those in common
+        //    use for date and time formatting in r.
+        let list = data::calendar_preferences(loc.id.region)
+            .iter()
+            .map(|ca| js_string!(*ca).into());
+
+        // 4. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list(list, context).into())
+    }
+
+    /// [`Intl.Locale.prototype.getCollations ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getCollations
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCollations
+    pub(crate) fn get_collations(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getCollations` can only be called on a `Locale` object")
+        })?;
+        let loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getCollations` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Return ! CollationsOfLocale(loc).
+        // CollationsOfLocale ( loc )
+        // 1. If loc.[[Collation]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[Collation]] »).
+        if let Some(co) = loc.extensions.unicode.keywords.get(&key!("co")) {
+            let co = js_string!(co.to_string()).into();
+            return Ok(Array::create_array_from_list([co], context).into());
+        }
+
+        // 2. Let list be a List of one or more unique canonical collation identifiers, which
+        //    must be lower case String values conforming to the type sequence from UTS 35
+        //    Unicode Locale Identifier, section 3.2, ordered as if an Array of the same values
+        //    had been sorted, using %Array.prototype.sort% using undefined as comparefn, of those
+        //    in common use for string comparison in loc.[[Locale]]. The values "standard" and
+        //    "search" must be excluded from list.
+        let list = data::collations(&loc.id, context.intl_provider())
+            .into_iter()
+            .map(|co| js_string!(co).into());
+
+        // 3. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list(list, context).into())
+    }
+
+    /// [`Intl.Locale.prototype.getHourCycles ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getHourCycles
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getHourCycles
+    pub(crate) fn get_hour_cycles(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getHourCycles` can only be called on a `Locale` object")
+        })?;
+        let loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getHourCycles` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Return ! HourCyclesOfLocale(loc).
+        // HourCyclesOfLocale ( loc )
+        // 1. If loc.[[HourCycle]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[HourCycle]] »).
+        if let Some(hc) = loc.extensions.unicode.keywords.get(&key!("hc")) {
+            let hc = js_string!(hc.to_string()).into();
+            return Ok(Array::create_array_from_list([hc], context).into());
+        }
+
+        // 2. Let list be a List of one or more unique hour cycle identifiers, which must be lower
+        //    case String values indicating either the 12-hour format ("h11", "h12") or the 24-hour
+        //    format ("h23", "h24"), sorted in descending preference of those in common use for
+        //    date and time formatting in loc.[[Locale]].
+        let hour_cycles = locale_hour_cycles(&DataLocale::from(&loc.id), context.intl_provider())?;
+        let hc = hour_cycle_value(hour_cycles.default);
+
+        // 3. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list([js_string!(hc.to_string()).into()], context).into())
+    }
+
+    /// [`Intl.Locale.prototype.getNumberingSystems ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getNumberingSystems
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getNumberingSystems
+    pub(crate) fn get_numbering_systems(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getNumberingSystems` can only be called on a `Locale` object")
+        })?;
+        let loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getNumberingSystems` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Return ! NumberingSystemsOfLocale(loc).
+        // NumberingSystemsOfLocale ( loc )
+        // 1. If loc.[[NumberingSystem]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[NumberingSystem]] »).
+        if let Some(nu) = loc.extensions.unicode.keywords.get(&key!("nu")) {
+            let nu = js_string!(nu.to_string()).into();
+            return Ok(Array::create_array_from_list([nu], context).into());
+        }
+
+        // 2. Let list be a List of one or more unique canonical numbering system identifiers,
+        //    which must be lower case String values conforming to the type sequence from UTS 35
+        //    Unicode Locale Identifier, section 3.2, sorted in descending preference of those in
+        //    common use for formatting numeric values in loc.[[Locale]].
+        let nu = data::default_numbering_system(&loc.id, context.intl_provider());
+
+        // 3. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list([js_string!(nu).into()], context).into())
+    }
+
+    /// [`Intl.Locale.prototype.getTimeZones ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getTimeZones
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getTimeZones
+    pub(crate) fn get_time_zones(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getTimeZones` can only be called on a `Locale` object")
+        })?;
+        let loc = loc.downcast_ref::<icu_locid::Locale>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getTimeZones` can only be called on a `Locale` object")
+        })?;
+
+        // 3. Let locale be loc.[[Locale]].
+        // 4. If the unicode_language_id production of locale does not contain the
+        //    ["-" unicode_region_subtag] sequence, return undefined.
+        let Some(region) = loc.id.region else {
+            return Ok(JsValue::undefined());
+        };
+
+        // 5. Return ! TimeZonesOfLocale(loc).
+        // TimeZonesOfLocale ( loc )
+        // 1. Let locale be loc.[[Locale]].
+        // 2. Assert: locale matches the unicode_locale_id production.
+        // 3. Let region be the substring of locale corresponding to the unicode_region_subtag
+        //    production of the unicode_language_id.
+        // 4. Let list be a List of unique canonical time zone identifiers, which must be String
+        //    values indicating a canonical Zone name of the IANA Time Zone Database, ordered as
+        //    if an Array of the same values had been sorted using %Array.prototype.sort% using
+        //    undefined as comparefn, of those in common use in region.
+        let list = data::time_zones(Some(region), context.intl_provider())?
+            .into_iter()
+            .map(|tz| js_string!(tz).into());
+
+        // 5. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list(list, context).into())
+    }
+
+    /// [`Intl.Locale.prototype.getTextInfo ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getTextInfo
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getTextInfo
+    pub(crate) fn get_text_info(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getTextInfo` can only be called on a `Locale` object")
+        })?;
+        let loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getTextInfo` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Return ! TextInfoOfLocale(loc).
+        // TextInfoOfLocale ( loc )
+        // 1. Let locale be loc.[[Locale]].
+        // 2. Let info be ! OrdinaryObjectCreate(%Object.prototype%).
+        let info = JsObject::with_object_proto(context.intrinsics());
+
+        // 3. Let dir be "ltr".
+        // 4. If the default general ordering of characters (characterOrder) within a line in
+        //    locale is right-to-left, then
+        //     a. Set dir to "rtl".
+        let provider = context.intl_provider();
+        let directionality = LocaleDirectionality::try_new_with_expander_unstable(
+            provider,
+            provider.locale_expander().clone(),
+        )
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+        let dir = if directionality.is_right_to_left(&loc.id) {
+            js_string!("rtl")
+        } else {
+            js_string!("ltr")
+        };
+
+        // 5. Perform ! CreateDataPropertyOrThrow(info, "direction", dir).
+        info.create_data_property_or_throw(utf16!("direction"), dir, context)
+            .expect("operation must not fail per the spec");
+
+        // 6. Return info.
+        Ok(info.into())
+    }
+
+    /// [`Intl.Locale.prototype.getWeekInfo ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getWeekInfo
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getWeekInfo
+    pub(crate) fn get_week_info(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getWeekInfo` can only be called on a `Locale` object")
+        })?;
+        let mut loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getWeekInfo` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Return ! WeekInfoOfLocale(loc).
+        // WeekInfoOfLocale ( loc )
+        // 1. Let locale be loc.[[Locale]].
+        // 2. Assert: locale matches the unicode_locale_id production.
+        // 3. Let info be ! OrdinaryObjectCreate(%Object.prototype%).
+        let info = JsObject::with_object_proto(context.intrinsics());
+
+        // The week data is defined by region, so the likely region of the locale is used if the
+        // locale doesn't have one.
+        let fw = loc.extensions.unicode.keywords.get(&key!("fw")).cloned();
+        context.intl_provider().locale_expander().maximize(&mut loc);
+        let region = loc.id.region;
+
+        // 4. Let fw be loc.[[FirstDayOfWeek]].
+        // 5. If fw is undefined, let fd be the first day of the week in locale, where 1 is
+        //    Monday and 7 is Sunday. Otherwise, let fd be ! WeekdayToNumber(fw).
+        let first_day = fw
+            .and_then(|fw| {
+                [
+                    value!("mon"),
+                    value!("tue"),
+                    value!("wed"),
+                    value!("thu"),
+                    value!("fri"),
+                    value!("sat"),
+                    value!("sun"),
+                ]
+                .iter()
+                .position(|day| &fw == day)
+            })
+            .map_or_else(|| data::first_day(region), |i| i as u8 + 1);
+
+        // 6. Perform ! CreateDataPropertyOrThrow(info, "firstDay", fd).
+        info.create_data_property_or_throw(utf16!("firstDay"), first_day, context)
+            .expect("operation must not fail per the spec");
+
+        // 7. Let we be a List of the days of the weekend in locale, where 1 is Monday and 7 is
+        //    Sunday, sorted in ascending order.
+        // 8. Perform ! CreateDataPropertyOrThrow(info, "weekend", CreateArrayFromList(we)).
+        let weekend = data::weekend(region).into_iter().map(JsValue::from);
+        let weekend = Array::create_array_from_list(weekend, context);
+        info.create_data_property_or_throw(utf16!("weekend"), weekend, context)
+            .expect("operation must not fail per the spec");
+
+        // 9. Let md be the minimal number of days required in the first week of a month or year
+        //    in locale.
+        // 10. Perform ! CreateDataPropertyOrThrow(info, "minimalDays", md).
+        info.create_data_property_or_throw(
+            utf16!("minimalDays"),
+            data::minimal_days(region),
+            context,
+        )
+        .expect("operation must not fail per the spec");
+
+        // 11. Return info.
+        Ok(info.into())
+    }
+
     /// [`get Intl.Locale.prototype.baseName`][spec].
     ///
     /// More information:
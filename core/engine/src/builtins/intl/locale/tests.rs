@@ -16,6 +16,7 @@ use crate::{
         Service,
     },
     context::icu::IntlProvider,
+    js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction,
 };
 
 #[derive(Debug)]
@@ -118,3 +119,61 @@ fn locale_resolution() {
     let locale = resolve_locale::<TestService>(&[locale!("es-AR")], &mut options, &provider);
     assert_eq!(locale, "es-u-hc-h23".parse().unwrap());
 }
+
+#[test]
+fn locale_info() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run("let loc = new Intl.Locale('ar-EG')"),
+        TestAction::assert("arrayEquals(loc.getCalendars(), ['gregory', 'coptic', 'islamic', 'islamic-civil', 'islamic-tbla'])"),
+        TestAction::assert("arrayEquals(loc.getCollations(), ['compat'])"),
+        TestAction::assert("arrayEquals(loc.getHourCycles(), ['h12'])"),
+        TestAction::assert("arrayEquals(loc.getNumberingSystems(), ['arab'])"),
+        TestAction::assert_eq("loc.getTextInfo().direction", js_string!("rtl")),
+        TestAction::assert_eq("loc.getWeekInfo().firstDay", 6),
+        TestAction::assert("arrayEquals(loc.getWeekInfo().weekend, [5, 6])"),
+        TestAction::assert_eq("loc.getWeekInfo().minimalDays", 1),
+        TestAction::assert("arrayEquals(new Intl.Locale('de-DE').getTimeZones(), ['Europe/Berlin', 'Europe/Busingen'])"),
+        TestAction::assert_eq("new Intl.Locale('de').getTimeZones()", JsValue::undefined()),
+        TestAction::assert_eq("new Intl.Locale('en-US').getWeekInfo().firstDay", 7),
+        TestAction::assert_eq("new Intl.Locale('en-GB').getWeekInfo().minimalDays", 4),
+        TestAction::assert(
+            "arrayEquals(new Intl.Locale('en-u-ca-japanese-hc-h23-nu-thai-co-emoji').getCalendars(), ['japanese'])",
+        ),
+        TestAction::assert(
+            "arrayEquals(new Intl.Locale('en-u-ca-japanese-hc-h23-nu-thai-co-emoji').getHourCycles(), ['h23'])",
+        ),
+        TestAction::assert_eq("new Intl.Locale('en-u-fw-sun').getWeekInfo().firstDay", 7),
+        TestAction::assert_native_error(
+            "Intl.Locale.prototype.getCalendars.call({})",
+            JsNativeErrorKind::Type,
+            "`getCalendars` can only be called on a `Locale` object",
+        ),
+    ]);
+}
+
+#[test]
+fn supported_values_of() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::assert("Intl.supportedValuesOf('calendar').includes('gregory')"),
+        TestAction::assert("Intl.supportedValuesOf('collation').includes('phonebk')"),
+        TestAction::assert("Intl.supportedValuesOf('currency').includes('EUR')"),
+        TestAction::assert("Intl.supportedValuesOf('numberingSystem').includes('latn')"),
+        TestAction::assert("Intl.supportedValuesOf('timeZone').includes('America/New_York')"),
+        TestAction::assert("Intl.supportedValuesOf('timeZone').includes('UTC')"),
+        TestAction::assert("!Intl.supportedValuesOf('timeZone').includes('Etc/UTC')"),
+        TestAction::assert("Intl.supportedValuesOf('unit').includes('kilometer')"),
+        TestAction::assert(
+            "['calendar', 'collation', 'currency', 'numberingSystem', 'timeZone', 'unit'].every(key => {
+                const values = Intl.supportedValuesOf(key);
+                return arrayEquals(values, [...values].sort());
+            })",
+        ),
+        TestAction::assert_native_error(
+            "Intl.supportedValuesOf('foo')",
+            JsNativeErrorKind::Range,
+            "invalid key `foo` for `Intl.supportedValuesOf`",
+        ),
+    ]);
+}
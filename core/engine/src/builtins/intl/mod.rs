@@ -22,7 +22,7 @@ use crate::{
     realm::Realm,
     string::common::StaticJsStrings,
     symbol::JsSymbol,
-    Context, JsArgs, JsData, JsResult, JsString, JsValue,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
 };
 
 use boa_gc::{Finalize, Trace};
@@ -31,22 +31,26 @@ use icu_provider::KeyedDataMarker;
 
 pub(crate) mod collator;
 pub(crate) mod date_time_format;
+pub(crate) mod display_names;
+pub(crate) mod duration_format;
 pub(crate) mod list_format;
 pub(crate) mod locale;
 pub(crate) mod number_format;
 pub(crate) mod plural_rules;
+pub(crate) mod relative_time_format;
 pub(crate) mod segmenter;
 
 pub(crate) use self::{
-    collator::Collator, date_time_format::DateTimeFormat, list_format::ListFormat, locale::Locale,
-    number_format::NumberFormat, plural_rules::PluralRules, segmenter::Segmenter,
+    collator::Collator, date_time_format::DateTimeFormat, display_names::DisplayNames,
+    duration_format::DurationFormat, list_format::ListFormat, locale::Locale,
+    number_format::NumberFormat, plural_rules::PluralRules,
+    relative_time_format::RelativeTimeFormat, segmenter::Segmenter,
 };
 
 mod options;
 
 /// JavaScript `Intl` object.
 #[derive(Debug, Clone, Trace, Finalize, JsData)]
-#[boa_gc(unsafe_empty_trace)]
 pub struct Intl {
     fallback_symbol: JsSymbol,
 }
@@ -79,6 +83,24 @@ impl IntrinsicObject for Intl {
                 realm.intrinsics().constructors().collator().constructor(),
                 Collator::ATTRIBUTE,
             )
+            .static_property(
+                DisplayNames::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .display_names()
+                    .constructor(),
+                DisplayNames::ATTRIBUTE,
+            )
+            .static_property(
+                DurationFormat::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .duration_format()
+                    .constructor(),
+                DurationFormat::ATTRIBUTE,
+            )
             .static_property(
                 ListFormat::NAME,
                 realm
@@ -125,11 +147,25 @@ impl IntrinsicObject for Intl {
                     .constructor(),
                 NumberFormat::ATTRIBUTE,
             )
+            .static_property(
+                RelativeTimeFormat::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .relative_time_format()
+                    .constructor(),
+                RelativeTimeFormat::ATTRIBUTE,
+            )
             .static_method(
                 Self::get_canonical_locales,
                 js_string!("getCanonicalLocales"),
                 1,
             )
+            .static_method(
+                Self::supported_values_of,
+                js_string!("supportedValuesOf"),
+                1,
+            )
             .build();
     }
 
@@ -169,6 +205,79 @@ impl Intl {
             context,
         )))
     }
+
+    /// `Intl.supportedValuesOf ( key )`
+    ///
+    /// Returns an array containing the supported calendar, collation, currency, numbering
+    /// system, time zone or unit identifiers of the implementation.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN docs][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.supportedvaluesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/supportedValuesOf
+    pub(crate) fn supported_values_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let key be ? ToString(key).
+        let key = args.get_or_undefined(0).to_string(context)?;
+
+        let list: Vec<JsString> = match key.to_std_string_escaped().as_str() {
+            // 2. If key is "calendar", then
+            //     a. Let list be ! AvailableCanonicalCalendars( ).
+            "calendar" => locale::data::CALENDARS
+                .iter()
+                .map(|ca| js_string!(*ca))
+                .collect(),
+            // 3. Else if key is "collation", then
+            //     a. Let list be ! AvailableCanonicalCollations( ).
+            "collation" => locale::data::available_collations(context.intl_provider())
+                .into_iter()
+                .map(JsString::from)
+                .collect(),
+            // 4. Else if key is "currency", then
+            //     a. Let list be ! AvailableCanonicalCurrencies( ).
+            "currency" => number_format::data::currency_codes()
+                .map(JsString::from)
+                .collect(),
+            // 5. Else if key is "numberingSystem", then
+            //     a. Let list be ! AvailableCanonicalNumberingSystems( ).
+            "numberingSystem" => locale::data::numbering_systems()
+                .map(JsString::from)
+                .collect(),
+            // 6. Else if key is "timeZone", then
+            //     a. Let list be ! AvailablePrimaryTimeZoneIdentifiers( ).
+            "timeZone" => locale::data::time_zones(None, context.intl_provider())?
+                .into_iter()
+                .map(JsString::from)
+                .collect(),
+            // 7. Else if key is "unit", then
+            //     a. Let list be ! AvailableCanonicalUnits( ).
+            "unit" => number_format::SANCTIONED_UNITS
+                .iter()
+                .map(|unit| js_string!(*unit))
+                .collect(),
+            // 8. Else,
+            //     a. Throw a RangeError exception.
+            _ => {
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "invalid key `{}` for `Intl.supportedValuesOf`",
+                        key.to_std_string_escaped()
+                    ))
+                    .into())
+            }
+        };
+
+        // 9. Return CreateArrayFromList( list ).
+        Ok(JsValue::Object(Array::create_array_from_list(
+            list.into_iter().map(JsValue::from),
+            context,
+        )))
+    }
 }
 
 /// A service component that is part of the `Intl` API.
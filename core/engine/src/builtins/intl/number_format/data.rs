@@ -0,0 +1,432 @@
+//! Locale data for the styles and notations of `Intl.NumberFormat` that aren't supported by
+//! ICU4X yet.
+//!
+//! ICU4X only provides the formatting of plain decimal numbers, so the currency, percent, unit
+//! and compact patterns are post-processed from the output of `FixedDecimalFormatter` using the
+//! subset of the CLDR data included here. Languages without data fall back to the English
+//! patterns, and units and currency names are always displayed in English. The currency names
+//! are also used by the `currency` type of `Intl.DisplayNames`.
+
+/// The position of a currency symbol relative to the formatted number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum CurrencyPosition {
+    /// The currency is displayed before the number, e.g. `$1.00`.
+    ///
+    /// If `spaced` is `true`, a non-breaking space always separates the currency from the number.
+    /// Otherwise, the space is only inserted for alphabetic currency symbols, e.g. `USD 1.00`.
+    Prefix { spaced: bool },
+    /// The currency is displayed after the number, separated by a non-breaking space, e.g.
+    /// `1,00 €`.
+    Suffix,
+}
+
+/// A compact notation pattern, such as `0K` for thousands in English.
+#[derive(Debug)]
+pub(super) struct CompactPattern {
+    /// The smallest magnitude of the numbers formatted with this pattern.
+    pub(super) magnitude: i16,
+    /// The power of ten that numbers formatted with this pattern are scaled down by.
+    pub(super) exponent: i16,
+    /// The text displayed after numbers with the plural category `one`.
+    pub(super) one: &'static str,
+    /// The text displayed after numbers with any other plural category.
+    pub(super) other: &'static str,
+}
+
+/// The number patterns of a language.
+#[derive(Debug)]
+pub(super) struct NumberPatterns {
+    language: &'static str,
+    /// The text displayed between a number and the percent sign.
+    pub(super) percent_separator: &'static str,
+    pub(super) currency_position: CurrencyPosition,
+    /// Whether negative amounts are wrapped in parentheses on the `accounting` currency sign.
+    pub(super) accounting_parentheses: bool,
+    /// The patterns of the compact notation, sorted by magnitude.
+    pub(super) compact_short: &'static [CompactPattern],
+    /// The patterns of the compact notation with `compactDisplay: "long"`, sorted by magnitude.
+    pub(super) compact_long: &'static [CompactPattern],
+}
+
+macro_rules! compact {
+    ($(($magnitude:literal, $exponent:literal, $one:literal, $other:literal)),* $(,)?) => {
+        &[$(CompactPattern {
+            magnitude: $magnitude,
+            exponent: $exponent,
+            one: $one,
+            other: $other,
+        }),*]
+    };
+}
+
+const ENGLISH: NumberPatterns = NumberPatterns {
+    language: "en",
+    percent_separator: "",
+    currency_position: CurrencyPosition::Prefix { spaced: false },
+    accounting_parentheses: true,
+    compact_short: compact![
+        (3, 3, "K", "K"),
+        (6, 6, "M", "M"),
+        (9, 9, "B", "B"),
+        (12, 12, "T", "T"),
+    ],
+    compact_long: compact![
+        (3, 3, " thousand", " thousand"),
+        (6, 6, " million", " million"),
+        (9, 9, " billion", " billion"),
+        (12, 12, " trillion", " trillion"),
+    ],
+};
+
+static PATTERNS: [NumberPatterns; 11] = [
+    NumberPatterns {
+        language: "de",
+        percent_separator: "\u{a0}",
+        currency_position: CurrencyPosition::Suffix,
+        accounting_parentheses: false,
+        compact_short: compact![
+            (6, 6, "\u{a0}Mio.", "\u{a0}Mio."),
+            (9, 9, "\u{a0}Mrd.", "\u{a0}Mrd."),
+            (12, 12, "\u{a0}Bio.", "\u{a0}Bio."),
+        ],
+        compact_long: compact![
+            (3, 3, " Tausend", " Tausend"),
+            (6, 6, " Million", " Millionen"),
+            (9, 9, " Milliarde", " Milliarden"),
+            (12, 12, " Billion", " Billionen"),
+        ],
+    },
+    ENGLISH,
+    NumberPatterns {
+        language: "es",
+        percent_separator: "\u{a0}",
+        currency_position: CurrencyPosition::Suffix,
+        accounting_parentheses: false,
+        compact_short: compact![
+            (3, 3, "\u{a0}mil", "\u{a0}mil"),
+            (6, 6, "\u{a0}M", "\u{a0}M"),
+            (12, 12, "\u{a0}B", "\u{a0}B"),
+        ],
+        compact_long: compact![
+            (3, 3, " mil", " mil"),
+            (6, 6, " millón", " millones"),
+            (9, 9, " mil millones", " mil millones"),
+            (12, 12, " billón", " billones"),
+        ],
+    },
+    NumberPatterns {
+        language: "fr",
+        percent_separator: "\u{202f}",
+        currency_position: CurrencyPosition::Suffix,
+        accounting_parentheses: true,
+        compact_short: compact![
+            (3, 3, "\u{a0}k", "\u{a0}k"),
+            (6, 6, "\u{a0}M", "\u{a0}M"),
+            (9, 9, "\u{a0}Md", "\u{a0}Md"),
+            (12, 12, "\u{a0}Bn", "\u{a0}Bn"),
+        ],
+        compact_long: compact![
+            (3, 3, " mille", " mille"),
+            (6, 6, " million", " millions"),
+            (9, 9, " milliard", " milliards"),
+            (12, 12, " billion", " billions"),
+        ],
+    },
+    NumberPatterns {
+        language: "it",
+        percent_separator: "",
+        currency_position: CurrencyPosition::Suffix,
+        accounting_parentheses: false,
+        compact_short: compact![
+            (6, 6, "\u{a0}Mln", "\u{a0}Mln"),
+            (9, 9, "\u{a0}Mrd", "\u{a0}Mrd"),
+            (12, 12, "\u{a0}Bln", "\u{a0}Bln"),
+        ],
+        compact_long: compact![
+            (3, 3, " mila", " mila"),
+            (6, 6, " milione", " milioni"),
+            (9, 9, " miliardo", " miliardi"),
+            (12, 12, " mila miliardi", " mila miliardi"),
+        ],
+    },
+    NumberPatterns {
+        language: "ja",
+        percent_separator: "",
+        currency_position: CurrencyPosition::Prefix { spaced: false },
+        accounting_parentheses: true,
+        compact_short: compact![(4, 4, "万", "万"), (8, 8, "億", "億"), (12, 12, "兆", "兆")],
+        compact_long: compact![(4, 4, "万", "万"), (8, 8, "億", "億"), (12, 12, "兆", "兆")],
+    },
+    NumberPatterns {
+        language: "ko",
+        percent_separator: "",
+        currency_position: CurrencyPosition::Prefix { spaced: false },
+        accounting_parentheses: true,
+        compact_short: compact![
+            (3, 3, "천", "천"),
+            (4, 4, "만", "만"),
+            (8, 8, "억", "억"),
+            (12, 12, "조", "조"),
+        ],
+        compact_long: compact![
+            (3, 3, "천", "천"),
+            (4, 4, "만", "만"),
+            (8, 8, "억", "억"),
+            (12, 12, "조", "조"),
+        ],
+    },
+    NumberPatterns {
+        language: "nl",
+        percent_separator: "",
+        currency_position: CurrencyPosition::Prefix { spaced: true },
+        accounting_parentheses: true,
+        compact_short: compact![
+            (3, 3, "K", "K"),
+            (6, 6, "\u{a0}mln.", "\u{a0}mln."),
+            (9, 9, "\u{a0}mld.", "\u{a0}mld."),
+            (12, 12, "\u{a0}bln.", "\u{a0}bln."),
+        ],
+        compact_long: compact![
+            (3, 3, " duizend", " duizend"),
+            (6, 6, " miljoen", " miljoen"),
+            (9, 9, " miljard", " miljard"),
+            (12, 12, " biljoen", " biljoen"),
+        ],
+    },
+    NumberPatterns {
+        language: "pt",
+        percent_separator: "",
+        currency_position: CurrencyPosition::Prefix { spaced: true },
+        accounting_parentheses: true,
+        compact_short: compact![
+            (3, 3, "\u{a0}mil", "\u{a0}mil"),
+            (6, 6, "\u{a0}mi", "\u{a0}mi"),
+            (9, 9, "\u{a0}bi", "\u{a0}bi"),
+            (12, 12, "\u{a0}tri", "\u{a0}tri"),
+        ],
+        compact_long: compact![
+            (3, 3, " mil", " mil"),
+            (6, 6, " milhão", " milhões"),
+            (9, 9, " bilhão", " bilhões"),
+            (12, 12, " trilhão", " trilhões"),
+        ],
+    },
+    // The long compact names of Russian need the `few` and `many` plural categories, so they
+    // use the short names instead.
+    NumberPatterns {
+        language: "ru",
+        percent_separator: "\u{a0}",
+        currency_position: CurrencyPosition::Suffix,
+        accounting_parentheses: false,
+        compact_short: compact![
+            (3, 3, "\u{a0}тыс.", "\u{a0}тыс."),
+            (6, 6, "\u{a0}млн", "\u{a0}млн"),
+            (9, 9, "\u{a0}млрд", "\u{a0}млрд"),
+            (12, 12, "\u{a0}трлн", "\u{a0}трлн"),
+        ],
+        compact_long: compact![
+            (3, 3, "\u{a0}тыс.", "\u{a0}тыс."),
+            (6, 6, "\u{a0}млн", "\u{a0}млн"),
+            (9, 9, "\u{a0}млрд", "\u{a0}млрд"),
+            (12, 12, "\u{a0}трлн", "\u{a0}трлн"),
+        ],
+    },
+    NumberPatterns {
+        language: "zh",
+        percent_separator: "",
+        currency_position: CurrencyPosition::Prefix { spaced: false },
+        accounting_parentheses: true,
+        compact_short: compact![
+            (4, 4, "万", "万"),
+            (8, 8, "亿", "亿"),
+            (12, 12, "万亿", "万亿")
+        ],
+        compact_long: compact![
+            (4, 4, "万", "万"),
+            (8, 8, "亿", "亿"),
+            (12, 12, "万亿", "万亿")
+        ],
+    },
+];
+
+/// Gets the number patterns of `language`, falling back to the English patterns.
+pub(super) fn number_patterns(language: &str) -> &'static NumberPatterns {
+    PATTERNS
+        .binary_search_by(|patterns| patterns.language.cmp(language))
+        .map_or(&ENGLISH, |i| &PATTERNS[i])
+}
+
+/// Abstract operation [`CurrencyDigits ( currency )`][spec].
+///
+/// Returns the number of minor units of `currency`, as defined by ISO 4217.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-currencydigits
+pub(super) fn currency_digits(currency: &str) -> u8 {
+    match currency {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        "CLF" | "UYW" => 4,
+        _ => 2,
+    }
+}
+
+/// The symbols and English names of the most used currencies, sorted by currency code.
+///
+/// Each entry contains the currency code, symbol, narrow symbol, display name, and the singular
+/// and plural names of the currency.
+#[rustfmt::skip]
+static CURRENCIES: [(&str, &str, &str, &str, &str, &str); 32] = [
+    ("AUD", "A$", "$", "Australian Dollar", "Australian dollar", "Australian dollars"),
+    ("BRL", "R$", "R$", "Brazilian Real", "Brazilian real", "Brazilian reals"),
+    ("CAD", "CA$", "$", "Canadian Dollar", "Canadian dollar", "Canadian dollars"),
+    ("CHF", "CHF", "CHF", "Swiss Franc", "Swiss franc", "Swiss francs"),
+    ("CNY", "CN¥", "¥", "Chinese Yuan", "Chinese yuan", "Chinese yuan"),
+    ("CZK", "CZK", "Kč", "Czech Koruna", "Czech koruna", "Czech korunas"),
+    ("DKK", "DKK", "kr", "Danish Krone", "Danish krone", "Danish kroner"),
+    ("EUR", "€", "€", "Euro", "euro", "euros"),
+    ("GBP", "£", "£", "British Pound", "British pound", "British pounds"),
+    ("HKD", "HK$", "$", "Hong Kong Dollar", "Hong Kong dollar", "Hong Kong dollars"),
+    ("HUF", "HUF", "Ft", "Hungarian Forint", "Hungarian forint", "Hungarian forints"),
+    ("IDR", "IDR", "Rp", "Indonesian Rupiah", "Indonesian rupiah", "Indonesian rupiahs"),
+    ("ILS", "₪", "₪", "Israeli New Shekel", "Israeli new shekel", "Israeli new shekels"),
+    ("INR", "₹", "₹", "Indian Rupee", "Indian rupee", "Indian rupees"),
+    ("JPY", "¥", "¥", "Japanese Yen", "Japanese yen", "Japanese yen"),
+    ("KRW", "₩", "₩", "South Korean Won", "South Korean won", "South Korean won"),
+    ("MXN", "MX$", "$", "Mexican Peso", "Mexican peso", "Mexican pesos"),
+    ("NOK", "NOK", "kr", "Norwegian Krone", "Norwegian krone", "Norwegian kroner"),
+    ("NZD", "NZ$", "$", "New Zealand Dollar", "New Zealand dollar", "New Zealand dollars"),
+    ("PHP", "₱", "₱", "Philippine Peso", "Philippine peso", "Philippine pesos"),
+    ("PLN", "PLN", "zł", "Polish Zloty", "Polish zloty", "Polish zlotys"),
+    ("RUB", "RUB", "₽", "Russian Ruble", "Russian ruble", "Russian rubles"),
+    ("SEK", "SEK", "kr", "Swedish Krona", "Swedish krona", "Swedish kronor"),
+    ("SGD", "SGD", "$", "Singapore Dollar", "Singapore dollar", "Singapore dollars"),
+    ("THB", "THB", "฿", "Thai Baht", "Thai baht", "Thai baht"),
+    ("TRY", "TRY", "₺", "Turkish Lira", "Turkish lira", "Turkish Lira"),
+    ("TWD", "NT$", "$", "New Taiwan Dollar", "New Taiwan dollar", "New Taiwan dollars"),
+    ("USD", "$", "$", "US Dollar", "US dollar", "US dollars"),
+    ("VND", "₫", "₫", "Vietnamese Dong", "Vietnamese dong", "Vietnamese dong"),
+    ("XAF", "FCFA", "FCFA", "Central African CFA Franc", "Central African CFA franc", "Central African CFA francs"),
+    ("XOF", "F\u{202f}CFA", "F\u{202f}CFA", "West African CFA Franc", "West African CFA franc", "West African CFA francs"),
+    ("ZAR", "ZAR", "R", "South African Rand", "South African rand", "South African rand"),
+];
+
+/// The display data of a currency.
+#[derive(Debug, Clone, Copy)]
+pub(in crate::builtins::intl) struct CurrencyData {
+    pub(in crate::builtins::intl) symbol: &'static str,
+    pub(in crate::builtins::intl) narrow_symbol: &'static str,
+    /// The standalone name of the currency, as displayed by `Intl.DisplayNames`.
+    pub(in crate::builtins::intl) display_name: &'static str,
+    pub(super) one: &'static str,
+    pub(super) other: &'static str,
+}
+
+/// Gets the codes of the currencies with display data, sorted by currency code.
+pub(in crate::builtins::intl) fn currency_codes() -> impl Iterator<Item = &'static str> {
+    CURRENCIES.iter().map(|(code, ..)| *code)
+}
+
+/// Gets the display data of `currency`, or `None` if the currency is unknown.
+pub(in crate::builtins::intl) fn currency_data(currency: &str) -> Option<CurrencyData> {
+    CURRENCIES
+        .binary_search_by(|(code, ..)| (*code).cmp(currency))
+        .ok()
+        .map(|i| {
+            let (_, symbol, narrow_symbol, display_name, one, other) = CURRENCIES[i];
+            CurrencyData {
+                symbol,
+                narrow_symbol,
+                display_name,
+                one,
+                other,
+            }
+        })
+}
+
+/// The English patterns of a unit, where `{0}` is replaced by the formatted number.
+#[derive(Debug)]
+pub(super) struct UnitPatterns {
+    unit: &'static str,
+    pub(super) short_one: &'static str,
+    pub(super) short_other: &'static str,
+    pub(super) narrow: &'static str,
+    pub(super) long_one: &'static str,
+    pub(super) long_other: &'static str,
+}
+
+macro_rules! units {
+    ($(($unit:literal, $short_one:literal, $short_other:literal, $narrow:literal, $long_one:literal, $long_other:literal)),* $(,)?) => {
+        [$(UnitPatterns {
+            unit: $unit,
+            short_one: $short_one,
+            short_other: $short_other,
+            narrow: $narrow,
+            long_one: $long_one,
+            long_other: $long_other,
+        }),*]
+    };
+}
+
+/// The patterns of the sanctioned units and their most common compound units, sorted by unit
+/// identifier.
+#[rustfmt::skip]
+static UNITS: [UnitPatterns; 49] = units![
+    ("acre", "{0} ac", "{0} ac", "{0}ac", "{0} acre", "{0} acres"),
+    ("bit", "{0} bit", "{0} bit", "{0}bit", "{0} bit", "{0} bits"),
+    ("byte", "{0} byte", "{0} byte", "{0}B", "{0} byte", "{0} bytes"),
+    ("celsius", "{0}°C", "{0}°C", "{0}°C", "{0} degree Celsius", "{0} degrees Celsius"),
+    ("centimeter", "{0} cm", "{0} cm", "{0}cm", "{0} centimeter", "{0} centimeters"),
+    ("day", "{0} day", "{0} days", "{0}d", "{0} day", "{0} days"),
+    ("degree", "{0} deg", "{0} deg", "{0}°", "{0} degree", "{0} degrees"),
+    ("fahrenheit", "{0}°F", "{0}°F", "{0}°", "{0} degree Fahrenheit", "{0} degrees Fahrenheit"),
+    ("fluid-ounce", "{0} fl oz", "{0} fl oz", "{0}fl oz", "{0} fluid ounce", "{0} fluid ounces"),
+    ("foot", "{0} ft", "{0} ft", "{0}′", "{0} foot", "{0} feet"),
+    ("gallon", "{0} gal", "{0} gal", "{0}gal", "{0} gallon", "{0} gallons"),
+    ("gigabit", "{0} Gb", "{0} Gb", "{0}Gb", "{0} gigabit", "{0} gigabits"),
+    ("gigabyte", "{0} GB", "{0} GB", "{0}GB", "{0} gigabyte", "{0} gigabytes"),
+    ("gram", "{0} g", "{0} g", "{0}g", "{0} gram", "{0} grams"),
+    ("hectare", "{0} ha", "{0} ha", "{0}ha", "{0} hectare", "{0} hectares"),
+    ("hour", "{0} hr", "{0} hr", "{0}h", "{0} hour", "{0} hours"),
+    ("inch", "{0} in", "{0} in", "{0}″", "{0} inch", "{0} inches"),
+    ("kilobit", "{0} kb", "{0} kb", "{0}kb", "{0} kilobit", "{0} kilobits"),
+    ("kilobyte", "{0} kB", "{0} kB", "{0}kB", "{0} kilobyte", "{0} kilobytes"),
+    ("kilogram", "{0} kg", "{0} kg", "{0}kg", "{0} kilogram", "{0} kilograms"),
+    ("kilometer", "{0} km", "{0} km", "{0}km", "{0} kilometer", "{0} kilometers"),
+    ("kilometer-per-hour", "{0} km/h", "{0} km/h", "{0}km/h", "{0} kilometer per hour", "{0} kilometers per hour"),
+    ("liter", "{0} L", "{0} L", "{0}L", "{0} liter", "{0} liters"),
+    ("megabit", "{0} Mb", "{0} Mb", "{0}Mb", "{0} megabit", "{0} megabits"),
+    ("megabyte", "{0} MB", "{0} MB", "{0}MB", "{0} megabyte", "{0} megabytes"),
+    ("meter", "{0} m", "{0} m", "{0}m", "{0} meter", "{0} meters"),
+    ("meter-per-second", "{0} m/s", "{0} m/s", "{0}m/s", "{0} meter per second", "{0} meters per second"),
+    ("microsecond", "{0} μs", "{0} μs", "{0}μs", "{0} microsecond", "{0} microseconds"),
+    ("mile", "{0} mi", "{0} mi", "{0}mi", "{0} mile", "{0} miles"),
+    ("mile-per-gallon", "{0} mpg", "{0} mpg", "{0}mpg", "{0} mile per gallon", "{0} miles per gallon"),
+    ("mile-per-hour", "{0} mph", "{0} mph", "{0}mph", "{0} mile per hour", "{0} miles per hour"),
+    ("mile-scandinavian", "{0} smi", "{0} smi", "{0}smi", "{0} mile-scandinavian", "{0} miles-scandinavian"),
+    ("milliliter", "{0} mL", "{0} mL", "{0}mL", "{0} milliliter", "{0} milliliters"),
+    ("millimeter", "{0} mm", "{0} mm", "{0}mm", "{0} millimeter", "{0} millimeters"),
+    ("millisecond", "{0} ms", "{0} ms", "{0}ms", "{0} millisecond", "{0} milliseconds"),
+    ("minute", "{0} min", "{0} min", "{0}m", "{0} minute", "{0} minutes"),
+    ("month", "{0} mth", "{0} mths", "{0}m", "{0} month", "{0} months"),
+    ("nanosecond", "{0} ns", "{0} ns", "{0}ns", "{0} nanosecond", "{0} nanoseconds"),
+    ("ounce", "{0} oz", "{0} oz", "{0}oz", "{0} ounce", "{0} ounces"),
+    ("percent", "{0}%", "{0}%", "{0}%", "{0} percent", "{0} percent"),
+    ("petabyte", "{0} PB", "{0} PB", "{0}PB", "{0} petabyte", "{0} petabytes"),
+    ("pound", "{0} lb", "{0} lb", "{0}#", "{0} pound", "{0} pounds"),
+    ("second", "{0} sec", "{0} sec", "{0}s", "{0} second", "{0} seconds"),
+    ("stone", "{0} st", "{0} st", "{0}st", "{0} stone", "{0} stones"),
+    ("terabit", "{0} Tb", "{0} Tb", "{0}Tb", "{0} terabit", "{0} terabits"),
+    ("terabyte", "{0} TB", "{0} TB", "{0}TB", "{0} terabyte", "{0} terabytes"),
+    ("week", "{0} wk", "{0} wks", "{0}w", "{0} week", "{0} weeks"),
+    ("yard", "{0} yd", "{0} yd", "{0}yd", "{0} yard", "{0} yards"),
+    ("year", "{0} yr", "{0} yrs", "{0}y", "{0} year", "{0} years"),
+];
+
+/// Gets the patterns of `unit`, or `None` if the unit doesn't have its own patterns.
+pub(super) fn unit_patterns(unit: &str) -> Option<&'static UnitPatterns> {
+    UNITS
+        .binary_search_by(|patterns| patterns.unit.cmp(unit))
+        .ok()
+        .map(|i| &UNITS[i])
+}
@@ -3,13 +3,18 @@ use std::borrow::Cow;
 use boa_gc::{Finalize, Trace};
 use boa_macros::utf16;
 use boa_profiler::Profiler;
-use fixed_decimal::{FixedDecimal, FloatPrecision, SignDisplay};
+use fixed_decimal::{FixedDecimal, FloatPrecision, Sign, SignDisplay};
 use icu_decimal::{
     options::{FixedDecimalFormatterOptions, GroupingStrategy},
     provider::DecimalSymbolsV1Marker,
-    FixedDecimalFormatter, FormattedFixedDecimal,
+    FixedDecimalFormatter,
+};
+use icu_plurals::{PluralCategory, PluralRules as NativePluralRules};
+use icu_provider::{
+    DataLocale, DataPayload, DataProvider, DataRequest, DataRequestMetadata, DataResponse,
 };
 
+pub(super) mod data;
 mod options;
 use icu_locid::{
     extensions::unicode::{key, Value},
@@ -21,10 +26,13 @@ pub(crate) use options::*;
 
 use crate::{
     builtins::{
-        builder::BuiltInBuilder, options::get_option, string::is_trimmable_whitespace,
-        BuiltInConstructor, BuiltInObject, IntrinsicObject,
+        builder::BuiltInBuilder, options::get_option, string::is_trimmable_whitespace, Array,
+        BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     },
-    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
     object::{
         internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsFunction,
@@ -38,6 +46,8 @@ use crate::{
     NativeFunction,
 };
 
+use self::data::{CompactPattern, CurrencyPosition, NumberPatterns};
+
 use super::{
     locale::{canonicalize_locale_list, resolve_locale, supported_locales, validate_extension},
     options::{coerce_options_to_object, IntlOptions},
@@ -53,6 +63,9 @@ mod tests;
 pub(crate) struct NumberFormat {
     locale: Locale,
     formatter: FixedDecimalFormatter,
+    symbols: DataPayload<DecimalSymbolsV1Marker>,
+    plural_rules: NativePluralRules,
+    patterns: &'static NumberPatterns,
     numbering_system: Option<Value>,
     unit_options: UnitFormatOptions,
     digit_options: DigitFormatOptions,
@@ -62,28 +75,518 @@ pub(crate) struct NumberFormat {
     bound_format: Option<JsFunction>,
 }
 
+/// A mathematical value, as returned by [`ToIntlMathematicalValue ( value )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-tointlmathematicalvalue
+#[derive(Debug, Clone)]
+pub(crate) enum IntlMathematicalValue {
+    Finite(FixedDecimal),
+    PositiveInfinity,
+    NegativeInfinity,
+    NotANumber,
+}
+
+impl From<FixedDecimal> for IntlMathematicalValue {
+    fn from(value: FixedDecimal) -> Self {
+        Self::Finite(value)
+    }
+}
+
 impl NumberFormat {
-    /// [`FormatNumeric ( numberFormat, x )`][full] and [`FormatNumericToParts ( numberFormat, x )`][parts].
+    /// [`FormatNumeric ( numberFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumber
+    pub(crate) fn format(&self, x: IntlMathematicalValue) -> JsString {
+        // 1. Let parts be ? PartitionNumberPattern(numberFormat, x).
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        js_string!(concat_parts(&self.partition_number_pattern(x)))
+    }
+
+    /// Abstract operation [`PartitionNumberPattern ( numberFormat, x )`][spec].
+    ///
+    /// Formats `x` and splits the result into its parts.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberpattern
+    pub(crate) fn partition_number_pattern(&self, x: IntlMathematicalValue) -> Vec<NumberPart> {
+        let infinity_sign = |sign| {
+            FixedDecimal::from(1)
+                .with_sign(sign)
+                .with_sign_display(self.sign_display)
+                .sign()
+        };
+
+        let (sign, number, category) = match x {
+            // 1. If x is not-a-number, then
+            //     a. Let n be an ILD String value indicating the NaN value.
+            IntlMathematicalValue::NotANumber => (
+                Sign::None,
+                vec![NumberPart::new("nan", String::from("NaN"))],
+                PluralCategory::Other,
+            ),
+            // 2. Else if x is positive-infinity, then
+            //     a. Let n be an ILD String value indicating positive infinity.
+            IntlMathematicalValue::PositiveInfinity => (
+                infinity_sign(Sign::None),
+                vec![NumberPart::new("infinity", String::from("∞"))],
+                PluralCategory::Other,
+            ),
+            // 3. Else if x is negative-infinity, then
+            //     a. Let n be an ILD String value indicating negative infinity.
+            IntlMathematicalValue::NegativeInfinity => (
+                infinity_sign(Sign::Negative),
+                vec![NumberPart::new("infinity", String::from("∞"))],
+                PluralCategory::Other,
+            ),
+            // 4. Else,
+            //     a. If x is not negative-zero, then
+            //         i. Assert: x is a mathematical value.
+            //         ii. If numberFormat.[[Style]] is "percent", let x be 100 × x.
+            //         iii. Let exponent be ComputeExponent(numberFormat, x).
+            //         iv. Let x be x × 10^-exponent.
+            //     b. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+            //     c. Let n be formatNumberResult.[[FormattedString]].
+            //     d. Let x be formatNumberResult.[[RoundedNumber]].
+            IntlMathematicalValue::Finite(x) => self.partition_notation_sub_pattern(x),
+        };
+
+        // 5. Let pattern be GetNumberFormatPattern(numberFormat, x).
+        // 6. Let result be a new empty List.
+        // 7. Let patternParts be PartitionPattern(pattern).
+        // 8. For each Record { [[Type]], [[Value]] } patternPart of patternParts, do
+        //     ...
+        // 9. Return result.
+        self.partition_style_pattern(sign, number, category)
+    }
+
+    /// Abstract operation [`PartitionNotationSubPattern ( numberFormat, x, n, exponent )`][spec].
+    ///
+    /// Rounds `x` and splits it into the parts of its notation, returning the sign of the rounded
+    /// number and its plural category alongside the parts.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnotationsubpattern
+    fn partition_notation_sub_pattern(
+        &self,
+        mut x: FixedDecimal,
+    ) -> (Sign, Vec<NumberPart>, PluralCategory) {
+        if self.unit_options.style() == Style::Percent {
+            x.multiply_pow10(2);
+        }
+
+        let (exponent, compact) = self.compute_exponent(&x);
+        x.multiply_pow10(-exponent);
+        self.digit_options.format_fixed_decimal(&mut x);
+
+        x.apply_sign_display(self.sign_display);
+        let sign = x.sign();
+        x.set_sign(Sign::None);
+
+        let category = self.plural_rules.category_for(&x);
+
+        let symbols = self.symbols.get();
+        let mut parts = Vec::new();
+
+        // TODO: ICU4X doesn't expose the parts of a formatted number yet, so the parts are
+        // recovered from the symbols of the number's locale.
+        let formatted = self.formatter.format(&x).to_string();
+        let (integer, fraction) = match formatted.split_once(&*symbols.decimal_separator) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (formatted.as_str(), None),
+        };
+
+        for (i, group) in integer.split(&*symbols.grouping_separator).enumerate() {
+            if i != 0 {
+                parts.push(NumberPart::new(
+                    "group",
+                    symbols.grouping_separator.to_string(),
+                ));
+            }
+            parts.push(NumberPart::new("integer", group.to_owned()));
+        }
+
+        if let Some(fraction) = fraction {
+            parts.push(NumberPart::new(
+                "decimal",
+                symbols.decimal_separator.to_string(),
+            ));
+            parts.push(NumberPart::new("fraction", fraction.to_owned()));
+        }
+
+        match self.notation {
+            Notation::Standard => {}
+            Notation::Scientific | Notation::Engineering => {
+                parts.push(NumberPart::new("exponentSeparator", String::from("E")));
+                if exponent < 0 {
+                    parts.push(NumberPart::new(
+                        "exponentMinusSign",
+                        symbols.minus_sign_affixes.prefix.to_string(),
+                    ));
+                }
+                let exponent = FixedDecimal::from(exponent.unsigned_abs());
+                parts.push(NumberPart::new(
+                    "exponentInteger",
+                    self.formatter.format(&exponent).to_string(),
+                ));
+            }
+            Notation::Compact { .. } => {
+                if let Some(compact) = compact {
+                    let affix = if category == PluralCategory::One {
+                        compact.one
+                    } else {
+                        compact.other
+                    };
+                    push_affix(&mut parts, "compact", affix);
+                }
+            }
+        }
+
+        (sign, parts, category)
+    }
+
+    /// Abstract operation [`ComputeExponent ( numberFormat, x )`][spec].
     ///
-    /// The returned struct implements `Writable`, allowing to either write the number as a full
-    /// string or by parts.
+    /// Also returns the compact pattern used to format `x` on the compact notation.
     ///
-    /// [full]: https://tc39.es/ecma402/#sec-formatnumber
-    /// [parts]: https://tc39.es/ecma402/#sec-formatnumbertoparts
-    fn format<'a>(&'a self, value: &'a mut FixedDecimal) -> FormattedFixedDecimal<'a> {
-        // TODO: Missing support from ICU4X for Percent/Currency/Unit formatting.
-        // TODO: Missing support from ICU4X for Scientific/Engineering/Compact notation.
+    /// [spec]: https://tc39.es/ecma402/#sec-computeexponent
+    fn compute_exponent(&self, x: &FixedDecimal) -> (i16, Option<&'static CompactPattern>) {
+        // 1. If x = 0, then
+        if x.is_zero() || self.notation == Notation::Standard {
+            // a. Return 0.
+            return (0, None);
+        }
 
-        self.digit_options.format_fixed_decimal(value);
-        value.apply_sign_display(self.sign_display);
+        // 2. If x < 0, then
+        //     a. Let x = -x.
+        // 3. Let magnitude be the base 10 logarithm of x rounded down to the nearest integer.
+        let magnitude = x.nonzero_magnitude_start();
+
+        // 4. Let exponent be ComputeExponentForMagnitude(numberFormat, magnitude).
+        let (exponent, compact) = self.compute_exponent_for_magnitude(magnitude);
+
+        // 5. Let x be x × 10^-exponent.
+        // 6. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+        let mut rounded = x.clone();
+        rounded.multiply_pow10(-exponent);
+        self.digit_options.format_fixed_decimal(&mut rounded);
+
+        // 7. If formatNumberResult.[[RoundedNumber]] = 0, then
+        //     a. Return exponent.
+        // 8. Let newMagnitude be the base 10 logarithm of formatNumberResult.[[RoundedNumber]] rounded down to the nearest integer.
+        // 9. If newMagnitude is magnitude - exponent, then
+        //     a. Return exponent.
+        if rounded.is_zero() || rounded.nonzero_magnitude_start() == magnitude - exponent {
+            return (exponent, compact);
+        }
 
-        self.formatter.format(value)
+        // 10. Return ComputeExponentForMagnitude(numberFormat, magnitude + 1).
+        self.compute_exponent_for_magnitude(magnitude + 1)
+    }
+
+    /// Abstract operation [`ComputeExponentForMagnitude ( numberFormat, magnitude )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-computeexponentformagnitude
+    fn compute_exponent_for_magnitude(
+        &self,
+        magnitude: i16,
+    ) -> (i16, Option<&'static CompactPattern>) {
+        // 1. Let notation be numberFormat.[[Notation]].
+        match self.notation {
+            // 2. If notation is "scientific", then
+            //     a. Return magnitude.
+            Notation::Scientific => (magnitude, None),
+            // 3. Else if notation is "engineering", then
+            //     a. Let thousands be the greatest integer that is not greater than magnitude / 3.
+            //     b. Return thousands × 3.
+            Notation::Engineering => (magnitude.div_euclid(3) * 3, None),
+            // 4. Else,
+            //     a. Assert: notation is "compact".
+            //     b. Let exponent be an implementation- and locale-dependent (ILD) integer by which
+            //        to scale a number of the given magnitude in compact notation for the current
+            //        locale.
+            //     c. Return exponent.
+            Notation::Compact { display } => {
+                let patterns = match display {
+                    CompactDisplay::Short => self.patterns.compact_short,
+                    CompactDisplay::Long => self.patterns.compact_long,
+                };
+                patterns
+                    .iter()
+                    .rev()
+                    .find(|pattern| pattern.magnitude <= magnitude)
+                    .map_or((0, None), |pattern| (pattern.exponent, Some(pattern)))
+            }
+            Notation::Standard => (0, None),
+        }
+    }
+
+    /// Wraps the formatted number `number` with its sign and the symbols of the style of this
+    /// `NumberFormat`, as described by [`GetNumberFormatPattern ( intlObject, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-getnumberformatpattern
+    fn partition_style_pattern(
+        &self,
+        sign: Sign,
+        number: Vec<NumberPart>,
+        category: PluralCategory,
+    ) -> Vec<NumberPart> {
+        let symbols = self.symbols.get();
+        let mut parts = Vec::with_capacity(number.len() + 4);
+
+        let accounting = sign == Sign::Negative
+            && self.patterns.accounting_parentheses
+            && matches!(
+                self.unit_options,
+                UnitFormatOptions::Currency {
+                    sign: CurrencySign::Accounting,
+                    display,
+                    ..
+                } if display != CurrencyDisplay::Name
+            );
+
+        let sign_affixes = match sign {
+            Sign::None => None,
+            Sign::Negative => Some(("minusSign", &symbols.minus_sign_affixes)),
+            Sign::Positive => Some(("plusSign", &symbols.plus_sign_affixes)),
+        };
+
+        if accounting {
+            parts.push(NumberPart::new("literal", String::from("(")));
+        } else if let Some((typ, affixes)) = sign_affixes {
+            if !affixes.prefix.is_empty() {
+                parts.push(NumberPart::new(typ, affixes.prefix.to_string()));
+            }
+        }
+
+        match &self.unit_options {
+            UnitFormatOptions::Decimal => parts.extend(number),
+            UnitFormatOptions::Percent => {
+                parts.extend(number);
+                push_affix(&mut parts, "literal", self.patterns.percent_separator);
+                parts.push(NumberPart::new("percentSign", String::from("%")));
+            }
+            UnitFormatOptions::Currency {
+                currency, display, ..
+            } => {
+                let data = data::currency_data(currency.as_str());
+
+                if *display == CurrencyDisplay::Name {
+                    let name = data.map_or(currency.as_str(), |data| {
+                        if category == PluralCategory::One {
+                            data.one
+                        } else {
+                            data.other
+                        }
+                    });
+                    parts.extend(number);
+                    parts.push(NumberPart::new("literal", String::from(" ")));
+                    parts.push(NumberPart::new("currency", name.to_owned()));
+                } else {
+                    let symbol = match (display, data) {
+                        (CurrencyDisplay::Symbol, Some(data)) => data.symbol,
+                        (CurrencyDisplay::NarrowSymbol, Some(data)) => data.narrow_symbol,
+                        _ => currency.as_str(),
+                    };
+                    let currency = NumberPart::new("currency", symbol.to_owned());
+                    let space = NumberPart::new("literal", String::from("\u{a0}"));
+
+                    match self.patterns.currency_position {
+                        CurrencyPosition::Prefix { spaced } => {
+                            parts.push(currency);
+                            if spaced || symbol.ends_with(|c: char| c.is_alphabetic()) {
+                                parts.push(space);
+                            }
+                            parts.extend(number);
+                        }
+                        CurrencyPosition::Suffix => {
+                            parts.extend(number);
+                            parts.push(space);
+                            parts.push(currency);
+                        }
+                    }
+                }
+            }
+            UnitFormatOptions::Unit { unit, display } => {
+                let pattern = unit_pattern(unit, *display, category);
+                let (prefix, suffix) = pattern
+                    .split_once("{0}")
+                    .expect("unit patterns must contain a placeholder");
+                push_affix(&mut parts, "unit", prefix);
+                parts.extend(number);
+                push_affix(&mut parts, "unit", suffix);
+            }
+        }
+
+        if accounting {
+            parts.push(NumberPart::new("literal", String::from(")")));
+        } else if let Some((typ, affixes)) = sign_affixes {
+            if !affixes.suffix.is_empty() {
+                parts.push(NumberPart::new(typ, affixes.suffix.to_string()));
+            }
+        }
+
+        parts
+    }
+
+    /// Abstract operation [`PartitionNumberRangePattern ( numberFormat, x, y )`][spec].
+    ///
+    /// Returns the parts of the formatted range alongside the source of each part.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberrangepattern
+    pub(crate) fn partition_number_range_pattern(
+        &self,
+        x: IntlMathematicalValue,
+        y: IntlMathematicalValue,
+    ) -> JsResult<Vec<(NumberPart, &'static str)>> {
+        // 1. If x is not-a-number or y is not-a-number, throw a RangeError exception.
+        if matches!(x, IntlMathematicalValue::NotANumber)
+            || matches!(y, IntlMathematicalValue::NotANumber)
+        {
+            return Err(JsNativeError::range()
+                .with_message("cannot format a range with a NaN bound")
+                .into());
+        }
+
+        // 2. Let xResult be ? PartitionNumberPattern(numberFormat, x).
+        let x_result = self.partition_number_pattern(x);
+        // 3. Let yResult be ? PartitionNumberPattern(numberFormat, y).
+        let y_result = self.partition_number_pattern(y);
+
+        // 4. If ! FormatNumeric(numberFormat, x) is equal to ! FormatNumeric(numberFormat, y), then
+        if concat_parts(&x_result) == concat_parts(&y_result) {
+            // a. Let appxResult be ? FormatApproximately(numberFormat, xResult).
+            // b. For each element r in appxResult, do
+            //     i. Set r.[[Source]] to "shared".
+            // c. Return appxResult.
+
+            // `FormatApproximately ( numberFormat, result )`
+            // https://tc39.es/ecma402/#sec-formatapproximately
+
+            // 1. Let approximatelySign be an ILND String value used to signify that a number is approximate.
+            // 2. If approximatelySign is not empty, insert a new Record { [[Type]]: "approximatelySign", [[Value]]: approximatelySign } at an ILND index in result.
+            // 3. Return result.
+            let approximately = NumberPart::new("approximatelySign", String::from("~"));
+            return Ok(std::iter::once(approximately)
+                .chain(x_result)
+                .map(|part| (part, "shared"))
+                .collect());
+        }
+
+        // 5. Let result be a new empty List.
+        // 6. For each element r in xResult, do
+        //     a. Append a new Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "startRange" } to result.
+        // 7. Let rangeSeparator be an ILND String value used to separate two numbers.
+        // 8. Append a new Record { [[Type]]: "literal", [[Value]]: rangeSeparator, [[Source]]: "shared" } to result.
+        // 9. For each element r in yResult, do
+        //     a. Append a new Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "endRange" } to result.
+        // 10. Return ! CollapseNumberRange(numberFormat, result).
+        // The ranges are never collapsed, so numbers with symbols are separated by spaces to
+        // avoid ambiguity with the minus sign, e.g. `-$3.00 – -$5.00`.
+        let is_plain = |part: &NumberPart| {
+            matches!(
+                part.typ,
+                "integer" | "group" | "decimal" | "fraction" | "minusSign" | "plusSign"
+            )
+        };
+        let separator = if x_result.iter().chain(&y_result).all(is_plain) {
+            "–"
+        } else {
+            " – "
+        };
+
+        Ok(x_result
+            .into_iter()
+            .map(|part| (part, "startRange"))
+            .chain(std::iter::once((
+                NumberPart::new("literal", String::from(separator)),
+                "shared",
+            )))
+            .chain(y_result.into_iter().map(|part| (part, "endRange")))
+            .collect())
     }
 }
 
+/// A part of a formatted number, as returned by [`NumberFormat::partition_number_pattern`].
+#[derive(Debug, Clone)]
+pub(crate) struct NumberPart {
+    pub(crate) typ: &'static str,
+    pub(crate) value: String,
+}
+
+impl NumberPart {
+    const fn new(typ: &'static str, value: String) -> Self {
+        Self { typ, value }
+    }
+}
+
+/// Concatenates the values of `parts` into the formatted string.
+fn concat_parts(parts: &[NumberPart]) -> String {
+    parts.iter().map(|part| part.value.as_str()).collect()
+}
+
+/// Pushes `affix` to `parts` as a part of type `typ`, splitting its surrounding whitespace into
+/// literal parts.
+fn push_affix(parts: &mut Vec<NumberPart>, typ: &'static str, affix: &str) {
+    let start = affix.trim_start();
+    let trimmed = start.trim_end();
+    let leading = &affix[..affix.len() - start.len()];
+    let trailing = &start[trimmed.len()..];
+
+    for (typ, value) in [("literal", leading), (typ, trimmed), ("literal", trailing)] {
+        if !value.is_empty() {
+            parts.push(NumberPart::new(typ, value.to_owned()));
+        }
+    }
+}
+
+/// Gets the pattern used to display `unit`, where `{0}` is replaced by the formatted number.
+fn unit_pattern(unit: &Unit, display: UnitDisplay, category: PluralCategory) -> Cow<'static, str> {
+    fn select(
+        patterns: &'static data::UnitPatterns,
+        display: UnitDisplay,
+        category: PluralCategory,
+    ) -> &'static str {
+        match (display, category) {
+            (UnitDisplay::Short, PluralCategory::One) => patterns.short_one,
+            (UnitDisplay::Short, _) => patterns.short_other,
+            (UnitDisplay::Narrow, _) => patterns.narrow,
+            (UnitDisplay::Long, PluralCategory::One) => patterns.long_one,
+            (UnitDisplay::Long, _) => patterns.long_other,
+        }
+    }
+
+    fn patterns(unit: &str) -> &'static data::UnitPatterns {
+        data::unit_patterns(unit).expect("all sanctioned units must have patterns")
+    }
+
+    let denominator = unit.denominator();
+    if denominator.is_empty() {
+        return Cow::Borrowed(select(patterns(unit.numerator()), display, category));
+    }
+
+    if let Some(patterns) = data::unit_patterns(&format!("{}-per-{denominator}", unit.numerator()))
+    {
+        return Cow::Borrowed(select(patterns, display, category));
+    }
+
+    // Compound units without their own patterns combine the pattern of the numerator with the
+    // singular name of the denominator.
+    let numerator = select(patterns(unit.numerator()), display, category);
+    let denominator = select(patterns(denominator), display, PluralCategory::One)
+        .replace("{0}", "")
+        .trim()
+        .to_owned();
+
+    Cow::Owned(match display {
+        UnitDisplay::Long => format!("{numerator} per {denominator}"),
+        UnitDisplay::Short | UnitDisplay::Narrow => format!("{numerator}/{denominator}"),
+    })
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct NumberFormatLocaleOptions {
-    numbering_system: Option<Value>,
+    pub(super) numbering_system: Option<Value>,
 }
 
 impl Service for NumberFormat {
@@ -91,11 +594,7 @@ impl Service for NumberFormat {
 
     type LocaleOptions = NumberFormatLocaleOptions;
 
-    fn resolve(
-        locale: &mut Locale,
-        options: &mut Self::LocaleOptions,
-        provider: &crate::context::icu::IntlProvider,
-    ) {
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
         let numbering_system = options
             .numbering_system
             .take()
@@ -154,6 +653,13 @@ impl IntrinsicObject for NumberFormat {
                 None,
                 Attribute::CONFIGURABLE,
             )
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::format_range, js_string!("formatRange"), 2)
+            .method(
+                Self::format_range_to_parts,
+                js_string!("formatRangeToParts"),
+                2,
+            )
             .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
             .build();
     }
@@ -208,10 +714,70 @@ impl BuiltInConstructor for NumberFormat {
         )?;
 
         // 3. Perform ? InitializeNumberFormat(numberFormat, locales, options).
+        let number_format = Self::new(locales, options, context)?;
+        let number_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            number_format,
+        );
+
+        // 4. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Let this be the this value.
+        //     b. Return ? ChainNumberFormat(numberFormat, NewTarget, this).
+        // ChainNumberFormat ( numberFormat, newTarget, this )
+        // <https://tc39.es/ecma402/#sec-chainnumberformat>
+
+        let this = context.vm.frame().this(&context.vm);
+        let Some(this_obj) = this.as_object() else {
+            return Ok(number_format.into());
+        };
+
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .number_format()
+            .constructor();
+
+        // 1. If newTarget is undefined and ? OrdinaryHasInstance(%Intl.NumberFormat%, this) is true, then
+        if new_target.is_undefined()
+            && JsValue::ordinary_has_instance(&constructor.into(), &this, context)?
+        {
+            let fallback_symbol = context
+                .intrinsics()
+                .objects()
+                .intl()
+                .borrow()
+                .data
+                .fallback_symbol();
 
-        // `InitializeNumberFormat ( numberFormat, locales, options )`
-        // https://tc39.es/ecma402/#sec-initializenumberformat
+            // a. Perform ? DefinePropertyOrThrow(this, %Intl%.[[FallbackSymbol]], PropertyDescriptor{ [[Value]]: numberFormat, [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }).
+            this_obj.define_property_or_throw(
+                fallback_symbol,
+                PropertyDescriptor::builder()
+                    .value(number_format)
+                    .writable(false)
+                    .enumerable(false)
+                    .configurable(false),
+                context,
+            )?;
+            // b. Return this.
+            Ok(this)
+        } else {
+            // 2. Return numberFormat.
+            Ok(number_format.into())
+        }
+    }
+}
 
+impl NumberFormat {
+    /// Abstract operation [`InitializeNumberFormat ( numberFormat, locales, options )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializenumberformat
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
         // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
         let requested_locales = canonicalize_locale_list(locales, context)?;
         // 2. Set options to ? CoerceOptionsToObject(options).
@@ -251,13 +817,17 @@ impl BuiltInConstructor for NumberFormat {
 
         // 15. Let style be numberFormat.[[Style]].
         // 16. If style is "currency", then
-        let (min_fractional, max_fractional) = if unit_options.style() == Style::Currency {
-            // TODO: Missing support from ICU4X
+        let (min_fractional, max_fractional) = if let UnitFormatOptions::Currency {
             // a. Let currency be numberFormat.[[Currency]].
+            currency,
+            ..
+        } = &unit_options
+        {
             // b. Let cDigits be CurrencyDigits(currency).
+            let c_digits = data::currency_digits(currency.as_str());
             // c. Let mnfdDefault be cDigits.
             // d. Let mxfdDefault be cDigits.
-            return Err(JsNativeError::typ().with_message("unimplemented").into());
+            (c_digits, c_digits)
         } else {
             // 17. Else,
             (
@@ -365,84 +935,46 @@ impl BuiltInConstructor for NumberFormat {
         let sign_display =
             get_option(&options, utf16!("signDisplay"), context)?.unwrap_or(SignDisplay::Auto);
 
-        let formatter = FixedDecimalFormatter::try_new_unstable(
-            context.intl_provider(),
-            &locale.clone().into(),
-            {
-                let mut options = FixedDecimalFormatterOptions::default();
-                options.grouping_strategy = use_grouping;
-                options
-            },
-        )
-        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
-
-        let number_format = JsObject::from_proto_and_data_with_shared_shape(
-            context.root_shape(),
-            prototype,
-            NumberFormat {
-                locale,
-                numbering_system: intl_options.service_options.numbering_system,
-                formatter,
-                unit_options,
-                digit_options,
-                notation,
-                use_grouping,
-                sign_display,
-                bound_format: None,
-            },
-        );
-
-        // 31. Return unused.
+        let data_locale = DataLocale::from(&locale);
+        let provider = context.intl_provider();
 
-        // 4. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
-        //     a. Let this be the this value.
-        //     b. Return ? ChainNumberFormat(numberFormat, NewTarget, this).
-        // ChainNumberFormat ( numberFormat, newTarget, this )
-        // <https://tc39.es/ecma402/#sec-chainnumberformat>
+        let formatter = FixedDecimalFormatter::try_new_unstable(provider, &data_locale, {
+            let mut options = FixedDecimalFormatterOptions::default();
+            options.grouping_strategy = use_grouping;
+            options
+        })
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
 
-        let this = context.vm.frame().this(&context.vm);
-        let Some(this_obj) = this.as_object() else {
-            return Ok(number_format.into());
-        };
+        let symbols = provider
+            .load(DataRequest {
+                locale: &data_locale,
+                metadata: DataRequestMetadata::default(),
+            })
+            .and_then(DataResponse::take_payload)
+            .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
 
-        let constructor = context
-            .intrinsics()
-            .constructors()
-            .number_format()
-            .constructor();
+        let plural_rules = NativePluralRules::try_new_cardinal_unstable(provider, &data_locale)
+            .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
 
-        // 1. If newTarget is undefined and ? OrdinaryHasInstance(%Intl.NumberFormat%, this) is true, then
-        if new_target.is_undefined()
-            && JsValue::ordinary_has_instance(&constructor.into(), &this, context)?
-        {
-            let fallback_symbol = context
-                .intrinsics()
-                .objects()
-                .intl()
-                .borrow()
-                .data
-                .fallback_symbol();
+        let patterns = data::number_patterns(locale.id.language.as_str());
 
-            // a. Perform ? DefinePropertyOrThrow(this, %Intl%.[[FallbackSymbol]], PropertyDescriptor{ [[Value]]: numberFormat, [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }).
-            this_obj.define_property_or_throw(
-                fallback_symbol,
-                PropertyDescriptor::builder()
-                    .value(number_format)
-                    .writable(false)
-                    .enumerable(false)
-                    .configurable(false),
-                context,
-            )?;
-            // b. Return this.
-            Ok(this)
-        } else {
-            // 2. Return numberFormat.
-            Ok(number_format.into())
-        }
+        // 31. Return unused.
+        Ok(Self {
+            locale,
+            formatter,
+            symbols,
+            plural_rules,
+            patterns,
+            numbering_system: intl_options.service_options.numbering_system,
+            unit_options,
+            digit_options,
+            notation,
+            use_grouping,
+            sign_display,
+            bound_format: None,
+        })
     }
-}
 
-impl NumberFormat {
     /// [`Intl.NumberFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
     ///
     /// Returns an array containing those of the provided locales that are supported in number format
@@ -502,10 +1034,10 @@ impl NumberFormat {
                         let value = args.get_or_undefined(0);
 
                         // 4. Let x be ? ToIntlMathematicalValue(value).
-                        let mut x = to_intl_mathematical_value(value, context)?;
+                        let x = to_intl_mathematical_value(value, context)?;
 
                         // 5. Return FormatNumeric(nf, x).
-                        Ok(js_string!(nf.borrow().data.format(&mut x).to_string()).into())
+                        Ok(nf.borrow().data.format(x).into())
                     },
                     nf_clone,
                 ),
@@ -521,6 +1053,167 @@ impl NumberFormat {
         Ok(bound_format.into())
     }
 
+    /// [`Intl.NumberFormat.prototype.formatToParts ( value )`][spec].
+    ///
+    /// Returns an array of objects representing the parts of the formatted number.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formattoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`formatToParts` can only be called on a `NumberFormat` object")
+            })?;
+
+        // 3. Let x be ? ToIntlMathematicalValue(value).
+        let x = to_intl_mathematical_value(args.get_or_undefined(0), context)?;
+
+        // 4. Return FormatNumericToParts(nf, x).
+
+        // `FormatNumericToParts ( numberFormat, x )`
+        // https://tc39.es/ecma402/#sec-formatnumbertoparts
+
+        // 1. Let parts be ? PartitionNumberPattern(numberFormat, x).
+        let parts = nf.borrow().data.partition_number_pattern(x);
+
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        //     b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        //     c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        //     d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+        //     e. Increment n by 1.
+        // 5. Return result.
+        Ok(parts_to_array(parts.into_iter().map(|part| (part, None)), context).into())
+    }
+
+    /// [`Intl.NumberFormat.prototype.formatRange ( start, end )`][spec].
+    ///
+    /// Formats a range of numbers based on the locale and options of this `Intl.NumberFormat`
+    /// object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        let (nf, x, y) = Self::range_arguments(this, args, "formatRange", context)?;
+
+        // 6. Return ? FormatNumericRange(nf, x, y).
+
+        // `FormatNumericRange ( numberFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatnumericrange
+
+        // 1. Let parts be ? PartitionNumberRangePattern(numberFormat, x, y).
+        let parts = nf.borrow().data.partition_number_range_pattern(x, y)?;
+
+        // 2. Let result be the empty String.
+        // 3. For each part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        let result: String = parts.iter().map(|(part, _)| part.value.as_str()).collect();
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.NumberFormat.prototype.formatRangeToParts ( start, end )`][spec].
+    ///
+    /// Returns an array of objects representing the parts of a formatted range of numbers,
+    /// including the bound of the range each part comes from.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrangetoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        let (nf, x, y) = Self::range_arguments(this, args, "formatRangeToParts", context)?;
+
+        // 6. Return ? FormatNumericRangeToParts(nf, x, y).
+
+        // `FormatNumericRangeToParts ( numberFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatnumericrangetoparts
+
+        // 1. Let parts be ? PartitionNumberRangePattern(numberFormat, x, y).
+        let parts = nf.borrow().data.partition_number_range_pattern(x, y)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        // 4. For each part in parts, do
+        //     a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        //     b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        //     c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        //     d. Perform ! CreateDataPropertyOrThrow(O, "source", part.[[Source]]).
+        //     e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+        //     f. Increment n by 1.
+        // 5. Return result.
+        Ok(parts_to_array(
+            parts.into_iter().map(|(part, source)| (part, Some(source))),
+            context,
+        )
+        .into())
+    }
+
+    /// Validates the arguments of `formatRange` and `formatRangeToParts`.
+    fn range_arguments(
+        this: &JsValue,
+        args: &[JsValue],
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<(JsObject<Self>, IntlMathematicalValue, IntlMathematicalValue)> {
+        let nf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "`{method}` can only be called on a `NumberFormat` object"
+                ))
+            })?;
+
+        let start = args.get_or_undefined(0);
+        let end = args.get_or_undefined(1);
+
+        if start.is_undefined() || end.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "`{method}` requires both a start and an end number"
+                ))
+                .into());
+        }
+
+        let x = to_intl_mathematical_value(start, context)?;
+        let y = to_intl_mathematical_value(end, context)?;
+
+        Ok((nf, x, y))
+    }
+
     /// [`Intl.NumberFormat.prototype.resolvedOptions ( )`][spec].
     ///
     /// Returns a new object with properties reflecting the locale and options computed during the
@@ -705,6 +1398,35 @@ impl NumberFormat {
     }
 }
 
+/// Creates an array with the `type`, `value` and optional `source` of each part in `parts`.
+fn parts_to_array(
+    parts: impl Iterator<Item = (NumberPart, Option<&'static str>)>,
+    context: &mut Context,
+) -> JsObject {
+    let parts = parts
+        .map(|(part, source)| {
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            o.create_data_property_or_throw(utf16!("type"), js_string!(part.typ), context)
+                .expect("operation must not fail per the spec");
+            o.create_data_property_or_throw(utf16!("value"), js_string!(part.value), context)
+                .expect("operation must not fail per the spec");
+            if let Some(source) = source {
+                o.create_data_property_or_throw(utf16!("source"), js_string!(source), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            o.into()
+        })
+        .collect::<Vec<_>>();
+
+    Array::create_array_from_list(parts, context)
+}
+
 /// Abstract operation [`UnwrapNumberFormat ( nf )`][spec].
 ///
 /// This also checks that the returned object is a `NumberFormat`, which skips the
@@ -755,17 +1477,19 @@ fn unwrap_number_format(nf: &JsValue, context: &mut Context) -> JsResult<JsObjec
 /// Abstract operation [`ToIntlMathematicalValue ( value )`][spec].
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-tointlmathematicalvalue
-fn to_intl_mathematical_value(value: &JsValue, context: &mut Context) -> JsResult<FixedDecimal> {
+pub(crate) fn to_intl_mathematical_value(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<IntlMathematicalValue> {
     // 1. Let primValue be ? ToPrimitive(value, number).
     let prim_value = value.to_primitive(context, PreferredType::Number)?;
 
-    // TODO: Add support in `FixedDecimal` for infinity and NaN, which
-    // should remove the returned errors.
     match prim_value {
         // 2. If Type(primValue) is BigInt, return ℝ(primValue).
         JsValue::BigInt(bi) => {
             let bi = bi.to_string();
             FixedDecimal::try_from(bi.as_bytes())
+                .map(IntlMathematicalValue::Finite)
                 .map_err(|err| JsNativeError::range().with_message(err.to_string()).into())
         }
         // 3. If Type(primValue) is String, then
@@ -781,10 +1505,16 @@ fn to_intl_mathematical_value(value: &JsValue, context: &mut Context) -> JsResul
             //     c. If rounded is +∞𝔽, return positive-infinity.
             //     d. If rounded is +0𝔽 and intlMV < 0, return negative-zero.
             //     e. If rounded is +0𝔽, return 0.
-            js_string_to_fixed_decimal(&s).ok_or_else(|| {
-                JsNativeError::syntax()
-                    .with_message("could not parse the provided string")
-                    .into()
+            // 10. Return intlMV.
+            if let Some(x) = js_string_to_fixed_decimal(&s) {
+                return Ok(IntlMathematicalValue::Finite(x));
+            }
+
+            let s = s.to_std_string_escaped();
+            Ok(match s.trim_matches(is_trimmable_whitespace) {
+                "Infinity" | "+Infinity" => IntlMathematicalValue::PositiveInfinity,
+                "-Infinity" => IntlMathematicalValue::NegativeInfinity,
+                _ => IntlMathematicalValue::NotANumber,
             })
         }
         // 4. Else,
@@ -794,8 +1524,18 @@ fn to_intl_mathematical_value(value: &JsValue, context: &mut Context) -> JsResul
             // c. Let str be Number::toString(x, 10).
             let x = other.to_number(context)?;
 
-            FixedDecimal::try_from_f64(x, FloatPrecision::Floating)
-                .map_err(|err| JsNativeError::range().with_message(err.to_string()).into())
+            Ok(if x.is_nan() {
+                IntlMathematicalValue::NotANumber
+            } else if x == f64::INFINITY {
+                IntlMathematicalValue::PositiveInfinity
+            } else if x == f64::NEG_INFINITY {
+                IntlMathematicalValue::NegativeInfinity
+            } else {
+                IntlMathematicalValue::Finite(
+                    FixedDecimal::try_from_f64(x, FloatPrecision::Floating)
+                        .expect("`x` must be finite"),
+                )
+            })
         }
     }
 }
@@ -191,6 +191,11 @@ pub(crate) struct Currency {
 }
 
 impl Currency {
+    /// Gets the uppercase ISO 4217 code of this currency.
+    pub(crate) fn as_str(&self) -> &str {
+        &self.inner
+    }
+
     pub(crate) fn to_js_string(self) -> JsString {
         let bytes = self.inner.as_bytes();
         js_string!(&[
@@ -251,6 +256,16 @@ pub(crate) struct Unit {
 }
 
 impl Unit {
+    /// Gets the numerator of this unit.
+    pub(crate) const fn numerator(&self) -> &'static str {
+        self.numerator
+    }
+
+    /// Gets the denominator of this unit, or an empty string if this is not a compound unit.
+    pub(crate) const fn denominator(&self) -> &'static str {
+        self.denominator
+    }
+
     /// Gets the corresponding `JsString` of this unit.
     pub(crate) fn to_js_string(&self) -> JsString {
         if self.denominator.is_empty() {
@@ -265,6 +280,57 @@ impl Unit {
     }
 }
 
+/// The [sanctioned single unit identifiers][spec], sorted by identifier.
+///
+/// [spec]: https://tc39.es/ecma402/#table-sanctioned-single-unit-identifiers
+pub(in crate::builtins::intl) static SANCTIONED_UNITS: [&str; 45] = [
+    "acre",
+    "bit",
+    "byte",
+    "celsius",
+    "centimeter",
+    "day",
+    "degree",
+    "fahrenheit",
+    "fluid-ounce",
+    "foot",
+    "gallon",
+    "gigabit",
+    "gigabyte",
+    "gram",
+    "hectare",
+    "hour",
+    "inch",
+    "kilobit",
+    "kilobyte",
+    "kilogram",
+    "kilometer",
+    "liter",
+    "megabit",
+    "megabyte",
+    "meter",
+    "microsecond",
+    "mile",
+    "mile-scandinavian",
+    "milliliter",
+    "millimeter",
+    "millisecond",
+    "minute",
+    "month",
+    "nanosecond",
+    "ounce",
+    "percent",
+    "petabyte",
+    "pound",
+    "second",
+    "stone",
+    "terabit",
+    "terabyte",
+    "week",
+    "yard",
+    "year",
+];
+
 #[derive(Debug)]
 pub(crate) struct ParseUnitError;
 
@@ -281,54 +347,6 @@ impl std::str::FromStr for Unit {
     ///
     /// [spec]: https://tc39.es/ecma402/#sec-iswellformedunitidentifier
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        static SANCTIONED_UNITS: [&str; 45] = [
-            "acre",
-            "bit",
-            "byte",
-            "celsius",
-            "centimeter",
-            "day",
-            "degree",
-            "fahrenheit",
-            "fluid-ounce",
-            "foot",
-            "gallon",
-            "gigabit",
-            "gigabyte",
-            "gram",
-            "hectare",
-            "hour",
-            "inch",
-            "kilobit",
-            "kilobyte",
-            "kilogram",
-            "kilometer",
-            "liter",
-            "megabit",
-            "megabyte",
-            "meter",
-            "microsecond",
-            "mile",
-            "mile-scandinavian",
-            "milliliter",
-            "millimeter",
-            "millisecond",
-            "minute",
-            "month",
-            "nanosecond",
-            "ounce",
-            "percent",
-            "petabyte",
-            "pound",
-            "second",
-            "stone",
-            "terabit",
-            "terabyte",
-            "week",
-            "yard",
-            "year",
-        ];
-
         let (num, den) = s
             .split_once("-per-")
             .filter(|(_, den)| !den.is_empty())
@@ -746,10 +764,12 @@ impl DigitFormatOptions {
             rounding_mode: RoundingMode,
         ) -> i16 {
             let msb = number.nonzero_magnitude_start();
-            let min_msb = msb - i16::from(min_precision) + 1;
             let max_msb = msb - i16::from(max_precision) + 1;
             round(number, max_msb, rounding_mode, BaseMultiple::MultiplesOf1);
             number.trim_end();
+            // Rounding can carry into a new digit (e.g. 0.99 to 1.0), so the minimum precision
+            // must be relative to the rounded number.
+            let min_msb = number.nonzero_magnitude_start() - i16::from(min_precision) + 1;
             number.pad_end(min_msb);
             max_msb
         }
@@ -1,4 +1,7 @@
-use crate::builtins::intl::number_format::RoundingIncrement;
+use crate::{
+    builtins::intl::number_format::RoundingIncrement, js_string, run_test_actions,
+    JsNativeErrorKind, TestAction,
+};
 use fixed_decimal::RoundingIncrement::*;
 
 #[test]
@@ -39,3 +42,217 @@ fn u16_to_rounding_increment_rainy_day() {
         assert!(RoundingIncrement::from_u16(num).is_none());
     }
 }
+
+#[test]
+fn number_format_currency() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'USD' }).format(1234.5)",
+            js_string!("$1,234.50"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'JPY' }).format(1234.5)",
+            js_string!("¥1,235"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('de', { style: 'currency', currency: 'EUR' }).format(1234.5)",
+            js_string!("1.234,50\u{a0}€"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'EUR', currencyDisplay: 'code' }).format(-3)",
+            js_string!("-EUR\u{a0}3.00"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'USD', currencyDisplay: 'name', maximumFractionDigits: 0 }).format(1)",
+            js_string!("1 US dollar"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'USD', currencySign: 'accounting' }).format(-1234.5)",
+            js_string!("($1,234.50)"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'KWD' }).resolvedOptions().maximumFractionDigits",
+            3,
+        ),
+        TestAction::assert_native_error(
+            "new Intl.NumberFormat('en', { style: 'currency' })",
+            JsNativeErrorKind::Type,
+            "cannot format on the currency style without specifying a target currency",
+        ),
+    ]);
+}
+
+#[test]
+fn number_format_percent_and_unit() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'percent' }).format(0.256)",
+            js_string!("26%"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('de', { style: 'percent' }).format(0.256)",
+            js_string!("26\u{a0}%"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'unit', unit: 'kilometer-per-hour' }).format(50)",
+            js_string!("50 km/h"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'unit', unit: 'liter', unitDisplay: 'long' }).format(1)",
+            js_string!("1 liter"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'unit', unit: 'gigabyte-per-second', unitDisplay: 'long' }).format(2)",
+            js_string!("2 gigabytes per second"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'unit', unit: 'celsius', unitDisplay: 'narrow' }).format(-5)",
+            js_string!("-5°C"),
+        ),
+    ]);
+}
+
+#[test]
+fn number_format_notation() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { notation: 'compact' }).format(1234)",
+            js_string!("1.2K"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { notation: 'compact' }).format(999999)",
+            js_string!("1M"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { notation: 'compact', compactDisplay: 'long' }).format(2.5e9)",
+            js_string!("2.5 billion"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('de', { notation: 'compact' }).format(1234)",
+            js_string!("1234"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('ja', { notation: 'compact' }).format(123456789)",
+            js_string!("1.2億"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { notation: 'scientific' }).format(123456)",
+            js_string!("1.235E5"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { notation: 'scientific' }).format(0.00012)",
+            js_string!("1.2E-4"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { notation: 'engineering' }).format(123456)",
+            js_string!("123.456E3"),
+        ),
+    ]);
+}
+
+#[test]
+fn number_format_sign_display() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { signDisplay: 'always' }).format(5)",
+            js_string!("+5"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { signDisplay: 'exceptZero' }).format(0)",
+            js_string!("0"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { signDisplay: 'negative' }).format(-0)",
+            js_string!("0"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { signDisplay: 'never' }).format(-Infinity)",
+            js_string!("∞"),
+        ),
+        TestAction::assert_eq("new Intl.NumberFormat('en').format(NaN)", js_string!("NaN")),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en').format('-Infinity')",
+            js_string!("-∞"),
+        ),
+    ]);
+}
+
+#[test]
+fn number_format_to_parts() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "JSON.stringify(new Intl.NumberFormat('en', { style: 'currency', currency: 'USD' }).formatToParts(-1234.5))",
+            js_string!(
+                r#"[{"type":"minusSign","value":"-"},{"type":"currency","value":"$"},{"type":"integer","value":"1"},{"type":"group","value":","},{"type":"integer","value":"234"},{"type":"decimal","value":"."},{"type":"fraction","value":"50"}]"#
+            ),
+        ),
+        TestAction::assert_eq(
+            "JSON.stringify(new Intl.NumberFormat('en', { notation: 'scientific' }).formatToParts(0.0012))",
+            js_string!(
+                r#"[{"type":"integer","value":"1"},{"type":"decimal","value":"."},{"type":"fraction","value":"2"},{"type":"exponentSeparator","value":"E"},{"type":"exponentMinusSign","value":"-"},{"type":"exponentInteger","value":"3"}]"#
+            ),
+        ),
+        TestAction::assert_eq(
+            "JSON.stringify(new Intl.NumberFormat('en', { style: 'unit', unit: 'kilometer' }).formatToParts(5))",
+            js_string!(
+                r#"[{"type":"integer","value":"5"},{"type":"literal","value":" "},{"type":"unit","value":"km"}]"#
+            ),
+        ),
+    ]);
+}
+
+#[test]
+fn number_format_range() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en').formatRange(3, 5)",
+            js_string!("3–5"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'USD' }).formatRange(3, 5)",
+            js_string!("$3.00 – $5.00"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en', { maximumFractionDigits: 0 }).formatRange(2.9, 3.1)",
+            js_string!("~3"),
+        ),
+        TestAction::assert_eq(
+            "JSON.stringify(new Intl.NumberFormat('en').formatRangeToParts(3, 5))",
+            js_string!(
+                r#"[{"type":"integer","value":"3","source":"startRange"},{"type":"literal","value":"–","source":"shared"},{"type":"integer","value":"5","source":"endRange"}]"#
+            ),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.NumberFormat('en').formatRange(NaN, 5)",
+            JsNativeErrorKind::Range,
+            "cannot format a range with a NaN bound",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.NumberFormat('en').formatRange(3)",
+            JsNativeErrorKind::Type,
+            "`formatRange` requires both a start and an end number",
+        ),
+    ]);
+}
+
+#[test]
+fn to_locale_string() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "(1234567.891).toLocaleString('de-DE')",
+            js_string!("1.234.567,891"),
+        ),
+        TestAction::assert_eq(
+            "(0.5).toLocaleString('en', { style: 'percent' })",
+            js_string!("50%"),
+        ),
+        TestAction::assert_eq(
+            "12345678901234567890n.toLocaleString('en')",
+            js_string!("12,345,678,901,234,567,890"),
+        ),
+        TestAction::assert_eq(
+            "12345n.toLocaleString('de', { style: 'currency', currency: 'EUR' })",
+            js_string!("12.345,00\u{a0}€"),
+        ),
+    ]);
+}
@@ -440,9 +440,9 @@ impl PluralRules {
 
 #[derive(Debug)]
 #[allow(unused)] // Will be used when we implement `selectRange`
-struct ResolvedPlural {
-    category: PluralCategory,
-    formatted: Option<FixedDecimal>,
+pub(super) struct ResolvedPlural {
+    pub(super) category: PluralCategory,
+    pub(super) formatted: Option<FixedDecimal>,
 }
 
 /// Abstract operation [`ResolvePlural ( pluralRules, n )`][spec]
@@ -450,7 +450,7 @@ struct ResolvedPlural {
 /// Gets the plural corresponding to the number with the provided formatting options.
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-resolveplural
-fn resolve_plural(plural_rules: &PluralRules, n: f64) -> ResolvedPlural {
+pub(super) fn resolve_plural(plural_rules: &PluralRules, n: f64) -> ResolvedPlural {
     // 1. Assert: Type(pluralRules) is Object.
     // 2. Assert: pluralRules has an [[InitializedPluralRules]] internal slot.
     // 3. Assert: Type(n) is Number.
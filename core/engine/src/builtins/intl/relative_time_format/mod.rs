@@ -0,0 +1,686 @@
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use fixed_decimal::{FixedDecimal, FloatPrecision};
+use icu_locid::{extensions::unicode::Value, Locale};
+use icu_plurals::PluralCategory;
+use icu_provider::{
+    DataLocale, DataMarker, DataPayload, DataProvider, DataRequest, DataRequestMetadata,
+    DataResponse, KeyedDataMarker,
+};
+use icu_relativetime::{
+    options::Numeric,
+    provider::{
+        LongDayRelativeTimeFormatDataV1Marker, LongHourRelativeTimeFormatDataV1Marker,
+        LongMinuteRelativeTimeFormatDataV1Marker, LongMonthRelativeTimeFormatDataV1Marker,
+        LongQuarterRelativeTimeFormatDataV1Marker, LongSecondRelativeTimeFormatDataV1Marker,
+        LongWeekRelativeTimeFormatDataV1Marker, LongYearRelativeTimeFormatDataV1Marker,
+        NarrowDayRelativeTimeFormatDataV1Marker, NarrowHourRelativeTimeFormatDataV1Marker,
+        NarrowMinuteRelativeTimeFormatDataV1Marker, NarrowMonthRelativeTimeFormatDataV1Marker,
+        NarrowQuarterRelativeTimeFormatDataV1Marker, NarrowSecondRelativeTimeFormatDataV1Marker,
+        NarrowWeekRelativeTimeFormatDataV1Marker, NarrowYearRelativeTimeFormatDataV1Marker,
+        RelativeTimePatternDataV1, ShortDayRelativeTimeFormatDataV1Marker,
+        ShortHourRelativeTimeFormatDataV1Marker, ShortMinuteRelativeTimeFormatDataV1Marker,
+        ShortMonthRelativeTimeFormatDataV1Marker, ShortQuarterRelativeTimeFormatDataV1Marker,
+        ShortSecondRelativeTimeFormatDataV1Marker, ShortWeekRelativeTimeFormatDataV1Marker,
+        ShortYearRelativeTimeFormatDataV1Marker, SingularSubPattern,
+    },
+};
+
+use crate::{
+    builtins::{
+        options::get_option, Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::{common::StaticJsStrings, utf16},
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, resolve_locale, supported_locales},
+    number_format::{NumberFormat, NumberFormatLocaleOptions, NumberPart},
+    options::{coerce_options_to_object, IntlOptions},
+    plural_rules::{resolve_plural, PluralRules},
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct RelativeTimeFormat {
+    #[unsafe_ignore_trace]
+    locale: Locale,
+    #[unsafe_ignore_trace]
+    numbering_system: Option<Value>,
+    #[unsafe_ignore_trace]
+    style: Style,
+    #[unsafe_ignore_trace]
+    numeric: Numeric,
+    #[unsafe_ignore_trace]
+    fields: [DataPayload<RelativeTimePatternsV1Marker>; 8],
+    number_format: JsObject<NumberFormat>,
+    plural_rules: JsObject<PluralRules>,
+}
+
+/// Marker for the relative time patterns of any unit and style.
+///
+/// ICU4X uses a different marker for every combination of unit and style, but all of them share
+/// the same data struct.
+#[derive(Debug)]
+struct RelativeTimePatternsV1Marker;
+
+impl DataMarker for RelativeTimePatternsV1Marker {
+    type Yokeable = RelativeTimePatternDataV1<'static>;
+}
+
+/// Loads the relative time patterns of the unit and style represented by `M`.
+fn load_patterns<M>(
+    locale: &DataLocale,
+    provider: &IntlProvider,
+) -> JsResult<DataPayload<RelativeTimePatternsV1Marker>>
+where
+    M: KeyedDataMarker<Yokeable = RelativeTimePatternDataV1<'static>>,
+    IntlProvider: DataProvider<M>,
+{
+    provider
+        .load(DataRequest {
+            locale,
+            metadata: DataRequestMetadata::default(),
+        })
+        .and_then(DataResponse::take_payload)
+        .map(DataPayload::cast)
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()).into())
+}
+
+/// Loads the relative time patterns of every unit for `style`, indexed by [`Unit`].
+fn load_fields(
+    style: Style,
+    locale: &DataLocale,
+    provider: &IntlProvider,
+) -> JsResult<[DataPayload<RelativeTimePatternsV1Marker>; 8]> {
+    Ok(match style {
+        Style::Long => [
+            load_patterns::<LongSecondRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<LongMinuteRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<LongHourRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<LongDayRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<LongWeekRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<LongMonthRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<LongQuarterRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<LongYearRelativeTimeFormatDataV1Marker>(locale, provider)?,
+        ],
+        Style::Short => [
+            load_patterns::<ShortSecondRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<ShortMinuteRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<ShortHourRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<ShortDayRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<ShortWeekRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<ShortMonthRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<ShortQuarterRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<ShortYearRelativeTimeFormatDataV1Marker>(locale, provider)?,
+        ],
+        Style::Narrow => [
+            load_patterns::<NarrowSecondRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<NarrowMinuteRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<NarrowHourRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<NarrowDayRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<NarrowWeekRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<NarrowMonthRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<NarrowQuarterRelativeTimeFormatDataV1Marker>(locale, provider)?,
+            load_patterns::<NarrowYearRelativeTimeFormatDataV1Marker>(locale, provider)?,
+        ],
+    })
+}
+
+/// A part of a formatted relative time.
+#[derive(Debug)]
+struct Part {
+    typ: &'static str,
+    value: String,
+    unit: Option<Unit>,
+}
+
+impl Service for RelativeTimeFormat {
+    type LangMarker = LongSecondRelativeTimeFormatDataV1Marker;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        // The only relevant extension key is "nu", which is resolved in the same way as for
+        // `Intl.NumberFormat`.
+        NumberFormat::resolve(locale, options, provider);
+    }
+}
+
+impl IntrinsicObject for RelativeTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.RelativeTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 2)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 2)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for RelativeTimeFormat {
+    const NAME: JsString = StaticJsStrings::RELATIVE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for RelativeTimeFormat {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::relative_time_format;
+
+    /// Constructor [`Intl.RelativeTimeFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `RelativeTimeFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.RelativeTimeFormat` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 2. Let relativeTimeFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.RelativeTimeFormat.prototype%", « [[InitializedRelativeTimeFormat]], [[Locale]], [[DataLocale]], [[Style]], [[Numeric]], [[NumberFormat]], [[NumberingSystem]], [[PluralRules]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::relative_time_format,
+            context,
+        )?;
+
+        // 3. Return ? InitializeRelativeTimeFormat(relativeTimeFormat, locales, options).
+
+        // `InitializeRelativeTimeFormat ( relativeTimeFormat, locales, options )`
+        // https://tc39.es/ecma402/#sec-InitializeRelativeTimeFormat
+
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[LocaleMatcher]] to matcher.
+        let matcher = get_option(&options, utf16!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 8. Set opt.[[nu]] to numberingSystem.
+        let numbering_system = get_option(&options, utf16!("numberingSystem"), context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions { numbering_system },
+        };
+
+        // 9. Let localeData be %Intl.RelativeTimeFormat%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%Intl.RelativeTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.RelativeTimeFormat%.[[RelevantExtensionKeys]], localeData).
+        // 11. Let locale be r.[[locale]].
+        // 12. Set relativeTimeFormat.[[Locale]] to locale.
+        // 13. Set relativeTimeFormat.[[DataLocale]] to r.[[dataLocale]].
+        // 14. Set relativeTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            &requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        );
+
+        // 15. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow" », "long").
+        // 16. Set relativeTimeFormat.[[Style]] to style.
+        let style = get_option(&options, utf16!("style"), context)?.unwrap_or_default();
+
+        // 17. Let numeric be ? GetOption(options, "numeric", string, « "always", "auto" », "always").
+        // 18. Set relativeTimeFormat.[[Numeric]] to numeric.
+        let numeric = get_option(&options, utf16!("numeric"), context)?.unwrap_or_default();
+
+        let fields = load_fields(style, &DataLocale::from(&locale), context.intl_provider())?;
+
+        let locale_string: JsValue = js_string!(locale.to_string()).into();
+
+        // 19. Let relativeTimeFormat.[[NumberFormat]] be ! Construct(%Intl.NumberFormat%, « locale »).
+        let number_format = context
+            .intrinsics()
+            .constructors()
+            .number_format()
+            .constructor()
+            .construct(std::slice::from_ref(&locale_string), None, context)?
+            .downcast::<NumberFormat>()
+            .expect("`Intl.NumberFormat` must construct a `NumberFormat` object");
+
+        // 20. Let relativeTimeFormat.[[PluralRules]] be ! Construct(%Intl.PluralRules%, « locale »).
+        let plural_rules = context
+            .intrinsics()
+            .constructors()
+            .plural_rules()
+            .constructor()
+            .construct(&[locale_string], None, context)?
+            .downcast::<PluralRules>()
+            .expect("`Intl.PluralRules` must construct a `PluralRules` object");
+
+        let relative_time_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                numbering_system: intl_options.service_options.numbering_system,
+                style,
+                numeric,
+                fields,
+                number_format,
+                plural_rules,
+            },
+        );
+
+        // 21. Return relativeTimeFormat.
+        Ok(relative_time_format.into())
+    }
+}
+
+impl RelativeTimeFormat {
+    /// [`Intl.RelativeTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in relative
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.RelativeTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales::<<Self as Service>::LangMarker>(&requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.format ( value, unit )`][spec].
+    ///
+    /// Formats a value and unit according to the locale and formatting options of this
+    /// `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let object = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`format` can only be called on a `RelativeTimeFormat` object")
+        })?;
+        let relative_time_format = object.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`format` can only be called on a `RelativeTimeFormat` object")
+        })?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTime(relativeTimeFormat, value, unit).
+
+        // `FormatRelativeTime ( relativeTimeFormat, value, unit )`
+        // https://tc39.es/ecma402/#sec-FormatRelativeTime
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let parts = relative_time_format.partition_relative_time_pattern(value, &unit)?;
+
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        let result = parts.into_iter().map(|part| part.value).collect::<String>();
+
+        // 4. Return result.
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.formatToParts ( value, unit )`][spec].
+    ///
+    /// Returns an array of objects representing the relative time format in parts that can be
+    /// used for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let object = this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`formatToParts` can only be called on a `RelativeTimeFormat` object")
+        })?;
+        let relative_time_format = object.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`formatToParts` can only be called on a `RelativeTimeFormat` object")
+        })?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTimeToParts(relativeTimeFormat, value, unit).
+
+        // `FormatRelativeTimeToParts ( relativeTimeFormat, value, unit )`
+        // https://tc39.es/ecma402/#sec-FormatRelativeTimeToParts
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let parts = relative_time_format.partition_relative_time_pattern(value, &unit)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        // 3. Let n be 0.
+        let mut result = Vec::with_capacity(parts.len());
+
+        // 4. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for part in parts {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw(utf16!("type"), js_string!(part.typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw(utf16!("value"), js_string!(part.value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, then
+            if let Some(unit) = part.unit {
+                // i. Perform ! CreateDataPropertyOrThrow(O, "unit", part.[[Unit]]).
+                o.create_data_property_or_throw(utf16!("unit"), js_string!(unit.as_str()), context)
+                    .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
+            // f. Increment n by 1.
+            result.push(o.into());
+        }
+
+        // 5. Return result.
+        Ok(Array::create_array_from_list(result, context).into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during the
+    /// construction of the current `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let object = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on a `RelativeTimeFormat` object",
+            )
+        })?;
+        let relative_time_format = object.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on a `RelativeTimeFormat` object",
+            )
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 20, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of relativeTimeFormat's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                utf16!("locale"),
+                js_string!(relative_time_format.locale.to_string()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                utf16!("style"),
+                js_string!(relative_time_format.style.as_str()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                utf16!("numeric"),
+                match relative_time_format.numeric {
+                    Numeric::Always => js_string!("always"),
+                    Numeric::Auto => js_string!("auto"),
+                },
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        if let Some(nu) = &relative_time_format.numbering_system {
+            options
+                .create_data_property_or_throw(
+                    utf16!("numberingSystem"),
+                    js_string!(nu.to_string()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+
+    /// Abstract operation [`PartitionRelativeTimePattern ( relativeTimeFormat, value, unit )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-PartitionRelativeTimePattern
+    fn partition_relative_time_pattern(&self, value: f64, unit: &JsString) -> JsResult<Vec<Part>> {
+        // 1. Assert: relativeTimeFormat has an [[InitializedRelativeTimeFormat]] internal slot.
+        // 2. Assert: value is a Number.
+        // 3. Assert: unit is a String.
+        // 4. If value is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+        if !value.is_finite() {
+            return Err(JsNativeError::range()
+                .with_message("cannot format a non-finite relative time")
+                .into());
+        }
+
+        // 5. Let unit be ? SingularRelativeTimeUnit(unit).
+        let unit = Unit::from_js_string(unit)?;
+
+        // 6. Let fields be relativeTimeFormat.[[Fields]].
+        // 7. Let style be relativeTimeFormat.[[Style]].
+        // 8. If style is equal to "short", then
+        //     a. Let entry be the string-concatenation of unit and "-short".
+        // 9. Else if style is equal to "narrow", then
+        //     a. Let entry be the string-concatenation of unit and "-narrow".
+        // 10. Else,
+        //     a. Let entry be unit.
+        // 11. If fields doesn't have a field [[<entry>]], then
+        //     a. Let entry be unit.
+        // 12. Let patterns be fields.[[<entry>]].
+        let patterns = self.fields[unit as usize].get();
+
+        // 13. Let numeric be relativeTimeFormat.[[Numeric]].
+        // 14. If numeric is equal to "auto", then
+        if self.numeric == Numeric::Auto {
+            // a. Let valueString be ToString(value).
+            // b. If patterns has a field [[<valueString>]], then
+            //     i. Let result be patterns.[[<valueString>]].
+            //     ii. Return a List containing the Record { [[Type]]: "literal", [[Value]]: result, [[Unit]]: empty }.
+            if value.fract() == 0.0 && (f64::from(i8::MIN)..=f64::from(i8::MAX)).contains(&value) {
+                #[allow(clippy::cast_possible_truncation)]
+                if let Some(result) = patterns.relatives.get(&(value as i8)) {
+                    return Ok(vec![Part {
+                        typ: "literal",
+                        value: result.to_owned(),
+                        unit: None,
+                    }]);
+                }
+            }
+        }
+
+        // 15. If value is -0𝔽 or if value is less than 0, then
+        let (patterns, value) = if value.is_sign_negative() {
+            // a. Let tl be "past".
+            // b. Set value to -value.
+            (&patterns.past, -value)
+        } else {
+            // 16. Else,
+            //     a. Let tl be "future".
+            (&patterns.future, value)
+        };
+
+        // 17. Let po be patterns.[[<tl>]].
+        // 18. Let fv be ! PartitionNumberPattern(relativeTimeFormat.[[NumberFormat]], value).
+        let fixed = FixedDecimal::try_from_f64(value, FloatPrecision::Floating)
+            .expect("`value` must be finite");
+        let fv = self
+            .number_format
+            .borrow()
+            .data
+            .partition_number_pattern(fixed.into());
+
+        // 19. Let pr be ! ResolvePlural(relativeTimeFormat.[[PluralRules]], value).[[PluralCategory]].
+        let pr = resolve_plural(&self.plural_rules.borrow().data, value).category;
+
+        // 20. Let pattern be po.[[<pr>]].
+        let pattern = match pr {
+            PluralCategory::Zero => patterns.zero.as_ref(),
+            PluralCategory::One => patterns.one.as_ref(),
+            PluralCategory::Two => patterns.two.as_ref(),
+            PluralCategory::Few => patterns.few.as_ref(),
+            PluralCategory::Many => patterns.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&patterns.other);
+
+        // 21. Return MakePartsList(pattern, unit, fv).
+        Ok(make_parts_list(pattern, unit, fv))
+    }
+}
+
+/// Abstract operation [`MakePartsList ( pattern, unit, parts )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-MakePartsList
+fn make_parts_list(
+    pattern: &SingularSubPattern<'_>,
+    unit: Unit,
+    parts: Vec<NumberPart>,
+) -> Vec<Part> {
+    // 1. Let patternParts be PartitionPattern(pattern).
+    // 2. Let result be a new empty List.
+    let mut result = Vec::new();
+
+    let literal = |value: &str| Part {
+        typ: "literal",
+        value: value.to_owned(),
+        unit: None,
+    };
+
+    // The placeholder of the pattern is stored as the byte index at which the formatted number
+    // must be inserted, where an index of 255 means that the pattern doesn't have a placeholder.
+    let Some((before, after)) =
+        (pattern.index != u8::MAX).then(|| pattern.pattern.split_at(usize::from(pattern.index)))
+    else {
+        return vec![literal(&pattern.pattern)];
+    };
+
+    // 3. For each Record { [[Type]], [[Value]] } patternPart in patternParts, do
+    //     a. If patternPart.[[Type]] is "literal", then
+    //         i. Append the Record { [[Type]]: "literal", [[Value]]: patternPart.[[Value]], [[Unit]]: empty } to result.
+    if !before.is_empty() {
+        result.push(literal(before));
+    }
+
+    //     b. Else,
+    //         i. Assert: patternPart.[[Type]] is "0".
+    //         ii. For each Record { [[Type]], [[Value]] } part in parts, do
+    //             1. Append the Record { [[Type]]: part.[[Type]], [[Value]]: part.[[Value]], [[Unit]]: unit } to result.
+    result.extend(parts.into_iter().map(|part| Part {
+        typ: part.typ,
+        value: part.value,
+        unit: Some(unit),
+    }));
+
+    if !after.is_empty() {
+        result.push(literal(after));
+    }
+
+    // 4. Return result.
+    result
+}
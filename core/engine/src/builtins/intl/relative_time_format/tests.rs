@@ -0,0 +1,84 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn relative_time_format_format() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en').format(-1, 'day')",
+            js_string!("1 day ago"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en').format(2, 'hours')",
+            js_string!("in 2 hours"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en').format(1234.5, 'years')",
+            js_string!("in 1,234.5 years"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en', { numeric: 'auto' }).format(-1, 'day')",
+            js_string!("yesterday"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en', { numeric: 'auto' }).format(-0, 'day')",
+            js_string!("today"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en', { style: 'short' }).format(3, 'month')",
+            js_string!("in 3 mo."),
+        ),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('es').format(-3, 'day')",
+            js_string!("hace 3 días"),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.RelativeTimeFormat('en').format(1, 'decade')",
+            JsNativeErrorKind::Range,
+            "provided unit was not a valid relative time unit",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.RelativeTimeFormat('en').format(NaN, 'day')",
+            JsNativeErrorKind::Range,
+            "cannot format a non-finite relative time",
+        ),
+    ]);
+}
+
+#[test]
+fn relative_time_format_format_to_parts() {
+    run_test_actions([
+        TestAction::run("const parts = new Intl.RelativeTimeFormat('en').formatToParts(1234.5, 'day');"),
+        TestAction::assert_eq(
+            "JSON.stringify(parts)",
+            js_string!(
+                r#"[{"type":"literal","value":"in "},{"type":"integer","value":"1","unit":"day"},{"type":"group","value":",","unit":"day"},{"type":"integer","value":"234","unit":"day"},{"type":"decimal","value":".","unit":"day"},{"type":"fraction","value":"5","unit":"day"},{"type":"literal","value":" days"}]"#
+            ),
+        ),
+        TestAction::assert_eq(
+            "JSON.stringify(new Intl.RelativeTimeFormat('en', { numeric: 'auto' }).formatToParts(1, 'day'))",
+            js_string!(r#"[{"type":"literal","value":"tomorrow"}]"#),
+        ),
+    ]);
+}
+
+#[test]
+fn relative_time_format_options() {
+    run_test_actions([
+        TestAction::run(
+            "const options = new Intl.RelativeTimeFormat('en', { style: 'narrow' }).resolvedOptions();",
+        ),
+        TestAction::assert_eq("options.locale", js_string!("en")),
+        TestAction::assert_eq("options.style", js_string!("narrow")),
+        TestAction::assert_eq("options.numeric", js_string!("always")),
+        TestAction::assert_native_error(
+            "Intl.RelativeTimeFormat()",
+            JsNativeErrorKind::Type,
+            "cannot call `Intl.RelativeTimeFormat` constructor without `new`",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.RelativeTimeFormat('en', { numeric: 'sometimes' })",
+            JsNativeErrorKind::Range,
+            "provided string was not `always` or `auto`",
+        ),
+    ]);
+}
@@ -0,0 +1,497 @@
+//! This module implements the `%IteratorHelperPrototype%` object and the iterator helper objects
+//! returned by the lazy methods of `Iterator.prototype`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-iterator-helpers/#sec-%iteratorhelperprototype%-object
+
+use boa_gc::{custom_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        iterable::{create_iter_result_object, get_iterator_flattenable, StringHandling},
+        BuiltInBuilder, IntrinsicObject,
+    },
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    js_string,
+    object::JsObject,
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    Context, JsData, JsResult, JsValue,
+};
+
+use super::IteratorRecord;
+
+/// The abstract closure of an iterator helper, along with the state captured by it.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) enum IteratorHelperClosure {
+    /// The closure created by `Iterator.prototype.map`.
+    Map { mapper: JsObject, counter: u64 },
+
+    /// The closure created by `Iterator.prototype.filter`.
+    Filter { predicate: JsObject, counter: u64 },
+
+    /// The closure created by `Iterator.prototype.take`.
+    ///
+    /// A `remaining` value of `None` represents a limit of `+∞`.
+    Take { remaining: Option<u64> },
+
+    /// The closure created by `Iterator.prototype.drop`.
+    ///
+    /// A `remaining` value of `None` represents a limit of `+∞`.
+    Drop { remaining: Option<u64> },
+
+    /// The closure created by `Iterator.prototype.flatMap`.
+    FlatMap {
+        mapper: JsObject,
+        counter: u64,
+        inner: Option<IteratorRecord>,
+    },
+}
+
+impl IteratorHelperClosure {
+    /// Resumes the closure until it yields a new value, returning `None` when the closure
+    /// finishes its execution.
+    fn resume(
+        &mut self,
+        iterated: &mut IteratorRecord,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        match self {
+            Self::Map { mapper, counter } => {
+                // a. Let counter be 0.
+                // b. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return undefined.
+                if iterated.step(context)? {
+                    return Ok(None);
+                }
+                let value = iterated.value(context)?;
+
+                //     iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                //     iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = close_on_abrupt(mapped, iterated, context)?;
+
+                //     v. Let completion be Completion(Yield(mapped)).
+                //     vi. IfAbruptCloseIterator(completion, iterated).
+                //     vii. Set counter to counter + 1.
+                *counter += 1;
+                Ok(Some(mapped))
+            }
+            Self::Filter { predicate, counter } => {
+                // a. Let counter be 0.
+                // b. Repeat,
+                loop {
+                    // i. Let value be ? IteratorStepValue(iterated).
+                    // ii. If value is done, return undefined.
+                    if iterated.step(context)? {
+                        return Ok(None);
+                    }
+                    let value = iterated.value(context)?;
+
+                    // iii. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+                    let selected = predicate.call(
+                        &JsValue::undefined(),
+                        &[value.clone(), (*counter).into()],
+                        context,
+                    );
+
+                    // iv. IfAbruptCloseIterator(selected, iterated).
+                    let selected = close_on_abrupt(selected, iterated, context)?;
+
+                    // vi. Set counter to counter + 1.
+                    *counter += 1;
+
+                    // v. If ToBoolean(selected) is true, then
+                    if selected.to_boolean() {
+                        // 1. Let completion be Completion(Yield(value)).
+                        // 2. IfAbruptCloseIterator(completion, iterated).
+                        return Ok(Some(value));
+                    }
+                }
+            }
+            Self::Take { remaining } => {
+                // a. Let remaining be integerLimit.
+                // b. Repeat,
+                //     i. If remaining is 0, then
+                if *remaining == Some(0) {
+                    // 1. Return ? IteratorClose(iterated, ReturnCompletion(undefined)).
+                    iterated.close(Ok(JsValue::undefined()), context)?;
+                    return Ok(None);
+                }
+
+                //     ii. If remaining ≠ +∞, then
+                if let Some(remaining) = remaining {
+                    // 1. Set remaining to remaining - 1.
+                    *remaining -= 1;
+                }
+
+                //     iii. Let value be ? IteratorStepValue(iterated).
+                //     iv. If value is done, return undefined.
+                if iterated.step(context)? {
+                    return Ok(None);
+                }
+
+                //     v. Let completion be Completion(Yield(value)).
+                //     vi. IfAbruptCloseIterator(completion, iterated).
+                iterated.value(context).map(Some)
+            }
+            Self::Drop { remaining } => {
+                // a. Let remaining be integerLimit.
+                // b. Repeat, while remaining > 0,
+                while *remaining != Some(0) {
+                    // i. If remaining ≠ +∞, then
+                    if let Some(remaining) = remaining {
+                        // 1. Set remaining to remaining - 1.
+                        *remaining -= 1;
+                    }
+
+                    // ii. Let next be ? IteratorStep(iterated).
+                    // iii. If next is done, return undefined.
+                    if iterated.step(context)? {
+                        return Ok(None);
+                    }
+                }
+
+                // c. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return undefined.
+                if iterated.step(context)? {
+                    return Ok(None);
+                }
+
+                //     iii. Let completion be Completion(Yield(value)).
+                //     iv. IfAbruptCloseIterator(completion, iterated).
+                iterated.value(context).map(Some)
+            }
+            Self::FlatMap {
+                mapper,
+                counter,
+                inner,
+            } => {
+                // a. Let counter be 0.
+                // b. Repeat,
+                loop {
+                    // vi. Repeat, while innerAlive is true,
+                    if let Some(inner_iterator) = inner {
+                        // 1. Let innerValue be Completion(IteratorStepValue(innerIterator)).
+                        let inner_value = inner_iterator.step(context).and_then(|done| {
+                            if done {
+                                Ok(None)
+                            } else {
+                                inner_iterator.value(context).map(Some)
+                            }
+                        });
+
+                        // 2. IfAbruptCloseIterator(innerValue, iterated).
+                        // 4. Else,
+                        //     a. Let completion be Completion(Yield(innerValue)).
+                        if let Some(value) = close_on_abrupt(inner_value, iterated, context)? {
+                            return Ok(Some(value));
+                        }
+
+                        // 3. If innerValue is done, then
+                        //     a. Set innerAlive to false.
+                        *inner = None;
+
+                        // vii. Set counter to counter + 1.
+                        *counter += 1;
+                    }
+
+                    // i. Let value be ? IteratorStepValue(iterated).
+                    // ii. If value is done, return undefined.
+                    if iterated.step(context)? {
+                        return Ok(None);
+                    }
+                    let value = iterated.value(context)?;
+
+                    // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                    let mapped =
+                        mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                    // iv. IfAbruptCloseIterator(mapped, iterated).
+                    let mapped = close_on_abrupt(mapped, iterated, context)?;
+
+                    // v. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-primitives)).
+                    let inner_iterator =
+                        get_iterator_flattenable(&mapped, StringHandling::RejectStrings, context);
+
+                    //     vi. IfAbruptCloseIterator(innerIterator, iterated).
+                    //     vii. Let innerAlive be true.
+                    *inner = Some(close_on_abrupt(inner_iterator, iterated, context)?);
+                }
+            }
+        }
+    }
+
+    /// Handles a `return` completion received by the closure while it is suspended at a `Yield`.
+    fn resume_return(&mut self, iterated: &IteratorRecord, context: &mut Context) -> JsResult<()> {
+        // flatMap:
+        // vi. 4. b. If completion is an abrupt completion, then
+        if let Self::FlatMap {
+            inner: Some(inner), ..
+        } = self
+        {
+            // i. Let backupCompletion be Completion(IteratorClose(innerIterator, completion)).
+            let backup_completion = inner.close(Ok(JsValue::undefined()), context);
+
+            // ii. IfAbruptCloseIterator(backupCompletion, iterated).
+            close_on_abrupt(backup_completion, iterated, context)?;
+        }
+
+        // IfAbruptCloseIterator(completion, iterated).
+        // NOTE: `completion` is a return completion, so this closes the underlying iterator with
+        // a normal completion.
+        iterated.close(Ok(JsValue::undefined()), context)?;
+        Ok(())
+    }
+}
+
+/// Equivalent to `IfAbruptCloseIterator`, but for closures that don't directly return a
+/// `JsResult<JsValue>`.
+fn close_on_abrupt<T>(
+    value: JsResult<T>,
+    iterated: &IteratorRecord,
+    context: &mut Context,
+) -> JsResult<T> {
+    match value {
+        Ok(value) => Ok(value),
+        Err(err) => Err(iterated
+            .close(Err(err), context)
+            .expect_err("closing an iterator with a throw completion must return an error")),
+    }
+}
+
+/// The state of an iterator helper object.
+///
+/// This mirrors the `[[GeneratorState]]` of the generator that the specification uses to define
+/// iterator helpers.
+#[derive(Debug, Finalize)]
+enum IteratorHelperState {
+    SuspendedStart {
+        iterated: IteratorRecord,
+        closure: IteratorHelperClosure,
+    },
+    SuspendedYield {
+        iterated: IteratorRecord,
+        closure: IteratorHelperClosure,
+    },
+    Executing,
+    Completed,
+}
+
+// Need to manually implement, since `Trace` adds a `Drop` impl which disallows destructuring.
+unsafe impl Trace for IteratorHelperState {
+    custom_trace!(this, mark, {
+        match &this {
+            Self::SuspendedStart { iterated, closure }
+            | Self::SuspendedYield { iterated, closure } => {
+                mark(iterated);
+                mark(closure);
+            }
+            Self::Executing | Self::Completed => {}
+        }
+    });
+}
+
+/// An iterator helper object, returned by the lazy methods of `Iterator.prototype`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iterator-helper-objects
+#[derive(Debug, Finalize, Trace, JsData)]
+pub(crate) struct IteratorHelper {
+    state: IteratorHelperState,
+}
+
+impl IntrinsicObject for IteratorHelper {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event("%IteratorHelperPrototype%", "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
+            .static_method(Self::next, js_string!("next"), 0)
+            .static_method(Self::r#return, js_string!("return"), 0)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                js_string!("Iterator Helper"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().iterator_prototypes().iterator_helper()
+    }
+}
+
+impl IteratorHelper {
+    /// Creates a new iterator helper object that runs `closure` over the `iterated` iterator.
+    pub(crate) fn create(
+        iterated: IteratorRecord,
+        closure: IteratorHelperClosure,
+        context: &mut Context,
+    ) -> JsObject {
+        // Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // Set result.[[UnderlyingIterator]] to iterated.
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .iterator_helper(),
+            Self {
+                state: IteratorHelperState::SuspendedStart { iterated, closure },
+            },
+        )
+    }
+
+    /// Gets the iterator helper object of `this`, or throws if `this` is not one.
+    fn validate<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        this.as_object()
+            .filter(|obj| obj.is::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "%IteratorHelperPrototype%.{method}: `this` is not an Iterator Helper object"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Takes the state of the iterator helper, setting it to `Executing`.
+    ///
+    /// This is the equivalent of `GeneratorValidate`, which throws if the generator is already
+    /// running.
+    fn take_state(object: &JsObject, method: &str) -> JsResult<IteratorHelperState> {
+        let mut helper = object
+            .downcast_mut::<Self>()
+            .expect("must be an iterator helper object");
+
+        if matches!(helper.state, IteratorHelperState::Executing) {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "%IteratorHelperPrototype%.{method}: iterator helper is already running"
+                ))
+                .into());
+        }
+
+        Ok(std::mem::replace(
+            &mut helper.state,
+            IteratorHelperState::Executing,
+        ))
+    }
+
+    /// Sets the state of the iterator helper.
+    fn set_state(object: &JsObject, state: IteratorHelperState) {
+        object
+            .downcast_mut::<Self>()
+            .expect("must be an iterator helper object")
+            .state = state;
+    }
+
+    /// `%IteratorHelperPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-%iteratorhelperprototype%.next
+    pub(crate) fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? GeneratorResume(this value, undefined, "Iterator Helper").
+        let object = Self::validate(this, "next")?;
+
+        let (mut iterated, mut closure) = match Self::take_state(object, "next")? {
+            IteratorHelperState::SuspendedStart { iterated, closure }
+            | IteratorHelperState::SuspendedYield { iterated, closure } => (iterated, closure),
+            IteratorHelperState::Completed => {
+                Self::set_state(object, IteratorHelperState::Completed);
+                return Ok(create_iter_result_object(
+                    JsValue::undefined(),
+                    true,
+                    context,
+                ));
+            }
+            IteratorHelperState::Executing => unreachable!("`take_state` checks this case"),
+        };
+
+        match closure.resume(&mut iterated, context) {
+            Ok(Some(value)) => {
+                Self::set_state(
+                    object,
+                    IteratorHelperState::SuspendedYield { iterated, closure },
+                );
+                Ok(create_iter_result_object(value, false, context))
+            }
+            Ok(None) => {
+                Self::set_state(object, IteratorHelperState::Completed);
+                Ok(create_iter_result_object(
+                    JsValue::undefined(),
+                    true,
+                    context,
+                ))
+            }
+            Err(err) => {
+                Self::set_state(object, IteratorHelperState::Completed);
+                Err(err)
+            }
+        }
+    }
+
+    /// `%IteratorHelperPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-%iteratorhelperprototype%.return
+    pub(crate) fn r#return(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[UnderlyingIterator]]).
+        // 3. Assert: O has a [[GeneratorState]] slot.
+        let object = Self::validate(this, "return")?;
+
+        let result = match Self::take_state(object, "return")? {
+            // 4. If O.[[GeneratorState]] is suspended-start, then
+            IteratorHelperState::SuspendedStart { iterated, .. } => {
+                // a. Set O.[[GeneratorState]] to completed.
+                Self::set_state(object, IteratorHelperState::Completed);
+
+                // b. NOTE: Once a generator enters the completed state it never leaves it and its
+                //    associated execution context is never resumed. Any execution state associated
+                //    with O can be discarded at this point.
+                // c. Perform ? IteratorClose(O.[[UnderlyingIterator]], NormalCompletion(unused)).
+                iterated.close(Ok(JsValue::undefined()), context)
+            }
+            // 5. Let C be Completion { [[Type]]: return, [[Value]]: undefined, [[Target]]: empty }.
+            // 6. Return ? GeneratorResumeAbrupt(O, C, "Iterator Helper").
+            IteratorHelperState::SuspendedYield {
+                iterated,
+                mut closure,
+            } => {
+                let result = closure.resume_return(&iterated, context);
+                Self::set_state(object, IteratorHelperState::Completed);
+                result.map(|()| JsValue::undefined())
+            }
+            IteratorHelperState::Completed => {
+                Self::set_state(object, IteratorHelperState::Completed);
+                Ok(JsValue::undefined())
+            }
+            IteratorHelperState::Executing => unreachable!("`take_state` checks this case"),
+        };
+
+        // d. Return CreateIterResultObject(undefined, true).
+        result.map(|_| create_iter_result_object(JsValue::undefined(), true, context))
+    }
+}
@@ -1,20 +1,34 @@
 //! Boa's implementation of ECMAScript's `IteratorRecord` and iterator prototype objects.
 
 use crate::{
-    builtins::{BuiltInBuilder, IntrinsicObject},
-    context::intrinsics::Intrinsics,
+    builtins::{Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
-    object::JsObject,
+    object::{
+        internal_methods::{get_prototype_from_constructor, InternalMethodContext},
+        JsObject, CONSTRUCTOR,
+    },
+    property::{Attribute, PropertyKey},
     realm::Realm,
+    string::common::StaticJsStrings,
     symbol::JsSymbol,
-    Context, JsResult, JsValue,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
 mod async_from_sync_iterator;
+mod iterator_helper;
+mod wrap_for_valid_iterator;
+
 pub(crate) use async_from_sync_iterator::AsyncFromSyncIterator;
+pub(crate) use iterator_helper::{IteratorHelper, IteratorHelperClosure};
+pub(crate) use wrap_for_valid_iterator::WrapForValidIterator;
+
+#[cfg(test)]
+mod tests;
 
 /// `IfAbruptCloseIterator ( value, iteratorRecord )`
 ///
@@ -44,9 +58,6 @@ use super::OrdinaryObject;
 /// The built-in iterator prototypes.
 #[derive(Debug, Default, Trace, Finalize)]
 pub struct IteratorPrototypes {
-    /// The `IteratorPrototype` object.
-    iterator: JsObject,
-
     /// The `AsyncIteratorPrototype` object.
     async_iterator: JsObject,
 
@@ -74,6 +85,12 @@ pub struct IteratorPrototypes {
     /// The `%SegmentIteratorPrototype%` prototype object.
     #[cfg(feature = "intl")]
     segment: JsObject,
+
+    /// The `%IteratorHelperPrototype%` prototype object.
+    iterator_helper: JsObject,
+
+    /// The `%WrapForValidIteratorPrototype%` prototype object.
+    wrap_for_valid_iterator: JsObject,
 }
 
 impl IteratorPrototypes {
@@ -84,13 +101,6 @@ impl IteratorPrototypes {
         self.array.clone()
     }
 
-    /// Returns the `IteratorPrototype` object.
-    #[inline]
-    #[must_use]
-    pub fn iterator(&self) -> JsObject {
-        self.iterator.clone()
-    }
-
     /// Returns the `AsyncIteratorPrototype` object.
     #[inline]
     #[must_use]
@@ -147,29 +157,905 @@ impl IteratorPrototypes {
     pub fn segment(&self) -> JsObject {
         self.segment.clone()
     }
+
+    /// Returns the `%IteratorHelperPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn iterator_helper(&self) -> JsObject {
+        self.iterator_helper.clone()
+    }
+
+    /// Returns the `%WrapForValidIteratorPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn wrap_for_valid_iterator(&self) -> JsObject {
+        self.wrap_for_valid_iterator.clone()
+    }
 }
 
-/// `%IteratorPrototype%` object
+/// The `Iterator` constructor and the `%Iterator.prototype%` object.
 ///
 /// More information:
-///  - [ECMA reference][spec]
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
 ///
-/// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-object
-
+/// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iterator-constructor
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator
 pub(crate) struct Iterator;
 
 impl IntrinsicObject for Iterator {
     fn init(realm: &Realm) {
-        let _timer = Profiler::global().start_event("Iterator Prototype", "init");
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
-        BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .static_method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
+        let get_constructor = BuiltInBuilder::callable(realm, Self::get_constructor)
+            .name(js_string!("get constructor"))
+            .build();
+        let set_constructor = BuiltInBuilder::callable(realm, Self::set_constructor)
+            .name(js_string!("set constructor"))
+            .length(1)
+            .build();
+        let get_to_string_tag = BuiltInBuilder::callable(realm, Self::get_to_string_tag)
+            .name(js_string!("get [Symbol.toStringTag]"))
+            .build();
+        let set_to_string_tag = BuiltInBuilder::callable(realm, Self::set_to_string_tag)
+            .name(js_string!("set [Symbol.toStringTag]"))
+            .length(1)
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, js_string!("from"), 1)
+            .method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
+            .method(Self::map, js_string!("map"), 1)
+            .method(Self::filter, js_string!("filter"), 1)
+            .method(Self::take, js_string!("take"), 1)
+            .method(Self::drop, js_string!("drop"), 1)
+            .method(Self::flat_map, js_string!("flatMap"), 1)
+            .method(Self::reduce, js_string!("reduce"), 1)
+            .method(Self::to_array, js_string!("toArray"), 0)
+            .method(Self::for_each, js_string!("forEach"), 1)
+            .method(Self::some, js_string!("some"), 1)
+            .method(Self::every, js_string!("every"), 1)
+            .method(Self::find, js_string!("find"), 1)
+            .accessor(
+                CONSTRUCTOR,
+                Some(get_constructor),
+                Some(set_constructor),
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                JsSymbol::to_string_tag(),
+                Some(get_to_string_tag),
+                Some(set_to_string_tag),
+                Attribute::CONFIGURABLE,
+            )
             .build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
-        intrinsics.objects().iterator_prototypes().iterator()
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Iterator {
+    const NAME: JsString = StaticJsStrings::ITERATOR;
+}
+
+impl BuiltInConstructor for Iterator {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::iterator;
+
+    /// `Iterator ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iterator
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/Iterator
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined or the active function object, throw a TypeError exception.
+        let is_active_function = new_target.as_object().is_some_and(|new_target| {
+            context
+                .active_function_object()
+                .is_some_and(|active| JsObject::equals(&active, new_target))
+        });
+        if new_target.is_undefined() || is_active_function {
+            return Err(JsNativeError::typ()
+                .with_message("Iterator is an abstract class and cannot be constructed directly")
+                .into());
+        }
+
+        // 2. Return ? OrdinaryCreateFromConstructor(NewTarget, "%Iterator.prototype%").
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::iterator, context)?;
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            OrdinaryObject,
+        )
+        .into())
+    }
+}
+
+impl Iterator {
+    /// Gets the `this` object of an `Iterator.prototype` method, or throws if `this` is not an
+    /// object.
+    fn this_object<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        this.as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "Iterator.prototype.{method}: `this` is not an object"
+                ))
+                .into()
+        })
+    }
+
+    /// Gets the callable argument of an `Iterator.prototype` method, or throws if it is not
+    /// callable.
+    fn callable_arg<'a>(arg: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        arg.as_callable().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "Iterator.prototype.{method}: argument is not callable"
+                ))
+                .into()
+        })
+    }
+
+    /// Validates the `limit` argument of `Iterator.prototype.take` and `Iterator.prototype.drop`,
+    /// returning `None` if the limit is `+∞`.
+    fn limit_arg(limit: &JsValue, method: &str, context: &mut Context) -> JsResult<Option<u64>> {
+        // 3. Let numLimit be ? ToNumber(limit).
+        let num_limit = limit.to_number(context)?;
+
+        // 4. If numLimit is NaN, throw a RangeError exception.
+        if num_limit.is_nan() {
+            return Err(JsNativeError::range()
+                .with_message(format!("Iterator.prototype.{method}: limit cannot be NaN"))
+                .into());
+        }
+
+        // 5. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        // 6. If integerLimit < 0, throw a RangeError exception.
+        match IntegerOrInfinity::from(num_limit) {
+            IntegerOrInfinity::PositiveInfinity => Ok(None),
+            IntegerOrInfinity::Integer(limit) if limit >= 0 => Ok(Some(limit as u64)),
+            _ => Err(JsNativeError::range()
+                .with_message(format!(
+                    "Iterator.prototype.{method}: limit cannot be negative"
+                ))
+                .into()),
+        }
+    }
+
+    /// `Iterator.from ( O )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iterator.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/from
+    pub(crate) fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let iteratorRecord be ? GetIteratorFlattenable(O, iterate-strings).
+        let iterator_record = get_iterator_flattenable(
+            args.get_or_undefined(0),
+            StringHandling::IterateStrings,
+            context,
+        )?;
+
+        // 2. Let hasInstance be ? OrdinaryHasInstance(%Iterator%, iteratorRecord.[[Iterator]]).
+        let iterator = iterator_record.iterator().clone();
+        let has_instance = JsValue::ordinary_has_instance(
+            &context
+                .intrinsics()
+                .constructors()
+                .iterator()
+                .constructor()
+                .into(),
+            &iterator.clone().into(),
+            context,
+        )?;
+
+        // 3. If hasInstance is true, then
+        if has_instance {
+            // a. Return iteratorRecord.[[Iterator]].
+            return Ok(iterator.into());
+        }
+
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        // 6. Return wrapper.
+        Ok(WrapForValidIterator::create(iterator_record, context).into())
+    }
+
+    /// `get Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-get-iteratorprototype-constructor
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_constructor(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return %Iterator%.
+        Ok(context
+            .intrinsics()
+            .constructors()
+            .iterator()
+            .constructor()
+            .into())
+    }
+
+    /// `set Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-set-iteratorprototype-constructor
+    fn set_constructor(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, "constructor", v).
+        let home = context.intrinsics().constructors().iterator().prototype();
+        setter_that_ignores_prototype_properties(
+            this,
+            &home,
+            CONSTRUCTOR.into(),
+            args.get_or_undefined(0).clone(),
+            context,
+        )?;
+
+        // 2. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `get Iterator.prototype [ @@toStringTag ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-get-iteratorprototype-@@tostringtag
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_to_string_tag(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return "Iterator".
+        Ok(Self::NAME.into())
+    }
+
+    /// `set Iterator.prototype [ @@toStringTag ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-set-iteratorprototype-@@tostringtag
+    fn set_to_string_tag(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, @@toStringTag, v).
+        let home = context.intrinsics().constructors().iterator().prototype();
+        setter_that_ignores_prototype_properties(
+            this,
+            &home,
+            JsSymbol::to_string_tag().into(),
+            args.get_or_undefined(0).clone(),
+            context,
+        )?;
+
+        // 2. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Iterator.prototype.map ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.map
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/map
+    pub(crate) fn map(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "map")?;
+
+        // 3. If IsCallable(mapper) is false, throw a TypeError exception.
+        let mapper = Self::callable_arg(args.get_or_undefined(0), "map")?.clone();
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper ...
+        // 6. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 7. Set result.[[UnderlyingIterator]] to iterated.
+        // 8. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            IteratorHelperClosure::Map { mapper, counter: 0 },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.filter ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.filter
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/filter
+    pub(crate) fn filter(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "filter")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = Self::callable_arg(args.get_or_undefined(0), "filter")?.clone();
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and predicate ...
+        // 6. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 7. Set result.[[UnderlyingIterator]] to iterated.
+        // 8. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            IteratorHelperClosure::Filter {
+                predicate,
+                counter: 0,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.take ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.take
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/take
+    pub(crate) fn take(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "take")?;
+
+        // 3-6.
+        let remaining = Self::limit_arg(args.get_or_undefined(0), "take", context)?;
+
+        // 7. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 8. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit ...
+        // 9. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 10. Set result.[[UnderlyingIterator]] to iterated.
+        // 11. Return result.
+        Ok(
+            IteratorHelper::create(iterated, IteratorHelperClosure::Take { remaining }, context)
+                .into(),
+        )
+    }
+
+    /// `Iterator.prototype.drop ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.drop
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/drop
+    pub(crate) fn drop(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "drop")?;
+
+        // 3-6.
+        let remaining = Self::limit_arg(args.get_or_undefined(0), "drop", context)?;
+
+        // 7. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 8. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit ...
+        // 9. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 10. Set result.[[UnderlyingIterator]] to iterated.
+        // 11. Return result.
+        Ok(
+            IteratorHelper::create(iterated, IteratorHelperClosure::Drop { remaining }, context)
+                .into(),
+        )
+    }
+
+    /// `Iterator.prototype.flatMap ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.flatmap
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/flatMap
+    pub(crate) fn flat_map(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "flatMap")?;
+
+        // 3. If IsCallable(mapper) is false, throw a TypeError exception.
+        let mapper = Self::callable_arg(args.get_or_undefined(0), "flatMap")?.clone();
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper ...
+        // 6. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 7. Set result.[[UnderlyingIterator]] to iterated.
+        // 8. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            IteratorHelperClosure::FlatMap {
+                mapper,
+                counter: 0,
+                inner: None,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.reduce ( reducer [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.reduce
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/reduce
+    pub(crate) fn reduce(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "reduce")?;
+
+        // 3. If IsCallable(reducer) is false, throw a TypeError exception.
+        let reducer = Self::callable_arg(args.get_or_undefined(0), "reduce")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        let (mut accumulator, mut counter) = if let Some(initial_value) = args.get(1) {
+            // 6. Else,
+            //     a. Let accumulator be initialValue.
+            //     b. Let counter be 0.
+            (initial_value.clone(), 0u64)
+        } else {
+            // 5. If initialValue is not present, then
+            //     a. Let accumulator be ? IteratorStepValue(iterated).
+            //     b. If accumulator is done, throw a TypeError exception.
+            if iterated.step(context)? {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+                    )
+                    .into());
+            }
+
+            //     c. Let counter be 1.
+            (iterated.value(context)?, 1)
+        };
+
+        // 7. Repeat,
+        loop {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return accumulator.
+            if iterated.step(context)? {
+                return Ok(accumulator);
+            }
+            let value = iterated.value(context)?;
+
+            // c. Let result be Completion(Call(reducer, undefined, « accumulator, value, 𝔽(counter) »)).
+            let result = reducer.call(
+                &JsValue::undefined(),
+                &[accumulator, value, counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            // e. Set accumulator to result.
+            accumulator = if_abrupt_close_iterator!(result, iterated, context);
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+    }
+
+    /// `Iterator.prototype.toArray ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.toarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/toArray
+    pub(crate) fn to_array(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "toArray")?;
+
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 4. Let items be a new empty List.
+        let mut items = Vec::new();
+
+        // 5. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return CreateArrayFromList(items).
+        //     c. Append value to items.
+        while !iterated.step(context)? {
+            items.push(iterated.value(context)?);
+        }
+
+        Ok(Array::create_array_from_list(items, context).into())
+    }
+
+    /// `Iterator.prototype.forEach ( fn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/forEach
+    pub(crate) fn for_each(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "forEach")?;
+
+        // 3. If IsCallable(fn) is false, throw a TypeError exception.
+        let func = Self::callable_arg(args.get_or_undefined(0), "forEach")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 6. Repeat,
+        loop {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return undefined.
+            if iterated.step(context)? {
+                return Ok(JsValue::undefined());
+            }
+            let value = iterated.value(context)?;
+
+            // c. Let result be Completion(Call(fn, undefined, « value, 𝔽(counter) »)).
+            let result = func.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. Set counter to counter + 1.
+            counter += 1;
+        }
+    }
+
+    /// `Iterator.prototype.some ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.some
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/some
+    pub(crate) fn some(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "some")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = Self::callable_arg(args.get_or_undefined(0), "some")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 6. Repeat,
+        loop {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return false.
+            if iterated.step(context)? {
+                return Ok(false.into());
+            }
+            let value = iterated.value(context)?;
+
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(true)).
+            if result.to_boolean() {
+                return iterated.close(Ok(true.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+    }
+
+    /// `Iterator.prototype.every ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.every
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/every
+    pub(crate) fn every(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "every")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = Self::callable_arg(args.get_or_undefined(0), "every")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 6. Repeat,
+        loop {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return true.
+            if iterated.step(context)? {
+                return Ok(true.into());
+            }
+            let value = iterated.value(context)?;
+
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is false, return ? IteratorClose(iterated, NormalCompletion(false)).
+            if !result.to_boolean() {
+                return iterated.close(Ok(false.into()), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+    }
+
+    /// `Iterator.prototype.find ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iteratorprototype.find
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/find
+    pub(crate) fn find(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        let o = Self::this_object(this, "find")?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = Self::callable_arg(args.get_or_undefined(0), "find")?;
+
+        // 4. Let iterated be ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(o, context)?;
+
+        // 5. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 6. Repeat,
+        loop {
+            // a. Let value be ? IteratorStepValue(iterated).
+            // b. If value is done, return undefined.
+            if iterated.step(context)? {
+                return Ok(JsValue::undefined());
+            }
+            let value = iterated.value(context)?;
+
+            // c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(
+                &JsValue::undefined(),
+                &[value.clone(), counter.into()],
+                context,
+            );
+
+            // d. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(value)).
+            if result.to_boolean() {
+                return iterated.close(Ok(value), context);
+            }
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+    }
+}
+
+/// `SetterThatIgnoresPrototypeProperties ( thisValue, home, p, v )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-SetterThatIgnoresPrototypeProperties
+fn setter_that_ignores_prototype_properties(
+    this: &JsValue,
+    home: &JsObject,
+    p: PropertyKey,
+    v: JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. If this is not an Object, then
+    let Some(this) = this.as_object() else {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("cannot set a property of a non-object value")
+            .into());
+    };
+
+    // 2. If SameValue(this, home) is true, then
+    if JsObject::equals(this, home) {
+        // a. NOTE: Throwing here emulates assignment to a non-writable data property on the home
+        //    object in strict mode code.
+        // b. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("cannot set a property of the home object")
+            .into());
+    }
+
+    // 3. Let desc be ? this.[[GetOwnProperty]](p).
+    let desc = this.__get_own_property__(&p, &mut InternalMethodContext::new(context))?;
+
+    // 4. If desc is undefined, then
+    if desc.is_none() {
+        // a. Perform ? CreateDataPropertyOrThrow(this, p, v).
+        this.create_data_property_or_throw(p, v, context)?;
+    } else {
+        // 5. Else,
+        //     a. Perform ? Set(this, p, v, true).
+        this.set(p, v, true, context)?;
+    }
+
+    // 6. Return unused.
+    Ok(())
+}
+
+/// The string handling mode of [`get_iterator_flattenable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StringHandling {
+    /// Strings are iterated by their code points.
+    IterateStrings,
+
+    /// Strings are rejected like any other primitive.
+    RejectStrings,
+}
+
+/// `GetIteratorDirect ( obj )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-getiteratordirect
+pub(crate) fn get_iterator_direct(
+    obj: &JsObject,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. Let nextMethod be ? Get(obj, "next").
+    let next_method = obj.get(js_string!("next"), context)?;
+
+    // 2. Let record be the Iterator Record { [[Iterator]]: obj, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 3. Return record.
+    Ok(IteratorRecord::new(obj.clone(), next_method))
+}
+
+/// `GetIteratorFlattenable ( obj, stringHandling )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-getiteratorflattenable
+pub(crate) fn get_iterator_flattenable(
+    obj: &JsValue,
+    string_handling: StringHandling,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. If obj is not an Object, then
+    if !obj.is_object() {
+        // a. If stringHandling is reject-strings or obj is not a String, throw a TypeError exception.
+        if string_handling == StringHandling::RejectStrings || !obj.is_string() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "value with type `{}` is not an iterable object",
+                    obj.type_of()
+                ))
+                .into());
+        }
     }
+
+    // 2. Let method be ? GetMethod(obj, @@iterator).
+    let iterator = match obj.get_method(JsSymbol::iterator(), context)? {
+        // 3. If method is undefined, then
+        //     a. Let iterator be obj.
+        None => obj.clone(),
+        // 4. Else,
+        //     a. Let iterator be ? Call(method, obj).
+        Some(method) => method.call(obj, &[], context)?,
+    };
+
+    // 5. If iterator is not an Object, throw a TypeError exception.
+    let iterator = iterator
+        .as_object()
+        .ok_or_else(|| JsNativeError::typ().with_message("returned iterator is not an object"))?;
+
+    // 6. Return ? GetIteratorDirect(iterator).
+    get_iterator_direct(iterator, context)
 }
 
 /// `%AsyncIteratorPrototype%` object
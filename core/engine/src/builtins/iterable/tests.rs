@@ -0,0 +1,191 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn iterator_constructor() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new Iterator()",
+            JsNativeErrorKind::Type,
+            "Iterator is an abstract class and cannot be constructed directly",
+        ),
+        TestAction::assert_native_error(
+            "Iterator()",
+            JsNativeErrorKind::Type,
+            "Iterator is an abstract class and cannot be constructed directly",
+        ),
+        TestAction::run("class MyIterator extends Iterator {}"),
+        TestAction::assert("new MyIterator() instanceof Iterator"),
+        TestAction::assert("Iterator.prototype.constructor === Iterator"),
+        TestAction::assert_eq(
+            "Iterator.prototype[Symbol.toStringTag]",
+            js_string!("Iterator"),
+        ),
+    ]);
+}
+
+#[test]
+fn prototype_setters() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const obj = Object.create(Iterator.prototype);
+            obj.constructor = 1;
+            obj[Symbol.toStringTag] = "Custom";
+        "#}),
+        TestAction::assert("Object.hasOwn(obj, 'constructor')"),
+        TestAction::assert_eq("obj.constructor", 1),
+        TestAction::assert_eq("String(obj)", js_string!("[object Custom]")),
+        TestAction::assert("Iterator.prototype.constructor === Iterator"),
+        TestAction::assert_native_error(
+            "'use strict'; Iterator.prototype.constructor = 1",
+            JsNativeErrorKind::Type,
+            "cannot set a property of the home object",
+        ),
+    ]);
+}
+
+#[test]
+fn lazy_helpers() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function* gen() {
+                yield 1;
+                yield 2;
+                yield 3;
+                yield 4;
+            }
+        "#}),
+        TestAction::assert_eq(
+            "gen().map((x, i) => x * 10 + i).toArray().join()",
+            js_string!("10,21,32,43"),
+        ),
+        TestAction::assert_eq(
+            "gen().filter(x => x % 2 === 0).toArray().join()",
+            js_string!("2,4"),
+        ),
+        TestAction::assert_eq("gen().take(2).toArray().join()", js_string!("1,2")),
+        TestAction::assert_eq("gen().drop(3).toArray().join()", js_string!("4")),
+        TestAction::assert_eq("gen().take(Infinity).drop(Infinity).toArray().length", 0),
+        TestAction::assert_eq(
+            "gen().flatMap(x => [x, -x]).toArray().join()",
+            js_string!("1,-1,2,-2,3,-3,4,-4"),
+        ),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(gen().map(x => x))",
+            js_string!("[object Iterator Helper]"),
+        ),
+        TestAction::assert_native_error(
+            "gen().take(NaN)",
+            JsNativeErrorKind::Range,
+            "Iterator.prototype.take: limit cannot be NaN",
+        ),
+        TestAction::assert_native_error(
+            "gen().drop(-1)",
+            JsNativeErrorKind::Range,
+            "Iterator.prototype.drop: limit cannot be negative",
+        ),
+        TestAction::assert_native_error(
+            "gen().flatMap(x => x).next()",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterable object",
+        ),
+        TestAction::assert_native_error(
+            "gen().map(1)",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype.map: argument is not callable",
+        ),
+    ]);
+}
+
+#[test]
+fn eager_helpers() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function* gen() {
+                yield 1;
+                yield 2;
+                yield 3;
+            }
+        "#}),
+        TestAction::assert_eq("gen().reduce((acc, x) => acc + x)", 6),
+        TestAction::assert_eq("gen().reduce((acc, x) => acc + x, 10)", 16),
+        TestAction::assert("gen().some(x => x === 2)"),
+        TestAction::assert("!gen().every(x => x < 3)"),
+        TestAction::assert_eq("gen().find(x => x > 1)", 2),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let sum = 0;
+                gen().forEach(x => { sum += x; });
+                sum
+            "#},
+            6,
+        ),
+        TestAction::assert_native_error(
+            "gen().drop(3).reduce((acc, x) => acc + x)",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+        ),
+    ]);
+}
+
+#[test]
+fn helpers_close_iterators() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let closed = 0;
+            function source() {
+                return {
+                    __proto__: Iterator.prototype,
+                    next() { return { value: 1, done: false }; },
+                    return() { closed++; return {}; },
+                };
+            }
+        "#}),
+        TestAction::assert("source().some(x => x === 1)"),
+        TestAction::assert_eq("closed", 1),
+        TestAction::run(indoc! {r#"
+            const helper = source().map(x => x);
+            helper.next();
+            helper.return();
+        "#}),
+        TestAction::assert_eq("closed", 2),
+        TestAction::assert("helper.next().done"),
+        TestAction::assert_eq("source().take(1).toArray().length", 1),
+        TestAction::assert_eq("closed", 3),
+        TestAction::assert_eq(
+            indoc! {r#"
+                try {
+                    source().forEach(() => { throw 1; });
+                } catch {}
+                closed
+            "#},
+            4,
+        ),
+    ]);
+}
+
+#[test]
+fn iterator_from() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let i = 0;
+            const wrapped = Iterator.from({
+                next() { return { value: i++, done: i > 3 }; },
+            });
+        "#}),
+        TestAction::assert("wrapped instanceof Iterator"),
+        TestAction::assert_eq(
+            "wrapped.map(x => x * 2).toArray().join()",
+            js_string!("0,2,4"),
+        ),
+        TestAction::assert("wrapped.return().done"),
+        TestAction::assert_eq("Iterator.from('abc').toArray().join()", js_string!("a,b,c")),
+        TestAction::run("function* gen() {}"),
+        TestAction::assert("(() => { const g = gen(); return Iterator.from(g) === g; })()"),
+        TestAction::assert_native_error(
+            "Iterator.from(1)",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterable object",
+        ),
+    ]);
+}
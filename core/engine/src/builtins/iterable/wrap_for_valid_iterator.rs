@@ -0,0 +1,130 @@
+//! This module implements the `%WrapForValidIteratorPrototype%` object, used by `Iterator.from`
+//! to wrap iterators that don't inherit from `%Iterator.prototype%`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-iterator-helpers/#sec-wrapforvaliditeratorprototype-object
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{iterable::create_iter_result_object, BuiltInBuilder, IntrinsicObject},
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    js_string,
+    object::JsObject,
+    realm::Realm,
+    Context, JsData, JsResult, JsValue,
+};
+
+use super::IteratorRecord;
+
+/// An iterator object created by `Iterator.from`, which forwards all calls to the wrapped
+/// iterator.
+#[derive(Debug, Finalize, Trace, JsData)]
+pub(crate) struct WrapForValidIterator {
+    iterated: IteratorRecord,
+}
+
+impl IntrinsicObject for WrapForValidIterator {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event("%WrapForValidIteratorPrototype%", "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(realm.intrinsics().constructors().iterator().prototype())
+            .static_method(Self::next, js_string!("next"), 0)
+            .static_method(Self::r#return, js_string!("return"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics
+            .objects()
+            .iterator_prototypes()
+            .wrap_for_valid_iterator()
+    }
+}
+
+impl WrapForValidIterator {
+    /// Creates a new wrapper for the `iterated` iterator record.
+    pub(crate) fn create(iterated: IteratorRecord, context: &mut Context) -> JsObject {
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .wrap_for_valid_iterator(),
+            Self { iterated },
+        )
+    }
+
+    /// Gets the `[[Iterated]]` iterator record of `this`, or throws if `this` is not a wrapper
+    /// object.
+    fn iterated(this: &JsValue, method: &str) -> JsResult<IteratorRecord> {
+        this.as_object()
+            .and_then(|obj| obj.downcast_ref::<Self>().map(|w| w.iterated.clone()))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "%WrapForValidIteratorPrototype%.{method}: `this` is not a wrapped iterator"
+                    ))
+                    .into()
+            })
+    }
+
+    /// `%WrapForValidIteratorPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-wrapforvaliditeratorprototype.next
+    pub(crate) fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        // 3. Let iteratorRecord be O.[[Iterated]].
+        let iterated = Self::iterated(this, "next")?;
+
+        // 4. Return ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        iterated
+            .next_method()
+            .call(&iterated.iterator().clone().into(), &[], context)
+    }
+
+    /// `%WrapForValidIteratorPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-wrapforvaliditeratorprototype.return
+    pub(crate) fn r#return(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        // 3. Let iterator be O.[[Iterated]].[[Iterator]].
+        // 4. Assert: iterator is an Object.
+        let iterated = Self::iterated(this, "return")?;
+        let iterator = iterated.iterator();
+
+        // 5. Let returnMethod be ? GetMethod(iterator, "return").
+        let Some(return_method) = iterator.get_method(js_string!("return"), context)? else {
+            // 6. If returnMethod is undefined, then
+            //     a. Return CreateIterResultObject(undefined, true).
+            return Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            ));
+        };
+
+        // 7. Return ? Call(returnMethod, iterator).
+        return_method.call(&iterator.clone().into(), &[], context)
+    }
+}
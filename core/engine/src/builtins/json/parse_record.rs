@@ -0,0 +1,229 @@
+//! This module implements the JSON Parse Records used by `JSON.parse` to give revivers access
+//! to the source text of the parsed values.
+//!
+//! More information:
+//!  - [Proposal reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record
+
+use crate::{Context, JsResult, JsString, JsValue};
+
+/// A `JSON Parse Record`, associating a value created by `JSON.parse` with the node of the JSON
+/// text that produced it.
+#[derive(Debug)]
+pub(super) struct JsonParseRecord {
+    /// The value produced by the parse node.
+    value: JsValue,
+
+    /// The source text of the parse node, if the node is a primitive value.
+    source: Option<JsString>,
+
+    /// The records of the elements, if the parse node is an array.
+    elements: Vec<JsonParseRecord>,
+
+    /// The records of the entries, if the parse node is an object.
+    ///
+    /// Duplicate keys are kept in source order, so the record of a property is the last entry
+    /// with its key.
+    entries: Vec<(JsString, JsonParseRecord)>,
+}
+
+impl JsonParseRecord {
+    /// `CreateJSONParseRecord ( parseNode, key, val )`
+    ///
+    /// Creates the parse record of `value`, which must be the result of parsing `text`.
+    ///
+    /// `text` must be a valid JSON text.
+    pub(super) fn new(text: &str, value: JsValue, context: &mut Context) -> JsResult<Self> {
+        RecordBuilder {
+            text: text.as_bytes(),
+            position: 0,
+        }
+        .build(value, context)
+    }
+
+    /// Returns the value produced by the parse node.
+    pub(super) const fn value(&self) -> &JsValue {
+        &self.value
+    }
+
+    /// Returns the source text of the parse node if it is a primitive value.
+    pub(super) const fn source(&self) -> Option<&JsString> {
+        self.source.as_ref()
+    }
+
+    /// Returns the record of the element at `index`, if any.
+    pub(super) fn element(&self, index: usize) -> Option<&Self> {
+        self.elements.get(index)
+    }
+
+    /// Returns the record of the entry with `key`, if any.
+    pub(super) fn entry(&self, key: &JsString) -> Option<&Self> {
+        self.entries
+            .iter()
+            .rev()
+            .find_map(|(k, record)| (k == key).then_some(record))
+    }
+}
+
+/// Walks a valid JSON text, pairing each parse node with the value it produced.
+struct RecordBuilder<'a> {
+    text: &'a [u8],
+    position: usize,
+}
+
+impl RecordBuilder<'_> {
+    /// Builds the record of the value starting at the current position.
+    fn build(&mut self, value: JsValue, context: &mut Context) -> JsResult<JsonParseRecord> {
+        self.skip_whitespace();
+
+        let mut record = JsonParseRecord {
+            value,
+            source: None,
+            elements: Vec::new(),
+            entries: Vec::new(),
+        };
+
+        // Values nested in an object or array which was later replaced by a duplicate key don't
+        // have a corresponding value anymore, but still need to be walked.
+        let object = record.value.as_object().cloned();
+
+        match self.peek() {
+            Some(b'[') => {
+                self.position += 1;
+                self.skip_whitespace();
+                if self.peek() == Some(b']') {
+                    self.position += 1;
+                    return Ok(record);
+                }
+                loop {
+                    let index = record.elements.len();
+                    let element = match &object {
+                        Some(object) => object.get(index, context)?,
+                        None => JsValue::undefined(),
+                    };
+                    record.elements.push(self.build(element, context)?);
+
+                    self.skip_whitespace();
+                    let next = self.peek();
+                    self.position += 1;
+                    if next != Some(b',') {
+                        break;
+                    }
+                }
+            }
+            Some(b'{') => {
+                self.position += 1;
+                self.skip_whitespace();
+                if self.peek() == Some(b'}') {
+                    self.position += 1;
+                    return Ok(record);
+                }
+                loop {
+                    self.skip_whitespace();
+                    let start = self.position;
+                    self.skip_string();
+                    let key = decode_string(&self.text[start + 1..self.position - 1]);
+
+                    self.skip_whitespace();
+                    // Skip the colon.
+                    self.position += 1;
+
+                    let entry = match &object {
+                        Some(object) => object.get(key.clone(), context)?,
+                        None => JsValue::undefined(),
+                    };
+                    let entry = self.build(entry, context)?;
+                    record.entries.push((key, entry));
+
+                    self.skip_whitespace();
+                    let next = self.peek();
+                    self.position += 1;
+                    if next != Some(b',') {
+                        break;
+                    }
+                }
+            }
+            Some(b'"') => {
+                let start = self.position;
+                self.skip_string();
+                record.source = Some(self.source_text(start));
+            }
+            _ => {
+                let start = self.position;
+                while let Some(byte) = self.peek() {
+                    if matches!(byte, b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r') {
+                        break;
+                    }
+                    self.position += 1;
+                }
+                record.source = Some(self.source_text(start));
+            }
+        }
+
+        Ok(record)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    /// Skips the string starting at the current position, including its quotes.
+    fn skip_string(&mut self) {
+        self.position += 1;
+        while let Some(byte) = self.peek() {
+            self.position += 1;
+            match byte {
+                b'\\' => self.position += 1,
+                b'"' => break,
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the source text from `start` to the current position.
+    fn source_text(&self, start: usize) -> JsString {
+        let source = std::str::from_utf8(&self.text[start..self.position])
+            .expect("parse nodes must be delimited by ASCII characters");
+        JsString::from(source)
+    }
+}
+
+/// Decodes the contents of a valid JSON string literal, without its quotes.
+fn decode_string(contents: &[u8]) -> JsString {
+    let contents =
+        std::str::from_utf8(contents).expect("strings must be delimited by ASCII characters");
+
+    let mut result = Vec::with_capacity(contents.len());
+    let mut buf = [0; 2];
+    let mut chars = contents.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.extend_from_slice(c.encode_utf16(&mut buf));
+            continue;
+        }
+
+        let escaped = chars.next().expect("escape sequences must be complete");
+        let unit = match escaped {
+            'b' => 0x0008,
+            't' => 0x0009,
+            'n' => 0x000A,
+            'f' => 0x000C,
+            'r' => 0x000D,
+            'u' => {
+                let digits: String = chars.by_ref().take(4).collect();
+                u16::from_str_radix(&digits, 16).expect("unicode escapes must have 4 hex digits")
+            }
+            c => c as u16,
+        };
+        result.push(unit);
+    }
+
+    JsString::from(&result[..])
+}
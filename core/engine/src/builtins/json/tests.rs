@@ -317,3 +317,62 @@ fn json_parse_with_no_args_throws_syntax_error() {
         "expected value at line 1 column 1",
     )]);
 }
+
+#[test]
+fn json_parse_reviver_source() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const sources = {};
+                const parsed = JSON.parse(
+                    '{"big": 12345678901234567890, "arr": [1.0, "\\u0041"], "big": -1e3, "obj": {}}',
+                    (key, value, context) => {
+                        sources[key] = context.source;
+                        return key === "big" ? BigInt(context.source.replace("e3", "000")) : value;
+                    },
+                );
+            "#}),
+        TestAction::assert_eq("sources.big", js_string!("-1e3")),
+        TestAction::assert_eq("sources[0]", js_string!("1.0")),
+        TestAction::assert_eq("sources[1]", js_string!(r#""\u0041""#)),
+        TestAction::assert("sources.arr === undefined"),
+        TestAction::assert("sources.obj === undefined"),
+        TestAction::assert("parsed.big === -1000n"),
+        TestAction::assert(indoc! {r#"
+                JSON.parse("[1, 2]", function (key, value, context) {
+                    if (key === "0") {
+                        this[1] = 3;
+                    }
+                    return key === "1" ? context.source : value;
+                })[1] === undefined
+            "#}),
+    ]);
+}
+
+#[test]
+fn json_raw_json() {
+    run_test_actions([
+        TestAction::run(r#"const raw = JSON.rawJSON("12345678901234567890");"#),
+        TestAction::assert("JSON.isRawJSON(raw)"),
+        TestAction::assert("!JSON.isRawJSON({ rawJSON: '1' })"),
+        TestAction::assert("Object.isFrozen(raw)"),
+        TestAction::assert("Object.getPrototypeOf(raw) === null"),
+        TestAction::assert_eq(
+            "JSON.stringify({ id: raw, list: [JSON.rawJSON('\"a\"')] })",
+            js_string!(r#"{"id":12345678901234567890,"list":["a"]}"#),
+        ),
+        TestAction::assert_eq(
+            "JSON.stringify([1n], (key, value) => typeof value === 'bigint' ? JSON.rawJSON(String(value)) : value)",
+            js_string!("[1]"),
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON(' 1')",
+            JsNativeErrorKind::Syntax,
+            "JSON.rawJSON: invalid leading or trailing whitespace",
+        ),
+        TestAction::assert_native_error(
+            "JSON.rawJSON('{}')",
+            JsNativeErrorKind::Syntax,
+            "JSON.rawJSON: the JSON text cannot be an object or an array",
+        ),
+    ]);
+}
@@ -10,6 +10,7 @@ pub mod bigint;
 pub mod boolean;
 pub mod dataview;
 pub mod date;
+pub mod disposable_stack;
 pub mod error;
 pub mod eval;
 pub mod function;
@@ -51,6 +52,9 @@ pub(crate) mod options;
 #[cfg(feature = "temporal")]
 pub mod temporal;
 
+#[cfg(feature = "experimental")]
+pub mod shadow_realm;
+
 pub(crate) use self::{
     array::Array,
     async_function::AsyncFunction,
@@ -59,8 +63,8 @@ pub(crate) use self::{
     dataview::DataView,
     date::Date,
     error::{
-        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
-        UriError,
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SuppressedError, SyntaxError,
+        TypeError, UriError,
     },
     eval::Eval,
     function::BuiltInFunctionObject,
@@ -89,10 +93,13 @@ use crate::{
         async_generator::AsyncGenerator,
         async_generator_function::AsyncGeneratorFunction,
         atomics::Atomics,
+        disposable_stack::{AsyncDisposableStack, DisposableStack},
         error::r#type::ThrowTypeError,
         generator::Generator,
         generator_function::GeneratorFunction,
-        iterable::{AsyncFromSyncIterator, AsyncIterator, Iterator},
+        iterable::{
+            AsyncFromSyncIterator, AsyncIterator, Iterator, IteratorHelper, WrapForValidIterator,
+        },
         map::MapIterator,
         object::for_in_iterator::ForInIterator,
         regexp::RegExpStringIterator,
@@ -100,7 +107,7 @@ use crate::{
         string::StringIterator,
         typed_array::BuiltinTypedArray,
         uri::{DecodeUri, DecodeUriComponent, EncodeUri, EncodeUriComponent},
-        weak::WeakRef,
+        weak::{FinalizationRegistry, WeakRef},
         weak_map::WeakMap,
         weak_set::WeakSet,
     },
@@ -196,6 +203,8 @@ impl Realm {
         BuiltInFunctionObject::init(self);
         OrdinaryObject::init(self);
         Iterator::init(self);
+        IteratorHelper::init(self);
+        WrapForValidIterator::init(self);
         AsyncIterator::init(self);
         AsyncFromSyncIterator::init(self);
         ForInIterator::init(self);
@@ -246,6 +255,7 @@ impl Realm {
         EvalError::init(self);
         UriError::init(self);
         AggregateError::init(self);
+        SuppressedError::init(self);
         Reflect::init(self);
         Generator::init(self);
         GeneratorFunction::init(self);
@@ -258,9 +268,12 @@ impl Realm {
         DecodeUri::init(self);
         DecodeUriComponent::init(self);
         WeakRef::init(self);
+        FinalizationRegistry::init(self);
         WeakMap::init(self);
         WeakSet::init(self);
         Atomics::init(self);
+        DisposableStack::init(self);
+        AsyncDisposableStack::init(self);
 
         #[cfg(feature = "annex-b")]
         {
@@ -272,6 +285,8 @@ impl Realm {
         {
             intl::Intl::init(self);
             intl::Collator::init(self);
+            intl::DisplayNames::init(self);
+            intl::DurationFormat::init(self);
             intl::ListFormat::init(self);
             intl::Locale::init(self);
             intl::DateTimeFormat::init(self);
@@ -280,6 +295,7 @@ impl Realm {
             intl::segmenter::SegmentIterator::init(self);
             intl::PluralRules::init(self);
             intl::NumberFormat::init(self);
+            intl::RelativeTimeFormat::init(self);
         }
 
         #[cfg(feature = "temporal")]
@@ -297,6 +313,9 @@ impl Realm {
             temporal::ZonedDateTime::init(self);
             temporal::Calendar::init(self);
         }
+
+        #[cfg(feature = "experimental")]
+        shadow_realm::ShadowRealm::init(self);
     }
 }
 
@@ -340,6 +359,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<Math>(context)?;
     global_binding::<Json>(context)?;
     global_binding::<Array>(context)?;
+    global_binding::<Iterator>(context)?;
     global_binding::<Proxy>(context)?;
     global_binding::<ArrayBuffer>(context)?;
     global_binding::<SharedArrayBuffer>(context)?;
@@ -378,6 +398,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<EvalError>(context)?;
     global_binding::<UriError>(context)?;
     global_binding::<AggregateError>(context)?;
+    global_binding::<SuppressedError>(context)?;
     global_binding::<Reflect>(context)?;
     global_binding::<Promise>(context)?;
     global_binding::<EncodeUri>(context)?;
@@ -385,9 +406,12 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<DecodeUri>(context)?;
     global_binding::<DecodeUriComponent>(context)?;
     global_binding::<WeakRef>(context)?;
+    global_binding::<FinalizationRegistry>(context)?;
     global_binding::<WeakMap>(context)?;
     global_binding::<WeakSet>(context)?;
     global_binding::<Atomics>(context)?;
+    global_binding::<DisposableStack>(context)?;
+    global_binding::<AsyncDisposableStack>(context)?;
 
     #[cfg(feature = "annex-b")]
     {
@@ -403,5 +427,8 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
         global_binding::<temporal::Temporal>(context)?;
     }
 
+    #[cfg(feature = "experimental")]
+    global_binding::<shadow_realm::ShadowRealm>(context)?;
+
     Ok(())
 }
@@ -169,13 +169,17 @@ fn issue_2609() {
 
 #[test]
 fn to_locale_string() {
-    // TODO: We don't actually do any locale checking here
-    // To honor the spec we should print numbers according to user locale.
+    let grouped = if cfg!(feature = "intl") {
+        "345,600"
+    } else {
+        "345600"
+    };
+
     run_test_actions([
-        TestAction::assert_eq("Number().toLocaleString()", js_string!("0")),
-        TestAction::assert_eq("Number(5).toLocaleString()", js_string!("5")),
-        TestAction::assert_eq("Number('345600').toLocaleString()", js_string!("345600")),
-        TestAction::assert_eq("Number(-25).toLocaleString()", js_string!("-25")),
+        TestAction::assert_eq("Number().toLocaleString('en')", js_string!("0")),
+        TestAction::assert_eq("Number(5).toLocaleString('en')", js_string!("5")),
+        TestAction::assert_eq("Number('345600').toLocaleString('en')", js_string!(grouped)),
+        TestAction::assert_eq("Number(-25).toLocaleString('en')", js_string!("-25")),
     ]);
 }
 
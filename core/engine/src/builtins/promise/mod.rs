@@ -2080,6 +2080,11 @@ impl Promise {
             // 9. Return unused.
         }
 
+        // The captures of the functions aren't objects, so they can't be allocated in the heap of a
+        // prototype. This makes sure they end up in the heap of the context when called from the
+        // Rust API (e.g. `JsPromise::new_pending`).
+        let _heap = context.heap().enter();
+
         // 1. Let alreadyResolved be the Record { [[Value]]: false }.
         // 5. Set resolve.[[Promise]] to promise.
         // 6. Set resolve.[[AlreadyResolved]] to alreadyResolved.
@@ -0,0 +1,316 @@
+//! Boa's implementation of the legacy static properties of the `RegExp` constructor.
+//!
+//! These are the `RegExp.$1-$9`, `RegExp.input`, `RegExp.lastMatch`, `RegExp.lastParen`,
+//! `RegExp.leftContext` and `RegExp.rightContext` accessors (and their `$_`, `$&`, `$+`,
+//! `` $` `` and `$'` aliases), which expose information about the last successful match
+//! done by a `RegExp` of the realm.
+//!
+//! More information:
+//!  - [Proposal][proposal]
+//!
+//! [proposal]: https://github.com/tc39/proposal-regexp-legacy-features
+
+use std::ops::Range;
+
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    builtins::{builder::BuiltInConstructorWithPrototype, BuiltInBuilder},
+    error::JsNativeError,
+    js_string,
+    native_function::NativeFunctionPointer,
+    object::JsObject,
+    property::Attribute,
+    realm::Realm,
+    Context, JsArgs, JsResult, JsString, JsValue,
+};
+
+/// The legacy static state of the `%RegExp%` constructor of a realm.
+///
+/// `None` represents an `empty` slot, which happens after the state has been invalidated.
+#[derive(Debug, Trace, Finalize)]
+// Safety: `LegacyRegExpStatics` only contains strings and indices, so this is safe.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct LegacyRegExpStatics {
+    /// The `[[RegExpInput]]` slot.
+    input: Option<JsString>,
+
+    /// The last successful match, from which the rest of the slots are computed.
+    last_match: Option<LegacyMatch>,
+}
+
+impl Default for LegacyRegExpStatics {
+    fn default() -> Self {
+        // The initial value of all the slots is the empty String.
+        Self {
+            input: Some(js_string!()),
+            last_match: Some(LegacyMatch {
+                input: js_string!(),
+                range: 0..0,
+                captures: Box::default(),
+            }),
+        }
+    }
+}
+
+impl LegacyRegExpStatics {
+    /// Abstract operation `UpdateLegacyRegExpStaticProperties ( C, S, startIndex, endIndex, capturedValues )`
+    ///
+    /// More information:
+    ///  - [Proposal][spec]
+    ///
+    /// [spec]: https://github.com/tc39/proposal-regexp-legacy-features#updatelegacyregexpstaticproperties--c-s-startindex-endindex-capturedvalues-
+    pub(crate) fn update(&mut self, input: &JsString, m: &regress::Match) {
+        // 4. Set the value of C’s [[RegExpInput]] internal slot to S.
+        self.input = Some(input.clone());
+
+        // 5-10. The rest of the slots are computed lazily from the match.
+        self.last_match = Some(LegacyMatch {
+            input: input.clone(),
+            range: m.range(),
+            captures: m.captures.clone().into_boxed_slice(),
+        });
+    }
+
+    /// Abstract operation `InvalidateLegacyRegExpStaticProperties ( C )`
+    ///
+    /// More information:
+    ///  - [Proposal][spec]
+    ///
+    /// [spec]: https://github.com/tc39/proposal-regexp-legacy-features#invalidatelegacyregexpstaticproperties--c
+    pub(crate) fn invalidate(&mut self) {
+        // 2. Set the value of the following internal slots of C to empty:
+        self.input = None;
+        self.last_match = None;
+    }
+}
+
+/// The information of a successful match needed to compute the legacy static properties.
+#[derive(Debug)]
+struct LegacyMatch {
+    input: JsString,
+    range: Range<usize>,
+    captures: Box<[Option<Range<usize>>]>,
+}
+
+impl LegacyMatch {
+    fn substring(&self, range: Option<Range<usize>>) -> JsString {
+        range.map_or_else(|| js_string!(), |range| js_string!(&self.input[range]))
+    }
+
+    /// `[[RegExpLastMatch]]`
+    fn last_match(&self) -> JsString {
+        self.substring(Some(self.range.clone()))
+    }
+
+    /// `[[RegExpLastParen]]`
+    fn last_paren(&self) -> JsString {
+        // 6. If n > 0, set the value of C’s [[RegExpLastParen]] internal slot to the last element of capturedValues.
+        // 7. Else, set the value of C’s [[RegExpLastParen]] internal slot to the empty String.
+        self.substring(self.captures.last().cloned().flatten())
+    }
+
+    /// `[[RegExpLeftContext]]`
+    fn left_context(&self) -> JsString {
+        self.substring(Some(0..self.range.start))
+    }
+
+    /// `[[RegExpRightContext]]`
+    fn right_context(&self) -> JsString {
+        self.substring(Some(self.range.end..self.input.len()))
+    }
+
+    /// `[[RegExpParen1]]` to `[[RegExpParen9]]`
+    fn paren<const N: usize>(&self) -> JsString {
+        // 10. For each integer i such that 1 ≤ i ≤ 9
+        //     a. If i ≤ n, set the value of C’s [[RegExpPareni]] internal slot to the ith element of capturedValues.
+        //     b. Else, set the value of C’s [[RegExpPareni]] internal slot to the empty String.
+        self.substring(self.captures.get(N - 1).cloned().flatten())
+    }
+}
+
+/// Checks that `this` is the `%RegExp%` constructor of the current realm.
+fn this_is_regexp_constructor(this: &JsValue, name: &str, context: &Context) -> JsResult<()> {
+    let constructor = context.intrinsics().constructors().regexp().constructor();
+    if this
+        .as_object()
+        .is_some_and(|this| JsObject::equals(this, &constructor))
+    {
+        return Ok(());
+    }
+
+    Err(JsNativeError::typ()
+        .with_message(format!(
+            "RegExp.{name} accessor called on an object that is not the RegExp constructor of the realm"
+        ))
+        .into())
+}
+
+/// Abstract operation `GetLegacyRegExpStaticProperty ( C, thisValue, internalSlotName )`
+///
+/// More information:
+///  - [Proposal][spec]
+///
+/// [spec]: https://github.com/tc39/proposal-regexp-legacy-features#getlegacyregexpstaticproperty-c-thisvalue-internalslotname-
+fn get_legacy_static(
+    this: &JsValue,
+    name: &str,
+    slot: fn(&LegacyRegExpStatics) -> Option<JsString>,
+    context: &Context,
+) -> JsResult<JsValue> {
+    // 1. Assert C is an object that has an internal slot named internalSlotName.
+    // 2. If SameValue(C, thisValue) is false, throw a TypeError exception.
+    this_is_regexp_constructor(this, name, context)?;
+
+    // 3. Let value be the value of the internal slot of C named internalSlotName.
+    let value = slot(&context.realm().legacy_regexp_statics().borrow());
+
+    // 4. If value is empty, throw a TypeError exception.
+    // 5. Return value.
+    value.map(JsValue::from).ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message(format!(
+                "RegExp.{name} is not available after a match of a RegExp subclass"
+            ))
+            .into()
+    })
+}
+
+macro_rules! legacy_match_getter {
+    ($fn_name:ident, $name:literal, $slot:expr) => {
+        fn $fn_name(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+            get_legacy_static(
+                this,
+                $name,
+                |statics| statics.last_match.as_ref().map($slot),
+                context,
+            )
+        }
+    };
+}
+
+/// `get RegExp.input`
+fn get_input(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    // 1. Return ? GetLegacyRegExpStaticProperty(%RegExp%, this value, [[RegExpInput]]).
+    get_legacy_static(this, "input", |statics| statics.input.clone(), context)
+}
+
+/// `set RegExp.input`
+///
+/// Has the steps of `SetLegacyRegExpStaticProperty ( C, thisValue, internalSlotName, val )`.
+fn set_input(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    // 1. Assert C is an object that has an internal slot named internalSlotName.
+    // 2. If SameValue(C, thisValue) is false, throw a TypeError exception.
+    this_is_regexp_constructor(this, "input", context)?;
+    let realm = context.realm().clone();
+
+    // 3. Let strVal be ? ToString(val).
+    let value = args.get_or_undefined(0).to_string(context)?;
+
+    // 4. Set the value of the internal slot of C named internalSlotName to strVal.
+    realm.legacy_regexp_statics().borrow_mut().input = Some(value);
+
+    Ok(JsValue::undefined())
+}
+
+legacy_match_getter!(get_last_match, "lastMatch", LegacyMatch::last_match);
+legacy_match_getter!(get_last_paren, "lastParen", LegacyMatch::last_paren);
+legacy_match_getter!(get_left_context, "leftContext", LegacyMatch::left_context);
+legacy_match_getter!(
+    get_right_context,
+    "rightContext",
+    LegacyMatch::right_context
+);
+legacy_match_getter!(get_paren_1, "$1", LegacyMatch::paren::<1>);
+legacy_match_getter!(get_paren_2, "$2", LegacyMatch::paren::<2>);
+legacy_match_getter!(get_paren_3, "$3", LegacyMatch::paren::<3>);
+legacy_match_getter!(get_paren_4, "$4", LegacyMatch::paren::<4>);
+legacy_match_getter!(get_paren_5, "$5", LegacyMatch::paren::<5>);
+legacy_match_getter!(get_paren_6, "$6", LegacyMatch::paren::<6>);
+legacy_match_getter!(get_paren_7, "$7", LegacyMatch::paren::<7>);
+legacy_match_getter!(get_paren_8, "$8", LegacyMatch::paren::<8>);
+legacy_match_getter!(get_paren_9, "$9", LegacyMatch::paren::<9>);
+
+/// Defines the legacy static accessors on the `%RegExp%` constructor.
+pub(super) fn define_static_accessors<'ctx>(
+    realm: &'ctx Realm,
+    mut regexp: BuiltInConstructorWithPrototype<'ctx>,
+) -> BuiltInConstructorWithPrototype<'ctx> {
+    let attribute = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
+
+    let get_input = BuiltInBuilder::callable(realm, get_input)
+        .name(js_string!("get input"))
+        .build();
+    let set_input = BuiltInBuilder::callable(realm, set_input)
+        .name(js_string!("set input"))
+        .length(1)
+        .build();
+    regexp = regexp
+        .static_accessor(
+            js_string!("input"),
+            Some(get_input.clone()),
+            Some(set_input.clone()),
+            attribute,
+        )
+        .static_accessor(
+            js_string!("$_"),
+            Some(get_input),
+            Some(set_input),
+            attribute,
+        );
+
+    let getters: [(_, _, _, NativeFunctionPointer); 4] = [
+        (
+            js_string!("lastMatch"),
+            js_string!("$&"),
+            js_string!("get lastMatch"),
+            get_last_match,
+        ),
+        (
+            js_string!("lastParen"),
+            js_string!("$+"),
+            js_string!("get lastParen"),
+            get_last_paren,
+        ),
+        (
+            js_string!("leftContext"),
+            js_string!("$`"),
+            js_string!("get leftContext"),
+            get_left_context,
+        ),
+        (
+            js_string!("rightContext"),
+            js_string!("$'"),
+            js_string!("get rightContext"),
+            get_right_context,
+        ),
+    ];
+    for (name, alias, function_name, getter) in getters {
+        let getter = BuiltInBuilder::callable(realm, getter)
+            .name(function_name)
+            .build();
+        regexp = regexp
+            .static_accessor(name, Some(getter.clone()), None, attribute)
+            .static_accessor(alias, Some(getter), None, attribute);
+    }
+
+    let parens: [(_, _, NativeFunctionPointer); 9] = [
+        (js_string!("$1"), js_string!("get $1"), get_paren_1),
+        (js_string!("$2"), js_string!("get $2"), get_paren_2),
+        (js_string!("$3"), js_string!("get $3"), get_paren_3),
+        (js_string!("$4"), js_string!("get $4"), get_paren_4),
+        (js_string!("$5"), js_string!("get $5"), get_paren_5),
+        (js_string!("$6"), js_string!("get $6"), get_paren_6),
+        (js_string!("$7"), js_string!("get $7"), get_paren_7),
+        (js_string!("$8"), js_string!("get $8"), get_paren_8),
+        (js_string!("$9"), js_string!("get $9"), get_paren_9),
+    ];
+    for (name, function_name, getter) in parens {
+        let getter = BuiltInBuilder::callable(realm, getter)
+            .name(function_name)
+            .build();
+        regexp = regexp.static_accessor(name, Some(getter), None, attribute);
+    }
+
+    regexp
+}
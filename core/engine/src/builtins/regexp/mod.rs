@@ -23,28 +23,42 @@ use crate::{
     Context, JsArgs, JsData, JsResult, JsString,
 };
 use boa_gc::{Finalize, Trace};
-use boa_parser::lexer::regex::RegExpFlags;
+use boa_parser::lexer::regex::{compile_regex, NamedCaptureGroup, RegExpFlags};
 use boa_profiler::Profiler;
-use regress::{Flags, Range, Regex};
+use regress::{Flags, Regex};
 use std::str::FromStr;
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
+#[cfg(feature = "annex-b")]
+mod legacy;
 mod regexp_string_iterator;
+#[cfg(feature = "annex-b")]
+pub(crate) use legacy::LegacyRegExpStatics;
 pub(crate) use regexp_string_iterator::RegExpStringIterator;
 #[cfg(test)]
 mod tests;
 
 /// The internal representation of a `RegExp` object.
 #[derive(Debug, Clone, Trace, Finalize, JsData)]
-// Safety: `RegExp` does not contain any objects which needs to be traced, so this is safe.
-#[boa_gc(unsafe_empty_trace)]
 pub struct RegExp {
     /// Regex matcher.
+    #[unsafe_ignore_trace]
     matcher: Regex,
+    /// Named capture groups, in the order their names first appear in the pattern.
+    #[unsafe_ignore_trace]
+    named_groups: Box<[NamedCaptureGroup]>,
+    #[unsafe_ignore_trace]
     flags: RegExpFlags,
     original_source: JsString,
     original_flags: JsString,
+    /// The realm in which the `RegExp` was created.
+    #[cfg(feature = "annex-b")]
+    realm: Realm,
+    /// Whether matches of this `RegExp` update the legacy static properties of its realm,
+    /// which is only the case if it was not created by a subclass of `RegExp`.
+    #[cfg(feature = "annex-b")]
+    legacy_features_enabled: bool,
 }
 
 impl IntrinsicObject for RegExp {
@@ -160,7 +174,10 @@ impl IntrinsicObject for RegExp {
             );
 
         #[cfg(feature = "annex-b")]
-        let regexp = regexp.method(Self::compile, js_string!("compile"), 2);
+        let regexp = legacy::define_static_accessors(
+            realm,
+            regexp.method(Self::compile, js_string!("compile"), 2),
+        );
 
         regexp.build();
     }
@@ -262,8 +279,33 @@ impl BuiltInConstructor for RegExp {
         let proto =
             get_prototype_from_constructor(new_target, StandardConstructors::regexp, context)?;
 
+        // Annex B, legacy RegExp features:
+        // RegExpAlloc ( newTarget )
+        // 4. If SameValue(newTarget, thisRealm.[[Intrinsics]].[[%RegExp%]]) is true, then
+        //     a. Set the value of obj’s [[LegacyFeaturesEnabled]] internal slot to true.
+        // 5. Else,
+        //     a. Set the value of obj’s [[LegacyFeaturesEnabled]] internal slot to false.
+        #[cfg(feature = "annex-b")]
+        let legacy_features_enabled = new_target.as_object().map_or(true, |new_target| {
+            JsObject::equals(
+                new_target,
+                &context.intrinsics().constructors().regexp().constructor(),
+            )
+        });
+
         // 8.Return ? RegExpInitialize(O, P, F).
-        Self::initialize(Some(proto), &p, &f, context)
+        let regexp = Self::initialize(Some(proto), &p, &f, context)?;
+
+        #[cfg(feature = "annex-b")]
+        if !legacy_features_enabled {
+            regexp
+                .as_object()
+                .and_then(JsObject::downcast_mut::<RegExp>)
+                .expect("RegExpInitialize must return a RegExp object")
+                .legacy_features_enabled = false;
+        }
+
+        Ok(regexp)
     }
 }
 
@@ -338,12 +380,12 @@ impl RegExp {
 
         // 13. Let parseResult be ParsePattern(patternText, u, v).
         // 14. If parseResult is a non-empty List of SyntaxError objects, throw a SyntaxError exception.
-        let matcher =
-            Regex::from_unicode(p.code_points().map(CodePoint::as_u32), Flags::from(flags))
-                .map_err(|error| {
-                    JsNativeError::syntax()
-                        .with_message(format!("failed to create matcher: {}", error.text))
-                })?;
+        let pattern = p.code_points().map(CodePoint::as_u32).collect::<Vec<_>>();
+        let (matcher, named_groups) =
+            compile_regex(&pattern, Flags::from(flags)).map_err(|error| {
+                JsNativeError::syntax()
+                    .with_message(format!("failed to create matcher: {}", error.text))
+            })?;
 
         // 15. Assert: parseResult is a Pattern Parse Node.
         // 16. Set obj.[[OriginalSource]] to P.
@@ -354,9 +396,14 @@ impl RegExp {
         // 21. Set obj.[[RegExpMatcher]] to CompilePattern of parseResult with argument rer.
         Ok(RegExp {
             matcher,
+            named_groups,
             flags,
             original_source: p,
             original_flags: f,
+            #[cfg(feature = "annex-b")]
+            realm: context.realm().clone(),
+            #[cfg(feature = "annex-b")]
+            legacy_features_enabled: true,
         })
     }
 
@@ -1065,28 +1112,37 @@ impl RegExp {
         a.create_data_property_or_throw(0, matched_substr, context)
             .expect("this CreateDataPropertyOrThrow call must not fail");
 
-        let mut named_groups = match_value
-            .named_groups()
-            .collect::<Vec<(&str, Option<Range>)>>();
-        // Strict mode requires groups to be created in a sorted order
-        named_groups.sort_by(|(name_x, _), (name_y, _)| name_x.cmp(name_y));
-
         // Combines:
         // 26. Let groupNames be a new empty List.
         // 30. If R contains any GroupName, then
         // 31. Else,
         // 33. For each integer i such that 1 ≤ i ≤ n, in ascending order, do
         #[allow(clippy::if_not_else)]
-        let (groups, group_names) = if !named_groups.clone().is_empty() {
+        let (groups, group_names) = if !rx.named_groups.is_empty() {
             // a. Let groups be OrdinaryObjectCreate(null).
             let groups = JsObject::with_null_proto();
             let group_names = JsObject::with_null_proto();
 
             // e. If the ith capture of R was defined with a GroupName, then
             // i. Let s be the CapturingGroupName of that GroupName.
-            // ii. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
-            // iii. Append s to groupNames.
-            for (name, range) in named_groups {
+            // ii. If matchedGroupNames contains s, then
+            //     1. Assert: capturedValue is undefined.
+            //     2. Append undefined to groupNames.
+            // iii. Else,
+            //     1. If capturedValue is not undefined, append s to matchedGroupNames.
+            //     2. NOTE: If there are multiple groups named s, groups may already have an s property at this point.
+            //        However, because groups is an ordinary object whose properties are all writable data properties,
+            //        the call to CreateDataPropertyOrThrow is nevertheless guaranteed to succeed.
+            //     3. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
+            //     4. Append s to groupNames.
+            // NOTE: Only one of the groups with the same name can participate in a match, so we
+            // directly define the property with the value of that group.
+            for group in &*rx.named_groups {
+                let name = js_string!(group.name());
+                let range = group
+                    .indices()
+                    .iter()
+                    .find_map(|&index| match_value.group(index));
                 let name = js_string!(name);
                 if let Some(range) = range {
                     let value = js_string!(&input[range.clone()]);
@@ -1183,6 +1239,24 @@ impl RegExp {
                 .expect("this CreateDataPropertyOrThrow call must not fail");
         }
 
+        // Annex B, legacy RegExp features:
+        // a. Let thisRealm be the current Realm Record.
+        // b. Let rRealm be the value of R's [[Realm]] internal slot.
+        // c. If SameValue(thisRealm, rRealm) is true, then
+        #[cfg(feature = "annex-b")]
+        if rx.realm == *context.realm() {
+            let mut statics = rx.realm.legacy_regexp_statics().borrow_mut();
+            // i. If the value of R’s [[LegacyFeaturesEnabled]] internal slot is true, then
+            if rx.legacy_features_enabled {
+                // 1. Perform UpdateLegacyRegExpStaticProperties(%RegExp%, S, lastIndex, e, capturedValues).
+                statics.update(input, &match_value);
+            // ii. Else,
+            } else {
+                // 1. Perform InvalidateLegacyRegExpStaticProperties(%RegExp%).
+                statics.invalidate();
+            }
+        }
+
         // 35. Return A.
         Ok(Some(a))
     }
@@ -1863,9 +1937,36 @@ impl RegExp {
                 JsNativeError::typ()
                     .with_message("`RegExp.prototype.compile` cannot be called for a non-object")
             })?;
+
+        {
+            let rx = this
+                .downcast_ref::<RegExp>()
+                .expect("already checked that the object was a RegExp");
+
+            // 3. Let thisRealm be the current Realm Record.
+            // 4. Let oRealm be the value of O’s [[Realm]] internal slot.
+            // 5. If SameValue(thisRealm, oRealm) is false, throw a TypeError exception.
+            if rx.realm != *context.realm() {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "`RegExp.prototype.compile` cannot be called on a cross-realm RegExp",
+                    )
+                    .into());
+            }
+
+            // 6. If the value of R’s [[LegacyFeaturesEnabled]] internal slot is false, throw a TypeError exception.
+            if !rx.legacy_features_enabled {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "`RegExp.prototype.compile` cannot be called on a RegExp subclass instance",
+                    )
+                    .into());
+            }
+        }
+
         let pattern = args.get_or_undefined(0);
         let flags = args.get_or_undefined(1);
-        // 3. If pattern is an Object and pattern has a [[RegExpMatcher]] internal slot, then
+        // 7. If pattern is an Object and pattern has a [[RegExpMatcher]] internal slot, then
         let (pattern, flags) = if let Some((p, f)) = pattern.as_object().and_then(|o| {
             o.downcast_ref::<RegExp>()
                 .map(|rx| (rx.original_source.clone(), rx.original_flags.clone()))
@@ -1883,7 +1984,7 @@ impl RegExp {
             //     c. Let F be pattern.[[OriginalFlags]].
             (p.into(), f.into())
         } else {
-            // 4. Else,
+            // 8. Else,
             //     a. Let P be pattern.
             //     b. Let F be flags.
             (pattern.clone(), flags.clone())
@@ -1891,7 +1992,8 @@ impl RegExp {
 
         let regexp = Self::compile_native_regexp(&pattern, &flags, context)?;
 
-        // 5. Return ? RegExpInitialize(O, P, F).
+        // 9. Return ? RegExpInitialize(O, P, F).
+        // NOTE: The new `RegExp` has the same realm and legacy features as `O`, as checked above.
         {
             *this
                 .downcast_mut::<RegExp>()
@@ -119,6 +119,46 @@ fn exec() {
     ]);
 }
 
+#[test]
+fn duplicate_named_groups() {
+    run_test_actions([
+        TestAction::run(indoc! {r"
+                var re = /(?<year>\d{4})-\d\d|\d\d-(?<year>\d{4})/d;
+                var first = re.exec('1999-12');
+                var second = re.exec('12-2024');
+            "}),
+        TestAction::assert_eq("first.groups.year", js_string!("1999")),
+        TestAction::assert_eq("second.groups.year", js_string!("2024")),
+        TestAction::assert_eq("first.indices.groups.year[0]", 0),
+        TestAction::assert_eq("second.indices.groups.year[0]", 3),
+        TestAction::assert_eq("Object.keys(second.groups).length", 1),
+        TestAction::assert_eq(
+            r"'1999-12 12-2024'.replace(/(?<year>\d{4})-\d\d|\d\d-(?<year>\d{4})/g, '$<year>')",
+            js_string!("1999 2024"),
+        ),
+        TestAction::assert(r"/^(?:(?<a>x)|(?<a>y))\k<a>$/.test('yy')"),
+        TestAction::assert(r"!/^(?:(?<a>x)|(?<a>y))\k<a>$/.test('xy')"),
+        TestAction::assert_native_error(
+            "new RegExp('(?<a>x)(?<a>y)')",
+            JsNativeErrorKind::Syntax,
+            "failed to create matcher: Duplicate capture group name",
+        ),
+        TestAction::assert_native_error(
+            "new RegExp('(?:(?<a>x)|(?<a>y))(?<a>z)')",
+            JsNativeErrorKind::Syntax,
+            "failed to create matcher: Duplicate capture group name",
+        ),
+    ]);
+}
+
+#[test]
+fn named_groups_order() {
+    run_test_actions([TestAction::assert_eq(
+        r"Object.keys(/(?<b>.)(?:x)(?<a>.)\k<a>/.exec('1x22').groups).join()",
+        js_string!("b,a"),
+    )]);
+}
+
 #[test]
 fn no_panic_on_parse_fail() {
     run_test_actions([
@@ -224,3 +264,101 @@ fn regular_expression_construction_independant_of_global_reg_exp() {
         TestAction::run(regex),
     ]);
 }
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn legacy_static_properties() {
+    run_test_actions([
+        TestAction::assert_eq("RegExp.lastMatch", js_string!()),
+        TestAction::assert_eq("RegExp.$1", js_string!()),
+        TestAction::run("/(b)(c)?(d)/.exec('abde')"),
+        TestAction::assert_eq("RegExp.input", js_string!("abde")),
+        TestAction::assert_eq("RegExp.$_", js_string!("abde")),
+        TestAction::assert_eq("RegExp.lastMatch", js_string!("bd")),
+        TestAction::assert_eq("RegExp['$&']", js_string!("bd")),
+        TestAction::assert_eq("RegExp.lastParen", js_string!("d")),
+        TestAction::assert_eq("RegExp.leftContext", js_string!("a")),
+        TestAction::assert_eq("RegExp.rightContext", js_string!("e")),
+        TestAction::assert_eq("RegExp.$1", js_string!("b")),
+        TestAction::assert_eq("RegExp.$2", js_string!()),
+        TestAction::assert_eq("RegExp.$3", js_string!("d")),
+        TestAction::assert_eq("RegExp.$9", js_string!()),
+        TestAction::run("'xyz'.replace(/y/, '')"),
+        TestAction::assert_eq("RegExp.lastMatch", js_string!("y")),
+        TestAction::assert_eq("RegExp.lastParen", js_string!()),
+        TestAction::run("RegExp.input = 42"),
+        TestAction::assert_eq("RegExp.$_", js_string!("42")),
+        TestAction::assert_eq("RegExp.rightContext", js_string!("z")),
+        TestAction::assert(
+            "Object.getOwnPropertyDescriptor(RegExp, '$1').set === undefined \
+             && !Object.getOwnPropertyDescriptor(RegExp, 'input').enumerable",
+        ),
+        TestAction::assert_native_error(
+            "Object.getOwnPropertyDescriptor(RegExp, 'lastMatch').get.call({})",
+            JsNativeErrorKind::Type,
+            "RegExp.lastMatch accessor called on an object that is not the RegExp constructor of the realm",
+        ),
+    ]);
+}
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn legacy_static_properties_subclass() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class MyRegExp extends RegExp {}
+            /a/.exec('a');
+        "#}),
+        TestAction::assert_eq("RegExp.lastMatch", js_string!("a")),
+        TestAction::assert_native_error(
+            "MyRegExp.lastMatch",
+            JsNativeErrorKind::Type,
+            "RegExp.lastMatch accessor called on an object that is not the RegExp constructor of the realm",
+        ),
+        TestAction::run("new MyRegExp('b').exec('b')"),
+        TestAction::assert_native_error(
+            "RegExp.lastMatch",
+            JsNativeErrorKind::Type,
+            "RegExp.lastMatch is not available after a match of a RegExp subclass",
+        ),
+        TestAction::assert_native_error(
+            "RegExp.input",
+            JsNativeErrorKind::Type,
+            "RegExp.input is not available after a match of a RegExp subclass",
+        ),
+        TestAction::assert_native_error(
+            "new MyRegExp('b').compile('c')",
+            JsNativeErrorKind::Type,
+            "`RegExp.prototype.compile` cannot be called on a RegExp subclass instance",
+        ),
+        TestAction::run("/c/.exec('c')"),
+        TestAction::assert_eq("RegExp.lastMatch", js_string!("c")),
+    ]);
+}
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn legacy_static_properties_cross_realm() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let realm = context.create_realm().unwrap();
+            let other = realm.intrinsics().constructors().regexp().constructor();
+            context
+                .global_object()
+                .set(js_string!("OtherRegExp"), other, false, context)
+                .unwrap();
+        }),
+        TestAction::run("/a/.exec('a')"),
+        TestAction::run("OtherRegExp.prototype.exec.call(/b/, 'b')"),
+        TestAction::assert_eq("RegExp.lastMatch", js_string!("a")),
+        TestAction::assert_eq("OtherRegExp.lastMatch", js_string!()),
+        TestAction::run("OtherRegExp.prototype.exec.call(new OtherRegExp('c'), 'c')"),
+        TestAction::assert_eq("OtherRegExp.lastMatch", js_string!("c")),
+        TestAction::assert_eq("RegExp.lastMatch", js_string!("a")),
+        TestAction::assert_native_error(
+            "RegExp.prototype.compile.call(new OtherRegExp('d'))",
+            JsNativeErrorKind::Type,
+            "`RegExp.prototype.compile` cannot be called on a cross-realm RegExp",
+        ),
+    ]);
+}
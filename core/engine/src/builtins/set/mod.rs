@@ -19,7 +19,10 @@ pub mod ordered_set;
 
 use self::ordered_set::OrderedSet;
 use crate::{
-    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    builtins::{
+        iterable::{IteratorHint, IteratorRecord},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
@@ -28,6 +31,7 @@ use crate::{
     realm::Realm,
     string::{common::StaticJsStrings, utf16},
     symbol::JsSymbol,
+    value::IntegerOrInfinity,
     Context, JsArgs, JsResult, JsString, JsValue,
 };
 use boa_profiler::Profiler;
@@ -70,6 +74,17 @@ impl IntrinsicObject for Set {
             .method(Self::entries, js_string!("entries"), 0)
             .method(Self::for_each, js_string!("forEach"), 1)
             .method(Self::has, js_string!("has"), 1)
+            .method(Self::union, js_string!("union"), 1)
+            .method(Self::intersection, js_string!("intersection"), 1)
+            .method(Self::difference, js_string!("difference"), 1)
+            .method(
+                Self::symmetric_difference,
+                js_string!("symmetricDifference"),
+                1,
+            )
+            .method(Self::is_subset_of, js_string!("isSubsetOf"), 1)
+            .method(Self::is_superset_of, js_string!("isSupersetOf"), 1)
+            .method(Self::is_disjoint_from, js_string!("isDisjointFrom"), 1)
             .property(
                 utf16!("keys"),
                 values_function.clone(),
@@ -496,6 +511,419 @@ impl Set {
         ))
     }
 
+    /// `Set.prototype.union ( other )`
+    ///
+    /// This method returns a new set containing the elements of both this set and `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.union
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/union
+    pub(crate) fn union(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set(this, "union")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let mut keys_iter = other_rec.keys_iterator(context)?;
+
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let mut result_set_data = Self::copy_set_data(o);
+
+        // 6. Let next be not-started.
+        // 7. Repeat, while next is not done,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        //     b. If next is not done, then
+        while !keys_iter.step(context)? {
+            // i. Set next to CanonicalizeKeyedCollectionKey(next).
+            let next = canonicalize_keyed_collection_key(keys_iter.value(context)?);
+
+            // ii. If SetDataHas(resultSetData, next) is false, then
+            //     1. Append next to resultSetData.
+            result_set_data.add(next);
+        }
+
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(Self::create_from_set_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.intersection ( other )`
+    ///
+    /// This method returns a new set containing the elements that are both in this set and in
+    /// `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.intersection
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/intersection
+    pub(crate) fn intersection(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set(this, "intersection")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let resultSetData be a new empty List.
+        let mut result_set_data = OrderedSet::new();
+
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::set_data_size(o) as f64 <= other_rec.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            Self::for_each_element(o, context, |e, context| {
+                // i. Let e be O.[[SetData]][index].
+                // ii. Set index to index + 1.
+                // iii. If e is not empty, then
+                //     1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                //     2. If inOther is true, then
+                if other_rec.has(&e, context)? {
+                    // a. NOTE: It is possible for earlier calls to otherRec.[[Has]] to remove and
+                    //    re-add an element of O.[[SetData]], which can cause elements to be
+                    //    visited more than once during this iteration.
+                    // b. If SetDataHas(resultSetData, e) is false, then
+                    //     i. Append e to resultSetData.
+                    result_set_data.add(e);
+                }
+
+                //     3. NOTE: The number of elements in O.[[SetData]] may have increased during
+                //        execution of otherRec.[[Has]].
+                //     4. Set thisSize to the number of elements in O.[[SetData]].
+                Ok(true)
+            })?;
+        } else {
+            // 6. Else,
+            //     a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let mut keys_iter = other_rec.keys_iterator(context)?;
+
+            //     b. Let next be not-started.
+            //     c. Repeat, while next is not done,
+            //         i. Set next to ? IteratorStepValue(keysIter).
+            //         ii. If next is not done, then
+            while !keys_iter.step(context)? {
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                let next = canonicalize_keyed_collection_key(keys_iter.value(context)?);
+
+                // 2. Let inThis be SetDataHas(O.[[SetData]], next).
+                // 3. If inThis is true, then
+                if Self::set_data_has(o, &next) {
+                    // a. NOTE: Because other is an arbitrary object, it is possible for its
+                    //    "keys" iterator to produce the same value more than once.
+                    // b. If SetDataHas(resultSetData, next) is false, then
+                    //     i. Append next to resultSetData.
+                    result_set_data.add(next);
+                }
+            }
+        }
+
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(Self::create_from_set_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.difference ( other )`
+    ///
+    /// This method returns a new set containing the elements of this set that are not in `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.difference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/difference
+    pub(crate) fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set(this, "difference")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let resultSetData be a copy of O.[[SetData]].
+        let mut result_set_data = Self::copy_set_data(o);
+
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::set_data_size(o) as f64 <= other_rec.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be resultSetData[index].
+            //     ii. If e is not empty, then
+            //         1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+            //         2. If inOther is true, then
+            //             a. Set resultSetData[index] to empty.
+            //     iii. Set index to index + 1.
+            let elements = result_set_data.iter().cloned().collect::<Vec<_>>();
+            for e in elements {
+                if other_rec.has(&e, context)? {
+                    result_set_data.delete(&e);
+                }
+            }
+        } else {
+            // 6. Else,
+            //     a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let mut keys_iter = other_rec.keys_iterator(context)?;
+
+            //     b. Let next be not-started.
+            //     c. Repeat, while next is not done,
+            //         i. Set next to ? IteratorStepValue(keysIter).
+            //         ii. If next is not done, then
+            while !keys_iter.step(context)? {
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                let next = canonicalize_keyed_collection_key(keys_iter.value(context)?);
+
+                // 2. Let valueIndex be SetDataIndex(resultSetData, next).
+                // 3. If valueIndex is not not-found, then
+                //     a. Set resultSetData[valueIndex] to empty.
+                result_set_data.delete(&next);
+            }
+        }
+
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(Self::create_from_set_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.symmetricDifference ( other )`
+    ///
+    /// This method returns a new set containing the elements that are either in this set or in
+    /// `other`, but not in both.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.symmetricdifference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/symmetricDifference
+    pub(crate) fn symmetric_difference(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set(this, "symmetricDifference")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let mut keys_iter = other_rec.keys_iterator(context)?;
+
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let mut result_set_data = Self::copy_set_data(o);
+
+        // 6. Let next be not-started.
+        // 7. Repeat, while next is not done,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        //     b. If next is not done, then
+        while !keys_iter.step(context)? {
+            // i. Set next to CanonicalizeKeyedCollectionKey(next).
+            let next = canonicalize_keyed_collection_key(keys_iter.value(context)?);
+
+            // ii. Let resultIndex be SetDataIndex(resultSetData, next).
+            // iii. If resultIndex is not-found, let alreadyInResult be false. Otherwise let alreadyInResult be true.
+            let already_in_result = result_set_data.contains(&next);
+
+            // iv. If SetDataHas(O.[[SetData]], next) is true, then
+            if Self::set_data_has(o, &next) {
+                // 1. If alreadyInResult is true, set resultSetData[resultIndex] to empty.
+                if already_in_result {
+                    result_set_data.delete(&next);
+                }
+            } else if !already_in_result {
+                // v. Else,
+                //     1. If alreadyInResult is false, append next to resultSetData.
+                result_set_data.add(next);
+            }
+        }
+
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(Self::create_from_set_data(result_set_data, context).into())
+    }
+
+    /// `Set.prototype.isSubsetOf ( other )`
+    ///
+    /// This method returns `true` if all elements of this set are in `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.issubsetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSubsetOf
+    pub(crate) fn is_subset_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set(this, "isSubsetOf")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) > otherRec.[[Size]], return false.
+        if Self::set_data_size(o) as f64 > other_rec.size {
+            return Ok(false.into());
+        }
+
+        // 5. Let thisSize be the number of elements in O.[[SetData]].
+        // 6. Let index be 0.
+        // 7. Repeat, while index < thisSize,
+        //     a. Let e be O.[[SetData]][index].
+        //     b. Set index to index + 1.
+        //     c. If e is not empty, then
+        //         i. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+        //         ii. If inOther is false, return false.
+        //         iii. NOTE: The number of elements in O.[[SetData]] may have increased during
+        //              execution of otherRec.[[Has]].
+        //         iv. Set thisSize to the number of elements in O.[[SetData]].
+        // 8. Return true.
+        Self::for_each_element(o, context, |e, context| other_rec.has(&e, context))
+            .map(JsValue::from)
+    }
+
+    /// `Set.prototype.isSupersetOf ( other )`
+    ///
+    /// This method returns `true` if all elements of `other` are in this set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.issupersetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSupersetOf
+    pub(crate) fn is_superset_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set(this, "isSupersetOf")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) < otherRec.[[Size]], return false.
+        if (Self::set_data_size(o) as f64) < other_rec.size {
+            return Ok(false.into());
+        }
+
+        // 5. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let mut keys_iter = other_rec.keys_iterator(context)?;
+
+        // 6. Let next be not-started.
+        // 7. Repeat, while next is not done,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        //     b. If next is not done, then
+        while !keys_iter.step(context)? {
+            let next = keys_iter.value(context)?;
+
+            // i. If SetDataHas(O.[[SetData]], next) is false, then
+            if !Self::set_data_has(o, &canonicalize_keyed_collection_key(next)) {
+                // 1. Perform ? IteratorClose(keysIter, NormalCompletion(unused)).
+                // 2. Return false.
+                return keys_iter.close(Ok(false.into()), context);
+            }
+        }
+
+        // 8. Return true.
+        Ok(true.into())
+    }
+
+    /// `Set.prototype.isDisjointFrom ( other )`
+    ///
+    /// This method returns `true` if this set has no elements in common with `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-set.prototype.isdisjointfrom
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isDisjointFrom
+    pub(crate) fn is_disjoint_from(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = Self::this_set(this, "isDisjointFrom")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::set_data_size(o) as f64 <= other_rec.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be O.[[SetData]][index].
+            //     ii. Set index to index + 1.
+            //     iii. If e is not empty, then
+            //         1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+            //         2. If inOther is true, return false.
+            //         3. NOTE: The number of elements in O.[[SetData]] may have increased during
+            //            execution of otherRec.[[Has]].
+            //         4. Set thisSize to the number of elements in O.[[SetData]].
+            return Self::for_each_element(o, context, |e, context| {
+                other_rec.has(&e, context).map(|in_other| !in_other)
+            })
+            .map(JsValue::from);
+        }
+
+        // 5. Else,
+        //     a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let mut keys_iter = other_rec.keys_iterator(context)?;
+
+        //     b. Let next be not-started.
+        //     c. Repeat, while next is not done,
+        //         i. Set next to ? IteratorStepValue(keysIter).
+        //         ii. If next is not done, then
+        while !keys_iter.step(context)? {
+            let next = keys_iter.value(context)?;
+
+            // 1. If SetDataHas(O.[[SetData]], next) is true, then
+            if Self::set_data_has(o, &canonicalize_keyed_collection_key(next)) {
+                // a. Perform ? IteratorClose(keysIter, NormalCompletion(unused)).
+                // b. Return false.
+                return keys_iter.close(Ok(false.into()), context);
+            }
+        }
+
+        // 6. Return true.
+        Ok(true.into())
+    }
+
     fn size_getter(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
         Self::get_size(this).map(JsValue::from)
     }
@@ -529,4 +957,213 @@ impl Set {
                     .into()
             })
     }
+
+    /// Gets the `Set` object of `this`, or throws if `this` is not a `Set`.
+    fn this_set<'a>(this: &'a JsValue, method: &str) -> JsResult<&'a JsObject> {
+        this.as_object()
+            .filter(|o| o.is::<OrderedSet>())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Method Set.prototype.{method} called on incompatible receiver"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Abstract operation `SetDataSize ( setData )`.
+    fn set_data_size(set: &JsObject) -> usize {
+        set.downcast_ref::<OrderedSet>()
+            .expect("must be a `Set` object")
+            .len()
+    }
+
+    /// Abstract operation `SetDataHas ( setData, value )`.
+    fn set_data_has(set: &JsObject, value: &JsValue) -> bool {
+        set.downcast_ref::<OrderedSet>()
+            .expect("must be a `Set` object")
+            .contains(value)
+    }
+
+    /// Copies the non-empty elements of the `[[SetData]]` of `set`.
+    fn copy_set_data(set: &JsObject) -> OrderedSet {
+        let set = set
+            .downcast_ref::<OrderedSet>()
+            .expect("must be a `Set` object");
+        let mut copy = OrderedSet::with_capacity(set.len());
+        for value in set.iter() {
+            copy.add(value.clone());
+        }
+        copy
+    }
+
+    /// Creates a new `Set` object with `%Set.prototype%` as prototype and `set_data` as its
+    /// `[[SetData]]`.
+    fn create_from_set_data(set_data: OrderedSet, context: &mut Context) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context.intrinsics().constructors().set().prototype(),
+            set_data,
+        )
+    }
+
+    /// Calls `f` for each element of `set`, including elements added during the iteration,
+    /// stopping early and returning `false` if `f` returns `false`.
+    ///
+    /// The set is locked during the iteration, which allows `f` to remove elements without
+    /// affecting the indices of the remaining elements.
+    fn for_each_element<F>(set: &JsObject, context: &mut Context, mut f: F) -> JsResult<bool>
+    where
+        F: FnMut(JsValue, &mut Context) -> JsResult<bool>,
+    {
+        let _lock = set
+            .downcast_mut::<OrderedSet>()
+            .expect("must be a `Set` object")
+            .lock(set.clone());
+
+        let mut index = 0;
+        loop {
+            let element = {
+                let set = set
+                    .downcast_ref::<OrderedSet>()
+                    .expect("must be a `Set` object");
+                if index >= set.full_len() {
+                    return Ok(true);
+                }
+                set.get_index(index).cloned()
+            };
+            index += 1;
+
+            if let Some(element) = element {
+                if !f(element, context)? {
+                    return Ok(false);
+                }
+            }
+        }
+    }
+}
+
+/// Abstract operation `CanonicalizeKeyedCollectionKey ( key )`
+///
+/// Converts `-0` to `+0`, leaving any other value unchanged.
+fn canonicalize_keyed_collection_key(key: JsValue) -> JsValue {
+    // 1. If key is -0𝔽, return +0𝔽.
+    // 2. Return key.
+    match key.as_number() {
+        Some(n) if n.is_zero() => JsValue::Integer(0),
+        _ => key,
+    }
+}
+
+/// A Set Record, used to encapsulate the interface of a set-like object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-set-methods/#sec-set-records
+struct SetRecord {
+    /// `[[SetObject]]`
+    set_object: JsObject,
+
+    /// `[[Size]]`
+    ///
+    /// A non-negative integer or `+∞`.
+    size: f64,
+
+    /// `[[Has]]`
+    has: JsObject,
+
+    /// `[[Keys]]`
+    keys: JsObject,
+}
+
+impl SetRecord {
+    /// `GetSetRecord ( obj )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-set-methods/#sec-getsetrecord
+    fn get(obj: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. If obj is not an Object, throw a TypeError exception.
+        let Some(obj) = obj.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("set-like argument must be an object")
+                .into());
+        };
+
+        // 2. Let rawSize be ? Get(obj, "size").
+        let raw_size = obj.get(utf16!("size"), context)?;
+
+        // 3. Let numSize be ? ToNumber(rawSize).
+        // 4. NOTE: If rawSize is undefined, then numSize will be NaN.
+        let num_size = raw_size.to_number(context)?;
+
+        // 5. If numSize is NaN, throw a TypeError exception.
+        if num_size.is_nan() {
+            return Err(JsNativeError::typ()
+                .with_message("size of set-like argument must be a number")
+                .into());
+        }
+
+        // 6. Let intSize be ! ToIntegerOrInfinity(numSize).
+        // 7. If intSize < 0, throw a RangeError exception.
+        let size = match IntegerOrInfinity::from(num_size) {
+            IntegerOrInfinity::PositiveInfinity => f64::INFINITY,
+            IntegerOrInfinity::Integer(size) if size >= 0 => size as f64,
+            _ => {
+                return Err(JsNativeError::range()
+                    .with_message("size of set-like argument cannot be negative")
+                    .into())
+            }
+        };
+
+        // 8. Let has be ? Get(obj, "has").
+        // 9. If IsCallable(has) is false, throw a TypeError exception.
+        let has = obj
+            .get(utf16!("has"), context)?
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("`has` of set-like argument is not callable")
+            })?;
+
+        // 10. Let keys be ? Get(obj, "keys").
+        // 11. If IsCallable(keys) is false, throw a TypeError exception.
+        let keys = obj
+            .get(utf16!("keys"), context)?
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("`keys` of set-like argument is not callable")
+            })?;
+
+        // 12. Return a new Set Record { [[SetObject]]: obj, [[Size]]: intSize, [[Has]]: has, [[Keys]]: keys }.
+        Ok(Self {
+            set_object: obj.clone(),
+            size,
+            has,
+            keys,
+        })
+    }
+
+    /// Returns `ToBoolean(? Call(setRec.[[Has]], setRec.[[SetObject]], « value »))`.
+    fn has(&self, value: &JsValue, context: &mut Context) -> JsResult<bool> {
+        self.has
+            .call(
+                &self.set_object.clone().into(),
+                std::slice::from_ref(value),
+                context,
+            )
+            .map(|v| v.to_boolean())
+    }
+
+    /// Returns `? GetIteratorFromMethod(setRec.[[SetObject]], setRec.[[Keys]])`.
+    fn keys_iterator(&self, context: &mut Context) -> JsResult<IteratorRecord> {
+        JsValue::from(self.set_object.clone()).get_iterator(
+            context,
+            Some(IteratorHint::Sync),
+            Some(self.keys.clone()),
+        )
+    }
 }
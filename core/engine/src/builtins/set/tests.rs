@@ -1,4 +1,4 @@
-use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
 use indoc::indoc;
 
 #[test]
@@ -174,3 +174,65 @@ fn not_a_function() {
         "calling a builtin Set constructor without new is forbidden",
     )]);
 }
+
+#[test]
+fn set_methods() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const a = new Set([1, 2, 3]);
+                const b = new Set([3, 4]);
+            "#}),
+        TestAction::assert_eq("[...a.union(b)].join()", js_string!("1,2,3,4")),
+        TestAction::assert_eq("[...a.intersection(b)].join()", js_string!("3")),
+        TestAction::assert_eq("[...b.intersection(a)].join()", js_string!("3")),
+        TestAction::assert_eq("[...a.difference(b)].join()", js_string!("1,2")),
+        TestAction::assert_eq("[...b.difference(a)].join()", js_string!("4")),
+        TestAction::assert_eq("[...a.symmetricDifference(b)].join()", js_string!("1,2,4")),
+        TestAction::assert("a.isSubsetOf(new Set([0, 1, 2, 3]))"),
+        TestAction::assert("!a.isSubsetOf(b)"),
+        TestAction::assert("a.isSupersetOf(new Set([1, 3]))"),
+        TestAction::assert("!a.isSupersetOf(b)"),
+        TestAction::assert("a.isDisjointFrom(new Set([4, 5]))"),
+        TestAction::assert("!a.isDisjointFrom(b)"),
+        TestAction::assert("a.union(b) !== a && a.size === 3"),
+    ]);
+}
+
+#[test]
+fn set_methods_set_like() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const set = new Set([1, 2, 3]);
+                const map = new Map([[2, 'two'], [5, 'five']]);
+                const setLike = {
+                    size: 2,
+                    has(v) { return v === 1 || v === 2; },
+                    *keys() { yield 1; yield 2; },
+                };
+            "#}),
+        TestAction::assert_eq("[...set.intersection(map)].join()", js_string!("2")),
+        TestAction::assert_eq("[...set.union(map)].join()", js_string!("1,2,3,5")),
+        TestAction::assert_eq("[...set.difference(setLike)].join()", js_string!("3")),
+        TestAction::assert("set.isSupersetOf(setLike)"),
+        TestAction::assert_native_error(
+            "set.union([1, 2])",
+            JsNativeErrorKind::Type,
+            "size of set-like argument must be a number",
+        ),
+        TestAction::assert_native_error(
+            "set.union({ size: -1, has() {}, keys() {} })",
+            JsNativeErrorKind::Range,
+            "size of set-like argument cannot be negative",
+        ),
+        TestAction::assert_native_error(
+            "set.union({ size: 1, has: 1, keys() {} })",
+            JsNativeErrorKind::Type,
+            "`has` of set-like argument is not callable",
+        ),
+        TestAction::assert_native_error(
+            "Set.prototype.union.call({}, set)",
+            JsNativeErrorKind::Type,
+            "Method Set.prototype.union called on incompatible receiver",
+        ),
+    ]);
+}
@@ -0,0 +1,387 @@
+//! Boa's implementation of the ECMAScript `ShadowRealm` proposal.
+//!
+//! A `ShadowRealm` is a distinct global environment, with its own global object containing its
+//! own intrinsics and built-ins, which can only be communicated with by passing primitives and
+//! callables across its boundary.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/
+
+use boa_ast::operations::{contains, ContainsSymbol};
+use boa_gc::{Finalize, Trace};
+use boa_parser::{Parser, Source};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        promise::PromiseCapability, BuiltInBuilder, BuiltInConstructor, BuiltInObject, Eval,
+        IntrinsicObject, Promise,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    module::{load_dynamic_import, ModuleRequest, Referrer},
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::{Attribute, PropertyKey},
+    realm::Realm,
+    string::common::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue, NativeFunction,
+};
+
+mod wrapped;
+
+pub(crate) use wrapped::WrappedFunction;
+use wrapped::{boundary_error, get_wrapped_value};
+
+#[cfg(test)]
+mod tests;
+
+/// Boa's implementation of ECMAScript's `ShadowRealm` builtin object.
+///
+/// Every `ShadowRealm` object owns a [`Realm`] created with the default global bindings, in which
+/// all code passed to `evaluate` and all modules loaded by `importValue` are run.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct ShadowRealm {
+    realm: Realm,
+}
+
+impl IntrinsicObject for ShadowRealm {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::evaluate, js_string!("evaluate"), 1)
+            .method(Self::import_value, js_string!("importValue"), 2)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for ShadowRealm {
+    const NAME: JsString = StaticJsStrings::SHADOW_REALM;
+}
+
+impl BuiltInConstructor for ShadowRealm {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::shadow_realm;
+
+    /// Constructor [`ShadowRealm ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%ShadowRealm.prototype%", « [[ShadowRealm]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::shadow_realm,
+            context,
+        )?;
+
+        // 3. Let realmRec be CreateRealm().
+        // 4. Set O.[[ShadowRealm]] to realmRec.
+        // 5-9. Set up the execution context of the realm.
+        // 10. Perform ? SetDefaultGlobalBindings(O.[[ShadowRealm]]).
+        let realm = context.create_realm()?;
+
+        // 11. Perform ? HostInitializeShadowRealm(O.[[ShadowRealm]]).
+        // Note: there are no host-defined steps for this operation at the moment.
+
+        // 12. Return O.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { realm },
+        )
+        .into())
+    }
+}
+
+impl ShadowRealm {
+    /// Abstract operation [`ValidateShadowRealmObject ( O )`][spec].
+    ///
+    /// Returns the realm of the `ShadowRealm` object.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-validateshadowrealmobject
+    fn validate(this: &JsValue, method: &str) -> JsResult<Realm> {
+        // 1. Perform ? RequireInternalSlot(O, [[ShadowRealm]]).
+        this.as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .map(|shadow_realm| shadow_realm.realm.clone())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "ShadowRealm.prototype.{method}: `this` is not a ShadowRealm object"
+                    ))
+                    .into()
+            })
+    }
+
+    /// [`ShadowRealm.prototype.evaluate ( sourceText )`][spec].
+    ///
+    /// Synchronously evaluates `sourceText` as a script in the realm of this `ShadowRealm`.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.evaluate
+    fn evaluate(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::validate(this, "evaluate")?;
+
+        // 3. If sourceText is not a String, throw a TypeError exception.
+        let Some(source_text) = args.get_or_undefined(0).as_string() else {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.evaluate: source text must be a string")
+                .into());
+        };
+
+        // 4. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 5. Let evalRealm be O.[[ShadowRealm]].
+        // 6. Return ? PerformShadowRealmEval(sourceText, callerRealm, evalRealm).
+        Self::perform_shadow_realm_eval(source_text, &caller_realm, eval_realm, context)
+    }
+
+    /// Abstract operation [`PerformShadowRealmEval ( sourceText, callerRealm, evalRealm )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-performshadowrealmeval
+    fn perform_shadow_realm_eval(
+        source_text: &JsString,
+        caller_realm: &Realm,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? HostEnsureCanCompileStrings(evalRealm, « », sourceText, false).
+        context.host_hooks().ensure_can_compile_strings(
+            eval_realm.clone(),
+            &[],
+            source_text,
+            false,
+            context,
+        )?;
+
+        // 2. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+        //     a. Let script be ParseText(StringToCodePoints(sourceText), Script).
+        //     b. If script is a List of errors, throw a SyntaxError exception.
+        //     c. If script Contains ScriptBody is false, return undefined.
+        //     d. Let body be the ScriptBody of script.
+        let mut parser = Parser::new(Source::from_utf16(source_text));
+        parser.set_identifier(context.next_parser_identifier());
+        let body = parser.parse_eval(false, context.interner_mut())?;
+
+        //     e. If body Contains NewTarget is true, throw a SyntaxError exception.
+        if contains(&body, ContainsSymbol::NewTarget) {
+            return Err(JsNativeError::syntax()
+                .with_message("invalid `new.target` expression inside ShadowRealm code")
+                .into());
+        }
+        //     f. If body Contains SuperProperty is true, throw a SyntaxError exception.
+        if contains(&body, ContainsSymbol::SuperProperty) {
+            return Err(JsNativeError::syntax()
+                .with_message("invalid `super` reference inside ShadowRealm code")
+                .into());
+        }
+        //     g. If body Contains SuperCall is true, throw a SyntaxError exception.
+        if contains(&body, ContainsSymbol::SuperCall) {
+            return Err(JsNativeError::syntax()
+                .with_message("invalid `super` call inside ShadowRealm code")
+                .into());
+        }
+
+        // 3. Let strictEval be IsStrict of script.
+        let strict = body.strict();
+
+        // 4-14. Prepare a new execution context for evalRealm and its global environment.
+        let caller = context.enter_realm(eval_realm);
+
+        // 15. Let result be Completion(EvalDeclarationInstantiation(body, varEnv, lexEnv, null, strictEval)).
+        // 16. If result.[[Type]] is normal, then
+        //     a. Set result to the result of evaluating body.
+        // 17. If result.[[Type]] is normal and result.[[Value]] is empty, then
+        //     a. Set result to NormalCompletion(undefined).
+        let result = Eval::evaluate_body(&body, false, strict, context);
+
+        // 18. Suspend evalContext and remove it from the execution context stack.
+        // 19. Resume the context that is now on the top of the execution context stack as the running execution context.
+        context.enter_realm(caller);
+
+        // 20. If result.[[Type]] is not normal, then
+        //     a. Let copiedError be CreateTypeErrorCopy(callerRealm, result.[[Value]]).
+        //     b. Return ThrowCompletion(copiedError).
+        let result = result.map_err(|err| {
+            boundary_error(err, caller_realm.clone(), "ShadowRealm.prototype.evaluate")
+        })?;
+
+        // 21. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+        get_wrapped_value(caller_realm, &result, context)
+    }
+
+    /// [`ShadowRealm.prototype.importValue ( specifier, exportName )`][spec].
+    ///
+    /// Loads the module identified by `specifier` in the realm of this `ShadowRealm`, returning a
+    /// promise that resolves to the wrapped value of its export named `exportName`.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.importvalue
+    fn import_value(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::validate(this, "importValue")?;
+
+        // 3. Let specifierString be ? ToString(specifier).
+        let specifier = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. If exportName is not a String, throw a TypeError exception.
+        let Some(export_name) = args.get_or_undefined(1).as_string().cloned() else {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.importValue: export name must be a string")
+                .into());
+        };
+
+        // 5. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 6. Let evalRealm be O.[[ShadowRealm]].
+        // 7. Return ShadowRealmImportValue(specifierString, exportName, callerRealm, evalRealm).
+        Ok(Self::shadow_realm_import_value(
+            specifier,
+            export_name,
+            &caller_realm,
+            eval_realm,
+            context,
+        )
+        .into())
+    }
+
+    /// Abstract operation [`ShadowRealmImportValue ( specifierString, exportNameString, callerRealm, evalRealm )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealmimportvalue
+    fn shadow_realm_import_value(
+        specifier: JsString,
+        export_name: JsString,
+        caller_realm: &Realm,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsObject {
+        // 1. Let innerCapability be ! NewPromiseCapability(%Promise%).
+        let inner_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("operation cannot fail for the %Promise% intrinsic");
+
+        // 2. Let runningContext be the running execution context.
+        // 3. If runningContext is not already suspended, suspend runningContext.
+        // 4. Let evalContext be GetShadowRealmContext(evalRealm, true).
+        // 5. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+        let caller = context.enter_realm(eval_realm.clone());
+
+        // 6. Let referrer be evalContext's Realm component.
+        // 7. Perform HostLoadImportedModule(referrer, specifierString, empty, innerCapability).
+        //    Note: `ShadowRealm.prototype.importValue` doesn't support import attributes, so
+        //    the module request has no attributes.
+        load_dynamic_import(
+            Referrer::Realm(eval_realm),
+            ModuleRequest::from_specifier(specifier),
+            inner_capability.clone(),
+            context,
+        );
+
+        // 8. Suspend evalContext and remove it from the execution context stack.
+        // 9. Resume runningContext as the running execution context.
+        context.enter_realm(caller);
+
+        // 10. Let steps be the steps of an ExportGetter function as described below.
+        // 11. Let onFulfilled be CreateBuiltinFunction(steps, 1, "", « [[ExportNameString]] », callerRealm).
+        // 12. Set onFulfilled.[[ExportNameString]] to exportNameString.
+        let on_fulfilled = FunctionObjectBuilder::new(
+            caller_realm,
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, (export_name, caller_realm), context| {
+                    // An ExportGetter function is an anonymous built-in function with a [[ExportNameString]] internal slot.
+                    // When an ExportGetter function is called with argument exports, it performs the following steps:
+                    // 1. Assert: exports is a module namespace exotic object.
+                    let exports = args
+                        .get_or_undefined(0)
+                        .as_object()
+                        .expect("exports must be a module namespace object");
+
+                    // 2. Let f be the active function object.
+                    // 3. Let string be f.[[ExportNameString]].
+                    // 4. Assert: string is a String.
+                    let key = PropertyKey::from(export_name.clone());
+
+                    // 5. Let hasOwn be ? HasOwnProperty(exports, string).
+                    // 6. If hasOwn is false, throw a TypeError exception.
+                    if !exports.has_own_property(key.clone(), context)? {
+                        return Err(JsNativeError::typ()
+                            .with_message(format!(
+                                "ShadowRealm.prototype.importValue: module does not export `{}`",
+                                export_name.to_std_string_escaped()
+                            ))
+                            .into());
+                    }
+
+                    // 7. Let value be ? Get(exports, string).
+                    let value = exports.get(key, context)?;
+
+                    // 8. Let realm be f.[[Realm]].
+                    // 9. Return ? GetWrappedValue(realm, value).
+                    get_wrapped_value(caller_realm, &value, context)
+                },
+                (export_name, caller_realm.clone()),
+            ),
+        )
+        .length(1)
+        .build();
+
+        // 13. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("operation cannot fail for the %Promise% intrinsic");
+
+        // 14. Return PerformPromiseThen(innerCapability.[[Promise]], onFulfilled, callerRealm.[[Intrinsics]].[[%ThrowTypeError%]], promiseCapability).
+        let on_rejected = FunctionObjectBuilder::new(
+            caller_realm,
+            NativeFunction::from_fn_ptr(|_, _, _| {
+                Err(JsNativeError::typ()
+                    .with_message("ShadowRealm.prototype.importValue: could not import the module")
+                    .into())
+            }),
+        )
+        .length(1)
+        .build();
+
+        Promise::perform_promise_then(
+            inner_capability.promise(),
+            Some(on_fulfilled),
+            Some(on_rejected),
+            Some(promise_capability.clone()),
+            context,
+        );
+
+        promise_capability.promise().clone()
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{js_string, run_test_actions, Context, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn evaluate() {
+    run_test_actions([
+        TestAction::run("const r = new ShadowRealm();"),
+        TestAction::assert_eq("r.evaluate('1 + 2')", 3),
+        TestAction::assert_eq("r.evaluate('var x = 5; x')", 5),
+        TestAction::assert_eq("r.evaluate('x * 2')", 10),
+        TestAction::assert_eq("typeof x", js_string!("undefined")),
+        TestAction::assert("r.evaluate('Array') !== Array"),
+    ]);
+}
+
+#[test]
+fn evaluate_errors() {
+    run_test_actions([
+        TestAction::run("const r = new ShadowRealm();"),
+        TestAction::assert_native_error(
+            "ShadowRealm()",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: cannot call constructor without `new`",
+        ),
+        TestAction::assert_native_error(
+            "r.evaluate(1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: source text must be a string",
+        ),
+        TestAction::assert_native_error(
+            "r.evaluate('({})')",
+            JsNativeErrorKind::Type,
+            "cannot pass a non-callable object across a ShadowRealm boundary",
+        ),
+        TestAction::assert_native_error(
+            "r.evaluate('throw new RangeError(\"oops\")')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: an error was thrown inside the ShadowRealm",
+        ),
+        TestAction::assert_native_error(
+            "r.evaluate('null.x')",
+            JsNativeErrorKind::Type,
+            "cannot convert 'null' or 'undefined' to object",
+        ),
+        TestAction::assert(indoc! {r#"
+            try {
+                r.evaluate('null.x');
+                false
+            } catch (e) {
+                e instanceof TypeError
+            }
+        "#}),
+        TestAction::assert(indoc! {r#"
+            try {
+                r.evaluate('new.target');
+                false
+            } catch (e) {
+                e instanceof SyntaxError
+            }
+        "#}),
+    ]);
+}
+
+#[test]
+fn wrapped_functions() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const r = new ShadowRealm();
+            const add = r.evaluate('(function sum(a, b) { return a + b; })');
+            const call = r.evaluate('(f) => f(20)');
+            const leak = r.evaluate('(f) => f()');
+        "#}),
+        TestAction::assert_eq("add(1, 2)", 3),
+        TestAction::assert_eq("add.name", js_string!("sum")),
+        TestAction::assert_eq("add.length", 2),
+        TestAction::assert("Object.getPrototypeOf(add) === Function.prototype"),
+        TestAction::assert_eq("call((x) => x + 1)", 21),
+        TestAction::assert_native_error(
+            "leak(() => ({}))",
+            JsNativeErrorKind::Type,
+            "cannot pass a non-callable object across a ShadowRealm boundary",
+        ),
+        TestAction::assert_native_error(
+            "add({})",
+            JsNativeErrorKind::Type,
+            "cannot pass a non-callable object across a ShadowRealm boundary",
+        ),
+    ]);
+}
+
+#[test]
+fn import_value() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const r = new ShadowRealm();
+            let error;
+            r.importValue("./does-not-exist.mjs", "x").catch((e) => { error = e; });
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert("error instanceof TypeError"),
+        TestAction::assert_native_error(
+            "r.importValue('./does-not-exist.mjs', 1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.importValue: export name must be a string",
+        ),
+        TestAction::assert_native_error(
+            "ShadowRealm.prototype.importValue.call({}, '', '')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.importValue: `this` is not a ShadowRealm object",
+        ),
+    ]);
+}
@@ -0,0 +1,283 @@
+//! Boa's implementation of the `ShadowRealm` proposal's wrapped function exotic objects.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects
+
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    builtins::function::set_function_name,
+    object::{
+        internal_methods::{CallValue, InternalObjectMethods, ORDINARY_INTERNAL_METHODS},
+        JsData,
+    },
+    property::PropertyDescriptor,
+    realm::Realm,
+    string::utf16,
+    value::IntegerOrInfinity,
+    Context, JsError, JsNativeError, JsObject, JsResult, JsString, JsValue,
+};
+
+/// A function object that wraps a callable from another realm.
+///
+/// Values that cross the boundary of a `ShadowRealm` are either primitives or wrapped functions, which
+/// makes it impossible for objects of one realm to leak into the other.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct WrappedFunction {
+    target_function: JsObject,
+    realm: Realm,
+}
+
+impl JsData for WrappedFunction {
+    fn internal_methods(&self) -> &'static InternalObjectMethods {
+        static METHODS: InternalObjectMethods = InternalObjectMethods {
+            __call__: wrapped_function_exotic_call,
+            ..ORDINARY_INTERNAL_METHODS
+        };
+
+        &METHODS
+    }
+}
+
+impl WrappedFunction {
+    /// Abstract operation [`WrappedFunctionCreate ( callerRealm, Target )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrappedfunctioncreate
+    pub(crate) fn create(
+        caller_realm: Realm,
+        target: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let internalSlotsList be the internal slots listed in Table 2, plus [[Prototype]] and [[Extensible]].
+        // 2. Let wrapped be MakeBasicObject(internalSlotsList).
+        // 3. Set wrapped.[[Prototype]] to callerRealm.[[Intrinsics]].[[%Function.prototype%]].
+        // 4. Set wrapped.[[Call]] as described in 2.1.
+        // 5. Set wrapped.[[WrappedTargetFunction]] to Target.
+        // 6. Set wrapped.[[Realm]] to callerRealm.
+        let prototype = caller_realm
+            .intrinsics()
+            .constructors()
+            .function()
+            .prototype();
+        let wrapped = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                target_function: target.clone(),
+                realm: caller_realm.clone(),
+            },
+        );
+
+        // 7. Let result be Completion(CopyNameAndLength(wrapped, Target)).
+        // 8. If result is an abrupt completion, throw a TypeError exception.
+        if let Err(err) = copy_name_and_length(&wrapped, target, context) {
+            if !err.is_catchable() {
+                return Err(err);
+            }
+            return Err(JsNativeError::typ()
+                .with_message("could not copy the name and length of the wrapped function")
+                .with_realm(caller_realm)
+                .into());
+        }
+
+        // 9. Return wrapped.
+        Ok(wrapped)
+    }
+
+    /// Gets the realm this wrapped function was created in.
+    pub(crate) const fn realm(&self) -> &Realm {
+        &self.realm
+    }
+}
+
+/// Abstract operation [`GetWrappedValue ( callerRealm, value )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-getwrappedvalue
+pub(crate) fn get_wrapped_value(
+    caller_realm: &Realm,
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If value is an Object, then
+    if let Some(object) = value.as_object() {
+        // a. If IsCallable(value) is false, throw a TypeError exception.
+        if !object.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot pass a non-callable object across a ShadowRealm boundary")
+                .into());
+        }
+
+        // b. Return ? WrappedFunctionCreate(callerRealm, value).
+        return WrappedFunction::create(caller_realm.clone(), object, context).map(Into::into);
+    }
+
+    // 2. Return value.
+    Ok(value.clone())
+}
+
+/// Creates a `TypeError` of `realm` replacing the abrupt completion `err` that crossed a
+/// `ShadowRealm` boundary.
+///
+/// The message of native errors is preserved, since it cannot leak any object from the other realm.
+/// Errors that cannot be caught by ECMAScript code are propagated as is.
+pub(crate) fn boundary_error(err: JsError, realm: Realm, context: &str) -> JsError {
+    if !err.is_catchable() {
+        return err;
+    }
+
+    let message = err.as_native().map_or_else(
+        || format!("{context}: an error was thrown inside the ShadowRealm"),
+        |native| native.message().to_string(),
+    );
+
+    JsNativeError::typ()
+        .with_message(message)
+        .with_realm(realm)
+        .into()
+}
+
+/// Abstract operation [`CopyNameAndLength ( F, Target [ , prefix [ , argCount ] ] )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-copynameandlength
+fn copy_name_and_length(f: &JsObject, target: &JsObject, context: &mut Context) -> JsResult<()> {
+    // 1. If argCount is undefined, then set argCount to 0.
+    // 2. Let L be 0.
+    let mut l = JsValue::new(0);
+
+    // 3. Let targetHasLength be ? HasOwnProperty(Target, "length").
+    // 4. If targetHasLength is true, then
+    if target.has_own_property(utf16!("length"), context)? {
+        // a. Let targetLen be ? Get(Target, "length").
+        let target_len = target.get(utf16!("length"), context)?;
+        // b. If Type(targetLen) is Number, then
+        if target_len.is_number() {
+            match target_len
+                .to_integer_or_infinity(context)
+                .expect("to_integer_or_infinity cannot fail for a number")
+            {
+                // i. If targetLen is +∞𝔽, set L to +∞.
+                IntegerOrInfinity::PositiveInfinity => l = f64::INFINITY.into(),
+                // ii. Else if targetLen is -∞𝔽, set L to 0.
+                IntegerOrInfinity::NegativeInfinity => {}
+                // iii. Else,
+                IntegerOrInfinity::Integer(target_len) => {
+                    // 1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+                    // 2. Assert: targetLenAsInt is finite.
+                    // 3. Set L to max(targetLenAsInt - argCount, 0).
+                    l = target_len.max(0).into();
+                }
+            }
+        }
+    }
+
+    // 5. Perform SetFunctionLength(F, L).
+    f.define_property_or_throw(
+        utf16!("length"),
+        PropertyDescriptor::builder()
+            .value(l)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )
+    .expect("defining the `length` property for a new object should not fail");
+
+    // 6. Let targetName be ? Get(Target, "name").
+    let target_name = target.get(utf16!("name"), context)?;
+
+    // 7. If Type(targetName) is not String, set targetName to the empty String.
+    let target_name = target_name
+        .as_string()
+        .map_or_else(JsString::default, Clone::clone);
+
+    // 8. Perform SetFunctionName(F, targetName, prefix).
+    set_function_name(f, &target_name.into(), None, context);
+
+    Ok(())
+}
+
+/// Internal method [`[[Call]] ( thisArgument, argumentsList )`][spec] for wrapped function exotic
+/// objects.
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects-call-thisargument-argumentslist
+fn wrapped_function_exotic_call(
+    obj: &JsObject,
+    argument_count: usize,
+    context: &mut Context,
+) -> JsResult<CallValue> {
+    let args = context.vm.pop_n_values(argument_count);
+    let _func = context.vm.pop();
+    let this = context.vm.pop();
+
+    let (target, mut realm) = {
+        let wrapped = obj.downcast_ref::<WrappedFunction>().expect(
+            "wrapped function exotic method should only be callable from wrapped functions",
+        );
+        (wrapped.target_function.clone(), wrapped.realm.clone())
+    };
+
+    // 1. Let target be F.[[WrappedTargetFunction]].
+    // 2. Assert: IsCallable(target) is true.
+    // 3. Let callerContext be the running execution context.
+    // 4. NOTE: Any exception objects produced after this point are associated with callerRealm.
+    // 5. Let calleeContext be PrepareForWrappedFunctionCall(F).
+    context.swap_realm(&mut realm);
+
+    // 6. Let result be OrdinaryWrappedFunctionCall(F, thisArgument, argumentsList).
+    let result = ordinary_wrapped_function_call(&target, &this, &args, context);
+
+    // 7. Remove calleeContext from the execution context stack and restore callerContext as the running
+    //    execution context.
+    context.swap_realm(&mut realm);
+
+    // 8. If result.[[Type]] is normal or result.[[Type]] is return, then
+    //     a. Return result.[[Value]].
+    // 9. Else,
+    //     a. Let copiedError be CreateTypeErrorCopy(callerRealm, result.[[Value]]).
+    //     b. Return ThrowCompletion(copiedError).
+    let result =
+        result.map_err(|err| boundary_error(err, realm, "wrapped function call failed"))?;
+
+    context.vm.push(result);
+
+    Ok(CallValue::Complete)
+}
+
+/// Abstract operation [`OrdinaryWrappedFunctionCall ( F, thisArgument, argumentsList )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-ordinary-wrapped-function-call
+fn ordinary_wrapped_function_call(
+    target: &JsObject,
+    this: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let target be F.[[WrappedTargetFunction]].
+    // 2. Assert: IsCallable(target) is true.
+    // 3. Let callerRealm be F.[[Realm]].
+    let caller_realm = context.realm().clone();
+
+    // 4. NOTE: Any exception objects produced after this point are associated with callerRealm.
+    // 5. Let targetRealm be ? GetFunctionRealm(target).
+    let target_realm = target.get_function_realm(context)?;
+
+    // 6. Let wrappedArgs be a new empty List.
+    // 7. For each element arg of argumentsList, do
+    //     a. Let wrappedValue be ? GetWrappedValue(targetRealm, arg).
+    //     b. Append wrappedValue to wrappedArgs.
+    let wrapped_args = args
+        .iter()
+        .map(|arg| get_wrapped_value(&target_realm, arg, context))
+        .collect::<JsResult<Vec<_>>>()?;
+
+    // 8. Let wrappedThisArgument to ? GetWrappedValue(targetRealm, thisArgument).
+    let wrapped_this = get_wrapped_value(&target_realm, this, context)?;
+
+    // 9. Let result be ? Call(target, wrappedThisArgument, wrappedArgs).
+    let result = target.call(&wrapped_this, &wrapped_args, context)?;
+
+    // 10. Return ? GetWrappedValue(callerRealm, result).
+    get_wrapped_value(&caller_realm, &result, context)
+}
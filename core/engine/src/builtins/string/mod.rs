@@ -21,6 +21,7 @@ use crate::{
     string::{CodePoint, Utf16Trim},
     symbol::JsSymbol,
     value::IntegerOrInfinity,
+    vm::check_string_allocation,
     Context, JsArgs, JsResult, JsString, JsValue,
 };
 use boa_profiler::Profiler;
@@ -668,7 +669,9 @@ impl String {
         for arg in args {
             // a. Let nextString be ? ToString(next).
             // b. Set R to the string-concatenation of R and nextString.
-            string = js_string!(&string, &arg.to_string(context)?);
+            let next_string = arg.to_string(context)?;
+            check_string_allocation(string.len() + next_string.len())?;
+            string = js_string!(&string, &next_string);
         }
 
         // 5. Return R.
@@ -708,6 +711,7 @@ impl String {
                     return Ok(js_string!().into());
                 }
                 let n = n as usize;
+                check_string_allocation(n * len)?;
                 let mut result = Vec::with_capacity(n * len);
 
                 std::iter::repeat(&string[..])
@@ -1544,6 +1548,7 @@ impl String {
             }
         };
 
+        check_string_allocation(int_max_length.try_into().unwrap_or(usize::MAX))?;
         let truncated_string_filler = filler.repeat(repetitions as usize);
         let truncated_string_filler = &truncated_string_filler[..fill_len as usize];
 
@@ -51,10 +51,18 @@ type FxDashMap<K, V> = DashMap<K, V, BuildHasherDefault<FxHasher>>;
 // the first option would impact performance for all `JsString`s in general. For those reasons, we
 // opted for the second option, but we should try to optimize this in the future.
 struct GlobalSymbolRegistry {
-    keys: FxDashMap<Box<[u16]>, JsSymbol>,
-    symbols: FxDashMap<JsSymbol, Box<[u16]>>,
+    keys: FxDashMap<Box<[u16]>, RegisteredSymbol>,
+    symbols: FxDashMap<u64, Box<[u16]>>,
 }
 
+/// A symbol created by [`JsSymbol::new_registered`].
+struct RegisteredSymbol(JsSymbol);
+
+// SAFETY: Registered symbols use `Arc` to do the reference counting, making them thread-safe.
+unsafe impl Send for RegisteredSymbol {}
+// SAFETY: Registered symbols use `Arc` to do the reference counting, making them thread-safe.
+unsafe impl Sync for RegisteredSymbol {}
+
 impl GlobalSymbolRegistry {
     fn new() -> Self {
         Self {
@@ -66,20 +74,25 @@ impl GlobalSymbolRegistry {
     fn get_or_create_symbol(&self, key: &JsString) -> JsResult<JsSymbol> {
         let slice = &**key;
         if let Some(symbol) = self.keys.get(slice) {
-            return Ok(symbol.clone());
+            return Ok(symbol.0.clone());
         }
 
-        let symbol = JsSymbol::new(Some(key.clone())).ok_or_else(|| {
+        let symbol = JsSymbol::new_registered(key).ok_or_else(|| {
             JsNativeError::range()
                 .with_message("reached the maximum number of symbols that can be created")
         })?;
-        self.keys.insert(slice.into(), symbol.clone());
-        self.symbols.insert(symbol.clone(), slice.into());
+        self.symbols.insert(symbol.hash(), slice.into());
+        self.keys
+            .insert(slice.into(), RegisteredSymbol(symbol.clone()));
         Ok(symbol)
     }
 
     fn get_key(&self, sym: &JsSymbol) -> Option<JsString> {
-        if let Some(key) = self.symbols.get(sym) {
+        if !sym.is_registered() {
+            return None;
+        }
+
+        if let Some(key) = self.symbols.get(&sym.hash()) {
             return Some(js_string!(&**key));
         }
 
@@ -95,12 +108,15 @@ impl IntrinsicObject for Symbol {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
+        let symbol_async_dispose = JsSymbol::async_dispose();
         let symbol_async_iterator = JsSymbol::async_iterator();
+        let symbol_dispose = JsSymbol::dispose();
         let symbol_has_instance = JsSymbol::has_instance();
         let symbol_is_concat_spreadable = JsSymbol::is_concat_spreadable();
         let symbol_iterator = JsSymbol::iterator();
         let symbol_match = JsSymbol::r#match();
         let symbol_match_all = JsSymbol::match_all();
+        let symbol_metadata = JsSymbol::metadata();
         let symbol_replace = JsSymbol::replace();
         let symbol_search = JsSymbol::search();
         let symbol_species = JsSymbol::species();
@@ -123,11 +139,13 @@ impl IntrinsicObject for Symbol {
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_method(Self::for_, js_string!("for"), 1)
             .static_method(Self::key_for, js_string!("keyFor"), 1)
+            .static_property(js_string!("asyncDispose"), symbol_async_dispose, attribute)
             .static_property(
                 js_string!("asyncIterator"),
                 symbol_async_iterator,
                 attribute,
             )
+            .static_property(js_string!("dispose"), symbol_dispose, attribute)
             .static_property(js_string!("hasInstance"), symbol_has_instance, attribute)
             .static_property(
                 js_string!("isConcatSpreadable"),
@@ -137,6 +155,7 @@ impl IntrinsicObject for Symbol {
             .static_property(js_string!("iterator"), symbol_iterator, attribute)
             .static_property(js_string!("match"), symbol_match, attribute)
             .static_property(js_string!("matchAll"), symbol_match_all, attribute)
+            .static_property(js_string!("metadata"), symbol_metadata, attribute)
             .static_property(js_string!("replace"), symbol_replace, attribute)
             .static_property(js_string!("search"), symbol_search, attribute)
             .static_property(js_string!("species"), symbol_species, attribute)
@@ -185,6 +185,7 @@ impl IntrinsicObject for Duration {
             .method(Self::total, js_string!("total"), 1)
             .method(Self::to_string, js_string!("toString"), 1)
             .method(Self::to_json, js_string!("toJSON"), 0)
+            .method(Self::to_locale_string, js_string!("toLocaleString"), 0)
             .build();
     }
 
@@ -767,6 +768,70 @@ impl Duration {
             .with_message("not yet implemented.")
             .into())
     }
+
+    /// 7.3.24 `Temporal.Duration.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("this value must be a Duration object.")
+            })?
+            .inner;
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::{duration_format::DurationRecord, DurationFormat};
+
+            // NOTE: This follows the `Intl.DurationFormat` proposal, which replaces this method
+            // when ECMA-402 is included.
+            // 1. Let df be ? Construct(%DurationFormat%, « locales, options »).
+            let df = context
+                .intrinsics()
+                .constructors()
+                .duration_format()
+                .constructor()
+                .construct(
+                    &[
+                        args.get_or_undefined(0).clone(),
+                        args.get_or_undefined(1).clone(),
+                    ],
+                    None,
+                    context,
+                )?
+                .downcast::<DurationFormat>()
+                .expect("`Intl.DurationFormat` must construct a `DurationFormat` object");
+
+            // 2. Return FormatDurationFormatPattern(df, duration).
+            let record = DurationRecord::new([
+                duration.years(),
+                duration.months(),
+                duration.weeks(),
+                duration.days(),
+                duration.hours(),
+                duration.minutes(),
+                duration.seconds(),
+                duration.milliseconds(),
+                duration.microseconds(),
+                duration.nanoseconds(),
+            ]);
+            DurationFormat::format_duration(&df, &record, context).map(Into::into)
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = duration;
+
+            // 3. Return TemporalDurationToString(duration.[[Years]], [...], "auto").
+            Self::to_string(this, args, context)
+        }
+    }
 }
 
 // -- Duration Abstract Operations --
@@ -25,3 +25,18 @@ fn duration_abs() {
         TestAction::assert_eq("abs.milliseconds", 0),
     ]);
 }
+
+#[cfg(feature = "intl")]
+#[test]
+fn duration_to_locale_string() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Temporal.Duration(0, 0, 0, 1, 2).toLocaleString('en', { style: 'long' })",
+            crate::js_string!("1 day, 2 hours"),
+        ),
+        TestAction::assert_eq(
+            "new Temporal.Duration(0, 0, 0, 0, 1, 2, 3).toLocaleString('en', { style: 'digital' })",
+            crate::js_string!("1:02:03"),
+        ),
+    ]);
+}
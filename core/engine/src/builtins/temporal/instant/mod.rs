@@ -57,7 +57,7 @@ impl IntrinsicObject for Instant {
             .name(js_string!("get epochNanoseconds"))
             .build();
 
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .property(
                 JsSymbol::to_string_tag(),
                 Self::NAME,
@@ -98,8 +98,12 @@ impl IntrinsicObject for Instant {
                 Self::to_zoned_date_time_iso,
                 js_string!("toZonedDateTimeISO"),
                 1,
-            )
-            .build();
+            );
+
+        #[cfg(feature = "intl")]
+        let builder = builder.method(Self::to_locale_string, js_string!("toLocaleString"), 0);
+
+        builder.build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -456,6 +460,41 @@ impl Instant {
             .with_message("not yet implemented.")
             .into())
     }
+
+    /// 8.3.14 `Temporal.Instant.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    #[cfg(feature = "intl")]
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        use crate::builtins::intl::date_time_format::{DateTimeFormat, FormatDefaults, FormatType};
+
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let epoch_milliseconds = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("the this object must be an instant object.")
+            })?
+            .inner
+            .epoch_milliseconds();
+
+        // 3. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, any, all).
+        let date_format = DateTimeFormat::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            FormatType::Any,
+            FormatDefaults::All,
+            context,
+        )?;
+
+        // 4. Return ? FormatDateTime(dateFormat, instant).
+        date_format
+            .format_epoch_milliseconds(epoch_milliseconds)
+            .map(Into::into)
+    }
 }
 
 // -- Instant Abstract Operations --
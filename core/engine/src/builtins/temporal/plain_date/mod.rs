@@ -118,7 +118,7 @@ impl IntrinsicObject for PlainDate {
             .name(js_string!("get inLeapYear"))
             .build();
 
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .property(
                 JsSymbol::to_string_tag(),
                 Self::NAME,
@@ -213,8 +213,12 @@ impl IntrinsicObject for PlainDate {
             .method(Self::with_calendar, js_string!("withCalendar"), 1)
             .method(Self::until, js_string!("until"), 2)
             .method(Self::since, js_string!("since"), 2)
-            .method(Self::equals, js_string!("equals"), 1)
-            .build();
+            .method(Self::equals, js_string!("equals"), 1);
+
+        #[cfg(feature = "intl")]
+        let builder = builder.method(Self::to_locale_string, js_string!("toLocaleString"), 0);
+
+        builder.build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -553,6 +557,55 @@ impl PlainDate {
             .with_message("not yet implemented.")
             .into())
     }
+
+    /// `Temporal.PlainDate.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    #[cfg(feature = "intl")]
+    fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        use crate::builtins::intl::date_time_format::{
+            DateTimeFormat, FormatDefaults, FormatType, IsoDateTimeFields,
+        };
+
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("the this object must be a PlainDate object.")
+            })?
+            .inner
+            .clone();
+
+        // 3. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, date, date).
+        let date_format = DateTimeFormat::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            FormatType::Date,
+            FormatDefaults::Date,
+            context,
+        )?;
+
+        // 4. Return ? FormatDateTime(dateFormat, temporalDate).
+        let calendar = date.calendar().identifier(context)?;
+        date_format
+            .format_plain(
+                &calendar,
+                IsoDateTimeFields {
+                    year: date.iso_year(),
+                    month: date.iso_month(),
+                    day: date.iso_day(),
+                    hour: 0,
+                    minute: 0,
+                    second: 0,
+                    nanosecond: 0,
+                },
+            )
+            .map(Into::into)
+    }
 }
 
 // -- `PlainDate` Abstract Operations --
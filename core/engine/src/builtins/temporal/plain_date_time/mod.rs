@@ -145,7 +145,7 @@ impl IntrinsicObject for PlainDateTime {
             .name(js_string!("get inLeapYear"))
             .build();
 
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .property(
                 JsSymbol::to_string_tag(),
                 Self::NAME,
@@ -265,8 +265,12 @@ impl IntrinsicObject for PlainDateTime {
                 Some(get_in_leap_year),
                 None,
                 Attribute::CONFIGURABLE,
-            )
-            .build();
+            );
+
+        #[cfg(feature = "intl")]
+        let builder = builder.method(Self::to_locale_string, js_string!("toLocaleString"), 0);
+
+        builder.build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -657,6 +661,61 @@ impl PlainDateTime {
     }
 }
 
+// ==== `PlainDateTime` method implementations ====
+
+impl PlainDateTime {
+    /// `Temporal.PlainDateTime.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    #[cfg(feature = "intl")]
+    fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        use crate::builtins::intl::date_time_format::{
+            DateTimeFormat, FormatDefaults, FormatType, IsoDateTimeFields,
+        };
+
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("the this object must be a PlainDateTime object.")
+            })?
+            .inner
+            .clone();
+
+        // 3. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, any, all).
+        let date_format = DateTimeFormat::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            FormatType::Any,
+            FormatDefaults::All,
+            context,
+        )?;
+
+        // 4. Return ? FormatDateTime(dateFormat, dateTime).
+        let calendar = date_time.calendar().identifier(context)?;
+        date_format
+            .format_plain(
+                &calendar,
+                IsoDateTimeFields {
+                    year: date_time.iso_year(),
+                    month: date_time.iso_month(),
+                    day: date_time.iso_day(),
+                    hour: date_time.hour(),
+                    minute: date_time.minute(),
+                    second: date_time.second(),
+                    nanosecond: u32::from(date_time.millisecond()) * 1_000_000
+                        + u32::from(date_time.microsecond()) * 1_000
+                        + u32::from(date_time.nanosecond()),
+                },
+            )
+            .map(Into::into)
+    }
+}
+
 // ==== `PlainDateTime` Abstract Operations` ====
 
 pub(crate) fn create_temporal_datetime(
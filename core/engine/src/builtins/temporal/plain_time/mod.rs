@@ -65,7 +65,7 @@ impl IntrinsicObject for PlainTime {
             .name(js_string!("get nanosecond"))
             .build();
 
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .property(
                 JsSymbol::to_string_tag(),
                 Self::NAME,
@@ -106,8 +106,12 @@ impl IntrinsicObject for PlainTime {
             .method(Self::subtract, js_string!("subtract"), 1)
             .method(Self::round, js_string!("round"), 1)
             .method(Self::get_iso_fields, js_string!("getISOFields"), 0)
-            .method(Self::value_of, js_string!("valueOf"), 0)
-            .build();
+            .method(Self::value_of, js_string!("valueOf"), 0);
+
+        #[cfg(feature = "intl")]
+        let builder = builder.method(Self::to_locale_string, js_string!("toLocaleString"), 0);
+
+        builder.build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -439,6 +443,55 @@ impl PlainTime {
             .with_message("valueOf cannot be called on PlainTime.")
             .into())
     }
+
+    /// 4.3.20 `Temporal.PlainTime.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    #[cfg(feature = "intl")]
+    fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        use crate::builtins::intl::date_time_format::{
+            DateTimeFormat, FormatDefaults, FormatType, IsoDateTimeFields,
+        };
+
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("the this object must be a PlainTime object.")
+            })?
+            .inner;
+
+        // 3. Let timeFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, time, time).
+        let time_format = DateTimeFormat::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            FormatType::Time,
+            FormatDefaults::Time,
+            context,
+        )?;
+
+        // 4. Return ? FormatDateTime(timeFormat, temporalTime).
+        time_format
+            .format_plain(
+                "iso8601",
+                IsoDateTimeFields {
+                    year: 1970,
+                    month: 1,
+                    day: 1,
+                    hour: time.hour(),
+                    minute: time.minute(),
+                    second: time.second(),
+                    nanosecond: u32::from(time.millisecond()) * 1_000_000
+                        + u32::from(time.microsecond()) * 1_000
+                        + u32::from(time.nanosecond()),
+                },
+            )
+            .map(Into::into)
+    }
 }
 
 // ==== PlainTime Abstract Operations ====
@@ -0,0 +1,536 @@
+use boa_gc::{Finalize, Trace, WeakGc};
+use boa_profiler::Profiler;
+
+use super::{can_be_held_weakly, WeakKey};
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    job::NativeJob,
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsFunction, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::common::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+/// A `FinalizationRegistry` [cell][spec].
+///
+/// The target and the unregister token are held weakly, while the held value is kept alive by the
+/// registry until the cleanup callback is called with it.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-finalization-registry-instances
+#[derive(Trace, Finalize)]
+struct Cell {
+    target: WeakKey,
+    held_value: JsValue,
+    unregister_token: Option<WeakKey>,
+}
+
+impl Cell {
+    /// Checks if the `[[WeakRefTarget]]` of this cell is empty.
+    fn is_target_empty(&self) -> bool {
+        !self.target.is_upgradable()
+    }
+}
+
+/// Boa's implementation of ECMAScript's `FinalizationRegistry` builtin object.
+///
+/// A `FinalizationRegistry` allows requesting a cleanup callback to be called with a held value
+/// some time after a registered target object gets collected by the garbage collector.
+///
+/// More Information:
+///  - [ECMAScript Reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry
+#[derive(Trace, Finalize, JsData)]
+pub(crate) struct FinalizationRegistry {
+    realm: Realm,
+    cleanup_callback: JsFunction,
+    cells: Vec<Cell>,
+
+    /// Whether a cleanup job for this registry is currently waiting on the job queue.
+    cleanup_pending: bool,
+}
+
+impl std::fmt::Debug for FinalizationRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FinalizationRegistry")
+            .field("cleanup_callback", &self.cleanup_callback)
+            .field("cells", &self.cells.len())
+            .field("cleanup_pending", &self.cleanup_pending)
+            .finish_non_exhaustive()
+    }
+}
+
+impl IntrinsicObject for FinalizationRegistry {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::register, js_string!("register"), 2)
+            .method(Self::unregister, js_string!("unregister"), 1)
+            .method(Self::cleanup_some, js_string!("cleanupSome"), 0)
+            .build();
+    }
+}
+
+impl BuiltInObject for FinalizationRegistry {
+    const NAME: JsString = StaticJsStrings::FINALIZATION_REGISTRY;
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE.union(Attribute::CONFIGURABLE);
+}
+
+impl BuiltInConstructor for FinalizationRegistry {
+    /// The amount of arguments the `FinalizationRegistry` constructor takes.
+    const LENGTH: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::finalization_registry;
+
+    /// Constructor [`FinalizationRegistry ( cleanupCallback )`][cons]
+    ///
+    /// [cons]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("FinalizationRegistry: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError exception.
+        let cleanup_callback = args
+            .first()
+            .and_then(JsValue::as_callable)
+            .cloned()
+            .and_then(JsFunction::from_object)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("FinalizationRegistry: cleanup callback must be callable")
+            })?;
+
+        // 3. Let finalizationRegistry be ? OrdinaryCreateFromConstructor(NewTarget, "%FinalizationRegistry.prototype%", « [[Realm]], [[CleanupCallback]], [[Cells]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::finalization_registry,
+            context,
+        )?;
+
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to HostMakeJobCallback(cleanupCallback).
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        let registry = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                realm: context.realm().clone(),
+                cleanup_callback,
+                cells: Vec::new(),
+                cleanup_pending: false,
+            },
+        );
+
+        context
+            .finalization_registries
+            .push(WeakGc::new(registry.inner()));
+
+        // 8. Return finalizationRegistry.
+        Ok(registry.into())
+    }
+}
+
+impl FinalizationRegistry {
+    /// Method [`FinalizationRegistry.prototype.register ( target, heldValue [ , unregisterToken ] )`][spec].
+    ///
+    /// Registers `target` on the registry, calling the cleanup callback with `heldValue` some
+    /// time after `target` is collected.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    pub(crate) fn register(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let mut registry = this
+            .as_object()
+            .and_then(JsObject::downcast_mut::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.register: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        let target = args.get_or_undefined(0);
+        let held_value = args.get_or_undefined(1);
+        let unregister_token = args.get_or_undefined(2);
+
+        // 3. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let weak_target = WeakKey::new(target).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "FinalizationRegistry.prototype.register: `target` must be an object or a non-registered symbol",
+            )
+        })?;
+
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        if JsValue::same_value(target, held_value) {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "FinalizationRegistry.prototype.register: `heldValue` cannot be the same as `target`",
+                )
+                .into());
+        }
+
+        // 5. If CanBeHeldWeakly(unregisterToken) is false, then
+        let unregister_token = match WeakKey::new(unregister_token) {
+            Some(token) => Some(token),
+            // b. Set unregisterToken to empty.
+            None if unregister_token.is_undefined() => None,
+            // a. If unregisterToken is not undefined, throw a TypeError exception.
+            None => {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "FinalizationRegistry.prototype.register: `unregisterToken` must be an object, a non-registered symbol or undefined",
+                    )
+                    .into());
+            }
+        };
+
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        registry.cells.push(Cell {
+            target: weak_target,
+            held_value: held_value.clone(),
+            unregister_token,
+        });
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Method [`FinalizationRegistry.prototype.unregister ( unregisterToken )`][spec].
+    ///
+    /// Removes all cells registered with `unregisterToken`, returning `true` if any cell was
+    /// removed.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    pub(crate) fn unregister(
+        this: &JsValue,
+        args: &[JsValue],
+        _: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let mut registry = this
+            .as_object()
+            .and_then(JsObject::downcast_mut::<Self>)
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.unregister: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        // 3. If CanBeHeldWeakly(unregisterToken) is false, throw a TypeError exception.
+        let unregister_token = args.get_or_undefined(0);
+        if !can_be_held_weakly(unregister_token) {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "FinalizationRegistry.prototype.unregister: `unregisterToken` must be an object or a non-registered symbol",
+                )
+                .into());
+        }
+
+        // 4. Let removed be false.
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        //     a. If cell.[[UnregisterToken]] is not empty and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+        //         i. Remove cell from finalizationRegistry.[[Cells]].
+        //         ii. Set removed to true.
+        let len = registry.cells.len();
+        registry.cells.retain(|cell| {
+            !cell
+                .unregister_token
+                .as_ref()
+                .and_then(WeakKey::upgrade)
+                .is_some_and(|token| JsValue::same_value(&token, unregister_token))
+        });
+
+        // 6. Return removed.
+        Ok((registry.cells.len() != len).into())
+    }
+
+    /// Method [`FinalizationRegistry.prototype.cleanupSome ( [ callback ] )`][spec].
+    ///
+    /// Synchronously calls `callback` (or the cleanup callback of the registry if `callback` is
+    /// not provided) with the held values of all the collected targets.
+    ///
+    /// [spec]: https://tc39.es/proposal-cleanup-some/#sec-finalization-registry.prototype.cleanupSome
+    pub(crate) fn cleanup_some(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this
+            .as_object()
+            .filter(|obj| obj.is::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "FinalizationRegistry.prototype.cleanupSome: expected `this` to be a `FinalizationRegistry` object",
+                )
+            })?;
+
+        // 3. If callback is present and IsCallable(callback) is false, throw a TypeError exception.
+        let callback = match args.get_or_undefined(0) {
+            JsValue::Undefined => None,
+            callback => Some(
+                callback
+                    .as_callable()
+                    .cloned()
+                    .and_then(JsFunction::from_object)
+                    .ok_or_else(|| {
+                        JsNativeError::typ().with_message(
+                            "FinalizationRegistry.prototype.cleanupSome: `callback` must be callable",
+                        )
+                    })?,
+            ),
+        };
+
+        // 4. Perform ? CleanupFinalizationRegistry(finalizationRegistry, callback).
+        Self::cleanup(registry, callback, context)?;
+
+        // 5. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Abstract operation [`CleanupFinalizationRegistry ( finalizationRegistry [ , callback ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-cleanup-finalization-registry
+    fn cleanup(
+        registry: &JsObject,
+        callback: Option<JsFunction>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Assert: finalizationRegistry has [[Cells]] and [[CleanupCallback]] internal slots.
+        // 2. Let callback be finalizationRegistry.[[CleanupCallback]].
+        let callback = match callback {
+            Some(callback) => callback,
+            None => registry
+                .downcast_ref::<Self>()
+                .expect("must be a `FinalizationRegistry` object")
+                .cleanup_callback
+                .clone(),
+        };
+
+        // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that cell.[[WeakRefTarget]] is empty, an implementation may perform the following steps:
+        loop {
+            // a. Choose any such cell.
+            // b. Remove cell from finalizationRegistry.[[Cells]].
+            let held_value = {
+                let mut registry = registry
+                    .downcast_mut::<Self>()
+                    .expect("must be a `FinalizationRegistry` object");
+                let Some(index) = registry.cells.iter().position(Cell::is_target_empty) else {
+                    break;
+                };
+                registry.cells.swap_remove(index).held_value.clone()
+            };
+
+            // c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
+            //
+            // Note: `HostCallJobCallback` consumes its `JobCallback`, so the callback gets wrapped
+            // by `HostMakeJobCallback` each time it needs to be called.
+            let callback = context
+                .host_hooks()
+                .make_job_callback(callback.clone(), context);
+            context.host_hooks().call_job_callback(
+                callback,
+                &JsValue::undefined(),
+                &[held_value],
+                context,
+            )?;
+        }
+
+        // 4. Return unused.
+        Ok(())
+    }
+
+    /// Enqueues a cleanup job for every registry tracked by `context` that has at least one cell
+    /// with a collected target.
+    ///
+    /// This is Boa's hook for the "when the garbage collector determines that a target is
+    /// unreachable" step of the [weak reference execution semantics][spec], and it's called
+    /// before running the jobs of the job queue.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakref-execution
+    pub(crate) fn enqueue_cleanup_jobs(context: &mut Context) {
+        let registries = std::mem::take(&mut context.finalization_registries);
+        let mut live = Vec::with_capacity(registries.len());
+
+        for weak in registries {
+            let Some(registry) = weak.upgrade() else {
+                continue;
+            };
+            live.push(weak);
+
+            let registry = JsObject::from(registry);
+            let realm = {
+                let mut data = registry
+                    .downcast_mut::<Self>()
+                    .expect("must be a `FinalizationRegistry` object");
+                if data.cleanup_pending || !data.cells.iter().any(Cell::is_target_empty) {
+                    continue;
+                }
+                data.cleanup_pending = true;
+                data.realm.clone()
+            };
+
+            // HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )
+            // 1. Let cleanupJob be a new Job Abstract Closure with no parameters that captures
+            //    finalizationRegistry and performs the following steps when called:
+            let job = NativeJob::with_realm(
+                move |context| {
+                    registry
+                        .downcast_mut::<Self>()
+                        .expect("must be a `FinalizationRegistry` object")
+                        .cleanup_pending = false;
+
+                    // a. Let cleanupResult be Completion(CleanupFinalizationRegistry(finalizationRegistry)).
+                    // b. If cleanupResult is an abrupt completion, perform any host-defined steps for reporting the error.
+                    // c. Return unused.
+                    Self::cleanup(&registry, None, context)?;
+                    Ok(JsValue::undefined())
+                },
+                realm,
+                context,
+            );
+
+            // 2. An implementation of HostEnqueueFinalizationRegistryCleanupJob schedules cleanupJob
+            //    to be performed at some future time, if possible.
+            context
+                .job_queue()
+                .enqueue_finalization_registry_cleanup_job(job, context);
+        }
+
+        live.append(&mut context.finalization_registries);
+        context.finalization_registries = live;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+    #[test]
+    fn finalization_registry_cleanup() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var held = [];
+                var registry = new FinalizationRegistry(v => held.push(v));
+                {
+                    let obj = {};
+                    registry.register(obj, "collected");
+                }
+                var alive = {};
+                registry.register(alive, "alive");
+            "#}),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("held.length", 1),
+            TestAction::assert_eq("held[0]", js_string!("collected")),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_unregister() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var held = [];
+                var registry = new FinalizationRegistry(v => held.push(v));
+                var token = {};
+                {
+                    let obj = {};
+                    registry.register(obj, "collected", token);
+                }
+            "#}),
+            TestAction::assert("registry.unregister(token)"),
+            TestAction::assert("!registry.unregister(token)"),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("held.length", 0),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_cleanup_some() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var held = [];
+                var registry = new FinalizationRegistry(() => { throw new Error("unreachable") });
+                {
+                    let obj = {};
+                    registry.register(obj, 42);
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+            }),
+            TestAction::run("registry.cleanupSome(v => held.push(v))"),
+            TestAction::assert_eq("held.length", 1),
+            TestAction::assert_eq("held[0]", 42),
+            TestAction::assert_eq("registry.cleanupSome()", JsValue::undefined()),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_symbol() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var held = [];
+                var registry = new FinalizationRegistry(v => held.push(v));
+                var token = Symbol("token");
+                {
+                    registry.register(Symbol("collected"), "collected");
+                    registry.register(Symbol("unregistered"), "unregistered", token);
+                }
+                registry.register(Symbol.iterator, "well known");
+            "#}),
+            TestAction::assert("registry.unregister(token)"),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("held.length", 1),
+            TestAction::assert_eq("held[0]", js_string!("collected")),
+            TestAction::assert_native_error(
+                "registry.register(Symbol.for('registered'), 0)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.register: `target` must be an object or a non-registered symbol",
+            ),
+        ]);
+    }
+}
@@ -0,0 +1,208 @@
+use boa_gc::{Finalize, Gc, Trace, WeakGc};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    object::{ErasedVTableObject, JsObject},
+    symbol::{self, JsSymbol},
+    JsData, JsValue,
+};
+
+/// Abstract operation [`CanBeHeldWeakly ( v )`][spec].
+///
+/// Returns `true` if `v` is suitable for use as a weak reference, i.e. it can be a `WeakMap` key,
+/// a `WeakSet` value, a `WeakRef` target or a `FinalizationRegistry` target.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canbeheldweakly
+pub(crate) fn can_be_held_weakly(v: &JsValue) -> bool {
+    match v {
+        // 1. If v is an Object, return true.
+        JsValue::Object(_) => true,
+        // 2. If v is a Symbol and KeyForSymbol(v) is undefined, return true.
+        JsValue::Symbol(sym) => !sym.is_registered(),
+        // 3. Return false.
+        _ => false,
+    }
+}
+
+/// The strong handle of a value that can be held weakly.
+enum Key {
+    Object(Gc<ErasedVTableObject>),
+    Symbol(Gc<symbol::Inner>),
+    WellKnown(JsSymbol),
+}
+
+impl Key {
+    fn new(v: &JsValue) -> Option<Self> {
+        match v {
+            JsValue::Object(obj) => Some(Self::Object(obj.inner().clone())),
+            JsValue::Symbol(sym) => match sym.to_gc() {
+                Some(gc) => Some(Self::Symbol(gc)),
+                None if !sym.is_registered() => Some(Self::WellKnown(sym.clone())),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// A weak reference to a value for which [`can_be_held_weakly`] returns `true`.
+#[derive(Trace, Finalize, JsData)]
+pub(crate) enum WeakKey {
+    Object(WeakGc<ErasedVTableObject>),
+    Symbol(WeakGc<symbol::Inner>),
+    /// Well known symbols are shared by all realms and never collected, so there's no need to
+    /// hold them weakly.
+    WellKnown(JsSymbol),
+}
+
+impl WeakKey {
+    /// Creates a new weak reference to `v`, or returns `None` if `v` cannot be held weakly.
+    pub(crate) fn new(v: &JsValue) -> Option<Self> {
+        Some(match Key::new(v)? {
+            Key::Object(obj) => Self::Object(WeakGc::new(&obj)),
+            Key::Symbol(sym) => Self::Symbol(WeakGc::new(&sym)),
+            Key::WellKnown(sym) => Self::WellKnown(sym),
+        })
+    }
+
+    /// Upgrades the weak reference to its value, or returns `None` if it was collected.
+    pub(crate) fn upgrade(&self) -> Option<JsValue> {
+        match self {
+            Self::Object(obj) => obj.upgrade().map(|obj| JsObject::from(obj).into()),
+            Self::Symbol(sym) => sym.upgrade().map(|sym| JsSymbol::from_gc(sym).into()),
+            Self::WellKnown(sym) => Some(sym.clone().into()),
+        }
+    }
+
+    /// Checks if the value of the weak reference is still alive.
+    pub(crate) fn is_upgradable(&self) -> bool {
+        match self {
+            Self::Object(obj) => obj.is_upgradable(),
+            Self::Symbol(sym) => sym.is_upgradable(),
+            Self::WellKnown(_) => true,
+        }
+    }
+}
+
+/// A map whose keys are values for which [`can_be_held_weakly`] returns `true`.
+///
+/// Entries are removed from the map once their key gets collected.
+#[derive(Trace, Finalize, JsData)]
+pub(crate) struct WeakKeyMap<V: Trace + Clone + 'static> {
+    objects: boa_gc::WeakMap<ErasedVTableObject, V>,
+    symbols: boa_gc::WeakMap<symbol::Inner, V>,
+    well_known: FxHashMap<JsSymbol, V>,
+}
+
+impl<V: Trace + Clone + 'static> WeakKeyMap<V> {
+    /// Creates a new, empty `WeakKeyMap`.
+    pub(crate) fn new() -> Self {
+        Self {
+            objects: boa_gc::WeakMap::new(),
+            symbols: boa_gc::WeakMap::new(),
+            well_known: FxHashMap::default(),
+        }
+    }
+
+    /// Inserts a new entry into the map, replacing the value of the entry with the same `key`.
+    ///
+    /// Does nothing if `key` cannot be held weakly.
+    pub(crate) fn insert(&mut self, key: &JsValue, value: V) {
+        match Key::new(key) {
+            Some(Key::Object(obj)) => self.objects.insert(&obj, value),
+            Some(Key::Symbol(sym)) => self.symbols.insert(&sym, value),
+            Some(Key::WellKnown(sym)) => {
+                self.well_known.insert(sym, value);
+            }
+            None => {}
+        }
+    }
+
+    /// Removes the entry with the given `key`, returning its value if it was in the map.
+    pub(crate) fn remove(&mut self, key: &JsValue) -> Option<V> {
+        match Key::new(key)? {
+            Key::Object(obj) => self.objects.remove(&obj),
+            Key::Symbol(sym) => self.symbols.remove(&sym),
+            Key::WellKnown(sym) => self.well_known.remove(&sym),
+        }
+    }
+
+    /// Gets the value of the entry with the given `key`.
+    pub(crate) fn get(&self, key: &JsValue) -> Option<V> {
+        match Key::new(key)? {
+            Key::Object(obj) => self.objects.get(&obj),
+            Key::Symbol(sym) => self.symbols.get(&sym),
+            Key::WellKnown(sym) => self.well_known.get(&sym).cloned(),
+        }
+    }
+
+    /// Checks if the map contains an entry with the given `key`.
+    pub(crate) fn contains_key(&self, key: &JsValue) -> bool {
+        match Key::new(key) {
+            Some(Key::Object(obj)) => self.objects.contains_key(&obj),
+            Some(Key::Symbol(sym)) => self.symbols.contains_key(&sym),
+            Some(Key::WellKnown(sym)) => self.well_known.contains_key(&sym),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+
+    #[test]
+    fn weak_collections_symbol_keys() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var map = new WeakMap();
+                var set = new WeakSet();
+                var sym = Symbol("key");
+                map.set(sym, 1);
+                map.set(Symbol.iterator, 2);
+                set.add(sym);
+                set.add(Symbol.iterator);
+            "#}),
+            TestAction::assert_eq("map.get(sym)", 1),
+            TestAction::assert_eq("map.get(Symbol.iterator)", 2),
+            TestAction::assert("map.has(sym)"),
+            TestAction::assert("!map.has(Symbol('key'))"),
+            TestAction::assert("set.has(sym) && set.has(Symbol.iterator)"),
+            TestAction::assert("!map.has(Symbol.for('key')) && !set.has(Symbol.for('key'))"),
+            TestAction::assert("map.delete(sym) && !map.has(sym)"),
+            TestAction::assert("set.delete(Symbol.iterator) && !set.has(Symbol.iterator)"),
+            TestAction::assert_native_error(
+                "map.set(Symbol.for('registered'), 0)",
+                JsNativeErrorKind::Type,
+                "WeakMap.set: expected target argument of type `object` or non-registered `symbol`, got target of type `symbol`",
+            ),
+            TestAction::assert_native_error(
+                "set.add(Symbol.for('registered'))",
+                JsNativeErrorKind::Type,
+                "WeakSet.add: expected target argument of type `object` or non-registered `symbol`, got target of type `symbol`",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn weak_map_symbol_key_collected() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var map = new WeakMap();
+                var ref;
+                {
+                    let sym = Symbol();
+                    map.set(sym, {});
+                    ref = new WeakRef(map.get(sym));
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+            }),
+            TestAction::assert("ref.deref() === undefined"),
+        ]);
+    }
+}
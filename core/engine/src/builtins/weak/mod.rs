@@ -1,5 +1,9 @@
-//! Boa's implementation of ECMAScript's `WeakRef` object.
+//! Boa's implementation of ECMAScript's `WeakRef` and `FinalizationRegistry` objects.
 
+mod finalization_registry;
+mod key;
 mod weak_ref;
 
+pub(crate) use finalization_registry::FinalizationRegistry;
+pub(crate) use key::{can_be_held_weakly, WeakKey, WeakKeyMap};
 pub(crate) use weak_ref::WeakRef;
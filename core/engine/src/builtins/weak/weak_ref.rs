@@ -1,11 +1,12 @@
-use boa_gc::{Finalize, Trace, WeakGc};
+use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
+use super::WeakKey;
 use crate::{
     builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
-    object::{internal_methods::get_prototype_from_constructor, ErasedVTableObject, JsObject},
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
     property::Attribute,
     realm::Realm,
     string::common::StaticJsStrings,
@@ -72,11 +73,12 @@ impl BuiltInConstructor for WeakRef {
                 .into());
         }
 
-        // 2. If target is not an Object, throw a TypeError exception.
-        let target = args.first().and_then(JsValue::as_object).ok_or_else(|| {
+        // 2. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target = args.get_or_undefined(0);
+        let weak_target = WeakKey::new(target).ok_or_else(|| {
             JsNativeError::typ().with_message(format!(
-                "WeakRef: expected target argument of type `object`, got target of type `{}`",
-                args.get_or_undefined(0).type_of()
+                "WeakRef: expected target argument of type `object` or non-registered `symbol`, got target of type `{}`",
+                target.type_of()
             ))
         })?;
 
@@ -87,7 +89,7 @@ impl BuiltInConstructor for WeakRef {
         let weak_ref = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            WeakGc::new(target.inner()),
+            weak_target,
         );
 
         // 4. Perform AddToKeptObjects(target).
@@ -110,7 +112,7 @@ impl WeakRef {
         // 2. Perform ? RequireInternalSlot(weakRef, [[WeakRefTarget]]).
         let weak_ref = this
             .as_object()
-            .and_then(JsObject::downcast_ref::<WeakGc<ErasedVTableObject>>)
+            .and_then(JsObject::downcast_ref::<WeakKey>)
             .ok_or_else(|| {
                 JsNativeError::typ().with_message(
                     "WeakRef.prototype.deref: expected `this` to be a `WeakRef` object",
@@ -123,14 +125,12 @@ impl WeakRef {
         // https://tc39.es/ecma262/multipage/managing-memory.html#sec-weakrefderef
         // 1. Let target be weakRef.[[WeakRefTarget]].
         // 2. If target is not empty, then
-        if let Some(object) = weak_ref.upgrade() {
-            let object = JsObject::from(object);
-
+        if let Some(target) = weak_ref.upgrade() {
             // a. Perform AddToKeptObjects(target).
-            context.kept_alive.push(object.clone());
+            context.kept_alive.push(target.clone());
 
             // b. Return target.
-            Ok(object.into())
+            Ok(target)
         } else {
             // 3. Return undefined.
             Ok(JsValue::undefined())
@@ -142,7 +142,7 @@ impl WeakRef {
 mod tests {
     use indoc::indoc;
 
-    use crate::{run_test_actions, JsValue, TestAction};
+    use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
 
     #[test]
     fn weak_ref_collected() {
@@ -165,4 +165,30 @@ mod tests {
             TestAction::assert_eq("ptr.deref()", JsValue::undefined()),
         ]);
     }
+
+    #[test]
+    fn weak_ref_symbol() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var ptr;
+                {
+                    ptr = new WeakRef(Symbol("collected"));
+                }
+                var wellKnown = new WeakRef(Symbol.iterator);
+            "#}),
+            TestAction::assert("typeof ptr.deref() === 'symbol'"),
+            TestAction::assert("wellKnown.deref() === Symbol.iterator"),
+            TestAction::assert_native_error(
+                "new WeakRef(Symbol.for('registered'))",
+                JsNativeErrorKind::Type,
+                "WeakRef: expected target argument of type `object` or non-registered `symbol`, got target of type `symbol`",
+            ),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+            }),
+            TestAction::assert_eq("ptr.deref()", JsValue::undefined()),
+            TestAction::assert("wellKnown.deref() === Symbol.iterator"),
+        ]);
+    }
 }
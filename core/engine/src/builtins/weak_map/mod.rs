@@ -9,12 +9,13 @@
 
 use crate::{
     builtins::{
-        map::add_entries_from_iterable, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
-        IntrinsicObject,
+        map::add_entries_from_iterable,
+        weak::{can_be_held_weakly, WeakKeyMap},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
     },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
-    object::{internal_methods::get_prototype_from_constructor, ErasedVTableObject, JsObject},
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
     property::Attribute,
     realm::Realm,
     string::{common::StaticJsStrings, utf16},
@@ -24,7 +25,7 @@ use crate::{
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
-type NativeWeakMap = boa_gc::WeakMap<ErasedVTableObject, JsValue>;
+type NativeWeakMap = WeakKeyMap<JsValue>;
 
 #[derive(Debug, Trace, Finalize)]
 pub(crate) struct WeakMap;
@@ -138,10 +139,11 @@ impl WeakMap {
             })?;
 
         // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, return false.
-        let Some(key) = args.get_or_undefined(0).as_object() else {
+        // 4. If CanBeHeldWeakly(key) is false, return false.
+        let key = args.get_or_undefined(0);
+        if !can_be_held_weakly(key) {
             return Ok(false.into());
-        };
+        }
 
         // 5. For each Record { [[Key]], [[Value]] } p of entries, do
         // a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
@@ -149,7 +151,7 @@ impl WeakMap {
         // ii. Set p.[[Value]] to empty.
         // iii. Return true.
         // 6. Return false.
-        Ok(map.remove(key.inner()).is_some().into())
+        Ok(map.remove(key).is_some().into())
     }
 
     /// `WeakMap.prototype.get ( key )`
@@ -175,15 +177,16 @@ impl WeakMap {
             })?;
 
         // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, return undefined.
-        let Some(key) = args.get_or_undefined(0).as_object() else {
+        // 4. If CanBeHeldWeakly(key) is false, return undefined.
+        let key = args.get_or_undefined(0);
+        if !can_be_held_weakly(key) {
             return Ok(JsValue::undefined());
-        };
+        }
 
         // 5. For each Record { [[Key]], [[Value]] } p of entries, do
         // a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return p.[[Value]].
         // 6. Return undefined.
-        Ok(map.get(key.inner()).unwrap_or_default())
+        Ok(map.get(key).unwrap_or_default())
     }
 
     /// `WeakMap.prototype.has ( key )`
@@ -209,15 +212,16 @@ impl WeakMap {
             })?;
 
         // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, return false.
-        let Some(key) = args.get_or_undefined(0).as_object() else {
+        // 4. If CanBeHeldWeakly(key) is false, return false.
+        let key = args.get_or_undefined(0);
+        if !can_be_held_weakly(key) {
             return Ok(false.into());
-        };
+        }
 
         // 5. For each Record { [[Key]], [[Value]] } p of entries, do
         // a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return true.
         // 6. Return false.
-        Ok(map.contains_key(key.inner()).into())
+        Ok(map.contains_key(key).into())
     }
 
     /// `WeakMap.prototype.set ( key, value )`
@@ -243,15 +247,15 @@ impl WeakMap {
             })?;
 
         // 3. Let entries be M.[[WeakMapData]].
-        // 4. If key is not an Object, throw a TypeError exception.
+        // 4. If CanBeHeldWeakly(key) is false, throw a TypeError exception.
         let key = args.get_or_undefined(0);
-        let Some(key) = key.as_object() else {
+        if !can_be_held_weakly(key) {
             return Err(JsNativeError::typ()
                 .with_message(format!(
-                    "WeakMap.set: expected target argument of type `object`, got target of type `{}`",
+                    "WeakMap.set: expected target argument of type `object` or non-registered `symbol`, got target of type `{}`",
                     key.type_of()
                 )).into());
-        };
+        }
 
         // 5. For each Record { [[Key]], [[Value]] } p of entries, do
         // a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
@@ -259,7 +263,7 @@ impl WeakMap {
         // ii. Return M.
         // 6. Let p be the Record { [[Key]]: key, [[Value]]: value }.
         // 7. Append p to entries.
-        map.insert(key.inner(), args.get_or_undefined(1).clone());
+        map.insert(key, args.get_or_undefined(1).clone());
 
         // 8. Return M.
         Ok(this.clone())
@@ -1,12 +1,15 @@
 use super::{ByteCompiler, Literal, Operand, ToJsString};
 use crate::{
+    builtins::function::decorators::ClassElementKind,
     js_string,
     vm::{BindingOpcode, CodeBlock, CodeBlockFlags, Opcode},
+    JsString,
 };
 use boa_ast::{
     expression::Identifier,
-    function::{Class, ClassElement, FormalParameterList},
+    function::{Class, ClassElement, FormalParameterList, PrivateName},
     property::{MethodDefinition, PropertyName},
+    Expression,
 };
 use boa_gc::Gc;
 use boa_interner::Sym;
@@ -32,6 +35,25 @@ impl ByteCompiler<'_> {
         let strict = self.strict();
         self.code_block_flags |= CodeBlockFlags::STRICT;
 
+        // Classes with decorators or auto-accessors define their elements after all of them have
+        // been evaluated, so that the decorators can be applied in order.
+        let decorated = !class.decorators().is_empty()
+            || class.elements().iter().any(|element| {
+                !element.decorators().is_empty()
+                    || matches!(
+                        element,
+                        ClassElement::AccessorDefinition(..)
+                            | ClassElement::StaticAccessorDefinition(..)
+                            | ClassElement::PrivateAccessorDefinition(..)
+                            | ClassElement::PrivateStaticAccessorDefinition(..)
+                    )
+            });
+
+        // The class decorators are evaluated before the class heritage.
+        for decorator in class.decorators() {
+            self.compile_expr(decorator.expression(), true);
+        }
+
         let class_name = class
             .name()
             .map_or(Sym::EMPTY_STRING, Identifier::sym)
@@ -105,14 +127,23 @@ impl ByteCompiler<'_> {
         self.emit_opcode(Opcode::SetClassPrototype);
         self.emit_opcode(Opcode::Swap);
 
+        if decorated {
+            self.emit_with_varying_operand(
+                Opcode::PushClassDecorators,
+                class.decorators().len() as u32,
+            );
+        }
+
         let count_label = self.emit_opcode_with_operand(Opcode::PushPrivateEnvironment);
         let mut count = 0;
         for element in class.elements() {
             match element {
-                ClassElement::PrivateMethodDefinition(name, _)
-                | ClassElement::PrivateStaticMethodDefinition(name, _)
-                | ClassElement::PrivateFieldDefinition(name, _)
-                | ClassElement::PrivateStaticFieldDefinition(name, _) => {
+                ClassElement::PrivateMethodDefinition(name, _, _)
+                | ClassElement::PrivateStaticMethodDefinition(name, _, _)
+                | ClassElement::PrivateFieldDefinition(name, _, _)
+                | ClassElement::PrivateStaticFieldDefinition(name, _, _)
+                | ClassElement::PrivateAccessorDefinition(name, _, _)
+                | ClassElement::PrivateStaticAccessorDefinition(name, _, _) => {
                     count += 1;
                     let index = self.get_or_insert_private_name(*name);
                     self.emit_u32(index);
@@ -122,10 +153,37 @@ impl ByteCompiler<'_> {
         }
         self.patch_jump_with_target(count_label, count);
 
+        if decorated {
+            self.compile_decorated_class_elements(class, &class_name, old_lex_env.is_some());
+        } else {
+            self.compile_class_elements(class, &class_name, old_lex_env.is_some());
+        }
+
+        if let Some(old_lex_env) = old_lex_env {
+            self.pop_compile_environment();
+            self.lexical_environment = old_lex_env;
+            self.emit_opcode(Opcode::PopEnvironment);
+        }
+
+        self.emit_opcode(Opcode::PopPrivateEnvironment);
+
+        if !expression {
+            self.emit_binding(BindingOpcode::InitVar, class_name);
+        }
+
+        // NOTE: Reset strict mode to before class declaration/expression evalutation.
+        self.code_block_flags.set(CodeBlockFlags::STRICT, strict);
+    }
+
+    /// Compiles the elements of a class without decorators or auto-accessors.
+    ///
+    /// The elements are defined while they are evaluated, with the class prototype and the class
+    /// on the stack.
+    fn compile_class_elements(&mut self, class: &Class, class_name: &JsString, has_binding: bool) {
         let mut static_elements = Vec::new();
         let mut static_field_name_count = 0;
 
-        if old_lex_env.is_some() {
+        if has_binding {
             self.emit_opcode(Opcode::Dup);
             self.emit_binding(BindingOpcode::InitLexical, class_name.clone());
         }
@@ -133,7 +191,7 @@ impl ByteCompiler<'_> {
         // TODO: set function name for getter and setters
         for element in class.elements() {
             match element {
-                ClassElement::StaticMethodDefinition(name, method_definition) => {
+                ClassElement::StaticMethodDefinition(name, method_definition, _) => {
                     self.emit_opcode(Opcode::Dup);
                     match method_definition {
                         MethodDefinition::Get(expr) => match name {
@@ -234,7 +292,7 @@ impl ByteCompiler<'_> {
                         },
                     }
                 }
-                ClassElement::PrivateStaticMethodDefinition(name, method_definition) => {
+                ClassElement::PrivateStaticMethodDefinition(name, method_definition, _) => {
                     self.emit_opcode(Opcode::Dup);
                     match method_definition {
                         MethodDefinition::Get(expr) => {
@@ -269,7 +327,7 @@ impl ByteCompiler<'_> {
                         }
                     }
                 }
-                ClassElement::FieldDefinition(name, field) => {
+                ClassElement::FieldDefinition(name, field, _) => {
                     self.emit_opcode(Opcode::Dup);
                     match name {
                         PropertyName::Literal(name) => {
@@ -306,7 +364,7 @@ impl ByteCompiler<'_> {
                     self.emit_with_varying_operand(Opcode::GetFunction, index);
                     self.emit_opcode(Opcode::PushClassField);
                 }
-                ClassElement::PrivateFieldDefinition(name, field) => {
+                ClassElement::PrivateFieldDefinition(name, field, _) => {
                     self.emit_opcode(Opcode::Dup);
                     let name_index = self.get_or_insert_private_name(*name);
                     let mut field_compiler = ByteCompiler::new(
@@ -332,7 +390,7 @@ impl ByteCompiler<'_> {
                     self.emit_with_varying_operand(Opcode::GetFunction, index);
                     self.emit_with_varying_operand(Opcode::PushClassFieldPrivate, name_index);
                 }
-                ClassElement::StaticFieldDefinition(name, field) => {
+                ClassElement::StaticFieldDefinition(name, field, _) => {
                     let name_index = match name {
                         PropertyName::Literal(name) => {
                             Some(self.get_or_insert_name((*name).into()))
@@ -370,7 +428,7 @@ impl ByteCompiler<'_> {
 
                     static_elements.push(StaticElement::StaticField((code, name_index)));
                 }
-                ClassElement::PrivateStaticFieldDefinition(name, field) => {
+                ClassElement::PrivateStaticFieldDefinition(name, field, _) => {
                     self.emit_opcode(Opcode::Dup);
                     if let Some(node) = field {
                         self.compile_expr(node, true);
@@ -380,6 +438,12 @@ impl ByteCompiler<'_> {
                     let index = self.get_or_insert_private_name(*name);
                     self.emit_with_varying_operand(Opcode::DefinePrivateField, index);
                 }
+                ClassElement::AccessorDefinition(..)
+                | ClassElement::StaticAccessorDefinition(..)
+                | ClassElement::PrivateAccessorDefinition(..)
+                | ClassElement::PrivateStaticAccessorDefinition(..) => {
+                    unreachable!("auto-accessors are compiled as decorated class elements")
+                }
                 ClassElement::StaticBlock(body) => {
                     let mut compiler = ByteCompiler::new(
                         Sym::EMPTY_STRING.to_js_string(self.interner()),
@@ -404,7 +468,7 @@ impl ByteCompiler<'_> {
                     let code = Gc::new(compiler.finish());
                     static_elements.push(StaticElement::StaticBlock(code));
                 }
-                ClassElement::PrivateMethodDefinition(name, method_definition) => {
+                ClassElement::PrivateMethodDefinition(name, method_definition, _) => {
                     self.emit_opcode(Opcode::Dup);
                     match method_definition {
                         MethodDefinition::Get(expr) => {
@@ -439,7 +503,7 @@ impl ByteCompiler<'_> {
                         }
                     }
                 }
-                ClassElement::MethodDefinition(name, method_definition) => {
+                ClassElement::MethodDefinition(name, method_definition, _) => {
                     self.emit_opcode(Opcode::Swap);
                     self.emit_opcode(Opcode::Dup);
                     match method_definition {
@@ -575,20 +639,197 @@ impl ByteCompiler<'_> {
 
         self.emit_opcode(Opcode::Swap);
         self.emit_opcode(Opcode::Pop);
+    }
 
-        if let Some(old_lex_env) = old_lex_env {
-            self.pop_compile_environment();
-            self.lexical_environment = old_lex_env;
-            self.emit_opcode(Opcode::PopEnvironment);
+    /// Compiles the elements of a class with decorators or auto-accessors.
+    ///
+    /// The decorators, key and function of each element are evaluated and recorded in the class,
+    /// and `ApplyClassDecorators` defines all of the elements once they have been evaluated.
+    fn compile_decorated_class_elements(
+        &mut self,
+        class: &Class,
+        class_name: &JsString,
+        has_binding: bool,
+    ) {
+        for element in class.elements() {
+            for decorator in element.decorators() {
+                self.compile_expr(decorator.expression(), true);
+            }
+
+            let (kind, is_static, is_private) = match element {
+                ClassElement::MethodDefinition(name, method, _)
+                | ClassElement::StaticMethodDefinition(name, method, _) => {
+                    self.compile_class_element_key(name);
+                    let kind = self.compile_class_method(method);
+                    let is_static = matches!(element, ClassElement::StaticMethodDefinition(..));
+                    (kind, is_static, false)
+                }
+                ClassElement::PrivateMethodDefinition(name, method, _)
+                | ClassElement::PrivateStaticMethodDefinition(name, method, _) => {
+                    self.compile_private_element_key(*name);
+                    let kind = self.compile_class_method(method);
+                    let is_static =
+                        matches!(element, ClassElement::PrivateStaticMethodDefinition(..));
+                    (kind, is_static, true)
+                }
+                ClassElement::FieldDefinition(name, field, _)
+                | ClassElement::StaticFieldDefinition(name, field, _) => {
+                    self.compile_class_element_key(name);
+                    self.compile_class_field_initializer(field.as_ref(), class_name);
+                    let is_static = matches!(element, ClassElement::StaticFieldDefinition(..));
+                    (ClassElementKind::Field, is_static, false)
+                }
+                ClassElement::PrivateFieldDefinition(name, field, _)
+                | ClassElement::PrivateStaticFieldDefinition(name, field, _) => {
+                    self.compile_private_element_key(*name);
+                    self.compile_class_field_initializer(field.as_ref(), class_name);
+                    let is_static =
+                        matches!(element, ClassElement::PrivateStaticFieldDefinition(..));
+                    (ClassElementKind::Field, is_static, true)
+                }
+                ClassElement::AccessorDefinition(name, field, _)
+                | ClassElement::StaticAccessorDefinition(name, field, _) => {
+                    self.compile_class_element_key(name);
+                    self.compile_class_field_initializer(field.as_ref(), class_name);
+                    let is_static = matches!(element, ClassElement::StaticAccessorDefinition(..));
+                    (ClassElementKind::Accessor, is_static, false)
+                }
+                ClassElement::PrivateAccessorDefinition(name, field, _)
+                | ClassElement::PrivateStaticAccessorDefinition(name, field, _) => {
+                    self.compile_private_element_key(*name);
+                    self.compile_class_field_initializer(field.as_ref(), class_name);
+                    let is_static =
+                        matches!(element, ClassElement::PrivateStaticAccessorDefinition(..));
+                    (ClassElementKind::Accessor, is_static, true)
+                }
+                ClassElement::StaticBlock(body) => {
+                    self.emit_opcode(Opcode::PushUndefined);
+
+                    let mut compiler = ByteCompiler::new(
+                        Sym::EMPTY_STRING.to_js_string(self.interner()),
+                        true,
+                        false,
+                        self.variable_environment.clone(),
+                        self.lexical_environment.clone(),
+                        self.context,
+                    );
+                    let _ = compiler.push_compile_environment(true);
+
+                    compiler.function_declaration_instantiation(
+                        body,
+                        &FormalParameterList::default(),
+                        false,
+                        true,
+                        false,
+                    );
+
+                    compiler.compile_statement_list(body.statements(), false, false);
+
+                    let code = Gc::new(compiler.finish());
+                    let index = self.push_function_to_constants(code);
+                    self.emit_with_varying_operand(Opcode::GetFunction, index);
+                    (ClassElementKind::StaticBlock, true, false)
+                }
+            };
+
+            self.emit(
+                Opcode::PushClassElement,
+                &[
+                    Operand::U8(kind.into()),
+                    Operand::Bool(is_static),
+                    Operand::Bool(is_private),
+                    Operand::Varying(element.decorators().len() as u32),
+                ],
+            );
         }
 
-        self.emit_opcode(Opcode::PopPrivateEnvironment);
+        self.emit_opcode(Opcode::ApplyClassDecorators);
 
-        if !expression {
-            self.emit_binding(BindingOpcode::InitVar, class_name);
+        if has_binding {
+            self.emit_opcode(Opcode::Dup);
+            self.emit_binding(BindingOpcode::InitLexical, class_name.clone());
         }
 
-        // NOTE: Reset strict mode to before class declaration/expression evalutation.
-        self.code_block_flags.set(CodeBlockFlags::STRICT, strict);
+        self.emit_opcode(Opcode::InitializeDecoratedClass);
+    }
+
+    /// Pushes the property key of a decorated class element.
+    fn compile_class_element_key(&mut self, name: &PropertyName) {
+        match name {
+            PropertyName::Literal(name) => {
+                self.emit_push_literal(Literal::String(
+                    self.interner().resolve_expect(*name).into_common(false),
+                ));
+            }
+            PropertyName::Computed(name) => {
+                self.compile_expr(name, true);
+                self.emit_opcode(Opcode::ToPropertyKey);
+            }
+        }
+    }
+
+    /// Pushes the description of the private name of a decorated class element.
+    fn compile_private_element_key(&mut self, name: PrivateName) {
+        let description = name.description().to_js_string(self.interner());
+        self.emit_push_literal(Literal::String(description));
+    }
+
+    /// Pushes the function of a decorated class method, returning the kind of the method.
+    fn compile_class_method(&mut self, method: &MethodDefinition) -> ClassElementKind {
+        match method {
+            MethodDefinition::Get(expr) => {
+                self.method(expr.into());
+                ClassElementKind::Getter
+            }
+            MethodDefinition::Set(expr) => {
+                self.method(expr.into());
+                ClassElementKind::Setter
+            }
+            MethodDefinition::Ordinary(expr) => {
+                self.method(expr.into());
+                ClassElementKind::Method
+            }
+            MethodDefinition::Async(expr) => {
+                self.method(expr.into());
+                ClassElementKind::Method
+            }
+            MethodDefinition::Generator(expr) => {
+                self.method(expr.into());
+                ClassElementKind::Method
+            }
+            MethodDefinition::AsyncGenerator(expr) => {
+                self.method(expr.into());
+                ClassElementKind::Method
+            }
+        }
+    }
+
+    /// Pushes the initializer function of a decorated class field or auto-accessor.
+    fn compile_class_field_initializer(
+        &mut self,
+        field: Option<&Expression>,
+        class_name: &JsString,
+    ) {
+        let mut field_compiler = ByteCompiler::new(
+            class_name.clone(),
+            true,
+            self.json_parse,
+            self.variable_environment.clone(),
+            self.lexical_environment.clone(),
+            self.context,
+        );
+        let _ = field_compiler.push_compile_environment(true);
+        if let Some(node) = field {
+            field_compiler.compile_expr(node, true);
+        } else {
+            field_compiler.emit_opcode(Opcode::PushUndefined);
+        }
+        field_compiler.emit_opcode(Opcode::SetReturnValue);
+
+        field_compiler.code_block_flags |= CodeBlockFlags::IN_CLASS_FIELD_INITIALIZER;
+
+        let code = Gc::new(field_compiler.finish());
+        let index = self.push_function_to_constants(code);
+        self.emit_with_varying_operand(Opcode::GetFunction, index);
     }
 }
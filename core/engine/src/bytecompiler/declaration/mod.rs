@@ -1 +1,2 @@
 mod declaration_pattern;
+mod using;
@@ -0,0 +1,97 @@
+use crate::{
+    bytecompiler::{jump_control::JumpControlInfoFlags, ByteCompiler, Operand, ToJsString},
+    vm::{BindingOpcode, Opcode},
+};
+use boa_ast::declaration::{Binding, LexicalDeclaration};
+
+impl ByteCompiler<'_> {
+    /// Compiles a scope that may contain `using` or `await using` declarations.
+    ///
+    /// The scope is compiled like a `try` statement with a `finally` block, which disposes
+    /// of the resources added by the declarations in reverse order, and then rethrows the
+    /// completion of the scope or of the disposal.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn compile_using_scope<F>(&mut self, r#async: bool, use_expr: bool, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.emit_opcode(Opcode::PushDisposeScope);
+        self.push_try_with_finally_control_info(use_expr);
+
+        let handler = self.push_handler();
+        f(self);
+
+        // stack: false, 0
+        self.emit_opcode(Opcode::PushZero);
+        self.emit_opcode(Opcode::PushFalse);
+        let finally = self.jump();
+
+        self.patch_handler(handler);
+        self.emit_opcode(Opcode::CaptureDisposeError);
+
+        // stack: true, undefined
+        self.emit_opcode(Opcode::PushUndefined);
+        self.emit_opcode(Opcode::PushTrue);
+
+        self.patch_jump(finally);
+
+        let finally_start = self.next_opcode_location();
+        self.jump_info
+            .last_mut()
+            .expect("there should be a try block")
+            .flags |= JumpControlInfoFlags::IN_FINALLY;
+        self.current_stack_value_count += 2;
+
+        // Disposing of the resources may call functions, which overwrite the return value.
+        self.current_stack_value_count += 1;
+        self.emit_opcode(Opcode::GetReturnValue);
+        if r#async {
+            let start = self.next_opcode_location();
+            self.emit_opcode(Opcode::DisposeResources);
+            let exit = self.jump_if_false();
+            self.emit_opcode(Opcode::Await);
+            self.emit_opcode(Opcode::ResumeDisposeResources);
+            self.emit(Opcode::Jump, &[Operand::U32(start)]);
+            self.patch_jump(exit);
+        } else {
+            self.emit_opcode(Opcode::DisposeResources);
+            self.emit_opcode(Opcode::Pop);
+        }
+        self.emit_opcode(Opcode::SetReturnValue);
+        self.current_stack_value_count -= 1;
+
+        self.emit_opcode(Opcode::PopDisposeScope);
+
+        // Rethrow the error of the scope, or continue the `return()` call on the generator.
+        let do_not_throw_exit = self.jump_if_false();
+        self.emit_opcode(Opcode::ReThrow);
+        self.patch_jump(do_not_throw_exit);
+
+        self.current_stack_value_count -= 2;
+        self.pop_try_with_finally_control_info(finally_start);
+    }
+
+    /// Compiles the bindings of a `using` or `await using` declaration.
+    ///
+    /// Each value is added to the resources of the current dispose scope before it is bound.
+    pub(crate) fn compile_using_decl(&mut self, decl: &LexicalDeclaration) {
+        let r#async = decl.is_await_using();
+        for variable in decl.variable_list().as_ref() {
+            let Binding::Identifier(ident) = variable.binding() else {
+                unreachable!("using declarations cannot contain binding patterns");
+            };
+            let ident = ident.to_js_string(self.interner());
+            let init = variable
+                .init()
+                .expect("using declaration must have initializer");
+            self.compile_expr(init, true);
+            self.emit_opcode(Opcode::Dup);
+            self.emit(Opcode::AddDisposableResource, &[Operand::Bool(r#async)]);
+            self.emit_binding(BindingOpcode::InitLexical, ident);
+        }
+    }
+}
@@ -3,7 +3,7 @@ use std::rc::Rc;
 use crate::{
     bytecompiler::{ByteCompiler, FunctionCompiler, FunctionSpec, NodeKind},
     environments::CompileTimeEnvironment,
-    vm::{create_function_object_fast, BindingOpcode, Opcode},
+    vm::{create_function_object_fast, ActiveRunnable, BindingOpcode, Opcode},
     JsNativeError, JsResult,
 };
 use boa_ast::{
@@ -27,6 +27,9 @@ use super::{Operand, ToJsString};
 impl ByteCompiler<'_> {
     /// `GlobalDeclarationInstantiation ( script, env )`
     ///
+    /// `script_or_module` is the script being instantiated, which becomes the
+    /// `[[ScriptOrModule]]` of its function declarations.
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
@@ -35,6 +38,7 @@ impl ByteCompiler<'_> {
         &mut self,
         script: &Script,
         env: &Rc<CompileTimeEnvironment>,
+        script_or_module: &ActiveRunnable,
     ) -> JsResult<()> {
         // 1. Let lexNames be the LexicallyDeclaredNames of script.
         let lex_names = lexically_declared_names(script);
@@ -254,7 +258,11 @@ impl ByteCompiler<'_> {
                             env.create_mutable_binding(name, false);
                         }
                     }
-                    Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                    Declaration::Lexical(
+                        LexicalDeclaration::Const(declaration)
+                        | LexicalDeclaration::Using(declaration)
+                        | LexicalDeclaration::AwaitUsing(declaration),
+                    ) => {
                         for name in bound_names(declaration) {
                             let name = name.to_js_string(self.interner());
                             env.create_immutable_binding(name, true);
@@ -305,7 +313,8 @@ impl ByteCompiler<'_> {
             let _ = self.push_function_to_constants(code.clone());
 
             // b. Let fo be InstantiateFunctionObject of f with arguments env and privateEnv.
-            let function = create_function_object_fast(code, self.context);
+            let function =
+                create_function_object_fast(code, Some(script_or_module.clone()), self.context);
 
             // c. Perform ? env.CreateGlobalFunctionBinding(fn, fo, false).
             let name = name.to_js_string(self.interner());
@@ -346,7 +355,11 @@ impl ByteCompiler<'_> {
         // 3. For each element d of declarations, do
         for d in &declarations {
             // i. If IsConstantDeclaration of d is true, then
-            if let LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(d)) = d
+            if let LexicallyScopedDeclaration::LexicalDeclaration(
+                LexicalDeclaration::Const(d)
+                | LexicalDeclaration::Using(d)
+                | LexicalDeclaration::AwaitUsing(d),
+            ) = d
             {
                 // a. For each element dn of the BoundNames of d, do
                 for dn in bound_names::<'_, VariableList>(d) {
@@ -726,7 +739,11 @@ impl ByteCompiler<'_> {
                             lex_env.create_mutable_binding(name, false);
                         }
                     }
-                    Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                    Declaration::Lexical(
+                        LexicalDeclaration::Const(declaration)
+                        | LexicalDeclaration::Using(declaration)
+                        | LexicalDeclaration::AwaitUsing(declaration),
+                    ) => {
                         for name in bound_names(declaration) {
                             let name = name.to_js_string(self.interner());
                             lex_env.create_immutable_binding(name, true);
@@ -1241,7 +1258,11 @@ impl ByteCompiler<'_> {
                             lex_env.create_mutable_binding(name, false);
                         }
                     }
-                    Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                    Declaration::Lexical(
+                        LexicalDeclaration::Const(declaration)
+                        | LexicalDeclaration::Using(declaration)
+                        | LexicalDeclaration::AwaitUsing(declaration),
+                    ) => {
                         for name in bound_names(declaration) {
                             let name = name.to_js_string(self.interner());
                             lex_env.create_immutable_binding(name, true);
@@ -333,6 +333,11 @@ impl ByteCompiler<'_> {
             }
             Expression::ImportCall(import) => {
                 self.compile_expr(import.argument(), true);
+                if let Some(options) = import.options() {
+                    self.compile_expr(options, true);
+                } else {
+                    self.emit_opcode(Opcode::PushUndefined);
+                }
                 self.emit_opcode(Opcode::ImportCall);
                 if !use_expr {
                     self.emit_opcode(Opcode::Pop);
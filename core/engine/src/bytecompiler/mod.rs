@@ -19,7 +19,7 @@ use crate::{
     js_string,
     vm::{
         BindingOpcode, CodeBlock, CodeBlockFlags, Constant, GeneratorResumeKind, Handler,
-        InlineCache, Opcode, VaryingOperandKind,
+        InlineCache, Opcode, SourceMapEntry, VaryingOperandKind,
     },
     Context, JsBigInt, JsString,
 };
@@ -36,7 +36,7 @@ use boa_ast::{
     },
     operations::returns_value,
     pattern::Pattern,
-    Declaration, Expression, Statement, StatementList, StatementListItem,
+    Declaration, Expression, Position, Statement, StatementList, StatementListItem,
 };
 use boa_gc::Gc;
 use boa_interner::{Interner, Sym};
@@ -282,6 +282,7 @@ pub struct ByteCompiler<'ctx> {
     pub(crate) code_block_flags: CodeBlockFlags,
     handlers: ThinVec<Handler>,
     pub(crate) ic: Vec<InlineCache>,
+    source_map: Vec<SourceMapEntry>,
     literals_map: FxHashMap<Literal, u32>,
     names_map: FxHashMap<Identifier, u32>,
     bindings_map: FxHashMap<BindingLocator, u32>,
@@ -334,6 +335,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             code_block_flags,
             handlers: ThinVec::default(),
             ic: Vec::default(),
+            source_map: Vec::default(),
 
             literals_map: FxHashMap::default(),
             names_map: FxHashMap::default(),
@@ -370,6 +372,19 @@ impl<'ctx> ByteCompiler<'ctx> {
         self.context.interner()
     }
 
+    /// Records `position` as the source position of the next emitted instruction.
+    pub(crate) fn emit_position(&mut self, position: Option<Position>) {
+        let Some(position) = position else {
+            return;
+        };
+
+        let pc = self.next_opcode_location();
+        match self.source_map.last_mut() {
+            Some(entry) if entry.pc == pc => entry.position = position,
+            _ => self.source_map.push(SourceMapEntry { pc, position }),
+        }
+    }
+
     fn get_or_insert_literal(&mut self, literal: Literal) -> u32 {
         if let Some(index) = self.literals_map.get(&literal) {
             return *index;
@@ -931,6 +946,18 @@ impl<'ctx> ByteCompiler<'ctx> {
 
     /// Compile a [`StatementList`].
     pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool, block: bool) {
+        if list.contains_using_declaration() {
+            let r#async = list.contains_await_using_declaration();
+            self.compile_using_scope(r#async, use_expr, |compiler| {
+                compiler.compile_statement_list_items(list, use_expr, block);
+            });
+        } else {
+            self.compile_statement_list_items(list, use_expr, block);
+        }
+    }
+
+    /// Compile the items of a [`StatementList`].
+    fn compile_statement_list_items(&mut self, list: &StatementList, use_expr: bool, block: bool) {
         if use_expr || self.jump_control_info_has_use_expr() {
             let mut use_expr_index = 0;
             for (i, statement) in list.statements().iter().enumerate() {
@@ -949,10 +976,12 @@ impl<'ctx> ByteCompiler<'ctx> {
             }
 
             for (i, item) in list.statements().iter().enumerate() {
+                self.emit_position(list.position(i));
                 self.compile_stmt_list_item(item, i == use_expr_index, block);
             }
         } else {
-            for item in list.statements() {
+            for (i, item) in list.statements().iter().enumerate() {
+                self.emit_position(list.position(i));
                 self.compile_stmt_list_item(item, false, block);
             }
         }
@@ -1214,6 +1243,9 @@ impl<'ctx> ByteCompiler<'ctx> {
                     }
                 }
             }
+            LexicalDeclaration::Using(_) | LexicalDeclaration::AwaitUsing(_) => {
+                self.compile_using_decl(decl);
+            }
         };
     }
 
@@ -1494,6 +1526,8 @@ impl<'ctx> ByteCompiler<'ctx> {
             }
         }
 
+        self.emit_position(call.position());
+
         match kind {
             CallKind::CallEval if contains_spread => self.emit_opcode(Opcode::CallEvalSpread),
             CallKind::CallEval => {
@@ -1550,6 +1584,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             handlers: self.handlers,
             flags: Cell::new(self.code_block_flags),
             ic: self.ic.into_boxed_slice(),
+            source_map: self.source_map.into_boxed_slice(),
         }
     }
 
@@ -8,8 +8,17 @@ impl ByteCompiler<'_> {
     /// Compiles a [`ModuleItemList`].
     #[inline]
     pub fn compile_module_item_list(&mut self, list: &ModuleItemList) {
-        for node in list.items() {
-            self.compile_module_item(node);
+        if list.contains_using_declaration() {
+            let r#async = list.contains_await_using_declaration();
+            self.compile_using_scope(r#async, false, |compiler| {
+                for node in list.items() {
+                    compiler.compile_module_item(node);
+                }
+            });
+        } else {
+            for node in list.items() {
+                self.compile_module_item(node);
+            }
         }
     }
 
@@ -224,6 +224,9 @@ impl ByteCompiler<'_> {
                     self.compile_declaration_pattern(pattern, BindingOpcode::InitLexical);
                 }
             },
+            IterableLoopInitializer::Using(_) | IterableLoopInitializer::AwaitUsing(_) => {
+                unreachable!("using declarations are not allowed in for-in loops")
+            }
             IterableLoopInitializer::Pattern(pattern) => {
                 self.compile_declaration_pattern(pattern, BindingOpcode::SetName);
             }
@@ -258,7 +261,9 @@ impl ByteCompiler<'_> {
     ) {
         let initializer_bound_names = match for_of_loop.initializer() {
             IterableLoopInitializer::Let(declaration)
-            | IterableLoopInitializer::Const(declaration) => bound_names(declaration),
+            | IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => bound_names(declaration),
             _ => Vec::new(),
         };
         if initializer_bound_names.is_empty() {
@@ -313,6 +318,7 @@ impl ByteCompiler<'_> {
         };
 
         let mut handler_index = None;
+        let mut using_binding = None;
         match for_of_loop.initializer() {
             IterableLoopInitializer::Identifier(ref ident) => {
                 let ident = ident.to_js_string(self.interner());
@@ -387,6 +393,20 @@ impl ByteCompiler<'_> {
                 handler_index = Some(self.push_handler());
                 self.compile_declaration_pattern(pattern, BindingOpcode::SetName);
             }
+            IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
+                let Binding::Identifier(ident) = declaration else {
+                    unreachable!("using declarations cannot contain binding patterns");
+                };
+                let ident = ident.to_js_string(self.interner());
+                self.lexical_environment
+                    .create_immutable_binding(ident.clone(), true);
+                let r#async = matches!(
+                    for_of_loop.initializer(),
+                    IterableLoopInitializer::AwaitUsing(_)
+                );
+                using_binding = Some((ident, r#async));
+            }
         }
 
         // If the left-hand side is not a lexical binding and the assignment produces
@@ -411,7 +431,17 @@ impl ByteCompiler<'_> {
             self.patch_jump(exit);
         }
 
-        self.compile_stmt(for_of_loop.body(), use_expr, true);
+        if let Some((ident, r#async)) = using_binding {
+            // The value of each iteration is disposed of at the end of the iteration.
+            self.compile_using_scope(r#async, use_expr, |compiler| {
+                compiler.emit_opcode(Opcode::Dup);
+                compiler.emit(Opcode::AddDisposableResource, &[Operand::Bool(r#async)]);
+                compiler.emit_binding(BindingOpcode::InitLexical, ident);
+                compiler.compile_stmt(for_of_loop.body(), use_expr, true);
+            });
+        } else {
+            self.compile_stmt(for_of_loop.body(), use_expr, true);
+        }
 
         if let Some(old_lex_env) = old_lex_env {
             self.pop_compile_environment();
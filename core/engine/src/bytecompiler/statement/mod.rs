@@ -57,6 +57,7 @@ impl ByteCompiler<'_> {
             }
             Statement::Throw(throw) => {
                 self.compile_expr(throw.target(), true);
+                self.emit_position(throw.position());
                 self.emit(Opcode::Throw, &[]);
             }
             Statement::Switch(switch) => {
@@ -131,6 +131,7 @@ pub struct StandardConstructors {
     async_function: StandardConstructor,
     generator_function: StandardConstructor,
     async_generator_function: StandardConstructor,
+    iterator: StandardConstructor,
     array: StandardConstructor,
     bigint: StandardConstructor,
     number: StandardConstructor,
@@ -146,6 +147,7 @@ pub struct StandardConstructors {
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
     aggregate_error: StandardConstructor,
+    suppressed_error: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
     typed_array: StandardConstructor,
@@ -168,9 +170,16 @@ pub struct StandardConstructors {
     weak_ref: StandardConstructor,
     weak_map: StandardConstructor,
     weak_set: StandardConstructor,
+    finalization_registry: StandardConstructor,
+    disposable_stack: StandardConstructor,
+    async_disposable_stack: StandardConstructor,
+    #[cfg(feature = "experimental")]
+    shadow_realm: StandardConstructor,
     #[cfg(feature = "intl")]
     collator: StandardConstructor,
     #[cfg(feature = "intl")]
+    display_names: StandardConstructor,
+    #[cfg(feature = "intl")]
     list_format: StandardConstructor,
     #[cfg(feature = "intl")]
     locale: StandardConstructor,
@@ -180,6 +189,10 @@ pub struct StandardConstructors {
     plural_rules: StandardConstructor,
     #[cfg(feature = "intl")]
     number_format: StandardConstructor,
+    #[cfg(feature = "intl")]
+    relative_time_format: StandardConstructor,
+    #[cfg(feature = "intl")]
+    duration_format: StandardConstructor,
     #[cfg(feature = "temporal")]
     instant: StandardConstructor,
     #[cfg(feature = "temporal")]
@@ -218,6 +231,7 @@ impl Default for StandardConstructors {
             },
             async_function: StandardConstructor::default(),
             generator_function: StandardConstructor::default(),
+            iterator: StandardConstructor::default(),
             array: StandardConstructor::with_prototype(JsObject::from_proto_and_data(None, Array)),
             bigint: StandardConstructor::default(),
             number: StandardConstructor::with_prototype(JsObject::from_proto_and_data(None, 0.0)),
@@ -238,6 +252,7 @@ impl Default for StandardConstructors {
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
             aggregate_error: StandardConstructor::default(),
+            suppressed_error: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
             typed_array: StandardConstructor::default(),
@@ -260,9 +275,16 @@ impl Default for StandardConstructors {
             weak_ref: StandardConstructor::default(),
             weak_map: StandardConstructor::default(),
             weak_set: StandardConstructor::default(),
+            finalization_registry: StandardConstructor::default(),
+            disposable_stack: StandardConstructor::default(),
+            async_disposable_stack: StandardConstructor::default(),
+            #[cfg(feature = "experimental")]
+            shadow_realm: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             collator: StandardConstructor::default(),
             #[cfg(feature = "intl")]
+            display_names: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
             list_format: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             locale: StandardConstructor::default(),
@@ -272,6 +294,10 @@ impl Default for StandardConstructors {
             plural_rules: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             number_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            relative_time_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            duration_format: StandardConstructor::default(),
             #[cfg(feature = "temporal")]
             instant: StandardConstructor::default(),
             #[cfg(feature = "temporal")]
@@ -309,6 +335,18 @@ impl StandardConstructors {
         &self.async_generator_function
     }
 
+    /// Returns the `Iterator` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-iterator-helpers/#sec-iterator-constructor
+    #[inline]
+    #[must_use]
+    pub const fn iterator(&self) -> &StandardConstructor {
+        &self.iterator
+    }
+
     /// Returns the `Object` constructor.
     ///
     /// More information:
@@ -561,6 +599,18 @@ impl StandardConstructors {
         &self.aggregate_error
     }
 
+    /// Returns the `SuppressedError` constructor.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-constructor
+    #[inline]
+    #[must_use]
+    pub const fn suppressed_error(&self) -> &StandardConstructor {
+        &self.suppressed_error
+    }
+
     /// Returns the `Map` constructor.
     ///
     /// More information:
@@ -825,6 +875,55 @@ impl StandardConstructors {
         &self.weak_set
     }
 
+    /// Returns the `FinalizationRegistry` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-constructor
+    #[inline]
+    #[must_use]
+    pub const fn finalization_registry(&self) -> &StandardConstructor {
+        &self.finalization_registry
+    }
+
+    /// Returns the `DisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-constructor
+    #[inline]
+    #[must_use]
+    pub const fn disposable_stack(&self) -> &StandardConstructor {
+        &self.disposable_stack
+    }
+
+    /// Returns the `AsyncDisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-constructor
+    #[inline]
+    #[must_use]
+    pub const fn async_disposable_stack(&self) -> &StandardConstructor {
+        &self.async_disposable_stack
+    }
+
+    /// Returns the `ShadowRealm` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "experimental")]
+    pub const fn shadow_realm(&self) -> &StandardConstructor {
+        &self.shadow_realm
+    }
+
     /// Returns the `Intl.Collator` constructor.
     ///
     /// More information:
@@ -838,6 +937,19 @@ impl StandardConstructors {
         &self.collator
     }
 
+    /// Returns the `Intl.DisplayNames` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-displaynames-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn display_names(&self) -> &StandardConstructor {
+        &self.display_names
+    }
+
     /// Returns the `Intl.ListFormat` constructor.
     ///
     /// More information:
@@ -903,6 +1015,32 @@ impl StandardConstructors {
         &self.number_format
     }
 
+    /// Returns the `Intl.RelativeTimeFormat` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-relativetimeformat-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn relative_time_format(&self) -> &StandardConstructor {
+        &self.relative_time_format
+    }
+
+    /// Returns the `Intl.DurationFormat` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-intl-durationformat-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn duration_format(&self) -> &StandardConstructor {
+        &self.duration_format
+    }
+
     /// Returns the `Temporal.Instant` constructor.
     ///
     /// More information:
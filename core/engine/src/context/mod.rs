@@ -5,6 +5,7 @@ mod hooks;
 pub(crate) mod icu;
 pub mod intrinsics;
 
+pub use boa_gc::{GcConfig, GcHeap, HeapSnapshot};
 use boa_parser::source::ReadChar;
 pub use hooks::{DefaultHooks, HostHooks};
 
@@ -13,16 +14,17 @@ pub use icu::IcuError;
 
 use intrinsics::Intrinsics;
 
-use std::{cell::Cell, path::Path, rc::Rc};
+use std::{cell::Cell, fs::File, io, path::Path, rc::Rc};
 
 use crate::{
-    builtins,
+    builtins::{self, weak::FinalizationRegistry},
     class::{Class, ClassBuilder},
+    error::{ErrorStack, StackFrame, STACK_TRACE_LIMIT},
     job::{JobQueue, NativeJob, SimpleJobQueue},
     js_string,
     module::{IdleModuleLoader, ModuleLoader, SimpleModuleLoader},
     native_function::NativeFunction,
-    object::{shape::RootShape, FunctionObjectBuilder, JsObject},
+    object::{shape::RootShape, ErasedVTableObject, FunctionObjectBuilder, JsObject},
     optimizer::{Optimizer, OptimizerOptions, OptimizerStatistics},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
@@ -31,6 +33,7 @@ use crate::{
     JsNativeError, JsResult, JsString, JsValue, Source,
 };
 use boa_ast::StatementList;
+use boa_gc::{WeakGc, WeakMap};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 
@@ -99,7 +102,13 @@ pub struct Context {
 
     pub(crate) vm: Vm,
 
-    pub(crate) kept_alive: Vec<JsObject>,
+    pub(crate) kept_alive: Vec<JsValue>,
+
+    /// Weak references to all `FinalizationRegistry` objects created by this context.
+    pub(crate) finalization_registries: Vec<WeakGc<ErasedVTableObject>>,
+
+    /// The stacks captured when the `Error` objects of this context were created.
+    error_stacks: WeakMap<ErasedVTableObject, ErrorStack>,
 
     can_block: bool,
 
@@ -118,6 +127,12 @@ pub struct Context {
 
     /// Unique identifier for each parser instance used during the context lifetime.
     parser_identifier: u32,
+
+    /// The garbage collected heap of the context.
+    ///
+    /// This must be the last field, so that the heap is dropped after all the values of the
+    /// context.
+    heap: GcHeap,
 }
 
 impl std::fmt::Debug for Context {
@@ -186,6 +201,7 @@ impl Context {
     #[allow(clippy::unit_arg, dropping_copy_types)]
     pub fn eval<R: ReadChar>(&mut self, src: Source<'_, R>) -> JsResult<JsValue> {
         let main_timer = Profiler::global().start_event("Script evaluation", "Main");
+        let _heap = self.heap.enter();
 
         let result = Script::parse(src, None, self)?.evaluate(self);
 
@@ -250,6 +266,7 @@ impl Context {
         K: Into<PropertyKey>,
         V: Into<JsValue>,
     {
+        let _heap = self.heap.enter();
         self.global_object().define_property_or_throw(
             key,
             PropertyDescriptor::builder()
@@ -280,6 +297,7 @@ impl Context {
         length: usize,
         body: NativeFunction,
     ) -> JsResult<()> {
+        let _heap = self.heap.enter();
         let function = FunctionObjectBuilder::new(self.realm(), body)
             .name(name.clone())
             .length(length)
@@ -313,6 +331,7 @@ impl Context {
         length: usize,
         body: NativeFunction,
     ) -> JsResult<()> {
+        let _heap = self.heap.enter();
         let function = FunctionObjectBuilder::new(self.realm(), body)
             .name(name.clone())
             .length(length)
@@ -353,6 +372,7 @@ impl Context {
                 .into());
         }
 
+        let _heap = self.heap.enter();
         let mut class_builder = ClassBuilder::new::<C>(self);
         C::init(&mut class_builder)?;
 
@@ -478,8 +498,13 @@ impl Context {
     }
 
     /// Runs all the jobs in the job queue.
+    ///
+    /// Before running the queue, this enqueues the cleanup jobs of all `FinalizationRegistry`
+    /// objects with collected targets.
     #[inline]
     pub fn run_jobs(&mut self) {
+        let _heap = self.heap.enter();
+        FinalizationRegistry::enqueue_cleanup_jobs(self);
         self.job_queue().run_jobs(self);
         self.clear_kept_objects();
     }
@@ -493,6 +518,7 @@ impl Context {
     /// provide a custom implementor of `JobQueue` to the context.
     #[allow(clippy::future_not_send)]
     pub async fn run_jobs_async(&mut self) {
+        FinalizationRegistry::enqueue_cleanup_jobs(self);
         self.job_queue().run_jobs_async(self).await;
         self.clear_kept_objects();
     }
@@ -510,12 +536,77 @@ impl Context {
         self.kept_alive.clear();
     }
 
+    /// Returns the garbage collected heap of the context.
+    ///
+    /// The heap is entered while the context parses, compiles or runs code, and by the methods of
+    /// the context that create values (e.g. [`Context::register_global_class`]). Objects created
+    /// through the Rust API outside of those calls (e.g. with [`JsObject::with_object_proto`] or
+    /// [`ObjectInitializer`](crate::object::ObjectInitializer)) are allocated in the heap of their
+    /// prototype, which is the heap of the context for the intrinsics of its realms. Other values,
+    /// like objects with a `null` prototype or symbols, are allocated in the current heap of the
+    /// thread, unless the embedder enters the heap of the context with [`GcHeap::enter`].
+    ///
+    /// References between heaps keep the referenced values alive like roots, so a cycle of values
+    /// that spans two heaps can't be collected until all of its values end up in the same heap,
+    /// e.g. after the other heap is dropped.
+    #[inline]
+    #[must_use]
+    pub const fn heap(&self) -> &GcHeap {
+        &self.heap
+    }
+
+    /// Takes a snapshot of the garbage collected heap of the context.
+    ///
+    /// The snapshot contains every value of the heap reachable from its roots.
+    #[must_use]
+    pub fn heap_snapshot(&self) -> HeapSnapshot {
+        self.heap.snapshot()
+    }
+
+    /// Takes a snapshot of the garbage collected heap of the context and writes it to `path` in the
+    /// `.heapsnapshot` format of the Chrome `DevTools`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created or written.
+    pub fn write_heap_snapshot<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.heap_snapshot().write_json(File::create(path)?)
+    }
+
     /// Retrieves the current stack trace of the context.
     #[inline]
     pub fn stack_trace(&self) -> impl Iterator<Item = &CallFrame> {
         self.vm.frames.iter().rev()
     }
 
+    /// Captures the innermost [`STACK_TRACE_LIMIT`] frames of the current stack trace.
+    pub(crate) fn capture_stack(&self) -> Rc<[StackFrame]> {
+        self.stack_trace()
+            .take(STACK_TRACE_LIMIT)
+            .map(|frame| {
+                StackFrame::new(
+                    frame.code_block().name().clone(),
+                    frame
+                        .active_runnable
+                        .as_ref()
+                        .and_then(ActiveRunnable::path)
+                        .map(Rc::from),
+                    frame.code_block().position_at(frame.pc),
+                )
+            })
+            .collect()
+    }
+
+    /// Records `stack` as the stack captured when the `Error` object `error` was created.
+    pub(crate) fn set_error_stack(&mut self, error: &JsObject, stack: Rc<[StackFrame]>) {
+        self.error_stacks.insert(error.inner(), ErrorStack(stack));
+    }
+
+    /// Gets the stack captured when the `Error` object `error` was created, if any.
+    pub(crate) fn error_stack(&self, error: &JsObject) -> Option<Rc<[StackFrame]>> {
+        self.error_stacks.get(error.inner()).map(|stack| stack.0)
+    }
+
     /// Replaces the currently active realm with `realm`, and returns the old realm.
     #[inline]
     pub fn enter_realm(&mut self, realm: Realm) -> Realm {
@@ -527,6 +618,7 @@ impl Context {
 
     /// Create a new Realm with the default global bindings.
     pub fn create_realm(&mut self) -> JsResult<Realm> {
+        let _heap = self.heap.enter();
         let realm = Realm::create(self.host_hooks, &self.root_shape)?;
 
         let old_realm = self.enter_realm(realm);
@@ -856,6 +948,8 @@ pub struct ContextBuilder {
     job_queue: Option<Rc<dyn JobQueue>>,
     module_loader: Option<Rc<dyn ModuleLoader>>,
     can_block: bool,
+    gc_config: Option<GcConfig>,
+    heap: Option<GcHeap>,
     #[cfg(feature = "intl")]
     icu: Option<icu::IntlProvider>,
     #[cfg(feature = "fuzz")]
@@ -880,7 +974,9 @@ impl std::fmt::Debug for ContextBuilder {
                 "module_loader",
                 &self.module_loader.as_ref().map(|_| ModuleLoader),
             )
-            .field("can_block", &self.can_block);
+            .field("can_block", &self.can_block)
+            .field("gc_config", &self.gc_config)
+            .field("heap", &self.heap);
 
         #[cfg(feature = "intl")]
         out.field("icu", &self.icu);
@@ -998,6 +1094,55 @@ impl ContextBuilder {
         self
     }
 
+    /// Sets the configuration of the garbage collected heap of the context.
+    ///
+    /// Setting a maximum heap size with [`GcConfig::set_max_heap_size`] makes the execution
+    /// throw a [`RuntimeLimit`] error after an allocation goes over the limit. JavaScript code
+    /// can't intercept the error with `try`/`catch`, but the host can: it is returned as the
+    /// [`JsError`](crate::JsError) of the call that went over the limit (e.g. [`Context::eval`]).
+    ///
+    /// The limit is soft for the values of the heap: it is only checked on loop iterations, on
+    /// function calls and after native functions return, so the heap can go over the limit before
+    /// the error is thrown.
+    ///
+    /// The memory that values own outside of the heap (the contents of strings, the elements of
+    /// arrays and the data of array buffers) is not counted, but growing it is checked where it is
+    /// allocated: creating a string, array storage or buffer that doesn't fit in the remaining
+    /// space of the heap throws the error immediately.
+    ///
+    /// # Note
+    ///
+    /// The configuration applies to every context that shares the heap, see
+    /// [`ContextBuilder::heap`].
+    ///
+    /// [`RuntimeLimit`]: crate::JsNativeErrorKind::RuntimeLimit
+    #[must_use]
+    pub const fn gc_config(mut self, gc_config: GcConfig) -> Self {
+        self.gc_config = Some(gc_config);
+        self
+    }
+
+    /// Sets the garbage collected heap of the context.
+    ///
+    /// By default, a context uses the current heap of the thread, which is shared by all the
+    /// contexts that don't have their own heap. Giving each context a separate heap with
+    /// [`GcHeap::new`] isolates their collections, configuration and statistics. A heap can also
+    /// be shared by several contexts by cloning its handle.
+    ///
+    /// Dropping the last handle of a heap, e.g. by dropping its only context, frees all of its
+    /// unreachable values at once. The values that are still referenced from outside of the heap
+    /// (e.g. a [`JsObject`] kept by the embedder) are not freed, but moved to the default heap of
+    /// the thread.
+    ///
+    /// Values can reference values of other heaps, but those references keep the referenced
+    /// values alive until the referencing values are freed. See [`Context::heap`] for the values
+    /// that are allocated in the heap of the context.
+    #[must_use]
+    pub fn heap(mut self, heap: GcHeap) -> Self {
+        self.heap = Some(heap);
+        self
+    }
+
     /// Specifies the number of instructions remaining to the [`Context`].
     ///
     /// This function is only available if the `fuzz` feature is enabled.
@@ -1025,6 +1170,12 @@ impl ContextBuilder {
             CANNOT_BLOCK_COUNTER.set(CANNOT_BLOCK_COUNTER.get() + 1);
         }
 
+        let heap = self.heap.unwrap_or_else(GcHeap::current);
+        if let Some(gc_config) = self.gc_config {
+            heap.set_config(gc_config);
+        }
+        let _heap = heap.enter();
+
         let root_shape = RootShape::default();
 
         let host_hooks = self.host_hooks.unwrap_or(&DefaultHooks);
@@ -1066,6 +1217,8 @@ impl ContextBuilder {
             #[cfg(feature = "fuzz")]
             instructions_remaining: self.instructions_remaining,
             kept_alive: Vec::new(),
+            finalization_registries: Vec::new(),
+            error_stacks: WeakMap::new(),
             host_hooks,
             job_queue,
             module_loader,
@@ -1073,6 +1226,7 @@ impl ContextBuilder {
             root_shape,
             parser_identifier: 0,
             can_block: self.can_block,
+            heap,
         };
 
         builtins::set_default_global_bindings(&mut context)?;
@@ -89,6 +89,15 @@ impl CompileTimeEnvironment {
         self.bindings.borrow().len() as u32
     }
 
+    /// Returns the names of the bindings in this environment, with their indices.
+    pub(crate) fn binding_names(&self) -> Vec<(JsString, u32)> {
+        self.bindings
+            .borrow()
+            .iter()
+            .map(|(name, binding)| (name.clone(), binding.index))
+            .collect()
+    }
+
     /// Returns the index of this environment.
     pub(crate) fn environment_index(&self) -> u32 {
         self.environment_index
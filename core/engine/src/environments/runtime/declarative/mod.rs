@@ -5,7 +5,7 @@ mod module;
 
 use std::{cell::Cell, rc::Rc};
 
-use boa_gc::{Finalize, GcRefCell, Trace};
+use boa_gc::{EdgeName, Finalize, GcRefCell, NodeKind, Trace, Tracer};
 pub(crate) use function::{FunctionEnvironment, FunctionSlots, ThisBindingStatus};
 pub(crate) use global::GlobalEnvironment;
 pub(crate) use lexical::LexicalEnvironment;
@@ -33,15 +33,39 @@ use crate::{environments::CompileTimeEnvironment, JsObject, JsResult, JsValue};
 /// If bindings where added at runtime, the current environment and all inner environments
 /// are marked as poisoned.
 /// All poisoned environments have to be checked for added bindings.
-#[derive(Debug, Trace, Finalize)]
+#[derive(Debug, Finalize)]
 pub(crate) struct DeclarativeEnvironment {
     kind: DeclarativeEnvironmentKind,
 
-    // Safety: Nothing in CompileTimeEnvironment needs tracing.
-    #[unsafe_ignore_trace]
+    // Nothing in CompileTimeEnvironment needs tracing.
     compile: Rc<CompileTimeEnvironment>,
 }
 
+// SAFETY: This traces the kind like the derived implementation, but it also names the bindings
+// of the environment in heap snapshots.
+unsafe impl Trace for DeclarativeEnvironment {
+    unsafe fn trace(&self, tracer: &mut Tracer) {
+        if tracer.is_recording() {
+            tracer.describe_node(NodeKind::Hidden, || "system / Context".to_owned());
+            // SAFETY: The recording tracer doesn't mark any value.
+            unsafe { self.trace_bindings(tracer) };
+        }
+
+        // SAFETY: The implementor must ensure that `trace` is correctly implemented.
+        unsafe { self.kind.trace(tracer) }
+    }
+
+    unsafe fn trace_non_roots(&self) {
+        // SAFETY: The implementor must ensure that `trace_non_roots` is correctly implemented.
+        unsafe { self.kind.trace_non_roots() }
+    }
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+        self.kind.run_finalizer();
+    }
+}
+
 impl DeclarativeEnvironment {
     /// Creates a new global `DeclarativeEnvironment`.
     pub(crate) fn global(global_this: JsObject) -> Self {
@@ -59,6 +83,32 @@ impl DeclarativeEnvironment {
         Self { kind, compile }
     }
 
+    /// Traces the values of the bindings, naming the edges after the bindings.
+    ///
+    /// # Safety
+    ///
+    /// See [`Trace`].
+    unsafe fn trace_bindings(&self, tracer: &mut Tracer) {
+        let environment = match &self.kind {
+            DeclarativeEnvironmentKind::Lexical(env) => env.poisonable_environment(),
+            DeclarativeEnvironmentKind::Global(env) => env.poisonable_environment(),
+            DeclarativeEnvironmentKind::Function(env) => env.poisonable_environment(),
+            DeclarativeEnvironmentKind::Module(_) => return,
+        };
+        let Ok(bindings) = environment.bindings().try_borrow() else {
+            return;
+        };
+
+        for (name, index) in self.compile.binding_names() {
+            if let Some(Some(value)) = bindings.get(index as usize) {
+                tracer.push_edge(|| EdgeName::Context(name.to_std_string_escaped()));
+                // SAFETY: The caller must ensure that `trace` is correctly called.
+                unsafe { value.trace(tracer) };
+                tracer.pop_edge();
+            }
+        }
+    }
+
     /// Gets the compile time environment of this environment.
     pub(crate) fn compile_env(&self) -> Rc<CompileTimeEnvironment> {
         self.compile.clone()
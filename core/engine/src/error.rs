@@ -1,6 +1,6 @@
 //! Error-related types and conversions.
 
-use std::{error, fmt};
+use std::{error, fmt, path::Path, rc::Rc};
 
 use crate::{
     builtins::{error::ErrorObject, Array},
@@ -11,6 +11,7 @@ use crate::{
     string::utf16,
     Context, JsString, JsValue,
 };
+use boa_ast::Position;
 use boa_gc::{custom_trace, Finalize, Trace};
 use thiserror::Error;
 
@@ -46,12 +47,25 @@ use thiserror::Error;
 /// let kind = &native_error.as_native().unwrap().kind;
 /// assert!(matches!(kind, JsNativeErrorKind::Type));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Trace, Finalize)]
+#[derive(Debug, Clone, Trace, Finalize)]
 #[boa_gc(unsafe_no_drop)]
 pub struct JsError {
     inner: Repr,
+
+    /// The call stack at the point where this error was created.
+    #[unsafe_ignore_trace]
+    stack: Option<Rc<[StackFrame]>>,
 }
 
+// The stack is only debugging information and doesn't take part in the equality of two errors.
+impl PartialEq for JsError {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl Eq for JsError {}
+
 /// Internal representation of a [`JsError`].
 ///
 /// `JsError` is represented by an opaque enum because it restricts
@@ -140,6 +154,7 @@ impl JsError {
     pub const fn from_native(err: JsNativeError) -> Self {
         Self {
             inner: Repr::Native(err),
+            stack: None,
         }
     }
 
@@ -157,6 +172,7 @@ impl JsError {
     pub const fn from_opaque(value: JsValue) -> Self {
         Self {
             inner: Repr::Opaque(value),
+            stack: None,
         }
     }
 
@@ -178,11 +194,40 @@ impl JsError {
     /// ```
     pub fn to_opaque(&self, context: &mut Context) -> JsValue {
         match &self.inner {
-            Repr::Native(e) => e.to_opaque(context).into(),
+            Repr::Native(e) => match &self.stack {
+                Some(stack) => e.to_opaque_with_stack(stack.clone(), context).into(),
+                None => e.to_opaque(context).into(),
+            },
             Repr::Opaque(v) => v.clone(),
         }
     }
 
+    /// Gets the frames of the call stack at the point where this error was created, starting
+    /// from the innermost frame.
+    ///
+    /// The stack of a native error is captured when it is first thrown by the engine, and the
+    /// stack of an `Error` object is captured when the object is created. This is empty for
+    /// errors that haven't been thrown from JavaScript code yet, and for thrown values that are
+    /// not `Error` objects. Only the innermost [`STACK_TRACE_LIMIT`] frames are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, Source};
+    /// let context = &mut Context::default();
+    /// let error = context
+    ///     .eval(Source::from_bytes("function f() {\n  null.x;\n}\nf();"))
+    ///     .unwrap_err();
+    ///
+    /// let frames = error.stack_frames();
+    /// assert_eq!(frames[0].function_name(), "f");
+    /// assert_eq!(frames[1].position().unwrap().line_number(), 4);
+    /// ```
+    #[must_use]
+    pub fn stack_frames(&self) -> &[StackFrame] {
+        self.stack.as_deref().unwrap_or_default()
+    }
+
     /// Unwraps the inner error if this contains a native error.
     /// Otherwise, inspects the opaque error and tries to extract the
     /// necessary information to construct a native error similar to the provided
@@ -301,6 +346,20 @@ impl JsError {
 
                         JsNativeErrorKind::Aggregate(error_list)
                     }
+                    ErrorObject::Suppressed => {
+                        let mut get = |property| {
+                            obj.get(js_string!(property), context)
+                                .map(|value| Box::new(Self::from_opaque(value)))
+                                .map_err(|e| TryNativeError::InaccessibleProperty {
+                                    property,
+                                    source: e,
+                                })
+                        };
+                        JsNativeErrorKind::Suppressed {
+                            error: get("error")?,
+                            suppressed: get("suppressed")?,
+                        }
+                    }
                 };
 
                 let realm = try_get_property(js_string!("constructor"), "constructor", context)?
@@ -422,6 +481,12 @@ impl JsError {
                     .map(|err| err.into_erased(context))
                     .collect(),
             ),
+            JsNativeErrorKind::Suppressed { error, suppressed } => {
+                JsErasedNativeErrorKind::Suppressed {
+                    error: Box::new(error.into_erased(context)),
+                    suppressed: Box::new(suppressed.into_erased(context)),
+                }
+            }
             JsNativeErrorKind::Error => JsErasedNativeErrorKind::Error,
             JsNativeErrorKind::Eval => JsErasedNativeErrorKind::Eval,
             JsNativeErrorKind::Range => JsErasedNativeErrorKind::Range,
@@ -459,6 +524,31 @@ impl JsError {
         self
     }
 
+    /// Injects the current call stack of `context` on the `stack` field of a native error.
+    ///
+    /// This is a no-op if the error is not native or if its stack was already captured. The
+    /// stack of opaque errors is attached by [`JsError::attach_error_stack`] instead.
+    pub(crate) fn inject_stack(mut self, context: &Context) -> Self {
+        if self.stack.is_none() && matches!(self.inner, Repr::Native(_)) {
+            self.stack = Some(context.capture_stack());
+        }
+        self
+    }
+
+    /// Attaches the stack captured when the `Error` object thrown by an opaque error was
+    /// created.
+    ///
+    /// This is a no-op if the error doesn't throw an `Error` object or if its stack was already
+    /// attached.
+    pub(crate) fn attach_error_stack(mut self, context: &Context) -> Self {
+        if self.stack.is_none() {
+            if let Repr::Opaque(JsValue::Object(error)) = &self.inner {
+                self.stack = context.error_stack(error);
+            }
+        }
+        self
+    }
+
     /// Is the [`JsError`] catchable in JavaScript.
     #[inline]
     pub(crate) fn is_catchable(&self) -> bool {
@@ -476,6 +566,7 @@ impl From<JsNativeError> for JsError {
     fn from(error: JsNativeError) -> Self {
         Self {
             inner: Repr::Native(error),
+            stack: None,
         }
     }
 }
@@ -489,6 +580,106 @@ impl fmt::Display for JsError {
     }
 }
 
+/// The maximum number of frames captured in the stack of an error.
+pub const STACK_TRACE_LIMIT: usize = 10;
+
+/// A frame of the call stack captured by a [`JsError`], or by the `stack` property of an
+/// `Error` object.
+///
+/// # Examples
+///
+/// ```rust
+/// # use boa_engine::{Context, Source};
+/// let context = &mut Context::default();
+/// let error = context
+///     .eval(Source::from_bytes("function fail() { throw new Error(); }\nfail();"))
+///     .unwrap_err();
+///
+/// let frames = error.stack_frames();
+/// assert_eq!(frames.len(), 2);
+/// assert_eq!(frames[0].to_string(), "fail (<anonymous>:1:25)");
+/// assert_eq!(frames[1].to_string(), "<anonymous>:2:1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrame {
+    function_name: JsString,
+    path: Option<Rc<Path>>,
+    position: Option<Position>,
+}
+
+impl StackFrame {
+    /// Creates a new `StackFrame`.
+    pub(crate) const fn new(
+        function_name: JsString,
+        path: Option<Rc<Path>>,
+        position: Option<Position>,
+    ) -> Self {
+        Self {
+            function_name,
+            path,
+            position,
+        }
+    }
+
+    /// Gets the name of the function executing in this frame.
+    ///
+    /// This is `<main>` for the top level code of scripts, modules and `eval` calls, and empty
+    /// for anonymous functions.
+    #[must_use]
+    pub const fn function_name(&self) -> &JsString {
+        &self.function_name
+    }
+
+    /// Gets the path of the script or module of this frame, if it was parsed from a file.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Gets the source position of the code executing in this frame, if known.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl fmt::Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let named = !self.function_name.is_empty() && self.function_name != *"<main>";
+        if named {
+            write!(f, "{} (", self.function_name.to_std_string_escaped())?;
+        }
+        match &self.path {
+            Some(path) => write!(f, "{}", path.display())?,
+            None => f.write_str("<anonymous>")?,
+        }
+        if let Some(position) = self.position {
+            write!(f, ":{position}")?;
+        }
+        if named {
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+/// The frames of the call stack captured when an `Error` object was created.
+#[derive(Debug, Clone, Trace, Finalize)]
+// Safety: `StackFrame` doesn't contain any traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct ErrorStack(pub(crate) Rc<[StackFrame]>);
+
+/// Formats the `stack` property of an error object in the format used by V8: the `header`
+/// (usually `name: message`) followed by one line for each frame of `frames`.
+pub(crate) fn format_stack(header: &str, frames: &[StackFrame]) -> JsString {
+    let mut stack = String::from(header);
+    for frame in frames {
+        stack.push_str("\n    at ");
+        stack.push_str(&frame.to_string());
+    }
+    js_string!(&*stack)
+}
+
 /// Native representation of an ideal `Error` object from Javascript.
 ///
 /// This representation is more space efficient than its [`JsObject`] equivalent,
@@ -594,6 +785,40 @@ impl JsNativeError {
         matches!(self.kind, JsNativeErrorKind::Aggregate(_))
     }
 
+    /// Creates a new `JsNativeError` of kind `SuppressedError` from an `error` and the error
+    /// it `suppressed`, with empty `message` and undefined `cause`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+    /// let error = JsNativeError::suppressed(
+    ///     JsNativeError::typ().into(),
+    ///     JsNativeError::range().into(),
+    /// );
+    ///
+    /// assert!(matches!(error.kind, JsNativeErrorKind::Suppressed { .. }));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn suppressed(error: JsError, suppressed: JsError) -> Self {
+        Self::new(
+            JsNativeErrorKind::Suppressed {
+                error: Box::new(error),
+                suppressed: Box::new(suppressed),
+            },
+            Box::default(),
+            None,
+        )
+    }
+
+    /// Check if it's a [`JsNativeErrorKind::Suppressed`].
+    #[must_use]
+    #[inline]
+    pub const fn is_suppressed(&self) -> bool {
+        matches!(self.kind, JsNativeErrorKind::Suppressed { .. })
+    }
+
     /// Creates a new `JsNativeError` of kind `Error`, with empty `message` and undefined `cause`.
     ///
     /// # Examples
@@ -897,6 +1122,17 @@ impl JsNativeError {
     /// If converting a [`JsNativeErrorKind::RuntimeLimit`] to an opaque object.
     #[inline]
     pub fn to_opaque(&self, context: &mut Context) -> JsObject {
+        let stack = context.capture_stack();
+        self.to_opaque_with_stack(stack, context)
+    }
+
+    /// Converts this native error to its opaque representation, using `stack` as the frames of
+    /// the `stack` property of the error object.
+    pub(crate) fn to_opaque_with_stack(
+        &self,
+        stack: Rc<[StackFrame]>,
+        context: &mut Context,
+    ) -> JsObject {
         let Self {
             kind,
             message,
@@ -912,6 +1148,10 @@ impl JsNativeError {
                 constructors.aggregate_error().prototype(),
                 ErrorObject::Aggregate,
             ),
+            JsNativeErrorKind::Suppressed { .. } => (
+                constructors.suppressed_error().prototype(),
+                ErrorObject::Suppressed,
+            ),
             JsNativeErrorKind::Error => (constructors.error().prototype(), ErrorObject::Error),
             JsNativeErrorKind::Eval => (constructors.eval_error().prototype(), ErrorObject::Eval),
             JsNativeErrorKind::Range => {
@@ -946,6 +1186,13 @@ impl JsNativeError {
             context,
         );
 
+        o.create_non_enumerable_data_property_or_throw(
+            js_string!("stack"),
+            format_stack(&self.to_string(), &stack),
+            context,
+        );
+        context.set_error_stack(&o, stack);
+
         if let Some(cause) = cause {
             o.create_non_enumerable_data_property_or_throw(
                 js_string!("cause"),
@@ -971,6 +1218,17 @@ impl JsNativeError {
             )
             .expect("The spec guarantees this succeeds for a newly created object ");
         }
+
+        if let JsNativeErrorKind::Suppressed { error, suppressed } = kind {
+            let error = error.to_opaque(context);
+            let suppressed = suppressed.to_opaque(context);
+            o.create_non_enumerable_data_property_or_throw(js_string!("error"), error, context);
+            o.create_non_enumerable_data_property_or_throw(
+                js_string!("suppressed"),
+                suppressed,
+                context,
+            );
+        }
         o
     }
 
@@ -1013,6 +1271,20 @@ pub enum JsNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
     Aggregate(Vec<JsError>),
+    /// An error that occurred while another error was being handled, and that suppressed it.
+    ///
+    /// More information:
+    /// - [Explicit Resource Management proposal][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error that was thrown last.
+        error: Box<JsError>,
+        /// The error that was being handled when `error` was thrown.
+        suppressed: Box<JsError>,
+    },
     /// A generic error. Commonly used as the base for custom exceptions.
     ///
     /// More information:
@@ -1097,6 +1369,10 @@ unsafe impl Trace for JsNativeErrorKind {
         mark,
         match &this {
             Self::Aggregate(errors) => mark(errors),
+            Self::Suppressed { error, suppressed } => {
+                mark(error);
+                mark(suppressed);
+            }
             Self::Error
             | Self::Eval
             | Self::Range
@@ -1117,6 +1393,7 @@ impl JsNativeErrorKind {
     pub(crate) fn is_catchable(&self) -> bool {
         match self {
             Self::Aggregate(_)
+            | Self::Suppressed { .. }
             | Self::Error
             | Self::Eval
             | Self::Range
@@ -1136,6 +1413,7 @@ impl PartialEq<ErrorObject> for JsNativeErrorKind {
         matches!(
             (self, other),
             (Self::Aggregate(_), ErrorObject::Aggregate)
+                | (Self::Suppressed { .. }, ErrorObject::Suppressed)
                 | (Self::Error, ErrorObject::Error)
                 | (Self::Eval, ErrorObject::Eval)
                 | (Self::Range, ErrorObject::Range)
@@ -1151,6 +1429,7 @@ impl fmt::Display for JsNativeErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Aggregate(_) => "AggregateError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Error => "Error",
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
@@ -1262,6 +1541,20 @@ pub enum JsErasedNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
     Aggregate(Vec<JsErasedError>),
+    /// An error that occurred while another error was being handled, and that suppressed it.
+    ///
+    /// More information:
+    /// - [Explicit Resource Management proposal][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error that was thrown last.
+        error: Box<JsErasedError>,
+        /// The error that was being handled when `error` was thrown.
+        suppressed: Box<JsErasedError>,
+    },
     /// A generic error. Commonly used as the base for custom exceptions.
     ///
     /// More information:
@@ -1340,6 +1633,7 @@ impl fmt::Display for JsErasedNativeErrorKind {
             Self::Aggregate(errors) => {
                 return write!(f, "AggregateError(error count: {})", errors.len());
             }
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Error => "Error",
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
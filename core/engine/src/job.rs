@@ -14,10 +14,23 @@
 //! - [`SimpleJobQueue`], which is a simple FIFO queue that runs all jobs to completion, bailing
 //! on the first error encountered.
 //!
+//! [`PendingFutures`] polls the [`FutureJob`]s of a queue, and can be reused by custom job queues.
+//!
 //! [Job]: https://tc39.es/ecma262/#sec-jobs
 //! [JobCallback]: https://tc39.es/ecma262/#sec-jobcallback-records
 
-use std::{cell::RefCell, collections::VecDeque, fmt::Debug, future::Future, pin::Pin};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{self, Poll, Wake, Waker},
+};
 
 use crate::{
     object::{JsFunction, NativeObject},
@@ -226,6 +239,25 @@ pub trait JobQueue {
     /// or `catch` handlers, even if `future` was already completed.
     fn enqueue_future_job(&self, future: FutureJob, context: &mut Context);
 
+    /// [`HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )`][spec].
+    ///
+    /// Enqueues a [`NativeJob`] that calls the cleanup callback of a `FinalizationRegistry` for
+    /// all of its collected targets.
+    ///
+    /// # Requirements
+    ///
+    /// Per the [spec]:
+    /// > An implementation of `HostEnqueueFinalizationRegistryCleanupJob` schedules `cleanupJob` to be performed
+    /// > at some future time, if possible. It must also conform to the requirements in [9.5][Jobs].
+    ///
+    /// By default forwards to [`JobQueue::enqueue_promise_job`].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    /// [Jobs]: https://tc39.es/ecma262/#sec-jobs
+    fn enqueue_finalization_registry_cleanup_job(&self, job: NativeJob, context: &mut Context) {
+        self.enqueue_promise_job(job, context);
+    }
+
     /// Asynchronously runs all jobs in the queue.
     ///
     /// Running a job could enqueue more jobs in the queue. The implementor of the trait
@@ -274,14 +306,129 @@ impl JobQueue for IdleJobQueue {
     fn enqueue_future_job(&self, _: FutureJob, _: &mut Context) {}
 }
 
+/// The [`Waker`] of some [`PendingFutures`], which records that one of them was woken up.
+#[derive(Debug)]
+struct FuturesWaker {
+    woken: AtomicBool,
+    #[cfg(not(target_family = "wasm"))]
+    thread: std::thread::Thread,
+}
+
+impl Wake for FuturesWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+        #[cfg(not(target_family = "wasm"))]
+        self.thread.unpark();
+    }
+}
+
+/// The [`FutureJob`]s of a job queue that are waiting to complete.
+///
+/// Futures are polled on the thread that created the `PendingFutures`, which is woken up when
+/// one of them can make progress. Custom job queues can use this to drive their futures like
+/// [`SimpleJobQueue`], either blocking on them with [`PendingFutures::wait`] or polling them
+/// again on the next iteration of their event loop.
+pub struct PendingFutures {
+    futures: RefCell<Vec<FutureJob>>,
+    waker: Arc<FuturesWaker>,
+}
+
+impl Default for PendingFutures {
+    fn default() -> Self {
+        Self {
+            futures: RefCell::default(),
+            waker: Arc::new(FuturesWaker {
+                woken: AtomicBool::new(false),
+                #[cfg(not(target_family = "wasm"))]
+                thread: std::thread::current(),
+            }),
+        }
+    }
+}
+
+impl Debug for PendingFutures {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PendingFutures")
+            .field("len", &self.futures.borrow().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PendingFutures {
+    /// Creates an empty `PendingFutures`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a future to the pending futures.
+    pub fn push(&self, future: FutureJob) {
+        self.futures.borrow_mut().push(future);
+    }
+
+    /// Returns `true` if there are no pending futures.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.futures.borrow().is_empty()
+    }
+
+    /// Polls the pending futures once, passing the jobs of the ones that completed to `enqueue`.
+    ///
+    /// This never blocks. Returns `true` if at least one future completed.
+    pub fn poll(&self, mut enqueue: impl FnMut(NativeJob)) -> bool {
+        self.waker.woken.store(false, Ordering::Release);
+        let waker = Waker::from(self.waker.clone());
+        let mut cx = task::Context::from_waker(&waker);
+
+        let futures = std::mem::take(&mut *self.futures.borrow_mut());
+        let mut completed = false;
+        for mut future in futures {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(job) => {
+                    enqueue(job);
+                    completed = true;
+                }
+                Poll::Pending => self.futures.borrow_mut().push(future),
+            }
+        }
+
+        completed
+    }
+
+    /// Blocks the current thread until one of the pending futures is woken up.
+    ///
+    /// Returns immediately if one of them was woken up since the last call to
+    /// [`PendingFutures::poll`], or if there are no pending futures.
+    ///
+    /// This is not available on wasm, where the thread cannot be parked.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn wait(&self) {
+        while !self.is_empty() && !self.waker.woken.swap(false, Ordering::Acquire) {
+            std::thread::park();
+        }
+    }
+}
+
 /// A simple FIFO job queue that bails on the first error.
 ///
 /// This is the default job queue for the [`Context`], but it is mostly pretty limited for
 /// custom event queues.
 ///
+/// Enqueued futures are polled by [`JobQueue::run_jobs`] using [`PendingFutures`]. It never
+/// blocks the thread: once there are no jobs left and none of the pending futures can make
+/// progress, it returns, and the futures that are still pending are polled again on its next
+/// call.
+///
 /// To disable running promise jobs on the engine, see [`IdleJobQueue`].
 #[derive(Default)]
-pub struct SimpleJobQueue(RefCell<VecDeque<NativeJob>>);
+pub struct SimpleJobQueue {
+    jobs: RefCell<VecDeque<NativeJob>>,
+    futures: PendingFutures,
+}
 
 impl Debug for SimpleJobQueue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -299,24 +446,32 @@ impl SimpleJobQueue {
 
 impl JobQueue for SimpleJobQueue {
     fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
-        self.0.borrow_mut().push_back(job);
+        self.jobs.borrow_mut().push_back(job);
     }
 
     fn run_jobs(&self, context: &mut Context) {
-        // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
-        // dropped after calling `pop_front`.
-        let mut next_job = self.0.borrow_mut().pop_front();
-        while let Some(job) = next_job {
-            if job.call(context).is_err() {
-                self.0.borrow_mut().clear();
+        loop {
+            // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
+            // dropped after calling `pop_front`.
+            let mut next_job = self.jobs.borrow_mut().pop_front();
+            while let Some(job) = next_job {
+                if job.call(context).is_err() {
+                    self.jobs.borrow_mut().clear();
+                    return;
+                }
+                next_job = self.jobs.borrow_mut().pop_front();
+            }
+
+            if !self
+                .futures
+                .poll(|job| self.jobs.borrow_mut().push_back(job))
+            {
                 return;
-            };
-            next_job = self.0.borrow_mut().pop_front();
+            }
         }
     }
 
-    fn enqueue_future_job(&self, future: FutureJob, context: &mut Context) {
-        let job = pollster::block_on(future);
-        self.enqueue_promise_job(job, context);
+    fn enqueue_future_job(&self, future: FutureJob, _: &mut Context) {
+        self.futures.push(future);
     }
 }
@@ -2,13 +2,18 @@ use std::path::{Path, PathBuf};
 
 use rustc_hash::FxHashMap;
 
-use boa_gc::GcRefCell;
+use boa_gc::{Ephemeron, Finalize, GcRefCell, Trace};
+use boa_interner::Interner;
 use boa_parser::Source;
 
 use crate::script::Script;
 use crate::{
-    js_string, object::JsObject, realm::Realm, vm::ActiveRunnable, Context, JsError, JsNativeError,
-    JsResult, JsString,
+    bytecompiler::ToJsString,
+    js_string,
+    object::JsObject,
+    realm::{self, Realm},
+    vm::ActiveRunnable,
+    Context, JsError, JsNativeError, JsResult, JsString,
 };
 
 use super::Module;
@@ -33,12 +38,155 @@ impl From<ActiveRunnable> for Referrer {
     }
 }
 
+/// An import attribute, defined by the `with` clause of an import or a re-export, or by the
+/// `with` option of a dynamic import.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#importattribute-record
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Trace, Finalize)]
+pub struct ImportAttribute {
+    key: JsString,
+    value: JsString,
+}
+
+impl ImportAttribute {
+    /// Creates a new `ImportAttribute` from its key and value.
+    #[must_use]
+    pub const fn new(key: JsString, value: JsString) -> Self {
+        Self { key, value }
+    }
+
+    /// Gets the key of the attribute.
+    #[must_use]
+    pub const fn key(&self) -> &JsString {
+        &self.key
+    }
+
+    /// Gets the value of the attribute.
+    #[must_use]
+    pub const fn value(&self) -> &JsString {
+        &self.value
+    }
+}
+
+/// A request to load a module, containing its specifier and the import attributes used to
+/// import it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-modulerequest-record
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Trace, Finalize)]
+pub struct ModuleRequest {
+    specifier: JsString,
+    attributes: Box<[ImportAttribute]>,
+}
+
+impl ModuleRequest {
+    /// Creates a new `ModuleRequest` from a specifier and its import attributes.
+    ///
+    /// The attributes are sorted by key, which makes two requests with the same attributes in
+    /// different order equal.
+    #[must_use]
+    pub fn new(specifier: JsString, mut attributes: Vec<ImportAttribute>) -> Self {
+        attributes.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+        Self {
+            specifier,
+            attributes: attributes.into_boxed_slice(),
+        }
+    }
+
+    /// Creates a new `ModuleRequest` without import attributes.
+    #[must_use]
+    pub fn from_specifier(specifier: JsString) -> Self {
+        Self {
+            specifier,
+            attributes: Box::default(),
+        }
+    }
+
+    /// Creates a `ModuleRequest` from its AST representation.
+    pub(crate) fn from_ast(
+        request: &boa_ast::declaration::ModuleRequest,
+        interner: &Interner,
+    ) -> Self {
+        Self::new(
+            request.specifier().to_js_string(interner),
+            request
+                .attributes()
+                .iter()
+                .map(|attribute| {
+                    ImportAttribute::new(
+                        attribute.key().to_js_string(interner),
+                        attribute.value().to_js_string(interner),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Gets the module specifier of the request.
+    #[must_use]
+    pub const fn specifier(&self) -> &JsString {
+        &self.specifier
+    }
+
+    /// Gets the import attributes of the request, sorted by key.
+    #[must_use]
+    pub fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+
+    /// Gets the value of the `type` import attribute, if present.
+    #[must_use]
+    pub fn module_type(&self) -> Option<&JsString> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.key() == &js_string!("type"))
+            .map(ImportAttribute::value)
+    }
+
+    /// Abstract operation [`AllImportAttributesSupported ( attributes )`][spec].
+    ///
+    /// Returns a `SyntaxError` if the request has an attribute with a key other than `type`,
+    /// which is the only key returned by `HostGetSupportedImportAttributes`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-AllImportAttributesSupported
+    pub(crate) fn check_supported_attributes(&self) -> JsResult<()> {
+        // 1. Let supported be HostGetSupportedImportAttributes().
+        // 2. For each ImportAttribute Record attribute of attributes, do
+        //     a. If supported does not contain attribute.[[Key]], return false.
+        // 3. Return true.
+        if let Some(attribute) = self
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key() != &js_string!("type"))
+        {
+            return Err(JsNativeError::syntax()
+                .with_message(format!(
+                    "unsupported import attribute `{}`",
+                    attribute.key().to_std_string_escaped()
+                ))
+                .into());
+        }
+        Ok(())
+    }
+}
+
+impl From<JsString> for ModuleRequest {
+    fn from(specifier: JsString) -> Self {
+        Self::from_specifier(specifier)
+    }
+}
+
 /// Module loading related host hooks.
 ///
 /// This trait allows to customize the behaviour of the engine on module load requests and
 /// `import.meta` requests.
 pub trait ModuleLoader {
-    /// Host hook [`HostLoadImportedModule ( referrer, specifier, hostDefined, payload )`][spec].
+    /// Host hook [`HostLoadImportedModule ( referrer, moduleRequest, hostDefined, payload )`][spec].
     ///
     /// This hook allows to customize the module loading functionality of the engine. Technically,
     /// this should call the [`FinishLoadingImportedModule`][finish] operation, but this simpler API just provides
@@ -46,17 +194,20 @@ pub trait ModuleLoader {
     ///
     /// # Requirements
     ///
-    /// - The host environment must perform `FinishLoadingImportedModule(referrer, specifier, payload, result)`,
-    /// where result is either a normal completion containing the loaded Module Record or a throw
-    /// completion, either synchronously or asynchronously. This is equivalent to calling the `finish_load`
-    /// callback.
-    /// - If this operation is called multiple times with the same `(referrer, specifier)` pair and
-    /// it performs FinishLoadingImportedModule(referrer, specifier, payload, result) where result
-    /// is a normal completion, then it must perform
-    /// `FinishLoadingImportedModule(referrer, specifier, payload, result)` with the same result each
-    /// time.
+    /// - The host environment must perform `FinishLoadingImportedModule(referrer, moduleRequest, payload, result)`,
+    ///   where result is either a normal completion containing the loaded Module Record or a throw
+    ///   completion, either synchronously or asynchronously. This is equivalent to calling the `finish_load`
+    ///   callback.
+    /// - If this operation is called multiple times with the same `(referrer, moduleRequest)` pair and
+    ///   it performs FinishLoadingImportedModule(referrer, moduleRequest, payload, result) where result
+    ///   is a normal completion, then it must perform
+    ///   `FinishLoadingImportedModule(referrer, moduleRequest, payload, result)` with the same result each
+    ///   time.
+    /// - If `moduleRequest.[[Attributes]]` has an entry whose key is `"type"` and whose value the host
+    ///   doesn't support, the host must fail the load with a throw completion. A `type` of `"json"`
+    ///   should be loaded as a JSON module, for example by using [`Module::parse_json`].
     /// - The operation must treat payload as an opaque value to be passed through to
-    /// `FinishLoadingImportedModule`. (can be ignored)
+    ///   `FinishLoadingImportedModule`. (can be ignored)
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
     /// [finish]: https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
@@ -64,7 +215,7 @@ pub trait ModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     );
@@ -114,7 +265,7 @@ impl ModuleLoader for IdleModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: Referrer,
-        _specifier: JsString,
+        _request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
@@ -129,15 +280,43 @@ impl ModuleLoader for IdleModuleLoader {
 
 /// A simple module loader that loads modules relative to a root path.
 ///
+/// Modules are cached per realm, which means a `ShadowRealm` importing the same path as its
+/// parent realm will get its own instance of the module. The cache doesn't keep the realms alive,
+/// so the modules of a realm are dropped along with it.
+///
+/// Files imported with the `type: "json"` import attribute are loaded as JSON modules, which
+/// export the parsed JSON value as their `default` export. A file imported both with and without
+/// the attribute gets a different module for each import.
+///
 /// # Note
 ///
 /// This loader only works by using the type methods [`SimpleModuleLoader::insert`] and
 /// [`SimpleModuleLoader::get`]. The utility methods on [`ModuleLoader`] don't work at the moment,
 /// but we'll unify both APIs in the future.
-#[derive(Debug)]
 pub struct SimpleModuleLoader {
     root: PathBuf,
-    module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
+    /// The modules loaded from each path, at most one per realm and kind.
+    ///
+    /// A module references its realm, so it is held by an ephemeron keyed by the realm instead,
+    /// which is cleared once the realm is collected.
+    module_map: GcRefCell<FxHashMap<PathBuf, Vec<Ephemeron<realm::Inner, CachedModule>>>>,
+}
+
+/// A module cached by a [`SimpleModuleLoader`].
+#[derive(Clone, Trace, Finalize)]
+struct CachedModule {
+    module: Module,
+
+    /// Whether the module was loaded with the `type: "json"` import attribute.
+    json: bool,
+}
+
+impl std::fmt::Debug for SimpleModuleLoader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SimpleModuleLoader")
+            .field("root", &self.root)
+            .finish_non_exhaustive()
+    }
 }
 
 impl SimpleModuleLoader {
@@ -161,15 +340,63 @@ impl SimpleModuleLoader {
     }
 
     /// Inserts a new module onto the module map.
+    ///
+    /// The module is cached as a JavaScript module, replacing any JavaScript module loaded from
+    /// the same path in the realm of `module`. It is dropped from the map once its realm is
+    /// collected.
     #[inline]
     pub fn insert(&self, path: PathBuf, module: Module) {
-        self.module_map.borrow_mut().insert(path, module);
+        self.insert_cached(
+            path,
+            CachedModule {
+                module,
+                json: false,
+            },
+        );
+    }
+
+    /// Inserts a module onto the module map, replacing any module of the same kind loaded from
+    /// the same path in its realm.
+    fn insert_cached(&self, path: PathBuf, cached: CachedModule) {
+        let mut map = self.module_map.borrow_mut();
+        let modules = map.entry(path).or_default();
+        modules.retain(Ephemeron::has_value);
+
+        let realm = cached.module.realm().clone();
+        let json = cached.json;
+        let entry = realm.ephemeron(cached);
+        if let Some(slot) = modules.iter_mut().find(|m| {
+            m.value()
+                .is_some_and(|m| m.module.realm() == &realm && m.json == json)
+        }) {
+            *slot = entry;
+        } else {
+            modules.push(entry);
+        }
     }
 
     /// Gets a module from its original path.
+    ///
+    /// If the path was loaded by several realms, this returns the first inserted module.
     #[inline]
     pub fn get(&self, path: &Path) -> Option<Module> {
-        self.module_map.borrow().get(path).cloned()
+        self.module_map
+            .borrow()
+            .get(path)
+            .and_then(|modules| modules.iter().find_map(Ephemeron::value))
+            .map(|cached| cached.module.clone())
+    }
+
+    /// Gets the module loaded from `path` in `realm`, either as a JSON module or as a
+    /// JavaScript module.
+    fn get_in_realm(&self, path: &Path, realm: &Realm, json: bool) -> Option<Module> {
+        self.module_map
+            .borrow()
+            .get(path)?
+            .iter()
+            .filter_map(Ephemeron::value)
+            .find(|m| m.module.realm() == realm && m.json == json)
+            .map(|cached| cached.module.clone())
     }
 }
 
@@ -177,12 +404,25 @@ impl ModuleLoader for SimpleModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
         let result = (|| {
-            let path = specifier
+            let json = match request.module_type() {
+                None => false,
+                Some(ty) if ty == &js_string!("json") => true,
+                Some(ty) => {
+                    return Err(JsNativeError::typ()
+                        .with_message(format!(
+                            "unsupported module type `{}`",
+                            ty.to_std_string_escaped()
+                        ))
+                        .into())
+                }
+            };
+            let path = request
+                .specifier()
                 .to_std_string()
                 .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
             let short_path = Path::new(&path);
@@ -195,9 +435,41 @@ impl ModuleLoader for SimpleModuleLoader {
                     ))
                     .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
             })?;
-            if let Some(module) = self.get(&path) {
+            if let Some(module) = self.get_in_realm(&path, context.realm(), json) {
+                return Ok(module);
+            }
+            if json {
+                let source = std::fs::read_to_string(&path).map_err(|err| {
+                    JsNativeError::typ()
+                        .with_message(format!("could not open file `{}`", short_path.display()))
+                        .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
+                })?;
+                let module =
+                    Module::parse_json(js_string!(source), None, context).map_err(|err| {
+                        JsNativeError::syntax()
+                            .with_message(format!(
+                                "could not parse JSON module `{}`",
+                                short_path.display()
+                            ))
+                            .with_cause(err)
+                    })?;
+                self.insert_cached(
+                    path,
+                    CachedModule {
+                        module: module.clone(),
+                        json: true,
+                    },
+                );
                 return Ok(module);
             }
+            if path.extension().is_some_and(|ext| ext == "json") {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "JSON module `{}` must be imported with `type: \"json\"`",
+                        short_path.display()
+                    ))
+                    .into());
+            }
             let source = Source::from_filepath(&path).map_err(|err| {
                 JsNativeError::typ()
                     .with_message(format!("could not open file `{}`", short_path.display()))
@@ -34,6 +34,7 @@ pub use synthetic::{SyntheticModule, SyntheticModuleInitializer};
 use std::cell::{Cell, RefCell};
 use std::collections::HashSet;
 use std::hash::Hash;
+use std::path::Path;
 use std::rc::Rc;
 
 use rustc_hash::FxHashSet;
@@ -44,9 +45,13 @@ use boa_parser::{Parser, Source};
 use boa_profiler::Profiler;
 
 use crate::{
-    builtins::promise::{PromiseCapability, PromiseState},
+    builtins::{
+        promise::{PromiseCapability, PromiseState},
+        Json, Promise,
+    },
     environments::DeclarativeEnvironment,
-    object::{JsObject, JsPromise},
+    js_string,
+    object::{FunctionObjectBuilder, JsObject, JsPromise},
     realm::Realm,
     Context, HostDefined, JsError, JsResult, JsString, JsValue, NativeFunction,
 };
@@ -75,6 +80,8 @@ struct ModuleRepr {
     namespace: GcRefCell<Option<JsObject>>,
     kind: ModuleKind,
     host_defined: HostDefined,
+    #[unsafe_ignore_trace]
+    path: Option<Rc<Path>>,
 }
 
 /// The kind of a [`Module`].
@@ -155,10 +162,18 @@ impl Module {
         context: &mut Context,
     ) -> JsResult<Self> {
         let _timer = Profiler::global().start_event("Module parsing", "Main");
+        let _heap = context.heap().enter();
+        let path = src.path().map(Rc::from);
         let mut parser = Parser::new(src);
         parser.set_identifier(context.next_parser_identifier());
         let module = parser.parse_module(context.interner_mut())?;
 
+        // It is a Syntax Error if AllImportAttributesSupported(attributes) is false for any
+        // import or re-export of the module.
+        for request in module.items().requests() {
+            ModuleRequest::from_ast(&request, context.interner()).check_supported_attributes()?;
+        }
+
         let src = SourceTextModule::new(module, context.interner());
 
         Ok(Self {
@@ -167,6 +182,7 @@ impl Module {
                 namespace: GcRefCell::default(),
                 kind: ModuleKind::SourceText(src),
                 host_defined: HostDefined::default(),
+                path,
             }),
         })
     }
@@ -194,10 +210,47 @@ impl Module {
                 namespace: GcRefCell::default(),
                 kind: ModuleKind::Synthetic(synth),
                 host_defined: HostDefined::default(),
+                path: None,
             }),
         }
     }
 
+    /// Abstract operation [`ParseJSONModule ( source )`][spec].
+    ///
+    /// Parses `source` as a JSON text, creating a Synthetic Module with a single `default` export
+    /// containing the parsed value, optionally in a root realm.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if `source` is not a valid JSON text.
+    ///
+    /// [spec]: https://tc39.es/proposal-json-modules/#sec-parse-json-module
+    pub fn parse_json(
+        source: JsString,
+        realm: Option<Realm>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let json be ? Call(%JSON.parse%, undefined, « source »).
+        let json = Json::parse(&JsValue::undefined(), &[source.into()], context)?;
+
+        // 2. Return CreateDefaultExportSyntheticModule(json).
+        Ok(Self::synthetic(
+            &[js_string!("default")],
+            SyntheticModuleInitializer::from_copy_closure_with_captures(
+                |module, json, _| {
+                    // `CreateDefaultExportSyntheticModule ( defaultExport )`
+                    // https://tc39.es/proposal-json-modules/#sec-create-default-export-synthetic-module
+                    // 1. Let closure be a new Abstract Closure with parameters (module) that captures defaultExport and performs the following steps when called:
+                    //     a. Return ? module.SetSyntheticExport("default", defaultExport).
+                    module.set_export(&js_string!("default"), json.clone())
+                },
+                json,
+            ),
+            realm,
+            context,
+        ))
+    }
+
     /// Gets the realm of this `Module`.
     #[inline]
     #[must_use]
@@ -214,6 +267,13 @@ impl Module {
         &self.inner.host_defined
     }
 
+    /// Gets the path of the file this module was parsed from, if any.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.inner.path.as_deref()
+    }
+
     /// Gets the kind of this `Module`.
     pub(crate) fn kind(&self) -> &ModuleKind {
         &self.inner.kind
@@ -236,6 +296,7 @@ impl Module {
     #[allow(clippy::missing_panics_doc)]
     #[inline]
     pub fn load(&self, context: &mut Context) -> JsPromise {
+        let _heap = context.heap().enter();
         match self.kind() {
             ModuleKind::SourceText(_) => {
                 // Concrete method [`LoadRequestedModules ( [ hostDefined ] )`][spec].
@@ -373,6 +434,7 @@ impl Module {
     #[allow(clippy::missing_panics_doc)]
     #[inline]
     pub fn link(&self, context: &mut Context) -> JsResult<()> {
+        let _heap = context.heap().enter();
         match self.kind() {
             ModuleKind::SourceText(src) => src.link(self, context),
             ModuleKind::Synthetic(synth) => {
@@ -417,6 +479,7 @@ impl Module {
     /// [spec]: https://tc39.es/ecma262/#table-abstract-methods-of-module-records
     #[inline]
     pub fn evaluate(&self, context: &mut Context) -> JsPromise {
+        let _heap = context.heap().enter();
         match self.kind() {
             ModuleKind::SourceText(src) => src.evaluate(self, context),
             ModuleKind::Synthetic(synth) => synth.evaluate(self, context),
@@ -581,3 +644,174 @@ impl Hash for Module {
         std::ptr::hash(self.inner.as_ref(), state);
     }
 }
+
+/// Loads the module identified by `specifier` for a dynamic import, resolving `cap` with the
+/// namespace of the module after linking and evaluating it.
+///
+/// This performs [`HostLoadImportedModule ( referrer, moduleRequest, empty, promiseCapability )`][load],
+/// continuing with [`FinishLoadingImportedModule`][finish] and [`ContinueDynamicImport`][continue]
+/// when the module loader finishes loading the module.
+///
+/// [load]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+/// [finish]: https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
+/// [continue]: https://tc39.es/ecma262/#sec-ContinueDynamicImport
+pub(crate) fn load_dynamic_import(
+    referrer: Referrer,
+    request: ModuleRequest,
+    cap: PromiseCapability,
+    context: &mut Context,
+) {
+    context.module_loader().load_imported_module(
+        referrer.clone(),
+        request.clone(),
+        Box::new(move |completion, context| {
+            // `ContinueDynamicImport ( promiseCapability, moduleCompletion )`
+            // https://tc39.es/ecma262/#sec-ContinueDynamicImport
+
+            // `FinishLoadingImportedModule ( referrer, moduleRequest, payload, result )`
+            // https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
+            let module = match completion {
+                // 1. If result is a normal completion, then
+                Ok(m) => {
+                    match referrer {
+                        Referrer::Module(module) => {
+                            let ModuleKind::SourceText(src) = module.kind() else {
+                                panic!("referrer cannot be a synthetic module");
+                            };
+
+                            let mut loaded_modules = src.loaded_modules().borrow_mut();
+
+                            //     a. If referrer.[[LoadedModules]] contains a LoadedModuleRequest Record record such that ModuleRequestsEqual(record, moduleRequest) is true, then
+                            //     b. Else,
+                            //         i. Append the LoadedModuleRequest Record { [[Specifier]]: moduleRequest.[[Specifier]], [[Attributes]]: moduleRequest.[[Attributes]], [[Module]]: result.[[Value]] } to referrer.[[LoadedModules]].
+                            let entry = loaded_modules.entry(request).or_insert_with(|| m.clone());
+
+                            //         i. Assert: That Record's [[Module]] is result.[[Value]].
+                            debug_assert_eq!(&m, entry);
+
+                            // Same steps apply to referrers below
+                        }
+                        Referrer::Realm(realm) => {
+                            let mut loaded_modules = realm.loaded_modules().borrow_mut();
+                            let entry = loaded_modules.entry(request).or_insert_with(|| m.clone());
+                            debug_assert_eq!(&m, entry);
+                        }
+                        Referrer::Script(script) => {
+                            let mut loaded_modules = script.loaded_modules().borrow_mut();
+                            let entry = loaded_modules.entry(request).or_insert_with(|| m.clone());
+                            debug_assert_eq!(&m, entry);
+                        }
+                    }
+
+                    m
+                }
+                // 1. If moduleCompletion is an abrupt completion, then
+                Err(err) => {
+                    // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « moduleCompletion.[[Value]] »).
+                    let err = err.to_opaque(context);
+                    cap.reject()
+                        .call(&JsValue::undefined(), &[err], context)
+                        .expect("default `reject` function cannot throw");
+
+                    // b. Return unused.
+                    return;
+                }
+            };
+
+            // 2. Let module be moduleCompletion.[[Value]].
+            // 3. Let loadPromise be module.LoadRequestedModules().
+            let load = module.load(context);
+
+            // 4. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures promiseCapability and performs the following steps when called:
+            // 5. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+            let on_rejected = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, cap, context| {
+                        //     a. Perform ! Call(promiseCapability.[[Reject]], undefined, « reason »).
+                        cap.reject()
+                            .call(&JsValue::undefined(), args, context)
+                            .expect("default `reject` function cannot throw");
+
+                        //     b. Return unused.
+                        Ok(JsValue::undefined())
+                    },
+                    cap.clone(),
+                ),
+            )
+            .build();
+
+            // 6. Let linkAndEvaluateClosure be a new Abstract Closure with no parameters that captures module, promiseCapability, and onRejected and performs the following steps when called:
+            // 7. Let linkAndEvaluate be CreateBuiltinFunction(linkAndEvaluateClosure, 0, "", « »).
+            let link_evaluate = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, _, (module, cap, on_rejected), context| {
+                        // a. Let link be Completion(module.Link()).
+                        // b. If link is an abrupt completion, then
+                        if let Err(e) = module.link(context) {
+                            // i. Perform ! Call(promiseCapability.[[Reject]], undefined, « link.[[Value]] »).
+                            let e = e.to_opaque(context);
+                            cap.reject()
+                                .call(&JsValue::undefined(), &[e], context)
+                                .expect("default `reject` function cannot throw");
+                            // ii. Return unused.
+                            return Ok(JsValue::undefined());
+                        }
+
+                        // c. Let evaluatePromise be module.Evaluate().
+                        let evaluate = module.evaluate(context);
+
+                        // d. Let fulfilledClosure be a new Abstract Closure with no parameters that captures module and promiseCapability and performs the following steps when called:
+                        // e. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 0, "", « »).
+                        let fulfill = FunctionObjectBuilder::new(
+                            context.realm(),
+                            NativeFunction::from_copy_closure_with_captures(
+                                |_, _, (module, cap), context| {
+                                    // i. Let namespace be GetModuleNamespace(module).
+                                    let namespace = module.namespace(context);
+
+                                    // ii. Perform ! Call(promiseCapability.[[Resolve]], undefined, « namespace »).
+                                    cap.resolve()
+                                        .call(&JsValue::undefined(), &[namespace.into()], context)
+                                        .expect("default `resolve` function cannot throw");
+
+                                    // iii. Return unused.
+                                    Ok(JsValue::undefined())
+                                },
+                                (module.clone(), cap.clone()),
+                            ),
+                        )
+                        .build();
+
+                        // f. Perform PerformPromiseThen(evaluatePromise, onFulfilled, onRejected).
+                        Promise::perform_promise_then(
+                            &evaluate,
+                            Some(fulfill),
+                            Some(on_rejected.clone()),
+                            None,
+                            context,
+                        );
+
+                        // g. Return unused.
+                        Ok(JsValue::undefined())
+                    },
+                    (module.clone(), cap.clone(), on_rejected.clone()),
+                ),
+            )
+            .build();
+
+            // 8. Perform PerformPromiseThen(loadPromise, linkAndEvaluate, onRejected).
+            Promise::perform_promise_then(
+                &load,
+                Some(link_evaluate),
+                Some(on_rejected),
+                None,
+                context,
+            );
+
+            // 9. Return unused.
+        }),
+        context,
+    );
+}
@@ -34,7 +34,8 @@ use crate::{
 };
 
 use super::{
-    BindingName, GraphLoadingState, Module, Referrer, ResolveExportError, ResolvedBinding,
+    BindingName, GraphLoadingState, Module, ModuleRequest, Referrer, ResolveExportError,
+    ResolvedBinding,
 };
 
 /// Information for the [**Depth-first search**] algorithm used in the
@@ -210,7 +211,7 @@ impl std::fmt::Debug for SourceTextContext {
 #[derive(Trace, Finalize)]
 pub(crate) struct SourceTextModule {
     status: GcRefCell<ModuleStatus>,
-    loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
+    loaded_modules: GcRefCell<FxHashMap<ModuleRequest, Module>>,
     async_parent_modules: GcRefCell<Vec<Module>>,
     import_meta: GcRefCell<Option<JsObject>>,
     #[unsafe_ignore_trace]
@@ -231,12 +232,12 @@ impl std::fmt::Debug for SourceTextModule {
 #[derive(Debug)]
 struct ModuleCode {
     has_tla: bool,
-    requested_modules: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
+    requested_modules: IndexSet<ModuleRequest, BuildHasherDefault<FxHasher>>,
     source: boa_ast::Module,
     import_entries: Vec<ImportEntry>,
     local_export_entries: Vec<LocalExportEntry>,
     indirect_export_entries: Vec<IndirectExportEntry>,
-    star_export_entries: Vec<JsString>,
+    star_export_entries: Vec<ModuleRequest>,
 }
 
 impl SourceTextModule {
@@ -251,7 +252,7 @@ impl SourceTextModule {
             .items()
             .requests()
             .iter()
-            .map(|name| name.to_js_string(interner))
+            .map(|request| ModuleRequest::from_ast(request, interner))
             .collect();
         // 4. Let importEntries be ImportEntries of body.
         let import_entries = code.items().import_entries();
@@ -276,7 +277,7 @@ impl SourceTextModule {
                     if let Some((module, import)) =
                         import_entries.iter().find_map(|ie| match ie.import_name() {
                             ImportName::Name(name) if ie.local_name() == entry.local_name() => {
-                                Some((ie.module_request(), name))
+                                Some((ie.module_request().clone(), name))
                             }
                             _ => None,
                         })
@@ -305,7 +306,7 @@ impl SourceTextModule {
                 ExportEntry::StarReExport { module_request } => {
                     // i. Assert: ee.[[ExportName]] is null.
                     // ii. Append ee to starExportEntries.
-                    star_export_entries.push(module_request.to_js_string(interner));
+                    star_export_entries.push(ModuleRequest::from_ast(&module_request, interner));
                 }
                 // c. Else,
                 //    i. Append ee to indirectExportEntries.
@@ -367,27 +368,32 @@ impl SourceTextModule {
             state
                 .pending_modules
                 .set(state.pending_modules.get() + requested.len());
-            // d. For each String required of module.[[RequestedModules]], do
+            // d. For each ModuleRequest Record request of module.[[RequestedModules]], do
             for required in requested.iter().cloned() {
-                // i. If module.[[LoadedModules]] contains a Record whose [[Specifier]] is required, then
+                // i. If AllImportAttributesSupported(request.[[Attributes]]) is false, then
+                //    1. Let error be ThrowCompletion(a newly created SyntaxError object).
+                //    2. Perform ContinueModuleLoading(state, error).
+                // Note: this is checked as an early error when parsing the module.
+
+                // ii. Else if module.[[LoadedModules]] contains a LoadedModuleRequest Record record such that ModuleRequestsEqual(record, request) is true, then
                 let loaded = self.loaded_modules.borrow().get(&required).cloned();
                 if let Some(loaded) = loaded {
                     // 1. Let record be that Record.
                     // 2. Perform InnerModuleLoading(state, record.[[Module]]).
                     loaded.inner_load(state, context);
                 } else {
-                    //    ii. Else,
-                    //       1. Perform HostLoadImportedModule(module, required, state.[[HostDefined]], state).
+                    //    iii. Else,
+                    //       1. Perform HostLoadImportedModule(module, request, state.[[HostDefined]], state).
                     //       2. NOTE: HostLoadImportedModule will call FinishLoadingImportedModule, which re-enters
                     //          the graph loading process through ContinueModuleLoading.
-                    let name_specifier = required.clone();
+                    let request = required.clone();
                     let src = module_self.clone();
                     let state = state.clone();
                     context.module_loader().load_imported_module(
                         Referrer::Module(module_self.clone()),
-                        name_specifier,
+                        request,
                         Box::new(move |completion, context| {
-                            // FinishLoadingImportedModule ( referrer, specifier, payload, result )
+                            // FinishLoadingImportedModule ( referrer, moduleRequest, payload, result )
                             // https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
 
                             // 1. If result is a normal completion, then
@@ -395,9 +401,9 @@ impl SourceTextModule {
                                 let ModuleKind::SourceText(src) = src.kind() else {
                                     unreachable!("captured src must be a source text module");
                                 };
-                                // a. If referrer.[[LoadedModules]] contains a Record whose [[Specifier]] is specifier, then
+                                // a. If referrer.[[LoadedModules]] contains a LoadedModuleRequest Record record such that ModuleRequestsEqual(record, moduleRequest) is true, then
                                 // b. Else,
-                                //    i. Append the Record { [[Specifier]]: specifier, [[Module]]: result.[[Value]] } to referrer.[[LoadedModules]].
+                                //    i. Append the LoadedModuleRequest Record { [[Specifier]]: moduleRequest.[[Specifier]], [[Attributes]]: moduleRequest.[[Attributes]], [[Module]]: result.[[Value]] } to referrer.[[LoadedModules]].
                                 let mut loaded_modules = src.loaded_modules.borrow_mut();
                                 let entry = loaded_modules
                                     .entry(required)
@@ -561,7 +567,7 @@ impl SourceTextModule {
             // a. If SameValue(exportName, e.[[ExportName]]) is true, then
             if export_name == &e.export_name().to_js_string(interner) {
                 // i. Let importedModule be GetImportedModule(module, e.[[ModuleRequest]]).
-                let module_request = e.module_request().to_js_string(interner);
+                let module_request = ModuleRequest::from_ast(e.module_request(), interner);
                 let imported_module = self.loaded_modules.borrow()[&module_request].clone();
                 return match e.import_name() {
                     // ii. If e.[[ImportName]] is all, then
@@ -1444,7 +1450,8 @@ impl SourceTextModule {
             // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
             for entry in &self.code.import_entries {
                 // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
-                let module_request = entry.module_request().to_js_string(compiler.interner());
+                let module_request =
+                    ModuleRequest::from_ast(entry.module_request(), compiler.interner());
                 let imported_module = self.loaded_modules.borrow()[&module_request].clone();
 
                 if let ImportName::Name(name) = entry.import_name() {
@@ -1580,9 +1587,11 @@ impl SourceTextModule {
                         continue;
                     }
                     // i. If IsConstantDeclaration of d is true, then
-                    LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(
-                        c,
-                    )) => {
+                    LexicallyScopedDeclaration::LexicalDeclaration(
+                        LexicalDeclaration::Const(c)
+                        | LexicalDeclaration::Using(c)
+                        | LexicalDeclaration::AwaitUsing(c),
+                    ) => {
                         // a. For each element dn of the BoundNames of d, do
                         for name in bound_names(c) {
                             let name = name.to_js_string(compiler.interner());
@@ -1686,7 +1695,8 @@ impl SourceTextModule {
         for (index, locator) in functions {
             let code = codeblock.constant_function(index as usize);
 
-            let function = create_function_object_fast(code, context);
+            let script_or_module = context.get_active_script_or_module();
+            let function = create_function_object_fast(code, script_or_module, context);
 
             context.vm.environments.put_lexical_value(
                 locator.environment_index(),
@@ -1800,7 +1810,7 @@ impl SourceTextModule {
     }
 
     /// Gets the loaded modules of this module.
-    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
+    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<ModuleRequest, Module>> {
         &self.loaded_modules
     }
 
@@ -16,6 +16,7 @@ use crate::{
         FunctionObjectBuilder, JsData, JsFunction, JsPromise,
     },
     realm::Realm,
+    vm::check_heap_limit,
     Context, JsNativeError, JsObject, JsResult, JsValue,
 };
 
@@ -376,6 +377,10 @@ pub(crate) fn native_function_call(
     context.vm.native_active_function = None;
     context.swap_realm(&mut realm);
 
+    // Native functions don't run any loop of the vm, so they could allocate far past the heap
+    // limit without noticing it.
+    check_heap_limit()?;
+
     context.vm.push(result?);
 
     Ok(CallValue::Complete)
@@ -443,6 +448,10 @@ fn native_function_construct(
     context.vm.native_active_function = None;
     context.swap_realm(&mut realm);
 
+    // Native functions don't run any loop of the vm, so they could allocate far past the heap
+    // limit without noticing it.
+    check_heap_limit()?;
+
     context.vm.push(result?);
 
     Ok(CallValue::Complete)
@@ -0,0 +1,198 @@
+//! A Rust API wrapper for Boa's `Iterator` Builtin ECMAScript Object
+use crate::{
+    builtins::iterable::Iterator,
+    error::JsNativeError,
+    js_string,
+    object::{JsArray, JsFunction, JsObject, JsObjectType},
+    value::TryFromJs,
+    Context, JsResult, JsValue,
+};
+
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// `JsIterator` provides a wrapper for any ECMAScript object implementing the iterator protocol,
+/// exposing the methods of `Iterator.prototype` on it.
+///
+/// The wrapped object doesn't need to inherit from `Iterator.prototype`, which allows chaining
+/// iterator helpers on any object with a `next` method.
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct JsIterator {
+    inner: JsObject,
+}
+
+impl JsIterator {
+    /// Create a [`JsIterator`] from a [`JsObject`].
+    ///
+    /// The object is not checked to implement the iterator protocol; errors will be thrown
+    /// when advancing the iterator instead.
+    #[inline]
+    #[must_use]
+    pub const fn from_object(object: JsObject) -> Self {
+        Self { inner: object }
+    }
+
+    /// Calls `Iterator.from()`, creating a [`JsIterator`] from an iterable or an iterator.
+    #[inline]
+    pub fn from(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        Self::from_value(&Iterator::from(
+            &JsValue::undefined(),
+            std::slice::from_ref(value),
+            context,
+        )?)
+    }
+
+    /// Advances the `JsIterator`, returning the result object of the `next` method.
+    #[inline]
+    pub fn next(&self, context: &mut Context) -> JsResult<JsValue> {
+        self.inner.invoke(js_string!("next"), &[], context)
+    }
+
+    /// Calls `Iterator.prototype.map()`.
+    #[inline]
+    pub fn map(&self, mapper: JsFunction, context: &mut Context) -> JsResult<Self> {
+        Self::from_value(&Iterator::map(
+            &self.inner.clone().into(),
+            &[mapper.into()],
+            context,
+        )?)
+    }
+
+    /// Calls `Iterator.prototype.filter()`.
+    #[inline]
+    pub fn filter(&self, predicate: JsFunction, context: &mut Context) -> JsResult<Self> {
+        Self::from_value(&Iterator::filter(
+            &self.inner.clone().into(),
+            &[predicate.into()],
+            context,
+        )?)
+    }
+
+    /// Calls `Iterator.prototype.take()`.
+    #[inline]
+    pub fn take(&self, limit: f64, context: &mut Context) -> JsResult<Self> {
+        Self::from_value(&Iterator::take(
+            &self.inner.clone().into(),
+            &[limit.into()],
+            context,
+        )?)
+    }
+
+    /// Calls `Iterator.prototype.drop()`.
+    #[inline]
+    pub fn drop(&self, limit: f64, context: &mut Context) -> JsResult<Self> {
+        Self::from_value(&Iterator::drop(
+            &self.inner.clone().into(),
+            &[limit.into()],
+            context,
+        )?)
+    }
+
+    /// Calls `Iterator.prototype.flatMap()`.
+    #[inline]
+    pub fn flat_map(&self, mapper: JsFunction, context: &mut Context) -> JsResult<Self> {
+        Self::from_value(&Iterator::flat_map(
+            &self.inner.clone().into(),
+            &[mapper.into()],
+            context,
+        )?)
+    }
+
+    /// Calls `Iterator.prototype.reduce()`.
+    #[inline]
+    pub fn reduce(
+        &self,
+        reducer: JsFunction,
+        initial_value: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let mut args = vec![reducer.into()];
+        // `reduce` checks the presence of the initial value, so `undefined` cannot be passed
+        // instead.
+        if let Some(initial_value) = initial_value {
+            args.push(initial_value);
+        }
+        Iterator::reduce(&self.inner.clone().into(), &args, context)
+    }
+
+    /// Calls `Iterator.prototype.toArray()`.
+    #[inline]
+    pub fn to_array(&self, context: &mut Context) -> JsResult<JsArray> {
+        let array = Iterator::to_array(&self.inner.clone().into(), &[], context)?
+            .as_object()
+            .cloned()
+            .expect("Iterator.prototype.toArray should always return an object");
+
+        JsArray::from_object(array)
+    }
+
+    /// Calls `Iterator.prototype.forEach()`.
+    #[inline]
+    pub fn for_each(&self, callback: JsFunction, context: &mut Context) -> JsResult<()> {
+        Iterator::for_each(&self.inner.clone().into(), &[callback.into()], context)?;
+        Ok(())
+    }
+
+    /// Calls `Iterator.prototype.some()`.
+    #[inline]
+    pub fn some(&self, predicate: JsFunction, context: &mut Context) -> JsResult<bool> {
+        Iterator::some(&self.inner.clone().into(), &[predicate.into()], context)
+            .map(|v| v.to_boolean())
+    }
+
+    /// Calls `Iterator.prototype.every()`.
+    #[inline]
+    pub fn every(&self, predicate: JsFunction, context: &mut Context) -> JsResult<bool> {
+        Iterator::every(&self.inner.clone().into(), &[predicate.into()], context)
+            .map(|v| v.to_boolean())
+    }
+
+    /// Calls `Iterator.prototype.find()`.
+    #[inline]
+    pub fn find(&self, predicate: JsFunction, context: &mut Context) -> JsResult<JsValue> {
+        Iterator::find(&self.inner.clone().into(), &[predicate.into()], context)
+    }
+
+    fn from_value(value: &JsValue) -> JsResult<Self> {
+        value
+            .as_object()
+            .cloned()
+            .map(Self::from_object)
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("value is not an iterator object")
+                    .into()
+            })
+    }
+}
+
+impl From<JsIterator> for JsObject {
+    #[inline]
+    fn from(o: JsIterator) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsIterator> for JsValue {
+    #[inline]
+    fn from(o: JsIterator) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsIterator {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsIterator {}
+
+impl TryFromJs for JsIterator {
+    fn try_from_js(value: &JsValue, _context: &mut Context) -> JsResult<Self> {
+        Self::from_value(value)
+    }
+}
@@ -6,6 +6,7 @@ use boa_gc::{Finalize, Trace};
 use crate::{
     builtins::{set::ordered_set::OrderedSet, Set},
     error::JsNativeError,
+    native_function::NativeFunctionPointer,
     object::{JsFunction, JsObject, JsObjectType, JsSetIterator},
     value::TryFromJs,
     Context, JsResult, JsValue,
@@ -141,6 +142,89 @@ impl JsSet {
         )
     }
 
+    /// Returns a new set containing the elements of both this set and `other`.
+    ///
+    /// `other` can be any set-like object, such as a `Set` or a `Map`.
+    ///
+    /// Same as JavaScript's `set.union(other)`.
+    #[inline]
+    pub fn union(&self, other: &JsObject, context: &mut Context) -> JsResult<Self> {
+        self.set_operation(Set::union, other, context)
+    }
+
+    /// Returns a new set containing the elements that are both in this set and in `other`.
+    ///
+    /// `other` can be any set-like object, such as a `Set` or a `Map`.
+    ///
+    /// Same as JavaScript's `set.intersection(other)`.
+    #[inline]
+    pub fn intersection(&self, other: &JsObject, context: &mut Context) -> JsResult<Self> {
+        self.set_operation(Set::intersection, other, context)
+    }
+
+    /// Returns a new set containing the elements of this set that are not in `other`.
+    ///
+    /// `other` can be any set-like object, such as a `Set` or a `Map`.
+    ///
+    /// Same as JavaScript's `set.difference(other)`.
+    #[inline]
+    pub fn difference(&self, other: &JsObject, context: &mut Context) -> JsResult<Self> {
+        self.set_operation(Set::difference, other, context)
+    }
+
+    /// Returns a new set containing the elements that are either in this set or in `other`,
+    /// but not in both.
+    ///
+    /// `other` can be any set-like object, such as a `Set` or a `Map`.
+    ///
+    /// Same as JavaScript's `set.symmetricDifference(other)`.
+    #[inline]
+    pub fn symmetric_difference(&self, other: &JsObject, context: &mut Context) -> JsResult<Self> {
+        self.set_operation(Set::symmetric_difference, other, context)
+    }
+
+    /// Returns a boolean asserting whether all elements of this set are in `other`.
+    ///
+    /// Same as JavaScript's `set.isSubsetOf(other)`.
+    #[inline]
+    pub fn is_subset_of(&self, other: &JsObject, context: &mut Context) -> JsResult<bool> {
+        Set::is_subset_of(&self.inner.clone().into(), &[other.clone().into()], context)
+            .map(|v| v.to_boolean())
+    }
+
+    /// Returns a boolean asserting whether all elements of `other` are in this set.
+    ///
+    /// Same as JavaScript's `set.isSupersetOf(other)`.
+    #[inline]
+    pub fn is_superset_of(&self, other: &JsObject, context: &mut Context) -> JsResult<bool> {
+        Set::is_superset_of(&self.inner.clone().into(), &[other.clone().into()], context)
+            .map(|v| v.to_boolean())
+    }
+
+    /// Returns a boolean asserting whether this set has no elements in common with `other`.
+    ///
+    /// Same as JavaScript's `set.isDisjointFrom(other)`.
+    #[inline]
+    pub fn is_disjoint_from(&self, other: &JsObject, context: &mut Context) -> JsResult<bool> {
+        Set::is_disjoint_from(&self.inner.clone().into(), &[other.clone().into()], context)
+            .map(|v| v.to_boolean())
+    }
+
+    /// Utility: Calls one of the set methods returning a new `Set` object.
+    fn set_operation(
+        &self,
+        operation: NativeFunctionPointer,
+        other: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let result = operation(&self.inner.clone().into(), &[other.clone().into()], context)?
+            .as_object()
+            .cloned()
+            .expect("set methods should always return an object");
+
+        Self::from_object(result)
+    }
+
     /// Utility: Creates `JsSet` from `JsObject`, if not a Set throw `TypeError`.
     #[inline]
     pub fn from_object(object: JsObject) -> JsResult<Self> {
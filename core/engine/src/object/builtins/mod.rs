@@ -8,6 +8,7 @@ mod jsdataview;
 mod jsdate;
 mod jsfunction;
 mod jsgenerator;
+mod jsiterator;
 mod jsmap;
 mod jsmap_iterator;
 mod jspromise;
@@ -24,6 +25,7 @@ pub use jsdataview::*;
 pub use jsdate::*;
 pub use jsfunction::*;
 pub use jsgenerator::*;
+pub use jsiterator::*;
 pub use jsmap::*;
 pub use jsmap_iterator::*;
 pub use jspromise::*;
@@ -16,6 +16,7 @@ use crate::{
     object::JsObject,
     property::{DescriptorKind, PropertyDescriptor, PropertyKey},
     value::JsValue,
+    vm::check_external_allocation,
     Context, JsNativeError, JsResult,
 };
 use boa_profiler::Profiler;
@@ -739,6 +740,10 @@ pub(crate) fn ordinary_set(
 
         // e. Else
         // i. Assert: Receiver does not currently have a property P.
+        // The indexed properties are stored outside of the garbage collected heap, so their
+        // growth must be checked against the maximum heap size.
+        check_external_allocation(receiver.borrow().properties().indexed_growth(&key))?;
+
         // ii. Return ? CreateDataProperty(Receiver, P, V).
         return receiver.create_data_property_with_slot(key, value, context);
     }
@@ -12,6 +12,7 @@ use crate::{
         array::ARRAY_EXOTIC_INTERNAL_METHODS,
         array_buffer::{ArrayBuffer, BufferObject, SharedArrayBuffer},
         object::OrdinaryObject,
+        regexp::RegExp,
     },
     context::intrinsics::Intrinsics,
     error::JsNativeError,
@@ -21,8 +22,9 @@ use crate::{
     value::PreferredType,
     Context, JsResult, JsString, JsValue,
 };
-use boa_gc::{self, Finalize, Gc, GcBox, GcRefCell, Trace};
+use boa_gc::{self, Finalize, Gc, GcBox, GcHeap, GcRefCell, HeapGuard, NodeKind, Trace, Tracer};
 use std::{
+    any::TypeId,
     cell::RefCell,
     collections::HashMap,
     error::Error,
@@ -64,13 +66,96 @@ impl<T: NativeObject + ?Sized> Clone for JsObject<T> {
 // implementation of `Debug` for `JsObject` could easily cause stack overflows,
 // so we have to force our users to debug the `JsObject` instead.
 #[allow(missing_debug_implementations)]
-#[derive(Trace, Finalize)]
+#[derive(Finalize)]
 pub(crate) struct VTableObject<T: NativeObject + ?Sized> {
-    #[unsafe_ignore_trace]
     vtable: &'static InternalObjectMethods,
     object: GcRefCell<Object<T>>,
 }
 
+// SAFETY: This only traces the object like the derived implementation, but it also describes
+// the object in heap snapshots.
+unsafe impl<T: NativeObject + ?Sized> Trace for VTableObject<T> {
+    unsafe fn trace(&self, tracer: &mut Tracer) {
+        if tracer.is_recording() {
+            self.describe(tracer);
+        }
+
+        // SAFETY: The implementor must ensure that `trace` is correctly implemented.
+        unsafe { self.object.trace(tracer) }
+    }
+
+    unsafe fn trace_non_roots(&self) {
+        // SAFETY: The implementor must ensure that `trace_non_roots` is correctly implemented.
+        unsafe { self.object.trace_non_roots() }
+    }
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+        self.object.run_finalizer();
+    }
+}
+
+impl<T: NativeObject + ?Sized> VTableObject<T> {
+    /// Describes the object in a heap snapshot, naming it after its constructor or, for
+    /// functions, after their own `name` property, like the `DevTools` do.
+    fn describe(&self, tracer: &mut Tracer) {
+        let kind = if std::ptr::eq(self.vtable, &ARRAY_EXOTIC_INTERNAL_METHODS) {
+            NodeKind::Array
+        } else if self.vtable.__call__ as usize != ORDINARY_INTERNAL_METHODS.__call__ as usize {
+            NodeKind::Closure
+        } else if TypeId::of::<T>() == TypeId::of::<RegExp>() {
+            NodeKind::RegExp
+        } else {
+            NodeKind::Object
+        };
+
+        tracer.describe_node(kind, || {
+            // Only read own data properties, since running any code is not possible here.
+            fn own_data_property(
+                object: &VTableObject<impl NativeObject + ?Sized>,
+                key: &str,
+            ) -> Option<JsValue> {
+                object
+                    .object
+                    .try_borrow()
+                    .ok()?
+                    .properties
+                    .get(&js_string!(key).into())?
+                    .value()
+                    .cloned()
+            }
+
+            let name = if kind == NodeKind::Closure {
+                own_data_property(self, "name")
+            } else {
+                self.object
+                    .try_borrow()
+                    .ok()
+                    .and_then(|object| object.properties.shape.prototype())
+                    .and_then(|prototype| own_data_property(&prototype.inner, "constructor"))
+                    .and_then(|constructor| {
+                        own_data_property(&constructor.as_object()?.inner, "name")
+                    })
+            };
+
+            name.and_then(|name| name.as_string().map(JsString::to_std_string_escaped))
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| {
+                    if kind == NodeKind::Closure {
+                        return "(anonymous function)".to_owned();
+                    }
+                    let type_name = std::any::type_name::<T>();
+                    let type_name = type_name.split('<').next().unwrap_or(type_name);
+                    type_name
+                        .rsplit("::")
+                        .next()
+                        .unwrap_or(type_name)
+                        .to_owned()
+                })
+        });
+    }
+}
+
 impl Default for JsObject {
     fn default() -> Self {
         Self::from_proto_and_data(None, OrdinaryObject)
@@ -83,6 +168,7 @@ impl JsObject {
         object: Object<T>,
         vtable: &'static InternalObjectMethods,
     ) -> Self {
+        let _heap = enter_heap_of_prototype(object.properties.shape.prototype().as_ref());
         let gc = Gc::new(VTableObject {
             object: GcRefCell::new(object),
             vtable,
@@ -132,10 +218,12 @@ impl JsObject {
         data: T,
     ) -> Self {
         let internal_methods = data.internal_methods();
+        let prototype = prototype.into();
+        let _heap = enter_heap_of_prototype(prototype.as_ref());
         let gc = Gc::new(VTableObject {
             object: GcRefCell::new(Object {
                 data,
-                properties: PropertyMap::from_prototype_unique_shape(prototype.into()),
+                properties: PropertyMap::from_prototype_unique_shape(prototype),
                 extensible: true,
                 private_elements: ThinVec::new(),
             }),
@@ -160,13 +248,12 @@ impl JsObject {
         data: T,
     ) -> Self {
         let internal_methods = data.internal_methods();
+        let prototype = prototype.into();
+        let _heap = enter_heap_of_prototype(prototype.as_ref());
         let gc = Gc::new(VTableObject {
             object: GcRefCell::new(Object {
                 data,
-                properties: PropertyMap::from_prototype_with_shared_shape(
-                    root_shape,
-                    prototype.into(),
-                ),
+                properties: PropertyMap::from_prototype_with_shared_shape(root_shape, prototype),
                 extensible: true,
                 private_elements: ThinVec::new(),
             }),
@@ -581,13 +668,12 @@ impl<T: NativeObject + ?Sized> JsObject<T> {
         T: Sized,
     {
         let internal_methods = data.internal_methods();
+        let prototype = prototype.into();
+        let _heap = enter_heap_of_prototype(prototype.as_ref());
         let inner = Gc::new(VTableObject {
             object: GcRefCell::new(Object {
                 data,
-                properties: PropertyMap::from_prototype_with_shared_shape(
-                    root_shape,
-                    prototype.into(),
-                ),
+                properties: PropertyMap::from_prototype_with_shared_shape(root_shape, prototype),
                 extensible: true,
                 private_elements: ThinVec::new(),
             }),
@@ -606,10 +692,12 @@ impl<T: NativeObject + ?Sized> JsObject<T> {
         T: Sized,
     {
         let internal_methods = data.internal_methods();
+        let prototype = prototype.into();
+        let _heap = enter_heap_of_prototype(prototype.as_ref());
         let inner = Gc::new(VTableObject {
             object: GcRefCell::new(Object {
                 data,
-                properties: PropertyMap::from_prototype_unique_shape(prototype.into()),
+                properties: PropertyMap::from_prototype_unique_shape(prototype),
                 extensible: true,
                 private_elements: ThinVec::new(),
             }),
@@ -960,6 +1048,14 @@ impl<T: NativeObject + ?Sized> Debug for JsObject<T> {
     }
 }
 
+/// Enters the heap of `prototype`, so that the objects inheriting from it are allocated next to it.
+///
+/// The prototypes of the objects created through the Rust API usually come from the intrinsics of
+/// a context, so this keeps those objects in the heap of the context even when it isn't entered.
+fn enter_heap_of_prototype(prototype: Option<&JsObject>) -> Option<HeapGuard> {
+    prototype.and_then(|prototype| GcHeap::enter_heap_of(&prototype.inner))
+}
+
 /// Upcasts the reference to an object from a specific type `T` to an erased type `dyn NativeObject`.
 fn coerce_gc<T: NativeObject>(ptr: Gc<VTableObject<T>>) -> Gc<VTableObject<dyn NativeObject>> {
     // SAFETY: This just makes the casting from sized to unsized. Should eventually be replaced by
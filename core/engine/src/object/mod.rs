@@ -213,6 +213,11 @@ impl PrivateName {
     pub(crate) const fn new(description: JsString, id: usize) -> Self {
         Self { description, id }
     }
+
+    /// Returns the `[[Description]]` of the private name.
+    pub(crate) const fn description(&self) -> &JsString {
+        &self.description
+    }
 }
 
 /// The representation of private object elements.
@@ -14,6 +14,9 @@ use crate::{
     Context, JsResult, JsSymbol, JsValue,
 };
 
+#[cfg(feature = "experimental")]
+use crate::builtins::shadow_realm::WrappedFunction;
+
 use super::internal_methods::InternalMethodContext;
 
 /// Object integrity level.
@@ -788,6 +791,11 @@ impl JsObject {
             return fun.get_function_realm(context);
         }
 
+        #[cfg(feature = "experimental")]
+        if let Some(wrapped) = constructor.downcast_ref::<WrappedFunction>() {
+            return Ok(wrapped.realm().clone());
+        }
+
         Ok(context.realm().clone())
     }
 
@@ -1034,16 +1042,34 @@ impl JsObject {
         field_record: &ClassFieldDefinition,
         context: &mut Context,
     ) -> JsResult<()> {
+        let (field_record, initializers, extra_initializers) = match field_record {
+            ClassFieldDefinition::Decorated {
+                field,
+                initializers,
+                extra_initializers,
+            } => (&**field, &initializers[..], &extra_initializers[..]),
+            field_record => (field_record, &[][..], &[][..]),
+        };
+
         // 2. Let initializer be fieldRecord.[[Initializer]].
         let initializer = match field_record {
             ClassFieldDefinition::Public(_, function)
             | ClassFieldDefinition::Private(_, function) => function,
+            ClassFieldDefinition::Decorated { .. } => {
+                unreachable!("decorated fields cannot be nested")
+            }
         };
 
         // 3. If initializer is not empty, then
         // a. Let initValue be ? Call(initializer, receiver).
         // 4. Else, let initValue be undefined.
-        let init_value = initializer.call(&self.clone().into(), &[], context)?;
+        let mut init_value = initializer.call(&self.clone().into(), &[], context)?;
+
+        // 5. For each element initializer of fieldRecord.[[Initializers]], do
+        for initializer in initializers {
+            // a. Set initValue to ? Call(initializer, receiver, « initValue »).
+            init_value = initializer.call(&self.clone().into(), &[init_value], context)?;
+        }
 
         match field_record {
             // 1. Let fieldName be fieldRecord.[[Name]].
@@ -1059,9 +1085,18 @@ impl JsObject {
                 // b. Perform ? CreateDataPropertyOrThrow(receiver, fieldName, initValue).
                 self.create_data_property_or_throw(field_name.clone(), init_value, context)?;
             }
+            ClassFieldDefinition::Decorated { .. } => {
+                unreachable!("decorated fields cannot be nested")
+            }
         }
 
-        // 7. Return unused.
+        // 8. For each element initializer of fieldRecord.[[ExtraInitializers]], do
+        for initializer in extra_initializers {
+            // a. Perform ? Call(initializer, receiver).
+            initializer.call(&self.clone().into(), &[], context)?;
+        }
+
+        // 9. Return unused.
         Ok(())
     }
 
@@ -1089,14 +1124,21 @@ impl JsObject {
             self.private_method_or_accessor_add(name, method, context)?;
         }
 
-        // 3. Let fields be the value of constructor.[[Fields]].
-        // 4. For each element fieldRecord of fields, do
+        // 3. Let initializers be the value of constructor.[[Initializers]].
+        // 4. For each element initializer of initializers, do
+        for initializer in constructor_function.get_initializers() {
+            // a. Perform ? Call(initializer, O).
+            initializer.call(&self.clone().into(), &[], context)?;
+        }
+
+        // 5. Let fields be the value of constructor.[[Fields]].
+        // 6. For each element fieldRecord of fields, do
         for field_record in constructor_function.get_fields() {
             // a. Perform ? DefineField(O, fieldRecord).
             self.define_field(field_record, context)?;
         }
 
-        // 5. Return unused.
+        // 7. Return unused.
         Ok(())
     }
 
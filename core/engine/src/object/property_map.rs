@@ -8,7 +8,7 @@ use super::{
     JsPrototype, ObjectStorage, PropertyDescriptor, PropertyKey,
 };
 use crate::{property::PropertyDescriptorBuilder, JsString, JsSymbol, JsValue};
-use boa_gc::{custom_trace, Finalize, Trace};
+use boa_gc::{custom_trace, EdgeName, Finalize, Trace, Tracer};
 use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHasher};
 use std::{collections::hash_map, hash::BuildHasherDefault, iter::FusedIterator};
@@ -150,6 +150,30 @@ impl IndexedProperties {
         replaced
     }
 
+    /// Returns the number of bytes that inserting a new property with the specified key could
+    /// allocate, or `0` if the storage already has space for it.
+    fn growth(&self, key: u32) -> usize {
+        let key = key as usize;
+        match self {
+            Self::Dense(vec) if key < vec.len() => 0,
+            Self::Dense(vec) if key == vec.len() => {
+                if vec.len() < vec.capacity() {
+                    return 0;
+                }
+                // Pushing at least doubles the capacity of the vector.
+                (vec.capacity() * 2).max(4) * std::mem::size_of::<JsValue>()
+            }
+            // Leaving a hole converts the storage to sparse storage.
+            Self::Dense(vec) => (vec.len() + 1) * std::mem::size_of::<(u32, PropertyDescriptor)>(),
+            Self::Sparse(map) => {
+                if map.len() < map.capacity() {
+                    return 0;
+                }
+                (map.capacity() * 2).max(4) * std::mem::size_of::<(u32, PropertyDescriptor)>()
+            }
+        }
+    }
+
     /// Removes a property descriptor with the specified key.
     fn remove(&mut self, key: u32) -> bool {
         let vec = match self {
@@ -217,7 +241,7 @@ impl IndexedProperties {
 /// A [`PropertyMap`] contains all the properties of an object.
 ///
 /// The property values are stored in different data structures based on keys.
-#[derive(Default, Debug, Trace, Finalize)]
+#[derive(Default, Debug, Finalize)]
 pub struct PropertyMap {
     /// Properties stored with integers as keys.
     indexed_properties: IndexedProperties,
@@ -226,6 +250,41 @@ pub struct PropertyMap {
     pub(crate) storage: ObjectStorage,
 }
 
+// SAFETY: This traces all the fields of the map like the derived implementation, but it names
+// the edges of the properties after their keys in heap snapshots.
+unsafe impl Trace for PropertyMap {
+    unsafe fn trace(&self, tracer: &mut Tracer) {
+        if tracer.is_recording() {
+            // SAFETY: The recording tracer doesn't mark any value.
+            unsafe { self.trace_named(tracer) };
+            return;
+        }
+
+        // SAFETY: The implementor must ensure that `trace` is correctly implemented.
+        unsafe {
+            self.indexed_properties.trace(tracer);
+            self.shape.trace(tracer);
+            self.storage.trace(tracer);
+        }
+    }
+
+    unsafe fn trace_non_roots(&self) {
+        // SAFETY: The implementor must ensure that `trace_non_roots` is correctly implemented.
+        unsafe {
+            self.indexed_properties.trace_non_roots();
+            self.shape.trace_non_roots();
+            self.storage.trace_non_roots();
+        }
+    }
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+        self.indexed_properties.run_finalizer();
+        self.shape.run_finalizer();
+        self.storage.run_finalizer();
+    }
+}
+
 impl PropertyMap {
     /// Create a new [`PropertyMap`].
     #[must_use]
@@ -264,6 +323,64 @@ impl PropertyMap {
         }
     }
 
+    /// Traces the values of the properties, naming the edges after their keys.
+    ///
+    /// # Safety
+    ///
+    /// See [`Trace`].
+    unsafe fn trace_named(&self, tracer: &mut Tracer) {
+        match &self.indexed_properties {
+            IndexedProperties::Dense(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    tracer.push_edge(|| EdgeName::Element(index));
+                    // SAFETY: The caller must ensure that `trace` is correctly called.
+                    unsafe { value.trace(tracer) };
+                    tracer.pop_edge();
+                }
+            }
+            IndexedProperties::Sparse(properties) => {
+                for (index, property) in properties.iter() {
+                    tracer.push_edge(|| EdgeName::Element(*index as usize));
+                    // SAFETY: The caller must ensure that `trace` is correctly called.
+                    unsafe { property.trace(tracer) };
+                    tracer.pop_edge();
+                }
+            }
+        }
+
+        tracer.push_edge(|| EdgeName::Internal("shape".into()));
+        // SAFETY: The caller must ensure that `trace` is correctly called.
+        unsafe { self.shape.trace(tracer) };
+        tracer.pop_edge();
+
+        for key in self.shape.keys() {
+            let Some(Slot { index, attributes }) = self.shape.lookup(&key) else {
+                continue;
+            };
+            let index = index as usize;
+
+            let mut trace_slot = |index: usize, name: &dyn Fn() -> String| {
+                if let Some(value) = self.storage.get(index) {
+                    tracer.push_edge(|| EdgeName::Property(name()));
+                    // SAFETY: The caller must ensure that `trace` is correctly called.
+                    unsafe { value.trace(tracer) };
+                    tracer.pop_edge();
+                }
+            };
+
+            if attributes.is_accessor_descriptor() {
+                if attributes.has_get() {
+                    trace_slot(index, &|| format!("get {key}"));
+                }
+                if attributes.has_set() {
+                    trace_slot(index + 1, &|| format!("set {key}"));
+                }
+            } else {
+                trace_slot(index, &|| key.to_string());
+            }
+        }
+    }
+
     /// Get the property with the given key from the [`PropertyMap`].
     #[must_use]
     pub fn get(&self, key: &PropertyKey) -> Option<PropertyDescriptor> {
@@ -447,6 +564,16 @@ impl PropertyMap {
         false
     }
 
+    /// Returns the number of bytes that inserting a new property with the given key could allocate
+    /// for the storage of the indexed properties, which lives outside of the garbage collected heap.
+    pub(crate) fn indexed_growth(&self, key: &PropertyKey) -> usize {
+        if let PropertyKey::Index(index) = key {
+            self.indexed_properties.growth(index.get())
+        } else {
+            0
+        }
+    }
+
     /// Overrides all the indexed properties, setting it to dense storage.
     pub(crate) fn override_indexed_properties(&mut self, properties: ThinVec<JsValue>) {
         self.indexed_properties = IndexedProperties::Dense(properties);
@@ -41,7 +41,20 @@ impl<T: Debug + Trace + Finalize> TransitionMap<T> {
 #[derive(Default, Debug, Trace, Finalize)]
 struct Inner {
     properties: Option<Box<TransitionMap<TransitionKey>>>,
-    prototypes: Option<Box<TransitionMap<JsPrototype>>>,
+
+    /// Keyed by [`prototype_address`], so that the transitions don't keep the prototypes alive.
+    prototypes: Option<Box<TransitionMap<usize>>>,
+}
+
+/// Returns the address of `prototype`, or `0` if it is `null`.
+///
+/// A transition holds its prototype, so the address of a prototype can't be reused by another
+/// object while the transition is alive, and the entries of collected transitions are ignored.
+fn prototype_address(prototype: &JsPrototype) -> usize {
+    prototype.as_ref().map_or(0, |prototype| {
+        let ptr: *const _ = prototype.as_ref();
+        ptr.cast::<()>() as usize
+    })
 }
 
 /// Holds a forward reference to a previously created transition.
@@ -66,7 +79,7 @@ impl ForwardTransition {
     }
 
     /// Insert a prototype transition.
-    pub(super) fn insert_prototype(&self, key: JsPrototype, value: &Gc<SharedShapeInner>) {
+    pub(super) fn insert_prototype(&self, key: &JsPrototype, value: &Gc<SharedShapeInner>) {
         let mut this = self.inner.borrow_mut();
         let prototypes = this.prototypes.get_or_insert_with(Box::default);
 
@@ -74,7 +87,9 @@ impl ForwardTransition {
             prototypes.map.retain(|_, v| v.is_upgradable());
         }
 
-        prototypes.map.insert(key, WeakGc::new(value));
+        prototypes
+            .map
+            .insert(prototype_address(key), WeakGc::new(value));
     }
 
     /// Get a property transition, return [`None`] otherwise.
@@ -92,7 +107,7 @@ impl ForwardTransition {
         let Some(transitions) = this.prototypes.as_ref() else {
             return None;
         };
-        transitions.map.get(key).cloned()
+        transitions.map.get(&prototype_address(key)).cloned()
     }
 
     /// Prunes the [`WeakGc`]s that have been garbage collected.
@@ -7,7 +7,7 @@ mod tests;
 use std::{collections::hash_map::RandomState, hash::Hash};
 
 use bitflags::bitflags;
-use boa_gc::{empty_trace, Finalize, Gc, Trace, WeakGc};
+use boa_gc::{empty_trace, EdgeName, Finalize, Gc, GcHeap, NodeKind, Trace, Tracer, WeakGc};
 use indexmap::IndexMap;
 
 use crate::{object::JsPrototype, property::PropertyKey, JsObject};
@@ -26,8 +26,13 @@ pub(crate) struct TransitionKey {
     pub(crate) attributes: SlotAttributes,
 }
 
-// SAFETY: Non of the member of this struct are garbage collected,
-//         so this should be fine.
+// SAFETY: A symbol key holds a `Gc`, which is deliberately not traced. The collector only counts
+// the handles that are traced as non-root references, so every symbol held by a key is a root, and
+// stays alive for as long as the key. Symbols don't reference other garbage collected values, so
+// this can't keep a cycle alive. The same applies to the keys of a [`PropertyTable`], which can be
+// shared between shapes, and would otherwise count a handle once per shape.
+//
+// Since these edges are never traced, heap snapshots show the symbols as roots instead.
 unsafe impl Trace for TransitionKey {
     empty_trace!();
 }
@@ -78,14 +83,13 @@ impl ShapeFlags {
     }
 }
 
-// SAFETY: Non of the member of this struct are garbage collected,
-//         so this should be fine.
+// SAFETY: The flags are plain bits, so there is nothing to trace.
 unsafe impl Trace for ShapeFlags {
     empty_trace!();
 }
 
 /// The internal representation of a [`SharedShape`].
-#[derive(Debug, Trace, Finalize)]
+#[derive(Debug, Finalize)]
 struct Inner {
     /// See [`ForwardTransition`].
     forward_transitions: ForwardTransition,
@@ -96,9 +100,7 @@ struct Inner {
     /// Instance prototype `__proto__`.
     prototype: JsPrototype,
 
-    // SAFETY: This is safe because nothing in [`PropertyTable`]
-    //         needs tracing
-    #[unsafe_ignore_trace]
+    // The symbol keys of the table aren't traced, which keeps them rooted (see `TransitionKey`).
     property_table: PropertyTable,
 
     /// The previous shape in the transition chain.
@@ -113,6 +115,45 @@ struct Inner {
     flags: ShapeFlags,
 }
 
+// SAFETY: This traces the same fields as the derived implementation, but it also describes the
+// shape in heap snapshots.
+unsafe impl Trace for Inner {
+    unsafe fn trace(&self, tracer: &mut Tracer) {
+        tracer.describe_node(NodeKind::ObjectShape, || "SharedShape".to_owned());
+
+        tracer.push_edge(|| EdgeName::Internal("forward_transitions".into()));
+        // SAFETY: The implementor must ensure that `trace` is correctly implemented.
+        unsafe { self.forward_transitions.trace(tracer) };
+        tracer.pop_edge();
+
+        tracer.push_edge(|| EdgeName::Internal("prototype".into()));
+        // SAFETY: The implementor must ensure that `trace` is correctly implemented.
+        unsafe { self.prototype.trace(tracer) };
+        tracer.pop_edge();
+
+        tracer.push_edge(|| EdgeName::Internal("previous".into()));
+        // SAFETY: The implementor must ensure that `trace` is correctly implemented.
+        unsafe { self.previous.trace(tracer) };
+        tracer.pop_edge();
+    }
+
+    unsafe fn trace_non_roots(&self) {
+        // SAFETY: The implementor must ensure that `trace_non_roots` is correctly implemented.
+        unsafe {
+            self.forward_transitions.trace_non_roots();
+            self.prototype.trace_non_roots();
+            self.previous.trace_non_roots();
+        }
+    }
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+        self.forward_transitions.run_finalizer();
+        self.prototype.run_finalizer();
+        self.previous.run_finalizer();
+    }
+}
+
 /// Represents a shared object shape.
 #[derive(Debug, Trace, Finalize, Clone)]
 pub struct SharedShape {
@@ -179,6 +220,14 @@ impl SharedShape {
         }
     }
 
+    /// Create a [`SharedShape`] that transitions from this shape.
+    ///
+    /// It is allocated in the heap of this shape, which is the heap of its root shape.
+    fn new_transition(&self, inner: Inner) -> Self {
+        let _heap = GcHeap::enter_heap_of(&self.inner);
+        Self::new(inner)
+    }
+
     /// Create a root [`SharedShape`].
     #[must_use]
     pub(crate) fn root() -> Self {
@@ -204,17 +253,17 @@ impl SharedShape {
         }
         let new_inner_shape = Inner {
             forward_transitions: ForwardTransition::default(),
-            prototype: prototype.clone(),
+            prototype,
             property_table: self.property_table().clone(),
             property_count: self.property_count(),
             previous: Some(self.clone()),
             transition_count: self.transition_count() + 1,
             flags: ShapeFlags::prototype_transition_from(self.flags()),
         };
-        let new_shape = Self::new(new_inner_shape);
+        let new_shape = self.new_transition(new_inner_shape);
 
         self.forward_transitions()
-            .insert_prototype(prototype, &new_shape.inner);
+            .insert_prototype(&new_shape.inner.prototype, &new_shape.inner);
 
         new_shape
     }
@@ -244,7 +293,7 @@ impl SharedShape {
             transition_count: self.transition_count() + 1,
             flags: ShapeFlags::insert_property_transition_from(self.flags()),
         };
-        let new_shape = Self::new(new_inner_shape);
+        let new_shape = self.new_transition(new_inner_shape);
 
         self.forward_transitions()
             .insert_property(key, &new_shape.inner);
@@ -294,7 +343,7 @@ impl SharedShape {
                 transition_count: self.transition_count() + 1,
                 flags: ShapeFlags::configure_property_transition_from(self.flags()),
             };
-            let shape = Self::new(inner_shape);
+            let shape = self.new_transition(inner_shape);
 
             self.forward_transitions()
                 .insert_property(key, &shape.inner);
@@ -61,6 +61,15 @@ fn test_prune_property_on_counter_limit() {
 fn test_prune_prototype_on_counter_limit() {
     let shape = SharedShape::root();
 
+    // The transitions don't keep their prototypes alive, so they are kept here to make sure
+    // every prototype has a different address.
+    let mut prototypes = Vec::new();
+    let mut new_prototype = || {
+        let prototype = JsObject::with_null_proto();
+        prototypes.push(prototype.clone());
+        Some(prototype)
+    };
+
     assert_eq!(
         shape.forward_transitions().prototype_transitions_count(),
         (0, 0)
@@ -72,7 +81,7 @@ fn test_prune_prototype_on_counter_limit() {
             (i, i as u8)
         );
 
-        shape.change_prototype_transition(Some(JsObject::with_null_proto()));
+        shape.change_prototype_transition(new_prototype());
     }
 
     boa_gc::force_collect();
@@ -83,7 +92,7 @@ fn test_prune_prototype_on_counter_limit() {
     );
 
     {
-        shape.change_prototype_transition(Some(JsObject::with_null_proto()));
+        shape.change_prototype_transition(new_prototype());
     }
 
     assert_eq!(
@@ -92,7 +101,7 @@ fn test_prune_prototype_on_counter_limit() {
     );
 
     {
-        shape.change_prototype_transition(Some(JsObject::with_null_proto()));
+        shape.change_prototype_transition(new_prototype());
     }
 
     assert_eq!(
@@ -1,6 +1,6 @@
 use std::{cell::RefCell, fmt::Debug};
 
-use boa_gc::{Finalize, Gc, GcRefCell, Trace, WeakGc};
+use boa_gc::{EdgeName, Finalize, Gc, GcHeap, GcRefCell, NodeKind, Trace, Tracer, WeakGc};
 
 use crate::property::PropertyKey;
 
@@ -10,18 +10,40 @@ use super::{
 };
 
 /// The internal representation of [`UniqueShape`].
-#[derive(Default, Debug, Trace, Finalize)]
+#[derive(Default, Debug, Finalize)]
 struct Inner {
     /// The property table that maps a [`PropertyKey`] to a slot in the objects storage.
     //
-    // SAFETY: This is safe becasue nothing in this field needs tracing.
-    #[unsafe_ignore_trace]
+    // The symbol keys of this field aren't traced, which keeps them rooted (see `TransitionKey`).
     property_table: RefCell<PropertyTableInner>,
 
     /// The prototype of the shape.
     prototype: GcRefCell<JsPrototype>,
 }
 
+// SAFETY: This traces the same fields as the derived implementation, but it also describes the
+// shape in heap snapshots.
+unsafe impl Trace for Inner {
+    unsafe fn trace(&self, tracer: &mut Tracer) {
+        tracer.describe_node(NodeKind::ObjectShape, || "UniqueShape".to_owned());
+
+        tracer.push_edge(|| EdgeName::Internal("prototype".into()));
+        // SAFETY: The implementor must ensure that `trace` is correctly implemented.
+        unsafe { self.prototype.trace(tracer) };
+        tracer.pop_edge();
+    }
+
+    unsafe fn trace_non_roots(&self) {
+        // SAFETY: The implementor must ensure that `trace_non_roots` is correctly implemented.
+        unsafe { self.prototype.trace_non_roots() }
+    }
+
+    fn run_finalizer(&self) {
+        Finalize::finalize(self);
+        self.prototype.run_finalizer();
+    }
+}
+
 /// Represents a [`Shape`] that is not shared with any other object.
 ///
 /// This is useful for objects that are inherently unique like,
@@ -44,6 +66,12 @@ impl UniqueShape {
         }
     }
 
+    /// Create a [`UniqueShape`] that replaces this shape, in the heap of this shape.
+    fn new_transition(&self, prototype: JsPrototype, property_table: PropertyTableInner) -> Self {
+        let _heap = GcHeap::enter_heap_of(&self.inner);
+        Self::new(prototype, property_table)
+    }
+
     pub(crate) fn override_internal(
         &self,
         property_table: PropertyTableInner,
@@ -113,7 +141,7 @@ impl UniqueShape {
         }
 
         let prototype = self.inner.prototype.borrow_mut().take();
-        Self::new(prototype, property_table)
+        self.new_transition(prototype, property_table)
     }
 
     /// Does a property lookup on the [`UniqueShape`] returning the [`Slot`] where it's
@@ -208,7 +236,7 @@ impl UniqueShape {
         }
 
         let prototype = self.inner.prototype.borrow_mut().take();
-        let shape = Self::new(prototype, property_table);
+        let shape = self.new_transition(prototype, property_table);
 
         ChangeTransition {
             shape: shape.into(),
@@ -225,7 +253,7 @@ impl UniqueShape {
         // We need to create a new unique shape,
         // to invalidate any pointers to this shape i.e inline caches.
         let property_table = std::mem::take(&mut *property_table);
-        Self::new(prototype, property_table)
+        self.new_transition(prototype, property_table)
     }
 
     /// Gets all keys first strings then symbols in creation order.
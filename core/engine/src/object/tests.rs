@@ -38,3 +38,48 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[test]
+fn heap_snapshot_names() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                class Point {
+                    constructor(x, y) {
+                        this.x = x;
+                        this.y = y;
+                    }
+                }
+                function makeCounter() {
+                    let origin = new Point(0, 0);
+                    return function counter() { return origin; };
+                }
+                var points = [new Point(1, 2)];
+                var counter = makeCounter();
+                var accessors = { get origin() { return counter(); } };
+            "#}),
+        TestAction::inspect_context(|context| {
+            let mut json = Vec::new();
+            context
+                .heap_snapshot()
+                .write_json(&mut json)
+                .expect("writing to a vector should not fail");
+            let json = String::from_utf8(json).expect("the snapshot should be valid UTF-8");
+
+            // Objects are named after their constructors, and functions after their names.
+            for name in [
+                "\"Point\"",
+                "\"counter\"",
+                "\"makeCounter\"",
+                "\"Array\"",
+                "\"system / Context\"",
+            ] {
+                assert!(json.contains(name), "missing node named {name}");
+            }
+
+            // Edges are named after properties, accessors and closure variables.
+            for name in ["\"points\"", "\"get origin\"", "\"origin\""] {
+                assert!(json.contains(name), "missing edge named {name}");
+            }
+        }),
+    ]);
+}
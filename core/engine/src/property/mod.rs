@@ -19,7 +19,7 @@ mod attribute;
 mod nonmaxu32;
 
 use crate::{js_string, object::shape::slot::SlotAttributes, JsString, JsSymbol, JsValue};
-use boa_gc::{Finalize, Trace};
+use boa_gc::{custom_trace, Finalize, Trace};
 use std::{fmt, iter::FusedIterator};
 
 pub use {attribute::Attribute, nonmaxu32::NonMaxU32};
@@ -602,6 +602,15 @@ pub enum PropertyKey {
     Index(NonMaxU32),
 }
 
+// SAFETY: Symbols are the only keys that can hold a garbage collected value, and they are traced.
+unsafe impl Trace for PropertyKey {
+    custom_trace!(this, mark, {
+        if let Self::Symbol(symbol) = this {
+            mark(symbol);
+        }
+    });
+}
+
 /// Utility function for parsing [`PropertyKey`].
 fn parse_u32_index<I, T>(mut input: I) -> Option<NonMaxU32>
 where
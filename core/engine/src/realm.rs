@@ -17,11 +17,11 @@ use crate::{
         HostHooks,
     },
     environments::DeclarativeEnvironment,
-    module::Module,
+    module::{Module, ModuleRequest},
     object::shape::RootShape,
-    HostDefined, JsNativeError, JsObject, JsResult, JsString,
+    HostDefined, JsNativeError, JsObject, JsResult,
 };
-use boa_gc::{Finalize, Gc, GcRef, GcRefCell, GcRefMut, Trace};
+use boa_gc::{Ephemeron, Finalize, Gc, GcRef, GcRefCell, GcRefMut, Trace};
 use boa_profiler::Profiler;
 
 /// Representation of a Realm.
@@ -52,16 +52,19 @@ impl std::fmt::Debug for Realm {
 }
 
 #[derive(Trace, Finalize)]
-struct Inner {
+pub(crate) struct Inner {
     intrinsics: Intrinsics,
     environment: Gc<DeclarativeEnvironment>,
     global_object: JsObject,
     global_this: JsObject,
     template_map: GcRefCell<FxHashMap<u64, JsObject>>,
-    loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
+    loaded_modules: GcRefCell<FxHashMap<ModuleRequest, Module>>,
     host_classes: GcRefCell<FxHashMap<TypeId, StandardConstructor>>,
 
     host_defined: GcRefCell<HostDefined>,
+
+    #[cfg(feature = "annex-b")]
+    legacy_regexp_statics: GcRefCell<crate::builtins::regexp::LegacyRegExpStatics>,
 }
 
 impl Realm {
@@ -90,6 +93,8 @@ impl Realm {
                 loaded_modules: GcRefCell::default(),
                 host_classes: GcRefCell::default(),
                 host_defined: GcRefCell::default(),
+                #[cfg(feature = "annex-b")]
+                legacy_regexp_statics: GcRefCell::default(),
             }),
         };
 
@@ -164,10 +169,18 @@ impl Realm {
         &self.inner.global_this
     }
 
-    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
+    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<ModuleRequest, Module>> {
         &self.inner.loaded_modules
     }
 
+    /// Gets the legacy static state of the `%RegExp%` constructor of this realm.
+    #[cfg(feature = "annex-b")]
+    pub(crate) fn legacy_regexp_statics(
+        &self,
+    ) -> &GcRefCell<crate::builtins::regexp::LegacyRegExpStatics> {
+        &self.inner.legacy_regexp_statics
+    }
+
     /// Resizes the number of bindings on the global environment.
     pub(crate) fn resize_global_env(&self) {
         let binding_number = self.environment().compile_env().num_bindings();
@@ -206,6 +219,12 @@ impl Realm {
             .remove(&TypeId::of::<C>())
     }
 
+    /// Creates an ephemeron that only keeps `value` alive for as long as this realm is alive,
+    /// even if `value` references the realm.
+    pub(crate) fn ephemeron<V: Trace>(&self, value: V) -> Ephemeron<Inner, V> {
+        Ephemeron::new(&self.inner, value)
+    }
+
     pub(crate) fn addr(&self) -> *const () {
         let ptr: *const _ = &*self.inner;
         ptr.cast()
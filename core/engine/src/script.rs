@@ -8,6 +8,8 @@
 //! [spec]: https://tc39.es/ecma262/#sec-scripts
 //! [script]: https://tc39.es/ecma262/#sec-script-records
 
+use std::{path::Path, rc::Rc};
+
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_parser::{source::ReadChar, Parser, Source};
 use boa_profiler::Profiler;
@@ -16,9 +18,10 @@ use rustc_hash::FxHashMap;
 use crate::{
     bytecompiler::ByteCompiler,
     js_string,
+    module::ModuleRequest,
     realm::Realm,
     vm::{ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock},
-    Context, HostDefined, JsResult, JsString, JsValue, Module,
+    Context, HostDefined, JsResult, JsValue, Module,
 };
 
 /// ECMAScript's [**Script Record**][spec].
@@ -45,8 +48,10 @@ struct Inner {
     #[unsafe_ignore_trace]
     source: boa_ast::Script,
     codeblock: GcRefCell<Option<Gc<CodeBlock>>>,
-    loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
+    loaded_modules: GcRefCell<FxHashMap<ModuleRequest, Module>>,
     host_defined: HostDefined,
+    #[unsafe_ignore_trace]
+    path: Option<Rc<Path>>,
 }
 
 impl Script {
@@ -64,8 +69,14 @@ impl Script {
         &self.inner.host_defined
     }
 
+    /// Gets the path of the file this script was parsed from, if any.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.inner.path.as_deref()
+    }
+
     /// Gets the loaded modules of this script.
-    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
+    pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<ModuleRequest, Module>> {
         &self.inner.loaded_modules
     }
 
@@ -80,6 +91,8 @@ impl Script {
         context: &mut Context,
     ) -> JsResult<Self> {
         let _timer = Profiler::global().start_event("Script parsing", "Main");
+        let _heap = context.heap().enter();
+        let path = src.path().map(Rc::from);
         let mut parser = Parser::new(src);
         parser.set_identifier(context.next_parser_identifier());
         if context.is_strict() {
@@ -97,6 +110,7 @@ impl Script {
                 codeblock: GcRefCell::default(),
                 loaded_modules: GcRefCell::default(),
                 host_defined: HostDefined::default(),
+                path,
             }),
         })
     }
@@ -112,6 +126,7 @@ impl Script {
         };
 
         let _timer = Profiler::global().start_event("Script compilation", "Main");
+        let _heap = context.heap().enter();
 
         let mut compiler = ByteCompiler::new(
             js_string!("<main>"),
@@ -125,6 +140,7 @@ impl Script {
         compiler.global_declaration_instantiation(
             &self.inner.source,
             &self.inner.realm.environment().compile_env(),
+            &ActiveRunnable::Script(self.clone()),
         )?;
         compiler.compile_statement_list(self.inner.source.statements(), true, false);
 
@@ -81,12 +81,15 @@ impl StaticJsStrings {
     well_known_statics! {
         (EMPTY_STRING, ""),
         // Symbols
+        (SYMBOL_ASYNC_DISPOSE, "Symbol.asyncDispose"),
         (SYMBOL_ASYNC_ITERATOR, "Symbol.asyncIterator"),
+        (SYMBOL_DISPOSE, "Symbol.dispose"),
         (SYMBOL_HAS_INSTANCE, "Symbol.hasInstance"),
         (SYMBOL_IS_CONCAT_SPREADABLE, "Symbol.isConcatSpreadable"),
         (SYMBOL_ITERATOR, "Symbol.iterator"),
         (SYMBOL_MATCH, "Symbol.match"),
         (SYMBOL_MATCH_ALL, "Symbol.matchAll"),
+        (SYMBOL_METADATA, "Symbol.metadata"),
         (SYMBOL_REPLACE, "Symbol.replace"),
         (SYMBOL_SEARCH, "Symbol.search"),
         (SYMBOL_SPECIES, "Symbol.species"),
@@ -94,12 +97,15 @@ impl StaticJsStrings {
         (SYMBOL_TO_PRIMITIVE, "Symbol.toPrimitive"),
         (SYMBOL_TO_STRING_TAG, "Symbol.toStringTag"),
         (SYMBOL_UNSCOPABLES, "Symbol.unscopables"),
+        (FN_SYMBOL_ASYNC_DISPOSE, "[Symbol.asyncDispose]"),
         (FN_SYMBOL_ASYNC_ITERATOR, "[Symbol.asyncIterator]"),
+        (FN_SYMBOL_DISPOSE, "[Symbol.dispose]"),
         (FN_SYMBOL_HAS_INSTANCE, "[Symbol.hasInstance]"),
         (FN_SYMBOL_IS_CONCAT_SPREADABLE, "[Symbol.isConcatSpreadable]"),
         (FN_SYMBOL_ITERATOR, "[Symbol.iterator]"),
         (FN_SYMBOL_MATCH, "[Symbol.match]"),
         (FN_SYMBOL_MATCH_ALL, "[Symbol.matchAll]"),
+        (FN_SYMBOL_METADATA, "[Symbol.metadata]"),
         (FN_SYMBOL_REPLACE, "[Symbol.replace]"),
         (FN_SYMBOL_SEARCH, "[Symbol.search]"),
         (FN_SYMBOL_SPECIES, "[Symbol.species]"),
@@ -114,13 +120,16 @@ impl StaticJsStrings {
         (ASYNC_FUNCTION, "AsyncFunction"),
         (ASYNC_GENERATOR, "AsyncGenerator"),
         (ASYNC_GENERATOR_FUNCTION, "AsyncGeneratorFunction"),
+        (ASYNC_DISPOSABLE_STACK, "AsyncDisposableStack"),
         (ATOMICS, "Atomics"),
         (BIG_INT, "BigInt"),
         (BOOLEAN, "Boolean"),
         (DATA_VIEW, "DataView"),
         (DATE, "Date"),
+        (DISPOSABLE_STACK, "DisposableStack"),
         (ERROR, "Error"),
         (AGGREGATE_ERROR, "AggregateError"),
+        (SUPPRESSED_ERROR, "SuppressedError"),
         (EVAL_ERROR, "EvalError"),
         (RANGE_ERROR, "RangeError"),
         (REFERENCE_ERROR, "ReferenceError"),
@@ -133,14 +142,18 @@ impl StaticJsStrings {
         (FUNCTION, "Function"),
         (GENERATOR, "Generator"),
         (GENERATOR_FUNCTION, "GeneratorFunction"),
+        (ITERATOR, "Iterator"),
         (INTL, "Intl"),
         (COLLATOR, "Collator"),
+        (DISPLAY_NAMES, "DisplayNames"),
+        (DURATION_FORMAT, "DurationFormat"),
         (LIST_FORMAT, "ListFormat"),
         (LOCALE, "Locale"),
         (PLURAL_RULES, "PluralRules"),
         (SEGMENTER, "Segmenter"),
         (DATE_TIME_FORMAT, "DateTimeFormat"),
         (NUMBER_FORMAT, "NumberFormat"),
+        (RELATIVE_TIME_FORMAT, "RelativeTimeFormat"),
         (JSON, "JSON"),
         (MAP, "Map"),
         (MATH, "Math"),
@@ -176,6 +189,8 @@ impl StaticJsStrings {
         (WEAK_REF, "WeakRef"),
         (WEAK_MAP, "WeakMap"),
         (WEAK_SET, "WeakSet"),
+        (FINALIZATION_REGISTRY, "FinalizationRegistry"),
+        (SHADOW_REALM, "ShadowRealm"),
         (TEMPORAL, "Temporal"),
         (NOW, "Temporal.Now"),
         (INSTANT, "Temporal.Instant"),
@@ -224,8 +239,12 @@ thread_local! {
 const RAW_STATICS: &[&[u16]] = &[
     utf16!(""),
     // Well known symbols
+    utf16!("Symbol.asyncDispose"),
+    utf16!("[Symbol.asyncDispose]"),
     utf16!("Symbol.asyncIterator"),
     utf16!("[Symbol.asyncIterator]"),
+    utf16!("Symbol.dispose"),
+    utf16!("[Symbol.dispose]"),
     utf16!("Symbol.hasInstance"),
     utf16!("[Symbol.hasInstance]"),
     utf16!("Symbol.isConcatSpreadable"),
@@ -236,6 +255,8 @@ const RAW_STATICS: &[&[u16]] = &[
     utf16!("[Symbol.match]"),
     utf16!("Symbol.matchAll"),
     utf16!("[Symbol.matchAll]"),
+    utf16!("Symbol.metadata"),
+    utf16!("[Symbol.metadata]"),
     utf16!("Symbol.replace"),
     utf16!("[Symbol.replace]"),
     utf16!("Symbol.search"),
@@ -257,13 +278,16 @@ const RAW_STATICS: &[&[u16]] = &[
     utf16!("AsyncFunction"),
     utf16!("AsyncGenerator"),
     utf16!("AsyncGeneratorFunction"),
+    utf16!("AsyncDisposableStack"),
     utf16!("Atomics"),
     utf16!("BigInt"),
     utf16!("Boolean"),
     utf16!("DataView"),
     utf16!("Date"),
+    utf16!("DisposableStack"),
     utf16!("Error"),
     utf16!("AggregateError"),
+    utf16!("SuppressedError"),
     utf16!("EvalError"),
     utf16!("RangeError"),
     utf16!("ReferenceError"),
@@ -276,14 +300,18 @@ const RAW_STATICS: &[&[u16]] = &[
     utf16!("Function"),
     utf16!("Generator"),
     utf16!("GeneratorFunction"),
+    utf16!("Iterator"),
     utf16!("Intl"),
     utf16!("Collator"),
+    utf16!("DisplayNames"),
+    utf16!("DurationFormat"),
     utf16!("ListFormat"),
     utf16!("Locale"),
     utf16!("PluralRules"),
     utf16!("Segmenter"),
     utf16!("DateTimeFormat"),
     utf16!("NumberFormat"),
+    utf16!("RelativeTimeFormat"),
     utf16!("JSON"),
     utf16!("Map"),
     utf16!("Math"),
@@ -319,6 +347,8 @@ const RAW_STATICS: &[&[u16]] = &[
     utf16!("WeakRef"),
     utf16!("WeakMap"),
     utf16!("WeakSet"),
+    utf16!("FinalizationRegistry"),
+    utf16!("ShadowRealm"),
     utf16!("Temporal"),
     utf16!("Temporal.Now"),
     utf16!("Temporal.Instant"),
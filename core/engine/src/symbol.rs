@@ -27,12 +27,15 @@ use crate::{
     tagged::{Tagged, UnwrappedTagged},
     JsData, JsString,
 };
-use boa_gc::{Finalize, Trace};
+use boa_gc::{custom_trace, Finalize, Gc, GcBox, Trace};
 
 use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sptr::Strict;
 
 use std::{
     hash::{Hash, Hasher},
+    mem::{align_of, ManuallyDrop},
+    ptr::NonNull,
     sync::{atomic::Ordering, Arc},
 };
 
@@ -61,12 +64,15 @@ fn get_id() -> Option<u64> {
 #[derive(Debug, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 enum WellKnown {
+    AsyncDispose,
     AsyncIterator,
+    Dispose,
     HasInstance,
     IsConcatSpreadable,
     Iterator,
     Match,
     MatchAll,
+    Metadata,
     Replace,
     Search,
     Species,
@@ -79,12 +85,15 @@ enum WellKnown {
 impl WellKnown {
     const fn description(self) -> JsString {
         match self {
+            Self::AsyncDispose => StaticJsStrings::SYMBOL_ASYNC_DISPOSE,
             Self::AsyncIterator => StaticJsStrings::SYMBOL_ASYNC_ITERATOR,
+            Self::Dispose => StaticJsStrings::SYMBOL_DISPOSE,
             Self::HasInstance => StaticJsStrings::SYMBOL_HAS_INSTANCE,
             Self::IsConcatSpreadable => StaticJsStrings::SYMBOL_IS_CONCAT_SPREADABLE,
             Self::Iterator => StaticJsStrings::SYMBOL_ITERATOR,
             Self::Match => StaticJsStrings::SYMBOL_MATCH,
             Self::MatchAll => StaticJsStrings::SYMBOL_MATCH_ALL,
+            Self::Metadata => StaticJsStrings::SYMBOL_METADATA,
             Self::Replace => StaticJsStrings::SYMBOL_REPLACE,
             Self::Search => StaticJsStrings::SYMBOL_SEARCH,
             Self::Species => StaticJsStrings::SYMBOL_SPECIES,
@@ -97,12 +106,15 @@ impl WellKnown {
 
     const fn fn_name(self) -> JsString {
         match self {
+            Self::AsyncDispose => StaticJsStrings::FN_SYMBOL_ASYNC_DISPOSE,
             Self::AsyncIterator => StaticJsStrings::FN_SYMBOL_ASYNC_ITERATOR,
+            Self::Dispose => StaticJsStrings::FN_SYMBOL_DISPOSE,
             Self::HasInstance => StaticJsStrings::FN_SYMBOL_HAS_INSTANCE,
             Self::IsConcatSpreadable => StaticJsStrings::FN_SYMBOL_IS_CONCAT_SPREADABLE,
             Self::Iterator => StaticJsStrings::FN_SYMBOL_ITERATOR,
             Self::Match => StaticJsStrings::FN_SYMBOL_MATCH,
             Self::MatchAll => StaticJsStrings::FN_SYMBOL_MATCH_ALL,
+            Self::Metadata => StaticJsStrings::FN_SYMBOL_METADATA,
             Self::Replace => StaticJsStrings::FN_SYMBOL_REPLACE,
             Self::Search => StaticJsStrings::FN_SYMBOL_SEARCH,
             Self::Species => StaticJsStrings::FN_SYMBOL_SPECIES,
@@ -123,26 +135,63 @@ impl WellKnown {
 }
 
 /// The inner representation of a JavaScript symbol.
-#[derive(Debug, Clone)]
-struct Inner {
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct Inner {
     hash: u64,
     // must be a `Box`, since this needs to be shareable between many threads.
     description: Option<Box<[u16]>>,
 }
 
+/// Bit set on the pointer of symbols allocated on the garbage collected heap.
+///
+/// Both `Inner` and `GcBox<Inner>` have an alignment of at least 4, so the two lowest bits of
+/// their pointers are always unset. The first one is used by [`Tagged`] to store the well known
+/// symbols, and the second one is used to distinguish `Gc` pointers from `Arc` pointers.
+const HEAP_BIT: usize = 0b10;
+
+const _: () = assert!(align_of::<Inner>() >= 4);
+const _: () = assert!(align_of::<GcBox<Inner>>() >= 4);
+
+/// The unwrapped representation of a [`JsSymbol`].
+enum Repr {
+    /// A well known symbol, which doesn't need any allocation.
+    WellKnown(WellKnown),
+    /// A symbol of the global symbol registry, shared between threads using an `Arc`.
+    Registered(NonNull<Inner>),
+    /// A unique symbol, allocated on the garbage collected heap.
+    Heap(NonNull<GcBox<Inner>>),
+}
+
 /// This represents a JavaScript symbol primitive.
-#[derive(Trace, Finalize, JsData)]
-// Safety: JsSymbol does not contain any objects which needs to be traced,
-// so this is safe.
-#[boa_gc(unsafe_empty_trace)]
+///
+/// Symbols created with [`JsSymbol::new`] are allocated on the garbage collected heap, which gives
+/// them an identity that can be held weakly (e.g. as a `WeakMap` key). Symbols of the global
+/// symbol registry are reference counted and shared between threads, while well known symbols
+/// are stored inline.
+///
+/// Since a heap allocated symbol belongs to the thread that created it, `JsSymbol` implements
+/// neither `Send` nor `Sync`. Send the [description][JsSymbol::description] of a symbol to share
+/// it with another thread instead.
+///
+/// ```compile_fail
+/// # use boa_engine::JsSymbol;
+/// fn assert_send<T: Send>() {}
+/// assert_send::<JsSymbol>();
+/// ```
+#[derive(Finalize, JsData)]
 pub struct JsSymbol {
     repr: Tagged<Inner>,
 }
 
-// SAFETY: `JsSymbol` uses `Arc` to do the reference counting, making this type thread-safe.
-unsafe impl Send for JsSymbol {}
-// SAFETY: `JsSymbol` uses `Arc` to do the reference counting, making this type thread-safe.
-unsafe impl Sync for JsSymbol {}
+// SAFETY: The only traceable value of a `JsSymbol` is the `Gc` of heap allocated symbols, which
+// is marked like any other `Gc`.
+unsafe impl Trace for JsSymbol {
+    custom_trace!(this, mark, {
+        if let Some(gc) = this.as_gc() {
+            mark(&*gc);
+        }
+    });
+}
 
 macro_rules! well_known_symbols {
     ( $( $(#[$attr:meta])* ($name:ident, $variant:path) ),+$(,)? ) => {
@@ -165,9 +214,23 @@ impl JsSymbol {
     #[must_use]
     pub fn new(description: Option<JsString>) -> Option<Self> {
         let hash = get_id()?;
-        let arc = Arc::new(Inner {
+        Some(Self::from_gc(Gc::new(Inner {
             hash,
             description: description.map(|s| Box::from(&*s)),
+        })))
+    }
+
+    /// Creates a new symbol for the global symbol registry.
+    ///
+    /// Contrary to [`JsSymbol::new`], the symbol is reference counted instead of garbage
+    /// collected, which allows sharing it between threads.
+    ///
+    /// Returns `None` if the maximum number of possible symbols has been reached (`u64::MAX`).
+    pub(crate) fn new_registered(description: &JsString) -> Option<Self> {
+        let hash = get_id()?;
+        let arc = Arc::new(Inner {
+            hash,
+            description: Some(Box::from(&**description)),
         });
 
         Some(Self {
@@ -176,25 +239,89 @@ impl JsSymbol {
         })
     }
 
-    /// Returns the `Symbol` description.
-    #[inline]
-    #[must_use]
-    pub fn description(&self) -> Option<JsString> {
+    /// Unwraps the representation of this symbol.
+    fn unwrap(&self) -> Repr {
         match self.repr.unwrap() {
-            UnwrappedTagged::Ptr(ptr) => {
-                // SAFETY: `ptr` comes from `Arc`, which ensures the validity of the pointer
-                // as long as we correctly call `Arc::from_raw` on `Drop`.
-                unsafe { ptr.as_ref().description.as_ref().map(|v| js_string!(&**v)) }
+            UnwrappedTagged::Ptr(ptr) if Strict::addr(ptr.as_ptr()) & HEAP_BIT != 0 => {
+                let ptr =
+                    Strict::map_addr(ptr.as_ptr(), |addr| addr & !HEAP_BIT).cast::<GcBox<Inner>>();
+                // SAFETY: `ptr` is the pointer returned by `Gc::into_raw`, which is non-null.
+                Repr::Heap(unsafe { NonNull::new_unchecked(ptr) })
             }
+            UnwrappedTagged::Ptr(ptr) => Repr::Registered(ptr),
             UnwrappedTagged::Tag(tag) => {
                 // SAFETY: All tagged reprs always come from `WellKnown` itself, making
                 // this operation always safe.
-                let wk = unsafe { WellKnown::from_tag(tag).unwrap_unchecked() };
-                Some(wk.description())
+                Repr::WellKnown(unsafe { WellKnown::from_tag(tag).unwrap_unchecked() })
+            }
+        }
+    }
+
+    /// Gets the `Gc` of a heap allocated symbol, without modifying its reference count.
+    fn as_gc(&self) -> Option<ManuallyDrop<Gc<Inner>>> {
+        if let Repr::Heap(ptr) = self.unwrap() {
+            // SAFETY: `ptr` comes from `Gc::into_raw`, and wrapping it in a `ManuallyDrop` ensures
+            // the reference count is not decremented when the returned value is dropped.
+            return Some(ManuallyDrop::new(unsafe { Gc::from_raw(ptr) }));
+        }
+        None
+    }
+
+    /// Gets the inner data of this symbol, or `None` if this is a well known symbol.
+    fn inner(&self) -> Option<&Inner> {
+        match self.unwrap() {
+            Repr::WellKnown(_) => None,
+            // SAFETY: `ptr` comes from `Arc`, which ensures the validity of the pointer
+            // as long as we correctly call `Arc::from_raw` on `Drop`.
+            Repr::Registered(ptr) => Some(unsafe { ptr.as_ref() }),
+            Repr::Heap(_) => {
+                let gc = self.as_gc()?;
+                let inner: *const Inner = &**gc;
+                // SAFETY: `self` holds a strong reference to the allocation, which keeps it
+                // alive for at least the lifetime of `self`.
+                Some(unsafe { &*inner })
             }
         }
     }
 
+    /// Gets the garbage collected identity of this symbol, if it has one.
+    ///
+    /// Only symbols created with [`JsSymbol::new`] are allocated on the garbage collected heap,
+    /// meaning registered and well known symbols return `None`.
+    pub(crate) fn to_gc(&self) -> Option<Gc<Inner>> {
+        self.as_gc().map(|gc| Gc::clone(&gc))
+    }
+
+    /// Creates a symbol from its garbage collected identity.
+    pub(crate) fn from_gc(gc: Gc<Inner>) -> Self {
+        let ptr =
+            Strict::map_addr(Gc::into_raw(gc).as_ptr(), |addr| addr | HEAP_BIT).cast::<Inner>();
+        Self {
+            // SAFETY: Pointers returned by `Gc::into_raw` must be non-null.
+            repr: unsafe { Tagged::from_ptr(ptr) },
+        }
+    }
+
+    /// Returns `true` if this symbol is part of the global symbol registry, i.e. it was
+    /// created by `Symbol.for`.
+    #[must_use]
+    pub(crate) fn is_registered(&self) -> bool {
+        matches!(self.unwrap(), Repr::Registered(_))
+    }
+
+    /// Returns the `Symbol` description.
+    #[inline]
+    #[must_use]
+    pub fn description(&self) -> Option<JsString> {
+        match self.unwrap() {
+            Repr::WellKnown(wk) => Some(wk.description()),
+            _ => self
+                .inner()
+                .and_then(|inner| inner.description.as_ref())
+                .map(|v| js_string!(&**v)),
+        }
+    }
+
     /// Returns the `Symbol` as a function name.
     ///
     /// Equivalent to `[description]`, but returns the empty string if the symbol doesn't have a
@@ -202,10 +329,7 @@ impl JsSymbol {
     #[inline]
     #[must_use]
     pub fn fn_name(&self) -> JsString {
-        if let UnwrappedTagged::Tag(tag) = self.repr.unwrap() {
-            // SAFETY: All tagged reprs always come from `WellKnown` itself, making
-            // this operation always safe.
-            let wk = unsafe { WellKnown::from_tag(tag).unwrap_unchecked() };
+        if let Repr::WellKnown(wk) = self.unwrap() {
             return wk.fn_name();
         }
         self.description()
@@ -219,17 +343,9 @@ impl JsSymbol {
     #[inline]
     #[must_use]
     pub fn hash(&self) -> u64 {
-        match self.repr.unwrap() {
-            UnwrappedTagged::Ptr(ptr) => {
-                // SAFETY: `ptr` comes from `Arc`, which ensures the validity of the pointer
-                // as long as we correctly call `Arc::from_raw` on `Drop`.
-                unsafe { ptr.as_ref().hash }
-            }
-            UnwrappedTagged::Tag(tag) => {
-                // SAFETY: All tagged reprs always come from `WellKnown` itself, making
-                // this operation always safe.
-                unsafe { WellKnown::from_tag(tag).unwrap_unchecked().hash() }
-            }
+        match self.unwrap() {
+            Repr::WellKnown(wk) => wk.hash(),
+            _ => self.inner().map_or(0, |inner| inner.hash),
         }
     }
 
@@ -248,8 +364,12 @@ impl JsSymbol {
     }
 
     well_known_symbols! {
+        /// Gets the static `JsSymbol` for `"Symbol.asyncDispose"`.
+        (async_dispose, WellKnown::AsyncDispose),
         /// Gets the static `JsSymbol` for `"Symbol.asyncIterator"`.
         (async_iterator, WellKnown::AsyncIterator),
+        /// Gets the static `JsSymbol` for `"Symbol.dispose"`.
+        (dispose, WellKnown::Dispose),
         /// Gets the static `JsSymbol` for `"Symbol.hasInstance"`.
         (has_instance, WellKnown::HasInstance),
         /// Gets the static `JsSymbol` for `"Symbol.isConcatSpreadable"`.
@@ -260,6 +380,8 @@ impl JsSymbol {
         (r#match, WellKnown::Match),
         /// Gets the static `JsSymbol` for `"Symbol.matchAll"`.
         (match_all, WellKnown::MatchAll),
+        /// Gets the static `JsSymbol` for `"Symbol.metadata"`.
+        (metadata, WellKnown::Metadata),
         /// Gets the static `JsSymbol` for `"Symbol.replace"`.
         (replace, WellKnown::Replace),
         /// Gets the static `JsSymbol` for `"Symbol.search"`.
@@ -279,15 +401,24 @@ impl JsSymbol {
 
 impl Clone for JsSymbol {
     fn clone(&self) -> Self {
-        if let UnwrappedTagged::Ptr(ptr) = self.repr.unwrap() {
-            // SAFETY: the pointer returned by `self.repr` must be a valid pointer
-            // that came from an `Arc::into_raw` call.
-            unsafe {
-                let arc = Arc::from_raw(ptr.as_ptr().cast_const());
-                // Don't need the Arc since `self` is already a copyable pointer, just need to
-                // trigger the `clone` impl.
-                std::mem::forget(arc.clone());
-                std::mem::forget(arc);
+        match self.unwrap() {
+            Repr::WellKnown(_) => {}
+            Repr::Registered(ptr) => {
+                // SAFETY: the pointer returned by `self.repr` must be a valid pointer
+                // that came from an `Arc::into_raw` call.
+                unsafe {
+                    let arc = Arc::from_raw(ptr.as_ptr().cast_const());
+                    // Don't need the Arc since `self` is already a copyable pointer, just need to
+                    // trigger the `clone` impl.
+                    std::mem::forget(arc.clone());
+                    std::mem::forget(arc);
+                }
+            }
+            Repr::Heap(_) => {
+                // Same as above, but triggering the `clone` impl of `Gc`.
+                if let Some(gc) = self.as_gc() {
+                    std::mem::forget(Gc::clone(&gc));
+                }
             }
         }
         Self { repr: self.repr }
@@ -296,10 +427,14 @@ impl Clone for JsSymbol {
 
 impl Drop for JsSymbol {
     fn drop(&mut self) {
-        if let UnwrappedTagged::Ptr(ptr) = self.repr.unwrap() {
+        match self.unwrap() {
+            Repr::WellKnown(_) => {}
             // SAFETY: the pointer returned by `self.repr` must be a valid pointer
             // that came from an `Arc::into_raw` call.
-            unsafe { drop(Arc::from_raw(ptr.as_ptr().cast_const())) }
+            Repr::Registered(ptr) => unsafe { drop(Arc::from_raw(ptr.as_ptr().cast_const())) },
+            // SAFETY: the pointer returned by `self.repr` must be a valid pointer
+            // that came from a `Gc::into_raw` call.
+            Repr::Heap(ptr) => unsafe { drop(Gc::from_raw(ptr)) },
         }
     }
 }
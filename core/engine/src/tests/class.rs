@@ -0,0 +1,237 @@
+use crate::{js_string, run_test_actions, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn decorators_evaluation_order() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+            const log = [];
+            function dec(name) {
+                log.push(`eval ${name}`);
+                return function (value, context) {
+                    log.push(`apply ${name} (${context.kind})`);
+                };
+            }
+
+            @dec("class")
+            class A {
+                @dec("field") x = 1;
+                @dec("static method") static m() {}
+                @dec("method 1") @dec("method 2") m() {}
+            }
+
+            log.join(", ")
+        "#},
+        js_string!(
+            "eval class, eval field, eval static method, eval method 1, eval method 2, \
+             apply static method (method), apply method 2 (method), apply method 1 (method), \
+             apply field (field), apply class (class)"
+        ),
+    )]);
+}
+
+#[test]
+fn decorators_replace_method_and_class() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function double(method, context) {
+                return function (...args) {
+                    return method.call(this, ...args) * 2;
+                };
+            }
+
+            function replace(value, { kind, name }) {
+                return class extends value {
+                    kind = kind;
+                    name = name;
+                };
+            }
+
+            @replace
+            class A {
+                @double value() { return 21; }
+            }
+
+            const a = new A();
+        "#}),
+        TestAction::assert_eq("a.value()", 42),
+        TestAction::assert_eq("a.kind", js_string!("class")),
+        TestAction::assert_eq("a.name", js_string!("A")),
+    ]);
+}
+
+#[test]
+fn decorators_field_initializers() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const log = [];
+            function plusOne(value, context) {
+                context.addInitializer(function () {
+                    log.push(this.x);
+                });
+                return (initial) => initial + 1;
+            }
+
+            class A {
+                @plusOne x = 1;
+                @plusOne #y = 10;
+                get y() { return this.#y; }
+            }
+
+            const a = new A();
+        "#}),
+        TestAction::assert_eq("a.x", 2),
+        TestAction::assert_eq("a.y", 11),
+        TestAction::assert_eq("log.join()", js_string!("2,2")),
+    ]);
+}
+
+#[test]
+fn decorators_add_initializer() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const log = [];
+            function init(name) {
+                return function (value, context) {
+                    context.addInitializer(function () {
+                        log.push(`${name}: ${typeof this}`);
+                    });
+                };
+            }
+
+            @init("class")
+            class A {
+                @init("method") m() {}
+                @init("static method") static m() {}
+            }
+            log.push("defined");
+            new A();
+        "#}),
+        TestAction::assert_eq(
+            "log.join(', ')",
+            js_string!("static method: function, class: function, defined, method: object"),
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                let add;
+                class B {
+                    @((_, context) => { add = context.addInitializer; }) m() {}
+                }
+                add(() => {});
+            "#},
+            JsNativeErrorKind::Type,
+            "cannot add initializers after decoration has finished",
+        ),
+    ]);
+}
+
+#[test]
+fn decorators_auto_accessor() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const log = [];
+            function logged({ get, set }, context) {
+                return {
+                    get() {
+                        log.push(`get ${context.name}`);
+                        return get.call(this);
+                    },
+                    set(value) {
+                        log.push(`set ${context.name}`);
+                        set.call(this, value);
+                    },
+                    init(value) {
+                        return value * 10;
+                    },
+                };
+            }
+
+            class A {
+                @logged accessor x = 1;
+                accessor y = 2;
+                static accessor #z = 3;
+                static z() { return A.#z; }
+            }
+
+            const a = new A();
+        "#}),
+        TestAction::assert_eq("a.x", 10),
+        TestAction::assert_eq("a.x = 5; a.x", 5),
+        TestAction::assert_eq("log.join()", js_string!("get x,set x,get x")),
+        TestAction::assert_eq("a.y", 2),
+        TestAction::assert_eq("A.z()", 3),
+        TestAction::assert("Object.hasOwn(A.prototype, 'x')"),
+        TestAction::assert("!Object.hasOwn(a, 'x')"),
+    ]);
+}
+
+#[test]
+fn decorators_access_object() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let access;
+            class A {
+                @((_, context) => { access = context.access; }) #x = 1;
+                static has(obj) { return access.has(obj); }
+            }
+            const a = new A();
+        "#}),
+        TestAction::assert_eq("access.get(a)", 1),
+        TestAction::assert_eq("access.set(a, 2); access.get(a)", 2),
+        TestAction::assert("access.has(a)"),
+        TestAction::assert("!access.has({})"),
+        TestAction::assert_native_error(
+            "access.get(1)",
+            JsNativeErrorKind::Type,
+            "decorator access target must be an object",
+        ),
+    ]);
+}
+
+#[test]
+fn decorators_metadata() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function meta(value, context) {
+                context.metadata[context.name] = context.kind;
+            }
+
+            @meta
+            class A {
+                @meta x;
+            }
+
+            class B extends A {
+                @meta m() {}
+            }
+
+            class C {}
+        "#}),
+        TestAction::assert_eq("A[Symbol.metadata].x", js_string!("field")),
+        TestAction::assert_eq("A[Symbol.metadata].A", js_string!("class")),
+        TestAction::assert_eq("B[Symbol.metadata].m", js_string!("method")),
+        TestAction::assert("Object.getPrototypeOf(B[Symbol.metadata]) === A[Symbol.metadata]"),
+        TestAction::assert("C[Symbol.metadata] === undefined"),
+    ]);
+}
+
+#[test]
+fn decorators_invalid_return_value() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "class A { @(() => 1) m() {} }",
+            JsNativeErrorKind::Type,
+            "method decorators must return a function or undefined",
+        ),
+        TestAction::assert_native_error(
+            "class B { @(() => 1) accessor x; }",
+            JsNativeErrorKind::Type,
+            "accessor decorators must return an object or undefined",
+        ),
+        TestAction::assert_native_error(
+            "@(() => 1) class C {}",
+            JsNativeErrorKind::Type,
+            "class decorators must return a function or undefined",
+        ),
+    ]);
+}
@@ -1,12 +1,15 @@
 use indoc::indoc;
 
+mod class;
 mod control_flow;
 mod env;
 mod function;
 mod iterators;
+mod module;
 mod operators;
 mod promise;
 mod spread;
+mod using;
 
 use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
 
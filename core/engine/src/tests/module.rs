@@ -0,0 +1,118 @@
+use std::{
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use boa_parser::Source;
+use indoc::indoc;
+
+use crate::{
+    js_string,
+    module::{Module, SimpleModuleLoader, SyntheticModuleInitializer},
+    run_test_actions, Context, JsValue, TestAction,
+};
+
+#[test]
+#[allow(clippy::redundant_closure_for_method_calls)]
+fn dynamic_import_options() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let errors = [];
+            const options = [
+                1,
+                { with: 1 },
+                { with: { type: 1 } },
+                { with: { unsupported: "value" } },
+            ];
+            for (const option of options) {
+                import("./module.js", option).catch(e => errors.push(e.constructor.name));
+            }
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert_eq("errors.length", 4),
+        TestAction::assert_eq("errors[0]", js_string!("TypeError")),
+        TestAction::assert_eq("errors[1]", js_string!("TypeError")),
+        TestAction::assert_eq("errors[2]", js_string!("TypeError")),
+        TestAction::assert_eq("errors[3]", js_string!("SyntaxError")),
+    ]);
+}
+
+#[test]
+#[allow(clippy::redundant_closure_for_method_calls)]
+fn dynamic_import_options_getter() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let error;
+            const options = { get with() { throw new RangeError("getter"); } };
+            import("./module.js", options).catch(e => { error = e; });
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert("error instanceof RangeError"),
+        TestAction::assert_eq("error.message", js_string!("getter")),
+    ]);
+}
+
+#[test]
+fn simple_module_loader_drops_modules_of_collected_realms() {
+    let loader = Rc::new(SimpleModuleLoader::new(".").unwrap());
+    let context = &mut Context::builder()
+        .module_loader(loader.clone())
+        .build()
+        .unwrap();
+    let path = PathBuf::from("module.mjs");
+
+    let realm = context.create_realm().unwrap();
+    let module = Module::parse(
+        Source::from_bytes("export default 1;"),
+        Some(realm),
+        context,
+    )
+    .unwrap();
+    loader.insert(path.clone(), module);
+    assert!(loader.get(&path).is_some());
+
+    // Nothing else references the realm, so the loader doesn't keep its module alive.
+    boa_gc::force_collect();
+    assert!(loader.get(&path).is_none());
+}
+
+#[test]
+fn json_import_ignores_inserted_synthetic_modules() {
+    let loader = Rc::new(SimpleModuleLoader::new(".").unwrap());
+    let context = &mut Context::builder()
+        .module_loader(loader.clone())
+        .build()
+        .unwrap();
+
+    let module = Module::synthetic(
+        &[js_string!("default")],
+        SyntheticModuleInitializer::from_copy_closure(|module, _| {
+            module.set_export(&js_string!("default"), 1.into())
+        }),
+        None,
+        context,
+    );
+    loader.insert(Path::new("Cargo.toml").canonicalize().unwrap(), module);
+
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            let synthetic, error;
+            import("./Cargo.toml").then(ns => { synthetic = ns.default; });
+            import("./Cargo.toml", { with: { type: "json" } }).catch(e => { error = e; });
+        "#}))
+        .unwrap();
+    context.run_jobs();
+
+    // The synthetic module is only used for JavaScript imports, so the JSON import tries to
+    // parse the file instead.
+    assert_eq!(
+        context.eval(Source::from_bytes("synthetic")).unwrap(),
+        JsValue::from(1)
+    );
+    assert_eq!(
+        context
+            .eval(Source::from_bytes("error instanceof SyntaxError"))
+            .unwrap(),
+        JsValue::from(true)
+    );
+}
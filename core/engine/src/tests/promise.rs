@@ -1,6 +1,21 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{self, Poll},
+    thread,
+    time::Duration,
+};
+
 use indoc::indoc;
 
-use crate::{run_test_actions, TestAction};
+use crate::{
+    builtins::promise::PromiseState, object::builtins::JsPromise, run_test_actions, JsResult,
+    JsValue, TestAction,
+};
 
 #[test]
 #[allow(clippy::redundant_closure_for_method_calls)]
@@ -38,3 +53,54 @@ fn issue_2658() {
         TestAction::assert_eq("result2.value", 5),
     ]);
 }
+
+/// A future that completes after another thread wakes it up.
+#[derive(Default)]
+struct RemoteFuture {
+    done: Arc<AtomicBool>,
+    started: bool,
+}
+
+impl Future for RemoteFuture {
+    type Output = JsResult<JsValue>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        if self.done.load(Ordering::Acquire) {
+            return Poll::Ready(Ok(JsValue::new(42)));
+        }
+
+        if !self.started {
+            self.started = true;
+            let done = self.done.clone();
+            let waker = cx.waker().clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(10));
+                done.store(true, Ordering::Release);
+                waker.wake();
+            });
+        }
+
+        Poll::Pending
+    }
+}
+
+#[test]
+fn future_woken_by_another_thread() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        let promise = JsPromise::from_future(RemoteFuture::default(), ctx);
+
+        // The future is still pending, so this returns without blocking.
+        ctx.run_jobs();
+        assert_eq!(promise.state(), PromiseState::Pending);
+
+        // Once it is woken up, the future is polled again by the next call.
+        for _ in 0..1000 {
+            thread::sleep(Duration::from_millis(1));
+            ctx.run_jobs();
+            if promise.state() != PromiseState::Pending {
+                break;
+            }
+        }
+        assert_eq!(promise.state(), PromiseState::Fulfilled(JsValue::new(42)));
+    })]);
+}
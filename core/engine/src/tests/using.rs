@@ -0,0 +1,124 @@
+use crate::{js_string, run_test_actions, Context, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn using_disposes_in_reverse_order() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const log = [];
+            function resource(name) {
+                return { [Symbol.dispose]() { log.push(`dispose ${name}`); } };
+            }
+
+            {
+                using a = resource("a"), b = resource("b");
+                using c = null;
+                log.push("body");
+            }
+        "#}),
+        TestAction::assert_eq("log.join(', ')", js_string!("body, dispose b, dispose a")),
+    ]);
+}
+
+#[test]
+fn using_disposes_on_abrupt_completion() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const log = [];
+            function resource(name) {
+                return { [Symbol.dispose]() { log.push(name); } };
+            }
+
+            function returns() {
+                using r = resource("return");
+                return "value";
+            }
+
+            function throws() {
+                using r = resource("throw");
+                throw new Error("body");
+            }
+
+            const returned = returns();
+
+            let thrown;
+            try {
+                throws();
+            } catch (e) {
+                thrown = e;
+            }
+
+            for (let i = 0; i < 3; i++) {
+                using r = resource(`loop ${i}`);
+                if (i === 1) {
+                    break;
+                }
+            }
+
+            for (using r of [resource("of 0"), resource("of 1")]) {
+                log.push("body");
+            }
+        "#}),
+        TestAction::assert_eq("returned", js_string!("value")),
+        TestAction::assert_eq("thrown.message", js_string!("body")),
+        TestAction::assert_eq(
+            "log.join(', ')",
+            js_string!("return, throw, loop 0, loop 1, body, of 0, body, of 1"),
+        ),
+    ]);
+}
+
+#[test]
+fn using_suppressed_error() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let error;
+            try {
+                using a = { [Symbol.dispose]() { throw new Error("dispose"); } };
+                throw new Error("body");
+            } catch (e) {
+                error = e;
+            }
+        "#}),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error.message", js_string!("dispose")),
+        TestAction::assert_eq("error.suppressed.message", js_string!("body")),
+        TestAction::assert_native_error(
+            "{ using a = {}; }",
+            JsNativeErrorKind::Type,
+            "disposable resource must have a dispose method",
+        ),
+        TestAction::assert_native_error(
+            "{ using a = 1; }",
+            JsNativeErrorKind::Type,
+            "disposable resource must be an object",
+        ),
+    ]);
+}
+
+#[test]
+fn await_using() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const log = [];
+            async function f() {
+                await using a = {
+                    async [Symbol.asyncDispose]() {
+                        await null;
+                        log.push("async");
+                    },
+                };
+                await using b = { [Symbol.dispose]() { log.push("sync"); } };
+                log.push("body");
+                return "done";
+            }
+
+            let result;
+            f().then((value) => { result = value; });
+            log.push("called");
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("log.join(', ')", js_string!("body, sync, called, async")),
+        TestAction::assert_eq("result", js_string!("done")),
+    ]);
+}
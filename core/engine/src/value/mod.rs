@@ -82,8 +82,10 @@ pub enum JsValue {
 
 unsafe impl Trace for JsValue {
     custom_trace! {this, mark, {
-        if let Self::Object(o) = this {
-            mark(o);
+        match this {
+            Self::Object(o) => mark(o),
+            Self::Symbol(s) => mark(s),
+            _ => {}
         }
     }}
 }
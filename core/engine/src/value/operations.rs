@@ -6,6 +6,7 @@ use crate::{
     error::JsNativeError,
     js_string,
     value::{JsSymbol, Numeric, PreferredType},
+    vm::check_string_allocation,
     Context, JsBigInt, JsResult, JsValue,
 };
 
@@ -24,15 +25,26 @@ impl JsValue {
             (Self::BigInt(ref x), Self::BigInt(ref y)) => Self::new(JsBigInt::add(x, y)),
 
             // String concat
-            (Self::String(ref x), Self::String(ref y)) => Self::from(js_string!(x, y)),
+            (Self::String(ref x), Self::String(ref y)) => {
+                check_string_allocation(x.len() + y.len())?;
+                Self::from(js_string!(x, y))
+            }
 
             // Slow path:
             (_, _) => match (
                 self.to_primitive(context, PreferredType::Default)?,
                 other.to_primitive(context, PreferredType::Default)?,
             ) {
-                (Self::String(ref x), ref y) => Self::from(js_string!(x, &y.to_string(context)?)),
-                (ref x, Self::String(ref y)) => Self::from(js_string!(&x.to_string(context)?, y)),
+                (Self::String(ref x), ref y) => {
+                    let y = y.to_string(context)?;
+                    check_string_allocation(x.len() + y.len())?;
+                    Self::from(js_string!(x, &y))
+                }
+                (ref x, Self::String(ref y)) => {
+                    let x = x.to_string(context)?;
+                    check_string_allocation(x.len() + y.len())?;
+                    Self::from(js_string!(&x, y))
+                }
                 (x, y) => match (x.to_numeric(context)?, y.to_numeric(context)?) {
                     (Numeric::Number(x), Numeric::Number(y)) => Self::new(x + y),
                     (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => {
@@ -4,6 +4,7 @@
 
 use crate::{
     builtins::{
+        disposable_stack::DisposeCapability,
         iterable::IteratorRecord,
         promise::{PromiseCapability, ResolvingFunctions},
     },
@@ -50,6 +51,9 @@ pub struct CallFrame {
     // Iterators and their `[[Done]]` flags that must be closed when an abrupt completion is thrown.
     pub(crate) iterators: ThinVec<IteratorRecord>,
 
+    // The dispose capabilities of the scopes with `using` declarations.
+    pub(crate) dispose_capabilities: ThinVec<DisposeCapability>,
+
     // The stack of bindings being updated.
     pub(crate) binding_stack: Vec<BindingLocator>,
 
@@ -150,6 +154,7 @@ impl CallFrame {
             env_fp: 0,
             argument_count: 0,
             iterators: ThinVec::new(),
+            dispose_capabilities: ThinVec::new(),
             binding_stack: Vec::new(),
             loop_iteration_count: 0,
             active_runnable,
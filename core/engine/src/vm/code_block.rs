@@ -12,13 +12,13 @@ use crate::{
     Context, JsBigInt, JsString, JsValue,
 };
 use bitflags::bitflags;
-use boa_ast::function::FormalParameterList;
+use boa_ast::{function::FormalParameterList, Position};
 use boa_gc::{empty_trace, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
 use std::{cell::Cell, fmt::Display, mem::size_of, rc::Rc};
 use thin_vec::ThinVec;
 
-use super::{InlineCache, Instruction, InstructionIterator};
+use super::{ActiveRunnable, InlineCache, Instruction, InstructionIterator};
 
 /// This represents whether a value can be read from [`CodeBlock`] code.
 ///
@@ -106,6 +106,16 @@ impl Handler {
     }
 }
 
+/// Maps an offset of the bytecode to the source [`Position`] of the code that generated it.
+///
+/// The entries of a [`CodeBlock`] are sorted by `pc`, and an entry applies to all the
+/// instructions up to the next entry.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SourceMapEntry {
+    pub(crate) pc: u32,
+    pub(crate) position: Position,
+}
+
 #[derive(Clone, Debug, Trace, Finalize)]
 pub(crate) enum Constant {
     /// Property field names and private names `[[description]]`s.
@@ -162,6 +172,10 @@ pub struct CodeBlock {
 
     /// inline caching
     pub(crate) ic: Box<[InlineCache]>,
+
+    /// Source positions of the bytecode, used to build stack traces.
+    #[unsafe_ignore_trace]
+    pub(crate) source_map: Box<[SourceMapEntry]>,
 }
 
 /// ---- `CodeBlock` public API ----
@@ -183,6 +197,7 @@ impl CodeBlock {
             params: FormalParameterList::default(),
             handlers: ThinVec::default(),
             ic: Box::default(),
+            source_map: Box::default(),
         }
     }
 
@@ -192,6 +207,18 @@ impl CodeBlock {
         &self.name
     }
 
+    /// Gets the source position of the code that is executing when the program counter is `pc`.
+    ///
+    /// Since `pc` points past the instruction being executed, this returns the position of the
+    /// last recorded entry before `pc`.
+    #[must_use]
+    pub fn position_at(&self, pc: u32) -> Option<Position> {
+        let index = self.source_map.partition_point(|entry| entry.pc < pc);
+        index
+            .checked_sub(1)
+            .map(|index| self.source_map[index].position)
+    }
+
     /// Check if the function is traced.
     #[cfg(feature = "trace")]
     pub(crate) fn traceable(&self) -> bool {
@@ -425,6 +452,18 @@ impl CodeBlock {
             Instruction::PushDeclarativeEnvironment {
                 compile_environments_index,
             } => compile_environments_index.value().to_string(),
+            Instruction::PushClassDecorators { decorator_count } => {
+                decorator_count.value().to_string()
+            }
+            Instruction::PushClassElement {
+                kind,
+                is_static,
+                is_private,
+                decorator_count,
+            } => format!(
+                "kind: {kind}, static: {is_static}, private: {is_private}, decorators: {}",
+                decorator_count.value()
+            ),
             Instruction::CopyDataProperties {
                 excluded_key_count: value1,
                 excluded_key_count_computed: value2,
@@ -525,6 +564,9 @@ impl CodeBlock {
             Instruction::CreateIteratorResult { done } => {
                 format!("done: {done}")
             }
+            Instruction::AddDisposableResource { is_async } => {
+                format!("is_async: {is_async}")
+            }
             Instruction::CreateGlobalFunctionBinding {
                 name_index,
                 configurable,
@@ -602,6 +644,11 @@ impl CodeBlock {
             | Instruction::ReThrow
             | Instruction::Exception
             | Instruction::MaybeException
+            | Instruction::PushDisposeScope
+            | Instruction::DisposeResources
+            | Instruction::ResumeDisposeResources
+            | Instruction::CaptureDisposeError
+            | Instruction::PopDisposeScope
             | Instruction::This
             | Instruction::Super
             | Instruction::CheckReturn
@@ -636,6 +683,8 @@ impl CodeBlock {
             | Instruction::AsyncGeneratorYield
             | Instruction::GeneratorNext
             | Instruction::PushClassField
+            | Instruction::ApplyClassDecorators
+            | Instruction::InitializeDecoratedClass
             | Instruction::SuperCallDerived
             | Instruction::Await
             | Instruction::NewTarget
@@ -707,17 +756,7 @@ impl CodeBlock {
             | Instruction::Reserved45
             | Instruction::Reserved46
             | Instruction::Reserved47
-            | Instruction::Reserved48
-            | Instruction::Reserved49
-            | Instruction::Reserved50
-            | Instruction::Reserved51
-            | Instruction::Reserved52
-            | Instruction::Reserved53
-            | Instruction::Reserved54
-            | Instruction::Reserved55
-            | Instruction::Reserved56
-            | Instruction::Reserved57
-            | Instruction::Reserved58 => unreachable!("Reserved opcodes are unrechable"),
+            | Instruction::Reserved48 => unreachable!("Reserved opcodes are unrechable"),
         }
     }
 }
@@ -918,14 +957,19 @@ pub(crate) fn create_function_object(
 /// This is prefered over [`create_function_object`] if prototype is [`None`],
 /// because it constructs the function from a pre-initialized object template,
 /// with all the properties and prototype set.
-pub(crate) fn create_function_object_fast(code: Gc<CodeBlock>, context: &mut Context) -> JsObject {
+///
+/// `script_or_module` is the `[[ScriptOrModule]]` of the new function, which is usually the
+/// result of [`Context::get_active_script_or_module`].
+pub(crate) fn create_function_object_fast(
+    code: Gc<CodeBlock>,
+    script_or_module: Option<ActiveRunnable>,
+    context: &mut Context,
+) -> JsObject {
     let _timer = Profiler::global().start_event("create_function_object_fast", "vm");
 
     let name: JsValue = code.name().clone().into();
     let length: JsValue = code.length.into();
 
-    let script_or_module = context.get_active_script_or_module();
-
     let is_async = code.is_async();
     let is_generator = code.is_generator();
     let has_prototype_property = code.has_prototype_property();
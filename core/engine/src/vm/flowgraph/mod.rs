@@ -276,6 +276,10 @@ impl CodeBlock {
                 | Instruction::PushClassPrivateGetter { .. }
                 | Instruction::PushClassPrivateSetter { .. }
                 | Instruction::PushClassPrivateMethod { .. }
+                | Instruction::PushClassDecorators { .. }
+                | Instruction::PushClassElement { .. }
+                | Instruction::ApplyClassDecorators
+                | Instruction::InitializeDecoratedClass
                 | Instruction::InPrivate { .. }
                 | Instruction::ThrowMutateImmutable { .. } => {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
@@ -445,6 +449,12 @@ impl CodeBlock {
                 | Instruction::SetReturnValue
                 | Instruction::Exception
                 | Instruction::MaybeException
+                | Instruction::PushDisposeScope
+                | Instruction::AddDisposableResource { .. }
+                | Instruction::DisposeResources
+                | Instruction::ResumeDisposeResources
+                | Instruction::CaptureDisposeError
+                | Instruction::PopDisposeScope
                 | Instruction::CheckReturn
                 | Instruction::BindThisValue
                 | Instruction::CreateMappedArgumentsObject
@@ -506,17 +516,7 @@ impl CodeBlock {
                 | Instruction::Reserved45
                 | Instruction::Reserved46
                 | Instruction::Reserved47
-                | Instruction::Reserved48
-                | Instruction::Reserved49
-                | Instruction::Reserved50
-                | Instruction::Reserved51
-                | Instruction::Reserved52
-                | Instruction::Reserved53
-                | Instruction::Reserved54
-                | Instruction::Reserved55
-                | Instruction::Reserved56
-                | Instruction::Reserved57
-                | Instruction::Reserved58 => unreachable!("Reserved opcodes are unrechable"),
+                | Instruction::Reserved48 => unreachable!("Reserved opcodes are unrechable"),
             }
         }
 
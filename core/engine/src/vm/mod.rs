@@ -11,7 +11,7 @@ use crate::{
 
 use boa_gc::{custom_trace, Finalize, Trace};
 use boa_profiler::Profiler;
-use std::{future::Future, mem::size_of, ops::ControlFlow, pin::Pin, task};
+use std::{future::Future, mem::size_of, ops::ControlFlow, path::Path, pin::Pin, task};
 
 #[cfg(feature = "trace")]
 use crate::sys::time::Instant;
@@ -41,6 +41,7 @@ pub(crate) use {
     call_frame::CallFrameFlags,
     code_block::{
         create_function_object, create_function_object_fast, CodeBlockFlags, Constant, Handler,
+        SourceMapEntry,
     },
     completion_record::CompletionRecord,
     opcode::BindingOpcode,
@@ -86,6 +87,16 @@ pub(crate) enum ActiveRunnable {
     Module(Module),
 }
 
+impl ActiveRunnable {
+    /// Gets the path of the file this script or module was parsed from, if any.
+    pub(crate) fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Script(script) => script.path(),
+            Self::Module(module) => module.path(),
+        }
+    }
+}
+
 unsafe impl Trace for ActiveRunnable {
     custom_trace!(this, mark, {
         match this {
@@ -423,6 +434,9 @@ impl Context {
         let result = match result {
             Ok(result) => result,
             Err(err) => {
+                // Capture the call stack of native errors before any frame is popped.
+                let err = err.inject_stack(self);
+
                 // If we hit the execution step limit, bubble up the error to the
                 // (Rust) caller instead of trying to handle as an exception.
                 if !err.is_catchable() {
@@ -481,7 +495,8 @@ impl Context {
                         self.vm
                             .pending_exception
                             .take()
-                            .expect("Err must exist for a CompletionType::Throw"),
+                            .expect("Err must exist for a CompletionType::Throw")
+                            .attach_error_stack(self),
                     ));
                 }
 
@@ -502,7 +517,8 @@ impl Context {
                             self.vm
                                 .pending_exception
                                 .take()
-                                .expect("Err must exist for a CompletionType::Throw"),
+                                .expect("Err must exist for a CompletionType::Throw")
+                                .attach_error_stack(self),
                         ));
                     }
 
@@ -531,6 +547,7 @@ impl Context {
     #[allow(clippy::future_not_send)]
     pub(crate) async fn run_async_with_budget(&mut self, budget: u32) -> CompletionRecord {
         let _timer = Profiler::global().start_event("run_async_with_budget", "vm");
+        let mut heap = self.heap().enter();
 
         #[cfg(feature = "trace")]
         if self.vm.trace {
@@ -549,13 +566,18 @@ impl Context {
 
             if runtime_budget == 0 {
                 runtime_budget = budget;
+
+                // Other contexts can run while this one is suspended.
+                drop(heap);
                 yield_now().await;
+                heap = self.heap().enter();
             }
         }
     }
 
     pub(crate) fn run(&mut self) -> CompletionRecord {
         let _timer = Profiler::global().start_event("run", "vm");
+        let _heap = self.heap().enter();
 
         #[cfg(feature = "trace")]
         if self.vm.trace {
@@ -585,8 +607,42 @@ impl Context {
                 .into());
         }
 
-        Ok(())
+        check_heap_limit()
+    }
+}
+
+/// Must throw if an allocation went over the maximum size of the current heap.
+///
+/// The [`RuntimeLimit`](crate::JsNativeErrorKind::RuntimeLimit) error skips the `catch` blocks of the
+/// script, and is returned to the host that called into the context.
+pub(crate) fn check_heap_limit() -> JsResult<()> {
+    if boa_gc::take_heap_limit_exceeded() {
+        return Err(JsNativeError::runtime_limit()
+            .with_message("exceeded maximum heap size")
+            .into());
+    }
+
+    Ok(())
+}
+
+/// Must throw if allocating `bytes` outside of the garbage collected heap, for a value of the
+/// heap, would go over the maximum heap size.
+///
+/// This must be checked before growing the memory that values own outside of the heap, since
+/// it is not counted by the garbage collector.
+pub(crate) fn check_external_allocation(bytes: usize) -> JsResult<()> {
+    if bytes > 0 && !boa_gc::can_allocate_external(bytes) {
+        return Err(JsNativeError::runtime_limit()
+            .with_message("exceeded maximum heap size")
+            .into());
     }
+
+    Ok(())
+}
+
+/// Must throw if allocating a string of `len` code units would go over the maximum heap size.
+pub(crate) fn check_string_allocation(len: usize) -> JsResult<()> {
+    check_external_allocation(len.saturating_mul(std::mem::size_of::<u16>()))
 }
 
 /// Yields once to the executor.
@@ -1,5 +1,5 @@
 use crate::{
-    vm::{opcode::Operation, CompletionType},
+    vm::{check_string_allocation, opcode::Operation, CompletionType},
     Context, JsResult, JsString,
 };
 
@@ -17,6 +17,7 @@ impl ConcatToString {
             strings.push(context.vm.pop().to_string(context)?);
         }
         strings.reverse();
+        check_string_allocation(strings.iter().map(|s| s.len()).sum())?;
         let s = JsString::concat_array(
             &strings
                 .iter()
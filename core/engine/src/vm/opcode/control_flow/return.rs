@@ -1,6 +1,6 @@
 use crate::{
     vm::{opcode::Operation, CompletionType},
-    Context, JsNativeError, JsResult,
+    Context, JsError, JsNativeError, JsResult,
 };
 
 /// `Return` implements the Opcode Operation for `Opcode::Return`
@@ -46,12 +46,12 @@ impl Operation for CheckReturn {
             this
         } else if !result.is_undefined() {
             let realm = context.vm.frame().realm.clone();
-            context.vm.pending_exception = Some(
+            let err = JsError::from(
                 JsNativeError::typ()
                     .with_message("derived constructor can only return an Object or undefined")
-                    .with_realm(realm)
-                    .into(),
+                    .with_realm(realm),
             );
+            context.vm.pending_exception = Some(err.inject_stack(context));
             return Ok(CompletionType::Throw);
         } else {
             let realm = context.vm.frame().realm.clone();
@@ -59,7 +59,7 @@ impl Operation for CheckReturn {
 
             match this {
                 Err(err) => {
-                    let err = err.inject_realm(realm);
+                    let err = err.inject_realm(realm).inject_stack(context);
                     context.vm.pending_exception = Some(err);
                     return Ok(CompletionType::Throw);
                 }
@@ -0,0 +1,183 @@
+use crate::{
+    builtins::disposable_stack::{DisposableResource, DisposeCapability, DisposeHint},
+    vm::{opcode::Operation, CompletionType, GeneratorResumeKind},
+    Context, JsError, JsResult,
+};
+
+/// `PushDisposeScope` implements the Opcode Operation for `Opcode::PushDisposeScope`
+///
+/// Operation:
+///  - Push a new dispose capability for the `using` declarations of a scope.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushDisposeScope;
+
+impl Operation for PushDisposeScope {
+    const NAME: &'static str = "PushDisposeScope";
+    const INSTRUCTION: &'static str = "INST - PushDisposeScope";
+    const COST: u8 = 1;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .push(DisposeCapability::default());
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `AddDisposableResource` implements the Opcode Operation for `Opcode::AddDisposableResource`
+///
+/// Operation:
+///  - Add a resource to the dispose capability of the current scope.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddDisposableResource;
+
+impl Operation for AddDisposableResource {
+    const NAME: &'static str = "AddDisposableResource";
+    const INSTRUCTION: &'static str = "INST - AddDisposableResource";
+    const COST: u8 = 4;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let hint = if context.vm.read::<u8>() != 0 {
+            DisposeHint::AsyncDispose
+        } else {
+            DisposeHint::SyncDispose
+        };
+        let value = context.vm.pop();
+
+        let resource = DisposableResource::create(value, hint, context)?;
+        context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .last_mut()
+            .expect("dispose capability stack should have at least a capability")
+            .add_resource(resource);
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `DisposeResources` implements the Opcode Operation for `Opcode::DisposeResources`
+///
+/// Operation:
+///  - Dispose of the resources of the current scope until a value needs to be awaited.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeResources;
+
+impl Operation for DisposeResources {
+    const NAME: &'static str = "DisposeResources";
+    const INSTRUCTION: &'static str = "INST - DisposeResources";
+    const COST: u8 = 6;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let mut capability = context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .pop()
+            .expect("dispose capability stack should have at least a capability");
+
+        let value = capability.step(context);
+
+        context.vm.frame_mut().dispose_capabilities.push(capability);
+
+        if let Some(value) = value {
+            context.vm.push(value);
+            context.vm.push(true);
+        } else {
+            context.vm.push(false);
+        }
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `ResumeDisposeResources` implements the Opcode Operation for `Opcode::ResumeDisposeResources`
+///
+/// Operation:
+///  - Record the completion of a value awaited while disposing of the resources of the current scope.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ResumeDisposeResources;
+
+impl Operation for ResumeDisposeResources {
+    const NAME: &'static str = "ResumeDisposeResources";
+    const INSTRUCTION: &'static str = "INST - ResumeDisposeResources";
+    const COST: u8 = 2;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let resume_kind = context.vm.pop().to_generator_resume_kind();
+        let received = context.vm.pop();
+
+        let result = match resume_kind {
+            GeneratorResumeKind::Throw => Err(JsError::from_opaque(received)),
+            GeneratorResumeKind::Normal | GeneratorResumeKind::Return => Ok(()),
+        };
+
+        context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .last_mut()
+            .expect("dispose capability stack should have at least a capability")
+            .resume(result);
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `CaptureDisposeError` implements the Opcode Operation for `Opcode::CaptureDisposeError`
+///
+/// Operation:
+///  - Take the pending exception, if it's set, as the completion of the disposal of the current scope.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CaptureDisposeError;
+
+impl Operation for CaptureDisposeError {
+    const NAME: &'static str = "CaptureDisposeError";
+    const INSTRUCTION: &'static str = "INST - CaptureDisposeError";
+    const COST: u8 = 2;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        // Note: If there is no pending exception, then `return()` was called on the generator,
+        //       which is continued by the `ReThrow` after the disposal.
+        if let Some(error) = context.vm.pending_exception.take() {
+            context
+                .vm
+                .frame_mut()
+                .dispose_capabilities
+                .last_mut()
+                .expect("dispose capability stack should have at least a capability")
+                .record(error);
+        }
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `PopDisposeScope` implements the Opcode Operation for `Opcode::PopDisposeScope`
+///
+/// Operation:
+///  - Pop the dispose capability of the current scope, and throw the error of its disposal if there is one.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PopDisposeScope;
+
+impl Operation for PopDisposeScope {
+    const NAME: &'static str = "PopDisposeScope";
+    const INSTRUCTION: &'static str = "INST - PopDisposeScope";
+    const COST: u8 = 2;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let mut capability = context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .pop()
+            .expect("dispose capability stack should have at least a capability");
+
+        capability.take_completion()?;
+
+        Ok(CompletionType::Normal)
+    }
+}
@@ -14,7 +14,8 @@ impl GetFunction {
     #[allow(clippy::unnecessary_wraps)]
     fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
         let code = context.vm.frame().code_block().constant_function(index);
-        let function = create_function_object_fast(code, context);
+        let script_or_module = context.get_active_script_or_module();
+        let function = create_function_object_fast(code, script_or_module, context);
         context.vm.push(function);
         Ok(CompletionType::Normal)
     }
@@ -1,6 +1,6 @@
 use crate::JsNativeError;
 use crate::{
-    vm::{opcode::Operation, CompletionType},
+    vm::{check_heap_limit, opcode::Operation, CompletionType},
     Context, JsResult,
 };
 
@@ -26,6 +26,8 @@ impl Operation for IncrementLoopIteration {
                 .into());
         }
 
+        check_heap_limit()?;
+
         context.vm.frame_mut().loop_iteration_count = previous_iteration_count.wrapping_add(1);
         Ok(CompletionType::Normal)
     }
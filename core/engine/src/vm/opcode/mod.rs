@@ -13,6 +13,7 @@ mod control_flow;
 mod copy;
 mod define;
 mod delete;
+mod dispose;
 mod dup;
 mod environment;
 mod generator;
@@ -52,6 +53,8 @@ pub(crate) use define::*;
 #[doc(inline)]
 pub(crate) use delete::*;
 #[doc(inline)]
+pub(crate) use dispose::*;
+#[doc(inline)]
 pub(crate) use dup::*;
 #[doc(inline)]
 pub(crate) use environment::*;
@@ -1450,6 +1453,47 @@ generate_opcodes! {
     /// Stack: class, method **=>**
     PushClassPrivateMethod { index: VaryingOperand },
 
+    /// Push the decorators of a class, and start recording the elements of the class.
+    ///
+    /// Classes with decorators or auto-accessors record their elements with `PushClassElement`,
+    /// instead of defining them while they are evaluated.
+    ///
+    /// Operands: `decorator_count`: `VaryingOperand`
+    ///
+    /// Stack: `decorator_0` ... `decorator_n`, `class_proto`, class **=>** `class_proto`, class
+    PushClassDecorators { decorator_count: VaryingOperand },
+
+    /// Push an element to a class with decorators or auto-accessors.
+    ///
+    /// The kind operand is mapped as follows:
+    /// * 0 -> method
+    /// * 1 -> getter
+    /// * 2 -> setter
+    /// * 3 -> field
+    /// * 4 -> auto-accessor
+    /// * 5 -> static block
+    ///
+    /// The key of a private element is the description of its private name.
+    ///
+    /// Operands: kind: `u8`, `is_static`: `bool`, `is_private`: `bool`, `decorator_count`: `VaryingOperand`
+    ///
+    /// Stack: `class_proto`, class, `decorator_0` ... `decorator_n`, key, function **=>** `class_proto`, class
+    PushClassElement { kind: u8, is_static: bool, is_private: bool, decorator_count: VaryingOperand },
+
+    /// Apply the decorators of a class and its elements, and define the elements of the class.
+    ///
+    /// Operands:
+    ///
+    /// Stack: `class_proto`, class **=>** class, `decorated_class`
+    ApplyClassDecorators,
+
+    /// Run the static initializers of a class with decorators or auto-accessors.
+    ///
+    /// Operands:
+    ///
+    /// Stack: class, `decorated_class` **=>** `decorated_class`
+    InitializeDecoratedClass,
+
     /// Deletes a property by name of an object.
     ///
     /// Like `delete object.key`
@@ -1590,6 +1634,54 @@ generate_opcodes! {
     /// Stack: **=>**
     ThrowNewTypeError { message: VaryingOperand },
 
+    /// Push a new dispose capability for the `using` declarations of a scope.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    PushDisposeScope,
+
+    /// Add a resource to the dispose capability of the current scope.
+    ///
+    /// Performs [`AddDisposableResource ( disposeCapability, V, hint )`][spec] with the
+    /// `async-dispose` hint if `is_async` is `true`, or the `sync-dispose` hint otherwise.
+    ///
+    /// Operands:
+    /// - `is_async`: bool (codified as u8 with `0` -> `false` and `!0` -> `true`)
+    ///
+    /// Stack: value **=>**
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    AddDisposableResource { is_async: bool },
+
+    /// Dispose of the resources of the current scope until a value needs to be awaited.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>** (`true`, value) or `false`
+    DisposeResources,
+
+    /// Record the completion of a value awaited while disposing of the resources of the current scope.
+    ///
+    /// Operands:
+    ///
+    /// Stack: received, `resume_kind` **=>**
+    ResumeDisposeResources,
+
+    /// Take the pending exception, if it's set, as the completion of the disposal of the current scope.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    CaptureDisposeError,
+
+    /// Pop the dispose capability of the current scope, and throw the error of its disposal if there is one.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    PopDisposeScope,
+
     /// Pops value converts it to boolean and pushes it back.
     ///
     /// Operands:
@@ -1654,7 +1746,7 @@ generate_opcodes! {
     ///
     /// Operands:
     ///
-    /// Stack: specifier **=>** promise
+    /// Stack: specifier, options **=>** promise
     ImportCall,
 
     /// Pop the two values of the stack, strict equal compares the two values,
@@ -2202,26 +2294,6 @@ generate_opcodes! {
     Reserved47 => Reserved,
     /// Reserved [`Opcode`].
     Reserved48 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved49 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved50 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved51 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved52 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved53 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved54 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved55 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved56 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved57 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved58 => Reserved,
 }
 
 /// Specific opcodes for bindings.
@@ -1,7 +1,7 @@
 use crate::{
     builtins::Array,
     string::utf16,
-    vm::{opcode::Operation, CompletionType},
+    vm::{check_external_allocation, opcode::Operation, CompletionType},
     Context, JsResult, JsValue,
 };
 
@@ -47,6 +47,7 @@ impl Operation for PushValueToArray {
         let len = o
             .length_of_array_like(context)
             .expect("should have 'length' property");
+        check_external_allocation(o.borrow().properties().indexed_growth(&len.into()))?;
         o.create_data_property_or_throw(len, value, context)
             .expect("should be able to create new data property");
         context.vm.push(array);
@@ -0,0 +1,225 @@
+use crate::{
+    builtins::function::{
+        decorators::{
+            apply_class_decorators, initialize_decorated_class, ClassDecorations,
+            ClassElementDefinition, ClassElementKind, ClassElementName,
+        },
+        OrdinaryFunction,
+    },
+    js_string,
+    vm::{opcode::Operation, CompletionType},
+    Context, JsResult,
+};
+
+/// `PushClassDecorators` implements the Opcode Operation for `Opcode::PushClassDecorators`
+///
+/// Operation:
+///  - Push the decorators of a class and start recording its elements.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushClassDecorators;
+
+impl PushClassDecorators {
+    fn operation(context: &mut Context, decorator_count: usize) -> JsResult<CompletionType> {
+        let class = context.vm.pop();
+        let prototype = context.vm.pop();
+        let decorators = context.vm.pop_n_values(decorator_count);
+
+        let class_object = class.as_object().expect("class must be function object");
+        let prototype_object = prototype
+            .as_object()
+            .expect("class prototype must be an object");
+
+        let decorations =
+            ClassDecorations::new(decorators, class_object, prototype_object, context)?;
+        class_object
+            .downcast_mut::<OrdinaryFunction>()
+            .expect("class must be function object")
+            .class_decorations = Some(Box::new(decorations));
+
+        context.vm.push(prototype);
+        context.vm.push(class);
+        Ok(CompletionType::Normal)
+    }
+}
+
+impl Operation for PushClassDecorators {
+    const NAME: &'static str = "PushClassDecorators";
+    const INSTRUCTION: &'static str = "INST - PushClassDecorators";
+    const COST: u8 = 4;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let decorator_count = context.vm.read::<u8>() as usize;
+        Self::operation(context, decorator_count)
+    }
+
+    fn execute_with_u16_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let decorator_count = context.vm.read::<u16>() as usize;
+        Self::operation(context, decorator_count)
+    }
+
+    fn execute_with_u32_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let decorator_count = context.vm.read::<u32>() as usize;
+        Self::operation(context, decorator_count)
+    }
+}
+
+/// `PushClassElement` implements the Opcode Operation for `Opcode::PushClassElement`
+///
+/// Operation:
+///  - Push an element to a class with decorators or auto-accessors.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushClassElement;
+
+impl PushClassElement {
+    fn operation(
+        context: &mut Context,
+        kind: u8,
+        is_static: bool,
+        is_private: bool,
+        decorator_count: usize,
+    ) -> JsResult<CompletionType> {
+        let kind = ClassElementKind::try_from(kind).expect("invalid class element kind");
+        let function = context.vm.pop();
+        let key = context.vm.pop();
+        let decorators = context.vm.pop_n_values(decorator_count);
+
+        let class = context.vm.stack[context.vm.stack.len() - 1].clone();
+        let prototype = context.vm.stack[context.vm.stack.len() - 2].clone();
+        let class = class.as_object().expect("class must be function object");
+        let prototype = prototype
+            .as_object()
+            .expect("class prototype must be an object");
+
+        let name = if is_private {
+            let description = key.as_string().expect("private name must be a string");
+            ClassElementName::PrivateName(class.private_name(description.clone()))
+        } else if kind == ClassElementKind::StaticBlock {
+            ClassElementName::PropertyKey(js_string!().into())
+        } else {
+            ClassElementName::PropertyKey(key.to_property_key(context)?)
+        };
+
+        let home_object = if is_static { class } else { prototype };
+
+        // The backing storage of an auto-accessor is named after the element index, to make it
+        // unique in the class. It is not a valid private identifier, so it cannot be accessed
+        // from the class body.
+        let index = class
+            .downcast_ref::<OrdinaryFunction>()
+            .expect("class must be function object")
+            .class_decorations
+            .as_ref()
+            .expect("class must have a decoration state")
+            .element_count();
+        let storage = class.private_name(js_string!(format!("accessor storage {index}")));
+
+        let element = ClassElementDefinition::new(
+            kind,
+            is_static,
+            name,
+            function
+                .as_object()
+                .expect("class element must be function object")
+                .clone(),
+            decorators,
+            home_object,
+            storage,
+            context,
+        );
+
+        class
+            .downcast_mut::<OrdinaryFunction>()
+            .expect("class must be function object")
+            .class_decorations
+            .as_mut()
+            .expect("class must have a decoration state")
+            .push_element(element);
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+impl Operation for PushClassElement {
+    const NAME: &'static str = "PushClassElement";
+    const INSTRUCTION: &'static str = "INST - PushClassElement";
+    const COST: u8 = 6;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let kind = context.vm.read::<u8>();
+        let is_static = context.vm.read::<u8>() != 0;
+        let is_private = context.vm.read::<u8>() != 0;
+        let decorator_count = context.vm.read::<u8>() as usize;
+        Self::operation(context, kind, is_static, is_private, decorator_count)
+    }
+
+    fn execute_with_u16_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let kind = context.vm.read::<u8>();
+        let is_static = context.vm.read::<u8>() != 0;
+        let is_private = context.vm.read::<u8>() != 0;
+        let decorator_count = context.vm.read::<u16>() as usize;
+        Self::operation(context, kind, is_static, is_private, decorator_count)
+    }
+
+    fn execute_with_u32_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let kind = context.vm.read::<u8>();
+        let is_static = context.vm.read::<u8>() != 0;
+        let is_private = context.vm.read::<u8>() != 0;
+        let decorator_count = context.vm.read::<u32>() as usize;
+        Self::operation(context, kind, is_static, is_private, decorator_count)
+    }
+}
+
+/// `ApplyClassDecorators` implements the Opcode Operation for `Opcode::ApplyClassDecorators`
+///
+/// Operation:
+///  - Apply the decorators of a class and its elements, and define its elements.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ApplyClassDecorators;
+
+impl Operation for ApplyClassDecorators {
+    const NAME: &'static str = "ApplyClassDecorators";
+    const INSTRUCTION: &'static str = "INST - ApplyClassDecorators";
+    const COST: u8 = 8;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let class = context.vm.pop();
+        let prototype = context.vm.pop();
+        let class_object = class.as_object().expect("class must be function object");
+        let prototype_object = prototype
+            .as_object()
+            .expect("class prototype must be an object");
+
+        let new_class = apply_class_decorators(class_object, prototype_object, context)?;
+
+        context.vm.push(class);
+        context.vm.push(new_class);
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `InitializeDecoratedClass` implements the Opcode Operation for `Opcode::InitializeDecoratedClass`
+///
+/// Operation:
+///  - Run the static initializers of a class with decorators or auto-accessors.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct InitializeDecoratedClass;
+
+impl Operation for InitializeDecoratedClass {
+    const NAME: &'static str = "InitializeDecoratedClass";
+    const INSTRUCTION: &'static str = "INST - InitializeDecoratedClass";
+    const COST: u8 = 8;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let new_class = context.vm.pop();
+        let class = context.vm.pop();
+        let class_object = class.as_object().expect("class must be function object");
+        let new_class_object = new_class
+            .as_object()
+            .expect("decorated class must be an object");
+
+        initialize_decorated_class(class_object, new_class_object, context)?;
+
+        context.vm.push(new_class);
+        Ok(CompletionType::Normal)
+    }
+}
@@ -5,9 +5,11 @@ use crate::{
     Context, JsResult, JsValue,
 };
 
+pub(crate) mod decorator;
 pub(crate) mod field;
 pub(crate) mod private;
 
+pub(crate) use decorator::*;
 pub(crate) use field::*;
 pub(crate) use private::*;
 
@@ -165,6 +165,12 @@ impl Operation for SetPropertyByValue {
                                 break 'fast_path;
                             }
 
+                            // Growing the elements must be checked against the maximum heap size
+                            // by the slow path.
+                            if dense_elements.len() == dense_elements.capacity() {
+                                break 'fast_path;
+                            }
+
                             dense_elements.push(value.clone());
                             context.vm.push(value);
 
@@ -1,6 +1,13 @@
 use crate::{
-    js_string, property::Attribute, run_test_actions, Context, JsNativeErrorKind, JsValue,
-    TestAction,
+    context::{GcConfig, GcHeap},
+    js_string,
+    object::{
+        builtins::{JsArray, JsPromise},
+        FunctionObjectBuilder, ObjectInitializer,
+    },
+    property::Attribute,
+    run_test_actions, run_test_actions_with, Context, JsNativeErrorKind, JsObject, JsValue,
+    NativeFunction, TestAction,
 };
 use boa_parser::Source;
 use indoc::indoc;
@@ -314,6 +321,175 @@ fn recursion_runtime_limit() {
     ]);
 }
 
+#[test]
+fn heap_runtime_limit() {
+    let mut gc_config = GcConfig::default();
+    gc_config.set_max_heap_size(Some(32 * 1024 * 1024));
+    let context = &mut Context::builder().gc_config(gc_config).build().unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_native_error(
+                indoc! {r#"
+                    var objects = [];
+                    while (true) {
+                        objects.push({});
+                    }
+                "#},
+                JsNativeErrorKind::RuntimeLimit,
+                "exceeded maximum heap size",
+            ),
+            TestAction::run("objects = undefined"),
+            TestAction::assert_eq("[1, 2, 3].map((x) => ({ x })).length", 3),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn heap_runtime_limit_in_native_function() {
+    let mut gc_config = GcConfig::default();
+    gc_config.set_max_heap_size(Some(4 * 1024 * 1024));
+    let context = &mut Context::builder().gc_config(gc_config).build().unwrap();
+
+    run_test_actions_with(
+        [TestAction::assert_native_error(
+            r#"JSON.parse("[" + "{},".repeat(100000) + "{}]")"#,
+            JsNativeErrorKind::RuntimeLimit,
+            "exceeded maximum heap size",
+        )],
+        context,
+    );
+}
+
+#[test]
+fn heap_runtime_limit_on_string_growth() {
+    let mut gc_config = GcConfig::default();
+    gc_config.set_max_heap_size(Some(32 * 1024 * 1024));
+    let context = &mut Context::builder().gc_config(gc_config).build().unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_native_error(
+                indoc! {r#"
+                    let s = "x";
+                    for (;;) s += s;
+                "#},
+                JsNativeErrorKind::RuntimeLimit,
+                "exceeded maximum heap size",
+            ),
+            TestAction::assert_native_error(
+                "`${'x'.repeat(1024 * 1024)}`.repeat(1024)",
+                JsNativeErrorKind::RuntimeLimit,
+                "exceeded maximum heap size",
+            ),
+            TestAction::assert_eq("'x'.repeat(1024).length", 1024),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn heap_runtime_limit_on_array_growth() {
+    let mut gc_config = GcConfig::default();
+    gc_config.set_max_heap_size(Some(32 * 1024 * 1024));
+    let context = &mut Context::builder().gc_config(gc_config).build().unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_native_error(
+                "new Array(2 ** 31).fill(0)",
+                JsNativeErrorKind::RuntimeLimit,
+                "exceeded maximum heap size",
+            ),
+            TestAction::assert_native_error(
+                indoc! {r#"
+                    const array = [];
+                    for (let i = 0; ; i++) array[i] = i;
+                "#},
+                JsNativeErrorKind::RuntimeLimit,
+                "exceeded maximum heap size",
+            ),
+            TestAction::assert_native_error(
+                "[...new Array(2 ** 31).keys()]",
+                JsNativeErrorKind::RuntimeLimit,
+                "exceeded maximum heap size",
+            ),
+            TestAction::assert_native_error(
+                "new ArrayBuffer(64 * 1024 * 1024)",
+                JsNativeErrorKind::RuntimeLimit,
+                "exceeded maximum heap size",
+            ),
+            TestAction::assert_eq("new Array(1024).fill(0).length", 1024),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn context_heaps_are_isolated() {
+    let mut first = Context::builder()
+        .heap(GcHeap::new(GcConfig::default()))
+        .build()
+        .unwrap();
+    let second = Context::builder()
+        .heap(GcHeap::new(GcConfig::default()))
+        .build()
+        .unwrap();
+    let default_objects = GcHeap::current().stats().objects;
+    let second_objects = second.heap().stats().objects;
+
+    first
+        .eval(Source::from_bytes(
+            "var objects = []; for (let i = 0; i < 1000; i++) { objects.push({}); }",
+        ))
+        .unwrap();
+    assert!(first.heap().stats().objects > 1000);
+    assert_eq!(second.heap().stats().objects, second_objects);
+    assert_eq!(GcHeap::current().stats().objects, default_objects);
+
+    // Dropping the context frees its whole heap.
+    drop(first);
+    assert_eq!(GcHeap::current().stats().objects, default_objects);
+    assert_eq!(second.heap().stats().objects, second_objects);
+}
+
+#[test]
+fn context_heap_holds_embedder_objects() {
+    let mut context = Context::builder()
+        .heap(GcHeap::new(GcConfig::default()))
+        .build()
+        .unwrap();
+    let default_objects = GcHeap::current().stats().objects;
+    let context_objects = context.heap().stats().objects;
+
+    let object = JsObject::with_object_proto(context.intrinsics());
+    let array = JsArray::new(&mut context);
+    let (promise, _) = JsPromise::new_pending(&mut context);
+    let function = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::undefined())),
+    )
+    .build();
+    let initialized = ObjectInitializer::new(&mut context)
+        .property(js_string!("object"), object, Attribute::all())
+        .property(js_string!("array"), array, Attribute::all())
+        .property(js_string!("promise"), promise, Attribute::all())
+        .property(js_string!("function"), function, Attribute::all())
+        .build();
+    context
+        .register_global_property(js_string!("initialized"), initialized, Attribute::all())
+        .unwrap();
+
+    assert!(context.heap().stats().objects > context_objects);
+    assert_eq!(GcHeap::current().stats().objects, default_objects);
+
+    // Dropping the context frees the objects along with its heap.
+    drop(context);
+    GcHeap::current().collect();
+    assert_eq!(GcHeap::current().stats().objects, default_objects);
+}
+
 #[test]
 fn arguments_object_constructor_valid_index() {
     run_test_actions([TestAction::assert_eq(
@@ -0,0 +1,364 @@
+//! Explicit garbage collected heaps.
+//!
+//! Every thread has a default heap, which is used unless another heap is entered with
+//! [`GcHeap::enter`]. Values are allocated in the heap that is current when they are created, and
+//! each heap keeps its own configuration, collection threshold and statistics.
+//!
+//! Every value records the address of its heap, so a heap must outlive all of its values. When
+//! a heap is dropped, the values that survive its last collection are moved to the default heap.
+//! If the default heap is busy or already gone, they are moved to an orphaned heap instead, which
+//! is kept alive by the thread until the default heap can adopt it.
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt, mem, ptr,
+    rc::Rc,
+};
+
+use crate::{
+    internals::GcHeader, BoaGc, CollectionKind, Collector, Gc, GcConfig, GcStats, HeapSnapshot,
+    Trace,
+};
+
+thread_local!(static DEFAULT_HEAP: GcHeap = GcHeap::with_kind(GcConfig::default(), true));
+thread_local!(static CURRENT_HEAP: RefCell<GcHeap> = RefCell::new(DEFAULT_HEAP.with(GcHeap::clone)));
+thread_local!(static COLLECTING: Cell<Collecting> = const { Cell::new((ptr::null(), CollectionKind::Major)) });
+thread_local!(static ORPHANS: RefCell<Vec<Rc<HeapInner>>> = const { RefCell::new(Vec::new()) });
+
+/// The heap whose non-root references are being counted, and the kind of the collection.
+pub(crate) type Collecting = (*const HeapInner, CollectionKind);
+
+/// The shared state of a [`GcHeap`].
+///
+/// Its address identifies the heap, and is stored in the header of every value allocated in it.
+pub(crate) struct HeapInner {
+    pub(crate) gc: RefCell<BoaGc>,
+    is_default: bool,
+}
+
+impl HeapInner {
+    /// Moves all the values of `other` into this heap, returning `false` if the heap is busy.
+    fn adopt(&self, other: &mut BoaGc) -> bool {
+        let Ok(mut gc) = self.gc.try_borrow_mut() else {
+            return false;
+        };
+        move_values(other, &mut gc);
+        true
+    }
+}
+
+impl Drop for HeapInner {
+    fn drop(&mut self) {
+        // The default heap frees everything when the thread exits.
+        if self.is_default {
+            return;
+        }
+
+        let gc = self.gc.get_mut();
+        if is_empty(gc) {
+            return;
+        }
+
+        Collector::collect(gc, CollectionKind::Major);
+        if is_empty(gc) {
+            return;
+        }
+
+        // The values that survived are still referenced from outside of the heap, so they are
+        // kept alive by the default heap.
+        if DEFAULT_HEAP
+            .try_with(|heap| heap.inner.adopt(gc))
+            .unwrap_or(false)
+        {
+            return;
+        }
+
+        // The default heap is collecting or the thread is exiting, so the values are moved to
+        // a new heap that the default heap adopts on its next collection.
+        let orphan = GcHeap::new(gc.config);
+        move_values(gc, &mut orphan.inner.gc.borrow_mut());
+        let kept = ORPHANS.try_with(|orphans| orphans.borrow_mut().push(orphan.inner.clone()));
+        if kept.is_err() {
+            // The thread is exiting, so the values can only be leaked along with their heap.
+            mem::forget(orphan);
+        }
+    }
+}
+
+/// Returns `true` if the heap doesn't have any value.
+fn is_empty(gc: &BoaGc) -> bool {
+    gc.strongs.is_empty() && gc.young.is_empty() && gc.weaks.is_empty() && gc.weak_maps.is_empty()
+}
+
+/// Moves all the values of `from` into `to`.
+fn move_values(from: &mut BoaGc, to: &mut BoaGc) {
+    from.promote_young();
+    for node in from.strongs.drain(..) {
+        // SAFETY: nodes are always valid outside of a collection.
+        let node_ref = unsafe { node.as_ref() };
+        node_ref.header.set_heap(to.id);
+        to.runtime.bytes_allocated += node_ref.size();
+        to.strongs.push(node);
+    }
+    for eph in from.weaks.drain(..) {
+        // SAFETY: ephemerons are always valid outside of a collection.
+        let eph_ref = unsafe { eph.as_ref() };
+        eph_ref.header().set_heap(to.id);
+        to.runtime.bytes_allocated += size_of_val(eph_ref);
+        to.weaks.push(eph);
+    }
+    to.weak_maps.append(&mut from.weak_maps);
+    from.runtime.bytes_allocated = 0;
+}
+
+/// Moves the values of the orphaned heaps of the thread to `gc`, if it is the default heap.
+pub(crate) fn adopt_orphans(gc: &mut BoaGc) {
+    let is_default = DEFAULT_HEAP
+        .try_with(|heap| ptr::eq(Rc::as_ptr(&heap.inner), gc.id))
+        .unwrap_or(false);
+    if !is_default {
+        return;
+    }
+
+    let Ok(orphans) = ORPHANS.try_with(|orphans| mem::take(&mut *orphans.borrow_mut())) else {
+        return;
+    };
+    for orphan in orphans {
+        // An orphaned heap can only be busy if it is being collected, in which case it is
+        // adopted on a later collection.
+        if let Ok(mut orphan_gc) = orphan.gc.try_borrow_mut() {
+            move_values(&mut orphan_gc, gc);
+        } else {
+            ORPHANS.with(|orphans| orphans.borrow_mut().push(orphan.clone()));
+        }
+    }
+}
+
+/// A garbage collected heap.
+///
+/// A heap can be shared by cloning its handle, and it is freed when the last handle is dropped.
+/// At that point the heap runs a last collection, and the values that are still referenced from
+/// outside of the heap are moved to the default heap of the thread, instead of being freed.
+///
+/// Values can reference values of other heaps, but those references act as roots: the referenced
+/// values are only freed once the referencing value is freed, and cycles between heaps are only
+/// collected after one of the heaps is dropped.
+#[derive(Clone)]
+pub struct GcHeap {
+    pub(crate) inner: Rc<HeapInner>,
+}
+
+impl GcHeap {
+    /// Creates a new empty heap with the given configuration.
+    #[must_use]
+    pub fn new(config: GcConfig) -> Self {
+        Self::with_kind(config, false)
+    }
+
+    fn with_kind(config: GcConfig, is_default: bool) -> Self {
+        let inner = Rc::new(HeapInner {
+            gc: RefCell::new(BoaGc::new(config)),
+            is_default,
+        });
+        inner.gc.borrow_mut().id = Rc::as_ptr(&inner);
+        Self { inner }
+    }
+
+    /// Returns the heap where new values are allocated on the current thread.
+    #[must_use]
+    pub fn current() -> Self {
+        CURRENT_HEAP.with(|current| current.borrow().clone())
+    }
+
+    /// Returns the default heap of the current thread.
+    #[must_use]
+    pub fn thread_default() -> Self {
+        DEFAULT_HEAP.with(Self::clone)
+    }
+
+    /// Returns `true` if both handles refer to the same heap.
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Rc::ptr_eq(&this.inner, &other.inner)
+    }
+
+    /// Makes this heap the current heap of the thread until the returned guard is dropped.
+    pub fn enter(&self) -> HeapGuard {
+        let previous = CURRENT_HEAP.with(|current| current.replace(self.clone()));
+        HeapGuard { previous }
+    }
+
+    /// Makes the heap that owns `value` the current heap of the thread until the returned guard
+    /// is dropped, or returns `None` if it already is the current heap.
+    ///
+    /// This is useful to allocate values next to the values they belong to, e.g. in the heap of
+    /// a context when the context didn't enter it.
+    #[must_use]
+    pub fn enter_heap_of<T: Trace + ?Sized>(value: &Gc<T>) -> Option<HeapGuard> {
+        // SAFETY: `value` is alive, so its header is valid.
+        let heap = unsafe { value.inner_ptr().as_ref() }.header.heap();
+        if CURRENT_HEAP.with(|current| ptr::eq(Rc::as_ptr(&current.borrow().inner), heap)) {
+            return None;
+        }
+
+        // SAFETY: A heap outlives all of its values (see `HeapInner::drop`), and every heap is
+        // owned by an `Rc`, so a new handle can be created from its address.
+        let inner = unsafe {
+            Rc::increment_strong_count(heap);
+            Rc::from_raw(heap)
+        };
+        Some(Self { inner }.enter())
+    }
+
+    /// Forcefully runs a major garbage collection on this heap.
+    pub fn collect(&self) {
+        let mut gc = self.inner.gc.borrow_mut();
+        adopt_orphans(&mut gc);
+
+        if gc.runtime.bytes_allocated > 0 {
+            Collector::collect(&mut gc, CollectionKind::Major);
+        }
+    }
+
+    /// Forcefully runs a minor garbage collection on this heap, which only collects the values
+    /// of the nursery.
+    ///
+    /// See [`GcConfig::set_nursery_size`].
+    pub fn collect_nursery(&self) {
+        let mut gc = self.inner.gc.borrow_mut();
+
+        if !gc.young.is_empty() {
+            Collector::collect(&mut gc, CollectionKind::Minor);
+        }
+    }
+
+    /// Returns the configuration of this heap.
+    #[must_use]
+    pub fn config(&self) -> GcConfig {
+        self.inner.gc.borrow().config
+    }
+
+    /// Sets the configuration of this heap.
+    ///
+    /// This also resets the collection threshold to the initial threshold of `config`.
+    pub fn set_config(&self, config: GcConfig) {
+        let mut gc = self.inner.gc.borrow_mut();
+        gc.config = config;
+        gc.runtime.threshold = config.threshold;
+    }
+
+    /// Returns `true` if an allocation went over the maximum size of this heap, resetting the
+    /// notification.
+    ///
+    /// See [`GcConfig::set_max_heap_size`].
+    #[must_use]
+    pub fn take_heap_limit_exceeded(&self) -> bool {
+        mem::take(&mut self.inner.gc.borrow_mut().runtime.heap_limit_exceeded)
+    }
+
+    /// Returns `true` if `bytes` of memory can be allocated outside of this heap, for a value of
+    /// this heap, without going over its maximum size.
+    ///
+    /// The heap doesn't track the memory that its values own outside of it (e.g. the buffer of a
+    /// [`Vec`]), so growing that memory should be checked with this first. If the allocation
+    /// doesn't fit, this runs a major collection to make room for it.
+    ///
+    /// See [`GcConfig::set_max_heap_size`].
+    #[must_use]
+    pub fn can_allocate_external(&self, bytes: usize) -> bool {
+        let mut gc = self.inner.gc.borrow_mut();
+        let Some(max_heap_size) = gc.config.max_heap_size else {
+            return true;
+        };
+
+        if gc.runtime.bytes_allocated.saturating_add(bytes) <= max_heap_size {
+            return true;
+        }
+
+        Collector::collect(&mut gc, CollectionKind::Major);
+
+        gc.runtime.bytes_allocated.saturating_add(bytes) <= max_heap_size
+    }
+
+    /// Returns the statistics of this heap.
+    ///
+    /// This walks the whole heap to compute the statistics per type, so it should not be called
+    /// too often.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from a collection hook.
+    #[must_use]
+    pub fn stats(&self) -> GcStats {
+        crate::stats::heap_stats(&self.inner.gc.borrow())
+    }
+
+    /// Takes a snapshot of the values of this heap that are reachable from a root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from a collection hook.
+    #[must_use]
+    pub fn snapshot(&self) -> HeapSnapshot {
+        crate::snapshot::snapshot_heap(&self.inner.gc.borrow())
+    }
+}
+
+impl fmt::Debug for GcHeap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GcHeap")
+            .field("address", &Rc::as_ptr(&self.inner))
+            .field("is_default", &self.inner.is_default)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Restores the previous current heap of the thread when dropped.
+///
+/// Created by [`GcHeap::enter`].
+#[must_use = "the heap is only current until the guard is dropped"]
+pub struct HeapGuard {
+    previous: GcHeap,
+}
+
+impl fmt::Debug for HeapGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HeapGuard")
+            .field("previous", &self.previous)
+            .finish()
+    }
+}
+
+impl Drop for HeapGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.clone();
+        // The thread could be exiting, in which case there is nothing to restore.
+        if let Ok(entered) = CURRENT_HEAP.try_with(|current| current.replace(previous)) {
+            // This can free the entered heap, if it was its last handle.
+            drop(entered);
+        }
+    }
+}
+
+/// Runs `f` with the state of the current heap of the thread.
+pub(crate) fn with_current_heap<R>(f: impl FnOnce(&HeapInner) -> R) -> R {
+    let heap = CURRENT_HEAP.with(|current| current.borrow().inner.clone());
+    f(&heap)
+}
+
+/// Sets the values whose non-root references are being counted, returning the previous ones.
+pub(crate) fn set_collecting(collecting: Collecting) -> Collecting {
+    COLLECTING.with(|current| current.replace(collecting))
+}
+
+/// Returns `true` if the non-root references of the value with the given header are being
+/// counted.
+///
+/// References to values of other heaps are not counted, which makes them roots. Neither are
+/// references to old values while collecting the nursery.
+pub(crate) fn is_collecting(header: &GcHeader) -> bool {
+    COLLECTING.with(|current| {
+        let (heap, kind) = current.get();
+        ptr::eq(heap, header.heap()) && (kind == CollectionKind::Major || header.is_young())
+    })
+}
@@ -79,6 +79,10 @@ impl<T: Trace + ?Sized> GcBox<T> {
         self.vtable.drop_fn()
     }
 
+    pub(crate) fn type_name(&self) -> &'static str {
+        self.vtable.type_name()
+    }
+
     pub(crate) fn size(&self) -> usize {
         self.vtable.size()
     }
@@ -1,20 +1,29 @@
-use std::{cell::Cell, fmt};
+use std::{cell::Cell, fmt, ptr};
+
+use crate::heap::{is_collecting, HeapInner};
 
 const MARK_MASK: u32 = 1 << (u32::BITS - 1);
-const NON_ROOTS_MASK: u32 = !MARK_MASK;
+const YOUNG_MASK: u32 = 1 << (u32::BITS - 2);
+const EPHEMERON_KEY_MASK: u32 = 1 << (u32::BITS - 3);
+const NON_ROOTS_MASK: u32 = !(MARK_MASK | YOUNG_MASK | EPHEMERON_KEY_MASK);
 const NON_ROOTS_MAX: u32 = NON_ROOTS_MASK;
 
 /// The `Gcheader` contains the `GcBox`'s and `EphemeronBox`'s current state for the `Collector`'s
 /// Mark/Sweep as well as a pointer to the next node in the heap.
 ///
 /// `ref_count` is the number of Gc instances, and `non_root_count` is the number of
-/// Gc instances in the heap. `non_root_count` also includes Mark Flag bit.
+/// Gc instances in the heap. `non_root_count` also includes the Mark Flag bit, the Young Flag bit
+/// of values in the nursery, and the Ephemeron Key Flag bit of values that are the key of an
+/// ephemeron.
+///
+/// `heap` is the heap that owns the allocation, which is set by the `Allocator`.
 ///
 /// The next node is set by the `Allocator` during initialization and by the
 /// `Collector` during the sweep phase.
 pub(crate) struct GcHeader {
     ref_count: Cell<u32>,
     non_root_count: Cell<u32>,
+    heap: Cell<*const HeapInner>,
 }
 
 impl GcHeader {
@@ -23,6 +32,7 @@ impl GcHeader {
         Self {
             ref_count: Cell::new(1),
             non_root_count: Cell::new(0),
+            heap: Cell::new(ptr::null()),
         }
     }
 
@@ -36,8 +46,25 @@ impl GcHeader {
         self.non_root_count.get() & NON_ROOTS_MASK
     }
 
+    /// Returns the heap that owns the allocation.
+    pub(crate) fn heap(&self) -> *const HeapInner {
+        self.heap.get()
+    }
+
+    /// Sets the heap that owns the allocation.
+    pub(crate) fn set_heap(&self, heap: *const HeapInner) {
+        self.heap.set(heap);
+    }
+
     /// Increments [`GcHeader`]'s non-roots count.
+    ///
+    /// References from values of other heaps are not counted, since they are roots for the heap
+    /// being collected.
     pub(crate) fn inc_non_root_count(&self) {
+        if !is_collecting(self) {
+            return;
+        }
+
         let non_root_count = self.non_root_count.get();
 
         if (non_root_count & NON_ROOTS_MASK) < NON_ROOTS_MAX {
@@ -91,12 +118,42 @@ impl GcHeader {
         self.non_root_count
             .set(self.non_root_count.get() & !MARK_MASK);
     }
+
+    /// Returns a bool for whether the value is in the nursery.
+    pub(crate) fn is_young(&self) -> bool {
+        self.non_root_count.get() & YOUNG_MASK != 0
+    }
+
+    /// Sets whether the value is in the nursery.
+    pub(crate) fn set_young(&self, young: bool) {
+        let non_root_count = self.non_root_count.get();
+        self.non_root_count.set(if young {
+            non_root_count | YOUNG_MASK
+        } else {
+            non_root_count & !YOUNG_MASK
+        });
+    }
+
+    /// Returns a bool for whether the value is the key of an ephemeron.
+    ///
+    /// Minor collections keep these values alive, since only major collections clear
+    /// ephemerons.
+    pub(crate) fn is_ephemeron_key(&self) -> bool {
+        self.non_root_count.get() & EPHEMERON_KEY_MASK != 0
+    }
+
+    /// Flags the value as the key of an ephemeron.
+    pub(crate) fn set_ephemeron_key(&self) {
+        self.non_root_count
+            .set(self.non_root_count.get() | EPHEMERON_KEY_MASK);
+    }
 }
 
 impl fmt::Debug for GcHeader {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("GcHeader")
             .field("marked", &self.is_marked())
+            .field("young", &self.is_young())
             .field("ref_count", &self.ref_count.get())
             .field("non_root_count", &self.non_root_count())
             .finish_non_exhaustive()
@@ -48,6 +48,7 @@ pub(crate) const fn vtable_of<T: Trace + 'static>() -> &'static VTable {
             trace_non_roots_fn: T::trace_non_roots_fn,
             run_finalizer_fn: T::run_finalizer_fn,
             drop_fn: T::drop_fn,
+            type_name_fn: std::any::type_name::<T>,
             size: std::mem::size_of::<GcBox<T>>(),
         };
     }
@@ -59,6 +60,7 @@ pub(crate) type TraceFn = unsafe fn(this: GcErasedPointer, tracer: &mut Tracer);
 pub(crate) type TraceNonRootsFn = unsafe fn(this: GcErasedPointer);
 pub(crate) type RunFinalizerFn = unsafe fn(this: GcErasedPointer);
 pub(crate) type DropFn = unsafe fn(this: GcErasedPointer);
+pub(crate) type TypeNameFn = fn() -> &'static str;
 
 #[derive(Debug)]
 pub(crate) struct VTable {
@@ -66,6 +68,7 @@ pub(crate) struct VTable {
     trace_non_roots_fn: TraceNonRootsFn,
     run_finalizer_fn: RunFinalizerFn,
     drop_fn: DropFn,
+    type_name_fn: TypeNameFn,
     size: usize,
 }
 
@@ -86,6 +89,10 @@ impl VTable {
         self.drop_fn
     }
 
+    pub(crate) fn type_name(&self) -> &'static str {
+        (self.type_name_fn)()
+    }
+
     pub(crate) fn size(&self) -> usize {
         self.size
     }
@@ -18,72 +18,221 @@
 extern crate self as boa_gc;
 
 mod cell;
+mod heap;
 mod pointers;
+mod snapshot;
+mod stats;
 mod trace;
 
 pub(crate) mod internals;
 
 use boa_profiler::Profiler;
+use heap::{with_current_heap, HeapInner};
 use internals::{EphemeronBox, ErasedEphemeronBox, ErasedWeakMapBox, WeakMapBox};
 use pointers::{NonTraceable, RawWeakMap};
-use std::{
-    cell::{Cell, RefCell},
-    mem,
-    ptr::NonNull,
-};
+use stats::Instant;
+use std::{cell::Cell, mem, ptr, ptr::NonNull, time::Duration};
 
 pub use crate::trace::{Finalize, Trace, Tracer};
 pub use boa_macros::{Finalize, Trace};
 pub use cell::{GcRef, GcRefCell, GcRefMut};
+pub use heap::{GcHeap, HeapGuard};
 pub use internals::GcBox;
 pub use pointers::{Ephemeron, Gc, WeakGc, WeakMap};
+pub use snapshot::{heap_snapshot, EdgeName, HeapSnapshot, NodeKind};
+pub use stats::{set_collection_hook, stats, CollectionKind, GcEvent, GcHook, GcStats, TypeStats};
 
 type GcErasedPointer = NonNull<GcBox<NonTraceable>>;
 type EphemeronPointer = NonNull<dyn ErasedEphemeronBox>;
 type ErasedWeakMapBoxPointer = NonNull<dyn ErasedWeakMapBox>;
 
 thread_local!(static GC_DROPPING: Cell<bool> = Cell::new(false));
-thread_local!(static BOA_GC: RefCell<BoaGc> = RefCell::new( BoaGc {
-    config: GcConfig::default(),
-    runtime: GcRuntimeData::default(),
-    strongs: Vec::default(),
-    weaks: Vec::default(),
-    weak_maps: Vec::default(),
-}));
 
+/// The configuration of a garbage collected heap.
+///
+/// A collection is triggered every time the allocated bytes go over a threshold, which grows
+/// whenever the live bytes after a collection take too much space of it.
+///
+/// Only the sizes of the [`GcBox`]es are taken into account, which doesn't include memory owned
+/// by the values themselves (e.g. the buffer of a [`Vec`]).
 #[derive(Debug, Clone, Copy)]
-struct GcConfig {
+pub struct GcConfig {
+    /// The initial number of allocated bytes that triggers a collection.
     threshold: usize,
+
+    /// The percentage of the threshold that can be used by the live bytes after a collection
+    /// before growing the threshold.
     used_space_percentage: usize,
+
+    /// The size of the grown threshold, as a percentage of the live bytes.
+    growth_percentage: usize,
+
+    /// The maximum number of allocated bytes.
+    max_heap_size: Option<usize>,
+
+    /// The number of bytes allocated in the nursery that triggers a minor collection.
+    nursery_size: Option<usize>,
 }
 
 // Setting the defaults to an arbitrary value currently.
-//
-// TODO: Add a configure later
 impl Default for GcConfig {
     fn default() -> Self {
         Self {
             threshold: 1024,
             used_space_percentage: 70,
+            growth_percentage: 150,
+            max_heap_size: None,
+            nursery_size: Some(512 * 1024),
         }
     }
 }
 
+impl GcConfig {
+    /// Get the initial number of allocated bytes that triggers a collection.
+    #[must_use]
+    pub const fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Set the initial number of allocated bytes that triggers a collection.
+    pub fn set_threshold(&mut self, value: usize) {
+        self.threshold = value;
+    }
+
+    /// Get the percentage of the threshold that can be used by the live bytes after a
+    /// collection before growing the threshold.
+    #[must_use]
+    pub const fn used_space_percentage(&self) -> usize {
+        self.used_space_percentage
+    }
+
+    /// Set the percentage of the threshold that can be used by the live bytes after a
+    /// collection before growing the threshold.
+    ///
+    /// This only decides when the threshold grows, see [`GcConfig::set_growth_percentage`] for
+    /// how much it grows.
+    ///
+    /// The value is clamped to the range `1..=100`.
+    pub fn set_used_space_percentage(&mut self, value: usize) {
+        self.used_space_percentage = value.clamp(1, 100);
+    }
+
+    /// Get the size of the grown threshold, as a percentage of the live bytes.
+    #[must_use]
+    pub const fn growth_percentage(&self) -> usize {
+        self.growth_percentage
+    }
+
+    /// Set the growth factor of the heap, as a percentage of the live bytes.
+    ///
+    /// When the live bytes after a major collection take more than the
+    /// [used space percentage](GcConfig::set_used_space_percentage) of the threshold, the
+    /// threshold grows to this percentage of the live bytes. For example, the default of `150`
+    /// sets the threshold to 1.5 times the live bytes. The threshold never shrinks.
+    ///
+    /// The value is clamped to a minimum of `100`.
+    pub fn set_growth_percentage(&mut self, value: usize) {
+        self.growth_percentage = value.max(100);
+    }
+
+    /// Get the maximum number of allocated bytes, or `None` if there is no limit.
+    #[must_use]
+    pub const fn max_heap_size(&self) -> Option<usize> {
+        self.max_heap_size
+    }
+
+    /// Set the maximum number of allocated bytes, or `None` to disable the limit.
+    ///
+    /// Allocating over the limit forces a collection. If the heap is still over the limit after
+    /// it, the allocation succeeds but [`take_heap_limit_exceeded`] will return `true`, allowing
+    /// the embedder to abort the execution in a controlled way.
+    ///
+    /// The memory that values own outside of the heap is not counted, but growing it can be
+    /// checked against the limit with [`can_allocate_external`].
+    pub fn set_max_heap_size(&mut self, value: Option<usize>) {
+        self.max_heap_size = value;
+    }
+
+    /// Get the number of bytes allocated in the nursery that triggers a minor collection, or
+    /// `None` if the heap doesn't have a nursery.
+    #[must_use]
+    pub const fn nursery_size(&self) -> Option<usize> {
+        self.nursery_size
+    }
+
+    /// Set the number of bytes allocated in the nursery that triggers a minor collection, or
+    /// `None` to allocate all the values directly in the old generation.
+    ///
+    /// New values are allocated in the nursery, and a minor collection only traces the values of
+    /// the nursery, promoting the ones that survive to the old generation. Since most values
+    /// die young, this frees most of the garbage with pauses that are bounded by the size of
+    /// the nursery instead of the size of the heap. The whole heap is only traced by a major
+    /// collection, when the allocated bytes of the old generation go over the threshold.
+    ///
+    /// The values that are the key of an ephemeron are always promoted, since ephemerons are
+    /// only cleared by major collections.
+    pub fn set_nursery_size(&mut self, value: Option<usize>) {
+        self.nursery_size = value;
+    }
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 struct GcRuntimeData {
     collections: usize,
+    minor_collections: usize,
     bytes_allocated: usize,
+    young_bytes: usize,
+    live_bytes: usize,
+    threshold: usize,
+    last_pause: Duration,
+    max_pause: Duration,
+    total_pause: Duration,
+    max_minor_pause: Duration,
+    max_major_pause: Duration,
+    heap_limit_exceeded: bool,
 }
 
 #[derive(Debug)]
 struct BoaGc {
+    /// The address of the heap that owns this state.
+    id: *const HeapInner,
     config: GcConfig,
     runtime: GcRuntimeData,
+    /// The values of the old generation.
     strongs: Vec<GcErasedPointer>,
+    /// The values of the nursery.
+    young: Vec<GcErasedPointer>,
     weaks: Vec<EphemeronPointer>,
     weak_maps: Vec<ErasedWeakMapBoxPointer>,
 }
 
+impl BoaGc {
+    fn new(config: GcConfig) -> Self {
+        Self {
+            id: ptr::null(),
+            config,
+            runtime: GcRuntimeData {
+                threshold: config.threshold,
+                ..GcRuntimeData::default()
+            },
+            strongs: Vec::default(),
+            young: Vec::default(),
+            weaks: Vec::default(),
+            weak_maps: Vec::default(),
+        }
+    }
+
+    /// Moves all the values of the nursery to the old generation.
+    fn promote_young(&mut self) {
+        for node in &self.young {
+            // SAFETY: nodes are always valid outside of a collection.
+            unsafe { node.as_ref() }.header.set_young(false);
+        }
+        self.strongs.append(&mut self.young);
+        self.runtime.young_bytes = 0;
+    }
+}
+
 impl Drop for BoaGc {
     fn drop(&mut self) {
         Collector::dump(self);
@@ -125,34 +274,48 @@ pub fn finalizer_safe() -> bool {
 struct Allocator;
 
 impl Allocator {
-    /// Allocate a new garbage collected value to the Garbage Collector's heap.
+    /// Allocate a new garbage collected value to the current heap.
     fn alloc_gc<T: Trace>(value: GcBox<T>) -> NonNull<GcBox<T>> {
         let _timer = Profiler::global().start_event("New GcBox", "BoaAlloc");
         let element_size = mem::size_of_val::<GcBox<T>>(&value);
-        BOA_GC.with(|st| {
-            let mut gc = st.borrow_mut();
+        with_current_heap(|heap| {
+            let mut gc = heap.gc.borrow_mut();
 
-            Self::manage_state(&mut gc);
+            Self::manage_state(&mut gc, element_size);
+            let young = gc.config.nursery_size.is_some();
+            value.header.set_heap(gc.id);
+            value.header.set_young(young);
             // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
             let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) };
             let erased: NonNull<GcBox<NonTraceable>> = ptr.cast();
 
-            gc.strongs.push(erased);
+            if young {
+                gc.young.push(erased);
+                gc.runtime.young_bytes += element_size;
+            } else {
+                gc.strongs.push(erased);
+            }
             gc.runtime.bytes_allocated += element_size;
 
             ptr
         })
     }
 
+    /// Allocate a new ephemeron to the heap of its key, or to the current heap if it doesn't
+    /// have a key yet.
     fn alloc_ephemeron<K: Trace + ?Sized, V: Trace>(
         value: EphemeronBox<K, V>,
     ) -> NonNull<EphemeronBox<K, V>> {
         let _timer = Profiler::global().start_event("New EphemeronBox", "BoaAlloc");
         let element_size = mem::size_of_val::<EphemeronBox<K, V>>(&value);
-        BOA_GC.with(|st| {
-            let mut gc = st.borrow_mut();
 
-            Self::manage_state(&mut gc);
+        // SAFETY: The ephemeron box was just created, so nothing can mutate it.
+        let key_heap = unsafe { value.key() }.map(|key| key.header.heap());
+        let alloc = |heap: &HeapInner| {
+            let mut gc = heap.gc.borrow_mut();
+
+            Self::manage_state(&mut gc, element_size);
+            value.header.set_heap(gc.id);
             // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
             let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) };
             let erased: NonNull<dyn ErasedEphemeronBox> = ptr;
@@ -161,7 +324,14 @@ impl Allocator {
             gc.runtime.bytes_allocated += element_size;
 
             ptr
-        })
+        };
+
+        match key_heap {
+            // SAFETY: A heap outlives all of its values (see `HeapInner::drop`), and the key is kept
+            // alive by `value`.
+            Some(heap) => alloc(unsafe { &*heap }),
+            None => with_current_heap(alloc),
+        }
     }
 
     fn alloc_weak_map<K: Trace + ?Sized, V: Trace + Clone>() -> WeakMap<K, V> {
@@ -172,32 +342,71 @@ impl Allocator {
         };
         let weak = WeakGc::new(&weak_map.inner);
 
-        BOA_GC.with(|st| {
-            let mut gc = st.borrow_mut();
+        // SAFETY: A heap outlives all of its values (see `HeapInner::drop`), and the map is alive.
+        let heap = unsafe { &*weak_map.inner.inner_ptr().as_ref().header.heap() };
+        let mut gc = heap.gc.borrow_mut();
 
-            let weak_box = WeakMapBox { map: weak };
+        let weak_box = WeakMapBox { map: weak };
 
-            // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
-            let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(weak_box))) };
-            let erased: ErasedWeakMapBoxPointer = ptr;
+        // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
+        let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(weak_box))) };
+        let erased: ErasedWeakMapBoxPointer = ptr;
 
-            gc.weak_maps.push(erased);
+        gc.weak_maps.push(erased);
 
-            weak_map
-        })
+        drop(gc);
+        weak_map
     }
 
-    fn manage_state(gc: &mut BoaGc) {
-        if gc.runtime.bytes_allocated > gc.config.threshold {
-            Collector::collect(gc);
+    fn manage_state(gc: &mut BoaGc, element_size: usize) {
+        if gc
+            .config
+            .nursery_size
+            .is_some_and(|nursery_size| gc.runtime.young_bytes > nursery_size)
+        {
+            Collector::collect(gc, CollectionKind::Minor);
+        }
+
+        // The nursery is bounded by minor collections, so only the old generation counts
+        // towards the threshold of major collections.
+        let old_bytes = gc
+            .runtime
+            .bytes_allocated
+            .saturating_sub(gc.runtime.young_bytes);
+
+        let mut collected = false;
+        if old_bytes > gc.runtime.threshold {
+            Collector::collect(gc, CollectionKind::Major);
+            collected = true;
 
             if gc.runtime.bytes_allocated
-                > gc.config.threshold / 100 * gc.config.used_space_percentage
+                > gc.runtime.threshold / 100 * gc.config.used_space_percentage
             {
-                gc.config.threshold =
-                    gc.runtime.bytes_allocated / gc.config.used_space_percentage * 100;
+                let grown = gc.runtime.bytes_allocated / 100 * gc.config.growth_percentage;
+                gc.runtime.threshold = gc.runtime.threshold.max(grown);
             }
         }
+
+        let Some(max_heap_size) = gc.config.max_heap_size else {
+            return;
+        };
+
+        // Only check the limit until the embedder takes the notification, to avoid running a
+        // collection on every allocation while the heap is full.
+        if gc.runtime.bytes_allocated + element_size <= max_heap_size
+            || gc.runtime.heap_limit_exceeded
+        {
+            return;
+        }
+
+        // Try to free enough space before reporting the heap as full.
+        if !collected {
+            Collector::collect(gc, CollectionKind::Major);
+        }
+
+        if gc.runtime.bytes_allocated + element_size > max_heap_size {
+            gc.runtime.heap_limit_exceeded = true;
+        }
     }
 }
 
@@ -218,17 +427,62 @@ struct Unreachables {
 /// A better approach in a more concurrent structure may be to reorder.
 ///
 /// Mark -> Sweep -> Finalize
+///
+/// A minor collection runs the same phases on the nursery only. The roots of the nursery are
+/// found the same way as the roots of the heap: the references from the old generation are not
+/// counted as non-root references, so the young values they reference are roots of the nursery.
 struct Collector;
 
 impl Collector {
-    /// Run a collection on the full heap.
-    fn collect(gc: &mut BoaGc) {
+    /// Run a collection on the full heap or on its nursery, notifying the collection hook and
+    /// recording its statistics.
+    fn collect(gc: &mut BoaGc, kind: CollectionKind) {
+        heap::adopt_orphans(gc);
+
+        let bytes_before = gc.runtime.bytes_allocated;
+        stats::notify(&GcEvent::CollectionStart {
+            kind,
+            bytes_allocated: bytes_before,
+        });
+
+        let start = Instant::now();
+        match kind {
+            CollectionKind::Minor => Self::collect_nursery(gc),
+            CollectionKind::Major => Self::collect_heap(gc),
+        }
+        let pause = start.elapsed();
+
+        let runtime = &mut gc.runtime;
+        runtime.collections += 1;
+        runtime.live_bytes = runtime.bytes_allocated;
+        runtime.last_pause = pause;
+        runtime.max_pause = runtime.max_pause.max(pause);
+        runtime.total_pause += pause;
+        match kind {
+            CollectionKind::Minor => {
+                runtime.minor_collections += 1;
+                runtime.max_minor_pause = runtime.max_minor_pause.max(pause);
+            }
+            CollectionKind::Major => {
+                runtime.max_major_pause = runtime.max_major_pause.max(pause);
+            }
+        }
+
+        stats::notify(&GcEvent::CollectionEnd {
+            kind,
+            bytes_allocated: runtime.bytes_allocated,
+            bytes_freed: bytes_before.saturating_sub(runtime.bytes_allocated),
+            pause,
+        });
+    }
+
+    fn collect_heap(gc: &mut BoaGc) {
         let _timer = Profiler::global().start_event("Gc Full Collection", "gc");
-        gc.runtime.collections += 1;
 
-        Self::trace_non_roots(gc);
+        gc.promote_young();
+        Self::trace_non_roots(gc, CollectionKind::Major);
 
-        let mut tracer = Tracer::new();
+        let mut tracer = Tracer::new(gc.id, CollectionKind::Major);
 
         let unreachables = Self::mark_heap(&mut tracer, &gc.strongs, &gc.weaks, &gc.weak_maps);
 
@@ -278,10 +532,47 @@ impl Collector {
         gc.weak_maps.shrink_to(gc.weak_maps.len() >> 2);
     }
 
-    fn trace_non_roots(gc: &BoaGc) {
+    fn collect_nursery(gc: &mut BoaGc) {
+        let _timer = Profiler::global().start_event("Gc Minor Collection", "gc");
+
+        Self::trace_non_roots(gc, CollectionKind::Minor);
+
+        let mut tracer = Tracer::new(gc.id, CollectionKind::Minor);
+
+        // Ephemerons and weak maps are only cleared by major collections, so they are skipped.
+        let unreachables = Self::mark_heap(&mut tracer, &gc.young, &[], &[]);
+
+        assert!(tracer.is_empty(), "The queue should be empty");
+
+        if !unreachables.strong.is_empty() {
+            // SAFETY: All passed pointers are valid, since we won't deallocate until `Self::sweep`.
+            unsafe { Self::finalize(unreachables) };
+
+            let _final_unreachables = Self::mark_heap(&mut tracer, &gc.young, &[], &[]);
+        }
+
+        // SAFETY: The nursery only contains valid nodes per the invariants of our GC.
+        unsafe {
+            Self::sweep(
+                &mut gc.young,
+                &mut Vec::new(),
+                &mut gc.runtime.bytes_allocated,
+            );
+        }
+
+        gc.promote_young();
+    }
+
+    fn trace_non_roots(gc: &BoaGc, kind: CollectionKind) {
         // Count all the handles located in GC heap.
         // Then, we can find whether there is a reference from other places, and they are the roots.
-        for node in &gc.strongs {
+        // Handles located in other heaps are not counted, so they are roots too.
+        let previous = heap::set_collecting((gc.id, kind));
+        let old: &[GcErasedPointer] = match kind {
+            CollectionKind::Minor => &[],
+            CollectionKind::Major => &gc.strongs,
+        };
+        for node in old.iter().chain(&gc.young) {
             // SAFETY: node must be valid as this phase cannot drop any node.
             let trace_non_roots_fn = unsafe { node.as_ref() }.trace_non_roots_fn();
 
@@ -291,10 +582,36 @@ impl Collector {
             }
         }
 
-        for eph in &gc.weaks {
-            // SAFETY: node must be valid as this phase cannot drop any node.
-            let eph_ref = unsafe { eph.as_ref() };
-            eph_ref.trace_non_roots();
+        if kind == CollectionKind::Major {
+            for eph in &gc.weaks {
+                // SAFETY: node must be valid as this phase cannot drop any node.
+                let eph_ref = unsafe { eph.as_ref() };
+                eph_ref.trace_non_roots();
+            }
+        }
+
+        heap::set_collecting(previous);
+    }
+
+    /// Marks and traces all the queued nodes, and the nodes that are reachable from them.
+    fn drain(tracer: &mut Tracer) {
+        while let Some(node) = tracer.next() {
+            // SAFETY: the gc heap object should be alive if there is a root.
+            let node_ref = unsafe { node.as_ref() };
+
+            if !node_ref.header.is_marked() {
+                node_ref.header.mark();
+
+                // SAFETY: if `GcBox::trace_inner()` has been called, then,
+                // this box must have been deemed as reachable via tracing
+                // from a root, which by extension means that value has not
+                // been dropped either.
+
+                let trace_fn = node_ref.trace_fn();
+
+                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+                unsafe { trace_fn(node, tracer) }
+            }
         }
     }
 
@@ -317,27 +634,9 @@ impl Collector {
         for node in strongs {
             // SAFETY: node must be valid as this phase cannot drop any node.
             let node_ref = unsafe { node.as_ref() };
-            if node_ref.is_rooted() {
+            if node_ref.is_rooted() || tracer.is_pinned(&node_ref.header) {
                 tracer.enqueue(*node);
-
-                while let Some(node) = tracer.next() {
-                    // SAFETY: the gc heap object should be alive if there is a root.
-                    let node_ref = unsafe { node.as_ref() };
-
-                    if !node_ref.header.is_marked() {
-                        node_ref.header.mark();
-
-                        // SAFETY: if `GcBox::trace_inner()` has been called, then,
-                        // this box must have been deemed as reachable via tracing
-                        // from a root, which by extension means that value has not
-                        // been dropped either.
-
-                        let trace_fn = node_ref.trace_fn();
-
-                        // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                        unsafe { trace_fn(node, tracer) }
-                    }
-                }
+                Self::drain(tracer);
             } else if !node_ref.is_marked() {
                 strong_dead.push(*node);
             }
@@ -373,15 +672,7 @@ impl Collector {
                 pending_ephemerons.push(*eph);
             }
 
-            while let Some(node) = tracer.next() {
-                // SAFETY: node must be valid as this phase cannot drop any node.
-                let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                unsafe {
-                    trace_fn(node, tracer);
-                }
-            }
+            Self::drain(tracer);
         }
 
         // 2. Trace all the weak pointers in the live weak maps to make sure they do not get swept.
@@ -392,15 +683,7 @@ impl Collector {
             // SAFETY: The garbage collector ensures that all nodes are valid.
             unsafe { node_ref.trace(tracer) };
 
-            while let Some(node) = tracer.next() {
-                // SAFETY: node must be valid as this phase cannot drop any node.
-                let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                unsafe {
-                    trace_fn(node, tracer);
-                }
-            }
+            Self::drain(tracer);
         }
 
         // 3. Iterate through all pending ephemerons, removing the ones which have been successfully
@@ -414,15 +697,7 @@ impl Collector {
                 // SAFETY: the garbage collector ensures `eph_ref` always points to valid data.
                 let is_key_marked = unsafe { !eph_ref.trace(tracer) };
 
-                while let Some(node) = tracer.next() {
-                    // SAFETY: node must be valid as this phase cannot drop any node.
-                    let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                    // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                    unsafe {
-                        trace_fn(node, tracer);
-                    }
-                }
+                Self::drain(tracer);
 
                 is_key_marked
             });
@@ -540,9 +815,10 @@ impl Collector {
             let _unmarked_node = unsafe { Box::from_raw(node.as_ptr()) };
         }
 
-        // Not initializing a dropguard since this should only be invoked when BOA_GC is being dropped.
+        // Not initializing a dropguard since this should only be invoked when the heap is being dropped.
         let _guard = DropGuard::new();
 
+        gc.promote_young();
         for node in std::mem::take(&mut gc.strongs) {
             // SAFETY:
             // The `Allocator` must always ensure its start node is a valid, non-null pointer that
@@ -564,15 +840,42 @@ impl Collector {
     }
 }
 
-/// Forcefully runs a garbage collection of all unaccessible nodes.
+/// Forcefully runs a garbage collection of all unaccessible nodes of the current heap.
+///
+/// See [`GcHeap::collect`].
 pub fn force_collect() {
-    BOA_GC.with(|current| {
-        let mut gc = current.borrow_mut();
+    GcHeap::current().collect();
+}
 
-        if gc.runtime.bytes_allocated > 0 {
-            Collector::collect(&mut gc);
-        }
-    });
+/// Returns the configuration of the current heap.
+#[must_use]
+pub fn config() -> GcConfig {
+    GcHeap::current().config()
+}
+
+/// Sets the configuration of the current heap.
+///
+/// This also resets the collection threshold to the initial threshold of `config`.
+pub fn set_config(config: GcConfig) {
+    GcHeap::current().set_config(config);
+}
+
+/// Returns `true` if an allocation went over the maximum size of the current heap, resetting
+/// the notification.
+///
+/// See [`GcConfig::set_max_heap_size`].
+#[must_use]
+pub fn take_heap_limit_exceeded() -> bool {
+    GcHeap::current().take_heap_limit_exceeded()
+}
+
+/// Returns `true` if `bytes` of memory can be allocated outside of the current heap, for a value
+/// of the heap, without going over its maximum size.
+///
+/// See [`GcHeap::can_allocate_external`].
+#[must_use]
+pub fn can_allocate_external(bytes: usize) -> bool {
+    GcHeap::current().can_allocate_external(bytes)
 }
 
 #[cfg(test)]
@@ -582,9 +885,5 @@ mod test;
 #[cfg(test)]
 #[must_use]
 pub fn has_weak_maps() -> bool {
-    BOA_GC.with(|current| {
-        let gc = current.borrow();
-
-        !gc.weak_maps.is_empty()
-    })
+    with_current_heap(|heap| !heap.gc.borrow().weak_maps.is_empty())
 }
@@ -4,7 +4,7 @@ use crate::{
     finalizer_safe,
     internals::EphemeronBox,
     trace::{Finalize, Trace},
-    Allocator, Gc, Tracer,
+    Allocator, EdgeName, Gc, Tracer,
 };
 use std::ptr::NonNull;
 
@@ -112,7 +112,34 @@ impl<K: Trace + ?Sized, V: Trace> Finalize for Ephemeron<K, V> {
 // SAFETY: `Ephemeron`s trace implementation only marks its inner box because we want to stop
 // tracing through weakly held pointers.
 unsafe impl<K: Trace + ?Sized, V: Trace> Trace for Ephemeron<K, V> {
-    unsafe fn trace(&self, _tracer: &mut Tracer) {
+    unsafe fn trace(&self, tracer: &mut Tracer) {
+        // A heap snapshot must not change the marks, so it records weak edges to the key and the
+        // value instead.
+        if tracer.is_recording() {
+            let inner = self.inner();
+            tracer.begin_weak();
+            // SAFETY: The ephemeron box isn't mutated while tracing.
+            if let Some(key) = unsafe { inner.key_ptr() } {
+                tracer.push_edge(|| EdgeName::Internal("key".into()));
+                tracer.enqueue(key.cast());
+                tracer.pop_edge();
+            }
+            // SAFETY: The ephemeron box isn't mutated while tracing.
+            if let Some(value) = unsafe { inner.value() } {
+                tracer.push_edge(|| EdgeName::Internal("value".into()));
+                // SAFETY: The recording tracer doesn't mark any value.
+                unsafe { value.trace(tracer) };
+                tracer.pop_edge();
+            }
+            tracer.end_weak();
+            return;
+        }
+
+        // The ephemerons of other heaps are kept alive by their own heap.
+        if !tracer.is_traced(&self.inner().header) {
+            return;
+        }
+
         // SAFETY: We need to mark the inner box of the `Ephemeron` since it is reachable
         // from a root and this means it cannot be dropped.
         unsafe {
@@ -0,0 +1,541 @@
+//! Heap snapshots in the format of the Chrome `DevTools`.
+//!
+//! A [`HeapSnapshot`] is taken by walking every [`GcBox`] reachable from the roots with the
+//! [`Trace`] machinery, but with the [`Tracer`] in a recording mode that records the edges of
+//! every node instead of marking them. Types can describe themselves and name their edges with
+//! [`Tracer::describe_node`] and [`Tracer::push_edge`], which do nothing outside of a snapshot.
+//!
+//! [`Trace`]: crate::Trace
+
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    io::{self, Write},
+};
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::{BoaGc, CollectionKind, Collector, GcErasedPointer, GcHeap, Tracer};
+
+/// The type of a node of a heap snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    /// An internal value of the engine, which is shown as `(system)` by the `DevTools`.
+    Hidden,
+
+    /// An array object.
+    Array,
+
+    /// An ordinary object.
+    Object,
+
+    /// Compiled code.
+    Code,
+
+    /// A function object.
+    Closure,
+
+    /// A regular expression object.
+    RegExp,
+
+    /// A value owned by the embedder.
+    Native,
+
+    /// The shape of an object.
+    ObjectShape,
+}
+
+impl NodeKind {
+    const NAMES: [&'static str; 15] = [
+        "hidden",
+        "array",
+        "string",
+        "object",
+        "code",
+        "closure",
+        "regexp",
+        "number",
+        "native",
+        "synthetic",
+        "concatenated string",
+        "sliced string",
+        "symbol",
+        "bigint",
+        "object shape",
+    ];
+
+    const SYNTHETIC: usize = 9;
+
+    const fn index(self) -> usize {
+        match self {
+            Self::Hidden => 0,
+            Self::Array => 1,
+            Self::Object => 3,
+            Self::Code => 4,
+            Self::Closure => 5,
+            Self::RegExp => 6,
+            Self::Native => 8,
+            Self::ObjectShape => 14,
+        }
+    }
+}
+
+/// The name of an edge of a heap snapshot, set with [`Tracer::push_edge`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EdgeName {
+    /// A property of an object.
+    Property(String),
+
+    /// An indexed element of an object.
+    Element(usize),
+
+    /// An internal slot, like a field of a struct.
+    Internal(Cow<'static, str>),
+
+    /// A variable of a closure environment.
+    Context(String),
+}
+
+/// The type of an edge, in the order of the `edge_types` of the snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EdgeKind {
+    Context,
+    Element,
+    Property,
+    Internal,
+    Hidden,
+    Weak,
+}
+
+impl EdgeKind {
+    const NAMES: [&'static str; 7] = [
+        "context", "element", "property", "internal", "hidden", "shortcut", "weak",
+    ];
+
+    /// Returns `true` if the edge is named after a property, element or variable.
+    const fn is_named(self) -> bool {
+        matches!(self, Self::Context | Self::Element | Self::Property)
+    }
+
+    const fn index(self) -> usize {
+        match self {
+            Self::Context => 0,
+            Self::Element => 1,
+            Self::Property => 2,
+            Self::Internal => 3,
+            Self::Hidden => 4,
+            Self::Weak => 6,
+        }
+    }
+}
+
+/// The name or index of an edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum EdgeLabel {
+    Name(String),
+    Index(usize),
+}
+
+#[derive(Debug)]
+struct RecordedEdge {
+    kind: EdgeKind,
+    label: EdgeLabel,
+    target: GcErasedPointer,
+}
+
+/// The state of a [`Tracer`] that is recording a heap snapshot.
+#[derive(Debug, Default)]
+pub(crate) struct Recorder {
+    description: Option<(NodeKind, String)>,
+    labels: Vec<EdgeName>,
+    weak: usize,
+    hidden: usize,
+    edges: Vec<RecordedEdge>,
+}
+
+impl Recorder {
+    pub(crate) fn describe_node(&mut self, kind: NodeKind, name: String) {
+        self.description = Some((kind, name));
+    }
+
+    pub(crate) fn push_edge(&mut self, name: EdgeName) {
+        self.labels.push(name);
+    }
+
+    pub(crate) fn pop_edge(&mut self) {
+        self.labels.pop();
+    }
+
+    pub(crate) fn begin_weak(&mut self) {
+        self.weak += 1;
+    }
+
+    pub(crate) fn end_weak(&mut self) {
+        self.weak -= 1;
+    }
+
+    /// Records an edge to `target`, named after the innermost property, element or variable
+    /// that is being traced, or after the path of internal slots if there is none.
+    pub(crate) fn record(&mut self, target: GcErasedPointer) {
+        let (mut kind, label) = match self
+            .labels
+            .iter()
+            .rev()
+            .find(|label| !matches!(label, EdgeName::Internal(_)))
+        {
+            Some(EdgeName::Property(name)) => (EdgeKind::Property, EdgeLabel::Name(name.clone())),
+            Some(EdgeName::Element(index)) => (EdgeKind::Element, EdgeLabel::Index(*index)),
+            Some(EdgeName::Context(name)) => (EdgeKind::Context, EdgeLabel::Name(name.clone())),
+            Some(EdgeName::Internal(_)) => unreachable!(),
+            None if self.labels.is_empty() => {
+                self.hidden += 1;
+                (EdgeKind::Hidden, EdgeLabel::Index(self.hidden))
+            }
+            None => {
+                let path = self
+                    .labels
+                    .iter()
+                    .filter_map(|label| match label {
+                        EdgeName::Internal(name) => Some(&**name),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join(".");
+                (EdgeKind::Internal, EdgeLabel::Name(path))
+            }
+        };
+
+        // Weak edges always have a name.
+        let label = if self.weak > 0 {
+            kind = EdgeKind::Weak;
+            match label {
+                EdgeLabel::Index(index) => EdgeLabel::Name(index.to_string()),
+                label @ EdgeLabel::Name(_) => label,
+            }
+        } else {
+            label
+        };
+
+        self.edges.push(RecordedEdge {
+            kind,
+            label,
+            target,
+        });
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    kind: NodeKind,
+    name: String,
+    self_size: usize,
+    edges: Vec<(EdgeKind, EdgeLabel, usize)>,
+}
+
+/// A snapshot of the garbage collected heap, returned by [`heap_snapshot`].
+///
+/// The snapshot can be written in the `.heapsnapshot` format of the Chrome `DevTools` with
+/// [`HeapSnapshot::write_json`], to be loaded in the memory tab of the `DevTools`.
+#[derive(Debug)]
+pub struct HeapSnapshot {
+    /// The nodes of the snapshot. The first node is the synthetic root, which has an edge to
+    /// every rooted value.
+    nodes: Vec<Node>,
+}
+
+impl HeapSnapshot {
+    /// The fields of a node in the `nodes` array of the snapshot.
+    const NODE_FIELDS: [&'static str; 7] = [
+        "type",
+        "name",
+        "id",
+        "self_size",
+        "edge_count",
+        "trace_node_id",
+        "detachedness",
+    ];
+
+    /// Returns the number of nodes of the snapshot, which includes the synthetic root.
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of edges of the snapshot.
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.nodes.iter().map(|node| node.edges.len()).sum()
+    }
+
+    /// Writes the snapshot as JSON in the `.heapsnapshot` format of the Chrome `DevTools`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `writer` fails.
+    pub fn write_json<W: Write>(&self, writer: W) -> io::Result<()> {
+        let mut writer = io::BufWriter::new(writer);
+        let mut strings = Strings::default();
+
+        write!(writer, "{{\"snapshot\":{{\"meta\":{{\"node_fields\":")?;
+        write_string_array(&mut writer, &Self::NODE_FIELDS)?;
+        write!(writer, ",\"node_types\":[")?;
+        write_string_array(&mut writer, &NodeKind::NAMES)?;
+        write!(
+            writer,
+            ",\"string\",\"number\",\"number\",\"number\",\"number\",\"number\"],\"edge_fields\":"
+        )?;
+        write_string_array(&mut writer, &["type", "name_or_index", "to_node"])?;
+        write!(writer, ",\"edge_types\":[")?;
+        write_string_array(&mut writer, &EdgeKind::NAMES)?;
+        write!(
+            writer,
+            ",\"string_or_number\",\"node\"],\"trace_function_info_fields\":"
+        )?;
+        write_string_array(
+            &mut writer,
+            &[
+                "function_id",
+                "name",
+                "script_name",
+                "script_id",
+                "line",
+                "column",
+            ],
+        )?;
+        write!(writer, ",\"trace_node_fields\":")?;
+        write_string_array(
+            &mut writer,
+            &["id", "function_info_index", "count", "size", "children"],
+        )?;
+        write!(writer, ",\"sample_fields\":")?;
+        write_string_array(&mut writer, &["timestamp_us", "last_assigned_id"])?;
+        write!(writer, ",\"location_fields\":")?;
+        write_string_array(
+            &mut writer,
+            &["object_index", "script_id", "line", "column"],
+        )?;
+        write!(
+            writer,
+            "}},\"node_count\":{},\"edge_count\":{},\"trace_function_count\":0}},\n\"nodes\":[",
+            self.node_count(),
+            self.edge_count()
+        )?;
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let kind = if index == 0 {
+                NodeKind::SYNTHETIC
+            } else {
+                node.kind.index()
+            };
+            write!(
+                writer,
+                "{}{kind},{},{},{},{},0,0",
+                if index == 0 { "" } else { ",\n" },
+                strings.index(&node.name),
+                // The `DevTools` reserve the odd ids for the objects of the heap.
+                index * 2 + 1,
+                node.self_size,
+                node.edges.len(),
+            )?;
+        }
+
+        write!(writer, "],\n\"edges\":[")?;
+        let mut first = true;
+        for node in &self.nodes {
+            for (kind, label, target) in &node.edges {
+                let label = match label {
+                    EdgeLabel::Name(name) => strings.index(name),
+                    EdgeLabel::Index(index) => *index,
+                };
+                write!(
+                    writer,
+                    "{}{},{label},{}",
+                    if first { "" } else { ",\n" },
+                    kind.index(),
+                    target * Self::NODE_FIELDS.len()
+                )?;
+                first = false;
+            }
+        }
+
+        write!(
+            writer,
+            "],\n\"trace_function_infos\":[],\"trace_tree\":[],\"samples\":[],\"locations\":[],\n\"strings\":["
+        )?;
+        for (index, string) in strings.strings.iter().enumerate() {
+            if index != 0 {
+                writeln!(writer, ",")?;
+            }
+            write_string(&mut writer, string)?;
+        }
+        write!(writer, "]}}")?;
+
+        writer.flush()
+    }
+}
+
+/// The deduplicated strings of a snapshot, referenced by their index.
+#[derive(Debug, Default)]
+struct Strings<'a> {
+    indices: HashMap<&'a str, usize>,
+    strings: Vec<&'a str>,
+}
+
+impl<'a> Strings<'a> {
+    fn index(&mut self, string: &'a str) -> usize {
+        *self.indices.entry(string).or_insert_with(|| {
+            self.strings.push(string);
+            self.strings.len() - 1
+        })
+    }
+}
+
+fn write_string<W: Write>(writer: &mut W, string: &str) -> io::Result<()> {
+    write!(writer, "\"")?;
+    for c in string.chars() {
+        match c {
+            '"' => write!(writer, "\\\"")?,
+            '\\' => write!(writer, "\\\\")?,
+            '\n' => write!(writer, "\\n")?,
+            '\r' => write!(writer, "\\r")?,
+            '\t' => write!(writer, "\\t")?,
+            c if c.is_control() => write!(writer, "\\u{:04x}", u32::from(c))?,
+            c => write!(writer, "{c}")?,
+        }
+    }
+    write!(writer, "\"")
+}
+
+fn write_string_array<W: Write>(writer: &mut W, strings: &[&str]) -> io::Result<()> {
+    write!(writer, "[")?;
+    for (index, string) in strings.iter().enumerate() {
+        if index != 0 {
+            write!(writer, ",")?;
+        }
+        write_string(writer, string)?;
+    }
+    write!(writer, "]")
+}
+
+/// Shortens a type name by removing the paths of all the types in it.
+///
+/// For example, `alloc::vec::Vec<core::option::Option<u8>>` becomes `Vec<Option<u8>>`.
+fn short_type_name(type_name: &str) -> String {
+    let mut name = String::with_capacity(type_name.len());
+    let mut segment_start = 0;
+    let mut chars = type_name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            name.truncate(segment_start);
+        } else {
+            name.push(c);
+            if !(c.is_alphanumeric() || c == '_') {
+                segment_start = name.len();
+            }
+        }
+    }
+    name
+}
+
+/// Takes a snapshot of the current heap.
+///
+/// Every value reachable from the roots is a node of the snapshot, described by the
+/// [`Tracer::describe_node`] calls of its [`Trace`][crate::Trace] implementation or by the name
+/// of its type otherwise. Values only reachable through weak references, and values of other
+/// heaps, are not included.
+///
+/// # Panics
+///
+/// Panics if called from a collection hook.
+#[must_use]
+pub fn heap_snapshot() -> HeapSnapshot {
+    GcHeap::current().snapshot()
+}
+
+/// Takes a snapshot of a heap.
+pub(crate) fn snapshot_heap(gc: &BoaGc) -> HeapSnapshot {
+    // Find the roots the same way a collection does, then restore the counts.
+    Collector::trace_non_roots(gc, CollectionKind::Major);
+    let roots = gc
+        .strongs
+        .iter()
+        .chain(&gc.young)
+        .copied()
+        // SAFETY: nodes are always valid outside of a collection.
+        .filter(|node| unsafe { node.as_ref() }.is_rooted())
+        .collect::<Vec<_>>();
+    for node in gc.strongs.iter().chain(&gc.young) {
+        // SAFETY: nodes are always valid outside of a collection.
+        unsafe { node.as_ref() }.reset_non_root_count();
+    }
+    for eph in &gc.weaks {
+        // SAFETY: ephemerons are always valid outside of a collection.
+        unsafe { eph.as_ref() }.header().reset_non_root_count();
+    }
+
+    let mut indices = HashMap::with_capacity(gc.strongs.len() + gc.young.len());
+    let mut pending = VecDeque::with_capacity(roots.len());
+    let mut root = Node {
+        kind: NodeKind::Hidden,
+        name: String::new(),
+        self_size: 0,
+        edges: Vec::with_capacity(roots.len()),
+    };
+    for (index, node) in roots.into_iter().enumerate() {
+        indices.insert(node, index + 1);
+        pending.push_back(node);
+        root.edges
+            .push((EdgeKind::Element, EdgeLabel::Index(index + 1), index + 1));
+    }
+
+    let mut nodes = vec![root];
+    let mut tracer = Tracer::recording(gc.id);
+    while let Some(node) = pending.pop_front() {
+        // SAFETY: nodes are always valid outside of a collection.
+        let node_ref = unsafe { node.as_ref() };
+        let trace_fn = node_ref.trace_fn();
+
+        // SAFETY: The function pointer is appropriate for this node type because we extract
+        // it from its VTable, and the recording tracer doesn't mark any node.
+        unsafe { trace_fn(node, &mut tracer) };
+
+        let recorder = tracer.take_recording();
+        let (kind, name) = recorder
+            .description
+            .unwrap_or_else(|| (NodeKind::Hidden, short_type_name(node_ref.type_name())));
+
+        // Values can be traced both with and without a name, like the bindings of an
+        // environment, which are named from the environment but also traced by the storage
+        // of the bindings. Only keep the named edges in that case.
+        let named = recorder
+            .edges
+            .iter()
+            .filter(|edge| edge.kind.is_named())
+            .map(|edge| edge.target)
+            .collect::<HashSet<_>>();
+
+        let edges = recorder
+            .edges
+            .into_iter()
+            .filter(|edge| edge.kind.is_named() || !named.contains(&edge.target))
+            .map(|edge| {
+                let next = indices.len() + 1;
+                let target = *indices.entry(edge.target).or_insert_with(|| {
+                    pending.push_back(edge.target);
+                    next
+                });
+                (edge.kind, edge.label, target)
+            })
+            .collect();
+
+        nodes.push(Node {
+            kind,
+            name,
+            self_size: node_ref.size(),
+            edges,
+        });
+    }
+
+    HeapSnapshot { nodes }
+}
@@ -0,0 +1,268 @@
+//! Statistics and collection hooks of the garbage collector.
+
+use std::{cell::RefCell, fmt, time::Duration};
+
+use hashbrown::HashMap;
+
+use crate::{BoaGc, GcHeap};
+
+// `Instant::now()` panics on `wasm32-unknown-unknown`, so collections are only timed there if the
+// `js` feature provides a clock.
+#[cfg(not(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi"))
+)))]
+pub(crate) use std::time::Instant;
+
+#[cfg(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi")),
+    feature = "js"
+))]
+pub(crate) use web_time::Instant;
+
+#[cfg(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi")),
+    not(feature = "js")
+))]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Instant;
+
+#[cfg(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi")),
+    not(feature = "js")
+))]
+impl Instant {
+    pub(crate) const fn now() -> Self {
+        Self
+    }
+
+    pub(crate) const fn elapsed(self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+/// A hook that is called before and after every collection, set with [`set_collection_hook`].
+pub type GcHook = Box<dyn FnMut(&GcEvent)>;
+
+thread_local!(static GC_HOOK: RefCell<Option<GcHook>> = const { RefCell::new(None) });
+
+/// The statistics of the allocations of a single type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeStats {
+    /// The name of the type, as returned by [`std::any::type_name`].
+    pub type_name: &'static str,
+
+    /// The number of allocations of the type.
+    pub count: usize,
+
+    /// The number of bytes used by the allocations of the type.
+    pub bytes: usize,
+}
+
+/// The statistics of the garbage collector of the current thread, returned by [`stats`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GcStats {
+    /// The number of bytes currently allocated, which includes unreachable values that haven't
+    /// been collected yet.
+    pub bytes_allocated: usize,
+
+    /// The number of bytes that were alive after the last collection.
+    pub live_bytes: usize,
+
+    /// The number of bytes allocated in the nursery since the last collection.
+    pub young_bytes: usize,
+
+    /// The number of allocated bytes that will trigger the next collection.
+    pub threshold: usize,
+
+    /// The number of collections run so far, both minor and major.
+    pub collections: usize,
+
+    /// The number of minor collections run so far.
+    pub minor_collections: usize,
+
+    /// The duration of the last collection.
+    pub last_pause: Duration,
+
+    /// The duration of the longest collection.
+    pub max_pause: Duration,
+
+    /// The total duration of all collections.
+    pub total_pause: Duration,
+
+    /// The duration of the longest minor collection.
+    pub max_minor_pause: Duration,
+
+    /// The duration of the longest major collection.
+    pub max_major_pause: Duration,
+
+    /// The number of garbage collected values currently allocated.
+    pub objects: usize,
+
+    /// The number of ephemerons currently allocated, which back [`WeakGc`][crate::WeakGc]s
+    /// and [`Ephemeron`][crate::Ephemeron]s.
+    pub ephemerons: usize,
+
+    /// The number of weak maps currently allocated.
+    pub weak_maps: usize,
+
+    /// The garbage collected values currently allocated, grouped by type and sorted by the
+    /// number of bytes they use, in descending order.
+    pub types: Vec<TypeStats>,
+}
+
+/// The kind of a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionKind {
+    /// A collection of the nursery, which only traces the young values.
+    ///
+    /// See [`GcConfig::set_nursery_size`][crate::GcConfig::set_nursery_size].
+    Minor,
+
+    /// A collection of the whole heap.
+    Major,
+}
+
+impl fmt::Display for CollectionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Minor => "minor",
+            Self::Major => "major",
+        })
+    }
+}
+
+/// An event of the garbage collector, passed to the hook set with [`set_collection_hook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GcEvent {
+    /// A collection is about to start.
+    CollectionStart {
+        /// The kind of the collection.
+        kind: CollectionKind,
+
+        /// The number of bytes allocated before the collection.
+        bytes_allocated: usize,
+    },
+
+    /// A collection has finished.
+    CollectionEnd {
+        /// The kind of the collection.
+        kind: CollectionKind,
+
+        /// The number of bytes allocated after the collection.
+        bytes_allocated: usize,
+
+        /// The number of bytes freed by the collection.
+        bytes_freed: usize,
+
+        /// The duration of the collection.
+        pause: Duration,
+    },
+}
+
+impl fmt::Display for GcEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CollectionStart {
+                kind,
+                bytes_allocated,
+            } => {
+                write!(
+                    f,
+                    "{kind} collection started with {bytes_allocated} bytes allocated"
+                )
+            }
+            Self::CollectionEnd {
+                kind,
+                bytes_allocated,
+                bytes_freed,
+                pause,
+            } => write!(
+                f,
+                "{kind} collection finished in {pause:?}, freeing {bytes_freed} bytes \
+                ({bytes_allocated} bytes allocated)"
+            ),
+        }
+    }
+}
+
+/// Returns the statistics of the current heap.
+///
+/// This walks the whole heap to compute the statistics per type, so it should not be called
+/// too often.
+///
+/// # Panics
+///
+/// Panics if called from a collection hook.
+#[must_use]
+pub fn stats() -> GcStats {
+    GcHeap::current().stats()
+}
+
+/// Computes the statistics of a heap.
+pub(crate) fn heap_stats(gc: &BoaGc) -> GcStats {
+    let mut types: HashMap<&'static str, TypeStats> = HashMap::new();
+    for node in gc.strongs.iter().chain(&gc.young) {
+        // SAFETY: nodes are always valid outside of a collection.
+        let node = unsafe { node.as_ref() };
+        let type_name = node.type_name();
+        let stats = types.entry(type_name).or_insert(TypeStats {
+            type_name,
+            count: 0,
+            bytes: 0,
+        });
+        stats.count += 1;
+        stats.bytes += node.size();
+    }
+
+    let mut types = types.into_values().collect::<Vec<_>>();
+    types.sort_unstable_by(|a, b| {
+        b.bytes
+            .cmp(&a.bytes)
+            .then_with(|| a.type_name.cmp(b.type_name))
+    });
+
+    GcStats {
+        bytes_allocated: gc.runtime.bytes_allocated,
+        live_bytes: gc.runtime.live_bytes,
+        young_bytes: gc.runtime.young_bytes,
+        threshold: gc.runtime.threshold,
+        collections: gc.runtime.collections,
+        minor_collections: gc.runtime.minor_collections,
+        last_pause: gc.runtime.last_pause,
+        max_pause: gc.runtime.max_pause,
+        total_pause: gc.runtime.total_pause,
+        max_minor_pause: gc.runtime.max_minor_pause,
+        max_major_pause: gc.runtime.max_major_pause,
+        objects: gc.strongs.len() + gc.young.len(),
+        ephemerons: gc.weaks.len(),
+        weak_maps: gc.weak_maps.len(),
+        types,
+    }
+}
+
+/// Sets a hook that is called before and after every collection of the current thread,
+/// returning the previous hook.
+///
+/// The hook runs while the garbage collector is active, so it must not allocate or access
+/// garbage collected values, nor call any other function of this crate.
+#[must_use]
+pub fn set_collection_hook(hook: Option<GcHook>) -> Option<GcHook> {
+    GC_HOOK.with(|current| std::mem::replace(&mut *current.borrow_mut(), hook))
+}
+
+/// Calls the collection hook with `event`, if there is one.
+pub(crate) fn notify(event: &GcEvent) {
+    // The hook is already gone if a heap is dropped while the thread exits.
+    let _ = GC_HOOK.try_with(|current| {
+        // Skip the hook if it is already running.
+        if let Ok(mut hook) = current.try_borrow_mut() {
+            if let Some(hook) = hook.as_mut() {
+                hook(event);
+            }
+        }
+    });
+}
@@ -1,7 +1,10 @@
 use boa_macros::{Finalize, Trace};
 
 use super::{run_test, Harness};
-use crate::{force_collect, Gc, GcBox, GcRefCell};
+use crate::{
+    can_allocate_external, force_collect, set_config, take_heap_limit_exceeded, Gc, GcBox,
+    GcConfig, GcHeap, GcRefCell,
+};
 
 #[test]
 fn gc_basic_cell_allocation() {
@@ -62,3 +65,74 @@ fn gc_recursion() {
         Harness::assert_empty_gc();
     });
 }
+
+#[test]
+fn gc_growth_percentage() {
+    run_test(|| {
+        let mut config = GcConfig::default();
+        config.set_nursery_size(None);
+        config.set_growth_percentage(200);
+        set_config(config);
+
+        let values = (0..4).map(Gc::new).collect::<Vec<Gc<u64>>>();
+        let live_bytes = Harness::bytes_allocated();
+        config.set_threshold(live_bytes - 1);
+        set_config(config);
+
+        // All the values survive the collection, so the threshold grows to twice the live bytes.
+        let value = Gc::new(4);
+        Harness::assert_collections(1);
+        assert_eq!(GcHeap::current().stats().threshold, live_bytes / 100 * 200);
+
+        drop((values, value));
+    });
+}
+
+#[test]
+fn gc_max_heap_size() {
+    run_test(|| {
+        let mut values = (0..4).map(Gc::new).collect::<Vec<Gc<u64>>>();
+        let max_heap_size = Harness::bytes_allocated();
+
+        let mut config = GcConfig::default();
+        config.set_max_heap_size(Some(max_heap_size));
+        set_config(config);
+
+        // Going over the limit collects the unreachable values first.
+        drop(values.pop());
+        values.push(Gc::new(4));
+        Harness::assert_collections(1);
+        Harness::assert_exact_bytes_allocated(max_heap_size);
+        assert!(!take_heap_limit_exceeded());
+
+        values.push(Gc::new(5));
+        Harness::assert_collections(2);
+        assert!(take_heap_limit_exceeded());
+        assert!(!take_heap_limit_exceeded());
+    });
+}
+
+#[test]
+fn gc_max_heap_size_external() {
+    run_test(|| {
+        let mut values = (0..4).map(Gc::new).collect::<Vec<Gc<u64>>>();
+        let max_heap_size = Harness::bytes_allocated();
+        let value_size = max_heap_size / values.len();
+
+        let mut config = GcConfig::default();
+        config.set_max_heap_size(Some(max_heap_size));
+        set_config(config);
+
+        assert!(can_allocate_external(0));
+        Harness::assert_collections(0);
+
+        // Checking an allocation that doesn't fit collects the unreachable values first.
+        drop(values.pop());
+        assert!(can_allocate_external(value_size));
+        Harness::assert_collections(1);
+
+        assert!(!can_allocate_external(value_size + 1));
+        Harness::assert_collections(2);
+        assert!(!take_heap_limit_exceeded());
+    });
+}
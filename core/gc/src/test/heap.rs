@@ -0,0 +1,241 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use super::{run_test, Harness};
+use crate::{force_collect, stats, Finalize, Gc, GcConfig, GcHeap, GcRefCell, Trace, WeakGc};
+
+#[derive(Trace)]
+struct Counted {
+    #[unsafe_ignore_trace]
+    finalized: Rc<Cell<usize>>,
+}
+
+#[derive(Trace, Finalize)]
+struct Node(GcRefCell<Option<Gc<Node>>>);
+
+impl Finalize for Counted {
+    fn finalize(&self) {
+        self.finalized.set(self.finalized.get() + 1);
+    }
+}
+
+#[test]
+fn heaps_have_separate_accounting() {
+    run_test(|| {
+        let heap = GcHeap::new(GcConfig::default());
+        assert!(!GcHeap::ptr_eq(&heap, &GcHeap::current()));
+
+        let value = {
+            let _guard = heap.enter();
+            assert!(GcHeap::ptr_eq(&heap, &GcHeap::current()));
+            Gc::new(GcRefCell::new(vec![1_u32, 2, 3]))
+        };
+        assert!(GcHeap::ptr_eq(
+            &GcHeap::thread_default(),
+            &GcHeap::current()
+        ));
+
+        Harness::assert_empty_gc();
+        assert_eq!(heap.stats().objects, 1);
+        assert!(heap.stats().bytes_allocated > 0);
+
+        heap.collect();
+        force_collect();
+        assert_eq!(heap.stats().collections, 1);
+        assert_eq!(stats().collections, 0);
+        assert_eq!(*value.borrow(), vec![1, 2, 3]);
+    });
+}
+
+#[test]
+fn dropping_a_heap_frees_its_values() {
+    run_test(|| {
+        let finalized = Rc::new(Cell::new(0));
+        let heap = GcHeap::new(GcConfig::default());
+        {
+            let _guard = heap.enter();
+            let first = Gc::new(Node(GcRefCell::new(None)));
+            let second = Gc::new(Node(GcRefCell::new(Some(first.clone()))));
+            *first.0.borrow_mut() = Some(second);
+            let _counted = Gc::new(Counted {
+                finalized: finalized.clone(),
+            });
+        }
+
+        // A cycle that wasn't collected yet is freed with the heap.
+        assert_eq!(heap.stats().objects, 3);
+        drop(heap);
+        assert_eq!(finalized.get(), 1);
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn surviving_values_move_to_the_default_heap() {
+    run_test(|| {
+        let finalized = Rc::new(Cell::new(0));
+        let heap = GcHeap::new(GcConfig::default());
+        let counted = {
+            let _guard = heap.enter();
+            Gc::new(Counted {
+                finalized: finalized.clone(),
+            })
+        };
+        let weak = WeakGc::new(&counted);
+
+        drop(heap);
+        assert_eq!(finalized.get(), 0);
+        assert_eq!(stats().objects, 1);
+        assert_eq!(stats().ephemerons, 1);
+
+        drop(counted);
+        force_collect();
+        assert_eq!(finalized.get(), 1);
+        assert!(weak.upgrade().is_none());
+
+        drop(weak);
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn references_between_heaps_are_roots() {
+    run_test(|| {
+        let finalized = Rc::new(Cell::new(0));
+        let first = GcHeap::new(GcConfig::default());
+        let second = GcHeap::new(GcConfig::default());
+
+        let counted = {
+            let _guard = second.enter();
+            Gc::new(Counted {
+                finalized: finalized.clone(),
+            })
+        };
+        let (holder, weak) = {
+            let _guard = first.enter();
+            let weak = WeakGc::new(&counted);
+            (Gc::new(GcRefCell::new(Some(counted))), weak)
+        };
+
+        // The ephemeron is allocated in the heap of its key.
+        assert_eq!(first.stats().ephemerons, 0);
+        assert_eq!(second.stats().ephemerons, 1);
+
+        second.collect();
+        assert_eq!(finalized.get(), 0);
+        assert!(weak.upgrade().is_some());
+
+        holder.borrow_mut().take();
+        second.collect();
+        assert_eq!(finalized.get(), 1);
+        assert!(weak.upgrade().is_none());
+    });
+}
+
+#[derive(Trace)]
+struct HeapOwner {
+    #[unsafe_ignore_trace]
+    heap: RefCell<Option<GcHeap>>,
+}
+
+impl Finalize for HeapOwner {
+    fn finalize(&self) {
+        drop(self.heap.borrow_mut().take());
+    }
+}
+
+#[test]
+fn heap_dropped_while_the_default_heap_is_busy() {
+    run_test(|| {
+        let finalized = Rc::new(Cell::new(0));
+        let heap = GcHeap::new(GcConfig::default());
+        let counted = {
+            let _guard = heap.enter();
+            Gc::new(Counted {
+                finalized: finalized.clone(),
+            })
+        };
+
+        // The heap is dropped while the default heap is collecting, so it can't adopt the
+        // values that are still alive yet.
+        drop(Gc::new(HeapOwner {
+            heap: RefCell::new(Some(heap)),
+        }));
+        force_collect();
+        assert_eq!(finalized.get(), 0);
+
+        // Allocating an ephemeron reads the heap of its key, which must still be alive.
+        let weak = WeakGc::new(&counted);
+        force_collect();
+        assert_eq!(stats().objects, 1);
+        assert_eq!(stats().ephemerons, 1);
+
+        drop(counted);
+        force_collect();
+        assert_eq!(finalized.get(), 1);
+        assert!(weak.upgrade().is_none());
+
+        drop(weak);
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn orphaned_heaps_are_freed_when_the_thread_exits() {
+    run_test(|| {
+        let heap = GcHeap::new(GcConfig::default());
+        let value = {
+            let _guard = heap.enter();
+            Gc::new(GcRefCell::new(vec![1_u8]))
+        };
+        drop(Gc::new(HeapOwner {
+            heap: RefCell::new(Some(heap)),
+        }));
+        force_collect();
+
+        // The value lives in an orphaned heap until the thread exits.
+        std::thread_local!(static VALUE: RefCell<Option<Gc<GcRefCell<Vec<u8>>>>> = const { RefCell::new(None) });
+        VALUE.with(|v| *v.borrow_mut() = Some(value));
+    });
+}
+
+#[test]
+fn cycles_between_heaps_are_collected_once_in_one_heap() {
+    run_test(|| {
+        let first = GcHeap::new(GcConfig::default());
+        let second = GcHeap::new(GcConfig::default());
+
+        let a = {
+            let _guard = first.enter();
+            Gc::new(Node(GcRefCell::new(None)))
+        };
+        let b = {
+            let _guard = second.enter();
+            Gc::new(Node(GcRefCell::new(Some(a.clone()))))
+        };
+        *a.0.borrow_mut() = Some(b.clone());
+        let weak = WeakGc::new(&a);
+        drop((a, b));
+
+        // References between heaps are roots, so neither heap can collect the cycle.
+        first.collect();
+        second.collect();
+        assert!(weak.upgrade().is_some());
+
+        // The values survive the drop of their heaps, and end up in the default heap.
+        drop(first);
+        force_collect();
+        assert!(weak.upgrade().is_some());
+        drop(second);
+
+        force_collect();
+        assert!(weak.upgrade().is_none());
+        drop(weak);
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
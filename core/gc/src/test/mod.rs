@@ -1,7 +1,11 @@
-use crate::BOA_GC;
+use crate::heap::with_current_heap;
 
 mod allocation;
 mod cell;
+mod heap;
+mod nursery;
+mod snapshot;
+mod stats;
 mod weak;
 mod weak_map;
 
@@ -10,34 +14,39 @@ struct Harness;
 impl Harness {
     #[track_caller]
     fn assert_collections(o: usize) {
-        BOA_GC.with(|current| {
-            let gc = current.borrow();
+        with_current_heap(|heap| {
+            let gc = heap.gc.borrow();
             assert_eq!(gc.runtime.collections, o);
         });
     }
 
     #[track_caller]
     fn assert_empty_gc() {
-        BOA_GC.with(|current| {
-            let gc = current.borrow();
+        with_current_heap(|heap| {
+            let gc = heap.gc.borrow();
 
             assert!(gc.strongs.is_empty());
+            assert!(gc.young.is_empty());
             assert!(gc.runtime.bytes_allocated == 0);
         });
     }
 
     #[track_caller]
     fn assert_bytes_allocated() {
-        BOA_GC.with(|current| {
-            let gc = current.borrow();
+        with_current_heap(|heap| {
+            let gc = heap.gc.borrow();
             assert!(gc.runtime.bytes_allocated > 0);
         });
     }
 
+    fn bytes_allocated() -> usize {
+        with_current_heap(|heap| heap.gc.borrow().runtime.bytes_allocated)
+    }
+
     #[track_caller]
     fn assert_exact_bytes_allocated(bytes: usize) {
-        BOA_GC.with(|current| {
-            let gc = current.borrow();
+        with_current_heap(|heap| {
+            let gc = heap.gc.borrow();
             assert_eq!(gc.runtime.bytes_allocated, bytes);
         });
     }
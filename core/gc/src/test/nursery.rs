@@ -0,0 +1,144 @@
+use std::{cell::Cell, rc::Rc};
+
+use super::{run_test, Harness};
+use crate::{
+    force_collect, set_config, stats, Finalize, Gc, GcBox, GcConfig, GcHeap, GcRefCell, Trace,
+    WeakGc,
+};
+
+#[derive(Trace)]
+struct Counted {
+    #[unsafe_ignore_trace]
+    finalized: Rc<Cell<usize>>,
+}
+
+impl Finalize for Counted {
+    fn finalize(&self) {
+        self.finalized.set(self.finalized.get() + 1);
+    }
+}
+
+#[derive(Trace, Finalize)]
+struct Node(GcRefCell<Option<Gc<Node>>>);
+
+fn counted(finalized: &Rc<Cell<usize>>) -> Gc<Counted> {
+    Gc::new(Counted {
+        finalized: finalized.clone(),
+    })
+}
+
+#[test]
+fn minor_collection_frees_young_values() {
+    run_test(|| {
+        let finalized = Rc::new(Cell::new(0));
+        let kept = counted(&finalized);
+        drop(counted(&finalized));
+
+        let first = Gc::new(Node(GcRefCell::new(None)));
+        let second = Gc::new(Node(GcRefCell::new(Some(first.clone()))));
+        *first.0.borrow_mut() = Some(second);
+        drop(first);
+
+        assert_eq!(stats().young_bytes, Harness::bytes_allocated());
+        GcHeap::current().collect_nursery();
+
+        let stats = stats();
+        assert_eq!(finalized.get(), 1);
+        assert_eq!(stats.objects, 1);
+        assert_eq!(stats.young_bytes, 0);
+        assert_eq!(stats.collections, 1);
+        assert_eq!(stats.minor_collections, 1);
+        Harness::assert_exact_bytes_allocated(size_of::<GcBox<Counted>>());
+
+        drop(kept);
+        force_collect();
+        assert_eq!(finalized.get(), 2);
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn old_values_keep_young_values_alive() {
+    run_test(|| {
+        let finalized = Rc::new(Cell::new(0));
+        let old = Gc::new(GcRefCell::new(None));
+        GcHeap::current().collect_nursery();
+
+        *old.borrow_mut() = Some(counted(&finalized));
+        GcHeap::current().collect_nursery();
+        assert_eq!(finalized.get(), 0);
+        assert_eq!(stats().minor_collections, 2);
+
+        // Promoted values are only freed by major collections.
+        old.borrow_mut().take();
+        GcHeap::current().collect_nursery();
+        assert_eq!(finalized.get(), 0);
+
+        force_collect();
+        assert_eq!(finalized.get(), 1);
+    });
+}
+
+#[test]
+fn ephemeron_keys_are_promoted() {
+    run_test(|| {
+        let finalized = Rc::new(Cell::new(0));
+        let key = counted(&finalized);
+        let weak = WeakGc::new(&key);
+
+        drop(key);
+        GcHeap::current().collect_nursery();
+        assert_eq!(finalized.get(), 0);
+        assert!(weak.upgrade().is_some());
+
+        force_collect();
+        assert_eq!(finalized.get(), 1);
+        assert!(weak.upgrade().is_none());
+    });
+}
+
+#[test]
+fn nursery_size_triggers_minor_collections() {
+    run_test(|| {
+        let mut config = GcConfig::default();
+        config.set_threshold(usize::MAX);
+        config.set_nursery_size(Some(1024));
+        set_config(config);
+
+        let kept = Gc::new(GcRefCell::new(Vec::new()));
+        for i in 0..1000_u64 {
+            let value = Gc::new(i);
+            if i % 100 == 0 {
+                kept.borrow_mut().push(value);
+            }
+        }
+
+        let stats = stats();
+        assert!(stats.minor_collections > 0);
+        assert_eq!(stats.collections, stats.minor_collections);
+        assert!(stats.max_minor_pause <= stats.max_pause);
+        assert!(stats.young_bytes <= 1024 + size_of::<GcBox<u64>>());
+        assert_eq!(kept.borrow().len(), 10);
+        assert!(kept
+            .borrow()
+            .iter()
+            .zip((0..).step_by(100))
+            .all(|(v, i)| **v == i));
+    });
+}
+
+#[test]
+fn disabled_nursery() {
+    run_test(|| {
+        let mut config = GcConfig::default();
+        config.set_nursery_size(None);
+        set_config(config);
+
+        let value = Gc::new(1_u8);
+        assert_eq!(stats().young_bytes, 0);
+
+        GcHeap::current().collect_nursery();
+        assert_eq!(stats().collections, 0);
+        assert_eq!(*value, 1);
+    });
+}
@@ -0,0 +1,106 @@
+use super::{run_test, Harness};
+use crate::{
+    force_collect, heap_snapshot, EdgeName, Finalize, Gc, GcRefCell, HeapSnapshot, NodeKind, Trace,
+    Tracer, WeakGc,
+};
+
+#[derive(Trace, Finalize)]
+struct Node {
+    name: u8,
+    next: Option<Gc<GcRefCell<Node>>>,
+    children: Vec<Gc<u64>>,
+}
+
+fn json(snapshot: &HeapSnapshot) -> String {
+    let mut buffer = Vec::new();
+    snapshot
+        .write_json(&mut buffer)
+        .expect("writing to a vector should not fail");
+    String::from_utf8(buffer).expect("the snapshot should be valid UTF-8")
+}
+
+#[test]
+fn heap_snapshot_nodes_and_edges() {
+    run_test(|| {
+        let tail = Gc::new(GcRefCell::new(Node {
+            name: 1,
+            next: None,
+            children: vec![Gc::new(1), Gc::new(2)],
+        }));
+        let head = Gc::new(GcRefCell::new(Node {
+            name: 0,
+            next: Some(tail.clone()),
+            children: Vec::new(),
+        }));
+        drop(tail);
+        let weak = WeakGc::new(&head);
+        let unreachable = Gc::new(3_u64);
+        drop(unreachable);
+
+        let snapshot = heap_snapshot();
+
+        // The synthetic root, the head, the tail and the two children of the tail.
+        assert_eq!(snapshot.node_count(), 5);
+        // The root has an edge to the head, which has an edge to the tail, which has an edge to
+        // each child.
+        assert_eq!(snapshot.edge_count(), 4);
+
+        let json = json(&snapshot);
+        assert!(json.starts_with("{\"snapshot\":{\"meta\":{\"node_fields\":"));
+        assert!(json.contains("\"node_count\":5,\"edge_count\":4"));
+        assert!(json.contains("\"GcRefCell<Node>\""));
+        assert!(json.contains("\"u64\""));
+        assert!(json.contains("\"next\""));
+        assert!(json.contains("\"children\""));
+
+        // Taking a snapshot must not change what a collection frees.
+        let bytes_before = Harness::bytes_allocated();
+        force_collect();
+        assert!(Harness::bytes_allocated() < bytes_before);
+        assert_eq!(heap_snapshot().node_count(), 5);
+        assert!(weak.upgrade().is_some());
+
+        drop(head);
+        force_collect();
+        assert_eq!(heap_snapshot().node_count(), 1);
+        assert!(weak.upgrade().is_none());
+    });
+}
+
+#[test]
+fn heap_snapshot_escapes_strings() {
+    run_test(|| {
+        struct Quoted(Gc<u8>);
+
+        impl Finalize for Quoted {}
+
+        // SAFETY: `Quoted` traces its only field.
+        unsafe impl Trace for Quoted {
+            unsafe fn trace(&self, tracer: &mut Tracer) {
+                tracer.describe_node(NodeKind::Native, || "\"quoted\"\n".to_owned());
+                tracer.push_edge(|| EdgeName::Property("a\\b".to_owned()));
+                // SAFETY: `trace` is called by the garbage collector.
+                unsafe { self.0.trace(tracer) };
+                tracer.pop_edge();
+            }
+
+            unsafe fn trace_non_roots(&self) {
+                // SAFETY: `trace_non_roots` is called by the garbage collector.
+                unsafe { self.0.trace_non_roots() };
+            }
+
+            fn run_finalizer(&self) {
+                Finalize::finalize(self);
+                self.0.run_finalizer();
+            }
+        }
+
+        let value = Gc::new(Quoted(Gc::new(1)));
+
+        let json = json(&heap_snapshot());
+        assert!(json.contains(r#""\"quoted\"\n""#));
+        assert!(json.contains(r#""a\\b""#));
+
+        drop(value);
+    });
+}
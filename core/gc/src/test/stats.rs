@@ -0,0 +1,94 @@
+use std::{any::type_name, cell::RefCell, rc::Rc};
+
+use super::run_test;
+use crate::{
+    force_collect, set_collection_hook, stats, CollectionKind, Gc, GcEvent, GcRefCell, GcStats,
+    TypeStats, WeakGc, WeakMap,
+};
+
+fn u64_stats(stats: &GcStats) -> TypeStats {
+    *stats
+        .types
+        .iter()
+        .find(|ty| ty.type_name == type_name::<u64>())
+        .expect("there should be stats for `u64`")
+}
+
+#[test]
+fn gc_stats() {
+    run_test(|| {
+        let values = (0..3).map(Gc::new).collect::<Vec<Gc<u64>>>();
+        let cell = Gc::new(GcRefCell::new(1_u8));
+        let weak = WeakGc::new(&cell);
+        let map = WeakMap::<u8, u8>::new();
+
+        let before = stats();
+        assert_eq!(before.collections, 0);
+        // The weak map is backed by a weak reference too.
+        assert_eq!(before.ephemerons, 2);
+        assert_eq!(before.weak_maps, 1);
+        assert_eq!(u64_stats(&before).count, 3);
+        assert_eq!(
+            before.objects,
+            before.types.iter().map(|ty| ty.count).sum::<usize>()
+        );
+        assert!(before.bytes_allocated > before.types.iter().map(|ty| ty.bytes).sum::<usize>());
+
+        drop(values);
+        force_collect();
+
+        let after = stats();
+        assert_eq!(after.collections, 1);
+        assert_eq!(after.objects, before.objects - 3);
+        assert!(!after
+            .types
+            .iter()
+            .any(|ty| ty.type_name == type_name::<u64>()));
+        assert_eq!(after.live_bytes, after.bytes_allocated);
+        assert_eq!(
+            after.bytes_allocated,
+            before.bytes_allocated - u64_stats(&before).bytes
+        );
+        assert_eq!(after.total_pause, after.last_pause);
+        assert_eq!(after.max_pause, after.last_pause);
+
+        drop((weak, map));
+    });
+}
+
+#[test]
+fn gc_collection_hook() {
+    run_test(|| {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let hook_events = events.clone();
+        let previous = set_collection_hook(Some(Box::new(move |event: &GcEvent| {
+            hook_events.borrow_mut().push(*event);
+        })));
+        assert!(previous.is_none());
+
+        let value = Gc::new(1_u64);
+        drop(Gc::new(2_u64));
+        let size = u64_stats(&stats()).bytes / 2;
+        force_collect();
+
+        let events = events.borrow();
+        let [GcEvent::CollectionStart {
+            kind: CollectionKind::Major,
+            bytes_allocated,
+        }, GcEvent::CollectionEnd {
+            kind: CollectionKind::Major,
+            bytes_allocated: bytes_after,
+            bytes_freed,
+            ..
+        }] = events[..]
+        else {
+            panic!("expected a start and an end event, got {events:?}");
+        };
+        assert_eq!(bytes_allocated, 2 * size);
+        assert_eq!(bytes_after, size);
+        assert_eq!(bytes_freed, size);
+
+        assert!(set_collection_hook(None).is_some());
+        drop(value);
+    });
+}
@@ -164,7 +164,7 @@ fn eph_self_referential() {
             *root.inner.inner.borrow_mut() = Some(eph.clone());
 
             assert!(eph.value().is_some());
-            Harness::assert_exact_bytes_allocated(56);
+            Harness::assert_exact_bytes_allocated(72);
         }
 
         *root.inner.inner.borrow_mut() = None;
@@ -210,7 +210,7 @@ fn eph_self_referential_chain() {
 
             assert!(eph_start.value().is_some());
             assert!(eph_chain2.value().is_some());
-            Harness::assert_exact_bytes_allocated(168);
+            Harness::assert_exact_bytes_allocated(216);
         }
 
         *root.borrow_mut() = None;
@@ -290,3 +290,48 @@ fn eph_gc_finalizer() {
         assert_eq!(val.inner.get(), 1);
     });
 }
+
+#[test]
+fn eph_value_reachable_from_live_key() {
+    #[derive(Trace)]
+    struct S {
+        #[unsafe_ignore_trace]
+        inner: Rc<Cell<u8>>,
+        next: Option<Gc<S>>,
+    }
+
+    impl Finalize for S {
+        fn finalize(&self) {
+            self.inner.set(self.inner.get() + 1);
+        }
+    }
+
+    run_test(|| {
+        let finalized = Rc::new(Cell::new(0));
+
+        let key = Gc::new(50u32);
+        let tail = Gc::new(S {
+            inner: finalized.clone(),
+            next: None,
+        });
+        let eph = Ephemeron::new(
+            &key,
+            Gc::new(S {
+                inner: finalized.clone(),
+                next: Some(tail),
+            }),
+        );
+
+        // The value and everything it references are only reachable through the ephemeron.
+        force_collect();
+        assert_eq!(finalized.get(), 0);
+        let value = eph.value().expect("the key is still live");
+        assert!(value.next.is_some());
+        drop(value);
+
+        drop(key);
+        force_collect();
+        assert!(!eph.has_value());
+        assert_eq!(finalized.get(), 2);
+    });
+}
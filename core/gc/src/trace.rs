@@ -14,23 +14,72 @@ use std::{
     sync::atomic,
 };
 
-use crate::GcErasedPointer;
+use crate::{
+    heap::HeapInner,
+    internals::GcHeader,
+    snapshot::{EdgeName, NodeKind, Recorder},
+    CollectionKind, GcErasedPointer,
+};
 
 /// A queue used to trace [`crate::Gc<T>`] non-recursively.
+///
+/// Only the values of the heap being traced are queued, since the values of other heaps are kept
+/// alive by their own heap. A minor collection only queues the values of the nursery.
+///
+/// While taking a [`HeapSnapshot`][crate::HeapSnapshot], the tracer records the edges of the
+/// traced value instead, which can be described with [`Tracer::describe_node`] and
+/// [`Tracer::push_edge`].
 #[doc(hidden)]
 #[allow(missing_debug_implementations)]
 pub struct Tracer {
+    heap: *const HeapInner,
+    kind: CollectionKind,
     queue: VecDeque<GcErasedPointer>,
+    recorder: Option<Recorder>,
 }
 
 impl Tracer {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(heap: *const HeapInner, kind: CollectionKind) -> Self {
         Self {
+            heap,
+            kind,
             queue: VecDeque::default(),
+            recorder: None,
         }
     }
 
+    pub(crate) fn recording(heap: *const HeapInner) -> Self {
+        Self {
+            heap,
+            kind: CollectionKind::Major,
+            queue: VecDeque::default(),
+            recorder: Some(Recorder::default()),
+        }
+    }
+
+    /// Returns `true` if the value with the given header belongs to the heap being traced.
+    pub(crate) fn is_traced(&self, header: &GcHeader) -> bool {
+        std::ptr::eq(header.heap(), self.heap)
+            && (self.kind == CollectionKind::Major || header.is_young())
+    }
+
+    /// Returns `true` if the value with the given header must be kept alive even if it is not
+    /// reachable from a root, which is the case of the keys of ephemerons in a minor collection.
+    pub(crate) fn is_pinned(&self, header: &GcHeader) -> bool {
+        self.kind == CollectionKind::Minor && header.is_ephemeron_key()
+    }
+
     pub(crate) fn enqueue(&mut self, node: GcErasedPointer) {
+        // SAFETY: Only live values are traced.
+        if !self.is_traced(&unsafe { node.as_ref() }.header) {
+            return;
+        }
+
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(node);
+            return;
+        }
+
         self.queue.push_back(node);
     }
 
@@ -41,6 +90,62 @@ impl Tracer {
     pub(crate) fn is_empty(&mut self) -> bool {
         self.queue.is_empty()
     }
+
+    /// Takes the recording of the last traced value, leaving the tracer ready for the next one.
+    pub(crate) fn take_recording(&mut self) -> Recorder {
+        self.recorder
+            .as_mut()
+            .map(std::mem::take)
+            .expect("the tracer should be recording")
+    }
+
+    pub(crate) fn begin_weak(&mut self) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.begin_weak();
+        }
+    }
+
+    pub(crate) fn end_weak(&mut self) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.end_weak();
+        }
+    }
+
+    /// Returns `true` if the tracer is recording a heap snapshot instead of marking.
+    #[inline]
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// Sets the type and name of the value being traced in a heap snapshot.
+    ///
+    /// `name` is only called while recording a heap snapshot.
+    #[inline]
+    pub fn describe_node(&mut self, kind: NodeKind, name: impl FnOnce() -> String) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.describe_node(kind, name());
+        }
+    }
+
+    /// Names the edges traced until the matching call to [`Tracer::pop_edge`] in a heap
+    /// snapshot.
+    ///
+    /// `name` is only called while recording a heap snapshot.
+    #[inline]
+    pub fn push_edge(&mut self, name: impl FnOnce() -> EdgeName) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.push_edge(name());
+        }
+    }
+
+    /// Removes the name set by the last call to [`Tracer::push_edge`].
+    #[inline]
+    pub fn pop_edge(&mut self) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.pop_edge();
+        }
+    }
 }
 
 /// Substitute for the [`Drop`] trait for garbage collected types.
@@ -9,6 +9,7 @@
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{
+    ext::IdentExt,
     parse::{Parse, ParseStream},
     parse_macro_input,
     punctuated::Punctuated,
@@ -240,7 +241,28 @@ fn derive_trace(mut s: Structure<'_>) -> proc_macro2::TokenStream {
             .iter()
             .any(|attr| attr.path().is_ident("unsafe_ignore_trace"))
     });
-    let trace_body = s.each(|bi| quote!(::boa_gc::Trace::trace(#bi, tracer)));
+    let trace_body = s.each_variant(|variant| {
+        // Name the edges of named fields in heap snapshots, unless the variant only wraps a
+        // single field.
+        let named = variant.bindings().len() > 1;
+        variant
+            .bindings()
+            .iter()
+            .map(|bi| match &bi.ast().ident {
+                Some(name) if named => {
+                    let name = name.unraw().to_string();
+                    quote! {{
+                        tracer.push_edge(|| {
+                            ::boa_gc::EdgeName::Internal(::std::borrow::Cow::Borrowed(#name))
+                        });
+                        ::boa_gc::Trace::trace(#bi, tracer);
+                        tracer.pop_edge();
+                    }}
+                }
+                _ => quote!({ ::boa_gc::Trace::trace(#bi, tracer) }),
+            })
+            .collect::<proc_macro2::TokenStream>()
+    });
     let trace_other_body = s.each(|bi| quote!(mark(#bi)));
 
     s.add_bounds(AddBounds::Fields);
@@ -293,6 +293,10 @@ impl<R> Lexer<R> {
                     Punctuator::CloseBracket.into(),
                     Span::new(start, self.cursor.pos()),
                 )),
+                '@' => Ok(Token::new(
+                    Punctuator::At.into(),
+                    Span::new(start, self.cursor.pos()),
+                )),
                 '#' => PrivateIdentifier::new().lex(&mut self.cursor, start, interner),
                 '/' => self.lex_slash_token(start, interner),
                 #[cfg(feature = "annex-b")]
@@ -8,6 +8,7 @@ use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 use regress::{Flags, Regex};
 use std::str::{self, FromStr};
+use std::{borrow::Cow, ops::Range};
 
 /// Regex literal lexing.
 ///
@@ -126,7 +127,7 @@ impl<R> Tokenizer<R> for RegexLiteral {
             }
         }
 
-        if let Err(error) = Regex::from_unicode(body.into_iter(), flags_str) {
+        if let Err(error) = compile_regex(&body, flags_str) {
             return Err(Error::Syntax(
                 format!("Invalid regular expression literal: {error}").into(),
                 start_pos,
@@ -260,3 +261,337 @@ impl From<RegExpFlags> for Flags {
         }
     }
 }
+
+/// A named capture group of a regular expression pattern.
+///
+/// A pattern may contain several capture groups with the same name, as long as they are in
+/// different alternatives of a disjunction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedCaptureGroup {
+    name: String,
+    indices: Box<[usize]>,
+}
+
+impl NamedCaptureGroup {
+    /// Gets the name of the capture group.
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the indices of all the capture groups with this name, in ascending order.
+    ///
+    /// At most one of these groups can participate in a match.
+    #[inline]
+    #[must_use]
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+}
+
+/// Compiles a regular expression pattern with `regress`.
+///
+/// Returns the compiled matcher and the named capture groups of the pattern, in the order their
+/// names first appear.
+///
+/// `regress` does not support duplicate named capture groups, so the pattern is rewritten before
+/// compiling it: every group that reuses the name of a previous group is given a unique name, and
+/// every named backreference is replaced by the numbered backreferences of the groups with that
+/// name.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// # Errors
+///
+/// Returns an error if the pattern is not a valid regular expression.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors
+pub fn compile_regex<F>(
+    pattern: &[u32],
+    flags: F,
+) -> Result<(Regex, Box<[NamedCaptureGroup]>), regress::Error>
+where
+    F: Into<Flags>,
+{
+    let flags = flags.into();
+    let scan = PatternScan::new(pattern, flags.unicode_sets)?;
+    let groups = scan.named_capture_groups();
+    let pattern = scan.rewrite(pattern, &groups);
+
+    let matcher = Regex::from_unicode(pattern.iter().copied(), flags)?;
+
+    Ok((matcher, groups))
+}
+
+/// A named capture group, or a backreference to a named capture group, found in a pattern.
+#[derive(Debug)]
+struct NameOccurrence {
+    /// The name, with its unicode escapes resolved.
+    name: String,
+
+    /// The range of the pattern that must be rewritten for this occurrence.
+    range: Range<usize>,
+
+    /// The index of the capture group, or zero for backreferences.
+    index: usize,
+}
+
+/// The named capture groups of the current alternative and of the previous alternatives of a
+/// `Disjunction`.
+#[derive(Debug, Default)]
+struct Disjunction {
+    current: Vec<String>,
+    previous: Vec<String>,
+}
+
+/// The named capture groups and named backreferences of a pattern.
+#[derive(Debug, Default)]
+struct PatternScan {
+    groups: Vec<NameOccurrence>,
+    backreferences: Vec<NameOccurrence>,
+}
+
+impl PatternScan {
+    /// Scans a pattern for named capture groups and named backreferences.
+    ///
+    /// The rest of the syntax of the pattern is validated by `regress`, so this only returns an
+    /// error for two groups with the same name that might both participate in a match.
+    fn new(pattern: &[u32], unicode_sets: bool) -> Result<Self, regress::Error> {
+        let is = |i: usize, c: char| pattern.get(i) == Some(&u32::from(c));
+
+        let mut scan = Self::default();
+        let mut disjunctions = vec![Disjunction::default()];
+        let mut class_depth = 0usize;
+        let mut capture_count = 0;
+        let mut i = 0;
+
+        while i < pattern.len() {
+            if class_depth > 0 {
+                if is(i, '\\') {
+                    i += 1;
+                } else if is(i, '[') && unicode_sets {
+                    class_depth += 1;
+                } else if is(i, ']') {
+                    class_depth -= 1;
+                }
+                i += 1;
+                continue;
+            }
+
+            if is(i, '\\') {
+                if is(i + 1, 'k') && is(i + 2, '<') {
+                    if let Some((name, end)) = parse_group_name(pattern, i + 3) {
+                        scan.backreferences.push(NameOccurrence {
+                            name,
+                            range: i..end,
+                            index: 0,
+                        });
+                        i = end;
+                        continue;
+                    }
+                }
+                i += 2;
+                continue;
+            } else if is(i, '[') {
+                class_depth = 1;
+            } else if is(i, '(') {
+                if is(i + 1, '?') && is(i + 2, '<') && !is(i + 3, '=') && !is(i + 3, '!') {
+                    let Some((name, end)) = parse_group_name(pattern, i + 3) else {
+                        return Err(regress::Error {
+                            text: "Invalid capture group name".to_owned(),
+                        });
+                    };
+
+                    // It is a Syntax Error if Pattern contains two distinct GroupSpecifiers x and y
+                    // such that the CapturingGroupName of x is the CapturingGroupName of y and such
+                    // that MightBothParticipate(x, y) is true.
+                    for disjunction in disjunctions.iter().rev() {
+                        if disjunction.current.contains(&name) {
+                            return Err(regress::Error {
+                                text: "Duplicate capture group name".to_owned(),
+                            });
+                        }
+                        if disjunction.previous.contains(&name) {
+                            break;
+                        }
+                    }
+
+                    capture_count += 1;
+                    disjunctions
+                        .last_mut()
+                        .expect("there must be a disjunction for the pattern")
+                        .current
+                        .push(name.clone());
+                    scan.groups.push(NameOccurrence {
+                        name,
+                        range: i + 2..end,
+                        index: capture_count,
+                    });
+                    i = end;
+                } else {
+                    if !is(i + 1, '?') {
+                        capture_count += 1;
+                    }
+                    i += 1;
+                }
+                disjunctions.push(Disjunction::default());
+                continue;
+            } else if is(i, '|') {
+                let disjunction = disjunctions
+                    .last_mut()
+                    .expect("there must be a disjunction for the pattern");
+                let current = std::mem::take(&mut disjunction.current);
+                disjunction.previous.extend(current);
+            } else if is(i, ')') && disjunctions.len() > 1 {
+                let group = disjunctions
+                    .pop()
+                    .expect("there must be a disjunction for the group");
+                disjunctions
+                    .last_mut()
+                    .expect("there must be a disjunction for the pattern")
+                    .current
+                    .extend(group.previous.into_iter().chain(group.current));
+            }
+
+            i += 1;
+        }
+
+        Ok(scan)
+    }
+
+    /// Gets the named capture groups of the pattern, in the order their names first appear.
+    fn named_capture_groups(&self) -> Box<[NamedCaptureGroup]> {
+        let mut groups: Vec<(&str, Vec<usize>)> = Vec::new();
+        for group in &self.groups {
+            if let Some((_, indices)) = groups.iter_mut().find(|(name, _)| *name == group.name) {
+                indices.push(group.index);
+            } else {
+                groups.push((&group.name, vec![group.index]));
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|(name, indices)| NamedCaptureGroup {
+                name: name.to_owned(),
+                indices: indices.into_boxed_slice(),
+            })
+            .collect()
+    }
+
+    /// Rewrites the pattern into one that `regress` can compile.
+    fn rewrite<'a>(&self, pattern: &'a [u32], groups: &[NamedCaptureGroup]) -> Cow<'a, [u32]> {
+        let mut replacements = Vec::new();
+
+        for (i, group) in self.groups.iter().enumerate() {
+            if self.groups[..i].iter().any(|g| g.name == group.name) {
+                // The name only has to be unique, since the groups are looked up by index.
+                let mut suffix = i;
+                let name = loop {
+                    let name = format!("{}${suffix}", group.name);
+                    if !self.groups.iter().any(|g| g.name == name) {
+                        break name;
+                    }
+                    suffix += 1;
+                };
+                replacements.push((group.range.clone(), format!("<{name}>")));
+            }
+        }
+
+        for backreference in &self.backreferences {
+            let Some(group) = groups.iter().find(|g| g.name == backreference.name) else {
+                continue;
+            };
+
+            // Backreferences to groups that did not participate in the match always succeed, so
+            // matching all of the groups with the name is the same as matching the one that
+            // participated.
+            let mut replacement = String::from("(?:");
+            for index in &*group.indices {
+                replacement.push('\\');
+                replacement.push_str(&index.to_string());
+            }
+            replacement.push(')');
+            replacements.push((backreference.range.clone(), replacement));
+        }
+
+        if replacements.is_empty() {
+            return Cow::Borrowed(pattern);
+        }
+        replacements.sort_by_key(|(range, _)| range.start);
+
+        let mut result = Vec::with_capacity(pattern.len());
+        let mut last = 0;
+        for (range, replacement) in replacements {
+            result.extend_from_slice(&pattern[last..range.start]);
+            result.extend(replacement.chars().map(u32::from));
+            last = range.end;
+        }
+        result.extend_from_slice(&pattern[last..]);
+
+        Cow::Owned(result)
+    }
+}
+
+/// Parses a `GroupName` starting after its opening `<`.
+///
+/// Returns the name, with its unicode escapes resolved, and the index after the closing `>`.
+fn parse_group_name(pattern: &[u32], start: usize) -> Option<(String, usize)> {
+    let end = start + pattern[start..].iter().position(|&c| c == u32::from('>'))?;
+    let mut name = String::new();
+    let mut code_units = Vec::new();
+    let mut i = start;
+
+    while i < end {
+        if pattern[i] == u32::from('\\') && pattern.get(i + 1) == Some(&u32::from('u')) {
+            let (value, next) = parse_unicode_escape(pattern, i + 2, end)?;
+            i = next;
+            if let Ok(unit) = u16::try_from(value) {
+                code_units.push(unit);
+                continue;
+            }
+            name.extend(char::decode_utf16(code_units.drain(..)).map(|c| c.unwrap_or('\u{FFFD}')));
+            name.push(char::from_u32(value)?);
+        } else {
+            name.extend(char::decode_utf16(code_units.drain(..)).map(|c| c.unwrap_or('\u{FFFD}')));
+            name.push(char::from_u32(pattern[i])?);
+            i += 1;
+        }
+    }
+    name.extend(char::decode_utf16(code_units).map(|c| c.unwrap_or('\u{FFFD}')));
+
+    Some((name, end + 1))
+}
+
+/// Parses the value of a `\u` escape starting after the `u`, returning the value and the index
+/// after the escape.
+fn parse_unicode_escape(pattern: &[u32], start: usize, end: usize) -> Option<(u32, usize)> {
+    let digit = |i: usize| {
+        pattern
+            .get(i)
+            .filter(|_| i < end)
+            .and_then(|&c| char::from_u32(c))
+            .and_then(|c| c.to_digit(16))
+    };
+
+    if pattern.get(start) == Some(&u32::from('{')) {
+        let mut value = 0u32;
+        let mut i = start + 1;
+        while let Some(d) = digit(i) {
+            value = value.checked_mul(16)?.checked_add(d)?;
+            i += 1;
+        }
+        if i == start + 1 || pattern.get(i) != Some(&u32::from('}')) {
+            return None;
+        }
+        Some((value, i + 1))
+    } else {
+        let mut value = 0;
+        for i in start..start + 4 {
+            value = value * 16 + digit(i)?;
+        }
+        Some((value, start + 4))
+    }
+}
@@ -698,6 +698,26 @@ fn regex_literal_flags_err() {
         .expect_err("Lexer did not handle regex literal with error");
 }
 
+#[test]
+fn regex_literal_duplicate_named_groups() {
+    let mut lexer = Lexer::from(&br"/(?<a>x)|(?<a>y)/"[..]);
+    let interner = &mut Interner::default();
+
+    let expected = [TokenKind::regular_expression_literal(
+        interner.get_or_intern_static("(?<a>x)|(?<a>y)", utf16!("(?<a>x)|(?<a>y)")),
+        Sym::EMPTY_STRING,
+    )];
+
+    expect_tokens(&mut lexer, &expected, interner);
+
+    let mut lexer = Lexer::from(&br"/(?<a>x)(?:(?<a>y)|z)/"[..]);
+    let interner = &mut Interner::default();
+
+    lexer
+        .next(interner)
+        .expect_err("Lexer did not handle regex literal with duplicate group names");
+}
+
 #[test]
 fn addition_no_spaces() {
     let mut lexer = Lexer::from(&b"1+1"[..]);
@@ -24,7 +24,7 @@ use boa_ast::{
         access::{PrivatePropertyAccess, SimplePropertyAccess},
         Call,
     },
-    Punctuator,
+    Position, Punctuator,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -40,14 +40,19 @@ pub(super) struct CallExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     first_member_expr: ast::Expression,
+    position: Position,
 }
 
 impl CallExpression {
     /// Creates a new `CallExpression` parser.
+    ///
+    /// `position` is the start of the first member expression, and is recorded as the
+    /// source position of the call.
     pub(super) fn new<Y, A>(
         allow_yield: Y,
         allow_await: A,
         first_member_expr: ast::Expression,
+        position: Position,
     ) -> Self
     where
         Y: Into<AllowYield>,
@@ -57,6 +62,7 @@ impl CallExpression {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             first_member_expr,
+            position,
         }
     }
 }
@@ -75,7 +81,9 @@ where
         let lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            Call::new(self.first_member_expr, args).into()
+            Call::new(self.first_member_expr, args)
+                .with_position(self.position)
+                .into()
         } else {
             let next_token = cursor.next(interner)?.expect("token vanished");
             return Err(Error::expected(
@@ -128,7 +136,9 @@ where
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
                     let args = Arguments::new(self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                    lhs = ast::Expression::from(Call::new(lhs, args));
+                    lhs = ast::Expression::from(
+                        Call::new(lhs, args).with_position(token.span().start()),
+                    );
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.advance(interner);
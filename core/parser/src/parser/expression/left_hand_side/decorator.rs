@@ -0,0 +1,135 @@
+//! Decorator parsing.
+//!
+//! More information:
+//!  - [Proposal reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-decorators/#prod-Decorator
+
+use super::arguments::Arguments;
+use crate::{
+    lexer::TokenKind,
+    parser::{
+        expression::{identifiers::IdentifierReference, Expression},
+        AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
+    },
+    source::ReadChar,
+    Error,
+};
+use boa_ast::{
+    self as ast,
+    expression::{
+        access::{PrivatePropertyAccess, SimplePropertyAccess},
+        Call, Parenthesized,
+    },
+    function::{Decorator, PrivateName},
+    Punctuator,
+};
+use boa_interner::{Interner, Sym};
+use boa_profiler::Profiler;
+
+/// Parses a list of decorators.
+///
+/// More information:
+///  - [Proposal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-DecoratorList
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct DecoratorList {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl DecoratorList {
+    /// Creates a new `DecoratorList` parser.
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for DecoratorList
+where
+    R: ReadChar,
+{
+    type Output = Box<[Decorator]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let _timer = Profiler::global().start_event("DecoratorList", "Parsing");
+
+        let mut decorators = Vec::new();
+        while cursor.next_if(Punctuator::At, interner)?.is_some() {
+            let expression = if cursor.peek(0, interner).or_abrupt()?.kind()
+                == &TokenKind::Punctuator(Punctuator::OpenParen)
+            {
+                // DecoratorParenthesizedExpression : ( Expression )
+                cursor.advance(interner);
+                let expression = Expression::new(None, true, self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                cursor.expect(Punctuator::CloseParen, "decorator", interner)?;
+                ast::Expression::Parenthesized(Parenthesized::new(expression))
+            } else {
+                // DecoratorMemberExpression : IdentifierReference
+                let mut expression: ast::Expression =
+                    IdentifierReference::new(self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?
+                        .into();
+
+                // DecoratorMemberExpression :
+                //     DecoratorMemberExpression . IdentifierName
+                //     DecoratorMemberExpression . PrivateIdentifier
+                while cursor.next_if(Punctuator::Dot, interner)?.is_some() {
+                    let token = cursor.next(interner).or_abrupt()?;
+                    let access = match token.kind() {
+                        TokenKind::IdentifierName((name, _)) => {
+                            SimplePropertyAccess::new(expression, *name).into()
+                        }
+                        TokenKind::Keyword((kw, _)) => {
+                            SimplePropertyAccess::new(expression, kw.to_sym()).into()
+                        }
+                        TokenKind::BooleanLiteral((true, _)) => {
+                            SimplePropertyAccess::new(expression, Sym::TRUE).into()
+                        }
+                        TokenKind::BooleanLiteral((false, _)) => {
+                            SimplePropertyAccess::new(expression, Sym::FALSE).into()
+                        }
+                        TokenKind::NullLiteral(_) => {
+                            SimplePropertyAccess::new(expression, Sym::NULL).into()
+                        }
+                        TokenKind::PrivateIdentifier(name) => {
+                            PrivatePropertyAccess::new(expression, PrivateName::new(*name)).into()
+                        }
+                        _ => {
+                            return Err(Error::expected(
+                                ["identifier".to_owned()],
+                                token.to_string(interner),
+                                token.span(),
+                                "decorator",
+                            ));
+                        }
+                    };
+                    expression = ast::Expression::PropertyAccess(access);
+                }
+
+                // DecoratorCallExpression : DecoratorMemberExpression Arguments
+                if cursor.peek(0, interner).or_abrupt()?.kind()
+                    == &TokenKind::Punctuator(Punctuator::OpenParen)
+                {
+                    let args = Arguments::new(self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                    expression = Call::new(expression, args).into();
+                }
+
+                expression
+            };
+            decorators.push(Decorator::new(expression));
+        }
+
+        Ok(decorators.into_boxed_slice())
+    }
+}
@@ -12,6 +12,7 @@ mod tests;
 
 mod arguments;
 mod call;
+mod decorator;
 mod member;
 mod optional;
 mod template;
@@ -28,7 +29,7 @@ use crate::{
             },
             AssignmentExpression,
         },
-        AllowAwait, AllowYield, Cursor, ParseResult, TokenParser,
+        AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
     Error,
@@ -40,6 +41,8 @@ use boa_ast::{
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 
+pub(in crate::parser) use self::decorator::DecoratorList;
+
 /// Parses a left hand side expression.
 ///
 /// More information:
@@ -133,25 +136,41 @@ where
             let arg = AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
+            // `,`
+            let mut options = None;
+            if cursor.next_if(Punctuator::Comma, interner)?.is_some()
+                && cursor.peek(0, interner).or_abrupt()?.kind()
+                    != &TokenKind::Punctuator(Punctuator::CloseParen)
+            {
+                options = Some(
+                    AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?,
+                );
+                cursor.next_if(Punctuator::Comma, interner)?;
+            }
+
             cursor.expect(
                 TokenKind::Punctuator(Punctuator::CloseParen),
                 "import call",
                 interner,
             )?;
 
-            CallExpressionTail::new(
-                self.allow_yield,
-                self.allow_await,
-                ImportCall::new(arg).into(),
-            )
-            .parse(cursor, interner)?
+            let call = match options {
+                Some(options) => ImportCall::with_options(arg, options),
+                None => ImportCall::new(arg),
+            };
+
+            CallExpressionTail::new(self.allow_yield, self.allow_await, call.into())
+                .parse(cursor, interner)?
         } else {
+            let position = cursor.peek(0, interner).or_abrupt()?.span().start();
             let mut member = MemberExpression::new(self.name, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
             if let Some(tok) = cursor.peek(0, interner)? {
                 if tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                    member = CallExpression::new(self.allow_yield, self.allow_await, member)
-                        .parse(cursor, interner)?;
+                    member =
+                        CallExpression::new(self.allow_yield, self.allow_await, member, position)
+                            .parse(cursor, interner)?;
                 }
             }
             member
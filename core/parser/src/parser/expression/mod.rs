@@ -46,7 +46,7 @@ use boa_profiler::Profiler;
 pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
 pub(in crate::parser) use {
     identifiers::{BindingIdentifier, LabelIdentifier},
-    left_hand_side::LeftHandSideExpression,
+    left_hand_side::{DecoratorList, LeftHandSideExpression},
     primary::object_initializer::{
         AsyncGeneratorMethod, AsyncMethod, GeneratorMethod, PropertyName,
     },
@@ -6,7 +6,11 @@ use crate::{
     },
     source::ReadChar,
 };
-use boa_ast::{expression::Identifier, function::Class, Keyword};
+use boa_ast::{
+    expression::Identifier,
+    function::{Class, Decorator},
+    Keyword,
+};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 
@@ -16,16 +20,22 @@ use boa_profiler::Profiler;
 ///  - [ECMAScript specification][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-ClassExpression
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(super) struct ClassExpression {
     name: Option<Identifier>,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    decorators: Box<[Decorator]>,
 }
 
 impl ClassExpression {
     /// Creates a new `ClassExpression` parser.
-    pub(in crate::parser) fn new<N, Y, A>(name: N, allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::parser) fn new<N, Y, A>(
+        name: N,
+        allow_yield: Y,
+        allow_await: A,
+        decorators: Box<[Decorator]>,
+    ) -> Self
     where
         N: Into<Option<Identifier>>,
         Y: Into<AllowYield>,
@@ -35,6 +45,7 @@ impl ClassExpression {
             name: name.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            decorators,
         }
     }
 }
@@ -69,6 +80,7 @@ where
             has_binding_identifier,
             self.allow_yield,
             self.allow_await,
+            self.decorators,
         )
         .parse(cursor, interner)
     }
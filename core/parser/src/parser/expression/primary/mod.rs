@@ -34,7 +34,7 @@ use crate::{
     parser::{
         expression::{
             identifiers::IdentifierReference, primary::template::TemplateLiteral,
-            BindingIdentifier, Expression,
+            BindingIdentifier, DecoratorList, Expression,
         },
         statement::{ArrayBindingPattern, ObjectBindingPattern},
         AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
@@ -132,7 +132,20 @@ where
             }
             TokenKind::Keyword((Keyword::Class, _)) => {
                 cursor.advance(interner);
-                ClassExpression::new(self.name, self.allow_yield, self.allow_await)
+                ClassExpression::new(
+                    self.name,
+                    self.allow_yield,
+                    self.allow_await,
+                    Box::default(),
+                )
+                .parse(cursor, interner)
+                .map(Into::into)
+            }
+            TokenKind::Punctuator(Punctuator::At) => {
+                let decorators = DecoratorList::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                cursor.expect((Keyword::Class, false), "class expression", interner)?;
+                ClassExpression::new(self.name, self.allow_yield, self.allow_await, decorators)
                     .parse(cursor, interner)
                     .map(Into::into)
             }
@@ -373,6 +373,15 @@ where
             )));
         }
 
+        // It is a Syntax Error if StatementList Contains UsingDeclaration at the top level of a
+        // script.
+        if body.contains_using_declaration() {
+            return Err(Error::general(
+                "using declarations are not allowed at the top level of a script",
+                Position::new(1, 1),
+            ));
+        }
+
         Ok(body)
     }
 }
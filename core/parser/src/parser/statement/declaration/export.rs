@@ -28,7 +28,7 @@ use boa_profiler::Profiler;
 
 use super::{
     hoistable::{AsyncFunctionDeclaration, AsyncGeneratorDeclaration, GeneratorDeclaration},
-    Declaration, FromClause, FunctionDeclaration,
+    Declaration, FromClause, FunctionDeclaration, WithClause,
 };
 
 /// Parses an export declaration.
@@ -81,19 +81,25 @@ where
 
                         let specifier =
                             FromClause::new("export declaration").parse(cursor, interner)?;
+                        let attributes =
+                            WithClause::new("export declaration").parse(cursor, interner)?;
 
                         AstExportDeclaration::ReExport {
                             kind: ReExportKind::Namespaced { name: Some(alias) },
                             specifier,
+                            attributes,
                         }
                     }
                     TokenKind::IdentifierName((Sym::FROM, _)) => {
                         let specifier =
                             FromClause::new("export declaration").parse(cursor, interner)?;
+                        let attributes =
+                            WithClause::new("export declaration").parse(cursor, interner)?;
 
                         AstExportDeclaration::ReExport {
                             kind: ReExportKind::Namespaced { name: None },
                             specifier,
+                            attributes,
                         }
                     }
                     _ => {
@@ -121,12 +127,15 @@ where
                 ) {
                     let specifier =
                         FromClause::new("export declaration").parse(cursor, interner)?;
+                    let attributes =
+                        WithClause::new("export declaration").parse(cursor, interner)?;
 
                     cursor.expect_semicolon("named re-exports", interner)?;
 
                     AstExportDeclaration::ReExport {
                         kind: ReExportKind::Named { names },
                         specifier,
+                        attributes,
                     }
                 } else {
                     cursor.expect_semicolon("named exports", interner)?;
@@ -198,7 +207,8 @@ where
                             )
                         }
                     }
-                    TokenKind::Keyword((Keyword::Class, false)) => {
+                    TokenKind::Keyword((Keyword::Class, false))
+                    | TokenKind::Punctuator(Punctuator::At) => {
                         AstExportDeclaration::DefaultClassDeclaration(
                             ClassDeclaration::new(false, true, true).parse(cursor, interner)?,
                         )
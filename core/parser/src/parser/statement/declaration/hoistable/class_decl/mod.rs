@@ -6,7 +6,7 @@ use crate::{
     parser::{
         expression::{
             AssignmentExpression, AsyncGeneratorMethod, AsyncMethod, BindingIdentifier,
-            GeneratorMethod, LeftHandSideExpression, PropertyName,
+            DecoratorList, GeneratorMethod, LeftHandSideExpression, PropertyName,
         },
         function::{FunctionBody, UniqueFormalParameters, FUNCTION_BREAK_TOKENS},
         statement::StatementList,
@@ -24,7 +24,7 @@ use ast::{
 use boa_ast::{
     self as ast,
     expression::Identifier,
-    function::{self, Class, FormalParameterList, Function},
+    function::{self, Class, Decorator, FormalParameterList, Function},
     operations::{contains, contains_arguments, has_direct_super, ContainsSymbol},
     property::{ClassElementName, MethodDefinition},
     Expression, Keyword, Punctuator,
@@ -41,11 +41,12 @@ use rustc_hash::{FxHashMap, FxHashSet};
 ///
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
 /// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(in crate::parser) struct ClassDeclaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     is_default: AllowDefault,
+    decorators: Box<[Decorator]>,
 }
 
 impl ClassDeclaration {
@@ -60,8 +61,15 @@ impl ClassDeclaration {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             is_default: is_default.into(),
+            decorators: Box::default(),
         }
     }
+
+    /// Sets the decorators that were parsed before the `export` keyword of the declaration.
+    pub(in crate::parser) fn with_decorators(mut self, decorators: Box<[Decorator]>) -> Self {
+        self.decorators = decorators;
+        self
+    }
 }
 
 impl<R> TokenParser<R> for ClassDeclaration
@@ -71,6 +79,20 @@ where
     type Output = Class;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let position = cursor.peek(0, interner).or_abrupt()?.span().start();
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+        let decorators = match (self.decorators, decorators) {
+            (before, after) if after.is_empty() => before,
+            (before, after) if before.is_empty() => after,
+            _ => {
+                return Err(Error::general(
+                    "decorators cannot appear both before and after `export`",
+                    position,
+                ))
+            }
+        };
+
         cursor.expect((Keyword::Class, false), "class declaration", interner)?;
         let strict = cursor.strict();
         cursor.set_strict(true);
@@ -100,6 +122,7 @@ where
             has_binding_identifier,
             self.allow_yield,
             self.allow_await,
+            decorators,
         )
         .parse(cursor, interner)
     }
@@ -111,12 +134,13 @@ where
 ///  - [ECMAScript specification][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-ClassTail
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(in crate::parser) struct ClassTail {
     name: Option<Identifier>,
     has_binding_identifier: bool,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    decorators: Box<[Decorator]>,
 }
 
 impl ClassTail {
@@ -126,6 +150,7 @@ impl ClassTail {
         has_binding_identifier: bool,
         allow_yield: Y,
         allow_await: A,
+        decorators: Box<[Decorator]>,
     ) -> Self
     where
         N: Into<Option<Identifier>>,
@@ -137,6 +162,7 @@ impl ClassTail {
             has_binding_identifier,
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            decorators,
         }
     }
 }
@@ -179,6 +205,7 @@ where
                 None,
                 Box::default(),
                 self.has_binding_identifier,
+                self.decorators,
             ))
         } else {
             let body_start = cursor.peek(0, interner).or_abrupt()?.span().start();
@@ -204,6 +231,7 @@ where
                 constructor,
                 elements.into(),
                 self.has_binding_identifier,
+                self.decorators,
             ))
         }
     }
@@ -321,7 +349,7 @@ where
                     }
                     (None, Some(element)) => {
                         match &element {
-                            function::ClassElement::PrivateMethodDefinition(name, method) => {
+                            function::ClassElement::PrivateMethodDefinition(name, method, _) => {
                                 // It is a Syntax Error if PropName of MethodDefinition is not "constructor" and HasDirectSuper of MethodDefinition is true.
                                 if has_direct_super(method) {
                                     return Err(Error::lex(LexError::Syntax(
@@ -387,7 +415,11 @@ where
                                     }
                                 }
                             }
-                            function::ClassElement::PrivateStaticMethodDefinition(name, method) => {
+                            function::ClassElement::PrivateStaticMethodDefinition(
+                                name,
+                                method,
+                                _,
+                            ) => {
                                 // It is a Syntax Error if HasDirectSuper of MethodDefinition is true.
                                 if has_direct_super(method) {
                                     return Err(Error::lex(LexError::Syntax(
@@ -453,7 +485,8 @@ where
                                     }
                                 }
                             }
-                            function::ClassElement::PrivateFieldDefinition(name, init) => {
+                            function::ClassElement::PrivateFieldDefinition(name, init, _)
+                            | function::ClassElement::PrivateAccessorDefinition(name, init, _) => {
                                 if let Some(node) = init {
                                     if contains(node, ContainsSymbol::SuperCall) {
                                         return Err(Error::lex(LexError::Syntax(
@@ -472,7 +505,12 @@ where
                                     ));
                                 }
                             }
-                            function::ClassElement::PrivateStaticFieldDefinition(name, init) => {
+                            function::ClassElement::PrivateStaticFieldDefinition(name, init, _)
+                            | function::ClassElement::PrivateStaticAccessorDefinition(
+                                name,
+                                init,
+                                _,
+                            ) => {
                                 if let Some(node) = init {
                                     if contains(node, ContainsSymbol::SuperCall) {
                                         return Err(Error::lex(LexError::Syntax(
@@ -491,8 +529,8 @@ where
                                     ));
                                 }
                             }
-                            function::ClassElement::MethodDefinition(_, method)
-                            | function::ClassElement::StaticMethodDefinition(_, method) => {
+                            function::ClassElement::MethodDefinition(_, method, _)
+                            | function::ClassElement::StaticMethodDefinition(_, method, _) => {
                                 // ClassElement : MethodDefinition:
                                 //  It is a Syntax Error if PropName of MethodDefinition is not "constructor" and HasDirectSuper of MethodDefinition is true.
                                 // ClassElement : static MethodDefinition:
@@ -504,8 +542,10 @@ where
                                     )));
                                 }
                             }
-                            function::ClassElement::FieldDefinition(_, Some(node))
-                            | function::ClassElement::StaticFieldDefinition(_, Some(node)) => {
+                            function::ClassElement::FieldDefinition(_, Some(node), _)
+                            | function::ClassElement::StaticFieldDefinition(_, Some(node), _)
+                            | function::ClassElement::AccessorDefinition(_, Some(node), _)
+                            | function::ClassElement::StaticAccessorDefinition(_, Some(node), _) => {
                                 if contains(node, ContainsSymbol::SuperCall) {
                                     return Err(Error::lex(LexError::Syntax(
                                         "invalid super usage".into(),
@@ -575,9 +615,11 @@ where
     type Output = (Option<Function>, Option<function::ClassElement>);
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
         let token = cursor.peek(0, interner).or_abrupt()?;
         let r#static = match token.kind() {
-            TokenKind::Punctuator(Punctuator::Semicolon) => {
+            TokenKind::Punctuator(Punctuator::Semicolon) if decorators.is_empty() => {
                 cursor.advance(interner);
                 return Ok((None, None));
             }
@@ -620,10 +662,119 @@ where
             )
         );
 
+        // ClassElement : accessor [no LineTerminator here] ClassElementName Initializer[opt] ;
+        let skip_n = if cursor.peek_is_line_terminator(0, interner).or_abrupt()? {
+            2
+        } else {
+            1
+        };
+        let is_accessor = matches!(
+            cursor.peek(0, interner).or_abrupt()?.kind(),
+            TokenKind::IdentifierName((Sym::ACCESSOR, ContainsEscapeSequence(false)))
+        ) && cursor.peek_is_line_terminator(skip_n, interner)? == Some(false)
+            && matches!(
+                cursor.peek(1, interner).or_abrupt()?.kind(),
+                TokenKind::IdentifierName(_)
+                    | TokenKind::StringLiteral(_)
+                    | TokenKind::NumericLiteral(_)
+                    | TokenKind::Keyword(_)
+                    | TokenKind::NullLiteral(_)
+                    | TokenKind::PrivateIdentifier(_)
+                    | TokenKind::Punctuator(Punctuator::OpenBracket)
+            );
+
         let token = cursor.peek(0, interner).or_abrupt()?;
         let position = token.span().start();
         let element = match token.kind() {
+            TokenKind::IdentifierName((Sym::ACCESSOR, _)) if is_accessor => {
+                cursor.advance(interner);
+                let token = cursor.peek(0, interner).or_abrupt()?;
+                let name_position = token.span().start();
+                let (name, function_name) = match token.kind() {
+                    TokenKind::PrivateIdentifier(Sym::CONSTRUCTOR) => {
+                        return Err(Error::general(
+                            "class constructor may not be a private method",
+                            name_position,
+                        ))
+                    }
+                    TokenKind::PrivateIdentifier(name) => {
+                        let name = *name;
+                        let name_private = interner.get_or_intern(
+                            [utf16!("#"), interner.resolve_expect(name).utf16()]
+                                .concat()
+                                .as_slice(),
+                        );
+                        cursor.advance(interner);
+                        (
+                            ClassElementName::PrivateIdentifier(PrivateName::new(name)),
+                            Some(name_private.into()),
+                        )
+                    }
+                    _ => {
+                        let name = PropertyName::new(self.allow_yield, self.allow_await)
+                            .parse(cursor, interner)?;
+                        if let Some(name) = name.literal() {
+                            if r#static {
+                                if [Sym::CONSTRUCTOR, Sym::PROTOTYPE].contains(&name) {
+                                    return Err(Error::general(
+                                        "class may not have static accessor definitions named 'constructor' or 'prototype'",
+                                        name_position,
+                                    ));
+                                }
+                            } else if name == Sym::CONSTRUCTOR {
+                                return Err(Error::general(
+                                    "class may not have accessor definitions named 'constructor'",
+                                    name_position,
+                                ));
+                            }
+                        }
+                        let function_name = name.literal().map(Into::into);
+                        (ClassElementName::PropertyName(name), function_name)
+                    }
+                };
+
+                let strict = cursor.strict();
+                cursor.set_strict(true);
+                let rhs = if cursor.next_if(Punctuator::Assign, interner)?.is_some() {
+                    Some(
+                        AssignmentExpression::new(
+                            function_name,
+                            true,
+                            self.allow_yield,
+                            self.allow_await,
+                        )
+                        .parse(cursor, interner)?,
+                    )
+                } else {
+                    None
+                };
+                cursor.expect_semicolon("expected semicolon", interner)?;
+                cursor.set_strict(strict);
+
+                match name {
+                    ClassElementName::PropertyName(name) if r#static => {
+                        function::ClassElement::StaticAccessorDefinition(name, rhs, decorators)
+                    }
+                    ClassElementName::PropertyName(name) => {
+                        function::ClassElement::AccessorDefinition(name, rhs, decorators)
+                    }
+                    ClassElementName::PrivateIdentifier(name) if r#static => {
+                        function::ClassElement::PrivateStaticAccessorDefinition(
+                            name, rhs, decorators,
+                        )
+                    }
+                    ClassElementName::PrivateIdentifier(name) => {
+                        function::ClassElement::PrivateAccessorDefinition(name, rhs, decorators)
+                    }
+                }
+            }
             TokenKind::IdentifierName((Sym::CONSTRUCTOR, _)) if !r#static => {
+                if !decorators.is_empty() {
+                    return Err(Error::general(
+                        "decorators are not allowed on class constructors",
+                        position,
+                    ));
+                }
                 cursor.advance(interner);
                 let strict = cursor.strict();
                 cursor.set_strict(true);
@@ -646,6 +797,12 @@ where
                 return Ok((Some(Function::new(self.name, parameters, body)), None));
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) if r#static => {
+                if !decorators.is_empty() {
+                    return Err(Error::general(
+                        "decorators are not allowed on class static blocks",
+                        position,
+                    ));
+                }
                 cursor.advance(interner);
                 let statement_list = if cursor
                     .next_if(TokenKind::Punctuator(Punctuator::CloseBlock), interner)?
@@ -758,10 +915,14 @@ where
                                 name_position,
                             ));
                         }
-                        function::ClassElement::StaticMethodDefinition(property_name, method)
+                        function::ClassElement::StaticMethodDefinition(
+                            property_name,
+                            method,
+                            decorators,
+                        )
                     }
                     ClassElementName::PropertyName(property_name) => {
-                        function::ClassElement::MethodDefinition(property_name, method)
+                        function::ClassElement::MethodDefinition(property_name, method, decorators)
                     }
                     ClassElementName::PrivateIdentifier(name)
                         if name.description() == Sym::CONSTRUCTOR =>
@@ -772,10 +933,18 @@ where
                         ))
                     }
                     ClassElementName::PrivateIdentifier(private_ident) if r#static => {
-                        function::ClassElement::PrivateStaticMethodDefinition(private_ident, method)
+                        function::ClassElement::PrivateStaticMethodDefinition(
+                            private_ident,
+                            method,
+                            decorators,
+                        )
                     }
                     ClassElementName::PrivateIdentifier(private_ident) => {
-                        function::ClassElement::PrivateMethodDefinition(private_ident, method)
+                        function::ClassElement::PrivateMethodDefinition(
+                            private_ident,
+                            method,
+                            decorators,
+                        )
                     }
                 }
             }
@@ -825,21 +994,28 @@ where
                                 function::ClassElement::StaticMethodDefinition(
                                     property_name,
                                     method,
+                                    decorators,
                                 )
                             }
                             ClassElementName::PropertyName(property_name) => {
-                                function::ClassElement::MethodDefinition(property_name, method)
+                                function::ClassElement::MethodDefinition(
+                                    property_name,
+                                    method,
+                                    decorators,
+                                )
                             }
                             ClassElementName::PrivateIdentifier(private_ident) if r#static => {
                                 function::ClassElement::PrivateStaticMethodDefinition(
                                     private_ident,
                                     method,
+                                    decorators,
                                 )
                             }
                             ClassElementName::PrivateIdentifier(private_ident) => {
                                 function::ClassElement::PrivateMethodDefinition(
                                     private_ident,
                                     method,
+                                    decorators,
                                 )
                             }
                         }
@@ -870,10 +1046,15 @@ where
                                 function::ClassElement::StaticMethodDefinition(
                                     property_name,
                                     method,
+                                    decorators,
                                 )
                             }
                             ClassElementName::PropertyName(property_name) => {
-                                function::ClassElement::MethodDefinition(property_name, method)
+                                function::ClassElement::MethodDefinition(
+                                    property_name,
+                                    method,
+                                    decorators,
+                                )
                             }
                             ClassElementName::PrivateIdentifier(name)
                                 if name.description() == Sym::CONSTRUCTOR && r#static =>
@@ -885,11 +1066,13 @@ where
                             }
                             ClassElementName::PrivateIdentifier(identifier) if r#static => {
                                 function::ClassElement::PrivateStaticMethodDefinition(
-                                    identifier, method,
+                                    identifier, method, decorators,
                                 )
                             }
                             ClassElementName::PrivateIdentifier(identifier) => {
-                                function::ClassElement::PrivateMethodDefinition(identifier, method)
+                                function::ClassElement::PrivateMethodDefinition(
+                                    identifier, method, decorators,
+                                )
                             }
                         }
                     }
@@ -945,11 +1128,13 @@ where
                             function::ClassElement::PrivateStaticMethodDefinition(
                                 PrivateName::new(name),
                                 method,
+                                decorators,
                             )
                         } else {
                             function::ClassElement::PrivateMethodDefinition(
                                 PrivateName::new(name),
                                 method,
+                                decorators,
                             )
                         }
                     }
@@ -1005,9 +1190,9 @@ where
                                     name_position,
                                 ));
                             }
-                            function::ClassElement::StaticMethodDefinition(name, method)
+                            function::ClassElement::StaticMethodDefinition(name, method, decorators)
                         } else {
-                            function::ClassElement::MethodDefinition(name, method)
+                            function::ClassElement::MethodDefinition(name, method, decorators)
                         }
                     }
                     _ => {
@@ -1016,11 +1201,13 @@ where
                             function::ClassElement::StaticFieldDefinition(
                                 ast::property::PropertyName::Literal(Sym::GET),
                                 None,
+                                decorators,
                             )
                         } else {
                             function::ClassElement::FieldDefinition(
                                 ast::property::PropertyName::Literal(Sym::GET),
                                 None,
+                                decorators,
                             )
                         }
                     }
@@ -1076,11 +1263,13 @@ where
                             function::ClassElement::PrivateStaticMethodDefinition(
                                 PrivateName::new(name),
                                 method,
+                                decorators,
                             )
                         } else {
                             function::ClassElement::PrivateMethodDefinition(
                                 PrivateName::new(name),
                                 method,
+                                decorators,
                             )
                         }
                     }
@@ -1133,9 +1322,9 @@ where
                                     name_position,
                                 ));
                             }
-                            function::ClassElement::StaticMethodDefinition(name, method)
+                            function::ClassElement::StaticMethodDefinition(name, method, decorators)
                         } else {
-                            function::ClassElement::MethodDefinition(name, method)
+                            function::ClassElement::MethodDefinition(name, method, decorators)
                         }
                     }
                     _ => {
@@ -1144,11 +1333,13 @@ where
                             function::ClassElement::StaticFieldDefinition(
                                 ast::property::PropertyName::Literal(Sym::SET),
                                 None,
+                                decorators,
                             )
                         } else {
                             function::ClassElement::FieldDefinition(
                                 ast::property::PropertyName::Literal(Sym::SET),
                                 None,
+                                decorators,
                             )
                         }
                     }
@@ -1187,11 +1378,13 @@ where
                             function::ClassElement::PrivateStaticFieldDefinition(
                                 PrivateName::new(name),
                                 Some(rhs),
+                                decorators,
                             )
                         } else {
                             function::ClassElement::PrivateFieldDefinition(
                                 PrivateName::new(name),
                                 Some(rhs),
+                                decorators,
                             )
                         }
                     }
@@ -1227,11 +1420,13 @@ where
                             function::ClassElement::PrivateStaticMethodDefinition(
                                 PrivateName::new(name),
                                 method,
+                                decorators,
                             )
                         } else {
                             function::ClassElement::PrivateMethodDefinition(
                                 PrivateName::new(name),
                                 method,
+                                decorators,
                             )
                         }
                     }
@@ -1241,11 +1436,13 @@ where
                             function::ClassElement::PrivateStaticFieldDefinition(
                                 PrivateName::new(name),
                                 None,
+                                decorators,
                             )
                         } else {
                             function::ClassElement::PrivateFieldDefinition(
                                 PrivateName::new(name),
                                 None,
+                                decorators,
                             )
                         }
                     }
@@ -1291,9 +1488,13 @@ where
                         cursor.expect_semicolon("expected semicolon", interner)?;
                         cursor.set_strict(strict);
                         if r#static {
-                            function::ClassElement::StaticFieldDefinition(name, Some(rhs))
+                            function::ClassElement::StaticFieldDefinition(
+                                name,
+                                Some(rhs),
+                                decorators,
+                            )
                         } else {
-                            function::ClassElement::FieldDefinition(name, Some(rhs))
+                            function::ClassElement::FieldDefinition(name, Some(rhs), decorators)
                         }
                     }
                     TokenKind::Punctuator(Punctuator::OpenParen) => {
@@ -1331,9 +1532,9 @@ where
                         let method = MethodDefinition::Ordinary(Function::new(None, params, body));
                         cursor.set_strict(strict);
                         if r#static {
-                            function::ClassElement::StaticMethodDefinition(name, method)
+                            function::ClassElement::StaticMethodDefinition(name, method, decorators)
                         } else {
-                            function::ClassElement::MethodDefinition(name, method)
+                            function::ClassElement::MethodDefinition(name, method, decorators)
                         }
                     }
                     _ => {
@@ -1354,9 +1555,9 @@ where
                         }
                         cursor.expect_semicolon("expected semicolon", interner)?;
                         if r#static {
-                            function::ClassElement::StaticFieldDefinition(name, None)
+                            function::ClassElement::StaticFieldDefinition(name, None, decorators)
                         } else {
-                            function::ClassElement::FieldDefinition(name, None)
+                            function::ClassElement::FieldDefinition(name, None, decorators)
                         }
                     }
                 }
@@ -1367,10 +1568,14 @@ where
         match &element {
             // FieldDefinition : ClassElementName Initializer [opt]
             // It is a Syntax Error if Initializer is present and ContainsArguments of Initializer is true.
-            function::ClassElement::FieldDefinition(_, Some(node))
-            | function::ClassElement::StaticFieldDefinition(_, Some(node))
-            | function::ClassElement::PrivateFieldDefinition(_, Some(node))
-            | function::ClassElement::PrivateStaticFieldDefinition(_, Some(node)) => {
+            function::ClassElement::FieldDefinition(_, Some(node), _)
+            | function::ClassElement::StaticFieldDefinition(_, Some(node), _)
+            | function::ClassElement::PrivateFieldDefinition(_, Some(node), _)
+            | function::ClassElement::PrivateStaticFieldDefinition(_, Some(node), _)
+            | function::ClassElement::AccessorDefinition(_, Some(node), _)
+            | function::ClassElement::StaticAccessorDefinition(_, Some(node), _)
+            | function::ClassElement::PrivateAccessorDefinition(_, Some(node), _)
+            | function::ClassElement::PrivateStaticAccessorDefinition(_, Some(node), _) => {
                 if contains_arguments(node) {
                     return Err(Error::general(
                         "'arguments' not allowed in class field definition",
@@ -1,4 +1,4 @@
-use crate::parser::tests::check_script_parser;
+use crate::parser::tests::{check_invalid_script, check_script_parser};
 use boa_ast::{
     declaration::{LexicalDeclaration, Variable, VariableList},
     expression::{
@@ -6,7 +6,9 @@ use boa_ast::{
         literal::Literal,
         Call, Identifier,
     },
-    function::{Class, ClassElement, FormalParameterList, Function, FunctionBody},
+    function::{
+        Class, ClassElement, Decorator, FormalParameterList, Function, FunctionBody, PrivateName,
+    },
     property::{MethodDefinition, PropertyName},
     Declaration, Expression, Statement, StatementList, StatementListItem,
 };
@@ -24,6 +26,7 @@ fn check_async_ordinary_method() {
             FormalParameterList::default(),
             FunctionBody::default(),
         )),
+        Box::default(),
     )];
 
     check_script_parser(
@@ -37,6 +40,7 @@ fn check_async_ordinary_method() {
             None,
             elements.into(),
             true,
+            Box::default(),
         ))
         .into()],
         interner,
@@ -50,6 +54,7 @@ fn check_async_field_initialization() {
     let elements = vec![ClassElement::FieldDefinition(
         PropertyName::Literal(interner.get_or_intern_static("async", utf16!("async"))),
         Some(Literal::from(1).into()),
+        Box::default(),
     )];
 
     check_script_parser(
@@ -64,6 +69,7 @@ fn check_async_field_initialization() {
             None,
             elements.into(),
             true,
+            Box::default(),
         ))
         .into()],
         interner,
@@ -77,6 +83,7 @@ fn check_async_field() {
     let elements = vec![ClassElement::FieldDefinition(
         PropertyName::Literal(interner.get_or_intern_static("async", utf16!("async"))),
         None,
+        Box::default(),
     )];
 
     check_script_parser(
@@ -90,6 +97,7 @@ fn check_async_field() {
             None,
             elements.into(),
             true,
+            Box::default(),
         ))
         .into()],
         interner,
@@ -132,6 +140,7 @@ fn check_new_target_with_property_access() {
         Some(constructor),
         Box::default(),
         true,
+        Box::default(),
     );
 
     let instantiation = Expression::New(
@@ -171,3 +180,119 @@ fn check_new_target_with_property_access() {
         interner,
     );
 }
+
+#[test]
+fn check_decorators() {
+    let interner = &mut Interner::default();
+
+    let dec = Identifier::from(interner.get_or_intern_static("dec", utf16!("dec")));
+    let log = interner.get_or_intern_static("log", utf16!("log"));
+
+    let elements = vec![
+        ClassElement::MethodDefinition(
+            PropertyName::Literal(interner.get_or_intern_static("m", utf16!("m"))),
+            MethodDefinition::Ordinary(Function::new(
+                None,
+                FormalParameterList::default(),
+                FunctionBody::default(),
+            )),
+            [
+                Decorator::new(dec.into()),
+                Decorator::new(
+                    Call::new(
+                        PropertyAccess::Simple(SimplePropertyAccess::new(dec.into(), log)).into(),
+                        [Literal::from(1).into()].into(),
+                    )
+                    .into(),
+                ),
+            ]
+            .into(),
+        ),
+        ClassElement::StaticAccessorDefinition(
+            PropertyName::Literal(interner.get_or_intern_static("x", utf16!("x"))),
+            Some(Literal::from(1).into()),
+            [Decorator::new(dec.into())].into(),
+        ),
+        ClassElement::PrivateAccessorDefinition(
+            PrivateName::new(interner.get_or_intern_static("y", utf16!("y"))),
+            None,
+            Box::default(),
+        ),
+    ];
+
+    check_script_parser(
+        "@dec class A {
+            @dec @dec.log(1) m() { }
+            @dec static accessor x = 1;
+            accessor #y;
+         }
+        ",
+        [Declaration::Class(Class::new(
+            Some(interner.get_or_intern_static("A", utf16!("A")).into()),
+            None,
+            None,
+            elements.into(),
+            true,
+            [Decorator::new(dec.into())].into(),
+        ))
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_accessor_field() {
+    let interner = &mut Interner::default();
+
+    let accessor =
+        PropertyName::Literal(interner.get_or_intern_static("accessor", utf16!("accessor")));
+    let elements = vec![
+        ClassElement::FieldDefinition(accessor.clone(), None, Box::default()),
+        ClassElement::FieldDefinition(
+            PropertyName::Literal(interner.get_or_intern_static("x", utf16!("x"))),
+            None,
+            Box::default(),
+        ),
+        ClassElement::MethodDefinition(
+            accessor,
+            MethodDefinition::Ordinary(Function::new(
+                None,
+                FormalParameterList::default(),
+                FunctionBody::default(),
+            )),
+            Box::default(),
+        ),
+    ];
+
+    check_script_parser(
+        "class A {
+            accessor
+            x
+            accessor() { }
+         }
+        ",
+        [Declaration::Class(Class::new(
+            Some(interner.get_or_intern_static("A", utf16!("A")).into()),
+            None,
+            None,
+            elements.into(),
+            true,
+            Box::default(),
+        ))
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_invalid_decorators() {
+    check_invalid_script("class A { @dec constructor() {} }");
+    check_invalid_script("class A { @dec static {} }");
+    check_invalid_script("class A { @dec; }");
+    check_invalid_script("class A { @dec[0] m() {} }");
+    check_invalid_script("class A { accessor constructor; }");
+    check_invalid_script("class A { static accessor prototype; }");
+    check_invalid_script("class A { accessor #x; #x; }");
+    check_invalid_script("@dec let x = 1;");
+    check_invalid_script("if (true) @dec class A {}");
+}
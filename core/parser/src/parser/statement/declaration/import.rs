@@ -13,14 +13,17 @@ use crate::{
     lexer::TokenKind,
     parser::{
         cursor::Cursor,
-        statement::{declaration::FromClause, BindingIdentifier},
+        statement::{
+            declaration::{FromClause, WithClause},
+            BindingIdentifier,
+        },
         Error, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
 };
 use boa_ast::{
     declaration::{
-        ImportDeclaration as AstImportDeclaration, ImportKind,
+        ImportAttribute, ImportDeclaration as AstImportDeclaration, ImportKind,
         ImportSpecifier as AstImportSpecifier, ModuleSpecifier,
     },
     expression::Identifier,
@@ -87,12 +90,14 @@ where
                 let module_identifier = *module_identifier;
 
                 cursor.advance(interner);
+                let attributes = WithClause::new("import declaration").parse(cursor, interner)?;
                 cursor.expect_semicolon("import declaration", interner)?;
 
                 return Ok(AstImportDeclaration::new(
                     None,
                     ImportKind::DefaultOrUnnamed,
                     ModuleSpecifier::new(module_identifier),
+                    attributes,
                 ));
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
@@ -155,8 +160,10 @@ where
         };
 
         let module_identifier = FromClause::new("import declaration").parse(cursor, interner)?;
+        let attributes = WithClause::new("import declaration").parse(cursor, interner)?;
+        cursor.expect_semicolon("import declaration", interner)?;
 
-        Ok(import_clause.with_specifier(module_identifier))
+        Ok(import_clause.with_specifier(module_identifier, attributes))
     }
 }
 
@@ -261,19 +268,22 @@ enum ImportClause {
 impl ImportClause {
     #[inline]
     #[allow(clippy::missing_const_for_fn)]
-    fn with_specifier(self, specifier: ModuleSpecifier) -> AstImportDeclaration {
-        match self {
-            Self::Namespace(default, binding) => {
-                AstImportDeclaration::new(default, ImportKind::Namespaced { binding }, specifier)
-            }
+    fn with_specifier(
+        self,
+        specifier: ModuleSpecifier,
+        attributes: Box<[ImportAttribute]>,
+    ) -> AstImportDeclaration {
+        let (default, kind) = match self {
+            Self::Namespace(default, binding) => (default, ImportKind::Namespaced { binding }),
             Self::ImportList(default, names) => {
                 if names.is_empty() {
-                    AstImportDeclaration::new(default, ImportKind::DefaultOrUnnamed, specifier)
+                    (default, ImportKind::DefaultOrUnnamed)
                 } else {
-                    AstImportDeclaration::new(default, ImportKind::Named { names }, specifier)
+                    (default, ImportKind::Named { names })
                 }
             }
-        }
+        };
+        AstImportDeclaration::new(default, kind, specifier, attributes)
     }
 }
 
@@ -1,14 +1,16 @@
 //! Lexical declaration parsing.
 //!
-//! This parses `let` and `const` declarations.
+//! This parses `let`, `const`, `using` and `await using` declarations.
 //!
 //! More information:
 //!  - [ECMAScript specification][spec]
+//!  - [Explicit Resource Management proposal][proposal]
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-let-and-const-declarations
+//! [proposal]: https://tc39.es/proposal-explicit-resource-management/#sec-let-const-using-and-await-using-declarations
 
 use crate::{
-    lexer::{Error as LexError, TokenKind},
+    lexer::{token::ContainsEscapeSequence, Error as LexError, Token, TokenKind},
     parser::{
         cursor::{Cursor, SemicolonResult},
         expression::Initializer,
@@ -19,7 +21,12 @@ use crate::{
     Error,
 };
 use ast::operations::bound_names;
-use boa_ast::{self as ast, declaration::Variable, pattern::Pattern, Keyword, Punctuator};
+use boa_ast::{
+    self as ast,
+    declaration::{Binding, Variable},
+    pattern::Pattern,
+    Keyword, Punctuator,
+};
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 use rustc_hash::FxHashSet;
@@ -70,32 +77,39 @@ where
         let _timer = Profiler::global().start_event("LexicalDeclaration", "Parsing");
         let tok = cursor.next(interner).or_abrupt()?;
 
-        let lexical_declaration = match tok.kind() {
-            TokenKind::Keyword((Keyword::Const | Keyword::Let, true)) => {
+        let kind = match tok.kind() {
+            TokenKind::Keyword((Keyword::Const | Keyword::Let | Keyword::Await, true))
+            | TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(true))) => {
                 return Err(Error::general(
                     "Keyword must not contain escaped characters",
                     tok.span().start(),
                 ))
             }
-            TokenKind::Keyword((Keyword::Const, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                true,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
-            TokenKind::Keyword((Keyword::Let, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                false,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
+            TokenKind::Keyword((Keyword::Const, false)) => DeclarationKind::Const,
+            TokenKind::Keyword((Keyword::Let, false)) => DeclarationKind::Let,
+            TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false))) => {
+                DeclarationKind::Using
+            }
+            TokenKind::Keyword((Keyword::Await, false)) => {
+                cursor.expect(
+                    TokenKind::identifier(Sym::USING),
+                    "await using declaration",
+                    interner,
+                )?;
+                DeclarationKind::AwaitUsing
+            }
             _ => unreachable!("unknown token found: {:?}", tok),
         };
 
+        let lexical_declaration = BindingList::new(
+            self.allow_in,
+            self.allow_yield,
+            self.allow_await,
+            kind,
+            self.loop_init,
+        )
+        .parse(cursor, interner)?;
+
         if !self.loop_init {
             cursor.expect_semicolon("lexical declaration", interner)?;
         }
@@ -123,10 +137,74 @@ where
     }
 }
 
+/// Checks if the next tokens start a `using` or an `await using` declaration.
+///
+/// `using` is not a reserved word, so it only starts a declaration if it is followed by a binding
+/// identifier on the same line. Likewise, `await using` needs both `using` and the binding
+/// identifier to be on the same line, and is only valid where `await` is a keyword.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#prod-UsingDeclaration
+pub(in crate::parser) fn is_using_declaration<R>(
+    cursor: &mut Cursor<R>,
+    interner: &mut Interner,
+    allow_await: AllowAwait,
+    loop_init: bool,
+) -> ParseResult<bool>
+where
+    R: ReadChar,
+{
+    // Line terminators are only peeked when not skipping them, so we keep track of the index of
+    // the previous token in the raw token stream to check for line terminators in between.
+    let mut raw_index = usize::from(cursor.peek_is_line_terminator(0, interner)? == Some(true));
+
+    let skip = match cursor.peek(0, interner)?.map(Token::kind) {
+        Some(TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false)))) => 1,
+        Some(TokenKind::Keyword((Keyword::Await, false))) if allow_await.0 => {
+            if cursor.peek_is_line_terminator(raw_index + 1, interner)? != Some(false)
+                || cursor.peek(1, interner)?.map(Token::kind)
+                    != Some(&TokenKind::identifier(Sym::USING))
+            {
+                return Ok(false);
+            }
+            raw_index += 1;
+            2
+        }
+        _ => return Ok(false),
+    };
+
+    if cursor.peek_is_line_terminator(raw_index + 1, interner)? != Some(false) {
+        return Ok(false);
+    }
+
+    Ok(match cursor.peek(skip, interner)?.map(Token::kind) {
+        Some(TokenKind::Keyword((Keyword::Of, _))) => !loop_init,
+        Some(
+            TokenKind::IdentifierName(_)
+            | TokenKind::Keyword((
+                Keyword::Async | Keyword::Await | Keyword::Let | Keyword::Yield,
+                _,
+            )),
+        ) => true,
+        _ => false,
+    })
+}
+
+/// The kind of the declaration parsed by a [`BindingList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeclarationKind {
+    Let,
+    Const,
+    Using,
+    AwaitUsing,
+}
+
 /// Parses a binding list.
 ///
-/// It will return an error if a `const` declaration is being parsed and there is no
-/// initializer.
+/// It will return an error if a `const`, `using` or `await using` declaration is being parsed
+/// and there is no initializer.
 ///
 /// More information:
 ///  - [ECMAScript specification][spec]
@@ -137,7 +215,7 @@ struct BindingList {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    is_const: bool,
+    kind: DeclarationKind,
     loop_init: bool,
 }
 
@@ -147,7 +225,7 @@ impl BindingList {
         allow_in: I,
         allow_yield: Y,
         allow_await: A,
-        is_const: bool,
+        kind: DeclarationKind,
         loop_init: bool,
     ) -> Self
     where
@@ -159,7 +237,7 @@ impl BindingList {
             allow_in: allow_in.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            is_const,
+            kind,
             loop_init,
         }
     }
@@ -179,23 +257,37 @@ where
         let mut decls = Vec::new();
 
         loop {
+            let position = cursor.peek(0, interner).or_abrupt()?.span().start();
             let decl = LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
-            if self.is_const {
-                let init_is_some = decl.init().is_some();
-
-                if init_is_some || self.loop_init {
-                    decls.push(decl);
-                } else {
-                    let next = cursor.next(interner).or_abrupt()?;
+            match self.kind {
+                DeclarationKind::Let => decls.push(decl),
+                DeclarationKind::Using | DeclarationKind::AwaitUsing
+                    if matches!(decl.binding(), Binding::Pattern(_)) =>
+                {
                     return Err(Error::general(
-                        "Expected initializer for const declaration",
-                        next.span().start(),
+                        "using declarations may not contain binding patterns",
+                        position,
                     ));
                 }
-            } else {
-                decls.push(decl);
+                DeclarationKind::Const | DeclarationKind::Using | DeclarationKind::AwaitUsing => {
+                    let init_is_some = decl.init().is_some();
+
+                    if init_is_some || self.loop_init {
+                        decls.push(decl);
+                    } else {
+                        let next = cursor.next(interner).or_abrupt()?;
+                        return Err(Error::general(
+                            if self.kind == DeclarationKind::Const {
+                                "Expected initializer for const declaration"
+                            } else {
+                                "Expected initializer for using declaration"
+                            },
+                            next.span().start(),
+                        ));
+                    }
+                }
             }
 
             match cursor.peek_semicolon(interner)? {
@@ -238,11 +330,12 @@ where
             .try_into()
             .expect("`LexicalBinding` must return at least one variable");
 
-        if self.is_const {
-            Ok(ast::declaration::LexicalDeclaration::Const(decls))
-        } else {
-            Ok(ast::declaration::LexicalDeclaration::Let(decls))
-        }
+        Ok(match self.kind {
+            DeclarationKind::Let => ast::declaration::LexicalDeclaration::Let(decls),
+            DeclarationKind::Const => ast::declaration::LexicalDeclaration::Const(decls),
+            DeclarationKind::Using => ast::declaration::LexicalDeclaration::Using(decls),
+            DeclarationKind::AwaitUsing => ast::declaration::LexicalDeclaration::AwaitUsing(decls),
+        })
     }
 }
 
@@ -20,15 +20,15 @@ pub(in crate::parser) use self::{
         class_decl::ClassTail, ClassDeclaration, FunctionDeclaration, HoistableDeclaration,
     },
     import::ImportDeclaration,
-    lexical::LexicalDeclaration,
+    lexical::{is_using_declaration, LexicalDeclaration},
 };
 use crate::{
-    lexer::TokenKind,
+    lexer::{Token, TokenKind},
     parser::{AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser},
     source::ReadChar,
     Error,
 };
-use boa_ast::{self as ast, Keyword};
+use boa_ast::{self as ast, Keyword, Punctuator};
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 
@@ -70,7 +70,8 @@ where
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         match tok.kind() {
-            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _)) => {
+            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _))
+            | TokenKind::Punctuator(Punctuator::At) => {
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false)
                     .parse(cursor, interner)
             }
@@ -139,3 +140,115 @@ where
         Ok((*from).into())
     }
 }
+
+/// Parses an optional `with` clause.
+///
+/// Returns an empty list of import attributes if the next token is not the `with` keyword.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-WithClause
+#[derive(Debug, Clone, Copy)]
+struct WithClause {
+    context: &'static str,
+}
+
+impl WithClause {
+    /// Creates a new `with` clause parser
+    #[inline]
+    const fn new(context: &'static str) -> Self {
+        Self { context }
+    }
+}
+
+impl<R> TokenParser<R> for WithClause
+where
+    R: ReadChar,
+{
+    type Output = Box<[ast::declaration::ImportAttribute]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let _timer = Profiler::global().start_event("WithClause", "Parsing");
+
+        if !matches!(
+            cursor.peek(0, interner)?.map(Token::kind),
+            Some(TokenKind::Keyword((Keyword::With, false)))
+        ) {
+            return Ok(Box::default());
+        }
+        cursor.advance(interner);
+
+        cursor.expect(Punctuator::OpenBlock, self.context, interner)?;
+
+        let mut attributes: Vec<ast::declaration::ImportAttribute> = Vec::new();
+        loop {
+            let tok = cursor.next(interner).or_abrupt()?;
+            let key = match tok.kind() {
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                TokenKind::IdentifierName((key, _)) | TokenKind::StringLiteral((key, _)) => *key,
+                TokenKind::Keyword((keyword, _)) => keyword.to_sym(),
+                TokenKind::NullLiteral(_) => Sym::NULL,
+                TokenKind::BooleanLiteral((true, _)) => Sym::TRUE,
+                TokenKind::BooleanLiteral((false, _)) => Sym::FALSE,
+                _ => {
+                    return Err(Error::expected(
+                        [
+                            "identifier name".to_owned(),
+                            "string literal".to_owned(),
+                            Punctuator::CloseBlock.to_string(),
+                        ],
+                        tok.to_string(interner),
+                        tok.span(),
+                        self.context,
+                    ))
+                }
+            };
+
+            // It is a Syntax Error if WithClauseToAttributes of WithClause has two different
+            // entries a and b such that a.[[Key]] is b.[[Key]].
+            if attributes.iter().any(|attribute| attribute.key() == key) {
+                return Err(Error::general(
+                    format!(
+                        "duplicate import attribute `{}`",
+                        interner.resolve_expect(key)
+                    ),
+                    tok.span().start(),
+                ));
+            }
+
+            cursor.expect(Punctuator::Colon, self.context, interner)?;
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            let TokenKind::StringLiteral((value, _)) = tok.kind() else {
+                return Err(Error::expected(
+                    ["string literal".to_owned()],
+                    tok.to_string(interner),
+                    tok.span(),
+                    self.context,
+                ));
+            };
+
+            attributes.push(ast::declaration::ImportAttribute::new(key, *value));
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Comma) => {}
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                _ => {
+                    return Err(Error::expected(
+                        [
+                            Punctuator::Comma.to_string(),
+                            Punctuator::CloseBlock.to_string(),
+                        ],
+                        tok.to_string(interner),
+                        tok.span(),
+                        self.context,
+                    ))
+                }
+            }
+        }
+
+        Ok(attributes.into_boxed_slice())
+    }
+}
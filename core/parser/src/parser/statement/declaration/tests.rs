@@ -1,8 +1,12 @@
-use crate::parser::tests::{check_invalid_script, check_script_parser};
+use crate::{
+    parser::tests::{check_invalid_script, check_script_parser},
+    Parser, Source,
+};
 use boa_ast::{
-    declaration::{LexicalDeclaration, VarDeclaration, Variable},
-    expression::literal::Literal,
-    Declaration, Statement,
+    declaration::{ImportAttribute, LexicalDeclaration, ModuleRequest, VarDeclaration, Variable},
+    expression::{literal::Literal, Identifier},
+    statement::Block,
+    Declaration, Statement, StatementListItem,
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
@@ -325,6 +329,61 @@ fn empty_const_declaration() {
     check_invalid_script("const a;");
 }
 
+/// Checks `using` declaration parsing.
+#[test]
+fn using_declaration() {
+    let interner = &mut Interner::default();
+    check_script_parser(
+        "{ using a = b; }",
+        vec![
+            Statement::Block(Block::from(vec![StatementListItem::Declaration(
+                Declaration::Lexical(LexicalDeclaration::Using(
+                    vec![Variable::from_identifier(
+                        interner.get_or_intern_static("a", utf16!("a")).into(),
+                        Some(
+                            Identifier::new(interner.get_or_intern_static("b", utf16!("b"))).into(),
+                        ),
+                    )]
+                    .try_into()
+                    .unwrap(),
+                )),
+            )]))
+            .into(),
+        ],
+        interner,
+    );
+}
+
+/// Checks the early errors of `using` declarations.
+#[test]
+fn using_declaration_early_errors() {
+    check_invalid_script("using a = b;");
+    check_invalid_script("{ using a; }");
+    check_invalid_script("{ using { a } = b; }");
+    check_invalid_script("switch (a) { case 0: using b = c; }");
+    check_invalid_script("for (using a in b) {}");
+    check_invalid_script("async function f() { { await using a; } }");
+}
+
+/// Checks that `using` is still usable as an identifier.
+#[test]
+fn using_identifier() {
+    for src in [
+        "let using = 1;",
+        "using = 1;",
+        "{ using[0] = 1; }",
+        "{ using\na = 1; }",
+        "for (using of []) {}",
+    ] {
+        assert!(
+            Parser::new(Source::from_bytes(src))
+                .parse_script(&mut Interner::default())
+                .is_ok(),
+            "{src} should parse"
+        );
+    }
+}
+
 /// Checks multiple `const` declarations.
 #[test]
 fn multiple_const_declaration() {
@@ -360,3 +419,64 @@ fn lexical_declaration_early_errors() {
     check_invalid_script("for (let a = 0, a = 0; ; ) {}");
     check_invalid_script("for (const a = 0, a = 0; ; ) {}");
 }
+
+/// Checks the parsing of the import attributes of imports and re-exports.
+#[test]
+fn import_attributes() {
+    let interner = &mut Interner::default();
+    let module = Parser::new(Source::from_bytes(
+        r#"
+            import a from "a" with { type: "json" };
+            import "b" with { "type": "json", if: "x", };
+            export * from "c" with {};
+            export { d } from "d" with { type: "css" };
+        "#,
+    ))
+    .parse_module(interner)
+    .expect("failed to parse");
+
+    let type_ = interner.get_or_intern_static("type", utf16!("type"));
+    let if_ = interner.get_or_intern_static("if", utf16!("if"));
+    let json = interner.get_or_intern_static("json", utf16!("json"));
+    let x = interner.get_or_intern_static("x", utf16!("x"));
+    let css = interner.get_or_intern_static("css", utf16!("css"));
+
+    let requests = module.items().requests();
+    let requests: Vec<_> = requests.iter().collect();
+    assert_eq!(
+        requests,
+        [
+            &ModuleRequest::new(
+                interner.get_or_intern_static("a", utf16!("a")),
+                &[ImportAttribute::new(type_, json)],
+            ),
+            &ModuleRequest::new(
+                interner.get_or_intern_static("b", utf16!("b")),
+                &[
+                    ImportAttribute::new(type_, json),
+                    ImportAttribute::new(if_, x)
+                ],
+            ),
+            &ModuleRequest::new(interner.get_or_intern_static("c", utf16!("c")), &[]),
+            &ModuleRequest::new(
+                interner.get_or_intern_static("d", utf16!("d")),
+                &[ImportAttribute::new(type_, css)],
+            ),
+        ]
+    );
+}
+
+/// Checks the early errors of import attributes.
+#[test]
+fn import_attributes_early_errors() {
+    for src in [
+        r#"import a from "a" with { type: "json", type: "json" };"#,
+        r#"import a from "a" with { type: json };"#,
+        r#"import a from "a" with { type: "json" } foo;"#,
+        r#"export * from "a" with { "type": "json", type: "css" };"#,
+    ] {
+        assert!(Parser::new(Source::from_bytes(src))
+            .parse_module(&mut Interner::default())
+            .is_err());
+    }
+}
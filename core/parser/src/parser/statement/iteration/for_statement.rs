@@ -11,7 +11,7 @@ use crate::{
     lexer::{Error as LexError, TokenKind},
     parser::{
         expression::{AssignmentExpression, Expression},
-        statement::declaration::LexicalDeclaration,
+        statement::declaration::{is_using_declaration, LexicalDeclaration},
         statement::{variable::VariableDeclarationList, Statement},
         AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
@@ -107,7 +107,13 @@ where
             }
         };
 
+        let is_using = is_using_declaration(cursor, interner, self.allow_await, true)?;
         let init = match cursor.peek(0, interner).or_abrupt()?.kind() {
+            _ if is_using => Some(
+                LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
+                    .parse(cursor, interner)?
+                    .into(),
+            ),
             TokenKind::Keyword((Keyword::Var, _)) => {
                 cursor.advance(interner);
                 Some(
@@ -206,7 +212,10 @@ where
                 // Checks are only applicable to lexical bindings.
                 if matches!(
                     &init,
-                    IterableLoopInitializer::Const(_) | IterableLoopInitializer::Let(_)
+                    IterableLoopInitializer::Const(_)
+                        | IterableLoopInitializer::Let(_)
+                        | IterableLoopInitializer::Using(_)
+                        | IterableLoopInitializer::AwaitUsing(_)
                 ) {
                     // It is a Syntax Error if the BoundNames of ForDeclaration contains "let".
                     // It is a Syntax Error if any element of the BoundNames of ForDeclaration also occurs in the VarDeclaredNames of Statement.
@@ -243,17 +252,22 @@ where
             (init, _) => init,
         };
 
-        if let Some(ForLoopInitializer::Lexical(ast::declaration::LexicalDeclaration::Const(
-            ref list,
-        ))) = init
-        {
-            for decl in list.as_ref() {
-                if decl.init().is_none() {
-                    return Err(Error::general(
-                        "Expected initializer for const declaration",
-                        position,
-                    ));
-                }
+        if let Some(ForLoopInitializer::Lexical(ref decl)) = init {
+            if decl.is_const()
+                && decl
+                    .variable_list()
+                    .as_ref()
+                    .iter()
+                    .any(|v| v.init().is_none())
+            {
+                return Err(Error::general(
+                    if decl.is_using() || decl.is_await_using() {
+                        "Expected initializer for using declaration"
+                    } else {
+                        "Expected initializer for const declaration"
+                    },
+                    position,
+                ));
             }
         }
 
@@ -378,6 +392,22 @@ fn initializer_to_iterable_loop_initializer(
                     ast::declaration::LexicalDeclaration::Let(_) => {
                         IterableLoopInitializer::Let(declaration.binding().clone())
                     }
+                    ast::declaration::LexicalDeclaration::Using(_)
+                    | ast::declaration::LexicalDeclaration::AwaitUsing(_)
+                        if in_loop =>
+                    {
+                        return Err(Error::lex(LexError::Syntax(
+                            "using declarations are not allowed in the head of a for-in loop"
+                                .into(),
+                            position,
+                        )));
+                    }
+                    ast::declaration::LexicalDeclaration::Using(_) => {
+                        IterableLoopInitializer::Using(declaration.binding().clone())
+                    }
+                    ast::declaration::LexicalDeclaration::AwaitUsing(_) => {
+                        IterableLoopInitializer::AwaitUsing(declaration.binding().clone())
+                    }
                 })
             }
             _ => Err(Error::lex(LexError::Syntax(
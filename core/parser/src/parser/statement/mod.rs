@@ -26,7 +26,10 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::{Declaration, ExportDeclaration, ImportDeclaration},
+    declaration::{
+        is_using_declaration, ClassDeclaration, Declaration, ExportDeclaration, ImportDeclaration,
+        LexicalDeclaration,
+    },
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -41,7 +44,7 @@ use self::{
 use crate::{
     lexer::{token::EscapeSequence, Error as LexError, InputElement, Token, TokenKind},
     parser::{
-        expression::{BindingIdentifier, Initializer, PropertyName},
+        expression::{BindingIdentifier, DecoratorList, Initializer, PropertyName},
         AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
@@ -290,6 +293,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("StatementList", "Parsing");
         let mut items = Vec::new();
+        let mut positions = Vec::new();
 
         let global_strict = cursor.strict();
         let mut directive_prologues = self.directive_prologues;
@@ -297,16 +301,18 @@ where
         let mut directives_stack = Vec::new();
 
         loop {
-            match cursor.peek(0, interner)? {
+            let position = match cursor.peek(0, interner)? {
                 Some(token) if self.break_nodes.contains(token.kind()) => break,
-                Some(token) if directive_prologues => {
-                    if let TokenKind::StringLiteral((_, escape)) = token.kind() {
-                        directives_stack.push((token.span().start(), *escape));
+                Some(token) => {
+                    if directive_prologues {
+                        if let TokenKind::StringLiteral((_, escape)) = token.kind() {
+                            directives_stack.push((token.span().start(), *escape));
+                        }
                     }
+                    token.span().start()
                 }
                 None => break,
-                _ => {}
-            }
+            };
 
             let item =
                 StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
@@ -361,11 +367,12 @@ where
             }
 
             items.push(item);
+            positions.push(position);
         }
 
         cursor.set_strict(global_strict);
 
-        Ok(ast::StatementList::new(items, strict))
+        Ok(ast::StatementList::new(items, strict).with_positions(positions))
     }
 }
 
@@ -410,14 +417,24 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("StatementListItem", "Parsing");
+
+        if is_using_declaration(cursor, interner, self.allow_await, false)? {
+            return LexicalDeclaration::new(true, self.allow_yield, self.allow_await, false)
+                .parse(cursor, interner)
+                .map(|decl| ast::StatementListItem::Declaration(decl.into()));
+        }
+
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         match *tok.kind() {
             TokenKind::Keyword(
                 (Keyword::Function | Keyword::Class | Keyword::Const, _) | (Keyword::Let, false),
-            ) => Declaration::new(self.allow_yield, self.allow_await)
-                .parse(cursor, interner)
-                .map(ast::StatementListItem::from),
+            )
+            | TokenKind::Punctuator(Punctuator::At) => {
+                Declaration::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)
+                    .map(ast::StatementListItem::from)
+            }
             TokenKind::Keyword((Keyword::Async, false)) => {
                 let skip_n = if cursor.peek_is_line_terminator(0, interner).or_abrupt()? {
                     2
@@ -966,6 +983,41 @@ where
             TokenKind::Keyword((Keyword::Export, false)) => ExportDeclaration
                 .parse(cursor, interner)
                 .map(Self::Output::ExportDeclaration),
+            TokenKind::Punctuator(Punctuator::At) => {
+                let decorators = DecoratorList::new(false, true).parse(cursor, interner)?;
+
+                // ExportDeclaration : DecoratorList export ClassDeclaration
+                if cursor
+                    .next_if((Keyword::Export, false), interner)?
+                    .is_none()
+                {
+                    return ClassDeclaration::new(false, true, false)
+                        .with_decorators(decorators)
+                        .parse(cursor, interner)
+                        .map(|class| {
+                            Self::Output::StatementListItem(ast::Declaration::from(class).into())
+                        });
+                }
+
+                let export = if cursor
+                    .next_if((Keyword::Default, false), interner)?
+                    .is_some()
+                {
+                    ast::declaration::ExportDeclaration::DefaultClassDeclaration(
+                        ClassDeclaration::new(false, true, true)
+                            .with_decorators(decorators)
+                            .parse(cursor, interner)?,
+                    )
+                } else {
+                    ast::declaration::ExportDeclaration::Declaration(
+                        ClassDeclaration::new(false, true, false)
+                            .with_decorators(decorators)
+                            .parse(cursor, interner)?
+                            .into(),
+                    )
+                };
+                Ok(Self::Output::ExportDeclaration(export))
+            }
             TokenKind::Keyword((Keyword::Import, false)) => {
                 if ImportDeclaration::test(cursor, interner)? {
                     ImportDeclaration
@@ -177,6 +177,14 @@ where
                     )
                     .parse(cursor, interner)?;
 
+                    // It is a Syntax Error if StatementList Contains UsingDeclaration.
+                    if statement_list.contains_using_declaration() {
+                        return Err(Error::general(
+                            "using declarations are not allowed in switch cases",
+                            token.span().start(),
+                        ));
+                    }
+
                     cases.push(statement::Case::new(cond, statement_list));
                 }
                 TokenKind::Keyword((Keyword::Default, false)) => {
@@ -201,6 +209,14 @@ where
                     )
                     .parse(cursor, interner)?;
 
+                    // It is a Syntax Error if StatementList Contains UsingDeclaration.
+                    if statement_list.contains_using_declaration() {
+                        return Err(Error::general(
+                            "using declarations are not allowed in switch cases",
+                            token.span().start(),
+                        ));
+                    }
+
                     cases.push(statement::Case::default(statement_list));
 
                     has_default_case = true;
@@ -46,7 +46,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ThrowStatement", "Parsing");
-        cursor.expect((Keyword::Throw, false), "throw statement", interner)?;
+        let position = cursor
+            .expect((Keyword::Throw, false), "throw statement", interner)?
+            .span()
+            .start();
 
         cursor.peek_expect_no_lineterminator(0, "throw statement", interner)?;
 
@@ -58,6 +61,6 @@ where
             }
         }
 
-        Ok(Throw::new(expr))
+        Ok(Throw::new(expr).with_position(position))
     }
 }
@@ -49,5 +49,26 @@ fn main() -> Result<(), JsError> {
 
     set.clear(context)?;
 
+    // Compute unions, intersections and differences of sets.
+    let other = JsSet::from_iter([JsValue::new(3), JsValue::new(4)], context);
+
+    let union = slice_set.union(&other, context)?;
+    assert_eq!(union.size()?, 4);
+
+    let intersection = slice_set.intersection(&other, context)?;
+    assert_eq!(intersection.size()?, 1);
+    assert!(intersection.has(3, context)?);
+
+    let difference = slice_set.difference(&other, context)?;
+    assert_eq!(difference.size()?, 2);
+    assert_eq!(difference.has(3, context)?, false);
+
+    let symmetric_difference = slice_set.symmetric_difference(&other, context)?;
+    assert_eq!(symmetric_difference.size()?, 3);
+
+    assert!(intersection.is_subset_of(&slice_set, context)?);
+    assert!(union.is_superset_of(&other, context)?);
+    assert!(difference.is_disjoint_from(&other, context)?);
+
     Ok(())
 }
@@ -8,9 +8,9 @@ use boa_engine::{
     builtins::promise::PromiseState,
     job::{FutureJob, JobQueue, NativeJob},
     js_string,
-    module::ModuleLoader,
+    module::{ModuleLoader, ModuleRequest},
     string::utf16,
-    Context, JsNativeError, JsResult, JsString, JsValue, Module,
+    Context, JsNativeError, JsResult, JsValue, Module,
 };
 use boa_parser::Source;
 use futures_util::{stream::FuturesUnordered, StreamExt};
@@ -30,11 +30,14 @@ impl ModuleLoader for HttpModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: boa_engine::module::Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        let url = specifier.to_std_string_escaped();
+        let url = request.specifier().to_std_string_escaped();
+        let json = request
+            .module_type()
+            .is_some_and(|ty| ty == &js_string!("json"));
 
         let fetch = async move {
             // Adding some prints to show the non-deterministic nature of the async fetches.
@@ -72,10 +75,15 @@ impl ModuleLoader for HttpModuleLoader {
                     }
                 };
 
-                // Could also add a path if needed.
-                let source = Source::from_bytes(body.as_bytes());
+                // Modules imported with `type: "json"` are parsed as JSON modules.
+                let module = if json {
+                    Module::parse_json(js_string!(body), None, context)
+                } else {
+                    // Could also add a path if needed.
+                    let source = Source::from_bytes(body.as_bytes());
 
-                let module = Module::parse(source, None, context);
+                    Module::parse(source, None, context)
+                };
 
                 // We don't do any error handling, `finish_load` takes care of that for us.
                 finish_load(module, context);
@@ -12,10 +12,12 @@ fn main() -> Result<(), Box<dyn Error>> {
     const MODULE_SRC: &str = r#"
         import { pyth } from "./trig.mjs";
         import * as ops from "./operations.mjs";
+        // JSON modules are imported using the `type: "json"` import attribute.
+        import constants from "./constants.json" with { type: "json" };
 
         export let result = pyth(3, 4);
         export function mix(a, b) {
-            return ops.sum(ops.mult(a, ops.sub(b, a)), 10);
+            return ops.sum(ops.mult(a, ops.sub(b, a)), constants.offset);
         }
     "#;
 
@@ -78,16 +78,20 @@ impl IterableDynamicDataProvider<ExportMarker> for PluralRangesFallbackHack {
 /// List of keys used by `Intl` components.
 ///
 /// This must be kept in sync with the list of implemented components of `Intl`.
-const KEYS: [&[DataKey]; 9] = [
+const KEYS: [&[DataKey]; 13] = [
+    icu_calendar::provider::KEYS,
     icu_casemap::provider::KEYS,
     icu_collator::provider::KEYS,
     icu_datetime::provider::KEYS,
     icu_decimal::provider::KEYS,
+    icu_displaynames::provider::KEYS,
     icu_list::provider::KEYS,
     icu_locid_transform::provider::KEYS,
     icu_normalizer::provider::KEYS,
     icu_plurals::provider::KEYS,
+    icu_relativetime::provider::KEYS,
     icu_segmenter::provider::KEYS,
+    icu_timezone::provider::KEYS,
 ];
 
 fn main() -> Result<(), Box<dyn Error>> {